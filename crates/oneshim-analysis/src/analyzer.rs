@@ -504,6 +504,10 @@ mod tests {
             Ok(())
         }
 
+        async fn save_events(&self, _events: &[Event]) -> Result<(), CoreError> {
+            Ok(())
+        }
+
         async fn get_events(
             &self,
             _from: DateTime<Utc>,
@@ -521,6 +525,13 @@ mod tests {
             Ok(())
         }
 
+        async fn get_idempotency_keys(
+            &self,
+            _event_ids: &[String],
+        ) -> Result<std::collections::HashMap<String, String>, CoreError> {
+            Ok(Default::default())
+        }
+
         async fn mark_unsent_as_sent_before(
             &self,
             _before: DateTime<Utc>,
@@ -528,6 +539,13 @@ mod tests {
             Ok(0)
         }
 
+        async fn quarantine_events(
+            &self,
+            _events: &[oneshim_core::models::event::QuarantinedEvent],
+        ) -> Result<(), CoreError> {
+            Ok(())
+        }
+
         async fn enforce_retention(&self) -> Result<usize, CoreError> {
             Ok(0)
         }
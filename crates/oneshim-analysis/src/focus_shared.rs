@@ -4,7 +4,8 @@
 //! cooldown enums, session tracking state, and the `make_rule_suggestion`
 //! helper that both binary entry-points need.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use oneshim_core::config::FocusClassificationConfig;
 use oneshim_core::models::suggestion::{Priority, Suggestion, SuggestionSource, SuggestionType};
 use oneshim_core::models::work_session::AppCategory;
 use uuid::Uuid;
@@ -23,6 +24,15 @@ pub struct FocusAnalyzerConfig {
     pub workflow_split_idle_secs: u64,
     pub playbook_min_relevance: f32,
     pub playbook_stale_flush_secs: u64,
+    /// A deep-work session left for a communication app and resumed within
+    /// this many seconds continues as the same session. `0` splits on every
+    /// switch.
+    pub session_gap_grace_secs: u64,
+    /// Idle this long ends the active session (see
+    /// [`SessionTracker::observe_idle`]). `0` disables.
+    pub idle_session_end_secs: u64,
+    /// Which apps count as deep work, shallow work or communication.
+    pub classification: FocusClassificationConfig,
 }
 
 impl Default for FocusAnalyzerConfig {
@@ -37,6 +47,9 @@ impl Default for FocusAnalyzerConfig {
             workflow_split_idle_secs: 300, // 5 min
             playbook_min_relevance: 0.35,
             playbook_stale_flush_secs: 900, // 15 min
+            session_gap_grace_secs: 120,    // 2 min
+            idle_session_end_secs: 0,
+            classification: FocusClassificationConfig::default(),
         }
     }
 }
@@ -75,6 +88,50 @@ pub struct SessionTracker {
     pub current_app_start: Option<DateTime<Utc>>,
     pub continuous_deep_work_secs: u64,
     pub pending_interruption_id: Option<i64>,
+    /// When the active session was left for a communication app. The session
+    /// stays open until the gap outlives the grace period.
+    pub session_gap_started_at: Option<DateTime<Utc>>,
+    /// Set once a long idle stretch has ended the session; cleared when
+    /// activity returns.
+    pub ended_by_idle: bool,
+}
+
+/// What an idle reading means for the active work session.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdleSessionTransition {
+    /// Idle passed the threshold: end the session at `idle_started_at`.
+    End { idle_started_at: DateTime<Utc> },
+    /// Activity returned after an idle-ended session.
+    Resume,
+}
+
+impl SessionTracker {
+    /// Feed the current idle duration. Reports [`IdleSessionTransition::End`]
+    /// once when idle reaches `end_after_secs`, then
+    /// [`IdleSessionTransition::Resume`] once on the first reading below it.
+    /// `end_after_secs == 0` disables idle-based session end.
+    pub fn observe_idle(
+        &mut self,
+        idle_secs: u64,
+        end_after_secs: u64,
+        now: DateTime<Utc>,
+    ) -> Option<IdleSessionTransition> {
+        if end_after_secs == 0 {
+            return None;
+        }
+        let idle_long = idle_secs >= end_after_secs;
+        if idle_long == self.ended_by_idle {
+            return None;
+        }
+        self.ended_by_idle = idle_long;
+        Some(if idle_long {
+            IdleSessionTransition::End {
+                idle_started_at: now - Duration::seconds(idle_secs as i64),
+            }
+        } else {
+            IdleSessionTransition::Resume
+        })
+    }
 }
 
 // ── Helpers ───────────────────────────────────────────────────────
@@ -112,6 +169,7 @@ mod tests {
         assert_eq!(cfg.break_suggestion_mins, 90);
         assert!((cfg.excessive_communication_threshold - 0.4).abs() < f32::EPSILON);
         assert_eq!(cfg.suggestion_cooldown_secs, 1800);
+        assert_eq!(cfg.session_gap_grace_secs, 120);
     }
 
     #[test]
@@ -137,6 +195,37 @@ mod tests {
         assert!(st.current_app.is_none());
         assert!(st.current_category.is_none());
         assert_eq!(st.continuous_deep_work_secs, 0);
+        assert!(st.session_gap_started_at.is_none());
+        assert!(!st.ended_by_idle);
+    }
+
+    #[test]
+    fn idle_beyond_threshold_ends_once_then_resumes_on_activity() {
+        let mut tracker = SessionTracker::default();
+        let now = Utc::now();
+
+        assert_eq!(tracker.observe_idle(60, 900, now), None);
+        assert_eq!(
+            tracker.observe_idle(900, 900, now),
+            Some(IdleSessionTransition::End {
+                idle_started_at: now - Duration::seconds(900)
+            })
+        );
+        assert!(tracker.ended_by_idle);
+        assert_eq!(tracker.observe_idle(1500, 900, now), None);
+        assert_eq!(
+            tracker.observe_idle(0, 900, now),
+            Some(IdleSessionTransition::Resume)
+        );
+        assert!(!tracker.ended_by_idle);
+        assert_eq!(tracker.observe_idle(5, 900, now), None);
+    }
+
+    #[test]
+    fn zero_threshold_disables_idle_session_end() {
+        let mut tracker = SessionTracker::default();
+        assert_eq!(tracker.observe_idle(86_400, 0, Utc::now()), None);
+        assert!(!tracker.ended_by_idle);
     }
 
     #[test]
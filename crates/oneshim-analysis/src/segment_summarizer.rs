@@ -223,6 +223,7 @@ mod tests {
             prev_app_name: None,
             timestamp: ts,
             input_activity_level: importance,
+            repeat_count: 0,
         })
     }
 
@@ -31,6 +31,9 @@ pub struct AuditEntryDto {
     pub details: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub elapsed_ms: Option<u64>,
+    /// `success`, `failed`, `denied` or `timeout`; absent for started entries.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub outcome: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -44,6 +47,29 @@ fn default_audit_limit() -> usize {
     50
 }
 
+/// `TimeRangeQuery`-style params for paging through audit entries.
+/// `outcome` is a comma-separated list (`success,denied,...`).
+#[derive(Debug, Default, Deserialize)]
+pub struct AuditSearchQuery {
+    pub from: Option<String>,
+    pub to: Option<String>,
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub outcome: Option<String>,
+}
+
+impl AuditSearchQuery {
+    pub fn time_range(&self) -> crate::common::TimeRangeQuery {
+        crate::common::TimeRangeQuery {
+            from: self.from.clone(),
+            to: self.to.clone(),
+            limit: self.limit,
+            offset: self.offset,
+            min_importance: None,
+        }
+    }
+}
+
 #[derive(Debug, Deserialize)]
 pub struct PolicyEventQuery {
     #[serde(default = "default_policy_event_limit")]
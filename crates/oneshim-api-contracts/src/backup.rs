@@ -10,18 +10,51 @@ pub struct BackupQuery {
     pub include_events: bool,
     #[serde(default)]
     pub include_frames: bool,
+    #[serde(default)]
+    pub redaction_profile: RedactionProfile,
 }
 
 fn default_true() -> bool {
     true
 }
 
+/// Named redaction applied to events and frames before a backup is written.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RedactionProfile {
+    /// Keep everything; for the user's own devices.
+    #[default]
+    Full,
+    /// Drop OCR text from frames.
+    StripOcr,
+    /// For sharing outside the user's control: no OCR text, no window
+    /// titles and no input-activity events.
+    External,
+}
+
+impl RedactionProfile {
+    pub fn strips_ocr(self) -> bool {
+        matches!(self, Self::StripOcr | Self::External)
+    }
+
+    pub fn strips_window_titles(self) -> bool {
+        matches!(self, Self::External)
+    }
+
+    pub fn drops_input_events(self) -> bool {
+        matches!(self, Self::External)
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
 pub struct BackupMetadata {
     pub version: String,
     pub created_at: String,
     pub app_version: String,
     pub includes: BackupIncludes,
+    /// Absent in archives written before profiles existed, which were full.
+    #[serde(default)]
+    pub redaction_profile: RedactionProfile,
 }
 
 #[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
@@ -113,12 +146,21 @@ mod tests {
                 events: false,
                 frames: false,
             },
+            redaction_profile: RedactionProfile::StripOcr,
         };
         let json = serde_json::to_string(&original).unwrap();
+        assert!(json.contains("\"redaction_profile\":\"strip_ocr\""));
         let decoded: BackupMetadata = serde_json::from_str(&json).unwrap();
         assert_eq!(original, decoded);
     }
 
+    #[test]
+    fn metadata_without_profile_defaults_to_full() {
+        let json = r#"{"version":"1.0","created_at":"2026-04-11T00:00:00Z","app_version":"0.4.33","includes":{"settings":true,"tags":true,"events":false,"frames":false}}"#;
+        let decoded: BackupMetadata = serde_json::from_str(json).unwrap();
+        assert_eq!(decoded.redaction_profile, RedactionProfile::Full);
+    }
+
     #[test]
     fn round_trip_tag_backup() {
         let original = TagBackup {
@@ -179,3 +221,13 @@ pub struct RestoredCounts {
     pub events: u64,
     pub frames: u64,
 }
+
+/// Response of `POST /api/backup/database`.
+#[derive(Debug, Serialize)]
+pub struct DatabaseBackupResult {
+    pub path: String,
+    pub size_bytes: u64,
+    pub created_at: String,
+    /// Older snapshots deleted to stay within `storage.db_backup_keep`.
+    pub rotated_out: usize,
+}
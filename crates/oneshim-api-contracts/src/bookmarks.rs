@@ -0,0 +1,10 @@
+use serde::Deserialize;
+
+/// Request body for `POST /api/bookmarks`.
+#[derive(Debug, Default, Deserialize)]
+pub struct CreateBookmarkRequest {
+    #[serde(default)]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub frame_id: Option<i64>,
+}
@@ -1,5 +1,5 @@
-use chrono::{DateTime, Duration, Utc};
-use oneshim_core::types::{TimeWindow, TimeWindowError};
+use chrono::{DateTime, Duration, Local, TimeZone, Utc};
+use oneshim_core::types::{parse_window_bound, TimeWindow, TimeWindowError, WindowBound};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Default, Deserialize)]
@@ -56,6 +56,10 @@ impl TimeRangeQuery {
     /// - If `from` is None: defaults to `to - default_lookback`.
     /// - `default_lookback` is domain-specific (e.g. `Duration::hours(24)` to
     ///   preserve `from_datetime()` semantics).
+    /// - Either bound may be a relative token (`today`, `yesterday`,
+    ///   `this_week`, `7d`, `24h`) instead of RFC3339; `from` takes the
+    ///   token's start and `to` its end. Calendar tokens resolve in the local
+    ///   timezone — use [`Self::to_time_window_at`] to pick another.
     ///
     /// Per spec U5: this is the boundary where Optional bounds become Required
     /// bounds. Internal code (storage, models) work with `TimeWindow`.
@@ -65,23 +69,29 @@ impl TimeRangeQuery {
     /// fields don't need to clone or restructure.
     ///
     /// # Errors
-    /// - [`TimeWindowError::ParseFailed`] if `from` or `to` is not valid RFC3339.
+    /// - [`TimeWindowError::ParseFailed`] if `from` or `to` is neither valid
+    ///   RFC3339 nor a known relative token.
     /// - [`TimeWindowError::InvertedBounds`] if parsed `start > end`.
     pub fn to_time_window(
         &self,
         default_lookback: Duration,
     ) -> Result<TimeWindow, TimeWindowError> {
-        let now = Utc::now();
+        self.to_time_window_at(default_lookback, Local::now())
+    }
+
+    /// [`Self::to_time_window`] relative to `now`, resolving calendar tokens
+    /// in `now`'s timezone.
+    pub fn to_time_window_at<Tz: TimeZone>(
+        &self,
+        default_lookback: Duration,
+        now: DateTime<Tz>,
+    ) -> Result<TimeWindow, TimeWindowError> {
         let end = match self.to.as_deref() {
-            Some(s) => DateTime::parse_from_rfc3339(s)
-                .map_err(|e| TimeWindowError::ParseFailed(e.to_string()))?
-                .with_timezone(&Utc),
-            None => now,
+            Some(s) => parse_window_bound(s, WindowBound::End, &now)?,
+            None => now.with_timezone(&Utc),
         };
         let start = match self.from.as_deref() {
-            Some(s) => DateTime::parse_from_rfc3339(s)
-                .map_err(|e| TimeWindowError::ParseFailed(e.to_string()))?
-                .with_timezone(&Utc),
+            Some(s) => parse_window_bound(s, WindowBound::Start, &now)?,
             None => end - default_lookback,
         };
         TimeWindow::new(start, end)
@@ -196,4 +206,50 @@ mod time_window_adapter_tests {
         // q still usable after adapter call
         assert_eq!(q.limit, Some(50));
     }
+
+    #[test]
+    fn to_time_window_resolves_relative_days_ending_now() {
+        let q = TimeRangeQuery {
+            from: Some("7d".to_string()),
+            ..Default::default()
+        };
+        let before = Utc::now();
+        let w = q.to_time_window(Duration::hours(24)).unwrap();
+        let after = Utc::now();
+        assert!(w.end >= before && w.end <= after);
+        assert_eq!(w.duration(), Duration::days(7));
+    }
+
+    #[test]
+    fn to_time_window_mixes_relative_and_absolute_bounds() {
+        let now = dt(2026, 4, 25) + Duration::hours(12);
+        let q = TimeRangeQuery {
+            from: Some("2026-04-20T00:00:00Z".to_string()),
+            to: Some("yesterday".to_string()),
+            ..Default::default()
+        };
+        let w = q.to_time_window_at(Duration::days(1), now).unwrap();
+        assert_eq!(w.start, dt(2026, 4, 20));
+        assert_eq!(w.end, dt(2026, 4, 25));
+
+        let q = TimeRangeQuery {
+            from: Some("today".to_string()),
+            to: Some("2026-04-25T18:00:00Z".to_string()),
+            ..Default::default()
+        };
+        let w = q.to_time_window_at(Duration::days(1), now).unwrap();
+        assert_eq!(w.start, dt(2026, 4, 25));
+        assert_eq!(w.end, dt(2026, 4, 25) + Duration::hours(18));
+    }
+
+    #[test]
+    fn to_time_window_rejects_unknown_relative_token() {
+        let q = TimeRangeQuery {
+            from: Some("last_month".to_string()),
+            ..Default::default()
+        };
+        let err = q.to_time_window(Duration::days(1)).unwrap_err();
+        assert!(matches!(err, TimeWindowError::ParseFailed(_)));
+        assert!(err.to_string().contains("this_week"));
+    }
 }
@@ -1,4 +1,4 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 /// Query parameters for the dashboard day endpoint.
 #[derive(Debug, Deserialize)]
@@ -7,6 +7,27 @@ pub struct DashboardDayQuery {
     pub date: Option<String>,
 }
 
+/// Liveness of the background scheduler loops (`GET /api/dashboard/loops`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchedulerLoopsResponse {
+    /// `false` when the scheduler is not running in this process.
+    pub available: bool,
+    pub stall_multiplier: u32,
+    pub loops: Vec<SchedulerLoopHealth>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchedulerLoopHealth {
+    pub name: String,
+    /// `healthy` or `stalled`.
+    pub status: String,
+    pub interval_secs: u64,
+    pub last_tick_at: String,
+    pub seconds_since_last_tick: u64,
+    pub tick_count: u64,
+    pub restart_count: u32,
+}
+
 /// Internal deserialization helper for content activity JSON blobs.
 #[derive(Debug, Deserialize)]
 pub struct RawContentActivity {
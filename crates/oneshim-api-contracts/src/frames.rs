@@ -1,4 +1,4 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize)]
 pub struct FrameResponse {
@@ -18,3 +18,50 @@ pub struct FrameResponse {
     #[serde(default)]
     pub tag_ids: Vec<i64>,
 }
+
+/// Query for `GET /api/frames/:id/image`. Without any field the stored image
+/// is served as-is; otherwise it is downscaled to fit `width` x `height` and
+/// re-encoded as `format` (`webp`, `png` or `jpeg`; default `webp`).
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct FrameImageQuery {
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub format: Option<String>,
+}
+
+impl FrameImageQuery {
+    pub fn is_original(&self) -> bool {
+        self.width.is_none() && self.height.is_none() && self.format.is_none()
+    }
+}
+
+/// Query for `GET /api/frames/diff?from=&to=` — frame ids to compare.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FrameDiffQuery {
+    pub from: i64,
+    pub to: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FrameRegionDto {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+}
+
+/// What changed on screen between frame `from` and frame `to`.
+#[derive(Debug, Serialize)]
+pub struct FrameDiffResponse {
+    pub from_frame_id: i64,
+    pub to_frame_id: i64,
+    /// Share of pixels that changed, 0.0–1.0.
+    pub changed_pixel_ratio: f32,
+    pub changed_tiles: u32,
+    pub total_tiles: u32,
+    /// Bounding box of the changed area; absent when nothing changed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changed_region: Option<FrameRegionDto>,
+    /// Base64 PNG of the `to` frame with changed regions highlighted.
+    pub diff_image_png: String,
+}
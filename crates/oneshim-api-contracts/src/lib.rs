@@ -27,6 +27,7 @@ pub mod audit_export;
 pub mod automation;
 pub mod automation_gui;
 pub mod backup;
+pub mod bookmarks;
 pub mod bug_report;
 pub mod coaching;
 pub mod common;
@@ -52,6 +53,7 @@ pub mod reports;
 pub mod search;
 pub mod sessions;
 pub mod settings;
+pub mod share_links;
 pub mod stats;
 pub mod stream;
 pub mod suggestions;
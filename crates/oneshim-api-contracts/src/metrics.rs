@@ -18,9 +18,19 @@ pub struct HourlyQuery {
     pub hours: Option<usize>,
 }
 
+/// Bucket width of a `HourlyMetricsResponse` row.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MetricsGranularity {
+    Hour,
+    Day,
+}
+
 #[derive(Debug, Serialize)]
 pub struct HourlyMetricsResponse {
+    /// Bucket start; midnight UTC for daily rows.
     pub hour: String,
+    pub granularity: MetricsGranularity,
     pub cpu_avg: f64,
     pub cpu_max: f64,
     pub memory_avg: u64,
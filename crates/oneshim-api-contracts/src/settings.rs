@@ -14,6 +14,50 @@ pub struct StorageStats {
     pub newest_data_date: Option<String>,
 }
 
+/// Storage usage breakdown (`GET /api/storage/report`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StorageReportResponse {
+    pub db_size_bytes: u64,
+    pub frames_dir_bytes: u64,
+    pub total_size_bytes: u64,
+    /// Largest tables first.
+    pub tables: Vec<TableRowCount>,
+    /// Categories with the most image bytes first. Files shared across
+    /// categories count toward each.
+    pub app_categories: Vec<CategoryStorageUsage>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TableRowCount {
+    pub table: String,
+    pub rows: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CategoryStorageUsage {
+    pub category: String,
+    pub frame_count: u64,
+    pub bytes: u64,
+}
+
+/// Result of the on-demand SQLite integrity check (`GET /api/storage/integrity`).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StorageIntegrityReport {
+    pub healthy: bool,
+    pub integrity_errors: Vec<String>,
+    pub foreign_key_violations: Vec<ForeignKeyViolation>,
+    /// Suggested recovery steps; empty when `healthy`.
+    pub recovery_steps: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ForeignKeyViolation {
+    pub table: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rowid: Option<i64>,
+    pub parent: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppSettings {
     pub retention_days: u32,
@@ -0,0 +1,40 @@
+//! Read-only report share links.
+
+use serde::{Deserialize, Serialize};
+
+/// Body of `POST /api/share-links`. Dates are `%Y-%m-%d`; `to` defaults to
+/// `from` so a single day can be shared with just `from`.
+#[derive(Debug, Clone, Deserialize, Serialize, PartialEq, Eq)]
+pub struct CreateShareLinkRequest {
+    pub from: String,
+    #[serde(default)]
+    pub to: Option<String>,
+    /// Link lifetime; defaults to 72 hours.
+    #[serde(default)]
+    pub ttl_hours: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ShareLinkResponse {
+    /// Pass to `DELETE /api/share-links/{id}` to revoke the link.
+    pub id: String,
+    pub token: String,
+    /// Path of the shared report, relative to the dashboard origin.
+    pub path: String,
+    pub from: String,
+    pub to: String,
+    /// RFC 3339.
+    pub expires_at: String,
+}
+
+/// Query of `GET /share/v1/report`. `from`/`to` are optional and must equal
+/// the link's range when given.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, PartialEq, Eq)]
+pub struct SharedReportQuery {
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub from: Option<String>,
+    #[serde(default)]
+    pub to: Option<String>,
+}
@@ -1,3 +1,4 @@
+use oneshim_core::models::frame::FramePreview;
 use serde::Serialize;
 
 #[derive(Debug, Clone, Serialize)]
@@ -19,6 +20,10 @@ pub enum RealtimeEvent {
     Idle(IdleUpdate),
     #[serde(rename = "ai_runtime_status")]
     AiRuntimeStatus(AiRuntimeStatus),
+    #[serde(rename = "connectivity")]
+    Connectivity(ConnectivityUpdate),
+    #[serde(rename = "disk_space")]
+    DiskSpace(DiskSpaceUpdate),
     #[serde(rename = "ping")]
     Ping,
 }
@@ -40,6 +45,10 @@ pub struct FrameUpdate {
     pub window_title: String,
     pub importance: f32,
     pub trigger_type: String,
+    /// Downscaled, blurred rendition when `privacy.preview` is enabled.
+    /// Frame events never carry the full image.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preview: Option<FramePreview>,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -48,6 +57,23 @@ pub struct IdleUpdate {
     pub idle_secs: u64,
 }
 
+/// Debounced server connectivity transition (`online`, `offline`, `captive`).
+#[derive(Debug, Clone, Serialize)]
+pub struct ConnectivityUpdate {
+    /// When the new state was first observed.
+    pub timestamp: String,
+    pub from: String,
+    pub to: String,
+}
+
+/// Frame image writes were suspended (disk nearly full) or resumed.
+#[derive(Debug, Clone, Serialize)]
+pub struct DiskSpaceUpdate {
+    pub timestamp: String,
+    pub images_suspended: bool,
+    pub free_mb: u64,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -61,10 +87,33 @@ mod tests {
             window_title: "w".to_string(),
             importance: 0.5,
             trigger_type: "timer".to_string(),
+            preview: None,
         });
         let j = serde_json::to_string(&e).unwrap();
         assert!(j.contains("\"trigger_type\":\"timer\""));
         let v: serde_json::Value = serde_json::from_str(&j).unwrap();
         assert_eq!(v["data"]["trigger_type"], "timer");
+        assert!(v["data"].get("preview").is_none());
+    }
+
+    #[test]
+    fn frame_update_carries_preview_when_present() {
+        let e = RealtimeEvent::Frame(FrameUpdate {
+            id: 2,
+            timestamp: "t".to_string(),
+            app_name: "a".to_string(),
+            window_title: "w".to_string(),
+            importance: 0.5,
+            trigger_type: "timer".to_string(),
+            preview: Some(FramePreview {
+                data: "UklGRg==".to_string(),
+                format: "webp".to_string(),
+                width: 96,
+                height: 54,
+            }),
+        });
+        let v = serde_json::to_value(&e).unwrap();
+        assert_eq!(v["data"]["preview"]["width"], 96);
+        assert_eq!(v["data"]["preview"]["format"], "webp");
     }
 }
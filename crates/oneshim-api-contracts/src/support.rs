@@ -1,5 +1,6 @@
 use crate::automation::AuditEntryDto;
 use crate::settings::{AppSettings, StorageStats};
+use oneshim_core::models::capture_latency::StageLatencySummary;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +24,9 @@ pub struct DiagnosticsBundleDto {
     pub storage_stats: Option<StorageStats>,
     pub recent_audit_entries: Vec<AuditEntryDto>,
     pub recent_policy_events: Vec<AuditEntryDto>,
+    /// p50/p95 of recent capture, encode and OCR passes.
+    #[serde(default)]
+    pub capture_latency: Vec<StageLatencySummary>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -6,6 +6,8 @@ pub struct TagResponse {
     pub name: String,
     pub color: String,
     pub created_at: String,
+    /// Applied to the frame by an auto-tag rule (frame tag listings only).
+    pub auto_applied: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -30,3 +32,17 @@ pub struct BatchTagRequest {
 pub struct BatchTagResponse {
     pub tagged_count: u32,
 }
+
+/// Tags frames whose app name contains `app_pattern` (case-insensitive).
+#[derive(Debug, Serialize)]
+pub struct TagRuleResponse {
+    pub id: i64,
+    pub app_pattern: String,
+    pub tag_id: i64,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TagRuleRequest {
+    pub app_pattern: String,
+    pub tag_id: i64,
+}
@@ -27,6 +27,7 @@ fn create_policies(n: usize) -> Vec<ExecutionPolicy> {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         })
         .collect()
 }
@@ -166,6 +167,7 @@ fn bench_policy_validate_args(c: &mut Criterion) {
         allow_network: None,
         require_signed_token: false,
         confirmation: Default::default(),
+        expires_at: None,
     };
 
     let matching_args = vec![
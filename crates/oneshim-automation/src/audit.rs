@@ -5,7 +5,10 @@ use std::collections::VecDeque;
 use std::sync::Arc;
 
 // Canonical types from oneshim-core — re-exported for backward compat
-pub use oneshim_core::models::audit::{AuditEntry, AuditLevel, AuditStats, AuditStatus};
+pub use oneshim_core::models::audit::{
+    AuditEntry, AuditFilter, AuditLevel, AuditOutcome, AuditPage, AuditQueryResult, AuditStats,
+    AuditStatus,
+};
 
 /// Callback trait for persisting audit entries to durable storage.
 ///
@@ -47,6 +50,13 @@ pub trait AuditQuery: Send + Sync {
     /// Ordered by `timestamp DESC`. Empty vec if none match.
     /// Synchronous — implementations doing I/O should use `block_in_place`.
     fn entries_by_command_id(&self, command_id: &str, limit: usize) -> Vec<AuditEntry>;
+
+    /// Filtered, paged lookup over the full persisted history, newest first.
+    /// Returning `None` (the default) makes [`AuditLogger::query`] fall back
+    /// to the in-memory buffer.
+    fn query(&self, _filter: &AuditFilter, _page: AuditPage) -> Option<AuditQueryResult> {
+        None
+    }
 }
 
 pub struct AuditLogger {
@@ -145,6 +155,22 @@ impl AuditLogger {
         );
     }
 
+    pub fn log_denied_with_reason(
+        &mut self,
+        command_id: &str,
+        session_id: &str,
+        action_type: &str,
+        reason: &str,
+    ) {
+        self.push_entry(
+            command_id,
+            session_id,
+            action_type,
+            AuditStatus::Denied,
+            Some(reason.to_string()),
+        );
+    }
+
     pub fn log_failed(&mut self, command_id: &str, session_id: &str, error: &str) {
         self.push_entry(
             command_id,
@@ -165,6 +191,22 @@ impl AuditLogger {
         );
     }
 
+    /// Link the before/after frames captured around a command or step.
+    pub fn log_outcome_frames(
+        &mut self,
+        command_id: &str,
+        session_id: &str,
+        frames: &crate::outcome_capture::OutcomeFrames,
+    ) {
+        self.push_entry(
+            command_id,
+            session_id,
+            "outcome_frames",
+            AuditStatus::Completed,
+            Some(frames.audit_details()),
+        );
+    }
+
     pub fn log_start_if(
         &mut self,
         level: AuditLevel,
@@ -237,6 +279,16 @@ impl AuditLogger {
         self.buffer.iter().rev().take(limit).cloned().collect()
     }
 
+    /// One page of entries matching `filter`, newest first, with the total
+    /// match count. Served from storage when a query handle is attached —
+    /// the buffer only holds recent, not-yet-drained entries.
+    pub fn query(&self, filter: &AuditFilter, page: AuditPage) -> AuditQueryResult {
+        if let Some(result) = self.query.as_ref().and_then(|q| q.query(filter, page)) {
+            return result;
+        }
+        AuditQueryResult::paginate(self.buffer.iter().rev().cloned(), filter, page)
+    }
+
     pub fn entries_by_status(&self, status: &AuditStatus, limit: usize) -> Vec<AuditEntry> {
         self.buffer
             .iter()
@@ -455,6 +507,10 @@ impl oneshim_core::ports::audit_log::AuditLogPort for AuditLogAdapter {
             .entries_by_command_id(command_id, limit)
     }
 
+    async fn query(&self, filter: &AuditFilter, page: AuditPage) -> AuditQueryResult {
+        self.inner.read().await.query(filter, page)
+    }
+
     async fn stats(&self) -> AuditStats {
         self.inner.read().await.stats()
     }
@@ -736,6 +792,59 @@ mod tests {
         assert_eq!(stats.timeout, 0);
     }
 
+    #[test]
+    fn query_filters_by_outcome() {
+        let mut logger = AuditLogger::new(100, 10);
+        logger.log_start("cmd-1", "s", "a");
+        logger.log_complete("cmd-1", "s", "ok");
+        logger.log_failed("cmd-2", "s", "boom");
+        logger.log_denied("cmd-3", "s", "c");
+        logger.log_timeout("cmd-4", "s", 500);
+
+        let filter = AuditFilter {
+            outcomes: vec![AuditOutcome::Failed, AuditOutcome::Timeout],
+            ..Default::default()
+        };
+        let result = logger.query(
+            &filter,
+            AuditPage {
+                offset: 0,
+                limit: 10,
+            },
+        );
+
+        assert_eq!(result.total, 2);
+        let ids: Vec<_> = result
+            .entries
+            .iter()
+            .map(|e| e.command_id.as_str())
+            .collect();
+        assert_eq!(ids, ["cmd-4", "cmd-2"]);
+    }
+
+    #[test]
+    fn query_paginates_newest_first_with_total() {
+        let mut logger = AuditLogger::new(100, 10);
+        for i in 0..7 {
+            logger.log_complete(&format!("cmd-{i}"), "s", "ok");
+        }
+
+        let page = |offset| logger.query(&AuditFilter::default(), AuditPage { offset, limit: 3 });
+        let first = page(0);
+        let last = page(6);
+
+        assert_eq!(first.total, 7);
+        let ids: Vec<_> = first
+            .entries
+            .iter()
+            .map(|e| e.command_id.as_str())
+            .collect();
+        assert_eq!(ids, ["cmd-6", "cmd-5", "cmd-4"]);
+        assert_eq!(last.total, 7);
+        assert_eq!(last.entries.len(), 1);
+        assert_eq!(last.entries[0].command_id, "cmd-0");
+    }
+
     #[test]
     fn drain_batch_on_empty_logger() {
         let mut logger = AuditLogger::new(100, 10);
@@ -20,9 +20,10 @@ use crate::error::AutomationError;
 use crate::gui_interaction::{GuiInteractionError, GuiInteractionService};
 use crate::intent_planner::IntentPlanner;
 use crate::intent_resolver::IntentExecutor;
+use crate::outcome_capture::{OutcomeCapture, OutcomeFrames};
 use crate::policy::PolicyClient;
 use gate::CommandExecutionGate;
-use oneshim_core::config::SandboxConfig;
+use oneshim_core::config::{SandboxConfig, ScheduleConfig};
 use oneshim_core::models::automation::PendingConfirmation;
 use oneshim_core::ports::element_finder::ElementFinder;
 use oneshim_core::ports::focus_probe::FocusProbe;
@@ -40,10 +41,14 @@ pub struct AutomationController {
     pub(super) action_dispatcher: Arc<dyn AutomationActionDispatcher>,
     pub(super) base_sandbox_config: SandboxConfig,
     pub(super) enabled: bool,
+    /// Window in which commands and workflows may run (`automation.allowed_hours`).
+    pub(super) allowed_hours: ScheduleConfig,
     pub(super) intent_executor: Option<Arc<IntentExecutor>>,
     pub(super) intent_planner: Option<Arc<dyn IntentPlanner>>,
     pub(super) scene_finder: Option<Arc<dyn ElementFinder>>,
     pub(super) gui_service: Option<Arc<GuiInteractionService>>,
+    /// Before/after screenshots around each command and workflow step.
+    pub(super) outcome_capture: Option<Arc<dyn OutcomeCapture>>,
     /// Health flag: `true` after a successful command, `false` on failure.
     /// Read by the health-check loop. `None` when no caller has wired a flag.
     pub(super) last_command_ok: Option<Arc<AtomicBool>>,
@@ -79,10 +84,12 @@ impl AutomationController {
             action_dispatcher: Arc::new(SandboxActionDispatcher::new(sandbox)),
             base_sandbox_config: sandbox_config,
             enabled: false, // disabled by default
+            allowed_hours: ScheduleConfig::default(),
             intent_executor: None,
             intent_planner: None,
             scene_finder: None,
             gui_service: None,
+            outcome_capture: None,
             last_command_ok: None,
             pending_confirmations: Arc::new(tokio::sync::Mutex::new(HashMap::new())),
             on_confirmation_needed: None,
@@ -150,6 +157,10 @@ impl AutomationController {
         self.enabled = enabled;
     }
 
+    pub fn set_allowed_hours(&mut self, allowed_hours: ScheduleConfig) {
+        self.allowed_hours = allowed_hours;
+    }
+
     pub fn set_intent_executor(&mut self, executor: Arc<IntentExecutor>) {
         self.intent_executor = Some(executor);
     }
@@ -166,6 +177,41 @@ impl AutomationController {
         self.scene_finder.as_ref()
     }
 
+    pub fn set_outcome_capture(&mut self, capture: Arc<dyn OutcomeCapture>) {
+        self.outcome_capture = Some(capture);
+    }
+
+    /// Best-effort frame capture around a step. Failures are logged and
+    /// never fail the automation.
+    pub(super) async fn capture_outcome_frame(&self, command_id: &str, phase: &str) -> Option<i64> {
+        let capture = self.outcome_capture.as_ref()?;
+        match capture
+            .capture_frame(&format!("{command_id}:{phase}"))
+            .await
+        {
+            Ok(frame_id) => Some(frame_id),
+            Err(error) => {
+                tracing::warn!(command_id, phase, error = %error, "automation outcome capture failed");
+                None
+            }
+        }
+    }
+
+    pub(super) async fn log_outcome_frames(
+        &self,
+        command_id: &str,
+        session_id: &str,
+        frames: OutcomeFrames,
+    ) {
+        if frames.is_empty() {
+            return;
+        }
+        self.audit_logger
+            .write()
+            .await
+            .log_outcome_frames(command_id, session_id, &frames);
+    }
+
     pub fn set_action_dispatcher(&mut self, dispatcher: Arc<dyn AutomationActionDispatcher>) {
         self.action_dispatcher = dispatcher;
     }
@@ -211,6 +257,42 @@ impl AutomationController {
         }
     }
 
+    /// Returns a denial reason when `now` falls outside `allowed_hours`.
+    pub(super) fn allowed_hours_denial<Tz: chrono::TimeZone>(
+        &self,
+        now: chrono::DateTime<Tz>,
+    ) -> Option<String> {
+        if self.allowed_hours.is_active_at(now) {
+            return None;
+        }
+        Some(format!(
+            "outside allowed automation hours ({:02}:00-{:02}:00 on {:?})",
+            self.allowed_hours.active_start_hour,
+            self.allowed_hours.active_end_hour,
+            self.allowed_hours.active_days
+        ))
+    }
+
+    /// Audits and rejects execution outside the configured allowed hours.
+    pub(super) async fn ensure_within_allowed_hours(
+        &self,
+        command_id: &str,
+        session_id: &str,
+        action_type: &str,
+    ) -> Result<(), AutomationError> {
+        let Some(reason) = self.allowed_hours_denial(chrono::Local::now()) else {
+            return Ok(());
+        };
+        tracing::warn!(command_id, reason = %reason, "automation denied by allowed hours");
+        self.audit_logger.write().await.log_denied_with_reason(
+            command_id,
+            session_id,
+            action_type,
+            &reason,
+        );
+        Err(AutomationError::PolicyDenied(reason))
+    }
+
     pub(super) fn require_intent_executor(&self) -> Result<&Arc<IntentExecutor>, AutomationError> {
         self.intent_executor.as_ref().ok_or_else(|| {
             // Iter-100: "not configured" = Missing semantic.
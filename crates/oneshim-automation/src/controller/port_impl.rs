@@ -35,6 +35,7 @@ fn policy_to_dto(p: &ExecutionPolicy) -> ExecutionPolicyDto {
         allow_network: p.allow_network,
         require_signed_token: p.require_signed_token,
         confirmation: format!("{:?}", p.confirmation),
+        expires_at: p.expires_at,
     }
 }
 
@@ -69,6 +70,7 @@ fn dto_to_policy(d: &ExecutionPolicyDto) -> ExecutionPolicy {
         allow_network: d.allow_network,
         require_signed_token: d.require_signed_token,
         confirmation,
+        expires_at: d.expires_at,
     }
 }
 
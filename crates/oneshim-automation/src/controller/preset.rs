@@ -3,6 +3,7 @@ use std::time::Instant;
 
 use crate::controller::gate::WORKFLOW_STEP_POLICY_TOKEN;
 use crate::error::AutomationError;
+use crate::outcome_capture::OutcomeFrames;
 use crate::policy::AuditLevel;
 #[cfg(test)]
 use oneshim_core::config::SandboxConfig;
@@ -17,6 +18,8 @@ impl AutomationController {
         preset: &WorkflowPreset,
     ) -> Result<WorkflowResult, AutomationError> {
         self.ensure_enabled()?;
+        self.ensure_within_allowed_hours(&preset.id, &preset.id, "run_workflow")
+            .await?;
 
         let total_steps = preset.steps.len();
         let mut step_results = Vec::with_capacity(total_steps);
@@ -55,9 +58,16 @@ impl AutomationController {
                 );
             }
 
+            let before_frame_id = self.capture_outcome_frame(&step_cmd_id, "before").await;
             let step_start = Instant::now();
             let result = executor.execute(&intent_command.intent).await;
             let step_elapsed = step_start.elapsed().as_millis() as u64;
+            let frames = OutcomeFrames {
+                before: before_frame_id,
+                after: self.capture_outcome_frame(&step_cmd_id, "after").await,
+            };
+            self.log_outcome_frames(&step_cmd_id, &preset.id, frames)
+                .await;
 
             match result {
                 Ok(intent_result) => {
@@ -86,6 +96,8 @@ impl AutomationController {
                         } else {
                             intent_result.error.clone()
                         },
+                        before_frame_id: frames.before,
+                        after_frame_id: frames.after,
                     });
 
                     if !step_success {
@@ -118,6 +130,8 @@ impl AutomationController {
                         success: false,
                         elapsed_ms: step_elapsed,
                         error: Some(e.to_string()),
+                        before_frame_id: frames.before,
+                        after_frame_id: frames.after,
                     });
 
                     all_success = false;
@@ -181,6 +195,17 @@ impl AutomationController {
         cmd: &AutomationCommand,
     ) -> Result<CommandResult, AutomationError> {
         self.ensure_enabled()?;
+        if self
+            .ensure_within_allowed_hours(
+                &cmd.command_id,
+                &cmd.session_id,
+                &format!("{:?}", cmd.action),
+            )
+            .await
+            .is_err()
+        {
+            return Ok(CommandResult::Denied);
+        }
 
         // Check confirmation requirement from the policy before execution.
         if let Some(policy) = self
@@ -209,7 +234,14 @@ impl AutomationController {
             }
         }
 
+        let before_frame_id = self.capture_outcome_frame(&cmd.command_id, "before").await;
         let result = self.command_execution_gate().execute(cmd).await;
+        let frames = OutcomeFrames {
+            before: before_frame_id,
+            after: self.capture_outcome_frame(&cmd.command_id, "after").await,
+        };
+        self.log_outcome_frames(&cmd.command_id, &cmd.session_id, frames)
+            .await;
         if let Some(ref flag) = self.last_command_ok {
             match &result {
                 Ok(CommandResult::Success) => flag.store(true, Ordering::Relaxed),
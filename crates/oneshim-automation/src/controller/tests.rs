@@ -55,6 +55,7 @@ fn make_policy(audit: AuditLevel, timeout: u64) -> ExecutionPolicy {
         allow_network: None,
         require_signed_token: false,
         confirmation: oneshim_core::config::ConfirmationRequirement::Auto,
+        expires_at: None,
     }
 }
 
@@ -333,6 +334,8 @@ fn workflow_result_serde_roundtrip() {
                 success: true,
                 elapsed_ms: 50,
                 error: None,
+                before_frame_id: None,
+                after_frame_id: None,
             },
             WorkflowStepResult {
                 step_name: "step2".to_string(),
@@ -340,6 +343,8 @@ fn workflow_result_serde_roundtrip() {
                 success: true,
                 elapsed_ms: 100,
                 error: None,
+                before_frame_id: None,
+                after_frame_id: None,
             },
         ],
         message: "success".to_string(),
@@ -918,6 +923,8 @@ async fn workflow_step_result_error_field() {
         success: false,
         elapsed_ms: 50,
         error: Some("Element not found".to_string()),
+        before_frame_id: None,
+        after_frame_id: None,
     };
     let json = serde_json::to_string(&result).unwrap();
     assert!(json.contains("Element not found"));
@@ -955,3 +962,276 @@ fn gui_action_timeout_less_than_total() {
         "Per-action timeout must be less than total execution timeout"
     );
 }
+
+// ── Allowed automation hours ───────────────────────────────────────
+
+/// A three-hour window centred on the current local hour, every day, so the
+/// test stays inside it even if the clock ticks over an hour boundary.
+fn window_around_now() -> ScheduleConfig {
+    use chrono::Timelike;
+    let hour = chrono::Local::now().hour() as u8;
+    ScheduleConfig {
+        active_hours_enabled: true,
+        active_start_hour: (hour + 23) % 24,
+        active_end_hour: (hour + 2) % 24,
+        active_days: vec![
+            oneshim_core::config::Weekday::Mon,
+            oneshim_core::config::Weekday::Tue,
+            oneshim_core::config::Weekday::Wed,
+            oneshim_core::config::Weekday::Thu,
+            oneshim_core::config::Weekday::Fri,
+            oneshim_core::config::Weekday::Sat,
+            oneshim_core::config::Weekday::Sun,
+        ],
+        ..ScheduleConfig::default()
+    }
+}
+
+fn never_allowed() -> ScheduleConfig {
+    ScheduleConfig {
+        active_hours_enabled: true,
+        active_days: vec![],
+        ..ScheduleConfig::default()
+    }
+}
+
+fn key_type_command(command_id: &str) -> AutomationCommand {
+    AutomationCommand {
+        command_id: command_id.to_string(),
+        session_id: "sess-hours".to_string(),
+        action: AutomationAction::KeyType {
+            text: "hello".to_string(),
+        },
+        timeout_ms: None,
+        policy_token: "test-pol:nonce_0100".to_string(),
+    }
+}
+
+#[test]
+fn allowed_hours_denial_reports_reason_outside_window() {
+    use chrono::TimeZone;
+    let mut controller = make_controller();
+    controller.set_allowed_hours(ScheduleConfig {
+        active_hours_enabled: true,
+        ..ScheduleConfig::default()
+    });
+    let utc = chrono::FixedOffset::east_opt(0).unwrap();
+    // 2024-11-11 is a Monday.
+    let monday_noon = utc.with_ymd_and_hms(2024, 11, 11, 12, 0, 0).unwrap();
+    let monday_night = utc.with_ymd_and_hms(2024, 11, 11, 22, 0, 0).unwrap();
+
+    assert!(controller.allowed_hours_denial(monday_noon).is_none());
+    let reason = controller.allowed_hours_denial(monday_night).unwrap();
+    assert!(reason.contains("09:00-18:00"), "reason: {reason}");
+}
+
+#[tokio::test]
+async fn execute_command_outside_allowed_hours_is_denied_and_audited() {
+    let policy = make_policy(AuditLevel::Basic, 5000);
+    let (mut controller, policy_client, audit_logger) = make_controller_with_policy(policy.clone());
+    controller.set_enabled(true);
+    controller.set_allowed_hours(never_allowed());
+    policy_client.update_policies(vec![policy]).await;
+
+    let result = controller
+        .execute_command(&key_type_command("cmd-after-hours"))
+        .await
+        .unwrap();
+    assert!(matches!(result, CommandResult::Denied));
+
+    let logger = audit_logger.read().await;
+    let entries = logger.entries_by_command_id("cmd-after-hours", 10);
+    assert_eq!(entries.len(), 1);
+    assert!(matches!(
+        entries[0].status,
+        crate::audit::AuditStatus::Denied
+    ));
+    assert!(entries[0]
+        .details
+        .as_deref()
+        .unwrap_or_default()
+        .contains("outside allowed automation hours"));
+}
+
+#[tokio::test]
+async fn execute_command_inside_allowed_hours_proceeds() {
+    let policy = make_policy(AuditLevel::Basic, 5000);
+    let (mut controller, policy_client, _) = make_controller_with_policy(policy.clone());
+    controller.set_enabled(true);
+    controller.set_allowed_hours(window_around_now());
+    policy_client.update_policies(vec![policy]).await;
+
+    let result = controller
+        .execute_command(&key_type_command("cmd-in-hours"))
+        .await
+        .unwrap();
+    assert!(matches!(result, CommandResult::Success));
+}
+
+#[tokio::test]
+async fn run_workflow_outside_allowed_hours_is_denied() {
+    let mut controller = make_controller();
+    controller.set_enabled(true);
+    controller.set_allowed_hours(never_allowed());
+    let preset = WorkflowPreset {
+        id: "after-hours".to_string(),
+        name: "after hours".to_string(),
+        description: String::new(),
+        category: PresetCategory::Productivity,
+        steps: vec![],
+        builtin: true,
+        platform: None,
+        ai_profile_id: None,
+    };
+
+    let err = controller.run_workflow(&preset).await.unwrap_err();
+    assert!(matches!(err, AutomationError::PolicyDenied(_)));
+}
+
+struct SequenceCapture {
+    next_id: std::sync::atomic::AtomicI64,
+    labels: std::sync::Mutex<Vec<String>>,
+    fail: bool,
+}
+
+impl SequenceCapture {
+    fn new(fail: bool) -> Arc<Self> {
+        Arc::new(Self {
+            next_id: std::sync::atomic::AtomicI64::new(100),
+            labels: std::sync::Mutex::new(Vec::new()),
+            fail,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl crate::outcome_capture::OutcomeCapture for SequenceCapture {
+    async fn capture_frame(&self, label: &str) -> Result<i64, CoreError> {
+        self.labels.lock().unwrap().push(label.to_string());
+        if self.fail {
+            return Err(CoreError::Internal {
+                code: oneshim_core::error_codes::InternalCode::Generic,
+                message: "screen unavailable".to_string(),
+            });
+        }
+        Ok(self
+            .next_id
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed))
+    }
+}
+
+fn hotkey_workflow_controller() -> (
+    AutomationController,
+    Arc<RwLock<AuditLogger>>,
+    WorkflowPreset,
+) {
+    use crate::input_driver::{NoOpElementFinder, NoOpInputDriver};
+    use crate::intent_resolver::{IntentExecutor, IntentResolver};
+
+    let (mut controller, _, audit_logger) =
+        make_controller_with_policy(make_policy(AuditLevel::Basic, 0));
+    controller.set_enabled(true);
+    let resolver = IntentResolver::new(
+        Arc::new(NoOpElementFinder),
+        Arc::new(NoOpInputDriver),
+        IntentConfig::default(),
+    );
+    controller.set_intent_executor(Arc::new(IntentExecutor::new(
+        resolver,
+        IntentConfig::default(),
+    )));
+    let step = |name: &str| WorkflowStep {
+        name: name.to_string(),
+        intent: AutomationIntent::ExecuteHotkey {
+            keys: vec!["Ctrl".to_string(), "S".to_string()],
+        },
+        delay_ms: 0,
+        stop_on_failure: true,
+    };
+    let preset = WorkflowPreset {
+        id: "save-twice".to_string(),
+        name: "save twice".to_string(),
+        description: "test".to_string(),
+        category: PresetCategory::Productivity,
+        steps: vec![step("first"), step("second")],
+        builtin: false,
+        platform: None,
+        ai_profile_id: None,
+    };
+    (controller, audit_logger, preset)
+}
+
+#[tokio::test]
+async fn workflow_steps_reference_before_and_after_frames() {
+    let (mut controller, audit_logger, preset) = hotkey_workflow_controller();
+    let capture = SequenceCapture::new(false);
+    controller.set_outcome_capture(capture.clone());
+
+    let result = controller.run_workflow(&preset).await.unwrap();
+
+    assert!(result.success);
+    let ids: Vec<_> = result
+        .step_results
+        .iter()
+        .map(|step| (step.before_frame_id, step.after_frame_id))
+        .collect();
+    assert_eq!(ids, [(Some(100), Some(101)), (Some(102), Some(103))]);
+    assert_eq!(
+        capture.labels.lock().unwrap()[..2],
+        ["save-twice:step-0:before", "save-twice:step-0:after"]
+    );
+
+    let logger = audit_logger.read().await;
+    let linked = logger.entries_by_command_id("save-twice:step-1", 10);
+    assert!(linked
+        .iter()
+        .any(|entry| entry.action_type == "outcome_frames"
+            && entry.details.as_deref() == Some("before_frame_id=102, after_frame_id=103")));
+}
+
+#[tokio::test]
+async fn failed_outcome_capture_does_not_fail_workflow() {
+    let (mut controller, audit_logger, preset) = hotkey_workflow_controller();
+    controller.set_outcome_capture(SequenceCapture::new(true));
+
+    let result = controller.run_workflow(&preset).await.unwrap();
+
+    assert!(result.success);
+    assert!(result
+        .step_results
+        .iter()
+        .all(|step| step.before_frame_id.is_none() && step.after_frame_id.is_none()));
+    let logger = audit_logger.read().await;
+    assert!(logger
+        .recent_entries(50)
+        .iter()
+        .all(|entry| entry.action_type != "outcome_frames"));
+}
+
+#[tokio::test]
+async fn command_outcome_frames_are_linked_in_audit() {
+    let policy = make_policy(AuditLevel::Basic, 0);
+    let (mut controller, policy_client, audit_logger) = make_controller_with_policy(policy.clone());
+    controller.set_enabled(true);
+    controller.set_outcome_capture(SequenceCapture::new(false));
+    policy_client.update_policies(vec![policy]).await;
+
+    let cmd = AutomationCommand {
+        command_id: "cmd-shot".to_string(),
+        session_id: "sess-1".to_string(),
+        action: AutomationAction::KeyPress {
+            key: "a".to_string(),
+        },
+        timeout_ms: None,
+        policy_token: "test-pol:nonce_0300".to_string(),
+    };
+    let result = controller.execute_command(&cmd).await.unwrap();
+
+    assert!(matches!(result, CommandResult::Success));
+    let logger = audit_logger.read().await;
+    let linked = logger.entries_by_command_id("cmd-shot", 10);
+    assert!(linked
+        .iter()
+        .any(|entry| entry.action_type == "outcome_frames"
+            && entry.details.as_deref() == Some("before_frame_id=100, after_frame_id=101")));
+}
@@ -1,6 +1,7 @@
-use std::sync::Arc;
-use std::time::Instant;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 
+use rand::RngExt;
 use tracing::{debug, info, warn};
 
 use crate::error::AutomationError;
@@ -14,8 +15,17 @@ pub struct IntentResolver {
     element_finder: Arc<dyn ElementFinder>,
     input_driver: Arc<dyn InputDriver>,
     config: IntentConfig,
+    /// Last pointer position this resolver moved to, the start point for
+    /// paced moves.
+    last_pointer: Mutex<Option<(i32, i32)>>,
 }
 
+/// Interval between intermediate pointer positions during a paced move.
+const MOUSE_MOVE_STEP_MS: u64 = 16;
+
+/// Jitter spread applied to paced timings, in percent of the base delay.
+const HUMANIZE_JITTER_PERCENT: u64 = 30;
+
 impl IntentResolver {
     pub fn new(
         element_finder: Arc<dyn ElementFinder>,
@@ -26,7 +36,78 @@ impl IntentResolver {
             element_finder,
             input_driver,
             config,
+            last_pointer: Mutex::new(None),
+        }
+    }
+
+    /// Sleep for `base_ms`, randomized when humanization is enabled.
+    async fn pause(&self, base_ms: u64) {
+        let ms = if self.config.humanize_jitter && base_ms > 0 {
+            let spread = base_ms * HUMANIZE_JITTER_PERCENT / 100;
+            rand::rng().random_range(base_ms - spread..=base_ms + spread)
+        } else {
+            base_ms
+        };
+        if ms > 0 {
+            tokio::time::sleep(Duration::from_millis(ms)).await;
+        }
+    }
+
+    /// Type `text`, one character per `keystroke_delay_ms` when configured.
+    async fn type_text(&self, text: &str) -> Result<(), AutomationError> {
+        if self.config.keystroke_delay_ms == 0 {
+            self.input_driver.type_text(text).await?;
+            return Ok(());
+        }
+        let mut buf = [0u8; 4];
+        for (i, ch) in text.chars().enumerate() {
+            if i > 0 {
+                self.pause(self.config.keystroke_delay_ms).await;
+            }
+            self.input_driver
+                .type_text(ch.encode_utf8(&mut buf))
+                .await?;
+        }
+        Ok(())
+    }
+
+    /// Move the pointer to `(x, y)` over `mouse_move_duration_ms`. Moves are
+    /// interpolated from the last known position; without one the pointer
+    /// jumps and then dwells for the duration.
+    async fn move_pointer(&self, x: i32, y: i32) -> Result<(), AutomationError> {
+        let duration_ms = self.config.mouse_move_duration_ms;
+        let from = self
+            .last_pointer
+            .lock()
+            .map_err(|e| AutomationError::Internal(format!("pointer state poisoned: {e}")))?
+            .replace((x, y));
+
+        match from {
+            Some((fx, fy)) if duration_ms > 0 => {
+                let steps = (duration_ms / MOUSE_MOVE_STEP_MS).max(1);
+                for step in 1..=steps {
+                    let t = step as f64 / steps as f64;
+                    let sx = fx + ((x - fx) as f64 * t).round() as i32;
+                    let sy = fy + ((y - fy) as f64 * t).round() as i32;
+                    self.input_driver.mouse_move(sx, sy).await?;
+                    self.pause(duration_ms / steps).await;
+                }
+            }
+            _ => {
+                self.input_driver.mouse_move(x, y).await?;
+                self.pause(duration_ms).await;
+            }
+        }
+        Ok(())
+    }
+
+    /// Click at `(x, y)`, moving there first when pointer pacing is on.
+    async fn click_at(&self, button: &str, x: i32, y: i32) -> Result<(), AutomationError> {
+        if self.config.mouse_move_duration_ms > 0 {
+            self.move_pointer(x, y).await?;
         }
+        self.input_driver.mouse_click(button, x, y).await?;
+        Ok(())
     }
 
     pub async fn resolve_and_execute(
@@ -59,7 +140,7 @@ impl IntentResolver {
 
                 let (cx, cy) = best.bounds.center();
                 debug!(text = %best.text, x = cx, y = cy, confidence = best.confidence, "element click");
-                self.input_driver.mouse_click(button, cx, cy).await?;
+                self.click_at(button, cx, cy).await?;
 
                 Ok((true, Some(best)))
             }
@@ -81,11 +162,11 @@ impl IntentResolver {
                 if let Some(elem) = &best {
                     let (cx, cy) = elem.bounds.center();
                     debug!(text = %elem.text, x = cx, y = cy, "click");
-                    self.input_driver.mouse_click("left", cx, cy).await?;
+                    self.click_at("left", cx, cy).await?;
                 }
 
                 debug!(text_len = text.len(), "text");
-                self.input_driver.type_text(text).await?;
+                self.type_text(text).await?;
 
                 Ok((true, best))
             }
@@ -140,17 +221,17 @@ impl IntentResolver {
                 debug!(?action, "execution");
                 match action {
                     oneshim_core::models::automation::AutomationAction::MouseMove { x, y } => {
-                        self.input_driver.mouse_move(*x, *y).await?;
+                        self.move_pointer(*x, *y).await?;
                     }
                     oneshim_core::models::automation::AutomationAction::MouseClick {
                         button,
                         x,
                         y,
                     } => {
-                        self.input_driver.mouse_click(button, *x, *y).await?;
+                        self.click_at(button, *x, *y).await?;
                     }
                     oneshim_core::models::automation::AutomationAction::KeyType { text } => {
-                        self.input_driver.type_text(text).await?;
+                        self.type_text(text).await?;
                     }
                     oneshim_core::models::automation::AutomationAction::KeyPress { key } => {
                         self.input_driver.key_press(key).await?;
@@ -585,4 +666,149 @@ mod tests {
         assert!(result.verification.is_none());
         assert_eq!(result.retry_count, 0);
     }
+
+    /// Records every driver call with the instant it happened.
+    #[derive(Default)]
+    struct TimingInputDriver {
+        calls: Mutex<Vec<(String, Instant)>>,
+    }
+
+    impl TimingInputDriver {
+        fn record(&self, call: String) {
+            self.calls.lock().unwrap().push((call, Instant::now()));
+        }
+
+        fn calls(&self) -> Vec<(String, Instant)> {
+            self.calls.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait::async_trait]
+    impl InputDriver for TimingInputDriver {
+        async fn mouse_move(&self, x: i32, y: i32) -> Result<(), CoreError> {
+            self.record(format!("move {x},{y}"));
+            Ok(())
+        }
+        async fn mouse_click(&self, button: &str, x: i32, y: i32) -> Result<(), CoreError> {
+            self.record(format!("click {button} {x},{y}"));
+            Ok(())
+        }
+        async fn type_text(&self, text: &str) -> Result<(), CoreError> {
+            self.record(format!("type {text}"));
+            Ok(())
+        }
+        async fn key_press(&self, _key: &str) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn key_release(&self, _key: &str) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn hotkey(&self, _keys: &[String]) -> Result<(), CoreError> {
+            Ok(())
+        }
+        fn platform(&self) -> &str {
+            "timing"
+        }
+    }
+
+    fn timing_resolver(config: IntentConfig) -> (IntentResolver, Arc<TimingInputDriver>) {
+        let driver = Arc::new(TimingInputDriver::default());
+        let resolver = IntentResolver::new(Arc::new(EmptyElementFinder), driver.clone(), config);
+        (resolver, driver)
+    }
+
+    fn type_action(text: &str) -> AutomationIntent {
+        AutomationIntent::Raw(AutomationAction::KeyType {
+            text: text.to_string(),
+        })
+    }
+
+    #[tokio::test]
+    async fn default_config_types_text_at_once() {
+        let (resolver, driver) = timing_resolver(IntentConfig::default());
+        resolver
+            .resolve_and_execute(&type_action("hello"))
+            .await
+            .unwrap();
+
+        let calls = driver.calls();
+        assert_eq!(calls.len(), 1);
+        assert_eq!(calls[0].0, "type hello");
+    }
+
+    #[tokio::test]
+    async fn keystroke_delay_paces_each_character() {
+        let (resolver, driver) = timing_resolver(IntentConfig {
+            keystroke_delay_ms: 20,
+            ..IntentConfig::default()
+        });
+        let start = Instant::now();
+        resolver
+            .resolve_and_execute(&type_action("abcd"))
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(60));
+        let calls = driver.calls();
+        let typed: Vec<&str> = calls.iter().map(|(call, _)| call.as_str()).collect();
+        assert_eq!(typed, ["type a", "type b", "type c", "type d"]);
+        for pair in calls.windows(2) {
+            assert!(pair[1].1 - pair[0].1 >= Duration::from_millis(20));
+        }
+    }
+
+    #[tokio::test]
+    async fn jittered_keystrokes_stay_above_lower_bound() {
+        let (resolver, driver) = timing_resolver(IntentConfig {
+            keystroke_delay_ms: 20,
+            humanize_jitter: true,
+            ..IntentConfig::default()
+        });
+        let start = Instant::now();
+        resolver
+            .resolve_and_execute(&type_action("abcd"))
+            .await
+            .unwrap();
+
+        // 3 gaps of at least 20ms - 30%.
+        assert!(start.elapsed() >= Duration::from_millis(42));
+        assert_eq!(driver.calls().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn mouse_move_duration_interpolates_from_last_position() {
+        let (resolver, driver) = timing_resolver(IntentConfig {
+            mouse_move_duration_ms: 48,
+            ..IntentConfig::default()
+        });
+        resolver
+            .resolve_and_execute(&AutomationIntent::Raw(AutomationAction::MouseMove {
+                x: 0,
+                y: 0,
+            }))
+            .await
+            .unwrap();
+        let start = Instant::now();
+        resolver
+            .resolve_and_execute(&AutomationIntent::Raw(AutomationAction::MouseClick {
+                button: "left".to_string(),
+                x: 30,
+                y: 60,
+            }))
+            .await
+            .unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(48));
+        let calls: Vec<String> = driver.calls().into_iter().map(|(call, _)| call).collect();
+        assert_eq!(
+            calls,
+            [
+                "move 0,0",
+                "move 10,20",
+                "move 20,40",
+                "move 30,60",
+                "click left 30,60"
+            ]
+        );
+    }
 }
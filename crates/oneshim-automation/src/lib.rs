@@ -36,6 +36,7 @@ pub mod input_driver;
 pub mod intent_planner;
 pub mod intent_resolver;
 pub mod local_llm;
+pub mod outcome_capture;
 pub mod overlay;
 pub mod policy;
 pub mod presets;
@@ -0,0 +1,42 @@
+//! Before/after screenshots around automation steps.
+//!
+//! When `automation.capture_step_frames` is on, the controller captures a
+//! frame immediately before and after each command and workflow step so the
+//! result can be audited visually. Capture is best-effort: a failure is
+//! logged and the step runs (and is reported) without that frame.
+
+use async_trait::async_trait;
+use oneshim_core::error::CoreError;
+
+/// Captures and stores a single screen frame, returning its frame id.
+///
+/// Implemented by the binary crate on top of the vision processor and frame
+/// storage — `oneshim-automation` does not depend on either directly.
+#[async_trait]
+pub trait OutcomeCapture: Send + Sync {
+    /// `label` identifies the step, e.g. `"preset:step-0:before"`.
+    async fn capture_frame(&self, label: &str) -> Result<i64, CoreError>;
+}
+
+/// Frame ids captured around one command or step.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutcomeFrames {
+    pub before: Option<i64>,
+    pub after: Option<i64>,
+}
+
+impl OutcomeFrames {
+    pub fn is_empty(&self) -> bool {
+        self.before.is_none() && self.after.is_none()
+    }
+
+    /// Audit `details` text linking the frames.
+    pub fn audit_details(&self) -> String {
+        let id = |frame: Option<i64>| frame.map_or_else(|| "none".to_string(), |id| id.to_string());
+        format!(
+            "before_frame_id={}, after_frame_id={}",
+            id(self.before),
+            id(self.after)
+        )
+    }
+}
@@ -2,7 +2,9 @@ mod models;
 mod token;
 
 // ── Public re-exports (external API) ────────────────────────────────
-pub use models::{AuditLevel, ExecutionPolicy, PolicyCache, ProcessOutput};
+pub use models::{
+    AuditLevel, ExecutionPolicy, PolicyCache, ProcessOutput, DEFAULT_POLICY_CACHE_TTL_SECS,
+};
 
 use chrono::Utc;
 use std::collections::{HashMap, HashSet};
@@ -33,6 +35,13 @@ impl PolicyClient {
         }
     }
 
+    /// Override how long a policy set stays valid after the last refresh.
+    /// `0` disables caching: every command token is rejected.
+    pub fn with_cache_ttl(mut self, ttl_seconds: u64) -> Self {
+        self.policy_cache.get_mut().ttl_seconds = ttl_seconds;
+        self
+    }
+
     pub async fn update_policies(&self, policies: Vec<ExecutionPolicy>) {
         let mut cache = self.policy_cache.write().await;
         let mut allowed = self.allowed_processes.write().await;
@@ -166,10 +175,11 @@ impl PolicyClient {
     pub async fn issue_command_token(&self, policy_id: &str) -> Result<String, AutomationError> {
         let policy = {
             let cache = self.policy_cache.read().await;
+            let now = Utc::now();
             cache
                 .policies
                 .iter()
-                .find(|p| p.policy_id == policy_id)
+                .find(|p| p.policy_id == policy_id && !p.is_expired_at(now))
                 .cloned()
         }
         .ok_or_else(|| AutomationError::PolicyDenied(format!("Unknown policy ID: {policy_id}")))?;
@@ -185,10 +195,11 @@ impl PolicyClient {
     ) -> Result<String, AutomationError> {
         let policy = {
             let cache = self.policy_cache.read().await;
+            let now = Utc::now();
             cache
                 .policies
                 .iter()
-                .find(|p| p.policy_id == policy_id)
+                .find(|p| p.policy_id == policy_id && !p.is_expired_at(now))
                 .cloned()
         }
         .ok_or_else(|| AutomationError::PolicyDenied(format!("Unknown policy ID: {policy_id}")))?;
@@ -200,10 +211,11 @@ impl PolicyClient {
 
     pub async fn get_policy_for_process(&self, process_name: &str) -> Option<ExecutionPolicy> {
         let cache = self.policy_cache.read().await;
+        let now = Utc::now();
         cache
             .policies
             .iter()
-            .find(|p| p.process_name == process_name)
+            .find(|p| p.process_name == process_name && !p.is_expired_at(now))
             .cloned()
     }
 
@@ -217,10 +229,11 @@ impl PolicyClient {
         let policy_id = parse_policy_token(policy_token)
             .map(|token| token.policy_id)
             .unwrap_or(policy_token);
+        let now = Utc::now();
         cache
             .policies
             .iter()
-            .find(|p| p.policy_id == policy_id)
+            .find(|p| p.policy_id == policy_id && !p.is_expired_at(now))
             .cloned()
     }
 
@@ -254,6 +267,30 @@ impl PolicyClient {
         }
     }
 
+    /// Revoke a cached policy: remove it and forget any tokens already
+    /// validated against it. Returns `true` if the policy was cached.
+    pub async fn revoke(&self, policy_id: &str) -> bool {
+        if !self.remove_policy(policy_id).await {
+            return false;
+        }
+        let mut validated = self.validated_tokens.write().await;
+        validated.retain(|token, _| {
+            parse_policy_token(token).map_or(true, |parsed| parsed.policy_id != policy_id)
+        });
+        true
+    }
+
+    /// Drop every cached policy and validated token. Commands are denied
+    /// until the next `update_policies`.
+    pub async fn clear(&self) {
+        let mut cache = self.policy_cache.write().await;
+        let mut allowed = self.allowed_processes.write().await;
+        let mut validated = self.validated_tokens.write().await;
+        cache.policies.clear();
+        allowed.clear();
+        validated.clear();
+    }
+
     /// Return a snapshot of all execution policies.
     pub async fn list_policies(&self) -> Vec<ExecutionPolicy> {
         self.policy_cache.read().await.policies.clone()
@@ -314,6 +351,7 @@ mod tests {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         };
         let json = serde_json::to_string(&policy).unwrap();
         let deser: ExecutionPolicy = serde_json::from_str(&json).unwrap();
@@ -340,6 +378,7 @@ mod tests {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         };
         assert!(PolicyClient::validate_args(
             &policy,
@@ -362,6 +401,7 @@ mod tests {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         };
         assert!(PolicyClient::validate_args(
             &policy,
@@ -386,6 +426,7 @@ mod tests {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         }];
 
         client.update_policies(policies).await;
@@ -409,6 +450,7 @@ mod tests {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         }];
         client.update_policies(policies).await;
 
@@ -438,6 +480,7 @@ mod tests {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         }
     }
 
@@ -695,6 +738,86 @@ mod tests {
         assert_eq!(list[0].process_name, "cat");
     }
 
+    #[tokio::test]
+    async fn expired_policy_is_not_used() {
+        let client = PolicyClient::new();
+        let mut policy = make_policy("pol-old");
+        policy.expires_at = Some(Utc::now() - chrono::Duration::seconds(1));
+        client.update_policies(vec![policy]).await;
+
+        assert!(client
+            .get_policy_for_token("pol-old:nonce_1234")
+            .await
+            .is_none());
+        assert!(client.get_policy_for_process("git").await.is_none());
+        assert!(!client
+            .validate_command(&make_command("pol-old:nonce_1234"))
+            .await
+            .unwrap());
+        assert!(matches!(
+            client.issue_command_token("pol-old").await,
+            Err(AutomationError::PolicyDenied(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn policy_with_future_expiry_is_used() {
+        let client = PolicyClient::new();
+        let mut policy = make_policy("pol-1");
+        policy.expires_at = Some(Utc::now() + chrono::Duration::minutes(5));
+        client.update_policies(vec![policy]).await;
+
+        assert!(client
+            .validate_command(&make_command("pol-1:nonce_1234"))
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn expired_cache_ttl_rejects_commands() {
+        let client = PolicyClient::new().with_cache_ttl(60);
+        client.update_policies(vec![make_policy("pol-1")]).await;
+        client.policy_cache.write().await.last_updated = Utc::now() - chrono::Duration::seconds(61);
+
+        assert!(!client.is_cache_valid().await);
+        assert!(!client
+            .validate_command(&make_command("pol-1:nonce_1234"))
+            .await
+            .unwrap());
+    }
+
+    #[tokio::test]
+    async fn revoke_removes_cached_policy() {
+        let client = PolicyClient::new();
+        client
+            .update_policies(vec![make_policy("pol-1"), make_policy("pol-2")])
+            .await;
+        assert!(client
+            .validate_command(&make_command("pol-1:nonce_1234"))
+            .await
+            .unwrap());
+
+        assert!(client.revoke("pol-1").await);
+        assert!(!client.revoke("pol-1").await);
+        assert!(client.get_policy_for_token("pol-1").await.is_none());
+        assert!(!client
+            .validate_command(&make_command("pol-1:nonce_5678"))
+            .await
+            .unwrap());
+        assert!(client.validated_tokens.read().await.is_empty());
+        assert_eq!(client.list_policies().await.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn clear_drops_all_cached_policies() {
+        let client = PolicyClient::new();
+        client.update_policies(vec![make_policy("pol-1")]).await;
+        client.clear().await;
+
+        assert!(client.list_policies().await.is_empty());
+        assert!(!client.is_process_allowed("git").await);
+    }
+
     #[tokio::test]
     async fn remove_policy_returns_false_for_unknown() {
         let client = PolicyClient::new();
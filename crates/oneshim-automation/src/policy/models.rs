@@ -26,8 +26,21 @@ pub struct ExecutionPolicy {
     pub require_signed_token: bool,
     #[serde(default)]
     pub confirmation: oneshim_core::config::ConfirmationRequirement,
+    /// Server-assigned expiry. An expired policy stays listed but is never
+    /// matched for validation or token issuance.
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
+impl ExecutionPolicy {
+    pub fn is_expired_at(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires_at| expires_at <= now)
+    }
+}
+
+/// Default lifetime of a cached policy set, in seconds.
+pub const DEFAULT_POLICY_CACHE_TTL_SECS: u64 = 300;
+
 #[derive(Debug, Clone)]
 pub struct PolicyCache {
     pub policies: Vec<ExecutionPolicy>,
@@ -40,7 +53,7 @@ impl Default for PolicyCache {
         Self {
             policies: Vec::new(),
             last_updated: Utc::now(),
-            ttl_seconds: 300, // 5 min
+            ttl_seconds: DEFAULT_POLICY_CACHE_TTL_SECS,
         }
     }
 }
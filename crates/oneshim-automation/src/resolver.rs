@@ -84,6 +84,7 @@ mod tests {
             allow_network: None,
             require_signed_token: false,
             confirmation: Default::default(),
+            expires_at: None,
         }
     }
 
@@ -130,6 +130,31 @@ pub enum OverlayMode {
     Adaptive,
 }
 
+/// Directory layout for frame image files under `frames/`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum FrameShardGranularity {
+    /// One `YYYY-MM-DD/` folder per day (the original layout).
+    Flat,
+    /// `YYYY/MM/DD/`
+    #[default]
+    Day,
+    /// `YYYY/MM/DD/HH/` for high capture rates.
+    Hour,
+}
+
+/// Where the SQLCipher database key comes from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum DbKeySource {
+    /// 32-byte key file (`.db_key`) in the data directory.
+    #[default]
+    File,
+    /// Random secret in the OS keychain; the key is derived from it and
+    /// never written to disk.
+    Keyring,
+}
+
 /// Speech-to-text language hint for Whisper transcription.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -1,24 +1,42 @@
 mod enums;
 mod sections;
+mod validation;
 
 // ── Public re-exports (external API) ────────────────────────────────
 pub use enums::*;
 pub use sections::*;
+pub use validation::*;
 
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::time::Duration;
 
 // Use default functions from sections for AppConfig::default_config()
 use sections::{
-    default_capture_enabled, default_capture_throttle_ms, default_heartbeat_interval_ms,
-    default_idle_threshold_secs, default_max_storage_mb, default_poll_interval_ms,
-    default_process_interval_secs, default_request_timeout_ms, default_retention_days,
-    default_sse_max_retry_secs, default_sync_interval_ms, default_thumbnail_height,
-    default_thumbnail_width,
+    default_capture_enabled, default_capture_throttle_ms, default_clock_skew_warn_secs,
+    default_db_backup_keep, default_disk_critical_free_mb, default_disk_resume_free_mb,
+    default_failback_probe_secs, default_failover_after_failures, default_heartbeat_interval_ms,
+    default_idle_threshold_secs, default_max_storage_mb, default_metrics_interval_ms,
+    default_ocr_max_chars, default_poll_interval_ms, default_process_interval_secs,
+    default_request_timeout_ms, default_retention_days, default_retention_keep_tags,
+    default_sse_max_retry_secs, default_sse_min_retry_secs, default_sse_retry_jitter_ratio,
+    default_sync_interval_ms, default_thumbnail_height, default_thumbnail_width,
 };
 
+/// Current `AppConfig` schema; older files are brought up to it by
+/// [`crate::config_manager::migrate`].
+pub const CONFIG_SCHEMA_VERSION: u32 = 2;
+
+fn default_schema_version() -> u32 {
+    CONFIG_SCHEMA_VERSION
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AppConfig {
+    /// Schema the file was written with. Configs from before versioning
+    /// carry no field and are treated as version 1 on load.
+    #[serde(default = "default_schema_version")]
+    pub schema_version: u32,
     pub server: ServerConfig,
     pub monitor: MonitorConfig,
     pub storage: StorageConfig,
@@ -52,6 +70,9 @@ pub struct AppConfig {
     /// 아웃바운드 TLS 설정 — 기본값: 활성화
     #[serde(default)]
     pub tls: TlsConfig,
+    /// Outbound host allowlist — disabled by default.
+    #[serde(default)]
+    pub egress: EgressConfig,
     #[serde(default)]
     pub analysis: AnalysisConfig,
     #[serde(default)]
@@ -66,6 +87,12 @@ pub struct AppConfig {
     pub audio: AudioConfig,
     #[serde(default)]
     pub focus_auto: FocusAutoConfig,
+    /// Deep-work / shallow / communication overrides for focus metrics.
+    #[serde(default)]
+    pub focus_classification: FocusClassificationConfig,
+    /// Rules that tag captured frames by app, title or category.
+    #[serde(default)]
+    pub auto_tag: AutoTagConfig,
     #[serde(default)]
     pub external_grpc: ExternalGrpcConfig,
     /// Tracking schedule — wall-clock mute windows (Phase 9 PR-A).
@@ -74,6 +101,13 @@ pub struct AppConfig {
     /// Autostart — onboarding state for cross-platform autostart feature (Phase 9 PR-B1).
     #[serde(default)]
     pub autostart: AutostartConfig,
+    /// Named partial configs, e.g. `"focus"` muting capture and notifications.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub profiles: HashMap<String, ConfigOverlay>,
+    /// Profile selected with [`AppConfig::apply_profile`]. Its overlay is
+    /// applied by [`AppConfig::effective`]; the fields above stay as saved.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub active_profile: Option<String>,
 }
 
 // AppConfig impl
@@ -81,25 +115,52 @@ pub struct AppConfig {
 impl AppConfig {
     pub fn default_config() -> Self {
         Self {
+            schema_version: CONFIG_SCHEMA_VERSION,
             server: ServerConfig {
                 base_url: "http://localhost:8000".to_string(),
                 request_timeout_ms: default_request_timeout_ms(),
                 sse_max_retry_secs: default_sse_max_retry_secs(),
+                sse_min_retry_secs: default_sse_min_retry_secs(),
+                sse_retry_jitter_ratio: default_sse_retry_jitter_ratio(),
+                clock_skew_warn_secs: default_clock_skew_warn_secs(),
+                standby_servers: Vec::new(),
+                failover_after_failures: default_failover_after_failures(),
+                failback_probe_secs: default_failback_probe_secs(),
+                content_encryption: ContentEncryptionConfig::default(),
             },
             monitor: MonitorConfig {
                 poll_interval_ms: default_poll_interval_ms(),
                 sync_interval_ms: default_sync_interval_ms(),
                 heartbeat_interval_ms: default_heartbeat_interval_ms(),
                 idle_threshold_secs: default_idle_threshold_secs(),
+                idle_session_end_secs: 0,
+                idle_merge_threshold_secs: 0,
                 process_interval_secs: default_process_interval_secs(),
+                metrics_interval_ms: default_metrics_interval_ms(),
                 process_monitoring: true,
                 input_activity: true,
                 upload_enabled: false,
+                watchdog: LoopWatchdogConfig::default(),
+                loops: SchedulerLoopsConfig::default(),
+                upload_queue: UploadQueueConfig::default(),
+                context_sampling: ContextSamplingConfig::default(),
+                process_allowlist: Vec::new(),
+                process_denylist: Vec::new(),
+                category_overrides: HashMap::new(),
             },
             storage: StorageConfig {
                 db_path: None,
                 retention_days: default_retention_days(),
+                retention_overrides: HashMap::new(),
                 max_storage_mb: default_max_storage_mb(),
+                frame_shard_granularity: FrameShardGranularity::default(),
+                retention_keep_tags: default_retention_keep_tags(),
+                text_compression_min_bytes: None,
+                db_backup_interval_hours: 0,
+                db_backup_keep: default_db_backup_keep(),
+                disk_critical_free_mb: default_disk_critical_free_mb(),
+                disk_resume_free_mb: default_disk_resume_free_mb(),
+                db_key_source: DbKeySource::default(),
             },
             vision: VisionConfig {
                 capture_enabled: default_capture_enabled(),
@@ -108,6 +169,11 @@ impl AppConfig {
                 thumbnail_height: default_thumbnail_height(),
                 ocr_enabled: false,
                 privacy_mode: false,
+                ocr_downscale_width: None,
+                ocr_max_chars: default_ocr_max_chars(),
+                signal_triggers: CaptureSignalTriggers::default(),
+                dwell_capture_secs: None,
+                bookmarks: BookmarkCaptureConfig::default(),
             },
             update: UpdateConfig::default(),
             integrity: IntegrityConfig::default(),
@@ -123,6 +189,7 @@ impl AppConfig {
             ai_session: AiSessionConfig::default(),
             integration: IntegrationConfig::default(),
             tls: TlsConfig::default(),
+            egress: EgressConfig::default(),
             analysis: AnalysisConfig::default(),
             sync: SyncConfig::default(),
             coaching: CoachingConfig::default(),
@@ -130,17 +197,74 @@ impl AppConfig {
             suggestions: SuggestionConfig::default(),
             audio: AudioConfig::default(),
             focus_auto: FocusAutoConfig::default(),
+            focus_classification: FocusClassificationConfig::default(),
+            auto_tag: AutoTagConfig::default(),
             external_grpc: ExternalGrpcConfig::default(),
             tracking_schedule: TrackingScheduleConfig::default(),
             autostart: AutostartConfig::default(),
+            profiles: HashMap::new(),
+            active_profile: None,
+        }
+    }
+
+    /// Record profile `name` as the active profile. Only the selection
+    /// changes; the overlay is applied by [`Self::effective`], so switching
+    /// to another profile leaves none of this one's values behind.
+    pub fn apply_profile(&mut self, name: &str) -> Result<(), String> {
+        if !self.profiles.contains_key(name) {
+            let mut known: Vec<_> = self.profiles.keys().map(String::as_str).collect();
+            known.sort_unstable();
+            return Err(format!(
+                "unknown profile '{name}' (known: {})",
+                known.join(", ")
+            ));
+        }
+        self.active_profile = Some(name.to_string());
+        Ok(())
+    }
+
+    /// This config with the active profile's overlay applied. Fields the
+    /// profile leaves unset keep their saved values; an active profile that
+    /// no longer exists is ignored.
+    pub fn effective(&self) -> AppConfig {
+        let mut config = self.clone();
+        if let Some(overlay) = self
+            .active_profile
+            .as_deref()
+            .and_then(|name| self.profiles.get(name))
+        {
+            overlay.apply_to(&mut config);
         }
+        config
     }
 
     /// Validate that all config sections have values within acceptable bounds.
+    ///
+    /// Same checks as [`Self::validate`], with the errors joined into one
+    /// message.
     pub fn validate_bounds(&self) -> Result<(), String> {
-        self.storage.validate_bounds()?;
-        self.vision.validate_bounds()?;
-        Ok(())
+        self.validate()
+            .map_err(|errors| format_validation_errors(&errors))
+    }
+
+    /// Check every section and return all invalid values at once, each with
+    /// its JSON path, so a hand-edited config.json can be fixed in one pass.
+    pub fn validate(&self) -> Result<(), Vec<ConfigValidationError>> {
+        let mut errors = Vec::new();
+        self.server.validate_into(&mut errors);
+        self.monitor.validate_into(&mut errors);
+        self.storage.validate_into(&mut errors);
+        self.vision.validate_into(&mut errors);
+        self.privacy.validate_into(&mut errors);
+        self.auto_tag.validate_into(&mut errors);
+        for (name, overlay) in &self.profiles {
+            overlay.validate_into(name, &mut errors);
+        }
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
     }
 
     pub fn request_timeout(&self) -> Duration {
@@ -186,6 +310,9 @@ mod tests {
         let parsed: TlsConfig = serde_json::from_value(json!({})).unwrap();
         assert!(parsed.enabled);
         assert!(!parsed.allow_self_signed);
+        assert!(parsed.client_cert_pem.is_none());
+        assert!(parsed.client_key_path.is_none());
+        assert!(parsed.ca_cert_pem.is_none());
     }
 
     #[test]
@@ -800,7 +927,16 @@ mod tests {
         let config = StorageConfig {
             db_path: None,
             retention_days: 0,
+            retention_overrides: HashMap::new(),
             max_storage_mb: 500,
+            frame_shard_granularity: FrameShardGranularity::default(),
+            retention_keep_tags: Vec::new(),
+            text_compression_min_bytes: None,
+            db_backup_interval_hours: 0,
+            db_backup_keep: 7,
+            disk_critical_free_mb: 50,
+            disk_resume_free_mb: 100,
+            db_key_source: DbKeySource::default(),
         };
         assert!(config.validate_bounds().is_err());
     }
@@ -810,7 +946,16 @@ mod tests {
         let config = StorageConfig {
             db_path: None,
             retention_days: 30,
+            retention_overrides: HashMap::new(),
             max_storage_mb: 5,
+            frame_shard_granularity: FrameShardGranularity::default(),
+            retention_keep_tags: Vec::new(),
+            text_compression_min_bytes: None,
+            db_backup_interval_hours: 0,
+            db_backup_keep: 7,
+            disk_critical_free_mb: 50,
+            disk_resume_free_mb: 100,
+            db_key_source: DbKeySource::default(),
         };
         let err = config.validate_bounds().unwrap_err();
         assert!(err.contains("max_storage_mb"));
@@ -821,11 +966,171 @@ mod tests {
         let config = StorageConfig {
             db_path: None,
             retention_days: 1,
+            retention_overrides: HashMap::new(),
             max_storage_mb: 10,
+            frame_shard_granularity: FrameShardGranularity::default(),
+            retention_keep_tags: Vec::new(),
+            text_compression_min_bytes: None,
+            db_backup_interval_hours: 0,
+            db_backup_keep: 7,
+            disk_critical_free_mb: 50,
+            disk_resume_free_mb: 100,
+            db_key_source: DbKeySource::default(),
         };
         assert!(config.validate_bounds().is_ok());
     }
 
+    #[test]
+    fn validate_reports_every_invalid_field_with_path_and_value() {
+        let mut config = AppConfig::default_config();
+        config.monitor.poll_interval_ms = 0;
+        config.vision.thumbnail_width = 0;
+        config.storage.max_storage_mb = 5;
+        config.storage.retention_days = 0;
+
+        let errors = config.validate().unwrap_err();
+        let paths: Vec<_> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "monitor.poll_interval_ms",
+                "storage.retention_days",
+                "storage.max_storage_mb",
+                "vision.thumbnail_width",
+            ]
+        );
+        let max_storage = &errors[2];
+        assert_eq!(max_storage.value, json!(5));
+        assert_eq!(max_storage.reason, "must be >= 10");
+
+        let joined = config.validate_bounds().unwrap_err();
+        assert!(
+            joined.contains("monitor.poll_interval_ms must be > 0 (got 0)"),
+            "{joined}"
+        );
+        assert!(joined.contains("vision.thumbnail_width"), "{joined}");
+    }
+
+    #[test]
+    fn validate_accepts_default_config() {
+        assert!(AppConfig::default_config().validate().is_ok());
+    }
+
+    #[test]
+    fn storage_zero_retention_allowed_only_in_test_mode() {
+        let mut config = AppConfig::default_config();
+        config.storage.retention_days = 0;
+
+        let mut errors = Vec::new();
+        config.storage.validate_into_with(false, &mut errors);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "storage.retention_days");
+
+        let mut errors = Vec::new();
+        config.storage.validate_into_with(true, &mut errors);
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn server_validate_bounds_rejects_non_http_standby() {
+        let mut config = AppConfig::default_config();
+        config.server.standby_servers.push(StandbyServerConfig {
+            base_url: "standby.example.com".to_string(),
+            grpc_endpoint: None,
+        });
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("standby_servers"), "{err}");
+
+        config.server.standby_servers[0].base_url = "https://standby.example.com".to_string();
+        assert!(config.validate_bounds().is_ok());
+    }
+
+    #[test]
+    fn server_validate_bounds_requires_key_when_content_encryption_enabled() {
+        let mut config = AppConfig::default_config();
+        config.server.content_encryption.enabled = true;
+        let err = config.validate_bounds().unwrap_err();
+        assert!(
+            err.contains("server.content_encryption.public_key"),
+            "{err}"
+        );
+
+        config.server.content_encryption.public_key =
+            "AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBkaGxwdHh8=".to_string();
+        assert!(config.validate_bounds().is_ok());
+    }
+
+    #[test]
+    fn storage_validate_bounds_rejects_resume_threshold_at_or_below_critical() {
+        let mut config = AppConfig::default_config();
+        config.storage.disk_resume_free_mb = config.storage.disk_critical_free_mb;
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("disk_resume_free_mb"), "{err}");
+    }
+
+    #[test]
+    fn privacy_validate_bounds_rejects_empty_notification_mask_region() {
+        let mut config = AppConfig::default_config();
+        assert!(!config.privacy.mask_notification_banners);
+        config.privacy.notification_mask_region = Some(NotificationMaskRegion {
+            width: 0,
+            height: 120,
+        });
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("notification_mask_region"), "{err}");
+
+        config.privacy.notification_mask_region = Some(NotificationMaskRegion {
+            width: 400,
+            height: 120,
+        });
+        assert!(config.validate_bounds().is_ok());
+    }
+
+    #[test]
+    fn privacy_preview_defaults_off_and_validates_bounds() {
+        let mut config = AppConfig::default_config();
+        assert!(!config.privacy.preview.enabled);
+        assert!(config.validate_bounds().is_ok());
+
+        config.privacy.preview.max_dimension = 4096;
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("privacy.preview.max_dimension"), "{err}");
+
+        config.privacy.preview.max_dimension = 64;
+        config.privacy.preview.blur_sigma = f32::NAN;
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("privacy.preview.blur_sigma"), "{err}");
+
+        let parsed: PrivacyConfig =
+            serde_json::from_str(r#"{"preview":{"enabled":true}}"#).unwrap();
+        assert!(parsed.preview.enabled);
+        assert_eq!(parsed.preview.max_dimension, 96);
+    }
+
+    #[test]
+    fn privacy_validate_bounds_rejects_invalid_title_regex() {
+        let mut config = AppConfig::default_config();
+        config.privacy.excluded_title_patterns =
+            vec!["*bank*".to_string(), "re:(unclosed".to_string()];
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("privacy.excluded_title_patterns"), "{err}");
+
+        config.privacy.excluded_title_patterns = vec![r"re:(?i)\bbank\b".to_string()];
+        assert!(config.validate_bounds().is_ok());
+    }
+
+    #[test]
+    fn monitor_validate_bounds_rejects_invalid_process_regex() {
+        let mut config = AppConfig::default_config();
+        config.monitor.process_denylist = vec!["re:[unclosed".to_string()];
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("monitor.process_denylist"), "{err}");
+
+        config.monitor.process_denylist = vec!["1Password*".to_string()];
+        assert!(config.validate_bounds().is_ok());
+        assert!(!config.monitor.process_filter().permits("1Password 7"));
+    }
+
     #[test]
     fn vision_validate_bounds_rejects_zero_throttle() {
         let config = VisionConfig {
@@ -835,6 +1140,11 @@ mod tests {
             thumbnail_height: 270,
             ocr_enabled: false,
             privacy_mode: false,
+            ocr_downscale_width: None,
+            ocr_max_chars: None,
+            signal_triggers: CaptureSignalTriggers::default(),
+            dwell_capture_secs: None,
+            bookmarks: BookmarkCaptureConfig::default(),
         };
         assert!(config.validate_bounds().is_err());
     }
@@ -848,6 +1158,11 @@ mod tests {
             thumbnail_height: 270,
             ocr_enabled: false,
             privacy_mode: false,
+            ocr_downscale_width: None,
+            ocr_max_chars: None,
+            signal_triggers: CaptureSignalTriggers::default(),
+            dwell_capture_secs: None,
+            bookmarks: BookmarkCaptureConfig::default(),
         };
         let err = config.validate_bounds().unwrap_err();
         assert!(err.contains("capture_throttle_ms"));
@@ -862,16 +1177,136 @@ mod tests {
             thumbnail_height: 270,
             ocr_enabled: false,
             privacy_mode: false,
+            ocr_downscale_width: None,
+            ocr_max_chars: None,
+            signal_triggers: CaptureSignalTriggers::default(),
+            dwell_capture_secs: None,
+            bookmarks: BookmarkCaptureConfig::default(),
         };
         assert!(config.validate_bounds().is_ok());
     }
 
+    #[test]
+    fn vision_validate_bounds_checks_ocr_downscale_width() {
+        let mut config = AppConfig::default_config().vision;
+        config.ocr_downscale_width = Some(320);
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("ocr_downscale_width"));
+
+        config.ocr_downscale_width = Some(1280);
+        assert!(config.validate_bounds().is_ok());
+    }
+
+    #[test]
+    fn vision_validate_bounds_checks_dwell_capture_secs() {
+        let mut config = AppConfig::default_config().vision;
+        assert!(config.dwell_capture_secs.is_none());
+        config.dwell_capture_secs = Some(5);
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("dwell_capture_secs"));
+
+        config.dwell_capture_secs = Some(300);
+        assert!(config.validate_bounds().is_ok());
+    }
+
+    #[test]
+    fn vision_validate_bounds_checks_ocr_max_chars() {
+        let mut config = AppConfig::default_config().vision;
+        assert_eq!(config.ocr_max_chars, Some(8_000));
+
+        config.ocr_max_chars = Some(100);
+        let err = config.validate_bounds().unwrap_err();
+        assert!(err.contains("ocr_max_chars"));
+
+        config.ocr_max_chars = None;
+        assert!(config.validate_bounds().is_ok());
+    }
+
     #[test]
     fn app_config_validate_bounds_default_passes() {
         let config = AppConfig::default_config();
         assert!(config.validate_bounds().is_ok());
     }
 
+    #[test]
+    fn apply_profile_overlays_only_set_fields() {
+        let mut config = AppConfig::default_config();
+        config.vision.ocr_enabled = true;
+        config.profiles.insert(
+            "focus".to_string(),
+            serde_json::from_value(json!({
+                "capture_enabled": false,
+                "notifications_enabled": false
+            }))
+            .expect("overlay parses"),
+        );
+
+        config.apply_profile("focus").unwrap();
+        assert!(config.vision.capture_enabled, "base config stays as saved");
+
+        let effective = config.effective();
+        assert!(!effective.vision.capture_enabled);
+        assert!(!effective.notification.enabled);
+        assert!(effective.vision.ocr_enabled, "unset keys fall through");
+        assert_eq!(config.active_profile.as_deref(), Some("focus"));
+    }
+
+    #[test]
+    fn switching_profiles_reverts_the_previous_overlay() {
+        let mut config = AppConfig::default_config();
+        config.profiles.insert(
+            "a".to_string(),
+            ConfigOverlay {
+                capture_enabled: Some(false),
+                poll_interval_ms: Some(5_000),
+                ..Default::default()
+            },
+        );
+        config.profiles.insert(
+            "b".to_string(),
+            ConfigOverlay {
+                notifications_enabled: Some(false),
+                ..Default::default()
+            },
+        );
+        let saved_poll = config.monitor.poll_interval_ms;
+
+        config.apply_profile("a").unwrap();
+        assert!(!config.effective().vision.capture_enabled);
+        config.apply_profile("b").unwrap();
+
+        let effective = config.effective();
+        assert!(effective.vision.capture_enabled);
+        assert_eq!(effective.monitor.poll_interval_ms, saved_poll);
+        assert!(!effective.notification.enabled);
+    }
+
+    #[test]
+    fn apply_unknown_profile_leaves_config_unchanged() {
+        let mut config = AppConfig::default_config();
+        config
+            .profiles
+            .insert("at-office".to_string(), ConfigOverlay::default());
+
+        let err = config.apply_profile("holiday").unwrap_err();
+        assert!(err.contains("holiday") && err.contains("at-office"));
+        assert!(config.active_profile.is_none());
+    }
+
+    #[test]
+    fn validate_checks_profile_overlays() {
+        let mut config = AppConfig::default_config();
+        config.profiles.insert(
+            "turbo".to_string(),
+            ConfigOverlay {
+                poll_interval_ms: Some(0),
+                ..Default::default()
+            },
+        );
+        let errors = config.validate().unwrap_err();
+        assert_eq!(errors[0].path, "profiles.turbo.poll_interval_ms");
+    }
+
     #[test]
     fn analysis_llm_work_type_enabled_defaults_true() {
         let payload = json!({});
@@ -0,0 +1,234 @@
+use serde::{Deserialize, Serialize};
+
+use super::super::validation::{check_patterns, ConfigValidationError};
+use crate::models::work_session::AppCategory;
+use crate::title_pattern::PatternSet;
+
+/// Tags applied to captured frames by rule, e.g. app `Figma` → `design`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoTagConfig {
+    pub enabled: bool,
+    pub match_mode: AutoTagMatchMode,
+    /// Evaluated in order.
+    pub rules: Vec<AutoTagRule>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AutoTagMatchMode {
+    /// Only the first matching rule applies its tag.
+    #[default]
+    FirstMatch,
+    /// Every matching rule applies its tag.
+    AllMatch,
+}
+
+/// One rule. Each non-empty condition must match; within a condition any
+/// entry may match. Patterns use [`crate::title_pattern`] syntax.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct AutoTagRule {
+    pub tag: String,
+    pub app_patterns: Vec<String>,
+    pub title_patterns: Vec<String>,
+    pub categories: Vec<AppCategory>,
+}
+
+impl AutoTagRule {
+    fn has_conditions(&self) -> bool {
+        !(self.app_patterns.is_empty()
+            && self.title_patterns.is_empty()
+            && self.categories.is_empty())
+    }
+}
+
+impl AutoTagConfig {
+    /// Reject blank tags, rules without conditions (which would tag every
+    /// frame) and patterns that do not compile.
+    pub fn validate_into(&self, errors: &mut Vec<ConfigValidationError>) {
+        for (index, rule) in self.rules.iter().enumerate() {
+            let path = format!("auto_tag.rules[{index}]");
+            if rule.tag.trim().is_empty() {
+                errors.push(ConfigValidationError::new(
+                    format!("{path}.tag"),
+                    &rule.tag,
+                    "must not be empty",
+                ));
+            }
+            if !rule.has_conditions() {
+                errors.push(ConfigValidationError::new(
+                    path.clone(),
+                    &rule.tag,
+                    "needs at least one app pattern, title pattern or category",
+                ));
+            }
+            check_patterns(&format!("{path}.app_patterns"), &rule.app_patterns, errors);
+            check_patterns(
+                &format!("{path}.title_patterns"),
+                &rule.title_patterns,
+                errors,
+            );
+        }
+    }
+
+    /// Compile the rules for capture-time evaluation. Rules that fail
+    /// validation are skipped; they are reported when the config is loaded.
+    pub fn compile(&self) -> AutoTagRules {
+        let rules = if self.enabled {
+            self.rules
+                .iter()
+                .filter(|rule| !rule.tag.trim().is_empty() && rule.has_conditions())
+                .filter_map(|rule| {
+                    Some(CompiledRule {
+                        tag: rule.tag.trim().to_string(),
+                        apps: PatternSet::compile(&rule.app_patterns).ok()?,
+                        titles: PatternSet::compile(&rule.title_patterns).ok()?,
+                        categories: rule.categories.clone(),
+                    })
+                })
+                .collect()
+        } else {
+            Vec::new()
+        };
+        AutoTagRules {
+            match_mode: self.match_mode,
+            rules,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct CompiledRule {
+    tag: String,
+    apps: PatternSet,
+    titles: PatternSet,
+    categories: Vec<AppCategory>,
+}
+
+impl CompiledRule {
+    fn matches(&self, app_name: &str, window_title: &str, category: AppCategory) -> bool {
+        (self.apps.is_empty() || self.apps.is_match(app_name))
+            && (self.titles.is_empty() || self.titles.is_match(window_title))
+            && (self.categories.is_empty() || self.categories.contains(&category))
+    }
+}
+
+/// Compiled [`AutoTagConfig`]; empty when auto-tagging is disabled.
+#[derive(Debug, Clone, Default)]
+pub struct AutoTagRules {
+    match_mode: AutoTagMatchMode,
+    rules: Vec<CompiledRule>,
+}
+
+impl AutoTagRules {
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+
+    /// Tags for a frame of `app_name` showing `window_title`, in rule order
+    /// and without duplicates.
+    pub fn tags_for(&self, app_name: &str, window_title: &str) -> Vec<String> {
+        if self.rules.is_empty() {
+            return Vec::new();
+        }
+        let category = AppCategory::from_app_name(app_name);
+        let mut tags: Vec<String> = Vec::new();
+        for rule in &self.rules {
+            if !rule.matches(app_name, window_title, category) {
+                continue;
+            }
+            if !tags.contains(&rule.tag) {
+                tags.push(rule.tag.clone());
+            }
+            if self.match_mode == AutoTagMatchMode::FirstMatch {
+                break;
+            }
+        }
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(match_mode: AutoTagMatchMode) -> AutoTagConfig {
+        AutoTagConfig {
+            enabled: true,
+            match_mode,
+            rules: vec![
+                AutoTagRule {
+                    tag: "design".to_string(),
+                    app_patterns: vec!["Figma".to_string()],
+                    ..Default::default()
+                },
+                AutoTagRule {
+                    tag: "client".to_string(),
+                    title_patterns: vec!["*acme*".to_string()],
+                    ..Default::default()
+                },
+                AutoTagRule {
+                    tag: "coding".to_string(),
+                    categories: vec![AppCategory::Development],
+                    ..Default::default()
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn first_match_applies_only_the_first_matching_rule() {
+        let rules = config(AutoTagMatchMode::FirstMatch).compile();
+        assert_eq!(rules.tags_for("Figma", "Acme landing page"), ["design"]);
+        assert_eq!(rules.tags_for("Code", "main.rs"), ["coding"]);
+        assert!(rules.tags_for("Terminal", "zsh").is_empty());
+    }
+
+    #[test]
+    fn all_match_applies_every_matching_rule() {
+        let rules = config(AutoTagMatchMode::AllMatch).compile();
+        assert_eq!(
+            rules.tags_for("Figma", "Acme landing page"),
+            ["design", "client"]
+        );
+    }
+
+    #[test]
+    fn disabled_config_compiles_to_no_rules() {
+        let mut cfg = config(AutoTagMatchMode::AllMatch);
+        cfg.enabled = false;
+        assert!(cfg.compile().is_empty());
+    }
+
+    #[test]
+    fn validation_rejects_blank_tags_unconditional_rules_and_bad_patterns() {
+        let cfg = AutoTagConfig {
+            enabled: true,
+            match_mode: AutoTagMatchMode::FirstMatch,
+            rules: vec![
+                AutoTagRule {
+                    tag: " ".to_string(),
+                    app_patterns: vec!["re:(unclosed".to_string()],
+                    ..Default::default()
+                },
+                AutoTagRule {
+                    tag: "everything".to_string(),
+                    ..Default::default()
+                },
+            ],
+        };
+        let mut errors = Vec::new();
+        cfg.validate_into(&mut errors);
+        let paths: Vec<_> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "auto_tag.rules[0].tag",
+                "auto_tag.rules[0].app_patterns[0]",
+                "auto_tag.rules[1]",
+            ]
+        );
+        assert!(cfg.compile().is_empty());
+    }
+}
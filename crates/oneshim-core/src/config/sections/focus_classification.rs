@@ -0,0 +1,77 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::models::work_session::{AppCategory, WorkClass};
+
+/// Overrides for how apps count towards deep-work and communication time.
+///
+/// App overrides win over category overrides, which win over
+/// [`AppCategory::work_class`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FocusClassificationConfig {
+    /// Per-category overrides, e.g. `{"communication": "deep_work"}` for a
+    /// support team.
+    pub categories: HashMap<AppCategory, WorkClass>,
+    /// Per-app overrides keyed by app name (case-insensitive exact match).
+    pub apps: HashMap<String, WorkClass>,
+}
+
+impl FocusClassificationConfig {
+    pub fn classify(&self, app_name: &str, category: AppCategory) -> WorkClass {
+        if !self.apps.is_empty() {
+            let app_name = app_name.to_lowercase();
+            if let Some(class) = self
+                .apps
+                .iter()
+                .find_map(|(app, class)| (app.to_lowercase() == app_name).then_some(*class))
+            {
+                return class;
+            }
+        }
+        self.categories
+            .get(&category)
+            .copied()
+            .unwrap_or_else(|| category.work_class())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn defaults_follow_builtin_mapping() {
+        let cfg = FocusClassificationConfig::default();
+        assert_eq!(
+            cfg.classify("Slack", AppCategory::Communication),
+            WorkClass::Communication
+        );
+        assert_eq!(
+            cfg.classify("Code", AppCategory::Development),
+            WorkClass::DeepWork
+        );
+        assert_eq!(
+            cfg.classify("Chrome", AppCategory::Browser),
+            WorkClass::Shallow
+        );
+    }
+
+    #[test]
+    fn app_override_beats_category_override() {
+        let cfg: FocusClassificationConfig = serde_json::from_str(
+            r#"{"categories": {"communication": "deep_work"}, "apps": {"Discord": "shallow"}}"#,
+        )
+        .expect("parse");
+
+        assert_eq!(
+            cfg.classify("Zendesk Chat", AppCategory::Communication),
+            WorkClass::DeepWork
+        );
+        assert_eq!(
+            cfg.classify("discord", AppCategory::Communication),
+            WorkClass::Shallow
+        );
+    }
+}
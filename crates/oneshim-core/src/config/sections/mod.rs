@@ -20,13 +20,16 @@ mod ai_session;
 mod ai_validation;
 mod analysis;
 mod audio;
+mod auto_tag;
 mod coaching;
 mod focus_auto;
+mod focus_classification;
 mod indicator;
 mod integration;
 mod monitoring;
 mod network;
 mod privacy;
+mod profiles;
 mod storage;
 mod suggestion;
 mod sync;
@@ -38,13 +41,16 @@ pub use ai_session::*;
 pub use ai_validation::*;
 pub use analysis::*;
 pub use audio::*;
+pub use auto_tag::*;
 pub use coaching::*;
 pub use focus_auto::*;
+pub use focus_classification::*;
 pub use indicator::*;
 pub use integration::*;
 pub use monitoring::*;
 pub use network::*;
 pub use privacy::*;
+pub use profiles::*;
 pub use storage::*;
 pub use suggestion::*;
 pub use sync::*;
@@ -56,14 +62,25 @@ pub(super) use monitoring::default_capture_enabled;
 pub(super) use monitoring::default_capture_throttle_ms;
 pub(super) use monitoring::default_heartbeat_interval_ms;
 pub(super) use monitoring::default_idle_threshold_secs;
+pub(super) use monitoring::default_metrics_interval_ms;
+pub(super) use monitoring::default_ocr_max_chars;
 pub(super) use monitoring::default_poll_interval_ms;
 pub(super) use monitoring::default_process_interval_secs;
 pub(super) use monitoring::default_sync_interval_ms;
 pub(super) use monitoring::default_thumbnail_height;
 pub(super) use monitoring::default_thumbnail_width;
 
+pub(super) use network::default_clock_skew_warn_secs;
+pub(super) use network::default_failback_probe_secs;
+pub(super) use network::default_failover_after_failures;
 pub(super) use network::default_request_timeout_ms;
 pub(super) use network::default_sse_max_retry_secs;
+pub(super) use network::default_sse_min_retry_secs;
+pub(super) use network::default_sse_retry_jitter_ratio;
 
+pub(super) use storage::default_db_backup_keep;
+pub(super) use storage::default_disk_critical_free_mb;
+pub(super) use storage::default_disk_resume_free_mb;
 pub(super) use storage::default_max_storage_mb;
 pub(super) use storage::default_retention_days;
+pub(super) use storage::default_retention_keep_tags;
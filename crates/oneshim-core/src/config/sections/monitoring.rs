@@ -1,6 +1,12 @@
 // 모니터링/스케줄 설정 — 시스템 감시, 화면 캡처, 활성 시간, 파일 접근 설정
 use super::super::enums::Weekday;
+use super::super::validation::{check_patterns, into_bounds_result, ConfigValidationError};
+use super::tracking_schedule::{chrono_weekday_to_ours, weekday_succ};
+use crate::models::work_session::AppCategory;
+use crate::title_pattern::{NameFilter, PatternSet};
+use chrono::{DateTime, Datelike, TimeZone, Timelike};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 // ── MonitorConfig ──────────────────────────────────────────────────
@@ -15,8 +21,21 @@ pub struct MonitorConfig {
     pub heartbeat_interval_ms: u64,
     #[serde(default = "default_idle_threshold_secs")]
     pub idle_threshold_secs: u64,
+    /// Idle this long ends the active work session, back-dated to when idle
+    /// began; a new session starts when activity returns. `0` disables.
+    #[serde(default)]
+    pub idle_session_end_secs: u64,
+    /// Idle that resumes within this many seconds of the previous idle
+    /// period ending extends that period instead of starting a new one.
+    /// `0` disables.
+    #[serde(default)]
+    pub idle_merge_threshold_secs: u64,
     #[serde(default = "default_process_interval_secs")]
     pub process_interval_secs: u64,
+    /// System metrics sampling period. Raise it to save battery; short
+    /// bursts can be requested at runtime without changing it.
+    #[serde(default = "default_metrics_interval_ms")]
+    pub metrics_interval_ms: u64,
     #[serde(default = "default_true")]
     pub process_monitoring: bool,
     #[serde(default = "default_true")]
@@ -24,6 +43,240 @@ pub struct MonitorConfig {
     /// Enable server upload of collected events. Default: false (safe).
     #[serde(default)]
     pub upload_enabled: bool,
+    /// Scheduler loop watchdog.
+    #[serde(default)]
+    pub watchdog: LoopWatchdogConfig,
+    /// Per-loop enable flags for the background scheduler.
+    #[serde(default)]
+    pub loops: SchedulerLoopsConfig,
+    /// Upload backlog cap and priority ordering.
+    #[serde(default)]
+    pub upload_queue: UploadQueueConfig,
+    /// Collapsing of rapid same-app context events.
+    #[serde(default)]
+    pub context_sampling: ContextSamplingConfig,
+    /// When set, only processes whose name matches one of these globs or
+    /// `re:` regexes are recorded in process snapshots and events.
+    #[serde(default)]
+    pub process_allowlist: Vec<String>,
+    /// Processes whose name matches one of these are never recorded, even
+    /// if allowlisted. Same pattern syntax as `process_allowlist`.
+    #[serde(default)]
+    pub process_denylist: Vec<String>,
+    /// App category overrides keyed by a case-insensitive app-name
+    /// substring, e.g. `{"Jira-Desktop": "communication"}`. Checked before
+    /// the built-in mapping; the longest matching key wins.
+    #[serde(default)]
+    pub category_overrides: HashMap<String, AppCategory>,
+}
+
+impl MonitorConfig {
+    /// Reject zero intervals and process patterns that do not compile.
+    pub fn validate_bounds(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        self.validate_into(&mut errors);
+        into_bounds_result(errors)
+    }
+
+    /// Push every invalid monitor value onto `errors`.
+    pub fn validate_into(&self, errors: &mut Vec<ConfigValidationError>) {
+        for (path, value) in [
+            ("monitor.poll_interval_ms", self.poll_interval_ms),
+            ("monitor.sync_interval_ms", self.sync_interval_ms),
+            ("monitor.heartbeat_interval_ms", self.heartbeat_interval_ms),
+            ("monitor.process_interval_secs", self.process_interval_secs),
+            ("monitor.metrics_interval_ms", self.metrics_interval_ms),
+        ] {
+            if value == 0 {
+                errors.push(ConfigValidationError::new(path, value, "must be > 0"));
+            }
+        }
+        check_patterns("monitor.process_allowlist", &self.process_allowlist, errors);
+        check_patterns("monitor.process_denylist", &self.process_denylist, errors);
+        if self
+            .category_overrides
+            .keys()
+            .any(|key| key.trim().is_empty())
+        {
+            errors.push(ConfigValidationError::new(
+                "monitor.category_overrides",
+                "",
+                "app name keys must not be empty",
+            ));
+        }
+    }
+
+    pub fn process_filter(&self) -> NameFilter {
+        NameFilter::new(
+            PatternSet::compile_lenient(&self.process_allowlist),
+            PatternSet::compile_lenient(&self.process_denylist),
+        )
+    }
+}
+
+// ── ContextSamplingConfig ──────────────────────────────────────────
+
+/// Collapses bursts of context events from the same app (e.g. a progress
+/// bar ticking in the window title) into one event with a repeat count.
+/// App switches always produce their own event.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ContextSamplingConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Same-app events within this many ms of the first one are merged.
+    #[serde(default = "default_context_sampling_window_ms")]
+    pub window_ms: u64,
+}
+
+impl Default for ContextSamplingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            window_ms: default_context_sampling_window_ms(),
+        }
+    }
+}
+
+// ── UploadQueueConfig ──────────────────────────────────────────────
+
+/// Backlog handling for the event upload queue on slow or offline uplinks.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct UploadQueueConfig {
+    /// Flush high-priority events (user actions, alerts) first and, at the
+    /// cap, drop the lowest-priority events instead of the oldest.
+    #[serde(default)]
+    pub prioritize: bool,
+    /// Maximum number of events held for upload.
+    #[serde(default = "default_upload_backlog_cap")]
+    pub backlog_cap: usize,
+    /// Re-send each event with the idempotency key stored on its local row,
+    /// so the server drops copies uploaded again after a restart. When off,
+    /// keys only survive retries within one run.
+    #[serde(default = "default_true")]
+    pub persist_idempotency_keys: bool,
+}
+
+impl Default for UploadQueueConfig {
+    fn default() -> Self {
+        Self {
+            prioritize: false,
+            backlog_cap: default_upload_backlog_cap(),
+            persist_idempotency_keys: true,
+        }
+    }
+}
+
+// ── LoopWatchdogConfig ─────────────────────────────────────────────
+
+/// Detects scheduler loops that stopped ticking (e.g. the task panicked).
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoopWatchdogConfig {
+    #[serde(default = "default_true")]
+    pub enabled: bool,
+    /// How often the watchdog inspects loop heartbeats.
+    #[serde(default = "default_watchdog_check_interval_secs")]
+    pub check_interval_secs: u64,
+    /// A loop is stalled once it has been silent for this many of its own intervals.
+    #[serde(default = "default_watchdog_stall_multiplier")]
+    pub stall_multiplier: u32,
+    /// Respawn loops whose task has exited. Loops holding non-reconstructible
+    /// state (the monitor loop) are only reported.
+    #[serde(default)]
+    pub auto_restart: bool,
+}
+
+impl Default for LoopWatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            check_interval_secs: default_watchdog_check_interval_secs(),
+            stall_multiplier: default_watchdog_stall_multiplier(),
+            auto_restart: false,
+        }
+    }
+}
+
+// ── SchedulerLoopsConfig ───────────────────────────────────────────
+
+/// Which scheduler loops are spawned. Everything is on by default.
+///
+/// Retention and SQLite maintenance normally run inside the aggregation loop;
+/// when aggregation is off they move to a standalone maintenance loop so
+/// storage stays bounded. Only `maintenance = false` stops them.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct SchedulerLoopsConfig {
+    /// Window/activity monitor loop (also drives capture).
+    #[serde(default = "default_true")]
+    pub monitoring: bool,
+    #[serde(default = "default_true")]
+    pub metrics: bool,
+    #[serde(default = "default_true")]
+    pub process: bool,
+    /// Screen capture inside the monitor loop.
+    #[serde(default = "default_true")]
+    pub capture: bool,
+    #[serde(default = "default_true")]
+    pub heartbeat: bool,
+    /// Hourly rollups, digests and index upkeep.
+    #[serde(default = "default_true")]
+    pub aggregation: bool,
+    #[serde(default = "default_true")]
+    pub notifications: bool,
+    #[serde(default = "default_true")]
+    pub focus: bool,
+    /// Detailed process and input-activity snapshots.
+    #[serde(default = "default_true")]
+    pub detailed_events: bool,
+    /// Data retention, WAL checkpoint and VACUUM.
+    #[serde(default = "default_true")]
+    pub maintenance: bool,
+}
+
+impl Default for SchedulerLoopsConfig {
+    fn default() -> Self {
+        Self {
+            monitoring: true,
+            metrics: true,
+            process: true,
+            capture: true,
+            heartbeat: true,
+            aggregation: true,
+            notifications: true,
+            focus: true,
+            detailed_events: true,
+            maintenance: true,
+        }
+    }
+}
+
+impl SchedulerLoopsConfig {
+    /// Whether the scheduler loop registered as `loop_name` should be spawned.
+    /// Loops without a toggle are always enabled.
+    pub fn is_enabled(&self, loop_name: &str) -> bool {
+        match loop_name {
+            "monitor" => self.monitoring,
+            "metrics" => self.metrics,
+            "process" => self.process,
+            "heartbeat" => self.heartbeat,
+            "aggregation" => self.aggregation,
+            "notification" => self.notifications,
+            "focus" => self.focus,
+            "event_snapshot" => self.detailed_events,
+            "maintenance" => self.needs_standalone_maintenance(),
+            _ => true,
+        }
+    }
+
+    /// Capture runs inside the monitor loop, so it needs both flags.
+    pub fn capture_enabled(&self) -> bool {
+        self.monitoring && self.capture
+    }
+
+    /// Retention must keep running when the aggregation loop that normally
+    /// hosts it is disabled.
+    pub fn needs_standalone_maintenance(&self) -> bool {
+        self.maintenance && !self.aggregation
+    }
 }
 
 // ── VisionConfig ───────────────────────────────────────────────────
@@ -42,15 +295,128 @@ pub struct VisionConfig {
     pub ocr_enabled: bool,
     #[serde(default)]
     pub privacy_mode: bool,
+    /// Run OCR on a copy downscaled to this width (aspect ratio kept).
+    /// Stored images are unaffected and OCR boxes are mapped back to
+    /// full-frame coordinates. `None` OCRs the full frame.
+    #[serde(default)]
+    pub ocr_downscale_width: Option<u32>,
+    /// Longest OCR text stored per frame; longer text is cut at a word
+    /// boundary. Keeps the database and FTS index lean for dense screens.
+    /// `None` stores the full text.
+    #[serde(default = "default_ocr_max_chars")]
+    pub ocr_max_chars: Option<usize>,
+    /// Non-window events that may also trigger a capture.
+    #[serde(default)]
+    pub signal_triggers: CaptureSignalTriggers,
+    /// Capture after this many seconds of continuous focus on one window
+    /// without a capture, so long reads still get frames. `None` disables.
+    #[serde(default)]
+    pub dwell_capture_secs: Option<u64>,
+    /// Bookmark hotkey behaviour.
+    #[serde(default)]
+    pub bookmarks: BookmarkCaptureConfig,
+}
+
+/// What the bookmark hotkey records besides the timestamp.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BookmarkCaptureConfig {
+    /// Capture a frame and link it to the bookmark.
+    #[serde(default = "default_true")]
+    pub capture_frame: bool,
+    /// Ask for a label before recording instead of recording right away.
+    #[serde(default)]
+    pub prompt_label: bool,
+}
+
+impl Default for BookmarkCaptureConfig {
+    fn default() -> Self {
+        Self {
+            capture_frame: true,
+            prompt_label: false,
+        }
+    }
 }
 
+/// Opt-in capture triggers beyond window and title changes. Both off by
+/// default since they raise capture volume.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaptureSignalTriggers {
+    /// Capture when clipboard content changes.
+    #[serde(default)]
+    pub clipboard: bool,
+    /// Capture when a watched file is saved.
+    #[serde(default)]
+    pub file_save: bool,
+}
+
+/// Below this width text becomes too small for Tesseract to read reliably.
+pub const MIN_OCR_DOWNSCALE_WIDTH: u32 = 640;
+
+/// Smallest accepted `ocr_max_chars`; shorter caps lose most search value.
+pub const MIN_OCR_MAX_CHARS: usize = 256;
+
+/// Shortest accepted `dwell_capture_secs`; anything shorter behaves like a
+/// fixed-interval capture.
+pub const MIN_DWELL_CAPTURE_SECS: u64 = 30;
+
 impl VisionConfig {
     /// Validate that vision configuration values are within acceptable bounds.
     pub fn validate_bounds(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        self.validate_into(&mut errors);
+        into_bounds_result(errors)
+    }
+
+    /// Push every out-of-bounds vision value onto `errors`.
+    pub fn validate_into(&self, errors: &mut Vec<ConfigValidationError>) {
         if self.capture_throttle_ms < 100 {
-            return Err("vision.capture_throttle_ms must be >= 100".to_string());
+            errors.push(ConfigValidationError::new(
+                "vision.capture_throttle_ms",
+                self.capture_throttle_ms,
+                "must be >= 100",
+            ));
+        }
+        if self.thumbnail_width == 0 {
+            errors.push(ConfigValidationError::new(
+                "vision.thumbnail_width",
+                self.thumbnail_width,
+                "must be > 0",
+            ));
+        }
+        if self.thumbnail_height == 0 {
+            errors.push(ConfigValidationError::new(
+                "vision.thumbnail_height",
+                self.thumbnail_height,
+                "must be > 0",
+            ));
+        }
+        if let Some(width) = self
+            .ocr_downscale_width
+            .filter(|&w| w < MIN_OCR_DOWNSCALE_WIDTH)
+        {
+            errors.push(ConfigValidationError::new(
+                "vision.ocr_downscale_width",
+                width,
+                format!("must be >= {MIN_OCR_DOWNSCALE_WIDTH}"),
+            ));
+        }
+        if let Some(max_chars) = self.ocr_max_chars.filter(|&n| n < MIN_OCR_MAX_CHARS) {
+            errors.push(ConfigValidationError::new(
+                "vision.ocr_max_chars",
+                max_chars,
+                format!("must be >= {MIN_OCR_MAX_CHARS}"),
+            ));
+        }
+        if let Some(secs) = self
+            .dwell_capture_secs
+            .filter(|&secs| secs < MIN_DWELL_CAPTURE_SECS)
+        {
+            errors.push(ConfigValidationError::new(
+                "vision.dwell_capture_secs",
+                secs,
+                format!("must be >= {MIN_DWELL_CAPTURE_SECS}"),
+            ));
         }
-        Ok(())
     }
 }
 
@@ -66,10 +432,18 @@ pub struct ScheduleConfig {
     pub active_end_hour: u8,
     #[serde(default = "default_active_days")]
     pub active_days: Vec<Weekday>,
+    /// Skip capture and context collection while the screen is locked.
     #[serde(default = "default_true")]
     pub pause_on_screen_lock: bool,
     #[serde(default)]
     pub pause_on_battery_saver: bool,
+    /// Skip capture, OCR and frame encoding while on battery below this
+    /// charge percentage. Monitoring and metrics continue. `None` disables.
+    #[serde(default)]
+    pub min_battery_percent_for_capture: Option<u8>,
+    /// Record screen lock/unlock transitions as system events.
+    #[serde(default)]
+    pub record_screen_lock_events: bool,
 }
 
 impl Default for ScheduleConfig {
@@ -81,6 +455,39 @@ impl Default for ScheduleConfig {
             active_days: default_active_days(),
             pause_on_screen_lock: true,
             pause_on_battery_saver: false,
+            min_battery_percent_for_capture: None,
+            record_screen_lock_events: false,
+        }
+    }
+}
+
+impl ScheduleConfig {
+    /// Return `true` if `now` falls inside the active-hours window, or if the
+    /// window is disabled.
+    ///
+    /// The window is half-open `[start, end)` in whole hours. When `end < start`
+    /// it wraps midnight and the early-morning part belongs to the previous
+    /// day's entry in `active_days`. `start == end` is an empty window.
+    pub fn is_active_at<Tz: TimeZone>(&self, now: DateTime<Tz>) -> bool {
+        if !self.active_hours_enabled {
+            return true;
+        }
+
+        let hour = now.hour() as u8;
+        let weekday = chrono_weekday_to_ours(now.weekday());
+        let start = self.active_start_hour;
+        let end = self.active_end_hour;
+
+        if end > start {
+            self.active_days.contains(&weekday) && hour >= start && hour < end
+        } else if end < start {
+            if hour >= start {
+                self.active_days.contains(&weekday)
+            } else {
+                hour < end && self.active_days.iter().any(|&d| weekday_succ(d) == weekday)
+            }
+        } else {
+            false
         }
     }
 }
@@ -132,6 +539,26 @@ pub(crate) fn default_process_interval_secs() -> u64 {
     10
 }
 
+pub(crate) fn default_metrics_interval_ms() -> u64 {
+    5_000
+}
+
+fn default_upload_backlog_cap() -> usize {
+    10_000
+}
+
+fn default_context_sampling_window_ms() -> u64 {
+    5_000
+}
+
+fn default_watchdog_check_interval_secs() -> u64 {
+    30
+}
+
+fn default_watchdog_stall_multiplier() -> u32 {
+    3
+}
+
 pub(crate) fn default_capture_enabled() -> bool {
     true
 }
@@ -148,6 +575,10 @@ pub(crate) fn default_thumbnail_height() -> u32 {
     270
 }
 
+pub(crate) fn default_ocr_max_chars() -> Option<usize> {
+    Some(8_000)
+}
+
 // ── Private default helpers ─────────────────────────────────────────
 
 fn default_true() -> bool {
@@ -184,3 +615,104 @@ fn default_excluded_extensions() -> Vec<String> {
 fn default_max_events_per_minute() -> u32 {
     100
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{FixedOffset, NaiveDate};
+
+    #[test]
+    fn scheduler_loops_default_to_enabled() {
+        let loops = SchedulerLoopsConfig::default();
+        for name in ["monitor", "metrics", "process", "aggregation", "sync"] {
+            assert!(loops.is_enabled(name), "{name}");
+        }
+        assert!(loops.capture_enabled());
+        assert!(!loops.is_enabled("maintenance"));
+    }
+
+    #[test]
+    fn disabled_loop_is_not_enabled() {
+        let loops: SchedulerLoopsConfig =
+            serde_json::from_str(r#"{"metrics": false, "detailed_events": false}"#).unwrap();
+        assert!(!loops.is_enabled("metrics"));
+        assert!(!loops.is_enabled("event_snapshot"));
+        assert!(loops.is_enabled("process"));
+    }
+
+    #[test]
+    fn maintenance_survives_disabled_aggregation() {
+        let loops = SchedulerLoopsConfig {
+            aggregation: false,
+            ..SchedulerLoopsConfig::default()
+        };
+        assert!(loops.is_enabled("maintenance"));
+
+        let off = SchedulerLoopsConfig {
+            aggregation: false,
+            maintenance: false,
+            ..SchedulerLoopsConfig::default()
+        };
+        assert!(!off.is_enabled("maintenance"));
+    }
+
+    #[test]
+    fn capture_requires_monitor_loop() {
+        let loops = SchedulerLoopsConfig {
+            monitoring: false,
+            ..SchedulerLoopsConfig::default()
+        };
+        assert!(!loops.capture_enabled());
+    }
+
+    /// 2024-11-11 is a Monday; `day_offset` moves forward through the week.
+    fn at(day_offset: u32, hour: u32) -> DateTime<FixedOffset> {
+        FixedOffset::east_opt(0)
+            .unwrap()
+            .from_local_datetime(
+                &NaiveDate::from_ymd_opt(2024, 11, 11 + day_offset)
+                    .unwrap()
+                    .and_hms_opt(hour, 0, 0)
+                    .unwrap(),
+            )
+            .unwrap()
+    }
+
+    fn business_hours() -> ScheduleConfig {
+        ScheduleConfig {
+            active_hours_enabled: true,
+            ..ScheduleConfig::default()
+        }
+    }
+
+    #[test]
+    fn disabled_schedule_is_always_active() {
+        let schedule = ScheduleConfig::default();
+        assert!(schedule.is_active_at(at(5, 3)));
+    }
+
+    #[test]
+    fn business_hours_window_is_half_open() {
+        let schedule = business_hours();
+        assert!(schedule.is_active_at(at(0, 9)));
+        assert!(schedule.is_active_at(at(0, 17)));
+        assert!(!schedule.is_active_at(at(0, 18)));
+        assert!(!schedule.is_active_at(at(0, 8)));
+        // Saturday is not in the default weekday list.
+        assert!(!schedule.is_active_at(at(5, 12)));
+    }
+
+    #[test]
+    fn overnight_window_carries_over_to_next_day() {
+        let schedule = ScheduleConfig {
+            active_start_hour: 22,
+            active_end_hour: 6,
+            active_days: vec![Weekday::Fri],
+            ..business_hours()
+        };
+        assert!(schedule.is_active_at(at(4, 23)));
+        assert!(schedule.is_active_at(at(5, 2)));
+        assert!(!schedule.is_active_at(at(5, 23)));
+        assert!(!schedule.is_active_at(at(4, 2)));
+    }
+}
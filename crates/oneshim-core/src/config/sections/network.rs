@@ -1,4 +1,5 @@
 // 네트워크 연결 설정 — 서버/gRPC/TLS/Web 설정 모음
+use super::super::validation::{into_bounds_result, ConfigValidationError};
 use serde::{Deserialize, Serialize};
 
 // ── TlsConfig ──────────────────────────────────────────────────────
@@ -7,6 +8,8 @@ use serde::{Deserialize, Serialize};
 ///
 /// 기본값: enabled=true (TLS 강제), allow_self_signed=false (운영 환경 표준).
 /// 개발 환경에서는 allow_self_signed=true 또는 enabled=false 로 설정 가능.
+/// mTLS 서버에는 client_cert_pem (PEM 본문) + client_key_path (키 파일 경로)를 함께 지정한다.
+/// 개인 키 본문은 config.json 에 두지 않는다.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TlsConfig {
@@ -16,6 +19,20 @@ pub struct TlsConfig {
     /// 자체 서명 인증서 허용 — 운영 환경에서는 반드시 false 유지
     #[serde(default)]
     pub allow_self_signed: bool,
+    /// mTLS 클라이언트 인증서 (PEM, 체인 포함 가능) — client_key_path 와 함께 설정
+    #[serde(default)]
+    pub client_cert_pem: Option<String>,
+    /// mTLS 클라이언트 개인 키 파일 경로 (PKCS#8 PEM, `BEGIN PRIVATE KEY`).
+    /// Unix 에서는 소유자 외 접근 권한이 있으면 거부한다 (`chmod 600`).
+    #[serde(default)]
+    pub client_key_path: Option<String>,
+    /// 구버전 config.json 의 인라인 개인 키 (PEM). 로드 시 소유자 전용 파일로
+    /// 옮겨 client_key_path 로 바뀐다. 옮기지 못한 경우에만 남아 다음 실행에서 재시도.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub client_key_pem: Option<String>,
+    /// 서버 인증서 검증에 추가할 CA 번들 (PEM) — 사설 CA 자체 호스팅용
+    #[serde(default)]
+    pub ca_cert_pem: Option<String>,
 }
 
 impl Default for TlsConfig {
@@ -23,10 +40,57 @@ impl Default for TlsConfig {
         Self {
             enabled: true,
             allow_self_signed: false,
+            client_cert_pem: None,
+            client_key_path: None,
+            client_key_pem: None,
+            ca_cert_pem: None,
         }
     }
 }
 
+// ── EgressConfig ───────────────────────────────────────────────────
+
+/// Outbound host allowlist shared by the API client, SSE stream and updater.
+///
+/// Disabled by default. When enabled, requests to any host not listed (or a
+/// subdomain of a listed host) are blocked and logged. Loopback is always allowed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct EgressConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default)]
+    pub allowed_hosts: Vec<String>,
+}
+
+impl EgressConfig {
+    /// Returns `true` if requests to `host` are permitted by this config.
+    pub fn is_allowed_host(&self, host: &str) -> bool {
+        !self.enabled || is_loopback_host(host) || host_matches_allowlist(host, &self.allowed_hosts)
+    }
+}
+
+/// Returns `true` if `host` equals an entry of `allowed` or is a subdomain of one.
+pub fn host_matches_allowlist<S: AsRef<str>>(host: &str, allowed: &[S]) -> bool {
+    let host = host.trim_end_matches('.');
+    allowed.iter().any(|entry| {
+        let entry = entry.as_ref().trim_end_matches('.');
+        let Some(split) = host.len().checked_sub(entry.len()) else {
+            return false;
+        };
+        host[split..].eq_ignore_ascii_case(entry)
+            && (split == 0 || host.as_bytes()[split - 1] == b'.')
+    })
+}
+
+fn is_loopback_host(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost")
+        || host
+            .trim_start_matches('[')
+            .trim_end_matches(']')
+            .parse::<std::net::IpAddr>()
+            .is_ok_and(|ip| ip.is_loopback())
+}
+
 // ── ServerConfig ───────────────────────────────────────────────────
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -36,6 +100,112 @@ pub struct ServerConfig {
     pub request_timeout_ms: u64,
     #[serde(default = "default_sse_max_retry_secs")]
     pub sse_max_retry_secs: u64,
+    /// Floor of the SSE reconnect backoff; the first retry waits at least this long.
+    #[serde(default = "default_sse_min_retry_secs")]
+    pub sse_min_retry_secs: u64,
+    /// Randomized share of each SSE reconnect delay (0.0–1.0). Spreads a fleet's
+    /// reconnects after a server restart instead of retrying in lockstep.
+    #[serde(default = "default_sse_retry_jitter_ratio")]
+    pub sse_retry_jitter_ratio: f64,
+    /// Warn when the server clock (from the `Date` header on login and
+    /// heartbeat responses) differs from the local clock by more than this.
+    #[serde(default = "default_clock_skew_warn_secs")]
+    pub clock_skew_warn_secs: u64,
+    /// Servers to switch to, in order, when `base_url` keeps failing.
+    #[serde(default)]
+    pub standby_servers: Vec<StandbyServerConfig>,
+    /// Consecutive failed requests (after their retries) before switching to
+    /// the next server.
+    #[serde(default = "default_failover_after_failures")]
+    pub failover_after_failures: u32,
+    /// Seconds between probes of `base_url` while a standby is active.
+    #[serde(default = "default_failback_probe_secs")]
+    pub failback_probe_secs: u64,
+    /// Client-side encryption of uploaded frame/OCR content.
+    #[serde(default)]
+    pub content_encryption: ContentEncryptionConfig,
+}
+
+/// Envelope encryption of uploaded content to a per-tenant public key.
+///
+/// When enabled, OCR text and frame images are sealed with an ephemeral
+/// X25519 key agreement + AES-256-GCM before upload; only the holder of the
+/// tenant private key can read them. Frame metadata stays in clear so the
+/// server can still index uploads.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ContentEncryptionConfig {
+    pub enabled: bool,
+    /// Tenant X25519 public key, base64 (32 bytes).
+    pub public_key: String,
+    /// Sent with each envelope so the server can pick the matching private key.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+}
+
+impl ContentEncryptionConfig {
+    /// Decoded public key, or `None` if it is not 32 bytes of base64.
+    pub fn public_key_bytes(&self) -> Option<[u8; 32]> {
+        use base64::Engine as _;
+        base64::engine::general_purpose::STANDARD
+            .decode(self.public_key.trim())
+            .ok()?
+            .try_into()
+            .ok()
+    }
+}
+
+/// A standby ONESHIM server used when the primary is unreachable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StandbyServerConfig {
+    pub base_url: String,
+    /// gRPC endpoint of this server; `None` keeps `grpc.grpc_endpoint`.
+    #[serde(default)]
+    pub grpc_endpoint: Option<String>,
+}
+
+impl ServerConfig {
+    pub fn validate_bounds(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        self.validate_into(&mut errors);
+        into_bounds_result(errors)
+    }
+
+    /// Push every out-of-bounds server value onto `errors`.
+    pub fn validate_into(&self, errors: &mut Vec<ConfigValidationError>) {
+        if self.failover_after_failures < 1 {
+            errors.push(ConfigValidationError::new(
+                "server.failover_after_failures",
+                self.failover_after_failures,
+                "must be >= 1",
+            ));
+        }
+        if self.failback_probe_secs < 10 {
+            errors.push(ConfigValidationError::new(
+                "server.failback_probe_secs",
+                self.failback_probe_secs,
+                "must be >= 10",
+            ));
+        }
+        for (index, standby) in self.standby_servers.iter().enumerate() {
+            if !(standby.base_url.starts_with("http://")
+                || standby.base_url.starts_with("https://"))
+            {
+                errors.push(ConfigValidationError::new(
+                    format!("server.standby_servers[{index}].base_url"),
+                    &standby.base_url,
+                    "must be http(s)",
+                ));
+            }
+        }
+        if self.content_encryption.enabled && self.content_encryption.public_key_bytes().is_none() {
+            errors.push(ConfigValidationError::new(
+                "server.content_encryption.public_key",
+                &self.content_encryption.public_key,
+                "must be a base64 X25519 public key (32 bytes)",
+            ));
+        }
+    }
 }
 
 // ── GrpcConfig ─────────────────────────────────────────────────────
@@ -118,6 +288,17 @@ pub struct WebConfig {
     /// `Status::resource_exhausted`.
     #[serde(default = "default_max_concurrent_streams")]
     pub grpc_max_concurrent_streams: usize,
+    /// Resized/re-encoded frame images kept in memory by the dashboard.
+    /// `0` disables the cache.
+    #[serde(default = "default_frame_image_cache_entries")]
+    pub frame_image_cache_entries: usize,
+    /// HMAC key for read-only report share links. Generated when the
+    /// first link is created; rotating it invalidates every issued link.
+    #[serde(default)]
+    pub share_link_secret: Option<String>,
+    /// Ids of revoked share links.
+    #[serde(default)]
+    pub revoked_share_links: Vec<String>,
 }
 
 impl Default for WebConfig {
@@ -131,6 +312,9 @@ impl Default for WebConfig {
             grpc_load_thresholds: None,
             grpc_streaming_enabled: true,
             grpc_max_concurrent_streams: default_max_concurrent_streams(),
+            frame_image_cache_entries: default_frame_image_cache_entries(),
+            share_link_secret: None,
+            revoked_share_links: Vec::new(),
         }
     }
 }
@@ -176,6 +360,26 @@ pub(crate) fn default_sse_max_retry_secs() -> u64 {
     30
 }
 
+pub(crate) fn default_sse_min_retry_secs() -> u64 {
+    1
+}
+
+pub(crate) fn default_sse_retry_jitter_ratio() -> f64 {
+    0.5
+}
+
+pub(crate) fn default_clock_skew_warn_secs() -> u64 {
+    30
+}
+
+pub(crate) fn default_failover_after_failures() -> u32 {
+    3
+}
+
+pub(crate) fn default_failback_probe_secs() -> u64 {
+    60
+}
+
 // ── Private default helpers ─────────────────────────────────────────
 
 fn default_true() -> bool {
@@ -253,10 +457,39 @@ fn default_max_concurrent_streams() -> usize {
     50
 }
 
+fn default_frame_image_cache_entries() -> usize {
+    128
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn host_matches_allowlist_exact_and_subdomains_only() {
+        let allowed = ["github.com"];
+        assert!(host_matches_allowlist("github.com", &allowed));
+        assert!(host_matches_allowlist("api.GitHub.com", &allowed));
+        assert!(!host_matches_allowlist("not-github.com", &allowed));
+        assert!(!host_matches_allowlist("github.com.evil.net", &allowed));
+        assert!(!host_matches_allowlist("hub.com", &allowed));
+    }
+
+    #[test]
+    fn egress_config_allows_loopback_and_listed_hosts_only_when_enabled() {
+        assert!(EgressConfig::default().is_allowed_host("anything.example.org"));
+
+        let config = EgressConfig {
+            enabled: true,
+            allowed_hosts: vec!["api.oneshim.dev".to_string()],
+        };
+        assert!(config.is_allowed_host("api.oneshim.dev"));
+        assert!(config.is_allowed_host("localhost"));
+        assert!(config.is_allowed_host("127.0.0.1"));
+        assert!(config.is_allowed_host("[::1]"));
+        assert!(!config.is_allowed_host("telemetry.example.com"));
+    }
+
     #[test]
     fn load_thresholds_default_values() {
         let t = LoadThresholds::default();
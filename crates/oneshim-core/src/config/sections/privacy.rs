@@ -1,5 +1,7 @@
 // 개인정보/격리 설정 — PII 필터 수준, 자동화 샌드박스, 제외 앱 목록
 use super::super::enums::{PiiFilterLevel, SandboxProfile};
+use super::super::validation::{check_patterns, into_bounds_result, ConfigValidationError};
+use super::monitoring::ScheduleConfig;
 use serde::{Deserialize, Serialize};
 
 // ── PrivacyConfig ──────────────────────────────────────────────────
@@ -8,14 +10,74 @@ use serde::{Deserialize, Serialize};
 pub struct PrivacyConfig {
     #[serde(default)]
     pub excluded_apps: Vec<String>,
+    /// Globs or `re:` regexes matched against the app name; see
+    /// [`crate::title_pattern`].
     #[serde(default)]
     pub excluded_app_patterns: Vec<String>,
+    /// Globs or `re:` regexes matched against the active window title, for
+    /// excluding single windows (e.g. a browser tab) of an otherwise
+    /// captured app.
     #[serde(default)]
     pub excluded_title_patterns: Vec<String>,
     #[serde(default = "default_true")]
     pub auto_exclude_sensitive: bool,
     #[serde(default)]
     pub pii_filter_level: PiiFilterLevel,
+    /// Blur the area where the OS shows notification banners before a frame
+    /// is encoded, so message previews are not stored.
+    #[serde(default)]
+    pub mask_notification_banners: bool,
+    /// Fixed top-right area to blur instead of the per-OS banner guess. Also
+    /// the only area masked on platforms without a known banner position.
+    #[serde(default)]
+    pub notification_mask_region: Option<NotificationMaskRegion>,
+    /// Keep all captured content on this device: frame, OCR and other
+    /// content uploads are refused regardless of online mode. Metrics and
+    /// activity metadata still upload when uploads are otherwise enabled.
+    #[serde(default)]
+    pub local_only: bool,
+    /// Low-detail presence stream sent to live dashboard viewers.
+    #[serde(default)]
+    pub preview: PrivacyPreviewConfig,
+}
+
+/// Downscaled, blurred frame previews on the realtime channel: enough to
+/// recognise window layout, not enough to read text.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct PrivacyPreviewConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Longest preview side in pixels.
+    #[serde(default = "default_preview_max_dimension")]
+    pub max_dimension: u32,
+    /// Gaussian blur sigma applied after downscaling, in preview pixels.
+    #[serde(default = "default_preview_blur_sigma")]
+    pub blur_sigma: f32,
+}
+
+fn default_preview_max_dimension() -> u32 {
+    96
+}
+
+fn default_preview_blur_sigma() -> f32 {
+    2.0
+}
+
+impl Default for PrivacyPreviewConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            max_dimension: default_preview_max_dimension(),
+            blur_sigma: default_preview_blur_sigma(),
+        }
+    }
+}
+
+/// Size in pixels of the top-right frame area masked for notifications.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NotificationMaskRegion {
+    pub width: u32,
+    pub height: u32,
 }
 
 impl Default for PrivacyConfig {
@@ -26,6 +88,58 @@ impl Default for PrivacyConfig {
             excluded_title_patterns: Vec::new(),
             auto_exclude_sensitive: true,
             pii_filter_level: PiiFilterLevel::Standard,
+            mask_notification_banners: false,
+            notification_mask_region: None,
+            local_only: false,
+            preview: PrivacyPreviewConfig::default(),
+        }
+    }
+}
+
+impl PrivacyConfig {
+    /// Reject exclusion patterns that do not compile.
+    pub fn validate_bounds(&self) -> Result<(), String> {
+        let mut errors = Vec::new();
+        self.validate_into(&mut errors);
+        into_bounds_result(errors)
+    }
+
+    /// Push every invalid privacy value onto `errors`.
+    pub fn validate_into(&self, errors: &mut Vec<ConfigValidationError>) {
+        check_patterns(
+            "privacy.excluded_app_patterns",
+            &self.excluded_app_patterns,
+            errors,
+        );
+        check_patterns(
+            "privacy.excluded_title_patterns",
+            &self.excluded_title_patterns,
+            errors,
+        );
+        if let Some(region) = self
+            .notification_mask_region
+            .filter(|region| region.width == 0 || region.height == 0)
+        {
+            errors.push(ConfigValidationError::new(
+                "privacy.notification_mask_region",
+                region,
+                "must have a non-zero size",
+            ));
+        }
+        if !(16..=512).contains(&self.preview.max_dimension) {
+            errors.push(ConfigValidationError::new(
+                "privacy.preview.max_dimension",
+                self.preview.max_dimension,
+                "must be between 16 and 512",
+            ));
+        }
+        if !(self.preview.blur_sigma.is_finite() && (0.5..=32.0).contains(&self.preview.blur_sigma))
+        {
+            errors.push(ConfigValidationError::new(
+                "privacy.preview.blur_sigma",
+                self.preview.blur_sigma,
+                "must be between 0.5 and 32",
+            ));
         }
     }
 }
@@ -89,6 +203,21 @@ pub struct AutomationConfig {
     pub custom_presets: Vec<crate::models::intent::WorkflowPreset>,
     #[serde(default)]
     pub confirmation_policy: AutomationConfirmPolicy,
+    /// Time-of-day/day-of-week window in which commands and workflows may run.
+    /// Independent of the monitoring `schedule`; disabled means no restriction.
+    #[serde(default)]
+    pub allowed_hours: ScheduleConfig,
+    /// How long a fetched execution-policy set stays valid, in seconds.
+    /// `None` keeps the built-in 5 minute default; `0` disables caching.
+    #[serde(default)]
+    pub policy_cache_ttl_secs: Option<u64>,
+    /// Accept commands pushed by the server over the automation WebSocket.
+    #[serde(default)]
+    pub command_channel_enabled: bool,
+    /// Capture a screen frame right before and after each command and
+    /// workflow step; the frame ids are linked in the audit log.
+    #[serde(default)]
+    pub capture_step_frames: bool,
 }
 
 // ── Private default helpers ─────────────────────────────────────────
@@ -0,0 +1,66 @@
+// 설정 프로필 — 이름 있는 부분 설정 오버레이
+use super::super::validation::ConfigValidationError;
+use super::super::AppConfig;
+use serde::{Deserialize, Serialize};
+
+/// Partial config applied by a named profile (`profiles.<name>`).
+///
+/// Every field is optional; unset fields leave the base config untouched.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ConfigOverlay {
+    /// `vision.capture_enabled`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub capture_enabled: Option<bool>,
+    /// `vision.ocr_enabled`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ocr_enabled: Option<bool>,
+    /// `monitor.upload_enabled`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub upload_enabled: Option<bool>,
+    /// `monitor.poll_interval_ms`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub poll_interval_ms: Option<u64>,
+    /// `sync.enabled`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sync_enabled: Option<bool>,
+    /// `notification.enabled`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notifications_enabled: Option<bool>,
+    /// `suggestions.enabled`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suggestions_enabled: Option<bool>,
+    /// `audio.enabled`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub audio_enabled: Option<bool>,
+}
+
+impl ConfigOverlay {
+    /// Copy every set field onto `config`.
+    pub fn apply_to(&self, config: &mut AppConfig) {
+        fn set<T: Copy>(target: &mut T, value: Option<T>) {
+            if let Some(value) = value {
+                *target = value;
+            }
+        }
+        set(&mut config.vision.capture_enabled, self.capture_enabled);
+        set(&mut config.vision.ocr_enabled, self.ocr_enabled);
+        set(&mut config.monitor.upload_enabled, self.upload_enabled);
+        set(&mut config.monitor.poll_interval_ms, self.poll_interval_ms);
+        set(&mut config.sync.enabled, self.sync_enabled);
+        set(&mut config.notification.enabled, self.notifications_enabled);
+        set(&mut config.suggestions.enabled, self.suggestions_enabled);
+        set(&mut config.audio.enabled, self.audio_enabled);
+    }
+
+    /// Push every invalid overlay value onto `errors`, under `profiles.<name>`.
+    pub fn validate_into(&self, name: &str, errors: &mut Vec<ConfigValidationError>) {
+        if self.poll_interval_ms == Some(0) {
+            errors.push(ConfigValidationError::new(
+                format!("profiles.{name}.poll_interval_ms"),
+                0u64,
+                "must be > 0",
+            ));
+        }
+    }
+}
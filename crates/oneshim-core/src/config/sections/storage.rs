@@ -1,7 +1,11 @@
 // 스토리지/무결성/알림/업데이트/텔레메트리 설정 — 데이터 생명주기 및 시스템 상태 관리
+use super::super::enums::{DbKeySource, FrameShardGranularity};
+use super::super::validation::{config_test_mode, into_bounds_result, ConfigValidationError};
 use crate::error::CoreError;
+use crate::models::work_session::AppCategory;
 use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
 
 // ── StorageConfig ──────────────────────────────────────────────────
@@ -11,20 +15,106 @@ pub struct StorageConfig {
     pub db_path: Option<PathBuf>,
     #[serde(default = "default_retention_days")]
     pub retention_days: u32,
+    /// Event retention per app category, e.g. `{"communication": 7}`.
+    /// Apps are classified as work sessions are (including
+    /// `monitor.category_overrides`); other events keep `retention_days`.
+    #[serde(default)]
+    pub retention_overrides: HashMap<AppCategory, u32>,
     #[serde(default = "default_max_storage_mb")]
     pub max_storage_mb: u64,
+    /// How frame files are split into date subdirectories. Frames written
+    /// under an earlier layout stay readable and are still evicted.
+    #[serde(default)]
+    pub frame_shard_granularity: FrameShardGranularity,
+    /// Frames carrying any of these tags (case-insensitive) are never
+    /// evicted by age or size retention. They still count toward
+    /// `max_storage_mb`.
+    #[serde(default = "default_retention_keep_tags")]
+    pub retention_keep_tags: Vec<String>,
+    /// zstd-compress frame OCR text at least this many bytes long before
+    /// writing it to SQLite. `None` stores all text uncompressed; rows
+    /// written either way stay readable after the setting changes.
+    #[serde(default)]
+    pub text_compression_min_bytes: Option<usize>,
+    /// Hours between scheduled database snapshots; 0 disables them.
+    /// Snapshots land in a `backups` directory next to the database.
+    #[serde(default)]
+    pub db_backup_interval_hours: u32,
+    /// Number of database snapshots kept; older ones are deleted.
+    #[serde(default = "default_db_backup_keep")]
+    pub db_backup_keep: usize,
+    /// Free space (MB) on the data volume below which frame image writes
+    /// stop. Metadata and events keep being recorded.
+    #[serde(default = "default_disk_critical_free_mb")]
+    pub disk_critical_free_mb: u64,
+    /// Free space (MB) at which suspended image writes resume. Kept above
+    /// `disk_critical_free_mb` so writes do not flap at the boundary.
+    #[serde(default = "default_disk_resume_free_mb")]
+    pub disk_resume_free_mb: u64,
+    /// Source of the SQLCipher key. Switching it on an existing database
+    /// does not re-key the file; the database then opens unreadable.
+    #[serde(default)]
+    pub db_key_source: DbKeySource,
 }
 
+/// Smallest accepted `max_storage_mb`; below this the pruner thrashes.
+pub const MIN_MAX_STORAGE_MB: u64 = 10;
+
 impl StorageConfig {
     /// Validate that storage configuration values are within acceptable bounds.
     pub fn validate_bounds(&self) -> Result<(), String> {
-        if self.retention_days < 1 {
-            return Err("storage.retention_days must be >= 1".to_string());
+        let mut errors = Vec::new();
+        self.validate_into(&mut errors);
+        into_bounds_result(errors)
+    }
+
+    /// Push every out-of-bounds storage value onto `errors`.
+    ///
+    /// `retention_days` of 0 is accepted only under [`config_test_mode`].
+    pub fn validate_into(&self, errors: &mut Vec<ConfigValidationError>) {
+        self.validate_into_with(config_test_mode(), errors);
+    }
+
+    pub(crate) fn validate_into_with(
+        &self,
+        test_mode: bool,
+        errors: &mut Vec<ConfigValidationError>,
+    ) {
+        if self.retention_days < 1 && !test_mode {
+            errors.push(ConfigValidationError::new(
+                "storage.retention_days",
+                self.retention_days,
+                "must be >= 1",
+            ));
         }
-        if self.max_storage_mb < 10 {
-            return Err("storage.max_storage_mb must be >= 10".to_string());
+        if self.retention_overrides.values().any(|days| *days < 1) && !test_mode {
+            errors.push(ConfigValidationError::new(
+                "storage.retention_overrides",
+                &self.retention_overrides,
+                "every window must be >= 1",
+            ));
+        }
+        if self.max_storage_mb < MIN_MAX_STORAGE_MB {
+            errors.push(ConfigValidationError::new(
+                "storage.max_storage_mb",
+                self.max_storage_mb,
+                format!("must be >= {MIN_MAX_STORAGE_MB}"),
+            ));
+        }
+        if self.db_backup_keep < 1 {
+            errors.push(ConfigValidationError::new(
+                "storage.db_backup_keep",
+                self.db_backup_keep,
+                "must be >= 1",
+            ));
+        }
+        if self.disk_resume_free_mb <= self.disk_critical_free_mb {
+            errors.push(ConfigValidationError::new(
+                "storage.disk_resume_free_mb",
+                self.disk_resume_free_mb,
+                "must be greater than disk_critical_free_mb",
+            ));
         }
-        Ok(())
     }
 }
 
@@ -304,6 +394,22 @@ pub(crate) fn default_max_storage_mb() -> u64 {
     500
 }
 
+pub(crate) fn default_retention_keep_tags() -> Vec<String> {
+    vec!["keep".to_string()]
+}
+
+pub(crate) fn default_db_backup_keep() -> usize {
+    7
+}
+
+pub(crate) fn default_disk_critical_free_mb() -> u64 {
+    50
+}
+
+pub(crate) fn default_disk_resume_free_mb() -> u64 {
+    100
+}
+
 // ── Private default helpers ─────────────────────────────────────────
 
 fn default_true() -> bool {
@@ -1,9 +1,156 @@
 use serde::{Deserialize, Serialize};
 
+use crate::models::suggestion::Priority;
+
 /// Configuration for real-time suggestion reception.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SuggestionConfig {
     /// Enable real-time suggestion reception from server.
     #[serde(default)]
     pub enabled: bool,
+    /// Local re-scoring of incoming suggestions against current activity.
+    #[serde(default)]
+    pub rescoring: SuggestionRescoringConfig,
+    /// What the pending queue gives up when it is already at
+    /// `analysis.max_suggestions`.
+    #[serde(default)]
+    pub overflow_policy: SuggestionOverflowPolicy,
+    /// Where accepted suggestions are surfaced, per priority.
+    #[serde(default)]
+    pub delivery: SuggestionDeliveryConfig,
+}
+
+/// A surface an accepted suggestion can be delivered to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestionDeliveryChannel {
+    /// OS-native desktop notification.
+    Notification,
+    /// Pending-count badge on the tray/overlay.
+    TrayBadge,
+    /// Dashboard list only. Every accepted suggestion is queued for the
+    /// dashboard regardless, so a priority mapped to just this channel is
+    /// delivered silently.
+    Dashboard,
+    /// JSON POST to `webhook_url`.
+    Webhook,
+}
+
+/// Per-priority delivery channels. The defaults match the behaviour before
+/// routing existed: every priority notifies and bumps the badge.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SuggestionDeliveryConfig {
+    #[serde(default = "default_interrupting_channels")]
+    pub critical: Vec<SuggestionDeliveryChannel>,
+    #[serde(default = "default_interrupting_channels")]
+    pub high: Vec<SuggestionDeliveryChannel>,
+    #[serde(default = "default_interrupting_channels")]
+    pub medium: Vec<SuggestionDeliveryChannel>,
+    #[serde(default = "default_interrupting_channels")]
+    pub low: Vec<SuggestionDeliveryChannel>,
+    /// Endpoint for the `webhook` channel. Priorities mapped to `webhook`
+    /// are not posted anywhere while this is unset.
+    #[serde(default)]
+    pub webhook_url: Option<String>,
+}
+
+impl Default for SuggestionDeliveryConfig {
+    fn default() -> Self {
+        Self {
+            critical: default_interrupting_channels(),
+            high: default_interrupting_channels(),
+            medium: default_interrupting_channels(),
+            low: default_interrupting_channels(),
+            webhook_url: None,
+        }
+    }
+}
+
+impl SuggestionDeliveryConfig {
+    pub fn channels_for(&self, priority: &Priority) -> &[SuggestionDeliveryChannel] {
+        match priority {
+            Priority::Critical => &self.critical,
+            Priority::High => &self.high,
+            Priority::Medium => &self.medium,
+            Priority::Low => &self.low,
+        }
+    }
+
+    pub fn delivers_to(&self, priority: &Priority, channel: SuggestionDeliveryChannel) -> bool {
+        self.channels_for(priority).contains(&channel)
+    }
+}
+
+/// Which suggestion a full queue discards when another arrives.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SuggestionOverflowPolicy {
+    /// Evict the lowest-priority (then oldest) item if the newcomer outranks
+    /// it; otherwise reject the newcomer.
+    #[default]
+    EvictLowestPriority,
+    /// Keep the queue as is and reject the newcomer.
+    DropNewest,
+    /// Evict the oldest item regardless of priority.
+    DropOldest,
+}
+
+/// Weights for adjusting a server suggestion's relevance using local context.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SuggestionRescoringConfig {
+    #[serde(default = "default_rescoring_enabled")]
+    pub enabled: bool,
+    /// Boost for work/workflow/productivity suggestions after a long
+    /// uninterrupted deep-work stretch.
+    #[serde(default = "default_dwell_weight")]
+    pub dwell_weight: f64,
+    /// Penalty for communication-style suggestions while in deep work.
+    #[serde(default = "default_interruption_penalty")]
+    pub interruption_penalty: f64,
+    /// Boost for communication-style suggestions while already communicating.
+    #[serde(default = "default_category_match_weight")]
+    pub category_match_weight: f64,
+    /// Deep-work minutes at which the dwell boost reaches its full weight.
+    #[serde(default = "default_dwell_saturation_mins")]
+    pub dwell_saturation_mins: u64,
+}
+
+impl Default for SuggestionRescoringConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_rescoring_enabled(),
+            dwell_weight: default_dwell_weight(),
+            interruption_penalty: default_interruption_penalty(),
+            category_match_weight: default_category_match_weight(),
+            dwell_saturation_mins: default_dwell_saturation_mins(),
+        }
+    }
+}
+
+fn default_interrupting_channels() -> Vec<SuggestionDeliveryChannel> {
+    vec![
+        SuggestionDeliveryChannel::Notification,
+        SuggestionDeliveryChannel::TrayBadge,
+        SuggestionDeliveryChannel::Dashboard,
+    ]
+}
+
+fn default_rescoring_enabled() -> bool {
+    true
+}
+
+fn default_dwell_weight() -> f64 {
+    0.2
+}
+
+fn default_interruption_penalty() -> f64 {
+    0.15
+}
+
+fn default_category_match_weight() -> f64 {
+    0.1
+}
+
+fn default_dwell_saturation_mins() -> u64 {
+    120
 }
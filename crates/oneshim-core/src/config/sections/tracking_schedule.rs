@@ -282,7 +282,7 @@ impl TrackingWindow {
 // ── Weekday conversion helpers ──────────────────────────────────────
 
 /// Convert a `chrono::Weekday` to our config `Weekday`.
-fn chrono_weekday_to_ours(w: chrono::Weekday) -> Weekday {
+pub(super) fn chrono_weekday_to_ours(w: chrono::Weekday) -> Weekday {
     match w {
         chrono::Weekday::Mon => Weekday::Mon,
         chrono::Weekday::Tue => Weekday::Tue,
@@ -295,7 +295,7 @@ fn chrono_weekday_to_ours(w: chrono::Weekday) -> Weekday {
 }
 
 /// Return the day after `d` (wrapping Sun → Mon).
-fn weekday_succ(d: Weekday) -> Weekday {
+pub(super) fn weekday_succ(d: Weekday) -> Weekday {
     match d {
         Weekday::Mon => Weekday::Tue,
         Weekday::Tue => Weekday::Wed,
@@ -0,0 +1,115 @@
+// 설정 검증 — 필드 경로/값/사유를 담은 구조화 오류
+use crate::title_pattern::Pattern;
+use serde::Serialize;
+use std::fmt;
+
+/// Environment variable that relaxes checks which only make sense for real
+/// installs (e.g. allows `storage.retention_days` of 0 in test harnesses).
+pub const CONFIG_TEST_MODE_ENV: &str = "ONESHIM_TEST_MODE";
+
+/// Whether [`CONFIG_TEST_MODE_ENV`] is set to `1` or `true`.
+pub fn config_test_mode() -> bool {
+    std::env::var(CONFIG_TEST_MODE_ENV)
+        .map(|v| matches!(v.trim().to_ascii_lowercase().as_str(), "1" | "true"))
+        .unwrap_or(false)
+}
+
+/// One invalid config value, addressed by its JSON path.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigValidationError {
+    /// Dotted JSON path, e.g. `monitor.poll_interval_ms` or
+    /// `server.standby_servers[0].base_url`.
+    pub path: String,
+    /// The offending value as it appears in config.json.
+    pub value: serde_json::Value,
+    pub reason: String,
+}
+
+impl ConfigValidationError {
+    pub fn new(path: impl Into<String>, value: impl Serialize, reason: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            value: serde_json::to_value(value).unwrap_or(serde_json::Value::Null),
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for ConfigValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} (got {})", self.path, self.reason, self.value)
+    }
+}
+
+impl std::error::Error for ConfigValidationError {}
+
+/// Join errors into one line for logs and `validate_bounds` callers.
+pub fn format_validation_errors(errors: &[ConfigValidationError]) -> String {
+    errors
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
+/// Collapse collected errors into the `validate_bounds` shape.
+pub(crate) fn into_bounds_result(errors: Vec<ConfigValidationError>) -> Result<(), String> {
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(format_validation_errors(&errors))
+    }
+}
+
+/// Record every pattern in `patterns` that does not compile.
+pub(crate) fn check_patterns(
+    path: &str,
+    patterns: &[String],
+    errors: &mut Vec<ConfigValidationError>,
+) {
+    for (index, pattern) in patterns.iter().enumerate() {
+        if let Err(reason) = Pattern::parse(pattern) {
+            errors.push(ConfigValidationError::new(
+                format!("{path}[{index}]"),
+                pattern,
+                reason,
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display_includes_path_reason_and_value() {
+        let err = ConfigValidationError::new("monitor.poll_interval_ms", 0u64, "must be > 0");
+        assert_eq!(
+            err.to_string(),
+            "monitor.poll_interval_ms must be > 0 (got 0)"
+        );
+    }
+
+    #[test]
+    fn check_patterns_reports_each_invalid_index() {
+        let mut errors = Vec::new();
+        check_patterns(
+            "privacy.excluded_title_patterns",
+            &[
+                "re:(unclosed".to_string(),
+                "*bank*".to_string(),
+                "re:[x".to_string(),
+            ],
+            &mut errors,
+        );
+        let paths: Vec<_> = errors.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            [
+                "privacy.excluded_title_patterns[0]",
+                "privacy.excluded_title_patterns[2]"
+            ]
+        );
+    }
+}
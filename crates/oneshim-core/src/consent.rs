@@ -1,6 +1,7 @@
 use chrono::{DateTime, Utc};
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use crate::error::CoreError;
 
@@ -253,6 +254,229 @@ impl ConsentManager {
     }
 }
 
+// ConsentRegistry
+
+/// Per-capability consent version shipped with this build. Raising it
+/// re-prompts every capability on the next start.
+pub const CAPABILITY_CONSENT_VERSION: u32 = 1;
+
+/// File name of the [`ConsentRegistry`], stored next to the config file.
+pub const CONSENT_REGISTRY_FILE_NAME: &str = "consent_registry.json";
+
+/// A data-collection capability that needs its own consent decision.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Capability {
+    ScreenCapture,
+    Ocr,
+    Clipboard,
+    InputMonitoring,
+}
+
+impl Capability {
+    pub const ALL: [Capability; 4] = [
+        Self::ScreenCapture,
+        Self::Ocr,
+        Self::Clipboard,
+        Self::InputMonitoring,
+    ];
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::ScreenCapture => "screen_capture",
+            Self::Ocr => "ocr",
+            Self::Clipboard => "clipboard",
+            Self::InputMonitoring => "input_monitoring",
+        }
+    }
+
+    /// The matching flag of the onboarding-wide [`ConsentPermissions`].
+    fn permitted_by(self, permissions: &ConsentPermissions) -> bool {
+        match self {
+            Self::ScreenCapture => permissions.screen_capture,
+            Self::Ocr => permissions.ocr_processing,
+            Self::Clipboard => permissions.clipboard_monitoring,
+            Self::InputMonitoring => permissions.input_activity,
+        }
+    }
+}
+
+/// The latest consent decision for one capability (audit record).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CapabilityConsent {
+    pub capability: Capability,
+    pub granted: bool,
+    /// When this decision was recorded.
+    pub granted_at: DateTime<Utc>,
+    /// Consent version the decision was made under.
+    pub version: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct ConsentRegistryFile {
+    #[serde(default)]
+    required_version: u32,
+    #[serde(default)]
+    records: Vec<CapabilityConsent>,
+}
+
+/// Per-capability consent decisions, shared by every subsystem that collects
+/// data.
+///
+/// Decisions take effect immediately: subsystems call [`Self::is_granted`]
+/// on each use rather than caching it, so a revocation stops collection
+/// without a restart. A decision made under an older version than
+/// [`Self::required_version`] no longer counts and must be asked again.
+pub struct ConsentRegistry {
+    path: PathBuf,
+    state: RwLock<ConsentRegistryFile>,
+}
+
+impl ConsentRegistry {
+    /// Load the registry at `path`; a missing or unreadable file starts empty.
+    pub fn open(path: PathBuf) -> Self {
+        let mut state: ConsentRegistryFile = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|data| match serde_json::from_str(&data) {
+                Ok(state) => Some(state),
+                Err(e) => {
+                    tracing::warn!(path = %path.display(), "consent registry unreadable: {e}");
+                    None
+                }
+            })
+            .unwrap_or_default();
+        state.required_version = state.required_version.max(CAPABILITY_CONSENT_VERSION);
+        Self {
+            path,
+            state: RwLock::new(state),
+        }
+    }
+
+    /// Registry path for a config file at `config_path`.
+    pub fn path_next_to(config_path: &Path) -> PathBuf {
+        config_path.with_file_name(CONSENT_REGISTRY_FILE_NAME)
+    }
+
+    pub fn required_version(&self) -> u32 {
+        self.state.read().required_version
+    }
+
+    /// Whether `capability` was granted under the current consent version.
+    pub fn is_granted(&self, capability: Capability) -> bool {
+        let state = self.state.read();
+        state.records.iter().any(|record| {
+            record.capability == capability
+                && record.granted
+                && record.version >= state.required_version
+        })
+    }
+
+    pub fn record(&self, capability: Capability) -> Option<CapabilityConsent> {
+        self.state
+            .read()
+            .records
+            .iter()
+            .find(|record| record.capability == capability)
+            .cloned()
+    }
+
+    pub fn records(&self) -> Vec<CapabilityConsent> {
+        self.state.read().records.clone()
+    }
+
+    /// Capabilities with no decision under the current version — the UI
+    /// should ask about these.
+    pub fn pending_prompts(&self) -> Vec<Capability> {
+        let state = self.state.read();
+        Capability::ALL
+            .into_iter()
+            .filter(|capability| {
+                !state.records.iter().any(|record| {
+                    record.capability == *capability && record.version >= state.required_version
+                })
+            })
+            .collect()
+    }
+
+    /// Record a grant or revocation under the current version and persist it.
+    ///
+    /// The in-memory decision changes even if the write fails, so a
+    /// revocation is never lost to a disk error.
+    pub fn set(
+        &self,
+        capability: Capability,
+        granted: bool,
+    ) -> Result<CapabilityConsent, CoreError> {
+        let mut state = self.state.write();
+        let record = CapabilityConsent {
+            capability,
+            granted,
+            granted_at: Utc::now(),
+            version: state.required_version,
+        };
+        state
+            .records
+            .retain(|existing| existing.capability != capability);
+        state.records.push(record.clone());
+        state.records.sort_by_key(|existing| existing.capability);
+        self.save(&state)?;
+        Ok(record)
+    }
+
+    pub fn grant(&self, capability: Capability) -> Result<CapabilityConsent, CoreError> {
+        self.set(capability, true)
+    }
+
+    pub fn revoke(&self, capability: Capability) -> Result<CapabilityConsent, CoreError> {
+        self.set(capability, false)
+    }
+
+    /// Invalidate every decision, e.g. after a privacy-policy change.
+    /// Returns the new required version.
+    pub fn bump_version(&self) -> Result<u32, CoreError> {
+        let mut state = self.state.write();
+        state.required_version += 1;
+        self.save(&state)?;
+        Ok(state.required_version)
+    }
+
+    /// Carry over the onboarding-wide permissions for capabilities that have
+    /// never been decided, so installs predating the registry keep working.
+    pub fn seed_from(&self, permissions: &ConsentPermissions) -> Result<(), CoreError> {
+        let mut state = self.state.write();
+        let now = Utc::now();
+        let version = state.required_version;
+        let missing: Vec<_> = Capability::ALL
+            .into_iter()
+            .filter(|capability| !state.records.iter().any(|r| r.capability == *capability))
+            .collect();
+        if missing.is_empty() {
+            return Ok(());
+        }
+        for capability in missing {
+            state.records.push(CapabilityConsent {
+                capability,
+                granted: capability.permitted_by(permissions),
+                granted_at: now,
+                version,
+            });
+        }
+        state.records.sort_by_key(|record| record.capability);
+        self.save(&state)
+    }
+
+    /// Atomic write: serialize to a .tmp file, then rename into place.
+    fn save(&self, state: &ConsentRegistryFile) -> Result<(), CoreError> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = self.path.with_extension("tmp");
+        std::fs::write(&tmp_path, serde_json::to_string_pretty(state)?)?;
+        std::fs::rename(&tmp_path, &self.path)?;
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -542,4 +766,87 @@ mod tests {
             "missing field must default to false"
         );
     }
+
+    fn registry_path(dir: &tempfile::TempDir) -> PathBuf {
+        dir.path().join(CONSENT_REGISTRY_FILE_NAME)
+    }
+
+    #[test]
+    fn registry_starts_with_nothing_granted() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = ConsentRegistry::open(registry_path(&dir));
+        assert!(!registry.is_granted(Capability::ScreenCapture));
+        assert_eq!(registry.pending_prompts(), Capability::ALL.to_vec());
+    }
+
+    #[test]
+    fn registry_revocation_takes_effect_for_shared_handles() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = std::sync::Arc::new(ConsentRegistry::open(registry_path(&dir)));
+        let processor_view = registry.clone();
+
+        registry.grant(Capability::ScreenCapture).unwrap();
+        assert!(processor_view.is_granted(Capability::ScreenCapture));
+
+        registry.revoke(Capability::ScreenCapture).unwrap();
+        assert!(!processor_view.is_granted(Capability::ScreenCapture));
+        let record = processor_view.record(Capability::ScreenCapture).unwrap();
+        assert!(!record.granted);
+        assert_eq!(record.version, CAPABILITY_CONSENT_VERSION);
+    }
+
+    #[test]
+    fn registry_persists_decisions() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = ConsentRegistry::open(registry_path(&dir));
+        registry.grant(Capability::Ocr).unwrap();
+        registry.revoke(Capability::Clipboard).unwrap();
+
+        let reopened = ConsentRegistry::open(registry_path(&dir));
+        assert!(reopened.is_granted(Capability::Ocr));
+        assert!(!reopened.is_granted(Capability::Clipboard));
+        assert_eq!(
+            reopened.pending_prompts(),
+            vec![Capability::ScreenCapture, Capability::InputMonitoring]
+        );
+    }
+
+    #[test]
+    fn registry_version_bump_requires_consent_again() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = ConsentRegistry::open(registry_path(&dir));
+        registry.grant(Capability::ScreenCapture).unwrap();
+
+        let version = registry.bump_version().unwrap();
+        assert_eq!(version, CAPABILITY_CONSENT_VERSION + 1);
+        assert!(!registry.is_granted(Capability::ScreenCapture));
+        assert!(registry
+            .pending_prompts()
+            .contains(&Capability::ScreenCapture));
+
+        let reopened = ConsentRegistry::open(registry_path(&dir));
+        assert_eq!(reopened.required_version(), version);
+        reopened.grant(Capability::ScreenCapture).unwrap();
+        assert!(reopened.is_granted(Capability::ScreenCapture));
+    }
+
+    #[test]
+    fn registry_seeds_only_undecided_capabilities() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = ConsentRegistry::open(registry_path(&dir));
+        registry.revoke(Capability::ScreenCapture).unwrap();
+
+        registry
+            .seed_from(&ConsentPermissions {
+                screen_capture: true,
+                ocr_processing: true,
+                ..Default::default()
+            })
+            .unwrap();
+
+        assert!(!registry.is_granted(Capability::ScreenCapture));
+        assert!(registry.is_granted(Capability::Ocr));
+        assert!(!registry.is_granted(Capability::Clipboard));
+        assert!(registry.pending_prompts().is_empty());
+    }
 }
@@ -5,6 +5,12 @@ define_code_enum! {
     pub enum StorageCode {
         /// 스토리지 연산 실패.
         Failed => "storage.failed",
+        /// SQLite 쿼리·트랜잭션 실패.
+        Database => "storage.database",
+        /// 스토리지 잠금 획득 실패 (poisoned mutex 등).
+        Lock => "storage.lock",
+        /// 저장 payload 직렬화/역직렬화 실패.
+        Serialization => "storage.serialization",
     }
 }
 
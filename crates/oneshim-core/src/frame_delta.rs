@@ -0,0 +1,115 @@
+//! Tile-based frame comparison.
+//!
+//! Shared by the capture delta pipeline (`oneshim-vision::delta`) and the
+//! frame diff endpoint so both agree on what "changed" means. Operates on raw
+//! RGBA8 buffers of equal size; alpha is ignored.
+
+use crate::models::frame::Rect;
+
+/// Edge length of a comparison tile, in pixels.
+pub const TILE_SIZE: u32 = 16;
+
+/// Mean per-pixel RGB difference (sum of channel deltas) above which a tile
+/// counts as changed.
+pub const CHANGE_THRESHOLD: u32 = 30;
+
+/// Sum of absolute RGB channel differences of the pixel at byte `offset`.
+#[inline]
+pub fn pixel_diff(prev: &[u8], curr: &[u8], offset: usize) -> u32 {
+    (0..3)
+        .map(|channel| {
+            (i32::from(prev[offset + channel]) - i32::from(curr[offset + channel])).unsigned_abs()
+        })
+        .sum()
+}
+
+/// Whether the mean [`pixel_diff`] over `tile` exceeds `threshold`.
+/// `stride` is the row length in bytes (`width * 4`).
+#[inline]
+pub fn tile_changed(prev: &[u8], curr: &[u8], stride: usize, tile: Rect, threshold: u64) -> bool {
+    let mut diff_sum = 0u64;
+    let mut pixel_count = 0u64;
+
+    for y in tile.y as usize..(tile.y + tile.h) as usize {
+        let row_offset = y * stride;
+        for x in tile.x as usize..(tile.x + tile.w) as usize {
+            diff_sum += u64::from(pixel_diff(prev, curr, row_offset + x * 4));
+            pixel_count += 1;
+        }
+    }
+
+    pixel_count > 0 && diff_sum / pixel_count > threshold
+}
+
+/// Tiles covering a `width` x `height` image, row by row. Edge tiles are
+/// clipped to the image.
+pub fn tiles(width: u32, height: u32) -> impl Iterator<Item = Rect> {
+    (0..height.div_ceil(TILE_SIZE)).flat_map(move |ty| {
+        (0..width.div_ceil(TILE_SIZE)).map(move |tx| {
+            let x = tx * TILE_SIZE;
+            let y = ty * TILE_SIZE;
+            Rect {
+                x,
+                y,
+                w: TILE_SIZE.min(width - x),
+                h: TILE_SIZE.min(height - y),
+            }
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn solid(width: u32, height: u32, rgb: [u8; 3]) -> Vec<u8> {
+        (0..width * height)
+            .flat_map(|_| [rgb[0], rgb[1], rgb[2], 255])
+            .collect()
+    }
+
+    #[test]
+    fn tiles_cover_image_with_clipped_edges() {
+        let all: Vec<_> = tiles(40, 20).collect();
+        assert_eq!(all.len(), 3 * 2);
+        assert_eq!(
+            all[2],
+            Rect {
+                x: 32,
+                y: 0,
+                w: 8,
+                h: 16
+            }
+        );
+        assert_eq!(
+            all[5],
+            Rect {
+                x: 32,
+                y: 16,
+                w: 8,
+                h: 4
+            }
+        );
+        let area: u32 = all.iter().map(|t| t.w * t.h).sum();
+        assert_eq!(area, 40 * 20);
+    }
+
+    #[test]
+    fn tile_changed_only_where_pixels_differ() {
+        let prev = solid(32, 16, [10, 10, 10]);
+        let mut curr = prev.clone();
+        for y in 0..16 {
+            for x in 16..32 {
+                let offset = (y * 32 + x) * 4;
+                curr[offset..offset + 3].copy_from_slice(&[200, 200, 200]);
+            }
+        }
+        let stride = 32 * 4;
+        let threshold = u64::from(CHANGE_THRESHOLD);
+
+        let changed: Vec<_> = tiles(32, 16)
+            .map(|tile| tile_changed(&prev, &curr, stride, tile, threshold))
+            .collect();
+        assert_eq!(changed, [false, true]);
+    }
+}
@@ -30,13 +30,16 @@ pub mod config_manager;
 pub mod consent;
 pub mod error;
 pub mod error_codes;
+pub mod frame_delta;
 pub mod ivf_index;
 pub mod models;
+pub mod monitor_feed;
 pub mod ports;
 pub mod provider_surface;
 pub mod quantization;
 pub mod sanitized_display;
 pub mod sync;
+pub mod title_pattern;
 pub mod types;
 
 pub use sanitized_display::{sanitized, SanitizedDisplay};
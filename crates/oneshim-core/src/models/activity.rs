@@ -16,6 +16,23 @@ pub struct IdleInfo {
     pub timestamp: DateTime<Utc>,
 }
 
+/// Presence fields attached to a session heartbeat. State only — never
+/// carries input content, key codes or window data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeartbeatPresence {
+    pub idle_state: IdleState,
+    pub secs_since_last_input: u64,
+}
+
+impl From<&IdleInfo> for HeartbeatPresence {
+    fn from(info: &IdleInfo) -> Self {
+        Self {
+            idle_state: info.state,
+            secs_since_last_input: info.idle_secs,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IdlePeriod {
     pub start_time: DateTime<Utc>,
@@ -80,6 +97,63 @@ impl SessionStats {
     pub fn end_now(&mut self) {
         self.ended_at = Some(Utc::now());
     }
+
+    /// Roll up many sessions, e.g. for weekly reports.
+    pub fn merge(sessions: impl IntoIterator<Item = SessionStats>) -> AggregateStats {
+        sessions
+            .into_iter()
+            .fold(AggregateStats::default(), |mut acc, session| {
+                acc.session_count += 1;
+                acc.total_events = acc.total_events.saturating_add(session.total_events);
+                acc.total_frames = acc.total_frames.saturating_add(session.total_frames);
+                acc.total_idle_secs = acc.total_idle_secs.saturating_add(session.total_idle_secs);
+                match session.ended_at {
+                    Some(ended_at) => {
+                        acc.ended_sessions += 1;
+                        // Clock skew can leave ended_at before started_at.
+                        let secs = (ended_at - session.started_at).num_seconds().max(0) as u64;
+                        acc.total_duration_secs = acc.total_duration_secs.saturating_add(secs);
+                    }
+                    None => acc.ongoing_sessions += 1,
+                }
+                let latest = session.ended_at.unwrap_or(session.started_at);
+                acc.earliest_at = Some(
+                    acc.earliest_at
+                        .map_or(session.started_at, |t| t.min(session.started_at)),
+                );
+                acc.latest_at = Some(acc.latest_at.map_or(latest, |t| t.max(latest)));
+                acc
+            })
+    }
+}
+
+/// Totals over several [`SessionStats`]; see [`SessionStats::merge`].
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AggregateStats {
+    pub session_count: u64,
+    pub ended_sessions: u64,
+    /// Sessions with no `ended_at`. They add to the counters but not to
+    /// `total_duration_secs`.
+    pub ongoing_sessions: u64,
+    pub total_events: u64,
+    pub total_frames: u64,
+    pub total_idle_secs: u64,
+    /// Summed duration of ended sessions.
+    pub total_duration_secs: u64,
+    /// Earliest `started_at`.
+    pub earliest_at: Option<DateTime<Utc>>,
+    /// Latest `ended_at`, or `started_at` for ongoing sessions.
+    pub latest_at: Option<DateTime<Utc>>,
+}
+
+impl AggregateStats {
+    /// Mean duration of ended sessions; `0.0` when none have ended.
+    pub fn avg_duration_secs(&self) -> f64 {
+        if self.ended_sessions == 0 {
+            return 0.0;
+        }
+        self.total_duration_secs as f64 / self.ended_sessions as f64
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -114,6 +188,24 @@ mod tests {
         assert!(period.duration_secs.is_some());
     }
 
+    #[test]
+    fn heartbeat_presence_serializes_state_only() {
+        let presence = HeartbeatPresence::from(&IdleInfo {
+            state: IdleState::Idle,
+            idle_secs: 420,
+            timestamp: Utc::now(),
+        });
+
+        let json = serde_json::to_value(presence).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({ "idle_state": "IDLE", "secs_since_last_input": 420 })
+        );
+
+        let back: HeartbeatPresence = serde_json::from_value(json).unwrap();
+        assert_eq!(back, presence);
+    }
+
     #[test]
     fn session_stats_counters() {
         let mut stats = SessionStats::new("test-session".to_string());
@@ -129,4 +221,58 @@ mod tests {
         assert_eq!(stats.total_frames, 1);
         assert_eq!(stats.total_idle_secs, 30);
     }
+
+    fn session(id: &str, start_hour: u32, end_hour: Option<u32>, events: u64) -> SessionStats {
+        use chrono::TimeZone;
+        let at = |hour| Utc.with_ymd_and_hms(2026, 3, 16, hour, 0, 0).unwrap();
+        SessionStats {
+            session_id: id.to_string(),
+            started_at: at(start_hour),
+            ended_at: end_hour.map(at),
+            total_events: events,
+            total_frames: events * 2,
+            total_idle_secs: 60,
+        }
+    }
+
+    #[test]
+    fn merge_sums_ended_and_ongoing_sessions() {
+        let aggregate = SessionStats::merge([
+            session("a", 9, Some(11), 10),
+            session("b", 13, Some(14), 5),
+            session("c", 15, None, 3),
+        ]);
+
+        assert_eq!(aggregate.session_count, 3);
+        assert_eq!(aggregate.ended_sessions, 2);
+        assert_eq!(aggregate.ongoing_sessions, 1);
+        assert_eq!(aggregate.total_events, 18);
+        assert_eq!(aggregate.total_frames, 36);
+        assert_eq!(aggregate.total_idle_secs, 180);
+        // The ongoing session adds nothing to duration.
+        assert_eq!(aggregate.total_duration_secs, 3 * 3600);
+        assert_eq!(aggregate.avg_duration_secs(), 1.5 * 3600.0);
+        assert_eq!(
+            aggregate.earliest_at,
+            Some(session("a", 9, None, 0).started_at)
+        );
+        assert_eq!(
+            aggregate.latest_at,
+            Some(session("c", 15, None, 0).started_at)
+        );
+    }
+
+    #[test]
+    fn merge_of_nothing_is_empty() {
+        let aggregate = SessionStats::merge(Vec::new());
+        assert_eq!(aggregate, AggregateStats::default());
+        assert_eq!(aggregate.avg_duration_secs(), 0.0);
+    }
+
+    #[test]
+    fn merge_ignores_negative_durations() {
+        let aggregate = SessionStats::merge([session("skewed", 12, Some(10), 0)]);
+        assert_eq!(aggregate.ended_sessions, 1);
+        assert_eq!(aggregate.total_duration_secs, 0);
+    }
 }
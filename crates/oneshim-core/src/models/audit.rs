@@ -34,6 +34,120 @@ pub struct AuditEntry {
     pub execution_time_ms: Option<u64>,
 }
 
+impl AuditEntry {
+    /// Final outcome, or `None` for a `Started` entry.
+    pub fn outcome(&self) -> Option<AuditOutcome> {
+        AuditOutcome::of(&self.status)
+    }
+}
+
+/// Final result of an audited command — every [`AuditStatus`] but `Started`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum AuditOutcome {
+    Success,
+    Failed,
+    Denied,
+    Timeout,
+}
+
+impl AuditOutcome {
+    pub fn of(status: &AuditStatus) -> Option<Self> {
+        match status {
+            AuditStatus::Started => None,
+            AuditStatus::Completed => Some(Self::Success),
+            AuditStatus::Failed => Some(Self::Failed),
+            AuditStatus::Denied => Some(Self::Denied),
+            AuditStatus::Timeout => Some(Self::Timeout),
+        }
+    }
+
+    pub fn status(self) -> AuditStatus {
+        match self {
+            Self::Success => AuditStatus::Completed,
+            Self::Failed => AuditStatus::Failed,
+            Self::Denied => AuditStatus::Denied,
+            Self::Timeout => AuditStatus::Timeout,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Success => "success",
+            Self::Failed => "failed",
+            Self::Denied => "denied",
+            Self::Timeout => "timeout",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.trim().to_ascii_lowercase().as_str() {
+            "success" => Some(Self::Success),
+            "failed" => Some(Self::Failed),
+            "denied" => Some(Self::Denied),
+            "timeout" => Some(Self::Timeout),
+            _ => None,
+        }
+    }
+}
+
+/// Audit query filter. Empty `outcomes` matches every entry, including
+/// `Started`; time bounds are inclusive.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AuditFilter {
+    pub outcomes: Vec<AuditOutcome>,
+    pub from: Option<DateTime<Utc>>,
+    pub to: Option<DateTime<Utc>>,
+}
+
+impl AuditFilter {
+    pub fn matches(&self, entry: &AuditEntry) -> bool {
+        let outcome_ok = self.outcomes.is_empty()
+            || entry
+                .outcome()
+                .is_some_and(|outcome| self.outcomes.contains(&outcome));
+        outcome_ok
+            && self.from.map_or(true, |from| entry.timestamp >= from)
+            && self.to.map_or(true, |to| entry.timestamp <= to)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuditPage {
+    pub offset: usize,
+    pub limit: usize,
+}
+
+/// One page of matching entries (newest first) and the total match count.
+#[derive(Debug, Clone, Default)]
+pub struct AuditQueryResult {
+    pub entries: Vec<AuditEntry>,
+    pub total: usize,
+}
+
+impl AuditQueryResult {
+    /// Filter and page `entries`, which must already be newest first.
+    pub fn paginate(
+        entries: impl IntoIterator<Item = AuditEntry>,
+        filter: &AuditFilter,
+        page: AuditPage,
+    ) -> Self {
+        let matching: Vec<_> = entries
+            .into_iter()
+            .filter(|entry| filter.matches(entry))
+            .collect();
+        let total = matching.len();
+        Self {
+            entries: matching
+                .into_iter()
+                .skip(page.offset)
+                .take(page.limit)
+                .collect(),
+            total,
+        }
+    }
+}
+
 /// 감사 로그 통계 (이전 튜플 반환값을 구조체로 대체)
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AuditStats {
@@ -2,7 +2,7 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
 use super::gui::{GuiExecutionOutcome, GuiExecutionTicket};
-use super::intent::{AutomationIntent, IntentResult};
+use super::intent::{AutomationIntent, IntentResult, WorkflowPreset};
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum MouseButton {
@@ -49,6 +49,12 @@ pub struct WorkflowStepResult {
     pub success: bool,
     pub elapsed_ms: u64,
     pub error: Option<String>,
+    /// Frame captured just before the step ran (`automation.capture_step_frames`).
+    #[serde(default)]
+    pub before_frame_id: Option<i64>,
+    /// Frame captured just after the step finished.
+    #[serde(default)]
+    pub after_frame_id: Option<i64>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -62,6 +68,21 @@ pub struct WorkflowResult {
     pub message: String,
 }
 
+/// One persisted workflow run.
+///
+/// `preset` is the preset exactly as it was executed, so a replay re-runs the
+/// same steps even if the preset has since been edited or deleted.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkflowExecutionRecord {
+    pub execution_id: String,
+    pub started_at: DateTime<Utc>,
+    pub preset: WorkflowPreset,
+    pub result: WorkflowResult,
+    /// Execution this run replayed, if it was a replay.
+    #[serde(default)]
+    pub replay_of: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlannedIntentResult {
     pub planned_intent: AutomationIntent,
@@ -119,6 +140,8 @@ pub struct ExecutionPolicyDto {
     pub require_signed_token: bool,
     #[serde(default = "default_confirmation")]
     pub confirmation: String,
+    #[serde(default)]
+    pub expires_at: Option<DateTime<Utc>>,
 }
 
 fn default_max_exec_time() -> u64 {
@@ -0,0 +1,53 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A user-marked moment ("bug reproduced here"), recorded from the bookmark
+/// hotkey or the dashboard.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Bookmark {
+    pub bookmark_id: String,
+    pub timestamp: DateTime<Utc>,
+    #[serde(default)]
+    pub label: Option<String>,
+    /// Frame captured with the bookmark, when capture was enabled and
+    /// succeeded.
+    #[serde(default)]
+    pub frame_id: Option<i64>,
+    #[serde(default)]
+    pub app_name: Option<String>,
+    #[serde(default)]
+    pub window_title: Option<String>,
+}
+
+impl Bookmark {
+    /// New bookmark stamped now. Blank labels are dropped.
+    pub fn new(label: Option<String>) -> Self {
+        Self {
+            bookmark_id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            label: label
+                .map(|l| l.trim().to_string())
+                .filter(|l| !l.is_empty()),
+            frame_id: None,
+            app_name: None,
+            window_title: None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_trims_and_drops_blank_labels() {
+        assert_eq!(
+            Bookmark::new(Some("  bug reproduced here ".to_string()))
+                .label
+                .as_deref(),
+            Some("bug reproduced here")
+        );
+        assert!(Bookmark::new(Some("   ".to_string())).label.is_none());
+        assert!(Bookmark::new(None).label.is_none());
+    }
+}
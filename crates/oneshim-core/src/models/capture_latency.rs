@@ -0,0 +1,230 @@
+//! Per-stage latency of the frame pipeline.
+//!
+//! The frame processor records how long each capture, encode and OCR pass
+//! took. A bounded window of recent samples per stage backs the p50/p95
+//! figures shown in diagnostics, exported as metrics and snapshotted to
+//! storage.
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Samples kept per stage; older ones fall out of the window.
+pub const LATENCY_WINDOW_SAMPLES: usize = 512;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum CaptureStage {
+    Capture,
+    Encode,
+    Ocr,
+}
+
+impl CaptureStage {
+    pub const ALL: [CaptureStage; 3] = [Self::Capture, Self::Encode, Self::Ocr];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Capture => "capture",
+            Self::Encode => "encode",
+            Self::Ocr => "ocr",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|stage| stage.as_str() == value)
+    }
+}
+
+/// Aggregate over the current sample window of one stage.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StageLatencySummary {
+    pub stage: CaptureStage,
+    /// Samples in the window the percentiles were computed over.
+    pub samples: u64,
+    /// Samples recorded since start, including ones no longer in the window.
+    pub total_samples: u64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub max_ms: f64,
+}
+
+/// A stored snapshot of [`StageLatencySummary`].
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CaptureLatencyRecord {
+    pub recorded_at: DateTime<Utc>,
+    pub summary: StageLatencySummary,
+}
+
+/// Rolling window of durations for one stage.
+#[derive(Debug, Clone)]
+pub struct LatencySamples {
+    window: VecDeque<Duration>,
+    capacity: usize,
+    total: u64,
+}
+
+impl LatencySamples {
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            window: VecDeque::with_capacity(capacity),
+            capacity,
+            total: 0,
+        }
+    }
+
+    pub fn record(&mut self, duration: Duration) {
+        if self.window.len() == self.capacity {
+            self.window.pop_front();
+        }
+        self.window.push_back(duration);
+        self.total = self.total.saturating_add(1);
+    }
+
+    pub fn len(&self) -> usize {
+        self.window.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.window.is_empty()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+
+    /// Nearest-rank percentile (`pct` in 0..=100) of the window.
+    pub fn percentile(&self, pct: f64) -> Option<Duration> {
+        let mut sorted: Vec<Duration> = self.window.iter().copied().collect();
+        sorted.sort_unstable();
+        nearest_rank(&sorted, pct)
+    }
+
+    pub fn summary(&self, stage: CaptureStage) -> Option<StageLatencySummary> {
+        let mut sorted: Vec<Duration> = self.window.iter().copied().collect();
+        sorted.sort_unstable();
+        let max = *sorted.last()?;
+        Some(StageLatencySummary {
+            stage,
+            samples: sorted.len() as u64,
+            total_samples: self.total,
+            p50_ms: as_ms(nearest_rank(&sorted, 50.0)?),
+            p95_ms: as_ms(nearest_rank(&sorted, 95.0)?),
+            max_ms: as_ms(max),
+        })
+    }
+}
+
+fn nearest_rank(sorted: &[Duration], pct: f64) -> Option<Duration> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let pct = pct.clamp(0.0, 100.0);
+    let rank = ((pct / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+/// Thread-safe recorder shared between the frame processor and readers.
+#[derive(Debug)]
+pub struct CaptureLatencyRecorder {
+    stages: Mutex<[LatencySamples; 3]>,
+}
+
+impl Default for CaptureLatencyRecorder {
+    fn default() -> Self {
+        Self::new(LATENCY_WINDOW_SAMPLES)
+    }
+}
+
+impl CaptureLatencyRecorder {
+    pub fn new(window: usize) -> Self {
+        Self {
+            stages: Mutex::new(std::array::from_fn(|_| LatencySamples::new(window))),
+        }
+    }
+
+    pub fn record(&self, stage: CaptureStage, duration: Duration) {
+        self.stages.lock()[stage_index(stage)].record(duration);
+    }
+
+    /// Summaries for stages that have at least one sample, in
+    /// [`CaptureStage::ALL`] order.
+    pub fn summaries(&self) -> Vec<StageLatencySummary> {
+        let stages = self.stages.lock();
+        CaptureStage::ALL
+            .into_iter()
+            .filter_map(|stage| stages[stage_index(stage)].summary(stage))
+            .collect()
+    }
+}
+
+fn stage_index(stage: CaptureStage) -> usize {
+    match stage {
+        CaptureStage::Capture => 0,
+        CaptureStage::Encode => 1,
+        CaptureStage::Ocr => 2,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(value: u64) -> Duration {
+        Duration::from_millis(value)
+    }
+
+    #[test]
+    fn percentiles_use_nearest_rank_over_window() {
+        let mut samples = LatencySamples::new(100);
+        assert_eq!(samples.percentile(50.0), None);
+        for value in 1..=20 {
+            samples.record(ms(value));
+        }
+        assert_eq!(samples.percentile(50.0), Some(ms(10)));
+        assert_eq!(samples.percentile(95.0), Some(ms(19)));
+        assert_eq!(samples.percentile(100.0), Some(ms(20)));
+        assert_eq!(samples.percentile(0.0), Some(ms(1)));
+
+        let summary = samples.summary(CaptureStage::Encode).unwrap();
+        assert_eq!(summary.samples, 20);
+        assert_eq!(summary.p50_ms, 10.0);
+        assert_eq!(summary.p95_ms, 19.0);
+        assert_eq!(summary.max_ms, 20.0);
+    }
+
+    #[test]
+    fn window_drops_oldest_but_keeps_total() {
+        let mut samples = LatencySamples::new(3);
+        for value in [100, 1, 2, 3] {
+            samples.record(ms(value));
+        }
+        assert_eq!(samples.len(), 3);
+        assert_eq!(samples.total(), 4);
+        assert_eq!(samples.percentile(100.0), Some(ms(3)));
+    }
+
+    #[test]
+    fn recorder_accumulates_per_stage() {
+        let recorder = CaptureLatencyRecorder::new(16);
+        recorder.record(CaptureStage::Capture, ms(30));
+        recorder.record(CaptureStage::Capture, ms(10));
+        recorder.record(CaptureStage::Ocr, ms(200));
+
+        let summaries = recorder.summaries();
+        assert_eq!(summaries.len(), 2, "encode has no samples yet");
+        assert_eq!(summaries[0].stage, CaptureStage::Capture);
+        assert_eq!(summaries[0].samples, 2);
+        assert_eq!(summaries[0].p50_ms, 10.0);
+        assert_eq!(summaries[0].max_ms, 30.0);
+        assert_eq!(summaries[1].stage, CaptureStage::Ocr);
+        assert_eq!(CaptureStage::parse("ocr"), Some(CaptureStage::Ocr));
+    }
+}
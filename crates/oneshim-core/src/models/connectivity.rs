@@ -0,0 +1,157 @@
+//! Server connectivity transitions.
+//!
+//! The health loop samples server reachability every few seconds. A single
+//! failed request flips the raw flag, so [`ConnectivityDebouncer`] only
+//! reports a transition once the new state has held for the debounce window.
+//! Reported transitions are stored as `ConnectivityChanged` system events and
+//! drive the dashboard's connectivity timeline.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ConnectivityState {
+    Online,
+    Offline,
+    /// Network reachable but requests are intercepted (captive portal).
+    Captive,
+}
+
+impl ConnectivityState {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Online => "online",
+            Self::Offline => "offline",
+            Self::Captive => "captive",
+        }
+    }
+}
+
+/// A debounced change from one connectivity state to another.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ConnectivityTransition {
+    pub from: ConnectivityState,
+    pub to: ConnectivityState,
+    /// When the new state was first observed, not when it was confirmed.
+    pub changed_at: DateTime<Utc>,
+}
+
+pub struct ConnectivityDebouncer {
+    debounce: Duration,
+    /// Last reported state. `None` until the first observation.
+    stable: Option<ConnectivityState>,
+    /// State that differs from `stable` and when it was first seen.
+    candidate: Option<(ConnectivityState, DateTime<Utc>)>,
+}
+
+impl ConnectivityDebouncer {
+    pub fn new(debounce: std::time::Duration) -> Self {
+        Self {
+            debounce: Duration::from_std(debounce).unwrap_or(Duration::MAX),
+            stable: None,
+            candidate: None,
+        }
+    }
+
+    /// Feed the latest sampled state. Returns a transition once a change has
+    /// persisted for the debounce window. The first observation only sets
+    /// the baseline.
+    pub fn observe(
+        &mut self,
+        state: ConnectivityState,
+        now: DateTime<Utc>,
+    ) -> Option<ConnectivityTransition> {
+        let Some(stable) = self.stable else {
+            self.stable = Some(state);
+            return None;
+        };
+        if state == stable {
+            self.candidate = None;
+            return None;
+        }
+        let since = match self.candidate {
+            Some((candidate, since)) if candidate == state => since,
+            _ => {
+                self.candidate = Some((state, now));
+                now
+            }
+        };
+        if now - since < self.debounce {
+            return None;
+        }
+        self.stable = Some(state);
+        self.candidate = None;
+        Some(ConnectivityTransition {
+            from: stable,
+            to: state,
+            changed_at: since,
+        })
+    }
+
+    pub fn state(&self) -> Option<ConnectivityState> {
+        self.stable
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use ConnectivityState::{Captive, Offline, Online};
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn run(samples: &[(ConnectivityState, i64)]) -> Vec<ConnectivityTransition> {
+        let mut debouncer = ConnectivityDebouncer::new(std::time::Duration::from_secs(30));
+        samples
+            .iter()
+            .filter_map(|(state, secs)| debouncer.observe(*state, at(*secs)))
+            .collect()
+    }
+
+    #[test]
+    fn flapping_settles_into_a_single_transition() {
+        let transitions = run(&[
+            (Online, 0),
+            (Offline, 5),
+            (Online, 10),
+            (Offline, 15),
+            (Online, 20),
+            (Offline, 25),
+            (Offline, 40),
+            (Offline, 55),
+            (Offline, 60),
+        ]);
+
+        assert_eq!(
+            transitions,
+            [ConnectivityTransition {
+                from: Online,
+                to: Offline,
+                changed_at: at(25),
+            }]
+        );
+    }
+
+    #[test]
+    fn sustained_change_reports_once() {
+        let transitions = run(&[
+            (Online, 0),
+            (Captive, 5),
+            (Captive, 35),
+            (Captive, 65),
+            (Captive, 95),
+        ]);
+
+        assert_eq!(transitions.len(), 1);
+        assert_eq!((transitions[0].from, transitions[0].to), (Online, Captive));
+    }
+
+    #[test]
+    fn short_blip_is_ignored() {
+        assert!(run(&[(Online, 0), (Offline, 5), (Online, 10), (Online, 60)]).is_empty());
+    }
+}
@@ -18,6 +18,110 @@ pub enum Event {
     FileAccess(FileAccessEvent),
 }
 
+/// Upload precedence of a queued event. Ordered low → high.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum UploadPriority {
+    Low,
+    Normal,
+    High,
+}
+
+impl UploadPriority {
+    pub const ALL: [UploadPriority; 3] = [Self::Low, Self::Normal, Self::High];
+}
+
+impl Event {
+    /// Upload precedence derived from the event type. Explicit user actions
+    /// and alerts go first; periodic metrics and process snapshots are the
+    /// first to be dropped on a congested uplink.
+    pub fn upload_priority(&self) -> UploadPriority {
+        match self {
+            Event::User(user) => match user.event_type {
+                UserEventType::SignificantAction | UserEventType::FormSubmission => {
+                    UploadPriority::High
+                }
+                UserEventType::WindowChange | UserEventType::AppSwitch => UploadPriority::Normal,
+            },
+            Event::System(system) => match system.event_type {
+                SystemEventType::Alert => UploadPriority::High,
+                SystemEventType::NetworkChange
+                | SystemEventType::ScreenLocked
+                | SystemEventType::ScreenUnlocked
+                | SystemEventType::ConnectivityChanged => UploadPriority::Normal,
+                SystemEventType::MetricsUpdate => UploadPriority::Low,
+            },
+            Event::Process(_) => UploadPriority::Low,
+            Event::Context(_)
+            | Event::Input(_)
+            | Event::Window(_)
+            | Event::Clipboard(_)
+            | Event::FileAccess(_) => UploadPriority::Normal,
+        }
+    }
+
+    /// Whether the event carries captured user content (window titles,
+    /// clipboard previews, file paths) rather than only metrics or
+    /// activity counters. Local-only mode keeps content events on device.
+    pub fn carries_content(&self) -> bool {
+        match self {
+            Event::User(_)
+            | Event::Context(_)
+            | Event::Window(_)
+            | Event::Clipboard(_)
+            | Event::FileAccess(_) => true,
+            Event::System(_) | Event::Input(_) | Event::Process(_) => false,
+        }
+    }
+
+    /// Primary key of the event's row in the local `events` table. Stable
+    /// across serialization, so upload results can be mapped back to rows.
+    pub fn local_id(&self) -> String {
+        match self {
+            Event::User(user_event) => user_event.event_id.to_string(),
+            Event::System(system_event) => system_event.event_id.to_string(),
+            Event::Context(context_event) => {
+                format!(
+                    "ctx_{}_{}_{}",
+                    context_event.timestamp.timestamp_millis(),
+                    context_event.app_name,
+                    context_event
+                        .window_title
+                        .chars()
+                        .take(20)
+                        .collect::<String>()
+                )
+            }
+            Event::Input(input_event) => {
+                format!(
+                    "input_{}_{}",
+                    input_event.timestamp.timestamp_millis(),
+                    input_event.app_name
+                )
+            }
+            Event::Process(process_event) => {
+                format!("proc_{}", process_event.timestamp.timestamp_millis())
+            }
+            Event::Window(window_event) => {
+                format!(
+                    "win_{}_{:?}",
+                    window_event.timestamp.timestamp_millis(),
+                    window_event.event_type
+                )
+            }
+            Event::Clipboard(cb) => {
+                format!("clip_{}", cb.timestamp.timestamp_millis())
+            }
+            Event::FileAccess(fa) => {
+                format!(
+                    "fa_{}_{}",
+                    fa.timestamp.timestamp_millis(),
+                    fa.relative_path.display()
+                )
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub enum ClipboardContentType {
     Text,
@@ -81,6 +185,9 @@ pub enum SystemEventType {
     MetricsUpdate,
     Alert,
     NetworkChange,
+    ScreenLocked,
+    ScreenUnlocked,
+    ConnectivityChanged,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -93,6 +200,9 @@ pub struct ContextEvent {
     /// Used by capture trigger to boost importance when user is actively interacting.
     #[serde(default)]
     pub input_activity_level: f32,
+    /// Further same-app events merged into this one by context sampling.
+    #[serde(default)]
+    pub repeat_count: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -100,6 +210,79 @@ pub struct EventBatch {
     pub session_id: String,
     pub events: Vec<Event>,
     pub created_at: DateTime<Utc>,
+    /// Key the server uses to drop re-sent copies of each entry in `events`,
+    /// index-aligned.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub idempotency_keys: Vec<String>,
+}
+
+impl EventBatch {
+    /// Batch with a fresh random key per event. Use
+    /// [`EventBatch::with_idempotency_keys`] when re-sending events whose
+    /// keys were issued earlier.
+    pub fn new(session_id: String, events: Vec<Event>, created_at: DateTime<Utc>) -> Self {
+        let idempotency_keys = events.iter().map(|_| new_idempotency_key()).collect();
+        Self::with_idempotency_keys(session_id, events, idempotency_keys, created_at)
+    }
+
+    /// Batch carrying previously issued keys, index-aligned with `events`.
+    pub fn with_idempotency_keys(
+        session_id: String,
+        events: Vec<Event>,
+        idempotency_keys: Vec<String>,
+        created_at: DateTime<Utc>,
+    ) -> Self {
+        debug_assert_eq!(events.len(), idempotency_keys.len());
+        Self {
+            session_id,
+            events,
+            created_at,
+            idempotency_keys,
+        }
+    }
+}
+
+/// A new random idempotency key for an event that does not have one yet.
+pub fn new_idempotency_key() -> String {
+    Uuid::new_v4().to_string()
+}
+
+/// Server verdict on one batch item, matched by idempotency key.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchItemResult {
+    pub idempotency_key: String,
+    pub accepted: bool,
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Whether resending the item may succeed. Malformed items are not
+    /// retryable and are quarantined on first rejection.
+    #[serde(default)]
+    pub retryable: bool,
+}
+
+/// Per-item outcome of a batch upload. Items the server does not list were
+/// accepted; an empty result (older servers) means the whole batch was.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+pub struct BatchUploadResult {
+    #[serde(default)]
+    pub items: Vec<BatchItemResult>,
+}
+
+impl BatchUploadResult {
+    /// The rejection for `idempotency_key`, if the server rejected it.
+    pub fn rejection(&self, idempotency_key: &str) -> Option<&BatchItemResult> {
+        self.items
+            .iter()
+            .find(|item| !item.accepted && item.idempotency_key == idempotency_key)
+    }
+}
+
+/// An event the uploader gave up on, with the server's reason.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuarantinedEvent {
+    /// [`Event::local_id`] of the quarantined event.
+    pub event_id: String,
+    pub reason: String,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -202,4 +385,93 @@ mod tests {
         let event: InputActivityEvent = serde_json::from_str(json).unwrap();
         assert!(event.keystroke_profile.is_none());
     }
+
+    #[test]
+    fn upload_priority_follows_event_type() {
+        let system = |event_type| {
+            Event::System(SystemEvent {
+                event_id: Uuid::new_v4(),
+                event_type,
+                timestamp: Utc::now(),
+                data: serde_json::Value::Null,
+            })
+        };
+        assert_eq!(
+            system(SystemEventType::Alert).upload_priority(),
+            UploadPriority::High
+        );
+        assert_eq!(
+            system(SystemEventType::MetricsUpdate).upload_priority(),
+            UploadPriority::Low
+        );
+        assert_eq!(
+            Event::Context(ContextEvent::default()).upload_priority(),
+            UploadPriority::Normal
+        );
+        assert!(UploadPriority::High > UploadPriority::Normal);
+    }
+
+    fn context_event(title: &str) -> Event {
+        Event::Context(ContextEvent {
+            app_name: "Code".to_string(),
+            window_title: title.to_string(),
+            timestamp: "2026-03-19T09:00:00.123Z".parse().unwrap(),
+            input_activity_level: 0.37,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn new_batch_keys_identical_events_separately() {
+        let batch = EventBatch::new(
+            "sess".to_string(),
+            vec![context_event("main.rs"), context_event("main.rs")],
+            Utc::now(),
+        );
+        assert_eq!(batch.idempotency_keys.len(), batch.events.len());
+        assert_ne!(batch.idempotency_keys[0], batch.idempotency_keys[1]);
+    }
+
+    #[test]
+    fn batch_keeps_supplied_idempotency_keys() {
+        let batch = EventBatch::with_idempotency_keys(
+            "sess".to_string(),
+            vec![context_event("main.rs")],
+            vec!["key-1".to_string()],
+            Utc::now(),
+        );
+        let json = serde_json::to_value(&batch).unwrap();
+        assert_eq!(json["idempotency_keys"], serde_json::json!(["key-1"]));
+    }
+
+    #[test]
+    fn batch_upload_result_finds_rejections_by_key() {
+        let result: BatchUploadResult = serde_json::from_str(
+            r#"{"items":[
+                {"idempotency_key":"a","accepted":true},
+                {"idempotency_key":"b","accepted":false,"reason":"bad schema"}
+            ]}"#,
+        )
+        .unwrap();
+        assert!(result.rejection("a").is_none());
+        assert!(result.rejection("missing").is_none());
+        let rejected = result.rejection("b").unwrap();
+        assert_eq!(rejected.reason.as_deref(), Some("bad schema"));
+        assert!(!rejected.retryable);
+
+        let legacy: BatchUploadResult = serde_json::from_str("{}").unwrap();
+        assert!(legacy.items.is_empty());
+    }
+
+    #[test]
+    fn content_events_are_separated_from_metrics() {
+        assert!(Event::Context(ContextEvent::default()).carries_content());
+        let metrics = Event::System(SystemEvent {
+            event_id: Uuid::new_v4(),
+            event_type: SystemEventType::MetricsUpdate,
+            timestamp: Utc::now(),
+            data: serde_json::Value::Null,
+        });
+        assert!(!metrics.carries_content());
+    }
 }
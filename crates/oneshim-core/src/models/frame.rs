@@ -1,5 +1,69 @@
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+
+/// Appended to OCR text cut short by `VisionConfig::ocr_max_chars`.
+pub const OCR_TRUNCATION_MARKER: &str = "…";
+
+/// Cap `text` at `max_chars` characters (marker included), cutting at the
+/// last word boundary when there is one. Text within the cap is returned
+/// unchanged.
+pub fn truncate_ocr_text(text: &str, max_chars: usize) -> Cow<'_, str> {
+    let Some((cut_at, next)) = text.char_indices().nth(max_chars) else {
+        return Cow::Borrowed(text);
+    };
+    if max_chars == 0 {
+        return Cow::Borrowed("");
+    }
+
+    let budget = max_chars.saturating_sub(OCR_TRUNCATION_MARKER.chars().count());
+    let (cut_at, next) = if budget < max_chars {
+        text.char_indices().nth(budget).unwrap_or((cut_at, next))
+    } else {
+        (cut_at, next)
+    };
+    let head = &text[..cut_at];
+    let head = if next.is_whitespace() {
+        head
+    } else {
+        match head.rfind(char::is_whitespace) {
+            Some(pos) if pos > 0 => &head[..pos],
+            // A single word longer than the cap is cut mid-word.
+            _ => head,
+        }
+    };
+
+    Cow::Owned(format!("{}{OCR_TRUNCATION_MARKER}", head.trim_end()))
+}
+
+/// Validation field a frame store reports when a frame file is truncated or
+/// cannot be decrypted, as opposed to missing or failing to read.
+pub const CORRUPT_FRAME_FIELD: &str = "frame_file";
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+const PNG_IEND_TRAILER: &[u8] = b"IEND\xaeB`\x82";
+const JPEG_SOI: &[u8] = &[0xff, 0xd8];
+const JPEG_EOI: &[u8] = &[0xff, 0xd9];
+
+/// Whether an image file was visibly cut short: empty, a WebP whose RIFF
+/// header declares more bytes than are present, or a PNG/JPEG without its end
+/// marker. Unrecognised formats are assumed complete.
+pub fn is_truncated_image(bytes: &[u8]) -> bool {
+    if bytes.is_empty() {
+        return true;
+    }
+    if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        let declared = u32::from_le_bytes([bytes[4], bytes[5], bytes[6], bytes[7]]) as usize;
+        return bytes.len() < declared.saturating_add(8);
+    }
+    if bytes.starts_with(PNG_SIGNATURE) {
+        return !bytes.ends_with(PNG_IEND_TRAILER);
+    }
+    if bytes.starts_with(JPEG_SOI) {
+        return !bytes.ends_with(JPEG_EOI);
+    }
+    false
+}
 
 /// Bounding box in pixel coordinates.
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
@@ -75,7 +139,7 @@ pub enum ImagePayload {
     },
 }
 
-#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 pub struct Rect {
     pub x: u32,
     pub y: u32,
@@ -96,6 +160,18 @@ pub struct ProcessedFrame {
     /// Only populated when importance >= 0.8 and OCR regions are non-empty.
     #[serde(skip)]
     pub raw_rgba: Option<Vec<u8>>,
+    /// Privacy preview for live viewers (`privacy.preview`), never stored.
+    #[serde(skip)]
+    pub preview: Option<FramePreview>,
+}
+
+/// Downscaled, blurred rendition of a frame, base64-encoded.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct FramePreview {
+    pub data: String,
+    pub format: String,
+    pub width: u32,
+    pub height: u32,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -113,6 +189,60 @@ pub struct ContextUpload {
 mod tests {
     use super::*;
 
+    #[test]
+    fn long_ocr_text_is_truncated_at_word_boundary() {
+        let text = "fn main() { println!(\"hello\"); } // trailing comment";
+        let truncated = truncate_ocr_text(text, 20);
+
+        assert_eq!(truncated, "fn main() {…");
+        assert!(truncated.chars().count() <= 20);
+    }
+
+    #[test]
+    fn short_ocr_text_is_unchanged() {
+        let text = "short text";
+        assert!(matches!(
+            truncate_ocr_text(text, 10),
+            Cow::Borrowed("short text")
+        ));
+        assert!(matches!(truncate_ocr_text(text, 100), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn single_long_word_is_cut_mid_word() {
+        let truncated = truncate_ocr_text("abcdefghijklmnop", 8);
+        assert_eq!(truncated, "abcdefg…");
+    }
+
+    #[test]
+    fn truncation_respects_multibyte_chars() {
+        let truncated = truncate_ocr_text("안녕하세요 세계 여러분", 9);
+        assert_eq!(truncated, "안녕하세요 세계…");
+    }
+
+    #[test]
+    fn truncated_webp_is_detected_from_riff_size() {
+        let mut webp = b"RIFF".to_vec();
+        webp.extend_from_slice(&12u32.to_le_bytes());
+        webp.extend_from_slice(b"WEBPVP8 \0\0\0\0");
+        assert!(!is_truncated_image(&webp));
+        assert!(is_truncated_image(&webp[..webp.len() - 3]));
+        assert!(is_truncated_image(&[]));
+    }
+
+    #[test]
+    fn png_and_jpeg_need_end_markers() {
+        let mut png = PNG_SIGNATURE.to_vec();
+        png.extend_from_slice(b"\0\0\0\0");
+        png.extend_from_slice(PNG_IEND_TRAILER);
+        assert!(!is_truncated_image(&png));
+        assert!(is_truncated_image(&png[..png.len() - 1]));
+
+        assert!(!is_truncated_image(&[0xff, 0xd8, 0x00, 0xff, 0xd9]));
+        assert!(is_truncated_image(&[0xff, 0xd8, 0x00, 0x00]));
+        assert!(!is_truncated_image(b"not an image header"));
+    }
+
     #[test]
     fn bounding_box_contains_point_inside() {
         let bbox = BoundingBox {
@@ -107,6 +107,15 @@ pub struct IntentConfig {
     pub verify_after_action: bool,
     #[serde(default = "default_verify_delay_ms")]
     pub verify_delay_ms: u64,
+    /// Delay between typed characters; 0 types the whole text at once.
+    #[serde(default)]
+    pub keystroke_delay_ms: u64,
+    /// Time a pointer move takes before a click; 0 jumps instantly.
+    #[serde(default)]
+    pub mouse_move_duration_ms: u64,
+    /// Randomize keystroke and pointer timings by up to ±30%.
+    #[serde(default)]
+    pub humanize_jitter: bool,
 }
 
 impl Default for IntentConfig {
@@ -117,6 +126,9 @@ impl Default for IntentConfig {
             retry_interval_ms: default_retry_interval_ms(),
             verify_after_action: default_verify(),
             verify_delay_ms: default_verify_delay_ms(),
+            keystroke_delay_ms: 0,
+            mouse_move_duration_ms: 0,
+            humanize_jitter: false,
         }
     }
 }
@@ -0,0 +1,125 @@
+//! Scheduler loop liveness records.
+//!
+//! Each background loop records a heartbeat on every tick. The watchdog
+//! compares the last heartbeat against a multiple of the loop's interval to
+//! decide whether the loop has stalled or died.
+
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Lower bound on the stall window so fast loops (1 s monitor poll) are not
+/// flagged during a single slow capture or OCR pass.
+pub const MIN_STALL_GRACE_SECS: u64 = 60;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LoopHealthStatus {
+    Healthy,
+    Stalled,
+}
+
+/// Last-known liveness of one scheduler loop.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LoopHeartbeat {
+    pub name: String,
+    pub interval_secs: u64,
+    pub last_tick_at: DateTime<Utc>,
+    pub tick_count: u64,
+    pub restart_count: u32,
+}
+
+impl LoopHeartbeat {
+    pub fn new(name: impl Into<String>, interval: std::time::Duration, now: DateTime<Utc>) -> Self {
+        Self {
+            name: name.into(),
+            interval_secs: interval.as_secs().max(1),
+            last_tick_at: now,
+            tick_count: 1,
+            restart_count: 0,
+        }
+    }
+
+    pub fn record_tick(&mut self, now: DateTime<Utc>) {
+        self.last_tick_at = now;
+        self.tick_count = self.tick_count.saturating_add(1);
+    }
+
+    /// Latest time by which the next tick is expected before the loop
+    /// counts as stalled. A multiplier of 0 is treated as 1.
+    pub fn stall_deadline(&self, stall_multiplier: u32) -> DateTime<Utc> {
+        let grace_secs = self
+            .interval_secs
+            .saturating_mul(u64::from(stall_multiplier.max(1)))
+            .max(MIN_STALL_GRACE_SECS);
+        self.last_tick_at + Duration::seconds(i64::try_from(grace_secs).unwrap_or(i64::MAX / 1000))
+    }
+
+    pub fn status_at(&self, now: DateTime<Utc>, stall_multiplier: u32) -> LoopHealthStatus {
+        if now > self.stall_deadline(stall_multiplier) {
+            LoopHealthStatus::Stalled
+        } else {
+            LoopHealthStatus::Healthy
+        }
+    }
+}
+
+/// Names of the loops in `heartbeats` that have missed their stall deadline.
+pub fn stalled_loops(
+    heartbeats: &[LoopHeartbeat],
+    now: DateTime<Utc>,
+    stall_multiplier: u32,
+) -> Vec<String> {
+    heartbeats
+        .iter()
+        .filter(|hb| hb.status_at(now, stall_multiplier) == LoopHealthStatus::Stalled)
+        .map(|hb| hb.name.clone())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        DateTime::from_timestamp(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    #[test]
+    fn loop_ticking_on_schedule_is_healthy() {
+        let mut hb = LoopHeartbeat::new("metrics", std::time::Duration::from_secs(5), at(0));
+        for tick in 1..=10 {
+            hb.record_tick(at(tick * 5));
+        }
+        assert_eq!(hb.tick_count, 11);
+        assert_eq!(hb.status_at(at(52), 3), LoopHealthStatus::Healthy);
+    }
+
+    #[test]
+    fn loop_past_multiple_of_interval_is_stalled() {
+        let hb = LoopHeartbeat::new("process", std::time::Duration::from_secs(30), at(0));
+        assert_eq!(hb.status_at(at(90), 3), LoopHealthStatus::Healthy);
+        assert_eq!(hb.status_at(at(91), 3), LoopHealthStatus::Stalled);
+    }
+
+    #[test]
+    fn fast_loop_gets_minimum_grace() {
+        let hb = LoopHeartbeat::new("monitor", std::time::Duration::from_secs(1), at(0));
+        assert_eq!(hb.stall_deadline(3), at(60));
+    }
+
+    #[test]
+    fn zero_multiplier_falls_back_to_single_interval() {
+        let hb = LoopHeartbeat::new("aggregation", std::time::Duration::from_secs(3600), at(0));
+        assert_eq!(hb.stall_deadline(0), at(3600));
+    }
+
+    #[test]
+    fn stalled_loops_reports_only_dead_loops() {
+        let now = at(100);
+        let mut alive = LoopHeartbeat::new("metrics", std::time::Duration::from_secs(5), at(0));
+        alive.record_tick(at(98));
+        let dead = LoopHeartbeat::new("process", std::time::Duration::from_secs(10), at(30));
+
+        assert_eq!(stalled_loops(&[alive, dead], now, 3), vec!["process"]);
+    }
+}
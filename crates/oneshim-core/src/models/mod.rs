@@ -6,9 +6,12 @@ pub mod app_registry;
 pub mod audio;
 pub mod audit;
 pub mod automation;
+pub mod bookmark;
 pub mod bug_report;
+pub mod capture_latency;
 pub mod coaching;
 pub mod coaching_template;
+pub mod connectivity;
 pub mod context;
 pub mod daily_digest;
 pub mod dashboard_streaming;
@@ -21,6 +24,8 @@ pub mod gui_activity;
 pub mod gui_interaction;
 pub mod integration;
 pub mod intent;
+pub mod loop_health;
+pub mod network_metrics;
 pub mod pomodoro;
 pub mod recalibration;
 pub mod session;
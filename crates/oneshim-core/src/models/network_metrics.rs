@@ -0,0 +1,16 @@
+//! Per-endpoint request figures for server calls.
+
+use serde::{Deserialize, Serialize};
+
+/// Totals and latency percentiles for one endpoint.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct EndpointMetricsSnapshot {
+    /// Method and route template, e.g. `POST /user_context/batches`.
+    pub endpoint: String,
+    pub requests: u64,
+    pub errors: u64,
+    pub p50_ms: f64,
+    pub p95_ms: f64,
+    pub p99_ms: f64,
+    pub max_ms: f64,
+}
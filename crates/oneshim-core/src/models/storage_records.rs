@@ -24,6 +24,18 @@ pub struct TagRecord {
     pub name: String,
     pub color: String,
     pub created_at: String,
+    /// Set only when listed for a frame: an auto-tag rule applied the tag
+    /// rather than the user.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub auto_applied: bool,
+}
+
+/// Tags every new frame whose app name contains `app_pattern` (any case).
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct TagRuleRecord {
+    pub id: i64,
+    pub app_pattern: String,
+    pub tag_id: i64,
 }
 
 #[derive(Debug, Clone)]
@@ -37,6 +49,9 @@ pub struct FocusWorkSessionRecord {
     pub interruption_count: u32,
     pub deep_work_secs: u64,
     pub duration_secs: u64,
+    /// Short switches away that were bridged instead of splitting the session.
+    pub merged_gap_count: u32,
+    pub merged_gap_secs: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -73,6 +88,18 @@ pub struct HourlyMetricsRecord {
     pub sample_count: u64,
 }
 
+/// One UTC day folded from the hourly rollups.
+#[derive(Debug, Clone)]
+pub struct DailyMetricsRecord {
+    /// `%Y-%m-%d`
+    pub date: String,
+    pub cpu_avg: f64,
+    pub cpu_max: f64,
+    pub memory_avg: u64,
+    pub memory_max: u64,
+    pub sample_count: u64,
+}
+
 #[derive(Debug, Clone)]
 pub struct StorageStatsSummaryRecord {
     pub frame_count: u64,
@@ -84,6 +111,78 @@ pub struct StorageStatsSummaryRecord {
     pub page_size: u64,
 }
 
+/// Where local storage is going, for the dashboard storage panel.
+#[derive(Debug, Clone, Default)]
+pub struct StorageReport {
+    pub db_size_bytes: u64,
+    pub frames_dir_bytes: u64,
+    /// Largest tables first.
+    pub tables: Vec<TableRowCountRecord>,
+    /// Categories with the most image bytes first.
+    pub app_categories: Vec<CategoryStorageRecord>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableRowCountRecord {
+    pub table: String,
+    pub rows: u64,
+}
+
+/// Frames of one app category and the image files they reference. A file
+/// shared by frames of several categories counts toward each of them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CategoryStorageRecord {
+    pub category: String,
+    pub frame_count: u64,
+    pub bytes: u64,
+}
+
+/// A database snapshot written by the online backup API.
+#[derive(Debug, Clone)]
+pub struct DatabaseBackupRecord {
+    pub path: String,
+    pub size_bytes: u64,
+    pub created_at: String,
+    /// Older snapshots deleted by rotation.
+    pub rotated_out: usize,
+}
+
+/// Outcome of `PRAGMA integrity_check` plus `PRAGMA foreign_key_check`.
+#[derive(Debug, Clone, Default)]
+pub struct IntegrityCheckRecord {
+    /// Problems reported by `integrity_check`; empty when SQLite answers `ok`.
+    pub integrity_errors: Vec<String>,
+    pub foreign_key_violations: Vec<ForeignKeyViolationRecord>,
+}
+
+/// One row of `PRAGMA foreign_key_check`.
+#[derive(Debug, Clone)]
+pub struct ForeignKeyViolationRecord {
+    pub table: String,
+    pub rowid: Option<i64>,
+    pub parent: String,
+}
+
+impl IntegrityCheckRecord {
+    pub fn is_healthy(&self) -> bool {
+        self.integrity_errors.is_empty() && self.foreign_key_violations.is_empty()
+    }
+
+    /// Human-readable recovery steps for the detected problems, most severe first.
+    pub fn recovery_steps(&self) -> Vec<&'static str> {
+        let mut steps = Vec::new();
+        if !self.integrity_errors.is_empty() {
+            steps.push("Quit ONESHIM and copy oneshim.db (with its -wal/-shm files) somewhere safe before attempting repair.");
+            steps.push("Rebuild the database with `sqlite3 oneshim.db \".recover\" | sqlite3 recovered.db`, then replace oneshim.db with recovered.db.");
+            steps.push("If recovery fails, restore a backup or delete oneshim.db so ONESHIM starts with a fresh database (stored history is lost).");
+        }
+        if !self.foreign_key_violations.is_empty() {
+            steps.push("Orphaned rows were found; deleting the affected data range from the Privacy page or letting retention run removes them.");
+        }
+        steps
+    }
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct DeletedRangeCounts {
     pub events_deleted: u64,
@@ -256,6 +355,15 @@ pub struct SegmentDetailRecord {
     pub regime_label: Option<String>,
 }
 
+/// Input DTO for one row of a batched `frames` metadata insert.
+#[derive(Debug, Clone, Copy)]
+pub struct NewFrameMetadata<'a> {
+    pub metadata: &'a crate::models::frame::FrameMetadata,
+    pub file_path: Option<&'a str>,
+    pub ocr_text: Option<&'a str>,
+    pub bounds: Option<&'a crate::models::context::WindowBounds>,
+}
+
 /// Input DTO for inserting a GUI interaction event (V13, extended V22).
 #[derive(Debug, Clone)]
 pub struct NewGuiInteraction<'a> {
@@ -45,6 +45,13 @@ pub struct Suggestion {
     pub reasoning: Option<String>,
 }
 
+impl Suggestion {
+    /// Expired once `now` reaches `expires_at`; never without one.
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at.is_some_and(|expires| expires <= now)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum SuggestionType {
@@ -1,3 +1,5 @@
+use std::collections::HashMap;
+
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -116,6 +118,29 @@ impl AppCategory {
         Self::Other
     }
 
+    /// [`Self::from_app_name`] with user overrides consulted first. Keys
+    /// match as case-insensitive substrings of `app_name`; when several
+    /// match, the longest key wins.
+    pub fn from_app_name_with_overrides(
+        app_name: &str,
+        overrides: &HashMap<String, AppCategory>,
+    ) -> Self {
+        if !overrides.is_empty() {
+            let name = app_name.to_lowercase();
+            let matched = overrides
+                .iter()
+                .filter(|(key, _)| {
+                    let key = key.trim();
+                    !key.is_empty() && name.contains(&key.to_lowercase())
+                })
+                .max_by_key(|(key, _)| key.trim().len());
+            if let Some((_, category)) = matched {
+                return *category;
+            }
+        }
+        Self::from_app_name(app_name)
+    }
+
     /// Parse a category name string (case-insensitive) into an `AppCategory`.
     ///
     /// This is the inverse of the serde `rename_all = "snake_case"` names
@@ -135,11 +160,21 @@ impl AppCategory {
     }
 
     pub fn is_communication(&self) -> bool {
-        matches!(self, Self::Communication)
+        self.work_class() == WorkClass::Communication
     }
 
     pub fn is_deep_work(&self) -> bool {
-        matches!(self, Self::Development | Self::Documentation | Self::Design)
+        self.work_class() == WorkClass::DeepWork
+    }
+
+    /// Built-in focus classification. `FocusClassificationConfig` can
+    /// override it per category or per app.
+    pub fn work_class(&self) -> WorkClass {
+        match self {
+            Self::Development | Self::Documentation | Self::Design => WorkClass::DeepWork,
+            Self::Communication => WorkClass::Communication,
+            Self::Browser | Self::Media | Self::System | Self::Other => WorkClass::Shallow,
+        }
     }
 
     /// Convenience: classify an app name as a coding/development app.
@@ -171,6 +206,25 @@ impl AppCategory {
     }
 }
 
+/// How time spent in an app counts towards focus metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WorkClass {
+    DeepWork,
+    Shallow,
+    Communication,
+}
+
+impl WorkClass {
+    pub fn is_deep_work(self) -> bool {
+        self == Self::DeepWork
+    }
+
+    pub fn is_communication(self) -> bool {
+        self == Self::Communication
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum SessionState {
@@ -386,6 +440,41 @@ impl LocalSuggestion {
 mod tests {
     use super::*;
 
+    #[test]
+    fn category_override_beats_builtin_heuristic() {
+        let overrides = HashMap::from([
+            ("jira-desktop".to_string(), AppCategory::Communication),
+            ("code".to_string(), AppCategory::Documentation),
+            ("Code Review".to_string(), AppCategory::Communication),
+        ]);
+
+        assert_eq!(
+            AppCategory::from_app_name("Jira-Desktop"),
+            AppCategory::Other
+        );
+        assert_eq!(
+            AppCategory::from_app_name_with_overrides("Jira-Desktop", &overrides),
+            AppCategory::Communication
+        );
+        // Longest matching key wins.
+        assert_eq!(
+            AppCategory::from_app_name_with_overrides("Acme Code Review", &overrides),
+            AppCategory::Communication
+        );
+        assert_eq!(
+            AppCategory::from_app_name_with_overrides("Visual Studio Code", &overrides),
+            AppCategory::Documentation
+        );
+        assert_eq!(
+            AppCategory::from_app_name_with_overrides("Slack", &overrides),
+            AppCategory::Communication
+        );
+        assert_eq!(
+            AppCategory::from_app_name_with_overrides("Figma", &HashMap::new()),
+            AppCategory::Design
+        );
+    }
+
     #[test]
     fn app_category_from_name() {
         assert_eq!(
@@ -0,0 +1,135 @@
+//! Live feed of monitor collection results.
+//!
+//! The scheduler publishes every `UserContext` and `SystemMetrics` it
+//! collects into a [`MonitorFeed`]; embedders call [`MonitorFeed::subscribe`]
+//! to consume them as an async stream instead of polling the monitors a
+//! second time or reading them back from storage. Dropping the stream
+//! unsubscribes it.
+
+use std::pin::Pin;
+
+use futures_core::Stream;
+use tokio::sync::broadcast;
+use tokio_stream::wrappers::errors::BroadcastStreamRecvError;
+use tokio_stream::wrappers::BroadcastStream;
+use tokio_stream::StreamExt;
+use tracing::debug;
+
+use crate::models::context::UserContext;
+use crate::models::system::SystemMetrics;
+
+/// Default number of items buffered per subscriber before it starts lagging.
+pub const DEFAULT_FEED_CAPACITY: usize = 64;
+
+/// One collection result.
+#[derive(Debug, Clone)]
+pub enum MonitorItem {
+    Context(UserContext),
+    Metrics(SystemMetrics),
+}
+
+/// Stream returned by [`MonitorFeed::subscribe`].
+pub type MonitorStream = Pin<Box<dyn Stream<Item = MonitorItem> + Send>>;
+
+/// Broadcast hub shared between the scheduler (publisher) and subscribers.
+#[derive(Debug, Clone)]
+pub struct MonitorFeed {
+    tx: broadcast::Sender<MonitorItem>,
+}
+
+impl Default for MonitorFeed {
+    fn default() -> Self {
+        Self::new(DEFAULT_FEED_CAPACITY)
+    }
+}
+
+impl MonitorFeed {
+    pub fn new(capacity: usize) -> Self {
+        let (tx, _) = broadcast::channel(capacity.max(1));
+        Self { tx }
+    }
+
+    /// Subscribe to items published from now on. A subscriber that falls
+    /// more than the feed capacity behind skips the missed items.
+    pub fn subscribe(&self) -> MonitorStream {
+        Box::pin(
+            BroadcastStream::new(self.tx.subscribe()).filter_map(|item| match item {
+                Ok(item) => Some(item),
+                Err(BroadcastStreamRecvError::Lagged(skipped)) => {
+                    debug!("monitor feed subscriber lagged, skipped {skipped} items");
+                    None
+                }
+            }),
+        )
+    }
+
+    /// Number of live subscriptions.
+    pub fn subscriber_count(&self) -> usize {
+        self.tx.receiver_count()
+    }
+
+    /// Publish a collected context. Skips the clone when nobody is listening.
+    pub fn publish_context(&self, context: &UserContext) {
+        if self.subscriber_count() > 0 {
+            let _ = self.tx.send(MonitorItem::Context(context.clone()));
+        }
+    }
+
+    /// Publish collected metrics. Skips the clone when nobody is listening.
+    pub fn publish_metrics(&self, metrics: &SystemMetrics) {
+        if self.subscriber_count() > 0 {
+            let _ = self.tx.send(MonitorItem::Metrics(metrics.clone()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn metrics(cpu_usage: f32) -> SystemMetrics {
+        SystemMetrics {
+            timestamp: Utc::now(),
+            cpu_usage,
+            memory_used: 1,
+            memory_total: 2,
+            disk_used: 0,
+            disk_total: 0,
+            network: None,
+            typing_wpm: 0.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn subscriber_receives_published_items() {
+        let feed = MonitorFeed::new(8);
+        let mut stream = feed.subscribe();
+
+        feed.publish_metrics(&metrics(12.5));
+        feed.publish_metrics(&metrics(40.0));
+
+        for expected in [12.5, 40.0] {
+            match stream.next().await {
+                Some(MonitorItem::Metrics(m)) => assert_eq!(m.cpu_usage, expected),
+                other => panic!("unexpected item: {other:?}"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn dropping_stream_unsubscribes() {
+        let feed = MonitorFeed::default();
+        let first = feed.subscribe();
+        let second = feed.subscribe();
+        assert_eq!(feed.subscriber_count(), 2);
+
+        drop(first);
+        assert_eq!(feed.subscriber_count(), 1);
+        drop(second);
+        assert_eq!(feed.subscriber_count(), 0);
+
+        // Publishing with no subscribers is a no-op.
+        feed.publish_metrics(&metrics(1.0));
+    }
+}
@@ -14,7 +14,7 @@ use crate::models::embedding::{SearchFilters, SearchResult};
 /// Primary adapter: `AdaptiveSearchCoordinator` in oneshim-analysis.
 ///
 /// # Errors
-/// - `CoreError::Storage` (wire: `storage.failed`) — delegated from the
+/// - `CoreError::Storage` (wire: `storage.*`) — delegated from the
 ///   underlying `VectorIndex` / `VectorStore` (SQLite read of vectors,
 ///   centroids, or binary codes).
 /// - `CoreError::Internal` (wire: `internal.generic`) — query/index
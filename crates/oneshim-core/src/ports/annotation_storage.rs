@@ -4,10 +4,10 @@ use crate::models::annotation::FrameAnnotation;
 /// Synchronous storage port for frame annotations (highlights, memos, arrows).
 ///
 /// Follows the same synchronous pattern as `TagStorage` and `PresetStorage`
-/// since all operations go through the single-connection `Mutex<Connection>`.
+/// since all operations are short blocking calls on a pooled SQLite connection.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for all SQLite operations
+/// `CoreError::Storage` (wire: `storage.*`) for all SQLite operations
 /// (iter-47 mass fix pattern: query/execute/commit/prepare). Annotation
 /// not found is expressed as `Ok(None)` / `Ok(Vec::new())`, not an Err
 /// variant.
@@ -5,7 +5,8 @@
 use async_trait::async_trait;
 
 use crate::error::CoreError;
-use crate::models::event::EventBatch;
+use crate::models::activity::HeartbeatPresence;
+use crate::models::event::{BatchUploadResult, EventBatch};
 use crate::models::frame::ContextUpload;
 use crate::models::suggestion::{Suggestion, SuggestionFeedback};
 
@@ -35,6 +36,17 @@ pub trait ApiClient: Send + Sync {
     /// Upload an event batch to the server.
     async fn upload_batch(&self, batch: &EventBatch) -> Result<(), CoreError>;
 
+    /// Upload an event batch and return the server's per-item verdicts.
+    /// Adapters whose endpoint only acknowledges whole batches report an
+    /// empty result, i.e. every item accepted.
+    async fn upload_batch_itemized(
+        &self,
+        batch: &EventBatch,
+    ) -> Result<BatchUploadResult, CoreError> {
+        self.upload_batch(batch).await?;
+        Ok(BatchUploadResult::default())
+    }
+
     /// Upload context data (frames + metadata) to the server.
     async fn upload_context(&self, upload: &ContextUpload) -> Result<(), CoreError>;
 
@@ -43,6 +55,16 @@ pub trait ApiClient: Send + Sync {
 
     /// Send a session heartbeat to keep the server session alive.
     async fn send_heartbeat(&self, session_id: &str) -> Result<(), CoreError>;
+
+    /// Send a heartbeat carrying the local presence state. Adapters without a
+    /// presence-aware endpoint fall back to a plain heartbeat.
+    async fn send_heartbeat_with_presence(
+        &self,
+        session_id: &str,
+        _presence: &HeartbeatPresence,
+    ) -> Result<(), CoreError> {
+        self.send_heartbeat(session_id).await
+    }
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -5,7 +5,9 @@
 use async_trait::async_trait;
 
 use crate::models::ai_session::SessionAuditEntry;
-use crate::models::audit::{AuditEntry, AuditLevel, AuditStats, AuditStatus};
+use crate::models::audit::{
+    AuditEntry, AuditFilter, AuditLevel, AuditPage, AuditQueryResult, AuditStats, AuditStatus,
+};
 
 /// 감사 로그 포트 — oneshim-web 핸들러가 사용하는 감사 로그 인터페이스
 ///
@@ -37,6 +39,13 @@ pub trait AuditLogPort: Send + Sync {
     /// action_type 접두사 기준 필터 조회
     async fn entries_by_action_prefix(&self, prefix: &str, limit: usize) -> Vec<AuditEntry>;
 
+    /// Filtered, paged lookup (newest first) with the total match count.
+    /// The default pages over `recent_entries`; adapters with persistent
+    /// history override it.
+    async fn query(&self, filter: &AuditFilter, page: AuditPage) -> AuditQueryResult {
+        AuditQueryResult::paginate(self.recent_entries(usize::MAX).await, filter, page)
+    }
+
     /// 통계 집계
     async fn stats(&self) -> AuditStats;
 
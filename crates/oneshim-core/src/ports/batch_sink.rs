@@ -3,7 +3,8 @@
 use async_trait::async_trait;
 
 use crate::error::CoreError;
-use crate::models::event::Event;
+use crate::models::event::{Event, QuarantinedEvent};
+use crate::ports::storage::StorageService;
 
 /// 이벤트를 배치로 서버에 전송하는 포트.
 /// `oneshim-network::BatchUploader`가 구현체.
@@ -28,11 +29,60 @@ pub trait BatchSink: Send + Sync {
     /// 복수 이벤트를 전송 큐에 추가
     fn enqueue_many(&self, events: Vec<Event>);
 
-    /// 큐에 쌓인 이벤트를 서버로 플러시. 전송된 건수 반환.
-    async fn flush(&self) -> Result<usize, CoreError>;
+    /// Queue an event whose local `events` row has id `local_id`. The
+    /// uploaded copy may be sanitized, so its own [`Event::local_id`] can
+    /// differ; [`FlushReport`] refers to rows by this id.
+    fn enqueue_local(&self, _local_id: String, event: Event) {
+        self.enqueue(event);
+    }
+
+    /// 큐에 쌓인 이벤트를 서버로 플러시. 항목별 결과 반환.
+    async fn flush(&self) -> Result<FlushReport, CoreError>;
 
     /// 마지막 호출 이후 드롭된 이벤트 수를 반환하고 카운터 리셋.
     fn take_dropped_since_last(&self) -> usize {
         0
     }
+
+    /// Queue the next page of rows `storage` still holds as unsent, e.g.
+    /// uploads lost from memory when the app exited while offline. `prepare`
+    /// maps each stored event to its upload form, or `None` to leave it out
+    /// (the row is then marked sent). Does nothing while events are queued,
+    /// so calling it after each drained flush works through the backlog;
+    /// returns how many events were queued.
+    async fn restore_pending(
+        &self,
+        _storage: &dyn StorageService,
+        _prepare: &(dyn Fn(Event) -> Option<Event> + Send + Sync),
+    ) -> Result<usize, CoreError> {
+        Ok(0)
+    }
+
+    /// Number of events waiting to be flushed.
+    fn queue_len(&self) -> usize {
+        0
+    }
+}
+
+/// Per-item outcome of one [`BatchSink::flush`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FlushReport {
+    /// Local ids of events the server accepted; safe to mark sent.
+    pub accepted: Vec<String>,
+    /// Events given up on after being rejected as malformed or too often.
+    pub quarantined: Vec<QuarantinedEvent>,
+    /// Rejected events put back in the queue for another attempt.
+    pub requeued: usize,
+}
+
+impl FlushReport {
+    /// Number of events the server accepted.
+    pub fn sent(&self) -> usize {
+        self.accepted.len()
+    }
+
+    /// Whether the flush made no progress at all (nothing was in the queue).
+    pub fn is_empty(&self) -> bool {
+        self.accepted.is_empty() && self.quarantined.is_empty() && self.requeued == 0
+    }
 }
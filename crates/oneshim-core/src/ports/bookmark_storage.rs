@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+
+use crate::error::CoreError;
+use crate::models::bookmark::Bookmark;
+
+/// Synchronous storage port for user bookmarks.
+///
+/// # Errors
+/// `CoreError::Storage` (wire: `storage.*`) for all SQLite operations.
+/// An empty window is `Ok(Vec::new())`.
+pub trait BookmarkStorage: Send + Sync {
+    /// Persist a new bookmark.
+    fn save_bookmark(&self, bookmark: &Bookmark) -> Result<(), CoreError>;
+
+    /// Bookmarks recorded within `[from, to]`, oldest first.
+    fn get_bookmarks(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Bookmark>, CoreError>;
+}
@@ -10,7 +10,7 @@ use crate::types::TimeWindow;
 /// typically write to SQLite in a single transaction.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite operations
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite operations
 /// (iter-47 mass fix pattern). The batch write is transactional — a
 /// single CHECK constraint violation aborts the whole batch; callers
 /// receive a single Err without per-entry partitioning.
@@ -13,7 +13,7 @@ use crate::sync::Hlc;
 /// containing all rows modified since the peer's last-known watermark.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite prepare/
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite prepare/
 /// query failures across syncable tables (iter-47 mass fix pattern).
 /// An empty changeset (no rows since the watermark) is `Ok(ChangeSet { .. })`
 /// with empty vectors — callers check `is_empty()`, not an error variant.
@@ -18,7 +18,7 @@ use crate::models::sync::{ChangeSet, SyncResult};
 /// - DeletionEvent changeset: hard-delete all rows from the originating device.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite transaction
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite transaction
 /// / constraint / commit failures during the merge (iter-47 mass fix
 /// pattern). Conflict resolution is not an error — LWW skips and
 /// tombstone propagations are recorded in `SyncResult`. A malformed
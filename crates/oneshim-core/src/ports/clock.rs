@@ -0,0 +1,215 @@
+//! Time source port — lets the scheduler and trackers read wall-clock and
+//! monotonic time through an injectable clock, so time-dependent behavior
+//! (retention, aggregation boundaries, idle transitions) is testable
+//! without real sleeps.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, Utc};
+use parking_lot::Mutex;
+use tracing::{debug, warn};
+
+/// Source of wall-clock and monotonic time.
+///
+/// # Errors
+/// **Infallible.**
+pub trait Clock: Send + Sync {
+    /// Current wall-clock time.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Current monotonic instant, for elapsed-time checks.
+    fn instant(&self) -> Instant;
+}
+
+/// Real clock backed by the OS.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn instant(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Controllable clock for tests. Time only moves on [`ManualClock::advance`]
+/// or [`ManualClock::set`]; both readings move together.
+#[derive(Debug)]
+pub struct ManualClock {
+    base_instant: Instant,
+    state: Mutex<ManualClockState>,
+}
+
+#[derive(Debug)]
+struct ManualClockState {
+    now: DateTime<Utc>,
+    elapsed: Duration,
+}
+
+impl ManualClock {
+    pub fn new(start: DateTime<Utc>) -> Self {
+        Self {
+            base_instant: Instant::now(),
+            state: Mutex::new(ManualClockState {
+                now: start,
+                elapsed: Duration::ZERO,
+            }),
+        }
+    }
+
+    /// Move both wall-clock and monotonic time forward by `by`.
+    pub fn advance(&self, by: Duration) {
+        let mut state = self.state.lock();
+        state.now += chrono::Duration::from_std(by).unwrap_or(chrono::Duration::MAX);
+        state.elapsed += by;
+    }
+
+    /// Jump wall-clock time to `now`. Monotonic time advances by the
+    /// difference when moving forward and stays put when moving backward.
+    pub fn set(&self, now: DateTime<Utc>) {
+        let mut state = self.state.lock();
+        if let Ok(forward) = (now - state.now).to_std() {
+            state.elapsed += forward;
+        }
+        state.now = now;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.state.lock().now
+    }
+
+    fn instant(&self) -> Instant {
+        self.base_instant + self.state.lock().elapsed
+    }
+}
+
+/// Clock corrected by the skew between a server clock and a local one.
+///
+/// Network adapters feed it server time observed on login and heartbeat
+/// responses via [`SkewCorrectedClock::observe_server_time`]. Use it where a
+/// timestamp is compared against or sent to the server (upload batch stamps,
+/// suggestion `expires_at`); locally stored timestamps keep using the raw
+/// clock. Reads the wrapped clock unchanged until the first observation.
+pub struct SkewCorrectedClock {
+    local: Arc<dyn Clock>,
+    /// `server - local`, in milliseconds.
+    skew_ms: AtomicI64,
+    warn_threshold: Duration,
+}
+
+impl SkewCorrectedClock {
+    pub fn new(local: Arc<dyn Clock>, warn_threshold: Duration) -> Self {
+        Self {
+            local,
+            skew_ms: AtomicI64::new(0),
+            warn_threshold,
+        }
+    }
+
+    /// Record the server's current time and return the resulting skew
+    /// (`server - local`). Logs a warning when it exceeds the threshold.
+    pub fn observe_server_time(&self, server_now: DateTime<Utc>) -> chrono::Duration {
+        let skew = server_now - self.local.now();
+        let skew_ms = skew.num_milliseconds();
+        let previous = self.skew_ms.swap(skew_ms, Ordering::Relaxed);
+
+        if skew.abs().to_std().unwrap_or(Duration::MAX) > self.warn_threshold {
+            warn!(
+                skew_ms,
+                threshold_secs = self.warn_threshold.as_secs(),
+                "local clock differs from server clock — correcting outbound timestamps"
+            );
+        } else if previous != skew_ms {
+            debug!(skew_ms, "server clock skew updated");
+        }
+        skew
+    }
+
+    /// Last observed `server - local` skew; zero before any observation.
+    pub fn skew(&self) -> chrono::Duration {
+        chrono::Duration::milliseconds(self.skew_ms.load(Ordering::Relaxed))
+    }
+}
+
+impl Clock for SkewCorrectedClock {
+    fn now(&self) -> DateTime<Utc> {
+        self.local.now() + self.skew()
+    }
+
+    /// Monotonic time is not affected by wall-clock skew.
+    fn instant(&self) -> Instant {
+        self.local.instant()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn start() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 1, 9, 59, 30).unwrap()
+    }
+
+    #[test]
+    fn manual_clock_only_moves_when_advanced() {
+        let clock = ManualClock::new(start());
+        let t0 = clock.instant();
+        assert_eq!(clock.now(), start());
+        assert_eq!(clock.now(), start());
+
+        clock.advance(Duration::from_secs(45));
+        assert_eq!(clock.now(), start() + chrono::Duration::seconds(45));
+        assert_eq!(clock.instant() - t0, Duration::from_secs(45));
+    }
+
+    #[test]
+    fn manual_clock_set_backwards_keeps_instant_monotonic() {
+        let clock = ManualClock::new(start());
+        clock.set(start() + chrono::Duration::minutes(5));
+        let after_forward = clock.instant();
+
+        clock.set(start());
+        assert_eq!(clock.now(), start());
+        assert_eq!(clock.instant(), after_forward);
+    }
+
+    #[test]
+    fn skew_corrected_clock_applies_observed_server_offset() {
+        let local = Arc::new(ManualClock::new(start()));
+        let clock = SkewCorrectedClock::new(local.clone(), Duration::from_secs(30));
+        assert_eq!(clock.now(), start());
+
+        // Server is 90 s ahead of the local clock.
+        let skew = clock.observe_server_time(start() + chrono::Duration::seconds(90));
+        assert_eq!(skew, chrono::Duration::seconds(90));
+        assert_eq!(clock.now(), start() + chrono::Duration::seconds(90));
+
+        local.advance(Duration::from_secs(10));
+        assert_eq!(clock.now(), start() + chrono::Duration::seconds(100));
+        assert_eq!(local.now(), start() + chrono::Duration::seconds(10));
+    }
+
+    #[test]
+    fn skew_corrected_clock_handles_server_behind() {
+        let local = Arc::new(ManualClock::new(start()));
+        let clock = SkewCorrectedClock::new(local, Duration::from_secs(30));
+        clock.observe_server_time(start() - chrono::Duration::milliseconds(2_500));
+        assert_eq!(clock.skew(), chrono::Duration::milliseconds(-2_500));
+        assert_eq!(clock.now(), start() - chrono::Duration::milliseconds(2_500));
+    }
+
+    #[test]
+    fn system_clock_tracks_utc_now() {
+        let before = Utc::now();
+        let now = SystemClock.now();
+        assert!(now >= before);
+    }
+}
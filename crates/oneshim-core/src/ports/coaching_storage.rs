@@ -6,7 +6,7 @@ use crate::models::coaching::CoachingEventRow;
 /// Focused persistence contract for scheduler coaching events.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite operations
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite operations
 /// (iter-47 mass fix pattern). The current `SqliteStorage` adapter
 /// treats rowcount=0 during `update_coaching_event_personalized` as a
 /// silent no-op (`Ok(())`) — unknown `event_id` does NOT surface a
@@ -5,7 +5,7 @@ use crate::models::suggestion::SuggestionHistoryEntry;
 /// Synchronous — matches StorageService / FocusStorage pattern (SQLite sync ops).
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite prepare/query/
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite prepare/query/
 /// execute operations (iter-47 mass fix pattern). Empty feedback history
 /// is `Ok(Vec::new())`. The current `SqliteStorage` adapter's
 /// `record_suggestion_feedback` executes an UPDATE without checking
@@ -1,5 +1,7 @@
 //! Synchronous storage port for focus-analysis data (work sessions, interruptions, focus metrics).
 
+use chrono::{DateTime, Utc};
+
 use crate::error::CoreError;
 use crate::models::suggestion::Suggestion;
 use crate::models::work_session::{AppCategory, FocusMetrics, Interruption, WorkSession};
@@ -11,7 +13,7 @@ use crate::models::work_session::{AppCategory, FocusMetrics, Interruption, WorkS
 /// `oneshim-storage` (backed by SQLite).
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for all SQLite operations
+/// `CoreError::Storage` (wire: `storage.*`) for all SQLite operations
 /// (iter-47 mass fix pattern: execute/query/transaction/lastInsertRowId).
 /// Conventions (verified against `SqliteStorage` adapter):
 /// - `get_pending_interruption` returns `Ok(None)` when no active row
@@ -45,6 +47,15 @@ pub trait FocusStorage: Send + Sync {
         resumed_to_app: &str,
     ) -> Result<(), CoreError>;
     fn end_work_session(&self, session_id: i64) -> Result<(), CoreError>;
+    /// End a session at `ended_at` instead of now — used when a bridged gap
+    /// outlives the grace period and the session really ended when it began.
+    fn end_work_session_at(
+        &self,
+        session_id: i64,
+        ended_at: DateTime<Utc>,
+    ) -> Result<(), CoreError>;
+    /// Record a short gap merged into `session_id` instead of splitting it.
+    fn record_work_session_gap(&self, session_id: i64, gap_secs: u64) -> Result<(), CoreError>;
     fn start_work_session(
         &self,
         primary_app: &str,
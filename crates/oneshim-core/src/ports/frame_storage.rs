@@ -6,6 +6,14 @@ use std::path::{Path, PathBuf};
 
 use crate::error::CoreError;
 
+/// Frame image writes were suspended or resumed because of disk space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskPressureChange {
+    /// `true` when image writes stopped, `false` when they resumed.
+    pub suspended: bool,
+    pub free_mb: u64,
+}
+
 /// Port for persisting captured frame images to storage.
 ///
 /// Implemented by `FrameFileStorage` in `oneshim-storage`.
@@ -16,7 +24,7 @@ use crate::error::CoreError;
 /// that do not belong in the port contract.
 ///
 /// # Errors
-/// - `CoreError::Storage` (wire: `storage.failed`) for SQLite
+/// - `CoreError::Storage` (wire: `storage.*`) for SQLite
 ///   index/retention metadata operations (iter-47 mass fix pattern).
 /// - `CoreError::AudioCapture` is NOT used — frame save uses
 ///   `CoreError::Io` (wire: `internal.io`) via `#[from]` for filesystem
@@ -45,4 +53,18 @@ pub trait FrameStoragePort: Send + Sync {
     /// Delete oldest frames to stay within storage size limits.
     /// Returns the number of deleted files.
     async fn enforce_storage_limit(&self) -> Result<usize, CoreError>;
+
+    /// Bytes currently used by stored frame files.
+    async fn total_size_bytes(&self) -> Result<u64, CoreError>;
+
+    /// Whether image writes are currently suspended for lack of disk space.
+    fn image_writes_suspended(&self) -> bool {
+        false
+    }
+
+    /// The latest suspend/resume transition not yet taken, if any. Each
+    /// transition is returned once, so callers can signal it exactly once.
+    fn take_disk_pressure_change(&self) -> Option<DiskPressureChange> {
+        None
+    }
 }
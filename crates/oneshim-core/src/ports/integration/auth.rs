@@ -14,7 +14,7 @@
 //! - `CoreError::Config` with `ConfigCode::Missing` (wire:
 //!   `config.missing`) — auth profile not configured, required claims
 //!   (client_id, token endpoint) absent.
-//! - `CoreError::Storage` (wire: `storage.failed`) — auth-material
+//! - `CoreError::Storage` (wire: `storage.*`) — auth-material
 //!   persistence failure during `reset_auth_state`.
 //! - `current_auth_status` does NOT surface "unauthenticated" as Err —
 //!   it returns `IntegrationAuthStatus::Unauthenticated` instead.
@@ -1,7 +1,7 @@
 //! Integration outbound egress ports, policy, and audit.
 //!
 //! # Errors (all traits in this module)
-//! - `CoreError::Storage` (wire: `storage.failed`) — outbox enqueue /
+//! - `CoreError::Storage` (wire: `storage.*`) — outbox enqueue /
 //!   list_pending / delete / cursor persistence failure (SQLite-backed
 //!   outbox), audit record persistence in `IntegrationAuditPort`.
 //! - `CoreError::Network` (wire: `network.generic`),
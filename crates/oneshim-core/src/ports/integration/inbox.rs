@@ -5,7 +5,7 @@
 //!   `CoreError::RateLimit` / `CoreError::ServiceUnavailable` /
 //!   `CoreError::Auth` — `refresh` and `acknowledge`/`dismiss` delegate
 //!   to the transport and follow the canonical HTTP semantic mapping.
-//! - `CoreError::Storage` (wire: `storage.failed`) — all local
+//! - `CoreError::Storage` (wire: `storage.*`) — all local
 //!   persistence methods (upsert_prompts, list_pending/unpresented,
 //!   pending_count, mark_presented, update_status, expire_stale,
 //!   ack-cursor load/store, record_prompt_receipt).
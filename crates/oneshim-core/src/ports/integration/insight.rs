@@ -1,7 +1,7 @@
 //! Integration insight producer, source, and checkpoint ports.
 //!
 //! # Errors (all traits in this module)
-//! - `CoreError::Storage` (wire: `storage.failed`) — all methods are
+//! - `CoreError::Storage` (wire: `storage.*`) — all methods are
 //!   SQLite-backed (local suggestion query, insight candidate
 //!   enumeration, checkpoint cursor load/store). iter-47 mass fix
 //!   pattern applies.
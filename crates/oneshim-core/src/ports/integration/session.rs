@@ -12,7 +12,7 @@
 //! - `CoreError::ServiceUnavailable` (wire: `service.unavailable`) —
 //!   502/503 from the integration backend, or running with the
 //!   feature flag disabled.
-//! - `CoreError::Storage` (wire: `storage.failed`) — persisted
+//! - `CoreError::Storage` (wire: `storage.*`) — persisted
 //!   session-state write/read failures in `IntegrationSessionStorePort`.
 //! - `IntegrationSessionStorePort::load` returns `Ok(None)` on first
 //!   launch (no persisted state), not Err.
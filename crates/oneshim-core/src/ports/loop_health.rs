@@ -0,0 +1,15 @@
+//! Scheduler loop liveness port.
+
+use crate::models::loop_health::LoopHeartbeat;
+
+/// Exposes the latest heartbeat of every scheduler loop.
+///
+/// # Errors
+/// **Infallible.** Loops that have not ticked yet are simply absent.
+pub trait LoopHealthProvider: Send + Sync {
+    /// Snapshot of all registered loop heartbeats, sorted by loop name.
+    fn heartbeats(&self) -> Vec<LoopHeartbeat>;
+
+    /// Interval multiple after which a silent loop is reported as stalled.
+    fn stall_multiplier(&self) -> u32;
+}
@@ -8,9 +8,11 @@ pub mod audio_capture;
 pub mod audit_log;
 pub mod automation;
 pub mod batch_sink;
+pub mod bookmark_storage;
 pub mod calibration_store;
 pub mod change_extractor;
 pub mod change_merger;
+pub mod clock;
 pub mod coaching;
 pub mod coaching_storage;
 pub mod compressor;
@@ -28,8 +30,10 @@ pub mod input_driver;
 pub mod integration;
 pub mod intent_planner;
 pub mod llm_provider;
+pub mod loop_health;
 pub mod model_downloader;
 pub mod monitor;
+pub mod network_metrics;
 pub mod notifier;
 pub mod oauth;
 pub mod ocr_provider;
@@ -43,6 +47,7 @@ pub mod runtime_log_provider;
 pub mod sandbox;
 pub mod secret_projection;
 pub mod secret_store;
+pub mod server_circuit;
 pub mod session_context_store;
 pub mod session_storage;
 pub mod skill_loader;
@@ -56,3 +61,4 @@ pub mod vector_store;
 pub mod vision;
 pub mod web_storage;
 pub mod work_classifier;
+pub mod workflow_history;
@@ -0,0 +1,12 @@
+//! Server request metrics port.
+
+use crate::models::network_metrics::EndpointMetricsSnapshot;
+
+/// Read side of the request metrics collected by the server clients.
+///
+/// # Errors
+/// **Infallible.** Endpoints that have not been called yet are simply absent.
+pub trait NetworkMetricsProvider: Send + Sync {
+    /// Per-endpoint figures, sorted by endpoint.
+    fn endpoint_metrics(&self) -> Vec<EndpointMetricsSnapshot>;
+}
@@ -23,3 +23,13 @@ pub trait DesktopNotifier: Send + Sync {
 
     async fn show_error(&self, message: &str) -> Result<(), CoreError>;
 }
+
+/// Outbound delivery of suggestions routed to the `webhook` channel.
+/// Implemented by `HttpSuggestionWebhook` in `oneshim-network`.
+///
+/// # Errors
+/// Best-effort like [`DesktopNotifier`]: callers log failures and move on.
+#[async_trait]
+pub trait SuggestionWebhook: Send + Sync {
+    async fn deliver(&self, suggestion: &Suggestion) -> Result<(), CoreError>;
+}
@@ -11,7 +11,7 @@ use crate::models::recalibration::RegimeOverride;
 /// Async port for CRUD operations on regime overrides.
 ///
 /// # Errors
-/// - `CoreError::Storage` (wire: `storage.failed`) for SQLite operations
+/// - `CoreError::Storage` (wire: `storage.*`) for SQLite operations
 ///   (iter-47 mass fix pattern).
 /// - `CoreError::NotFound` (wire: `resource.not_found`,
 ///   `resource_type = "RegimeOverride"`) from `delete_override` when the
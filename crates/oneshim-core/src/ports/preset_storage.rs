@@ -7,7 +7,7 @@ use crate::models::intent::WorkflowPreset;
 /// Built-in presets are not stored — they are returned by `builtin_presets()`.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite operations
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite operations
 /// (iter-47 mass fix pattern: query/execute/JSON column parse).
 /// `get_preset` expresses not-found as `Ok(None)`; `delete_preset` returns
 /// `Ok(false)` for a missing ID rather than erroring, so callers can
@@ -7,7 +7,7 @@ use async_trait::async_trait;
 /// Persist RegimeManager state across process restart.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite and JSON
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite and JSON
 /// serialization failures (iter-47 mass fix pattern). Note that
 /// `load_all` is NOT strictly read-only — adapters may quarantine a
 /// corrupted payload via a side-effect write to preserve user-curated
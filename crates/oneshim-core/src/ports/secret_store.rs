@@ -19,6 +19,9 @@ pub const INTEGRATION_ACCESS_TOKEN_SECRET_KEY: &str = "access_token";
 pub const INTEGRATION_REFRESH_TOKEN_SECRET_KEY: &str = "refresh_token";
 pub const INTEGRATION_EXPIRES_AT_SECRET_KEY: &str = "expires_at";
 pub const INTEGRATION_DPOP_SIGNING_KEY_SECRET_KEY: &str = "dpop_signing_key";
+pub const APP_CREDENTIALS_SECRET_NAMESPACE: &str = "app/credentials";
+pub const GUI_TICKET_HMAC_SECRET_KEY: &str = "gui_ticket_hmac_secret";
+pub const WEB_INTEGRATION_AUTH_TOKEN_SECRET_KEY: &str = "web_integration_auth_token";
 
 pub fn validate_secret_segment(raw: &str, field_name: &str) -> Result<String, CoreError> {
     let trimmed = raw.trim();
@@ -97,7 +100,8 @@ pub fn secret_env_var_name(namespace: &str, key: &str) -> String {
 /// Secure secret storage abstraction.
 ///
 /// Implementations may use OS keychain (macOS Keychain, Windows Credential
-/// Manager, Linux Secret Service) or an in-memory fallback.
+/// Manager, Linux Secret Service), an encrypted file fallback, or an
+/// in-memory test double.
 ///
 /// # Errors
 /// - `CoreError::SecretStoreError` (wire: `secret.failed`) for backend
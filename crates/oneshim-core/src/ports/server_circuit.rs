@@ -0,0 +1,14 @@
+//! Server circuit-breaker status port.
+
+use std::time::Duration;
+
+/// Read side of the breaker shared by the server clients, for indicators
+/// such as "server unreachable (retrying in 30s)".
+///
+/// # Errors
+/// **Infallible.**
+pub trait ServerCircuitStatus: Send + Sync {
+    /// Remaining cooldown while the circuit is open; `None` while requests
+    /// are allowed through (closed or half-open).
+    fn retry_in(&self) -> Option<Duration>;
+}
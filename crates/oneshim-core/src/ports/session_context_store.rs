@@ -7,7 +7,7 @@ use crate::ports::storage::StorageService;
 /// Query subset needed to assemble AI session system context.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite prepare/query
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite prepare/query
 /// operations (iter-47 mass fix pattern). Empty result is `Ok(Vec::new())`,
 /// not an Err variant — callers treat absence of suggestions as a valid
 /// empty context.
@@ -8,7 +8,7 @@ use crate::models::ai_session::{MessageRecord, SessionRecord, SessionState};
 /// Persist AI conversation sessions and per-turn messages.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for all SQLite operations
+/// `CoreError::Storage` (wire: `storage.*`) for all SQLite operations
 /// (iter-47 mass fix pattern: execute/query/transaction). The current
 /// `SqliteStorage` adapter returns `Ok(())` for rowcount=0 during
 /// `update_session_state` / `terminate_session` / `update_session_usage`
@@ -3,18 +3,19 @@
 //! Implemented by `SqliteStorage` in `oneshim-storage`.
 
 use async_trait::async_trait;
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::HashMap;
 
 use crate::error::CoreError;
 use crate::models::activity::{IdlePeriod, ProcessSnapshot, SessionStats};
-use crate::models::event::Event;
+use crate::models::event::{Event, QuarantinedEvent};
 use crate::models::suggestion::Suggestion;
 use crate::models::system::SystemMetrics;
 
 /// Local event and suggestion persistence.
 ///
 /// # Errors
-/// All methods return `CoreError::Storage` (wire: `storage.failed`) on
+/// All methods return `CoreError::Storage` (wire: `storage.*`) on
 /// SQLite failures (lock contention, constraint violation, disk I/O).
 /// Iter-47: previously documented as `Internal`; the mass conversion of
 /// `StorageError::Internal` → `CoreError::Storage` made the earlier doc
@@ -23,6 +24,10 @@ use crate::models::system::SystemMetrics;
 pub trait StorageService: Send + Sync {
     async fn save_event(&self, event: &Event) -> Result<(), CoreError>;
 
+    /// Persist `events` in one transaction. Prefer this over looping
+    /// `save_event` whenever several events are ready at once.
+    async fn save_events(&self, events: &[Event]) -> Result<(), CoreError>;
+
     async fn get_events(
         &self,
         from: DateTime<Utc>,
@@ -30,12 +35,27 @@ pub trait StorageService: Send + Sync {
         limit: usize,
     ) -> Result<Vec<Event>, CoreError>;
 
+    /// Oldest unsent events first. Rows that can no longer be decoded are
+    /// quarantined instead of returned.
     async fn get_pending_events(&self, limit: usize) -> Result<Vec<Event>, CoreError>;
 
     async fn mark_as_sent(&self, event_ids: &[String]) -> Result<(), CoreError>;
 
+    /// Idempotency keys generated when the `event_ids` rows were inserted,
+    /// keyed by event id. Rows that are missing or predate the key column
+    /// are absent from the map.
+    async fn get_idempotency_keys(
+        &self,
+        event_ids: &[String],
+    ) -> Result<HashMap<String, String>, CoreError>;
+
     async fn mark_unsent_as_sent_before(&self, before: DateTime<Utc>) -> Result<usize, CoreError>;
 
+    /// Take events the server keeps rejecting out of the upload backlog,
+    /// recording why. Quarantined rows are no longer pending and age out
+    /// with normal retention.
+    async fn quarantine_events(&self, events: &[QuarantinedEvent]) -> Result<(), CoreError>;
+
     async fn enforce_retention(&self) -> Result<usize, CoreError>;
 
     /// Persist an LLM/rule-based suggestion to the unified `suggestions` table.
@@ -66,6 +86,10 @@ pub trait MetricsStorage: Send + Sync {
 
     async fn aggregate_hourly_metrics(&self, hour: DateTime<Utc>) -> Result<(), CoreError>;
 
+    /// Fold the hourly rollups of `date`'s UTC day into one daily row.
+    /// Re-running for the same day replaces the row.
+    async fn aggregate_daily_metrics(&self, date: DateTime<Utc>) -> Result<(), CoreError>;
+
     async fn cleanup_old_metrics(&self, before: DateTime<Utc>) -> Result<usize, CoreError>;
 
     async fn save_process_snapshot(&self, snapshot: &ProcessSnapshot) -> Result<(), CoreError>;
@@ -82,7 +106,15 @@ pub trait MetricsStorage: Send + Sync {
         before: DateTime<Utc>,
     ) -> Result<usize, CoreError>;
 
-    async fn start_idle_period(&self, start_time: DateTime<Utc>) -> Result<i64, CoreError>;
+    /// Open an idle period at `start_time`. When the most recent period ended
+    /// at most `merge_threshold_secs` earlier, it is reopened instead and its
+    /// id returned, so a brief blip of activity does not split one break in
+    /// two. `0` never merges.
+    async fn start_idle_period(
+        &self,
+        start_time: DateTime<Utc>,
+        merge_threshold_secs: u64,
+    ) -> Result<i64, CoreError>;
 
     async fn end_idle_period(&self, id: i64, end_time: DateTime<Utc>) -> Result<(), CoreError>;
 
@@ -110,4 +142,13 @@ pub trait MetricsStorage: Send + Sync {
         frames: u64,
         idle_secs: u64,
     ) -> Result<(), CoreError>;
+
+    /// Event counts per `bucket`-wide slot of `[from, to)`, keyed by slot
+    /// start. Every slot is returned, empty ones with a zero count.
+    async fn event_histogram(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        bucket: Duration,
+    ) -> Result<Vec<(DateTime<Utc>, u64)>, CoreError>;
 }
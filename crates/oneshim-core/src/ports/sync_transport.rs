@@ -25,7 +25,7 @@ use crate::sync::Hlc;
 ///   HTTP calls) follow the same mapping. Push/pull are best-effort —
 ///   they return `Ok(bool)` or `Ok(Option)` rather than propagating
 ///   transient peer failures.
-/// - **FileSyncTransport**: `CoreError::Storage` (wire: `storage.failed`)
+/// - **FileSyncTransport**: `CoreError::Storage` (wire: `storage.*`)
 ///   for filesystem I/O and `CoreError::Internal` for encryption
 ///   failures.
 /// - Sync setup (config wiring in `agent_runtime/sync_setup.rs`):
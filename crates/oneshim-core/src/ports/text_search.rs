@@ -19,7 +19,7 @@ pub struct TextSearchResult {
 /// Implementations typically back this with SQLite FTS5 or similar.
 ///
 /// # Errors
-/// `CoreError::Storage` (wire: `storage.failed`) for SQLite-backed
+/// `CoreError::Storage` (wire: `storage.*`) for SQLite-backed
 /// FTS5 query/index operations (iter-47 mass fix pattern).
 /// Malformed FTS query syntax is returned as Storage as well — FTS5
 /// parser errors are surfaced as opaque SQL errors, not validation
@@ -31,7 +31,7 @@ pub struct IndexMeta {
 /// so that test mocks compile without change.
 ///
 /// # Errors
-/// - `CoreError::Storage` (wire: `storage.failed`) — production adapters
+/// - `CoreError::Storage` (wire: `storage.*`) — production adapters
 ///   (`SqliteVectorIndex`) surface SQLite errors during build/search/
 ///   centroid persistence (iter-47 mass fix pattern).
 /// - `CoreError::Internal` (wire: `internal.generic`) — trait default
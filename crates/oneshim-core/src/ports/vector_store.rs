@@ -12,7 +12,7 @@ use crate::quantization::QuantizedVector;
 /// Primary adapter: brute-force cosine similarity implementation in oneshim-storage.
 ///
 /// # Errors
-/// - `CoreError::Storage` (wire: `storage.failed`) for SQLite-backed
+/// - `CoreError::Storage` (wire: `storage.*`) for SQLite-backed
 ///   operations in the oneshim-storage adapter (iter-47 mass fix pattern).
 /// - `CoreError::InvalidArguments` (wire: `validation.invalid_arguments`)
 ///   for caller-side input violations — empty/NaN vectors, dimension
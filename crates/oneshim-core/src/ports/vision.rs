@@ -12,6 +12,23 @@ use crate::models::frame::ProcessedFrame;
 
 pub trait CaptureTrigger: Send + Sync {
     fn should_capture(&self, event: &ContextEvent) -> Option<CaptureRequest>;
+
+    /// Decide on a capture caused by a non-window `signal`. Returns `None`
+    /// when the trigger does not handle signals or the signal is disabled.
+    fn should_capture_signal(
+        &self,
+        _signal: CaptureSignal,
+        _event: &ContextEvent,
+    ) -> Option<CaptureRequest> {
+        None
+    }
+}
+
+/// Moments outside window changes that may warrant a capture.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CaptureSignal {
+    ClipboardChange,
+    FileSave,
 }
 
 #[derive(Debug, Clone)]
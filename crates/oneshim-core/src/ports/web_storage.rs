@@ -5,7 +5,7 @@
 //! can accept the narrow sub-trait instead of the full `WebStorage`.
 //!
 //! # Errors (applies to all sub-traits)
-//! `CoreError::Storage` (wire: `storage.failed`) for every SQLite operation
+//! `CoreError::Storage` (wire: `storage.*`) for every SQLite operation
 //! (iter-47 mass fix pattern: prepare/query/execute/transaction/FTS5 match).
 //! Consistent conventions across sub-traits:
 //! - Get-style methods returning `Option<T>` use `Ok(None)` for not-found
@@ -22,6 +22,7 @@
 //!   override implementations can surface Storage errors.
 
 use chrono::{DateTime, Utc};
+use std::path::Path;
 
 use crate::error::CoreError;
 use crate::models::activity::SessionStats;
@@ -29,15 +30,18 @@ use crate::types::TimeWindow;
 // (additional imports retained below)
 use crate::models::daily_digest::DailyDigest;
 use crate::models::storage_records::{
-    DeletedRangeCounts, EventExportRecord, FocusInterruptionRecord, FocusWorkSessionRecord,
-    FrameExportRecord, FrameRecord, FrameTagLinkRecord, GuiInteractionRecord, HourlyMetricsRecord,
+    DailyMetricsRecord, DatabaseBackupRecord, DeletedRangeCounts, EventExportRecord,
+    FocusInterruptionRecord, FocusWorkSessionRecord, FrameExportRecord, FrameRecord,
+    FrameTagLinkRecord, GuiInteractionRecord, HourlyMetricsRecord, IntegrityCheckRecord,
     LocalSuggestionRecord, MetricExportRecord, NewGuiInteraction, SearchEventRow, SearchFrameRow,
-    SegmentDetailRecord, SegmentSummaryRecord, StorageStatsSummaryRecord, SuggestionRecord,
-    TagRecord,
+    SegmentDetailRecord, SegmentSummaryRecord, StorageReport, StorageStatsSummaryRecord,
+    SuggestionRecord, TagRecord, TagRuleRecord,
 };
 use crate::models::work_session::FocusMetrics;
 use crate::ports::annotation_storage::AnnotationStorage;
+use crate::ports::bookmark_storage::BookmarkStorage;
 use crate::ports::storage::{MetricsStorage, StorageService};
+use crate::ports::workflow_history::WorkflowHistoryStorage;
 
 // ---------------------------------------------------------------------------
 // Sub-trait: TagStorage
@@ -57,6 +61,15 @@ pub trait TagStorage: Send + Sync {
     fn get_tags_for_frame(&self, frame_id: i64) -> Result<Vec<TagRecord>, CoreError>;
     fn add_tag_to_frame(&self, frame_id: i64, tag_id: i64) -> Result<(), CoreError>;
     fn remove_tag_from_frame(&self, frame_id: i64, tag_id: i64) -> Result<bool, CoreError>;
+    fn get_tag_rules(&self) -> Result<Vec<TagRuleRecord>, CoreError>;
+    fn create_tag_rule(&self, app_pattern: &str, tag_id: i64) -> Result<TagRuleRecord, CoreError>;
+    fn update_tag_rule(
+        &self,
+        rule_id: i64,
+        app_pattern: &str,
+        tag_id: i64,
+    ) -> Result<bool, CoreError>;
+    fn delete_tag_rule(&self, rule_id: i64) -> Result<bool, CoreError>;
 }
 
 // ---------------------------------------------------------------------------
@@ -73,6 +86,9 @@ pub trait FrameQueryStorage: Send + Sync {
         limit: usize,
     ) -> Result<Vec<FrameRecord>, CoreError>;
     fn get_frame_file_path(&self, frame_id: i64) -> Result<Option<String>, CoreError>;
+    /// Soft-deleted frames, most recently deleted first. Every other frame
+    /// query leaves them out.
+    fn get_trashed_frames(&self, limit: usize) -> Result<Vec<FrameRecord>, CoreError>;
     fn list_frame_file_paths_in_range(&self, window: &TimeWindow)
         -> Result<Vec<String>, CoreError>;
 
@@ -107,10 +123,38 @@ pub trait EventQueryStorage: Send + Sync {
 // Sub-trait: StorageMaintenanceStorage
 // ---------------------------------------------------------------------------
 
-/// Storage statistics, range deletion, and full data wipe.
+/// Storage statistics, integrity checks, range deletion, and full data wipe.
 pub trait StorageMaintenanceStorage: Send + Sync {
     fn get_storage_stats_summary(&self) -> Result<StorageStatsSummaryRecord, CoreError>;
 
+    /// Per-table row counts and per-app-category frame usage. Category bytes
+    /// are measured from files under `frames_dir`; `frames_dir_bytes` is
+    /// passed through from frame storage.
+    fn storage_report(
+        &self,
+        frames_dir: Option<&Path>,
+        frames_dir_bytes: u64,
+    ) -> Result<StorageReport, CoreError>;
+
+    /// Run SQLite's integrity and foreign-key checks. Corruption is reported in
+    /// the returned record, not as an `Err`.
+    fn check_integrity(&self) -> Result<IntegrityCheckRecord, CoreError>;
+
+    /// Snapshot the live database into its backup directory, keeping the
+    /// newest `keep` snapshots.
+    fn create_database_backup(&self, keep: usize) -> Result<DatabaseBackupRecord, CoreError>;
+
+    /// Flag a frame whose image file turned out to be unreadable; frame
+    /// retention deletes the file and clears the reference.
+    fn mark_frame_file_corrupt(&self, frame_id: i64) -> Result<(), CoreError>;
+
+    /// Move a frame to the trash; frame retention purges it after a grace
+    /// period. Returns `false` if it does not exist or is already trashed.
+    fn soft_delete_frame(&self, frame_id: i64) -> Result<bool, CoreError>;
+
+    /// Returns `false` if the frame is not in the trash.
+    fn restore_frame(&self, frame_id: i64) -> Result<bool, CoreError>;
+
     #[allow(clippy::too_many_arguments)]
     fn delete_data_in_range(
         &self,
@@ -254,6 +298,7 @@ pub trait BackupStorage: Send + Sync {
     fn list_frame_exports(&self, from: &str, to: &str)
         -> Result<Vec<FrameExportRecord>, CoreError>;
     fn list_hourly_metrics_since(&self, from: &str) -> Result<Vec<HourlyMetricsRecord>, CoreError>;
+    fn list_daily_metrics_since(&self, from: &str) -> Result<Vec<DailyMetricsRecord>, CoreError>;
     fn upsert_backup_tag(
         &self,
         id: i64,
@@ -460,6 +505,8 @@ pub trait WebStorage:
     + HabitStorage
     + AnnotationStorage
     + DashboardStreamingStorage
+    + WorkflowHistoryStorage
+    + BookmarkStorage
     + Send
     + Sync
 {
@@ -485,6 +532,8 @@ impl<T> WebStorage for T where
         + HabitStorage
         + AnnotationStorage
         + DashboardStreamingStorage
+        + WorkflowHistoryStorage
+        + BookmarkStorage
         + Send
         + Sync
 {
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+
+use crate::error::CoreError;
+use crate::models::automation::WorkflowExecutionRecord;
+
+/// Synchronous storage port for workflow execution history.
+///
+/// Every preset run (including replays) is recorded together with the preset
+/// snapshot it executed, which is what makes a later replay reproducible.
+///
+/// # Errors
+/// `CoreError::Storage` (wire: `storage.*`) for all SQLite operations
+/// and for stored rows whose JSON no longer deserializes. An unknown
+/// execution id is `Ok(None)`, an empty window is `Ok(Vec::new())`.
+pub trait WorkflowHistoryStorage: Send + Sync {
+    /// Persist a finished workflow run.
+    fn save_workflow_execution(&self, record: &WorkflowExecutionRecord) -> Result<(), CoreError>;
+
+    /// Runs started within `[from, to]`, newest first.
+    fn get_workflow_history(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<WorkflowExecutionRecord>, CoreError>;
+
+    /// A single run by execution id.
+    fn get_workflow_execution(
+        &self,
+        execution_id: &str,
+    ) -> Result<Option<WorkflowExecutionRecord>, CoreError>;
+}
@@ -0,0 +1,161 @@
+//! App-name / window-title exclusion patterns.
+//!
+//! A pattern is either a case-insensitive glob — `*bank*` (contains),
+//! `*- Private` (suffix), `Bank*` (prefix), `Discord` (exact) — or, with a
+//! `re:` prefix, a regular expression matched against the original text
+//! (e.g. `re:(?i)\bbank\b`). Invalid regexes are rejected by
+//! [`PatternSet::compile`], which config validation runs at load.
+
+use regex::Regex;
+
+/// Prefix marking a pattern as a regular expression.
+pub const REGEX_PREFIX: &str = "re:";
+
+#[derive(Debug, Clone)]
+pub enum Pattern {
+    /// Lowercased glob.
+    Glob(String),
+    Regex(Regex),
+}
+
+impl Pattern {
+    pub fn parse(pattern: &str) -> Result<Self, String> {
+        match pattern.strip_prefix(REGEX_PREFIX) {
+            Some(expr) => Regex::new(expr)
+                .map(Self::Regex)
+                .map_err(|e| format!("invalid regex pattern '{pattern}': {e}")),
+            None => Ok(Self::Glob(pattern.to_lowercase())),
+        }
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        match self {
+            Self::Regex(regex) => regex.is_match(text),
+            Self::Glob(glob) => glob_match(glob, &text.to_lowercase()),
+        }
+    }
+}
+
+fn glob_match(glob: &str, lower: &str) -> bool {
+    if let Some(rest) = glob.strip_prefix('*') {
+        if let Some(keyword) = rest.strip_suffix('*') {
+            lower.contains(keyword)
+        } else {
+            lower.ends_with(rest)
+        }
+    } else if let Some(prefix) = glob.strip_suffix('*') {
+        lower.starts_with(prefix)
+    } else {
+        lower == glob
+    }
+}
+
+/// Compiled list of exclusion patterns; matches if any pattern matches.
+#[derive(Debug, Clone, Default)]
+pub struct PatternSet {
+    patterns: Vec<Pattern>,
+}
+
+impl PatternSet {
+    /// Compile every pattern, failing on the first invalid one.
+    pub fn compile(patterns: &[String]) -> Result<Self, String> {
+        let patterns = patterns
+            .iter()
+            .map(|pattern| Pattern::parse(pattern))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { patterns })
+    }
+
+    /// Compile the valid patterns and drop invalid ones. For runtime paths;
+    /// invalid patterns are reported when the config is loaded.
+    pub fn compile_lenient(patterns: &[String]) -> Self {
+        Self {
+            patterns: patterns
+                .iter()
+                .filter_map(|pattern| Pattern::parse(pattern).ok())
+                .collect(),
+        }
+    }
+
+    pub fn is_match(&self, text: &str) -> bool {
+        self.patterns.iter().any(|pattern| pattern.is_match(text))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+}
+
+/// Allow/deny lists over names. A name passes when it matches no deny
+/// pattern and, if any allow patterns are set, at least one of those.
+#[derive(Debug, Clone, Default)]
+pub struct NameFilter {
+    allow: PatternSet,
+    deny: PatternSet,
+}
+
+impl NameFilter {
+    pub fn new(allow: PatternSet, deny: PatternSet) -> Self {
+        Self { allow, deny }
+    }
+
+    pub fn permits(&self, name: &str) -> bool {
+        !self.deny.is_match(name) && (self.allow.is_empty() || self.allow.is_match(name))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.allow.is_empty() && self.deny.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn set(patterns: &[&str]) -> PatternSet {
+        let patterns: Vec<String> = patterns.iter().map(|p| p.to_string()).collect();
+        PatternSet::compile(&patterns).expect("valid patterns")
+    }
+
+    #[test]
+    fn globs_match_case_insensitively() {
+        let patterns = set(&["*bank*", "Discord", "*- private", "Secret*"]);
+        assert!(patterns.is_match("KB Banking"));
+        assert!(patterns.is_match("discord"));
+        assert!(patterns.is_match("Notes - Private"));
+        assert!(patterns.is_match("secret plans"));
+        assert!(!patterns.is_match("Chrome"));
+    }
+
+    #[test]
+    fn regex_patterns_match_title() {
+        let patterns = set(&[r"re:(?i)\bbank\b.*- Google Chrome$"]);
+        assert!(patterns.is_match("My Bank - Login - Google Chrome"));
+        assert!(!patterns.is_match("Bankruptcy law - Google Chrome"));
+        assert!(!patterns.is_match("My Bank - Login - Firefox"));
+    }
+
+    #[test]
+    fn invalid_regex_is_reported_and_skipped_when_lenient() {
+        let patterns = vec!["re:([unclosed".to_string(), "*bank*".to_string()];
+        let err = PatternSet::compile(&patterns).unwrap_err();
+        assert!(err.contains("re:([unclosed"), "{err}");
+
+        let lenient = PatternSet::compile_lenient(&patterns);
+        assert!(lenient.is_match("Online Banking"));
+        assert!(!lenient.is_match("([unclosed"));
+    }
+
+    #[test]
+    fn name_filter_deny_wins_over_allow() {
+        let filter = NameFilter::new(set(&["*code*", "1password"]), set(&["1Password"]));
+        assert!(filter.permits("Code Helper"));
+        assert!(!filter.permits("1Password"));
+        assert!(!filter.permits("Slack"));
+
+        let deny_only = NameFilter::new(PatternSet::default(), set(&["re:^Keybase"]));
+        assert!(deny_only.permits("Slack"));
+        assert!(!deny_only.permits("Keybase Helper"));
+        assert!(NameFilter::default().permits("anything"));
+    }
+}
@@ -6,4 +6,4 @@
 
 pub mod time_window;
 
-pub use time_window::{TimeWindow, TimeWindowError};
+pub use time_window::{parse_window_bound, TimeWindow, TimeWindowError, WindowBound};
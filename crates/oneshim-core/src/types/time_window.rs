@@ -11,7 +11,7 @@
 //! construction bypasses bound validation — use only when both bounds are known
 //! to satisfy `start <= end`.
 
-use chrono::{DateTime, Duration, Utc};
+use chrono::{DateTime, Datelike, Duration, NaiveTime, TimeZone, Utc};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -90,6 +90,88 @@ impl TimeWindow {
             .with_timezone(&Utc);
         Self::new(start, end)
     }
+
+    /// Resolve a relative range token against `now`:
+    ///
+    /// - `today` — local midnight to `now`
+    /// - `yesterday` — previous local midnight to today's
+    /// - `this_week` — Monday local midnight to `now`
+    /// - `<N>h` / `<N>d` — `N` hours / days before `now` to `now`
+    ///
+    /// Calendar tokens use `now`'s timezone. Returns `None` for anything else.
+    pub fn from_relative<Tz: TimeZone>(token: &str, now: &DateTime<Tz>) -> Option<Self> {
+        let end = now.with_timezone(&Utc);
+        let midnight = |days_back: i64| {
+            let date = now.date_naive() - Duration::days(days_back);
+            let local = date.and_time(NaiveTime::MIN);
+            now.timezone()
+                .from_local_datetime(&local)
+                .earliest()
+                .map_or_else(|| local.and_utc(), |dt| dt.with_timezone(&Utc))
+        };
+        let window = match token {
+            "today" => Self {
+                start: midnight(0),
+                end,
+            },
+            "yesterday" => Self {
+                start: midnight(1),
+                end: midnight(0),
+            },
+            "this_week" => Self {
+                start: midnight(i64::from(now.weekday().num_days_from_monday())),
+                end,
+            },
+            _ => {
+                let (count, unit) = token.split_at(token.len().checked_sub(1)?);
+                let count: i64 = count.parse().ok().filter(|n| *n >= 0)?;
+                let lookback = match unit {
+                    "h" => Duration::try_hours(count)?,
+                    "d" => Duration::try_days(count)?,
+                    _ => return None,
+                };
+                Self {
+                    start: end.checked_sub_signed(lookback)?,
+                    end,
+                }
+            }
+        };
+        Some(window)
+    }
+}
+
+/// Which end of a window a query bound fills.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowBound {
+    Start,
+    End,
+}
+
+/// Parse one query bound: an RFC3339 timestamp, or a relative token (see
+/// [`TimeWindow::from_relative`]) contributing its start or end per `bound`.
+///
+/// # Errors
+/// [`TimeWindowError::ParseFailed`] naming the accepted forms.
+pub fn parse_window_bound<Tz: TimeZone>(
+    value: &str,
+    bound: WindowBound,
+    now: &DateTime<Tz>,
+) -> Result<DateTime<Utc>, TimeWindowError> {
+    let value = value.trim();
+    if let Some(window) = TimeWindow::from_relative(&value.to_ascii_lowercase(), now) {
+        return Ok(match bound {
+            WindowBound::Start => window.start,
+            WindowBound::End => window.end,
+        });
+    }
+    DateTime::parse_from_rfc3339(value)
+        .map(|dt| dt.with_timezone(&Utc))
+        .map_err(|_| {
+            TimeWindowError::ParseFailed(format!(
+                "'{value}' is not an RFC3339 timestamp or a relative range \
+                 (today, yesterday, this_week, <N>h, <N>d)"
+            ))
+        })
 }
 
 #[cfg(test)]
@@ -199,4 +281,58 @@ mod tests {
         let err = TimeWindow::from_rfc3339_pair("invalid", "valid").unwrap_err();
         assert_eq!(err.code(), TimeWindowCode::ParseFailed);
     }
+
+    fn kst_now() -> DateTime<chrono::FixedOffset> {
+        // Wednesday 2026-04-22 15:30 KST = 06:30 UTC
+        chrono::FixedOffset::east_opt(9 * 3600)
+            .unwrap()
+            .with_ymd_and_hms(2026, 4, 22, 15, 30, 0)
+            .unwrap()
+    }
+
+    #[test]
+    fn relative_days_and_hours_end_now() {
+        let now = kst_now();
+        let week = TimeWindow::from_relative("7d", &now).unwrap();
+        assert_eq!(week.end, now.with_timezone(&Utc));
+        assert_eq!(week.duration(), Duration::days(7));
+        let day = TimeWindow::from_relative("24h", &now).unwrap();
+        assert_eq!(day.duration(), Duration::hours(24));
+    }
+
+    #[test]
+    fn calendar_tokens_use_local_midnight() {
+        let now = kst_now();
+        // 2026-04-22 00:00 KST = 2026-04-21 15:00 UTC
+        let today_start = Utc.with_ymd_and_hms(2026, 4, 21, 15, 0, 0).unwrap();
+
+        let today = TimeWindow::from_relative("today", &now).unwrap();
+        assert_eq!(today.start, today_start);
+        let yesterday = TimeWindow::from_relative("yesterday", &now).unwrap();
+        assert_eq!(yesterday.start, today_start - Duration::days(1));
+        assert_eq!(yesterday.end, today_start);
+        let week = TimeWindow::from_relative("this_week", &now).unwrap();
+        assert_eq!(week.start, today_start - Duration::days(2)); // Monday
+    }
+
+    #[test]
+    fn unknown_relative_tokens_are_rejected() {
+        let now = kst_now();
+        for token in ["", "d", "7w", "-3d", "lastweek", "99999999999999d"] {
+            assert_eq!(TimeWindow::from_relative(token, &now), None, "{token}");
+        }
+    }
+
+    #[test]
+    fn parse_window_bound_accepts_relative_and_absolute() {
+        let now = kst_now();
+        let start = parse_window_bound("7D", WindowBound::Start, &now).unwrap();
+        assert_eq!(start, now.with_timezone(&Utc) - Duration::days(7));
+        let end = parse_window_bound("2026-04-01T00:00:00Z", WindowBound::End, &now).unwrap();
+        assert_eq!(end, dt(2026, 4, 1));
+
+        let err = parse_window_bound("fortnight", WindowBound::Start, &now).unwrap_err();
+        assert_eq!(err.code(), TimeWindowCode::ParseFailed);
+        assert!(err.to_string().contains("today, yesterday"));
+    }
 }
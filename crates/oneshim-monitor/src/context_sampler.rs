@@ -0,0 +1,136 @@
+//! Context event sampling.
+//!
+//! The monitor loop emits a context event every poll. When only the window
+//! title changes (a progress bar, a ticking clock) those events are near
+//! duplicates. [`ContextEventSampler`] holds the latest event back and folds
+//! further same-app events arriving within the window into it, counting them
+//! in `repeat_count`. An app switch always flushes the held event.
+
+use chrono::Duration;
+use oneshim_core::config::ContextSamplingConfig;
+use oneshim_core::models::event::ContextEvent;
+
+pub struct ContextEventSampler {
+    /// `None` when sampling is disabled.
+    window: Option<Duration>,
+    pending: Option<ContextEvent>,
+}
+
+impl ContextEventSampler {
+    pub fn new(config: &ContextSamplingConfig) -> Self {
+        let mut sampler = Self {
+            window: None,
+            pending: None,
+        };
+        sampler.set_config(config);
+        sampler
+    }
+
+    /// Apply a (reloaded) config. Takes effect from the next [`Self::push`].
+    pub fn set_config(&mut self, config: &ContextSamplingConfig) {
+        self.window = (config.enabled && config.window_ms > 0)
+            .then(|| Duration::milliseconds(config.window_ms.min(i64::MAX as u64) as i64));
+    }
+
+    /// Feed the next context event. Returns the events that are ready to be
+    /// stored, oldest first.
+    pub fn push(&mut self, event: ContextEvent) -> Vec<ContextEvent> {
+        let Some(window) = self.window else {
+            return self.pending.take().into_iter().chain([event]).collect();
+        };
+
+        if let Some(pending) = self.pending.as_mut() {
+            let same_app = pending.app_name == event.app_name;
+            if same_app && event.timestamp - pending.timestamp < window {
+                pending.repeat_count += 1;
+                pending.window_title = event.window_title;
+                pending.input_activity_level =
+                    pending.input_activity_level.max(event.input_activity_level);
+                return Vec::new();
+            }
+        }
+        self.pending.replace(event).into_iter().collect()
+    }
+
+    /// Release the held event, e.g. on shutdown.
+    pub fn flush(&mut self) -> Option<ContextEvent> {
+        self.pending.take()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{DateTime, TimeZone, Utc};
+
+    fn at(secs: i64) -> DateTime<Utc> {
+        Utc.timestamp_opt(1_700_000_000 + secs, 0).unwrap()
+    }
+
+    fn event(app: &str, title: &str, secs: i64) -> ContextEvent {
+        ContextEvent {
+            app_name: app.to_string(),
+            window_title: title.to_string(),
+            timestamp: at(secs),
+            ..Default::default()
+        }
+    }
+
+    fn sampler(window_ms: u64) -> ContextEventSampler {
+        ContextEventSampler::new(&ContextSamplingConfig {
+            enabled: true,
+            window_ms,
+        })
+    }
+
+    #[test]
+    fn same_app_title_churn_collapses_with_repeat_count() {
+        let mut sampler = sampler(5_000);
+        let mut stored = Vec::new();
+        for (i, pct) in ["10%", "20%", "30%", "40%"].iter().enumerate() {
+            stored.extend(sampler.push(event("Installer", pct, i as i64)));
+        }
+        stored.extend(sampler.flush());
+
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].repeat_count, 3);
+        assert_eq!(stored[0].window_title, "40%");
+        assert_eq!(stored[0].timestamp, at(0));
+    }
+
+    #[test]
+    fn app_switches_are_preserved() {
+        let mut sampler = sampler(5_000);
+        let mut stored = Vec::new();
+        stored.extend(sampler.push(event("Code", "main.rs", 0)));
+        stored.extend(sampler.push(event("Code", "lib.rs", 1)));
+        stored.extend(sampler.push(event("Slack", "general", 2)));
+        stored.extend(sampler.push(event("Code", "lib.rs", 3)));
+        stored.extend(sampler.flush());
+
+        let apps: Vec<_> = stored.iter().map(|e| e.app_name.as_str()).collect();
+        assert_eq!(apps, ["Code", "Slack", "Code"]);
+        assert_eq!(stored[0].repeat_count, 1);
+        assert_eq!(stored[1].repeat_count, 0);
+    }
+
+    #[test]
+    fn window_expiry_starts_a_new_event() {
+        let mut sampler = sampler(5_000);
+        assert!(sampler.push(event("Code", "a", 0)).is_empty());
+        assert!(sampler.push(event("Code", "b", 4)).is_empty());
+
+        let released = sampler.push(event("Code", "c", 5));
+        assert_eq!(released.len(), 1);
+        assert_eq!(released[0].repeat_count, 1);
+        assert_eq!(sampler.flush().map(|e| e.window_title), Some("c".into()));
+    }
+
+    #[test]
+    fn disabled_sampling_passes_events_through() {
+        let mut sampler = ContextEventSampler::new(&ContextSamplingConfig::default());
+        assert_eq!(sampler.push(event("Code", "a", 0)).len(), 1);
+        assert_eq!(sampler.push(event("Code", "b", 0)).len(), 1);
+        assert!(sampler.flush().is_none());
+    }
+}
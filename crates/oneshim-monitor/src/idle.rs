@@ -1,11 +1,20 @@
 use oneshim_core::models::activity::{IdleInfo, IdleState};
+use oneshim_core::ports::clock::{Clock, SystemClock};
+use std::sync::Arc;
 use tracing::debug;
 
 const DEFAULT_IDLE_THRESHOLD_SECS: u64 = 300; // 5min
+
+/// Seconds since the last user input, or `None` when unknown.
+pub type IdleProbe = Arc<dyn Fn() -> Option<u64> + Send + Sync>;
+
 pub struct IdleTracker {
     threshold_secs: u64,
     previous_state: IdleState,
     current_idle_period_id: Option<i64>,
+    clock: Arc<dyn Clock>,
+    /// Overrides the OS idle query. `None` uses [`get_idle_time`].
+    idle_probe: Option<IdleProbe>,
 }
 
 impl IdleTracker {
@@ -14,11 +23,29 @@ impl IdleTracker {
             threshold_secs: threshold_secs.unwrap_or(DEFAULT_IDLE_THRESHOLD_SECS),
             previous_state: IdleState::Active,
             current_idle_period_id: None,
+            clock: Arc::new(SystemClock),
+            idle_probe: None,
         }
     }
 
+    /// Stamp [`IdleInfo`] with `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Read idle seconds from `probe` instead of the OS.
+    pub fn with_idle_probe(mut self, probe: IdleProbe) -> Self {
+        self.idle_probe = Some(probe);
+        self
+    }
+
     pub async fn check_idle(&mut self) -> IdleInfo {
-        let idle_secs = get_idle_time().await.unwrap_or(0);
+        let idle_secs = match &self.idle_probe {
+            Some(probe) => probe(),
+            None => get_idle_time().await,
+        }
+        .unwrap_or(0);
         let state = if idle_secs >= self.threshold_secs {
             IdleState::Idle
         } else {
@@ -28,7 +55,7 @@ impl IdleTracker {
         let info = IdleInfo {
             state,
             idle_secs,
-            timestamp: chrono::Utc::now(),
+            timestamp: self.clock.now(),
         };
 
         if state != self.previous_state {
@@ -119,6 +146,36 @@ mod tests {
         assert!(info.state == IdleState::Idle || info.state == IdleState::Active);
     }
 
+    #[tokio::test]
+    async fn advancing_clock_triggers_idle_transition() {
+        use chrono::TimeZone;
+        use oneshim_core::ports::clock::ManualClock;
+
+        let clock = Arc::new(ManualClock::new(
+            chrono::Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap(),
+        ));
+        let last_input = clock.now();
+        let probe_clock = clock.clone();
+        let probe: IdleProbe =
+            Arc::new(move || Some((probe_clock.now() - last_input).num_seconds() as u64));
+        let mut tracker = IdleTracker::new(Some(300))
+            .with_clock(clock.clone())
+            .with_idle_probe(probe);
+
+        let info = tracker.check_idle().await;
+        assert_eq!(info.state, IdleState::Active);
+
+        clock.advance(std::time::Duration::from_secs(299));
+        assert_eq!(tracker.check_idle().await.state, IdleState::Active);
+
+        clock.advance(std::time::Duration::from_secs(1));
+        assert!(tracker.became_idle(IdleState::Idle));
+        let info = tracker.check_idle().await;
+        assert_eq!(info.state, IdleState::Idle);
+        assert_eq!(info.idle_secs, 300);
+        assert_eq!(info.timestamp, clock.now());
+    }
+
     #[test]
     fn idle_period_id_management() {
         let mut tracker = IdleTracker::default();
@@ -25,13 +25,16 @@ pub use error::MonitorError;
 
 pub mod activity;
 pub mod clipboard;
+pub mod context_sampler;
 pub mod file_access;
 pub mod idle;
 pub mod input_activity;
 pub mod input_detail;
 pub mod key_hook;
 pub mod keyboard_pattern;
+pub mod power;
 pub mod process;
+pub mod screen_lock;
 pub mod system;
 pub mod system_info;
 pub mod window_layout;
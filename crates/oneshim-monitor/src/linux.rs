@@ -1,4 +1,5 @@
 use crate::error::MonitorError;
+use crate::power::{PowerSource, PowerStatus};
 use oneshim_core::models::context::{MousePosition, WindowInfo};
 use std::time::Duration;
 use tokio::process::Command;
@@ -472,6 +473,54 @@ async fn get_idle_time_x11() -> Option<u64> {
     Some(ms / 1000)
 }
 
+/// Lock state from logind's `LockedHint`, falling back to the GNOME
+/// screensaver D-Bus API. `None` when neither answers.
+pub async fn is_screen_locked_linux() -> Option<bool> {
+    let session = std::env::var("XDG_SESSION_ID").unwrap_or_else(|_| "auto".to_string());
+    if let Ok(Ok(output)) = timeout(
+        Duration::from_secs(SUBPROCESS_TIMEOUT_SECS),
+        Command::new("loginctl")
+            .args(["show-session", &session, "-p", "LockedHint", "--value"])
+            .output(),
+    )
+    .await
+    {
+        if output.status.success() {
+            match String::from_utf8_lossy(&output.stdout).trim() {
+                "yes" => return Some(true),
+                "no" => return Some(false),
+                _ => {}
+            }
+        }
+    }
+
+    let output = timeout(
+        Duration::from_secs(SUBPROCESS_TIMEOUT_SECS),
+        Command::new("dbus-send")
+            .args([
+                "--session",
+                "--dest=org.gnome.ScreenSaver",
+                "--print-reply",
+                "/org/gnome/ScreenSaver",
+                "org.gnome.ScreenSaver.GetActive",
+            ])
+            .output(),
+    )
+    .await
+    .ok()?
+    .ok()?;
+    if !output.status.success() {
+        debug!("screen lock state unavailable — no logind hint or GNOME screensaver");
+        return None;
+    }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    stdout.lines().find_map(|line| match line.trim() {
+        "boolean true" => Some(true),
+        "boolean false" => Some(false),
+        _ => None,
+    })
+}
+
 pub async fn get_mouse_position_linux() -> Option<MousePosition> {
     let display_server = detect_display_server();
 
@@ -539,6 +588,40 @@ async fn get_mouse_position_x11() -> Option<MousePosition> {
     }
 }
 
+/// Power state from `/sys/class/power_supply`. On AC when any mains or USB
+/// supply is online; `None` without a battery.
+pub fn power_status_linux() -> Option<PowerStatus> {
+    let mut on_ac = false;
+    let mut battery: Option<(bool, Option<u8>)> = None;
+    for entry in std::fs::read_dir("/sys/class/power_supply").ok()?.flatten() {
+        let path = entry.path();
+        let read = |name: &str| {
+            std::fs::read_to_string(path.join(name))
+                .map(|value| value.trim().to_string())
+                .ok()
+        };
+        match read("type").as_deref() {
+            Some("Mains" | "USB") => on_ac |= read("online").as_deref() == Some("1"),
+            Some("Battery") if battery.is_none() => {
+                let discharging = read("status").as_deref() == Some("Discharging");
+                let percent = read("capacity").and_then(|v| v.parse::<u8>().ok());
+                battery = Some((discharging, percent));
+            }
+            _ => {}
+        }
+    }
+    let (discharging, battery_percent) = battery?;
+    let source = if discharging && !on_ac {
+        PowerSource::Battery
+    } else {
+        PowerSource::Ac
+    };
+    Some(PowerStatus {
+        source,
+        battery_percent,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
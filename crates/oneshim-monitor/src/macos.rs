@@ -1,4 +1,5 @@
 use crate::error::MonitorError;
+use crate::power::{PowerSource, PowerStatus};
 use core_graphics::event::CGEvent;
 use core_graphics::event_source::{CGEventSource, CGEventSourceStateID};
 use oneshim_core::models::context::{MousePosition, WindowBounds, WindowInfo};
@@ -238,6 +239,24 @@ pub async fn get_idle_time_macos() -> Option<u64> {
     None
 }
 
+/// Lock state from the console session dictionary (`CGSSessionScreenIsLocked`).
+pub async fn is_screen_locked_macos() -> Option<bool> {
+    let output = timeout(
+        Duration::from_secs(SUBPROCESS_TIMEOUT_SECS),
+        Command::new("ioreg").args(["-n", "Root", "-d1"]).output(),
+    )
+    .await
+    .ok()?
+    .ok()?;
+
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    Some(stdout.contains("\"CGSSessionScreenIsLocked\"=Yes"))
+}
+
 pub fn get_mouse_position_macos() -> Option<MousePosition> {
     let source = CGEventSource::new(CGEventSourceStateID::HIDSystemState).ok()?;
 
@@ -250,6 +269,39 @@ pub fn get_mouse_position_macos() -> Option<MousePosition> {
     })
 }
 
+/// Power state from `pmset -g batt`, e.g.
+/// `Now drawing from 'Battery Power'` / `-InternalBattery-0 (id=…)	42%; discharging; …`.
+pub async fn power_status_macos() -> Option<PowerStatus> {
+    let output = timeout(
+        Duration::from_secs(SUBPROCESS_TIMEOUT_SECS),
+        Command::new("pmset").args(["-g", "batt"]).output(),
+    )
+    .await
+    .ok()?
+    .ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let battery_line = stdout
+        .lines()
+        .find(|line| line.contains("InternalBattery"))?;
+    let battery_percent = battery_line
+        .split_whitespace()
+        .find_map(|token| token.trim_end_matches(';').strip_suffix('%'))
+        .and_then(|value| value.parse::<u8>().ok());
+    let source = if stdout.contains("'Battery Power'") {
+        PowerSource::Battery
+    } else {
+        PowerSource::Ac
+    };
+    Some(PowerStatus {
+        source,
+        battery_percent,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,189 @@
+//! Power source and battery level.
+//!
+//! Capture, OCR and frame encoding are the expensive parts of a monitor
+//! tick. When `schedule.min_battery_percent_for_capture` is set, the
+//! scheduler polls [`PowerTracker`] and skips them while running on battery
+//! below that level; metrics and context collection keep running.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+/// Battery readings change slowly and querying them spawns a process on
+/// macOS, so the tracker reuses a reading for this long.
+const DEFAULT_REFRESH: Duration = Duration::from_secs(60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PowerSource {
+    Ac,
+    Battery,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PowerStatus {
+    pub source: PowerSource,
+    /// Charge level 0–100, `None` when the platform does not report it.
+    pub battery_percent: Option<u8>,
+}
+
+/// `None` when the power state is unknown (e.g. a desktop without a battery).
+pub type PowerProbe = Arc<dyn Fn() -> Option<PowerStatus> + Send + Sync>;
+
+/// Whether heavy work (capture, OCR, encoding) may run under `status`.
+///
+/// Only a known battery reading strictly below `min_battery_percent` blocks
+/// it — on AC, at or above the threshold, or with an unknown state the work
+/// proceeds. `None` disables the check.
+pub fn heavy_tasks_allowed(status: Option<PowerStatus>, min_battery_percent: Option<u8>) -> bool {
+    let (Some(min), Some(status)) = (min_battery_percent, status) else {
+        return true;
+    };
+    match (status.source, status.battery_percent) {
+        (PowerSource::Battery, Some(percent)) => percent >= min,
+        _ => true,
+    }
+}
+
+pub struct PowerTracker {
+    refresh: Duration,
+    last: Option<(Instant, Option<PowerStatus>)>,
+    /// Overrides the OS query. `None` uses [`read_power_status`].
+    probe: Option<PowerProbe>,
+}
+
+impl Default for PowerTracker {
+    fn default() -> Self {
+        Self {
+            refresh: DEFAULT_REFRESH,
+            last: None,
+            probe: None,
+        }
+    }
+}
+
+impl PowerTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the power state from `probe` instead of the OS.
+    pub fn with_probe(mut self, probe: PowerProbe) -> Self {
+        self.probe = Some(probe);
+        self
+    }
+
+    pub fn with_refresh(mut self, refresh: Duration) -> Self {
+        self.refresh = refresh;
+        self
+    }
+
+    /// Current power state, re-read at most once per refresh interval.
+    pub async fn status(&mut self) -> Option<PowerStatus> {
+        if let Some((read_at, status)) = self.last {
+            if read_at.elapsed() < self.refresh {
+                return status;
+            }
+        }
+        let status = match &self.probe {
+            Some(probe) => probe(),
+            None => read_power_status().await,
+        };
+        if status != self.last.and_then(|(_, previous)| previous) {
+            debug!("power state: {status:?}");
+        }
+        self.last = Some((Instant::now(), status));
+        status
+    }
+}
+
+pub async fn read_power_status() -> Option<PowerStatus> {
+    #[cfg(target_os = "macos")]
+    {
+        crate::macos::power_status_macos().await
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        crate::windows::power_status_windows()
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        crate::linux::power_status_linux()
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    fn battery(percent: u8) -> Option<PowerStatus> {
+        Some(PowerStatus {
+            source: PowerSource::Battery,
+            battery_percent: Some(percent),
+        })
+    }
+
+    fn ac(percent: u8) -> Option<PowerStatus> {
+        Some(PowerStatus {
+            source: PowerSource::Ac,
+            battery_percent: Some(percent),
+        })
+    }
+
+    #[test]
+    fn battery_below_threshold_blocks_heavy_tasks() {
+        assert!(!heavy_tasks_allowed(battery(19), Some(20)));
+        assert!(!heavy_tasks_allowed(battery(0), Some(20)));
+    }
+
+    #[test]
+    fn battery_at_or_above_threshold_allows_heavy_tasks() {
+        assert!(heavy_tasks_allowed(battery(20), Some(20)));
+        assert!(heavy_tasks_allowed(battery(21), Some(20)));
+    }
+
+    #[test]
+    fn ac_power_allows_heavy_tasks_at_any_level() {
+        assert!(heavy_tasks_allowed(ac(5), Some(20)));
+        assert!(heavy_tasks_allowed(ac(20), Some(20)));
+        assert!(heavy_tasks_allowed(ac(90), Some(20)));
+    }
+
+    #[test]
+    fn disabled_or_unknown_state_allows_heavy_tasks() {
+        assert!(heavy_tasks_allowed(battery(5), None));
+        assert!(heavy_tasks_allowed(None, Some(20)));
+        assert!(heavy_tasks_allowed(
+            Some(PowerStatus {
+                source: PowerSource::Battery,
+                battery_percent: None,
+            }),
+            Some(20)
+        ));
+    }
+
+    #[tokio::test]
+    async fn tracker_reuses_reading_within_refresh_interval() {
+        let level = Arc::new(AtomicU8::new(50));
+        let probe_level = level.clone();
+        let probe: PowerProbe = Arc::new(move || battery(probe_level.load(Ordering::SeqCst)));
+
+        let mut cached = PowerTracker::new().with_probe(probe.clone());
+        let mut fresh = PowerTracker::new()
+            .with_probe(probe)
+            .with_refresh(Duration::ZERO);
+        assert_eq!(cached.status().await, battery(50));
+        assert_eq!(fresh.status().await, battery(50));
+
+        level.store(10, Ordering::SeqCst);
+        assert_eq!(cached.status().await, battery(50));
+        assert_eq!(fresh.status().await, battery(10));
+    }
+}
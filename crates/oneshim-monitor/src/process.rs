@@ -3,6 +3,7 @@ use oneshim_core::error::CoreError;
 use oneshim_core::models::context::{ProcessInfo, WindowInfo};
 use oneshim_core::models::event::ProcessDetail;
 use oneshim_core::ports::monitor::ProcessMonitor;
+use oneshim_core::title_pattern::NameFilter;
 use std::collections::HashSet;
 use std::sync::Mutex;
 use std::time::{Duration, Instant};
@@ -16,6 +17,8 @@ pub struct ProcessTracker {
     sys: Mutex<System>,
     /// Timestamp of the last `refresh_processes` call.
     last_refresh: Mutex<Instant>,
+    /// Processes this rejects are left out of every listing.
+    filter: NameFilter,
 }
 
 impl ProcessTracker {
@@ -23,9 +26,17 @@ impl ProcessTracker {
         Self {
             sys: Mutex::new(System::new_all()),
             last_refresh: Mutex::new(Instant::now()),
+            filter: NameFilter::default(),
         }
     }
 
+    /// Record only processes whose name `filter` permits. Filtering happens
+    /// before the top-N cut, so denied processes do not take up slots.
+    pub fn with_filter(mut self, filter: NameFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
     /// Refresh the process list only if the cooldown has elapsed.
     fn refresh_if_stale(&self, sys: &mut System) {
         let mut last = self.last_refresh.lock().unwrap_or_else(|e| e.into_inner());
@@ -100,6 +111,7 @@ impl ProcessMonitor for ProcessTracker {
                 cpu_usage: p.cpu_usage(),
                 memory_bytes: p.memory(),
             })
+            .filter(|p| self.filter.permits(&p.name))
             .collect();
 
         processes.sort_by(|a, b| {
@@ -134,6 +146,7 @@ impl ProcessMonitor for ProcessTracker {
         let mut all_details: Vec<ProcessDetail> = sys
             .processes()
             .values()
+            .filter(|p| self.filter.permits(&p.name().to_string_lossy()))
             .map(|p| {
                 let pid = p.pid().as_u32();
                 let start_time = p.start_time();
@@ -215,6 +228,7 @@ impl ProcessMonitor for ProcessTracker {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use oneshim_core::title_pattern::PatternSet;
 
     #[tokio::test]
     async fn get_top_processes() {
@@ -224,6 +238,32 @@ mod tests {
         assert!(!procs.is_empty());
     }
 
+    #[tokio::test]
+    async fn denied_process_is_excluded_from_snapshot() {
+        let all = ProcessTracker::new()
+            .get_top_processes(10_000)
+            .await
+            .unwrap();
+        let denied = all[0].name.clone();
+        assert!(
+            all.iter().any(|p| p.name != denied),
+            "need a second process"
+        );
+
+        let filter = NameFilter::new(
+            PatternSet::default(),
+            PatternSet::compile(std::slice::from_ref(&denied)).unwrap(),
+        );
+        let tracker = ProcessTracker::new().with_filter(filter);
+
+        let top = tracker.get_top_processes(10_000).await.unwrap();
+        assert!(!top.is_empty());
+        assert!(top.iter().all(|p| p.name != denied));
+        let detailed = tracker.get_detailed_processes(None, 10_000).await.unwrap();
+        assert!(!detailed.is_empty());
+        assert!(detailed.iter().all(|p| p.name != denied));
+    }
+
     #[tokio::test]
     async fn refresh_if_stale_skips_within_cooldown() {
         let tracker = ProcessTracker::new();
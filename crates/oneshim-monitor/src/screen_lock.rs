@@ -0,0 +1,150 @@
+//! Screen lock detection.
+//!
+//! While the screen is locked a capture only shows the lock screen, so the
+//! scheduler polls [`ScreenLockTracker`] each monitor tick to pause capture
+//! and collection and to notice lock/unlock transitions.
+
+use std::sync::Arc;
+use tracing::debug;
+
+/// `Some(true)` while the screen is locked, `None` when unknown.
+pub type LockProbe = Arc<dyn Fn() -> Option<bool> + Send + Sync>;
+
+/// A lock-state transition between two checks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScreenLockChange {
+    Locked,
+    Unlocked,
+}
+
+/// Result of one [`ScreenLockTracker::check`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScreenLockStatus {
+    /// Treated as unlocked when the platform cannot tell.
+    pub locked: bool,
+    pub change: Option<ScreenLockChange>,
+}
+
+#[derive(Default)]
+pub struct ScreenLockTracker {
+    locked: bool,
+    /// Overrides the OS query. `None` uses [`is_screen_locked`].
+    probe: Option<LockProbe>,
+}
+
+impl ScreenLockTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read the lock state from `probe` instead of the OS.
+    pub fn with_probe(mut self, probe: LockProbe) -> Self {
+        self.probe = Some(probe);
+        self
+    }
+
+    pub async fn check(&mut self) -> ScreenLockStatus {
+        let observed = match &self.probe {
+            Some(probe) => probe(),
+            None => is_screen_locked().await,
+        };
+        self.observe(observed)
+    }
+
+    /// Fold one observation into the tracked state. An unknown reading keeps
+    /// the previous state so a flaky probe does not flap.
+    pub fn observe(&mut self, observed: Option<bool>) -> ScreenLockStatus {
+        let locked = observed.unwrap_or(self.locked);
+        let change = match (self.locked, locked) {
+            (false, true) => Some(ScreenLockChange::Locked),
+            (true, false) => Some(ScreenLockChange::Unlocked),
+            _ => None,
+        };
+        if let Some(change) = change {
+            debug!("screen lock state changed: {change:?}");
+        }
+        self.locked = locked;
+        ScreenLockStatus { locked, change }
+    }
+
+    pub fn is_locked(&self) -> bool {
+        self.locked
+    }
+}
+
+pub async fn is_screen_locked() -> Option<bool> {
+    #[cfg(target_os = "macos")]
+    {
+        crate::macos::is_screen_locked_macos().await
+    }
+
+    #[cfg(target_os = "windows")]
+    {
+        crate::windows::is_screen_locked_windows()
+    }
+
+    #[cfg(target_os = "linux")]
+    {
+        crate::linux::is_screen_locked_linux().await
+    }
+
+    #[cfg(not(any(target_os = "macos", target_os = "windows", target_os = "linux")))]
+    {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicU8, Ordering};
+
+    #[test]
+    fn reports_lock_and_unlock_transitions() {
+        let mut tracker = ScreenLockTracker::new();
+
+        assert_eq!(
+            tracker.observe(Some(false)),
+            ScreenLockStatus {
+                locked: false,
+                change: None
+            }
+        );
+        assert_eq!(
+            tracker.observe(Some(true)).change,
+            Some(ScreenLockChange::Locked)
+        );
+        assert_eq!(tracker.observe(Some(true)).change, None);
+        assert!(tracker.is_locked());
+        assert_eq!(
+            tracker.observe(Some(false)).change,
+            Some(ScreenLockChange::Unlocked)
+        );
+    }
+
+    #[test]
+    fn unknown_reading_keeps_previous_state() {
+        let mut tracker = ScreenLockTracker::new();
+        tracker.observe(Some(true));
+
+        let status = tracker.observe(None);
+        assert!(status.locked);
+        assert_eq!(status.change, None);
+    }
+
+    #[tokio::test]
+    async fn check_uses_injected_probe() {
+        // 0 = unlocked, 1 = locked
+        let state = Arc::new(AtomicU8::new(0));
+        let probe_state = state.clone();
+        let mut tracker = ScreenLockTracker::new().with_probe(Arc::new(move || {
+            Some(probe_state.load(Ordering::SeqCst) == 1)
+        }));
+
+        assert!(!tracker.check().await.locked);
+        state.store(1, Ordering::SeqCst);
+        let status = tracker.check().await;
+        assert!(status.locked);
+        assert_eq!(status.change, Some(ScreenLockChange::Locked));
+    }
+}
@@ -1,6 +1,7 @@
 #![cfg(target_os = "windows")]
 
 use crate::error::MonitorError;
+use crate::power::{PowerSource, PowerStatus};
 use oneshim_core::models::context::{MousePosition, WindowBounds, WindowInfo};
 use tracing::debug;
 use windows_sys::Win32::Foundation::{HWND, POINT, RECT};
@@ -107,6 +108,25 @@ pub fn get_idle_time_windows() -> Option<u64> {
     }
 }
 
+/// The input desktop cannot be opened while the secure (lock) desktop is
+/// active, so a failed `OpenInputDesktop` means the session is locked.
+pub fn is_screen_locked_windows() -> Option<bool> {
+    use windows_sys::Win32::System::StationsAndDesktops::{
+        CloseDesktop, OpenInputDesktop, DESKTOP_SWITCHDESKTOP,
+    };
+
+    // SAFETY: OpenInputDesktop has no pointer arguments; a non-null handle is
+    // released with CloseDesktop before returning.
+    unsafe {
+        let desktop = OpenInputDesktop(0, 0, DESKTOP_SWITCHDESKTOP);
+        if desktop.is_null() {
+            return Some(true);
+        }
+        CloseDesktop(desktop);
+        Some(false)
+    }
+}
+
 pub fn get_mouse_position_windows() -> Option<MousePosition> {
     // SAFETY: GetCursorPos writes into a stack-allocated POINT via valid &mut.
     // zeroed() produces a valid POINT (all-zero POD struct). No resources to free.
@@ -123,5 +143,37 @@ pub fn get_mouse_position_windows() -> Option<MousePosition> {
     }
 }
 
+/// Power state from `GetSystemPowerStatus`. `None` without a battery.
+pub fn power_status_windows() -> Option<PowerStatus> {
+    use windows_sys::Win32::System::Power::{GetSystemPowerStatus, SYSTEM_POWER_STATUS};
+
+    // Battery flag 128 = no system battery; 255 = unknown status/percent.
+    const NO_BATTERY: u8 = 128;
+    const UNKNOWN: u8 = 255;
+
+    // SAFETY: GetSystemPowerStatus writes into a stack-allocated
+    // SYSTEM_POWER_STATUS via a valid &mut; zeroed() is a valid POD value.
+    let status = unsafe {
+        let mut status: SYSTEM_POWER_STATUS = std::mem::zeroed();
+        if GetSystemPowerStatus(&mut status) == 0 {
+            return None;
+        }
+        status
+    };
+    if status.BatteryFlag == UNKNOWN || status.BatteryFlag & NO_BATTERY != 0 {
+        return None;
+    }
+    let source = if status.ACLineStatus == 0 {
+        PowerSource::Battery
+    } else {
+        PowerSource::Ac
+    };
+    Some(PowerStatus {
+        source,
+        battery_percent: (status.BatteryLifePercent != UNKNOWN)
+            .then_some(status.BatteryLifePercent),
+    })
+}
+
 #[cfg(test)]
 mod tests {}
@@ -1,13 +1,16 @@
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
 use chrono::{DateTime, Duration, Utc};
 use oneshim_core::config::TlsConfig;
 use oneshim_core::error::CoreError;
 use serde::Deserialize;
 use std::sync::Arc;
 use std::time::Duration as StdDuration;
-use tokio::sync::RwLock;
+use tokio::sync::{Mutex, RwLock};
 use tracing::{debug, warn};
 
 use crate::error::NetworkError;
+use crate::failover::ServerEndpoints;
 use crate::http_client::build_reqwest_client;
 
 #[derive(Debug, Deserialize)]
@@ -24,11 +27,37 @@ struct TokenState {
     expires_at: DateTime<Utc>,
 }
 
+/// Refresh this long before the access token expires.
+pub const DEFAULT_REFRESH_SKEW: StdDuration = StdDuration::from_secs(60);
+
 #[derive(Clone)]
 pub struct TokenManager {
-    base_url: String,
+    endpoints: Arc<ServerEndpoints>,
     client: reqwest::Client,
     state: Arc<RwLock<Option<TokenState>>>,
+    refresh_skew: Duration,
+    /// Held for the whole of a proactive refresh so concurrent callers wait
+    /// for one refresh instead of each starting their own.
+    refresh_lock: Arc<Mutex<()>>,
+}
+
+#[derive(Deserialize)]
+struct JwtClaims {
+    exp: i64,
+}
+
+/// The `exp` claim of a JWT access token. Opaque tokens yield `None`. The
+/// signature is not checked — this only schedules refreshes.
+fn jwt_expiry(token: &str) -> Option<DateTime<Utc>> {
+    let payload = token.split('.').nth(1)?;
+    let claims: JwtClaims = serde_json::from_slice(&URL_SAFE_NO_PAD.decode(payload).ok()?).ok()?;
+    DateTime::from_timestamp(claims.exp, 0)
+}
+
+/// Expiry from the token's own `exp` claim, else the response's `expires_in`.
+fn token_expiry(token: &TokenResponse) -> DateTime<Utc> {
+    jwt_expiry(&token.access_token)
+        .unwrap_or_else(|| Utc::now() + Duration::seconds(token.expires_in.unwrap_or(3600)))
 }
 
 /// `Retry-After` 헤더 파싱 — 초(integer) 형식만 지원하며 최대 60초로 제한한다.
@@ -52,9 +81,11 @@ impl TokenManager {
     #[deprecated(note = "Use new_with_tls() for TLS enforcement")]
     pub fn new(base_url: &str) -> Self {
         Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+            endpoints: Arc::new(ServerEndpoints::single(base_url)),
             client: reqwest::Client::new(),
             state: Arc::new(RwLock::new(None)),
+            refresh_skew: Duration::from_std(DEFAULT_REFRESH_SKEW).unwrap_or_default(),
+            refresh_lock: Arc::new(Mutex::new(())),
         }
     }
 
@@ -74,9 +105,11 @@ impl TokenManager {
     /// ```
     pub fn new_with_client(base_url: &str, client: reqwest::Client) -> Self {
         Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+            endpoints: Arc::new(ServerEndpoints::single(base_url)),
             client,
             state: Arc::new(RwLock::new(None)),
+            refresh_skew: Duration::from_std(DEFAULT_REFRESH_SKEW).unwrap_or_default(),
+            refresh_lock: Arc::new(Mutex::new(())),
         }
     }
 
@@ -95,6 +128,19 @@ impl TokenManager {
         Ok(Self::new_with_client(base_url, client))
     }
 
+    /// Send auth requests to the server selected by `endpoints`, shared with
+    /// the API and SSE clients. Replaces the constructor's `base_url`.
+    pub fn with_endpoints(mut self, endpoints: Arc<ServerEndpoints>) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// How long before expiry [`Self::ensure_valid`] refreshes the token.
+    pub fn with_refresh_skew(mut self, skew: StdDuration) -> Self {
+        self.refresh_skew = Duration::from_std(skew).unwrap_or(self.refresh_skew);
+        self
+    }
+
     /// # Arguments
     pub async fn login(&self, email: &str, password: &str) -> Result<(), CoreError> {
         let organization_id =
@@ -108,7 +154,7 @@ impl TokenManager {
         password: &str,
         organization_id: &str,
     ) -> Result<(), CoreError> {
-        let url = format!("{}/api/v1/auth/tokens", self.base_url);
+        let url = format!("{}/api/v1/auth/tokens", self.endpoints.active_base_url());
         let body = serde_json::json!({
             "identifier": email,
             "password": password,
@@ -159,7 +205,7 @@ impl TokenManager {
             message: format!("Token parsing failed: {e}"),
         })?;
 
-        let expires_at = Utc::now() + Duration::seconds(token_resp.expires_in.unwrap_or(3600));
+        let expires_at = token_expiry(&token_resp);
 
         let mut state = self.state.write().await;
         *state = Some(TokenState {
@@ -191,7 +237,10 @@ impl TokenManager {
             message: "Refresh token is missing".to_string(),
         })?;
 
-        let url = format!("{}/api/v1/auth/tokens/refresh", self.base_url);
+        let url = format!(
+            "{}/api/v1/auth/tokens/refresh",
+            self.endpoints.active_base_url()
+        );
 
         let mut last_err = CoreError::Auth {
             code: oneshim_core::error_codes::AuthCode::Failed,
@@ -216,8 +265,7 @@ impl TokenManager {
                                 message: format!("refresh Token parsing failed: {e}"),
                             })?;
 
-                        let expires_at =
-                            Utc::now() + Duration::seconds(token_resp.expires_in.unwrap_or(3600));
+                        let expires_at = token_expiry(&token_resp);
 
                         let mut state = self.state.write().await;
                         *state = Some(TokenState {
@@ -314,29 +362,45 @@ impl TokenManager {
         Err(last_err)
     }
 
-    pub async fn get_token(&self) -> Result<String, CoreError> {
-        let needs_refresh = {
-            let state = self.state.read().await;
-            match &*state {
-                Some(s) => Utc::now() + Duration::minutes(5) >= s.expires_at,
-                None => {
-                    return Err(CoreError::Auth {
-                        code: oneshim_core::error_codes::AuthCode::Failed,
-                        message: "Not authenticated".to_string(),
-                    })
-                }
+    /// Refresh the access token if it expires within the refresh skew.
+    /// Concurrent callers share a single refresh.
+    pub async fn ensure_valid(&self) -> Result<(), CoreError> {
+        if !self.needs_refresh().await? {
+            return Ok(());
+        }
+        let _refreshing = self.refresh_lock.lock().await;
+        // Whoever held the lock before us may already have refreshed.
+        if !self.needs_refresh().await? {
+            return Ok(());
+        }
+        self.refresh().await.map_err(|e| {
+            warn!("token refresh failure: {e}");
+            CoreError::Auth {
+                code: oneshim_core::error_codes::AuthCode::Failed,
+                message: format!("Automatic token refresh failed: {e}"),
             }
-        };
+        })
+    }
 
-        if needs_refresh {
-            self.refresh().await.map_err(|e| {
-                warn!("token refresh failure: {e}");
-                CoreError::Auth {
-                    code: oneshim_core::error_codes::AuthCode::Failed,
-                    message: format!("Automatic token refresh failed: {e}"),
-                }
-            })?;
+    async fn needs_refresh(&self) -> Result<bool, CoreError> {
+        let state = self.state.read().await;
+        match &*state {
+            Some(s) => Ok(Utc::now() + self.refresh_skew >= s.expires_at),
+            None => Err(CoreError::Auth {
+                code: oneshim_core::error_codes::AuthCode::Failed,
+                message: "Not authenticated".to_string(),
+            }),
         }
+    }
+
+    /// When the current access token expires, if logged in.
+    pub async fn expires_at(&self) -> Option<DateTime<Utc>> {
+        self.state.read().await.as_ref().map(|s| s.expires_at)
+    }
+
+    /// The current access token, refreshed first via [`Self::ensure_valid`].
+    pub async fn get_token(&self) -> Result<String, CoreError> {
+        self.ensure_valid().await?;
 
         let state = self.state.read().await;
         state
@@ -350,7 +414,10 @@ impl TokenManager {
 
     pub async fn verify(&self) -> Result<bool, CoreError> {
         let token = self.get_token().await?;
-        let url = format!("{}/api/v1/auth/tokens/verify", self.base_url);
+        let url = format!(
+            "{}/api/v1/auth/tokens/verify",
+            self.endpoints.active_base_url()
+        );
 
         let resp = self
             .client
@@ -370,7 +437,7 @@ impl TokenManager {
         let token = self.get_token().await.ok();
 
         if let Some(token) = token {
-            let url = format!("{}/api/v1/auth/tokens", self.base_url);
+            let url = format!("{}/api/v1/auth/tokens", self.endpoints.active_base_url());
             if let Err(e) = self.client.delete(&url).bearer_auth(&token).send().await {
                 tracing::warn!("server-side token revocation failed (local state cleared): {e}");
             }
@@ -429,20 +496,20 @@ mod tests {
     #[test]
     fn token_manager_creation() {
         let tm = TokenManager::new("http://localhost:8000");
-        assert_eq!(tm.base_url, "http://localhost:8000");
+        assert_eq!(tm.endpoints.active_base_url(), "http://localhost:8000");
     }
 
     #[test]
     fn token_manager_trailing_slash() {
         let tm = TokenManager::new("http://localhost:8000/");
-        assert_eq!(tm.base_url, "http://localhost:8000");
+        assert_eq!(tm.endpoints.active_base_url(), "http://localhost:8000");
     }
 
     #[test]
     fn new_with_client_strips_trailing_slash() {
         let client = reqwest::Client::new();
         let tm = TokenManager::new_with_client("http://localhost:8000/", client);
-        assert_eq!(tm.base_url, "http://localhost:8000");
+        assert_eq!(tm.endpoints.active_base_url(), "http://localhost:8000");
     }
 
     #[test]
@@ -450,6 +517,7 @@ mod tests {
         let tls = TlsConfig {
             enabled: false,
             allow_self_signed: false,
+            ..TlsConfig::default()
         };
         let tm = TokenManager::new_with_tls(
             "http://localhost:8000",
@@ -457,7 +525,10 @@ mod tests {
             Some(std::time::Duration::from_secs(5)),
         );
         assert!(tm.is_ok());
-        assert_eq!(tm.unwrap().base_url, "http://localhost:8000");
+        assert_eq!(
+            tm.unwrap().endpoints.active_base_url(),
+            "http://localhost:8000"
+        );
     }
 
     #[test]
@@ -564,6 +635,59 @@ mod tests {
         refresh_mock.assert_async().await;
     }
 
+    fn jwt_expiring_at(exp: DateTime<Utc>) -> String {
+        let header = URL_SAFE_NO_PAD.encode(br#"{"alg":"HS256","typ":"JWT"}"#);
+        let claims = URL_SAFE_NO_PAD.encode(format!(r#"{{"exp":{}}}"#, exp.timestamp()));
+        format!("{header}.{claims}.sig")
+    }
+
+    #[test]
+    fn jwt_exp_claim_is_parsed() {
+        let exp = DateTime::from_timestamp(1_900_000_000, 0).unwrap();
+        assert_eq!(jwt_expiry(&jwt_expiring_at(exp)), Some(exp));
+        assert_eq!(jwt_expiry("opaque_token"), None);
+        assert_eq!(jwt_expiry("a.not-base64!.c"), None);
+    }
+
+    #[tokio::test]
+    async fn token_near_expiry_is_refreshed_once_for_concurrent_callers() {
+        let mut server = mockito::Server::new_async().await;
+        // The JWT's own `exp` (5s out) wins over the generous `expires_in`.
+        let expiring = jwt_expiring_at(Utc::now() + Duration::seconds(5));
+        let _login = server
+            .mock("POST", "/api/v1/auth/tokens")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(format!(
+                r#"{{"access_token":"{expiring}","refresh_token":"ref_tok","expires_in":3600}}"#
+            ))
+            .create_async()
+            .await;
+        let refresh_mock = server
+            .mock("POST", "/api/v1/auth/tokens/refresh")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"access_token":"new_jwt","refresh_token":"new_ref","expires_in":3600}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let tm = TokenManager::new(&server.url());
+        tm.login("user@test.com", "pass").await.unwrap();
+        assert!(tm.expires_at().await.unwrap() < Utc::now() + Duration::seconds(10));
+
+        let callers: Vec<_> = (0..8)
+            .map(|_| {
+                let tm = tm.clone();
+                tokio::spawn(async move { tm.get_token().await })
+            })
+            .collect();
+        for caller in callers {
+            assert_eq!(caller.await.unwrap().unwrap(), "new_jwt");
+        }
+        refresh_mock.assert_async().await;
+    }
+
     #[tokio::test]
     async fn get_token_propagates_refresh_failure() {
         let mut server = mockito::Server::new_async().await;
@@ -2,8 +2,14 @@ use crate::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig, CircuitState}
 use crate::error::NetworkError;
 use crossbeam::queue::SegQueue;
 use oneshim_core::error::CoreError;
-use oneshim_core::models::event::{Event, EventBatch};
+use oneshim_core::models::event::{
+    new_idempotency_key, BatchUploadResult, Event, EventBatch, QuarantinedEvent, UploadPriority,
+};
 use oneshim_core::ports::api_client::ApiClient;
+use oneshim_core::ports::batch_sink::FlushReport;
+use oneshim_core::ports::clock::{Clock, SystemClock};
+use oneshim_core::ports::storage::StorageService;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
@@ -19,9 +25,35 @@ const QUEUE_PRESSURE_WARN_RATIO: f64 = 0.80;
 /// Threshold ratio (90%) at which a critical error is emitted.
 const QUEUE_PRESSURE_CRITICAL_RATIO: f64 = 0.90;
 
+/// Retryable rejections an event may collect before it is quarantined.
+const MAX_ITEM_REJECTIONS: u32 = 3;
+
+/// Queued event plus the bookkeeping needed to report it back by row.
+struct QueuedEvent {
+    local_id: String,
+    rejections: u32,
+    /// Assigned on the first upload attempt and kept for every retry.
+    idempotency_key: Option<String>,
+    event: Event,
+}
+
+impl QueuedEvent {
+    fn new(local_id: String, event: Event) -> Self {
+        Self {
+            local_id,
+            rejections: 0,
+            idempotency_key: None,
+            event,
+        }
+    }
+}
+
 pub struct BatchUploader {
     api_client: Arc<dyn ApiClient>,
-    queue: Arc<SegQueue<Event>>,
+    /// One FIFO lane per [`UploadPriority`], indexed by `priority as usize`.
+    /// Without prioritization everything goes to the `Normal` lane.
+    lanes: [SegQueue<QueuedEvent>; 3],
+    prioritize: bool,
     queue_size: AtomicUsize,
     session_id: String,
     max_batch_size: usize,
@@ -45,6 +77,12 @@ pub struct BatchUploader {
     /// Suppression predicate wired by the tracking schedule (A.11).
     /// When it returns `true`, `flush()` early-returns `Ok(0)` without draining the queue.
     upload_suppressed: Arc<dyn Fn() -> bool + Send + Sync>,
+    /// Stamps `EventBatch::created_at`. A server-skew-corrected clock when
+    /// wired, so the server sees batch times on its own clock.
+    clock: Arc<dyn Clock>,
+    /// Source of the idempotency keys stored with each event row. Without
+    /// one, keys are issued per run and a restart re-sends under new keys.
+    key_store: Option<Arc<dyn StorageService>>,
 }
 
 impl BatchUploader {
@@ -56,7 +94,8 @@ impl BatchUploader {
     ) -> Self {
         Self {
             api_client,
-            queue: Arc::new(SegQueue::new()),
+            lanes: [SegQueue::new(), SegQueue::new(), SegQueue::new()],
+            prioritize: false,
             queue_size: AtomicUsize::new(0),
             session_id,
             max_batch_size,
@@ -70,6 +109,8 @@ impl BatchUploader {
             circuit_breaker: CircuitBreaker::new(CircuitBreakerConfig::default()),
             cycle_dropped: AtomicUsize::new(0),
             upload_suppressed: Arc::new(|| false),
+            clock: Arc::new(SystemClock),
+            key_store: None,
         }
     }
 
@@ -91,6 +132,14 @@ impl BatchUploader {
         self
     }
 
+    /// Flush higher-priority events first and, at capacity, evict the
+    /// lowest-priority events instead of the oldest. An incoming event that
+    /// ranks below everything queued is dropped itself.
+    pub fn with_prioritization(mut self, enabled: bool) -> Self {
+        self.prioritize = enabled;
+        self
+    }
+
     /// Attach a suppression predicate that gates each `flush()` call.
     ///
     /// When the predicate returns `true`, `flush()` skips draining the queue
@@ -102,6 +151,19 @@ impl BatchUploader {
         self
     }
 
+    /// Stamp outbound batches with `clock` instead of the system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Upload each event under the idempotency key stored with its row in
+    /// `storage`, so re-sends after a restart reuse the original key.
+    pub fn with_idempotency_key_store(mut self, storage: Arc<dyn StorageService>) -> Self {
+        self.key_store = Some(storage);
+        self
+    }
+
     /// Enqueue an event for batch upload.
     ///
     /// Note: Under heavy concurrent enqueue pressure, the queue may briefly
@@ -109,15 +171,25 @@ impl BatchUploader {
     /// check and the push. This is acceptable because the SegQueue is unbounded
     /// and the overshoot is limited to the number of concurrent callers.
     pub fn enqueue(&self, event: Event) {
+        self.enqueue_local(event.local_id(), event);
+    }
+
+    /// Enqueue an event whose local `events` row has id `local_id`, so flush
+    /// reports can name the row even if `event` was sanitized for upload.
+    pub fn enqueue_local(&self, local_id: String, event: Event) {
+        let priority = self.priority_of(&event);
         let size = self.queue_size.load(Ordering::Relaxed);
 
-        // If at capacity, drop the oldest entry to make room (newer data is
-        // more valuable for monitoring).
+        // If at capacity, evict to make room (newer data is more valuable for
+        // monitoring, higher-priority data more valuable still).
         if size >= self.max_queue_size {
-            self.drop_oldest(1);
+            self.record_dropped(1);
+            if !self.evict_one(priority) {
+                return;
+            }
         }
 
-        self.queue.push(event);
+        self.push(priority, QueuedEvent::new(local_id, event));
         let new_size = self.queue_size.fetch_add(1, Ordering::Relaxed) + 1;
         self.check_pressure(new_size);
         debug!("event add (lock-free), current size: {new_size}");
@@ -129,45 +201,132 @@ impl BatchUploader {
             return;
         }
 
-        let size = self.queue_size.load(Ordering::Relaxed);
+        let queued = events
+            .into_iter()
+            .map(|event| QueuedEvent::new(event.local_id(), event))
+            .collect();
+        let added = self.push_within_capacity(queued);
+        let new_size = self.queue_size.fetch_add(added, Ordering::Relaxed) + added;
+        self.check_pressure(new_size);
+        debug!("event {count}items add (lock-free), current size: {new_size}");
+    }
 
-        // Calculate how many oldest entries we need to evict to stay within
-        // capacity after inserting all new events.
-        let total_after = size + count;
-        if total_after > self.max_queue_size {
-            let overflow = total_after - self.max_queue_size;
-            self.drop_oldest(overflow);
+    /// Queue the next page of events `storage` still holds as unsent, so
+    /// uploads pending when the app last exited, or dropped at capacity, are
+    /// retried. Does nothing while events are queued: the caller flushes and
+    /// marks each report's `accepted` ids as sent, then calls again to work
+    /// through a backlog larger than the queue one page at a time. Rows stay
+    /// pending until acknowledged, so a crash at any point leaves them to be
+    /// restored again. Rows `prepare` leaves out have nothing to upload and
+    /// are marked sent so they do not hold up the rest of the backlog.
+    pub async fn restore_pending(
+        &self,
+        storage: &dyn StorageService,
+        prepare: &(dyn Fn(Event) -> Option<Event> + Send + Sync),
+    ) -> Result<usize, CoreError> {
+        loop {
+            if self.queue_size.load(Ordering::Relaxed) > 0 {
+                return Ok(0);
+            }
+            let pending = storage.get_pending_events(self.max_queue_size).await?;
+            if pending.is_empty() {
+                return Ok(0);
+            }
+            let mut skipped = Vec::new();
+            let queued: Vec<_> = pending
+                .into_iter()
+                .filter_map(|event| {
+                    let local_id = event.local_id();
+                    match prepare(event) {
+                        Some(event) => Some(QueuedEvent::new(local_id, event)),
+                        None => {
+                            skipped.push(local_id);
+                            None
+                        }
+                    }
+                })
+                .collect();
+            if !skipped.is_empty() {
+                storage.mark_as_sent(&skipped).await?;
+            }
+            if queued.is_empty() {
+                continue;
+            }
+            let added = self.push_within_capacity(queued);
+            let new_size = self.queue_size.fetch_add(added, Ordering::Relaxed) + added;
+            self.check_pressure(new_size);
+            return Ok(added);
         }
+    }
 
-        for event in events {
-            self.queue.push(event);
+    fn priority_of(&self, event: &Event) -> UploadPriority {
+        if self.prioritize {
+            event.upload_priority()
+        } else {
+            UploadPriority::Normal
         }
-        let new_size = self.queue_size.fetch_add(count, Ordering::Relaxed) + count;
-        self.check_pressure(new_size);
-        debug!("event {count}items add (lock-free), current size: {new_size}");
     }
 
-    /// Drop `count` oldest entries from the front of the queue.
-    fn drop_oldest(&self, count: usize) {
+    fn push(&self, priority: UploadPriority, queued: QueuedEvent) {
+        self.lanes[priority as usize].push(queued);
+    }
+
+    /// Push `events`, evicting queued entries as needed to stay within
+    /// capacity. Returns how many were pushed; the rest are counted as dropped.
+    fn push_within_capacity(&self, events: Vec<QueuedEvent>) -> usize {
+        let mut size = self.queue_size.load(Ordering::Relaxed);
+        let mut added = 0;
         let mut dropped = 0;
-        for _ in 0..count {
-            if self.queue.pop().is_some() {
+        for queued in events {
+            let priority = self.priority_of(&queued.event);
+            if size + added >= self.max_queue_size {
                 dropped += 1;
-            } else {
-                break;
+                if !self.evict_one(priority) {
+                    continue;
+                }
+                size -= 1;
             }
+            self.push(priority, queued);
+            added += 1;
         }
         if dropped > 0 {
-            self.queue_size.fetch_sub(dropped, Ordering::Relaxed);
-            self.total_dropped.fetch_add(dropped, Ordering::Relaxed);
-            self.cycle_dropped.fetch_add(dropped, Ordering::Relaxed);
-            warn!(
-                dropped,
-                total_dropped = self.total_dropped.load(Ordering::Relaxed),
-                max = self.max_queue_size,
-                "upload queue at capacity, dropped oldest event(s)",
-            );
+            self.record_dropped(dropped);
         }
+        added
+    }
+
+    /// Evict the oldest event from the lowest lane that does not outrank
+    /// `incoming`. Returns `false` when everything queued outranks it, in
+    /// which case the incoming event is the one to drop.
+    fn evict_one(&self, incoming: UploadPriority) -> bool {
+        let evicted = UploadPriority::ALL
+            .iter()
+            .filter(|priority| **priority <= incoming)
+            .any(|priority| self.lanes[*priority as usize].pop().is_some());
+        if evicted {
+            self.queue_size.fetch_sub(1, Ordering::Relaxed);
+        }
+        evicted
+    }
+
+    fn record_dropped(&self, dropped: usize) {
+        self.total_dropped.fetch_add(dropped, Ordering::Relaxed);
+        self.cycle_dropped.fetch_add(dropped, Ordering::Relaxed);
+        warn!(
+            dropped,
+            total_dropped = self.total_dropped.load(Ordering::Relaxed),
+            max = self.max_queue_size,
+            prioritized = self.prioritize,
+            "upload queue at capacity, dropped event(s)",
+        );
+    }
+
+    /// Pop the next event to upload, highest priority first.
+    fn pop_next(&self) -> Option<QueuedEvent> {
+        UploadPriority::ALL
+            .iter()
+            .rev()
+            .find_map(|priority| self.lanes[*priority as usize].pop())
     }
 
     /// Emit a warning once when the queue reaches the 80% pressure threshold,
@@ -212,15 +371,23 @@ impl BatchUploader {
     }
 
     pub async fn flush(&self) -> Result<usize, NetworkError> {
+        self.flush_report().await.map(|report| report.sent())
+    }
+
+    /// Upload one batch and settle each item by the server's verdict:
+    /// accepted items are reported for marking as sent, retryable rejections
+    /// are requeued up to [`MAX_ITEM_REJECTIONS`] times, and everything else
+    /// the server rejected is quarantined with its reason.
+    pub async fn flush_report(&self) -> Result<FlushReport, NetworkError> {
         if (self.upload_suppressed)() {
             debug!("upload flush suppressed — tracking schedule active");
-            return Ok(0);
+            return Ok(FlushReport::default());
         }
 
         let current_size = self.queue_size.load(Ordering::Relaxed);
 
         if current_size == 0 {
-            return Ok(0);
+            return Ok(FlushReport::default());
         }
 
         // Circuit breaker fast-fail
@@ -238,38 +405,54 @@ impl BatchUploader {
         let batch_size = self.compute_batch_size(current_size);
         let drain_count = current_size.min(batch_size);
 
-        let mut events = Vec::with_capacity(drain_count);
+        let mut drained = Vec::with_capacity(drain_count);
         for _ in 0..drain_count {
-            if let Some(event) = self.queue.pop() {
-                events.push(event);
+            if let Some(queued) = self.pop_next() {
+                drained.push(queued);
             } else {
                 break;
             }
         }
 
-        let actual_count = events.len();
+        let actual_count = drained.len();
         if actual_count == 0 {
-            return Ok(0);
+            return Ok(FlushReport::default());
         }
 
         self.queue_size.fetch_sub(actual_count, Ordering::Relaxed);
 
-        let batch = EventBatch {
-            session_id: self.session_id.clone(),
+        self.assign_idempotency_keys(&mut drained).await;
+        let mut rows = Vec::with_capacity(actual_count);
+        let mut keys = Vec::with_capacity(actual_count);
+        let mut events = Vec::with_capacity(actual_count);
+        for queued in drained {
+            rows.push((queued.local_id, queued.rejections));
+            keys.push(queued.idempotency_key.unwrap_or_else(new_idempotency_key));
+            events.push(queued.event);
+        }
+        let batch = EventBatch::with_idempotency_keys(
+            self.session_id.clone(),
             events,
-            created_at: chrono::Utc::now(),
-        };
+            keys,
+            self.clock.now(),
+        );
 
         let mut retry_delay = Duration::from_secs(1);
         for attempt in 0..=self.max_retries {
-            match self.api_client.upload_batch(&batch).await {
-                Ok(()) => {
+            match self.api_client.upload_batch_itemized(&batch).await {
+                Ok(result) => {
                     if let Some(ref flag) = self.last_upload_ok {
                         flag.store(true, Ordering::Relaxed);
                     }
                     self.circuit_breaker.record_success();
-                    debug!("batch upload success: {actual_count}items event");
-                    return Ok(actual_count);
+                    let report = self.settle(batch, rows, &result);
+                    debug!(
+                        accepted = report.accepted.len(),
+                        quarantined = report.quarantined.len(),
+                        requeued = report.requeued,
+                        "batch upload success"
+                    );
+                    return Ok(report);
                 }
                 Err(e) => {
                     if attempt < self.max_retries {
@@ -287,33 +470,113 @@ impl BatchUploader {
                         }
                         self.circuit_breaker.record_failure();
                         self.failed_batches.fetch_add(1, Ordering::Relaxed);
-                        self.requeue_failed_events(batch.events);
+                        let queued = rows
+                            .into_iter()
+                            .zip(batch.idempotency_keys)
+                            .zip(batch.events)
+                            .map(|(((local_id, rejections), key), event)| QueuedEvent {
+                                local_id,
+                                rejections,
+                                idempotency_key: Some(key),
+                                event,
+                            })
+                            .collect();
+                        self.requeue_failed_events(queued);
                         return Err(e.into());
                     }
                 }
             }
         }
 
-        Ok(0)
+        Ok(FlushReport::default())
     }
 
-    fn requeue_failed_events(&self, events: Vec<Event>) {
-        let count = events.len();
-        let current_size = self.queue_size.load(Ordering::Relaxed);
-
-        // Respect the queue limit when requeueing failed events.
-        // Drop oldest entries if we would exceed capacity.
-        let total_after = current_size + count;
-        if total_after > self.max_queue_size {
-            let overflow = total_after - self.max_queue_size;
-            self.drop_oldest(overflow);
+    /// Give every drained event that has no key yet the one stored with its
+    /// row, or a fresh key when the row has none or no store is wired.
+    async fn assign_idempotency_keys(&self, drained: &mut [QueuedEvent]) {
+        let missing: Vec<String> = drained
+            .iter()
+            .filter(|queued| queued.idempotency_key.is_none())
+            .map(|queued| queued.local_id.clone())
+            .collect();
+        if missing.is_empty() {
+            return;
+        }
+        let stored = match &self.key_store {
+            Some(store) => store
+                .get_idempotency_keys(&missing)
+                .await
+                .unwrap_or_else(|e| {
+                    warn!("idempotency key lookup failed, issuing new keys: {e}");
+                    HashMap::new()
+                }),
+            None => HashMap::new(),
+        };
+        for queued in drained
+            .iter_mut()
+            .filter(|queued| queued.idempotency_key.is_none())
+        {
+            let key = stored
+                .get(&queued.local_id)
+                .cloned()
+                .unwrap_or_else(new_idempotency_key);
+            queued.idempotency_key = Some(key);
         }
+    }
 
-        for event in events {
-            self.queue.push(event);
+    /// Split an uploaded batch by the server's per-item verdicts. `rows`
+    /// carries each event's local id and prior rejection count, index-aligned
+    /// with `batch.events`.
+    fn settle(
+        &self,
+        batch: EventBatch,
+        rows: Vec<(String, u32)>,
+        result: &BatchUploadResult,
+    ) -> FlushReport {
+        let mut report = FlushReport::default();
+        let mut retry = Vec::new();
+        for ((key, event), (local_id, rejections)) in batch
+            .idempotency_keys
+            .into_iter()
+            .zip(batch.events)
+            .zip(rows)
+        {
+            match result.rejection(&key) {
+                None => report.accepted.push(local_id),
+                Some(item) if item.retryable && rejections + 1 < MAX_ITEM_REJECTIONS => {
+                    retry.push(QueuedEvent {
+                        local_id,
+                        rejections: rejections + 1,
+                        idempotency_key: Some(key),
+                        event,
+                    });
+                }
+                Some(item) => {
+                    let reason = item
+                        .reason
+                        .clone()
+                        .unwrap_or_else(|| "rejected by server".to_string());
+                    warn!(event_id = %local_id, %reason, "event quarantined");
+                    report.quarantined.push(QuarantinedEvent {
+                        event_id: local_id,
+                        reason,
+                    });
+                }
+            }
+        }
+        if !retry.is_empty() {
+            let added = self.push_within_capacity(retry);
+            self.queue_size.fetch_add(added, Ordering::Relaxed);
+            report.requeued = added;
         }
-        self.queue_size.fetch_add(count, Ordering::Relaxed);
-        warn!("failure event {count}items requeued");
+        report
+    }
+
+    fn requeue_failed_events(&self, events: Vec<QueuedEvent>) {
+        // Respect the queue limit when requeueing failed events.
+        let added = self.push_within_capacity(events);
+        self.queue_size.fetch_add(added, Ordering::Relaxed);
+        warn!("failure event {added}items requeued");
     }
 }
 
@@ -327,13 +590,29 @@ impl oneshim_core::ports::batch_sink::BatchSink for BatchUploader {
         BatchUploader::enqueue_many(self, events);
     }
 
-    async fn flush(&self) -> Result<usize, CoreError> {
-        BatchUploader::flush(self).await.map_err(Into::into)
+    fn enqueue_local(&self, local_id: String, event: Event) {
+        BatchUploader::enqueue_local(self, local_id, event);
+    }
+
+    async fn flush(&self) -> Result<FlushReport, CoreError> {
+        BatchUploader::flush_report(self).await.map_err(Into::into)
     }
 
     fn take_dropped_since_last(&self) -> usize {
         BatchUploader::take_dropped_since_last(self)
     }
+
+    async fn restore_pending(
+        &self,
+        storage: &dyn StorageService,
+        prepare: &(dyn Fn(Event) -> Option<Event> + Send + Sync),
+    ) -> Result<usize, CoreError> {
+        BatchUploader::restore_pending(self, storage, prepare).await
+    }
+
+    fn queue_len(&self) -> usize {
+        self.queue_size()
+    }
 }
 
 impl BatchUploader {
@@ -472,6 +751,33 @@ mod tests {
         assert_eq!(uploader.queue_size(), 0);
     }
 
+    #[tokio::test]
+    async fn batch_created_at_uses_skew_corrected_clock() {
+        use chrono::TimeZone;
+        use oneshim_core::ports::clock::{ManualClock, SkewCorrectedClock};
+
+        let local_now = chrono::Utc.with_ymd_and_hms(2026, 4, 22, 9, 0, 0).unwrap();
+        let local = Arc::new(ManualClock::new(local_now));
+        let clock = Arc::new(SkewCorrectedClock::new(local, Duration::from_secs(30)));
+        // Local clock runs 45 s behind the server.
+        clock.observe_server_time(local_now + chrono::Duration::seconds(45));
+
+        let client = Arc::new(RecordingApiClient {
+            batches: std::sync::Mutex::new(Vec::new()),
+        });
+        let uploader =
+            BatchUploader::new(client.clone(), "sess_1".to_string(), 100, 0).with_clock(clock);
+        uploader.enqueue(make_test_event());
+        uploader.flush().await.unwrap();
+
+        let batches = client.batches.lock().unwrap();
+        assert_eq!(batches.len(), 1);
+        assert_eq!(
+            batches[0].created_at,
+            local_now + chrono::Duration::seconds(45)
+        );
+    }
+
     #[tokio::test]
     async fn flush_empty_queue() {
         let client = Arc::new(MockApiClient { should_fail: false });
@@ -524,6 +830,18 @@ mod tests {
     struct FlakeyApiClient {
         call_count: std::sync::atomic::AtomicU32,
         fail_until: u32,
+        /// Idempotency keys of every upload attempt, in call order.
+        keys: std::sync::Mutex<Vec<Vec<String>>>,
+    }
+
+    impl FlakeyApiClient {
+        fn new(fail_until: u32) -> Self {
+            Self {
+                call_count: std::sync::atomic::AtomicU32::new(0),
+                fail_until,
+                keys: std::sync::Mutex::new(Vec::new()),
+            }
+        }
     }
 
     #[async_trait::async_trait]
@@ -542,7 +860,11 @@ mod tests {
         async fn end_session(&self, _session_id: &str) -> Result<(), CoreError> {
             Ok(())
         }
-        async fn upload_batch(&self, _batch: &EventBatch) -> Result<(), CoreError> {
+        async fn upload_batch(&self, batch: &EventBatch) -> Result<(), CoreError> {
+            self.keys
+                .lock()
+                .unwrap()
+                .push(batch.idempotency_keys.clone());
             let count = self
                 .call_count
                 .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
@@ -575,10 +897,7 @@ mod tests {
 
     #[tokio::test]
     async fn retry_on_transient_failure() {
-        let client = Arc::new(FlakeyApiClient {
-            call_count: std::sync::atomic::AtomicU32::new(0),
-            fail_until: 1,
-        });
+        let client = Arc::new(FlakeyApiClient::new(1));
         let uploader = BatchUploader::new(client, "sess_retry".to_string(), 100, 3);
 
         uploader.enqueue(make_test_event());
@@ -697,8 +1016,8 @@ mod tests {
         sink.enqueue(make_test_event());
         sink.enqueue_many(vec![make_test_event(), make_test_event()]);
 
-        let sent = sink.flush().await.unwrap();
-        assert_eq!(sent, 3);
+        let report = sink.flush().await.unwrap();
+        assert_eq!(report.sent(), 3);
     }
 
     #[test]
@@ -811,6 +1130,328 @@ mod tests {
         assert_eq!(stats.max_queue_size, 2000);
     }
 
+    // --- Prioritization tests ---
+
+    struct RecordingApiClient {
+        batches: std::sync::Mutex<Vec<EventBatch>>,
+    }
+
+    #[async_trait::async_trait]
+    impl ApiClient for RecordingApiClient {
+        async fn create_session(
+            &self,
+            client_id: &str,
+        ) -> Result<oneshim_core::ports::api_client::SessionCreateResponse, CoreError> {
+            Ok(oneshim_core::ports::api_client::SessionCreateResponse {
+                session_id: format!("sess_{client_id}"),
+                user_id: "user_1".to_string(),
+                client_id: client_id.to_string(),
+                capabilities: vec![],
+            })
+        }
+        async fn end_session(&self, _session_id: &str) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn upload_batch(&self, batch: &EventBatch) -> Result<(), CoreError> {
+            self.batches.lock().unwrap().push(batch.clone());
+            Ok(())
+        }
+        async fn upload_context(
+            &self,
+            _upload: &oneshim_core::models::frame::ContextUpload,
+        ) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn send_feedback(
+            &self,
+            _feedback: &oneshim_core::models::suggestion::SuggestionFeedback,
+        ) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn send_heartbeat(&self, _session_id: &str) -> Result<(), CoreError> {
+            Ok(())
+        }
+    }
+
+    fn make_system_event(
+        event_type: oneshim_core::models::event::SystemEventType,
+        tag: &str,
+    ) -> Event {
+        Event::System(oneshim_core::models::event::SystemEvent {
+            event_id: uuid::Uuid::new_v4(),
+            event_type,
+            timestamp: chrono::Utc::now(),
+            data: serde_json::json!({ "tag": tag }),
+        })
+    }
+
+    fn alert(tag: &str) -> Event {
+        make_system_event(oneshim_core::models::event::SystemEventType::Alert, tag)
+    }
+
+    fn metrics(tag: &str) -> Event {
+        make_system_event(
+            oneshim_core::models::event::SystemEventType::MetricsUpdate,
+            tag,
+        )
+    }
+
+    fn tags(batch: &EventBatch) -> Vec<String> {
+        batch
+            .events
+            .iter()
+            .map(|event| match event {
+                Event::System(system) => system.data["tag"].as_str().unwrap().to_string(),
+                _ => panic!("unexpected event"),
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn prioritized_backlog_uploads_high_priority_first() {
+        let client = Arc::new(RecordingApiClient {
+            batches: std::sync::Mutex::new(Vec::new()),
+        });
+        let uploader = BatchUploader::new(client.clone(), "sess_prio".to_string(), 2, 0)
+            .with_dynamic_batch(false)
+            .with_prioritization(true);
+
+        uploader.enqueue(metrics("m1"));
+        uploader.enqueue(make_test_event());
+        uploader.enqueue(metrics("m2"));
+        uploader.enqueue(alert("a1"));
+        uploader.enqueue(alert("a2"));
+
+        assert_eq!(uploader.flush().await.unwrap(), 2);
+        let batches = client.batches.lock().unwrap().clone();
+        assert_eq!(tags(&batches[0]), vec!["a1", "a2"]);
+
+        uploader.flush().await.unwrap();
+        uploader.flush().await.unwrap();
+        let batches = client.batches.lock().unwrap().clone();
+        assert!(matches!(batches[1].events[0], Event::Context(_)));
+        assert_eq!(tags(&batches[2]), vec!["m2"]);
+    }
+
+    /// `(tag, idempotency key)` pairs of a recorded batch, sorted by tag.
+    fn keys_by_tag(batch: &EventBatch) -> Vec<(String, String)> {
+        let mut pairs: Vec<_> = tags(batch)
+            .into_iter()
+            .zip(batch.idempotency_keys.iter().cloned())
+            .collect();
+        pairs.sort();
+        pairs
+    }
+
+    #[tokio::test]
+    async fn restored_event_carries_its_persisted_idempotency_key() {
+        use oneshim_storage::sqlite::SqliteStorage;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("events.db");
+        let events = vec![alert("a1"), alert("a2")];
+        let ids: Vec<String> = events.iter().map(Event::local_id).collect();
+        let keep_all = |event: Event| Some(event);
+
+        // First run: the event is saved and queued live, but the app exits
+        // before the upload is acknowledged.
+        let stored = {
+            let storage = Arc::new(SqliteStorage::open(&path, 30, None).unwrap());
+            storage.save_events(&events).await.unwrap();
+            let client = Arc::new(RecordingApiClient {
+                batches: std::sync::Mutex::new(Vec::new()),
+            });
+            let uploader = BatchUploader::new(client.clone(), "sess_before".to_string(), 10, 0)
+                .with_idempotency_key_store(storage.clone());
+            for event in &events {
+                uploader.enqueue_local(event.local_id(), event.clone());
+            }
+            uploader.flush().await.unwrap();
+            let stored = storage.get_idempotency_keys(&ids).await.unwrap();
+            let mut expected = vec![
+                ("a1".to_string(), stored[&ids[0]].clone()),
+                ("a2".to_string(), stored[&ids[1]].clone()),
+            ];
+            expected.sort();
+            assert_eq!(keys_by_tag(&client.batches.lock().unwrap()[0]), expected);
+            expected
+        };
+
+        // After a restart the rows are restored and re-sent under the same keys.
+        let storage = Arc::new(SqliteStorage::open(&path, 30, None).unwrap());
+        let client = Arc::new(RecordingApiClient {
+            batches: std::sync::Mutex::new(Vec::new()),
+        });
+        let uploader = BatchUploader::new(client.clone(), "sess_after".to_string(), 10, 0)
+            .with_idempotency_key_store(storage.clone());
+        assert_eq!(
+            uploader
+                .restore_pending(storage.as_ref(), &keep_all)
+                .await
+                .unwrap(),
+            2
+        );
+        uploader.flush().await.unwrap();
+        assert_eq!(keys_by_tag(&client.batches.lock().unwrap()[0]), stored);
+    }
+
+    #[tokio::test]
+    async fn requeued_event_keeps_its_idempotency_key() {
+        let client = Arc::new(FlakeyApiClient::new(1));
+        let uploader = BatchUploader::new(client.clone(), "sess_retry".to_string(), 10, 0);
+        uploader.enqueue(alert("a1"));
+
+        assert!(uploader.flush().await.is_err());
+        assert_eq!(uploader.flush().await.unwrap(), 1);
+        let keys = client.keys.lock().unwrap().clone();
+        assert_eq!(keys.len(), 2);
+        assert_eq!(keys[0], keys[1]);
+    }
+
+    /// Rejects `bad*` tags as malformed and `busy*` tags as retryable.
+    struct ItemizedApiClient;
+
+    #[async_trait::async_trait]
+    impl ApiClient for ItemizedApiClient {
+        async fn create_session(
+            &self,
+            client_id: &str,
+        ) -> Result<oneshim_core::ports::api_client::SessionCreateResponse, CoreError> {
+            Ok(oneshim_core::ports::api_client::SessionCreateResponse {
+                session_id: format!("sess_{client_id}"),
+                user_id: "user_1".to_string(),
+                client_id: client_id.to_string(),
+                capabilities: vec![],
+            })
+        }
+        async fn end_session(&self, _session_id: &str) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn upload_batch(&self, _batch: &EventBatch) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn upload_batch_itemized(
+            &self,
+            batch: &EventBatch,
+        ) -> Result<BatchUploadResult, CoreError> {
+            let items = tags(batch)
+                .into_iter()
+                .zip(&batch.idempotency_keys)
+                .map(|(tag, key)| oneshim_core::models::event::BatchItemResult {
+                    idempotency_key: key.clone(),
+                    accepted: !tag.starts_with("bad") && !tag.starts_with("busy"),
+                    reason: tag
+                        .starts_with("bad")
+                        .then(|| "schema: missing field `data.kind`".to_string()),
+                    retryable: tag.starts_with("busy"),
+                })
+                .collect();
+            Ok(BatchUploadResult { items })
+        }
+        async fn upload_context(
+            &self,
+            _upload: &oneshim_core::models::frame::ContextUpload,
+        ) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn send_feedback(
+            &self,
+            _feedback: &oneshim_core::models::suggestion::SuggestionFeedback,
+        ) -> Result<(), CoreError> {
+            Ok(())
+        }
+        async fn send_heartbeat(&self, _session_id: &str) -> Result<(), CoreError> {
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn mixed_batch_marks_accepted_and_quarantines_rejected() {
+        let uploader =
+            BatchUploader::new(Arc::new(ItemizedApiClient), "sess_mixed".to_string(), 10, 0);
+        let good = alert("good");
+        let bad = alert("bad");
+        uploader.enqueue_local("row-good".to_string(), good);
+        uploader.enqueue_local("row-bad".to_string(), bad);
+
+        let report = uploader.flush_report().await.unwrap();
+
+        assert_eq!(report.accepted, vec!["row-good".to_string()]);
+        assert_eq!(
+            report.quarantined,
+            vec![QuarantinedEvent {
+                event_id: "row-bad".to_string(),
+                reason: "schema: missing field `data.kind`".to_string(),
+            }]
+        );
+        assert_eq!(report.requeued, 0);
+        assert_eq!(uploader.queue_size(), 0);
+    }
+
+    #[tokio::test]
+    async fn retryable_rejection_is_requeued_then_quarantined() {
+        let uploader =
+            BatchUploader::new(Arc::new(ItemizedApiClient), "sess_busy".to_string(), 10, 0);
+        uploader.enqueue_local("row-busy".to_string(), alert("busy"));
+
+        for _ in 1..MAX_ITEM_REJECTIONS {
+            let report = uploader.flush_report().await.unwrap();
+            assert_eq!(report.requeued, 1);
+            assert!(report.accepted.is_empty() && report.quarantined.is_empty());
+            assert_eq!(uploader.queue_size(), 1);
+        }
+
+        let report = uploader.flush_report().await.unwrap();
+        assert_eq!(report.quarantined.len(), 1);
+        assert_eq!(report.quarantined[0].event_id, "row-busy");
+        assert_eq!(report.quarantined[0].reason, "rejected by server");
+        assert_eq!(uploader.queue_size(), 0);
+    }
+
+    #[test]
+    fn prioritized_cap_drops_lowest_priority() {
+        let client = Arc::new(MockApiClient { should_fail: false });
+        let uploader = BatchUploader::new(client, "sess_prio_cap".to_string(), 100, 3)
+            .with_max_queue_size(3)
+            .with_prioritization(true);
+
+        uploader.enqueue(alert("a1"));
+        uploader.enqueue(metrics("m1"));
+        uploader.enqueue(alert("a2"));
+
+        // Full: an alert evicts the queued metrics update, not the oldest alert.
+        uploader.enqueue(alert("a3"));
+        assert_eq!(uploader.queue_size(), 3);
+        assert_eq!(uploader.total_dropped(), 1);
+        assert!(uploader.lanes[UploadPriority::Low as usize].is_empty());
+
+        // A metrics update ranks below everything queued and is dropped itself.
+        uploader.enqueue(metrics("m2"));
+        assert_eq!(uploader.queue_size(), 3);
+        assert_eq!(uploader.total_dropped(), 2);
+        assert_eq!(uploader.lanes[UploadPriority::High as usize].len(), 3);
+    }
+
+    #[test]
+    fn unprioritized_cap_still_drops_oldest() {
+        let client = Arc::new(MockApiClient { should_fail: false });
+        let uploader =
+            BatchUploader::new(client, "sess_fifo".to_string(), 100, 3).with_max_queue_size(2);
+
+        uploader.enqueue(alert("a1"));
+        uploader.enqueue(metrics("m1"));
+        uploader.enqueue(metrics("m2"));
+
+        assert_eq!(uploader.total_dropped(), 1);
+        let remaining: Vec<String> = std::iter::from_fn(|| uploader.pop_next())
+            .map(|queued| match queued.event {
+                Event::System(system) => system.data["tag"].as_str().unwrap().to_string(),
+                _ => panic!("unexpected event"),
+            })
+            .collect();
+        assert_eq!(remaining, vec!["m1", "m2"]);
+    }
+
     #[test]
     fn concurrent_enqueue_respects_capacity() {
         use std::thread;
@@ -844,4 +1485,161 @@ mod tests {
             uploader.queue_size()
         );
     }
+
+    #[tokio::test]
+    async fn restored_events_stay_pending_until_a_flush_is_acknowledged() {
+        use oneshim_storage::sqlite::SqliteStorage;
+
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let events: Vec<Event> = ["a", "b"]
+            .into_iter()
+            .map(|title| {
+                Event::Context(ContextEvent {
+                    app_name: "test".to_string(),
+                    window_title: title.to_string(),
+                    timestamp: chrono::Utc::now(),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        storage.save_events(&events).await.unwrap();
+        let keep_all = |event: Event| Some(event);
+
+        // Offline: the flush fails and nothing is marked sent.
+        let offline = BatchUploader::new(
+            Arc::new(MockApiClient { should_fail: true }),
+            "sess_restore".to_string(),
+            100,
+            0,
+        );
+        assert_eq!(
+            offline.restore_pending(&storage, &keep_all).await.unwrap(),
+            2
+        );
+        assert_eq!(
+            offline.restore_pending(&storage, &keep_all).await.unwrap(),
+            0
+        );
+        assert!(offline.flush_report().await.is_err());
+        assert_eq!(offline.queue_size(), 2);
+        assert_eq!(storage.get_pending_events(10).await.unwrap().len(), 2);
+
+        // After a restart the same rows are restored and only the
+        // acknowledged ones leave the pending set.
+        let online = BatchUploader::new(
+            Arc::new(MockApiClient { should_fail: false }),
+            "sess_restore".to_string(),
+            100,
+            0,
+        );
+        assert_eq!(
+            online.restore_pending(&storage, &keep_all).await.unwrap(),
+            2
+        );
+        let report = online.flush_report().await.unwrap();
+        let mut accepted = report.accepted.clone();
+        accepted.sort();
+        let mut expected: Vec<String> = events.iter().map(Event::local_id).collect();
+        expected.sort();
+        assert_eq!(accepted, expected);
+        storage.mark_as_sent(&report.accepted).await.unwrap();
+        assert!(storage.get_pending_events(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn backlog_larger_than_the_queue_is_restored_page_by_page() {
+        use oneshim_storage::sqlite::SqliteStorage;
+
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let start = chrono::Utc::now();
+        let events: Vec<Event> = (0..5)
+            .map(|i| {
+                Event::Context(ContextEvent {
+                    app_name: "test".to_string(),
+                    window_title: format!("w{i}"),
+                    timestamp: start + chrono::Duration::milliseconds(i),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        storage.save_events(&events).await.unwrap();
+        let keep_all = |event: Event| Some(event);
+
+        let uploader = BatchUploader::new(
+            Arc::new(MockApiClient { should_fail: false }),
+            "sess_backlog".to_string(),
+            100,
+            0,
+        )
+        .with_max_queue_size(2);
+
+        let mut uploaded = Vec::new();
+        loop {
+            let restored = uploader.restore_pending(&storage, &keep_all).await.unwrap();
+            assert!(restored <= 2);
+            if restored == 0 {
+                break;
+            }
+            // Nothing leaves the pending set before the server acknowledges it.
+            assert_eq!(
+                storage.get_pending_events(10).await.unwrap().len(),
+                events.len() - uploaded.len()
+            );
+            let report = uploader.flush_report().await.unwrap();
+            storage.mark_as_sent(&report.accepted).await.unwrap();
+            uploaded.extend(report.accepted);
+        }
+
+        uploaded.sort();
+        let mut expected: Vec<String> = events.iter().map(Event::local_id).collect();
+        expected.sort();
+        assert_eq!(uploaded, expected);
+        assert_eq!(uploader.total_dropped(), 0);
+        assert!(storage.get_pending_events(10).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn restore_marks_rows_left_out_by_prepare_as_sent() {
+        use oneshim_storage::sqlite::SqliteStorage;
+
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let start = chrono::Utc::now();
+        let events: Vec<Event> = ["skip", "skip", "keep"]
+            .into_iter()
+            .enumerate()
+            .map(|(i, app)| {
+                Event::Context(ContextEvent {
+                    app_name: app.to_string(),
+                    window_title: format!("w{i}"),
+                    timestamp: start + chrono::Duration::milliseconds(i as i64),
+                    ..Default::default()
+                })
+            })
+            .collect();
+        storage.save_events(&events).await.unwrap();
+        let skip_some = |event: Event| match &event {
+            Event::Context(ctx) if ctx.app_name == "skip" => None,
+            _ => Some(event),
+        };
+
+        // The first page holds only skipped rows; they must not stall the
+        // backlog behind them.
+        let uploader = BatchUploader::new(
+            Arc::new(MockApiClient { should_fail: false }),
+            "sess_skip".to_string(),
+            100,
+            0,
+        )
+        .with_max_queue_size(2);
+        assert_eq!(
+            uploader
+                .restore_pending(&storage, &skip_some)
+                .await
+                .unwrap(),
+            1
+        );
+        let pending = storage.get_pending_events(10).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].local_id(), events[2].local_id());
+    }
 }
@@ -1,3 +1,4 @@
+use oneshim_core::ports::server_circuit::ServerCircuitStatus;
 use parking_lot::Mutex;
 use std::collections::HashMap;
 use std::sync::Arc;
@@ -11,6 +12,8 @@ pub struct CircuitBreakerConfig {
     pub failure_threshold: u32,
     pub initial_cooldown: Duration,
     pub max_cooldown: Duration,
+    /// Requests let through at once while half-open (at least 1); the rest
+    /// are turned away as if the breaker were still open.
     pub half_open_probes: u32,
 }
 
@@ -39,6 +42,9 @@ pub struct CircuitBreakerStats {
     pub state: &'static str,
     pub consecutive_failures: u32,
     pub current_cooldown: Duration,
+    /// Time left before the next probe is allowed while open, for
+    /// "server unreachable (retrying in 30s)" style indicators.
+    pub retry_in: Option<Duration>,
 }
 
 // ── Inner (behind Mutex) ────────────────────────────────────────────
@@ -47,6 +53,10 @@ struct InnerState {
     status: CircuitState,
     consecutive_failures: u32,
     current_cooldown: Duration,
+    /// Half-open probes handed out by `check` whose outcome is not recorded yet.
+    probes_in_flight: u32,
+    /// When the first of `probes_in_flight` was handed out.
+    probes_since: Option<Instant>,
 }
 
 // ── CircuitBreaker ──────────────────────────────────────────────────
@@ -65,24 +75,53 @@ impl CircuitBreaker {
                 status: CircuitState::Closed,
                 consecutive_failures: 0,
                 current_cooldown: cooldown,
+                probes_in_flight: 0,
+                probes_since: None,
             }),
         }
     }
 
     /// Check current state, transitioning Open→HalfOpen if cooldown elapsed.
+    ///
+    /// A `HalfOpen` result is a probe slot: the caller is expected to send
+    /// the request and record its outcome. Once `half_open_probes` slots are
+    /// taken, further callers get `Open` until a probe resolves. Slots left
+    /// unresolved for a full cooldown (cancelled or neutral requests) are
+    /// reclaimed so the breaker cannot stay stuck.
     pub fn check(&self) -> CircuitState {
         let mut inner = self.state.lock();
+        let now = Instant::now();
         if let CircuitState::Open { until } = &inner.status {
-            if Instant::now() >= *until {
+            if now >= *until {
                 inner.status = CircuitState::HalfOpen;
+                inner.probes_in_flight = 0;
+                inner.probes_since = None;
                 warn!("circuit breaker: Open → HalfOpen (cooldown elapsed)");
             }
         }
+        if matches!(inner.status, CircuitState::HalfOpen) {
+            let reclaim_at = inner
+                .probes_since
+                .map(|since| since + inner.current_cooldown);
+            if reclaim_at.is_some_and(|at| now >= at) {
+                inner.probes_in_flight = 0;
+                inner.probes_since = None;
+            }
+            if inner.probes_in_flight >= self.config.half_open_probes.max(1) {
+                return CircuitState::Open {
+                    until: reclaim_at.unwrap_or(now),
+                };
+            }
+            inner.probes_in_flight += 1;
+            inner.probes_since.get_or_insert(now);
+        }
         inner.status.clone()
     }
 
     pub fn record_success(&self) {
         let mut inner = self.state.lock();
+        inner.probes_in_flight = 0;
+        inner.probes_since = None;
         let was_half_open = matches!(inner.status, CircuitState::HalfOpen);
         inner.consecutive_failures = 0;
         inner.current_cooldown = self.config.initial_cooldown;
@@ -95,6 +134,8 @@ impl CircuitBreaker {
     pub fn record_failure(&self) {
         let mut inner = self.state.lock();
         inner.consecutive_failures += 1;
+        inner.probes_in_flight = 0;
+        inner.probes_since = None;
 
         match &inner.status {
             CircuitState::Closed => {
@@ -128,7 +169,16 @@ impl CircuitBreaker {
         self.state.lock().status.clone()
     }
 
+    /// Remaining cooldown while open; `None` when closed or half-open.
+    pub fn retry_in(&self) -> Option<Duration> {
+        match self.state.lock().status {
+            CircuitState::Open { until } => Some(until.saturating_duration_since(Instant::now())),
+            _ => None,
+        }
+    }
+
     pub fn stats(&self) -> CircuitBreakerStats {
+        let retry_in = self.retry_in();
         let inner = self.state.lock();
         CircuitBreakerStats {
             state: match &inner.status {
@@ -138,10 +188,17 @@ impl CircuitBreaker {
             },
             consecutive_failures: inner.consecutive_failures,
             current_cooldown: inner.current_cooldown,
+            retry_in,
         }
     }
 }
 
+impl ServerCircuitStatus for CircuitBreaker {
+    fn retry_in(&self) -> Option<Duration> {
+        self.stats().retry_in
+    }
+}
+
 // ── CircuitBreakerRegistry ──────────────────────────────────────────
 
 /// Registry of per-endpoint `CircuitBreaker` instances keyed by
@@ -232,6 +289,54 @@ mod tests {
         assert_eq!(cb.stats().state, "half_open");
     }
 
+    #[test]
+    fn half_open_admits_one_probe_at_a_time() {
+        let cb = CircuitBreaker::new(fast_config());
+        for _ in 0..3 {
+            cb.record_failure();
+        }
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(matches!(cb.check(), CircuitState::HalfOpen));
+        assert!(
+            matches!(cb.check(), CircuitState::Open { .. }),
+            "a second caller must not probe while the first is in flight"
+        );
+        assert_eq!(cb.stats().state, "half_open");
+
+        cb.record_success();
+        assert!(matches!(cb.check(), CircuitState::Closed));
+        assert!(matches!(cb.check(), CircuitState::Closed));
+    }
+
+    #[test]
+    fn half_open_probe_limit_follows_config() {
+        let cb = CircuitBreaker::new(CircuitBreakerConfig {
+            half_open_probes: 2,
+            ..fast_config()
+        });
+        for _ in 0..3 {
+            cb.record_failure();
+        }
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(matches!(cb.check(), CircuitState::HalfOpen));
+        assert!(matches!(cb.check(), CircuitState::HalfOpen));
+        assert!(matches!(cb.check(), CircuitState::Open { .. }));
+    }
+
+    #[test]
+    fn unresolved_probe_slot_is_reclaimed_after_a_cooldown() {
+        let cb = CircuitBreaker::new(fast_config());
+        for _ in 0..3 {
+            cb.record_failure();
+        }
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(matches!(cb.check(), CircuitState::HalfOpen));
+        assert!(matches!(cb.check(), CircuitState::Open { .. }));
+        // The first probe never reports back.
+        std::thread::sleep(Duration::from_millis(60));
+        assert!(matches!(cb.check(), CircuitState::HalfOpen));
+    }
+
     #[test]
     fn half_open_to_closed_on_success() {
         let cb = CircuitBreaker::new(fast_config());
@@ -245,6 +350,23 @@ mod tests {
         assert_eq!(cb.stats().consecutive_failures, 0);
     }
 
+    #[test]
+    fn retry_in_counts_down_only_while_open() {
+        let cb = CircuitBreaker::new(fast_config());
+        assert_eq!(cb.retry_in(), None);
+        for _ in 0..3 {
+            cb.record_failure();
+        }
+        let retry_in = cb
+            .stats()
+            .retry_in
+            .expect("open breaker reports a retry delay");
+        assert!(retry_in <= Duration::from_millis(50));
+        std::thread::sleep(Duration::from_millis(60));
+        let _ = cb.check(); // HalfOpen
+        assert_eq!(cb.retry_in(), None);
+    }
+
     #[test]
     fn half_open_to_open_on_failure_doubles_cooldown() {
         let cb = CircuitBreaker::new(fast_config());
@@ -4,6 +4,93 @@ use oneshim_core::error::CoreError;
 use oneshim_core::ports::compressor::{CompressionAlgorithm, Compressor};
 use std::io::Read;
 
+/// Request body content-coding agreed with the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CompressionAlgo {
+    Zstd,
+    Lz4,
+    Gzip,
+    Identity,
+}
+
+impl CompressionAlgo {
+    /// `Content-Encoding` token, `None` for identity (header omitted).
+    pub fn content_encoding(self) -> Option<&'static str> {
+        match self {
+            Self::Zstd => Some("zstd"),
+            Self::Lz4 => Some("lz4"),
+            Self::Gzip => Some("gzip"),
+            Self::Identity => None,
+        }
+    }
+
+    fn algorithm(self) -> Option<CompressionAlgorithm> {
+        match self {
+            Self::Zstd => Some(CompressionAlgorithm::Zstd),
+            Self::Lz4 => Some(CompressionAlgorithm::Lz4),
+            Self::Gzip => Some(CompressionAlgorithm::Gzip),
+            Self::Identity => None,
+        }
+    }
+}
+
+/// Pick the encoding for `accepted` `Accept-Encoding` entries (each may be
+/// a comma-separated list with `;q=` weights). Prefers zstd > lz4 > gzip;
+/// `q=0` excludes a coding and `*` accepts any coding not excluded by
+/// name. Identity when nothing matches.
+pub fn negotiate(accepted: &[&str]) -> CompressionAlgo {
+    let mut codings: Vec<String> = Vec::new();
+    let mut refused_codings: Vec<String> = Vec::new();
+    for entry in accepted.iter().flat_map(|value| value.split(',')) {
+        let mut parts = entry.split(';');
+        let coding = parts.next().unwrap_or_default().trim().to_ascii_lowercase();
+        let refused = parts.any(|param| {
+            param
+                .trim()
+                .strip_prefix("q=")
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .is_some_and(|q| q <= 0.0)
+        });
+        if coding.is_empty() {
+            continue;
+        }
+        if refused {
+            refused_codings.push(coding);
+        } else {
+            codings.push(coding);
+        }
+    }
+    let wildcard = codings.iter().any(|coding| coding == "*");
+    [
+        CompressionAlgo::Zstd,
+        CompressionAlgo::Lz4,
+        CompressionAlgo::Gzip,
+    ]
+    .into_iter()
+    .find(|algo| {
+        let named = |list: &[String]| {
+            list.iter()
+                .any(|coding| Some(coding.as_str()) == algo.content_encoding())
+        };
+        named(&codings) || (wildcard && !named(&refused_codings))
+    })
+    .unwrap_or(CompressionAlgo::Identity)
+}
+
+pub fn compress(algo: CompressionAlgo, data: &[u8]) -> Result<Vec<u8>, CoreError> {
+    match algo.algorithm() {
+        Some(algorithm) => AdaptiveCompressor.compress(data, algorithm),
+        None => Ok(data.to_vec()),
+    }
+}
+
+pub fn decompress(algo: CompressionAlgo, data: &[u8]) -> Result<Vec<u8>, CoreError> {
+    match algo.algorithm() {
+        Some(algorithm) => AdaptiveCompressor.decompress(data, algorithm),
+        None => Ok(data.to_vec()),
+    }
+}
+
 pub struct AdaptiveCompressor;
 
 impl AdaptiveCompressor {
@@ -148,6 +235,49 @@ mod tests {
         );
     }
 
+    #[test]
+    fn negotiated_encodings_roundtrip() {
+        let data = b"{\"session_id\":\"sess_1\",\"events\":[]}".repeat(20);
+        for algo in [
+            CompressionAlgo::Zstd,
+            CompressionAlgo::Lz4,
+            CompressionAlgo::Gzip,
+            CompressionAlgo::Identity,
+        ] {
+            let compressed = compress(algo, &data).unwrap();
+            assert_eq!(decompress(algo, &compressed).unwrap(), data, "{algo:?}");
+        }
+    }
+
+    #[test]
+    fn negotiate_prefers_zstd_then_lz4_then_gzip() {
+        assert_eq!(negotiate(&["gzip, lz4, zstd"]), CompressionAlgo::Zstd);
+        assert_eq!(negotiate(&["gzip", "LZ4"]), CompressionAlgo::Lz4);
+        assert_eq!(negotiate(&["gzip;q=0.5"]), CompressionAlgo::Gzip);
+        assert_eq!(negotiate(&["zstd;q=0, gzip"]), CompressionAlgo::Gzip);
+        assert_eq!(negotiate(&["*"]), CompressionAlgo::Zstd);
+    }
+
+    #[test]
+    fn negotiate_wildcard_skips_codings_refused_by_name() {
+        assert_eq!(negotiate(&["zstd;q=0, *"]), CompressionAlgo::Lz4);
+        assert_eq!(
+            negotiate(&["*", "zstd;q=0, lz4;q=0"]),
+            CompressionAlgo::Gzip
+        );
+        assert_eq!(
+            negotiate(&["zstd;q=0, lz4;q=0, gzip;q=0, *"]),
+            CompressionAlgo::Identity
+        );
+    }
+
+    #[test]
+    fn negotiate_falls_back_to_identity_for_unknown_encodings() {
+        assert_eq!(negotiate(&["br, deflate"]), CompressionAlgo::Identity);
+        assert_eq!(negotiate(&[]), CompressionAlgo::Identity);
+        assert_eq!(CompressionAlgo::Identity.content_encoding(), None);
+    }
+
     #[test]
     fn compress_auto() {
         let compressor = AdaptiveCompressor::new();
@@ -0,0 +1,278 @@
+//! Client-side envelope encryption of uploaded frame/OCR content.
+//!
+//! Scheme `x25519-aes256gcm-v1`: each upload gets a fresh X25519 key pair,
+//! agrees a shared secret with the tenant public key and encrypts with
+//! AES-256-GCM under `SHA-256(label || shared || ephemeral_pk || tenant_pk)`.
+//! Only the tenant private key recovers the plaintext; frame metadata is
+//! sent in clear for indexing.
+
+use aes_gcm::{
+    aead::{rand_core::RngCore, Aead, KeyInit, OsRng},
+    Aes256Gcm, Nonce,
+};
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine as _;
+use chrono::{DateTime, Utc};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use oneshim_core::config::ContentEncryptionConfig;
+use oneshim_core::models::frame::{ContextUpload, FrameMetadata};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::error::NetworkError;
+
+/// Algorithm tag carried in every [`SealedContent`].
+pub const CONTENT_ENCRYPTION_ALG: &str = "x25519-aes256gcm-v1";
+
+const KDF_LABEL: &[u8] = b"oneshim-content-v1";
+const NONCE_SIZE: usize = 12;
+
+/// Encrypted payload plus what the key holder needs to open it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SealedContent {
+    pub alg: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    /// Base64 ephemeral X25519 public key.
+    pub ephemeral_public_key: String,
+    /// Base64 AES-GCM nonce.
+    pub nonce: String,
+    /// Base64 AES-GCM ciphertext (with tag).
+    pub ciphertext: String,
+}
+
+/// Body of `POST /user_context/contexts` when content encryption is on.
+///
+/// `sealed` opens to `{"ocr_text": ..., "image": ...}`.
+#[derive(Debug, Serialize)]
+pub struct SealedContextUpload<'a> {
+    pub session_id: &'a str,
+    pub timestamp: DateTime<Utc>,
+    pub metadata: &'a FrameMetadata,
+    pub sealed: SealedContent,
+}
+
+/// Seals upload content to a tenant public key.
+#[derive(Debug, Clone)]
+pub struct ContentEncryptor {
+    tenant_public: MontgomeryPoint,
+    key_id: Option<String>,
+}
+
+impl ContentEncryptor {
+    pub fn new(tenant_public_key: [u8; 32], key_id: Option<String>) -> Self {
+        Self {
+            tenant_public: MontgomeryPoint(tenant_public_key),
+            key_id,
+        }
+    }
+
+    /// `None` when content encryption is disabled.
+    pub fn from_config(config: &ContentEncryptionConfig) -> Result<Option<Self>, NetworkError> {
+        if !config.enabled {
+            return Ok(None);
+        }
+        let key = config.public_key_bytes().ok_or_else(|| {
+            NetworkError::Config(
+                "server.content_encryption.public_key is not a 32-byte base64 key".to_string(),
+            )
+        })?;
+        Ok(Some(Self::new(key, config.key_id.clone())))
+    }
+
+    pub fn seal(&self, plaintext: &[u8]) -> Result<SealedContent, NetworkError> {
+        let mut ephemeral_secret = [0u8; 32];
+        OsRng.fill_bytes(&mut ephemeral_secret);
+        let ephemeral_public = MontgomeryPoint::mul_base_clamped(ephemeral_secret);
+        let shared = self.tenant_public.mul_clamped(ephemeral_secret);
+        let cipher = content_cipher(&shared, &ephemeral_public, &self.tenant_public)?;
+
+        let mut nonce = [0u8; NONCE_SIZE];
+        OsRng.fill_bytes(&mut nonce);
+        let ciphertext = cipher
+            .encrypt(Nonce::from_slice(&nonce), plaintext)
+            .map_err(|e| NetworkError::Internal(format!("content encrypt: {e}")))?;
+
+        Ok(SealedContent {
+            alg: CONTENT_ENCRYPTION_ALG.to_string(),
+            key_id: self.key_id.clone(),
+            ephemeral_public_key: STANDARD.encode(ephemeral_public.to_bytes()),
+            nonce: STANDARD.encode(nonce),
+            ciphertext: STANDARD.encode(ciphertext),
+        })
+    }
+
+    /// Seal OCR text and image of `upload`, keeping session and metadata in clear.
+    pub fn seal_context<'a>(
+        &self,
+        upload: &'a ContextUpload,
+    ) -> Result<SealedContextUpload<'a>, NetworkError> {
+        let content = serde_json::to_vec(&serde_json::json!({
+            "ocr_text": upload.ocr_text,
+            "image": upload.image,
+        }))
+        .map_err(|e| NetworkError::Internal(format!("content serialize: {e}")))?;
+        Ok(SealedContextUpload {
+            session_id: &upload.session_id,
+            timestamp: upload.timestamp,
+            metadata: &upload.metadata,
+            sealed: self.seal(&content)?,
+        })
+    }
+}
+
+/// Public key matching an X25519 private key, for provisioning tenant keys.
+pub fn public_key_for(tenant_secret: [u8; 32]) -> [u8; 32] {
+    MontgomeryPoint::mul_base_clamped(tenant_secret).to_bytes()
+}
+
+/// Decrypt `sealed` with the tenant private key.
+pub fn open(tenant_secret: [u8; 32], sealed: &SealedContent) -> Result<Vec<u8>, NetworkError> {
+    if sealed.alg != CONTENT_ENCRYPTION_ALG {
+        return Err(NetworkError::Validation {
+            field: "alg".to_string(),
+            message: format!("unsupported content encryption {}", sealed.alg),
+        });
+    }
+    let decode = |field: &str, value: &str| {
+        STANDARD
+            .decode(value)
+            .map_err(|e| NetworkError::Validation {
+                field: field.to_string(),
+                message: e.to_string(),
+            })
+    };
+    let ephemeral_public: [u8; 32] = decode("ephemeral_public_key", &sealed.ephemeral_public_key)?
+        .try_into()
+        .map_err(|_| NetworkError::Validation {
+            field: "ephemeral_public_key".to_string(),
+            message: "must be 32 bytes".to_string(),
+        })?;
+    let nonce = decode("nonce", &sealed.nonce)?;
+    if nonce.len() != NONCE_SIZE {
+        return Err(NetworkError::Validation {
+            field: "nonce".to_string(),
+            message: format!("must be {NONCE_SIZE} bytes"),
+        });
+    }
+    let ciphertext = decode("ciphertext", &sealed.ciphertext)?;
+
+    let ephemeral_public = MontgomeryPoint(ephemeral_public);
+    let shared = ephemeral_public.mul_clamped(tenant_secret);
+    let tenant_public = MontgomeryPoint::mul_base_clamped(tenant_secret);
+    content_cipher(&shared, &ephemeral_public, &tenant_public)?
+        .decrypt(Nonce::from_slice(&nonce), ciphertext.as_slice())
+        .map_err(|e| NetworkError::Internal(format!("content decrypt: {e}")))
+}
+
+fn content_cipher(
+    shared: &MontgomeryPoint,
+    ephemeral_public: &MontgomeryPoint,
+    tenant_public: &MontgomeryPoint,
+) -> Result<Aes256Gcm, NetworkError> {
+    // A low-order tenant or ephemeral key yields an all-zero secret.
+    if shared.to_bytes() == [0u8; 32] {
+        return Err(NetworkError::Config(
+            "content encryption key agreement produced a zero secret".to_string(),
+        ));
+    }
+    let key = Sha256::new()
+        .chain_update(KDF_LABEL)
+        .chain_update(shared.as_bytes())
+        .chain_update(ephemeral_public.as_bytes())
+        .chain_update(tenant_public.as_bytes())
+        .finalize();
+    Aes256Gcm::new_from_slice(&key).map_err(|e| NetworkError::Internal(format!("AES init: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oneshim_core::models::frame::ImagePayload;
+
+    const TENANT_SECRET: [u8; 32] = [7u8; 32];
+
+    fn encryptor() -> ContentEncryptor {
+        ContentEncryptor::new(public_key_for(TENANT_SECRET), Some("tenant-1".to_string()))
+    }
+
+    fn upload() -> ContextUpload {
+        ContextUpload {
+            session_id: "sess_1".to_string(),
+            timestamp: Utc::now(),
+            metadata: FrameMetadata {
+                timestamp: Utc::now(),
+                trigger_type: "test".to_string(),
+                app_name: "Editor".to_string(),
+                window_title: "notes.md".to_string(),
+                resolution: (1920, 1080),
+                importance: 0.9,
+            },
+            ocr_text: Some("quarterly revenue draft".to_string()),
+            image: Some(ImagePayload::Full {
+                data: "aW1hZ2U=".to_string(),
+                format: "webp".to_string(),
+                ocr_text: None,
+            }),
+        }
+    }
+
+    #[test]
+    fn sealed_content_opens_with_tenant_private_key() {
+        let sealed = encryptor().seal(b"secret screen text").unwrap();
+
+        assert_eq!(sealed.alg, CONTENT_ENCRYPTION_ALG);
+        assert_eq!(sealed.key_id.as_deref(), Some("tenant-1"));
+        assert_ne!(
+            STANDARD.decode(&sealed.ciphertext).unwrap(),
+            b"secret screen text"
+        );
+        assert_eq!(open(TENANT_SECRET, &sealed).unwrap(), b"secret screen text");
+    }
+
+    #[test]
+    fn each_seal_uses_a_fresh_ephemeral_key() {
+        let encryptor = encryptor();
+        let first = encryptor.seal(b"same").unwrap();
+        let second = encryptor.seal(b"same").unwrap();
+        assert_ne!(first.ephemeral_public_key, second.ephemeral_public_key);
+        assert_ne!(first.ciphertext, second.ciphertext);
+    }
+
+    #[test]
+    fn wrong_private_key_cannot_open() {
+        let sealed = encryptor().seal(b"secret").unwrap();
+        assert!(open([9u8; 32], &sealed).is_err());
+    }
+
+    #[test]
+    fn sealed_context_keeps_metadata_clear_and_hides_content() {
+        let upload = upload();
+        let body = serde_json::to_value(encryptor().seal_context(&upload).unwrap()).unwrap();
+
+        assert_eq!(body["session_id"], "sess_1");
+        assert_eq!(body["metadata"]["app_name"], "Editor");
+        assert!(body.get("ocr_text").is_none());
+        assert!(body.get("image").is_none());
+        assert!(!body.to_string().contains("quarterly revenue draft"));
+
+        let sealed: SealedContent = serde_json::from_value(body["sealed"].clone()).unwrap();
+        let content: serde_json::Value =
+            serde_json::from_slice(&open(TENANT_SECRET, &sealed).unwrap()).unwrap();
+        assert_eq!(content["ocr_text"], "quarterly revenue draft");
+        assert_eq!(content["image"]["data"], "aW1hZ2U=");
+    }
+
+    #[test]
+    fn disabled_config_builds_no_encryptor() {
+        let config = ContentEncryptionConfig::default();
+        assert!(ContentEncryptor::from_config(&config).unwrap().is_none());
+
+        let config = ContentEncryptionConfig {
+            enabled: true,
+            public_key: "not-a-key".to_string(),
+            key_id: None,
+        };
+        assert!(ContentEncryptor::from_config(&config).is_err());
+    }
+}
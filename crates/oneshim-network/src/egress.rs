@@ -0,0 +1,106 @@
+//! Outbound host allowlist shared by every adapter that talks to the network.
+//!
+//! `HttpApiClient` and `SseStreamClient` consult the same [`EgressPolicy`]
+//! before sending; the updater applies the same [`EgressConfig`] directly.
+
+use oneshim_core::config::EgressConfig;
+use tracing::warn;
+
+use crate::error::NetworkError;
+
+/// Host allowlist applied before each outbound request.
+///
+/// The default policy is unrestricted.
+#[derive(Debug, Clone, Default)]
+pub struct EgressPolicy {
+    config: EgressConfig,
+}
+
+impl EgressPolicy {
+    pub fn unrestricted() -> Self {
+        Self::default()
+    }
+
+    pub fn allow_only<I, S>(hosts: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        Self::from_config(&EgressConfig {
+            enabled: true,
+            allowed_hosts: hosts.into_iter().map(Into::into).collect(),
+        })
+    }
+
+    pub fn from_config(config: &EgressConfig) -> Self {
+        Self {
+            config: config.clone(),
+        }
+    }
+
+    pub fn is_restricted(&self) -> bool {
+        self.config.enabled
+    }
+
+    /// Check `url` against the allowlist, logging and rejecting disallowed hosts.
+    pub fn check_url(&self, url: &str) -> Result<(), NetworkError> {
+        if !self.is_restricted() {
+            return Ok(());
+        }
+
+        let parsed = reqwest::Url::parse(url)
+            .map_err(|e| NetworkError::PolicyDenied(format!("egress blocked: invalid URL: {e}")))?;
+        let Some(host) = parsed.host_str() else {
+            warn!(url, "egress blocked: URL has no host");
+            return Err(NetworkError::PolicyDenied(
+                "egress blocked: URL has no host".to_string(),
+            ));
+        };
+
+        if self.config.is_allowed_host(host) {
+            Ok(())
+        } else {
+            warn!(host, "egress blocked: host is not in the egress allowlist");
+            Err(NetworkError::PolicyDenied(format!(
+                "egress blocked: host '{host}' is not in the egress allowlist"
+            )))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unrestricted_policy_allows_everything() {
+        let policy = EgressPolicy::from_config(&EgressConfig::default());
+        assert!(!policy.is_restricted());
+        assert!(policy.check_url("https://anywhere.example.org/x").is_ok());
+    }
+
+    #[test]
+    fn allowed_host_passes() {
+        let policy = EgressPolicy::from_config(&EgressConfig {
+            enabled: true,
+            allowed_hosts: vec!["oneshim.dev".to_string()],
+        });
+        assert!(policy
+            .check_url("https://api.oneshim.dev/user_context/batches")
+            .is_ok());
+        assert!(policy.check_url("http://127.0.0.1:8000/health").is_ok());
+    }
+
+    #[test]
+    fn disallowed_host_is_blocked_with_clear_error() {
+        let policy = EgressPolicy::allow_only(["api.oneshim.dev"]);
+        let err = policy
+            .check_url("https://telemetry.example.com/collect")
+            .unwrap_err();
+        assert!(matches!(err, NetworkError::PolicyDenied(_)));
+        assert_eq!(
+            err.to_string(),
+            "policy denied: egress blocked: host 'telemetry.example.com' is not in the egress allowlist"
+        );
+    }
+}
@@ -0,0 +1,318 @@
+//! Shared server endpoint selection with health-based failover.
+//!
+//! One [`ServerEndpoints`] is shared by the HTTP, SSE and gRPC clients so
+//! they always talk to the same server. Clients report one outcome per
+//! request (not per retry attempt); after `failure_threshold` consecutive
+//! failed requests the next configured server becomes active. While a standby is active the primary
+//! is probed every `failback_interval` and becomes active again once it
+//! answers.
+
+use oneshim_core::config::ServerConfig;
+use parking_lot::Mutex;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tracing::{debug, info, warn};
+
+use crate::error::NetworkError;
+
+const DEFAULT_FAILURE_THRESHOLD: u32 = 3;
+const DEFAULT_FAILBACK_INTERVAL: Duration = Duration::from_secs(60);
+const PROBE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// One server the client can talk to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServerEndpoint {
+    /// REST/SSE base URL without a trailing slash.
+    pub base_url: String,
+    /// gRPC endpoint; `None` keeps the configured default.
+    pub grpc_endpoint: Option<String>,
+}
+
+impl ServerEndpoint {
+    pub fn new(base_url: &str) -> Self {
+        Self {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            grpc_endpoint: None,
+        }
+    }
+
+    pub fn with_grpc_endpoint(mut self, grpc_endpoint: Option<String>) -> Self {
+        self.grpc_endpoint = grpc_endpoint;
+        self
+    }
+}
+
+/// Whether `error` means the server could not be reached or is down, as
+/// opposed to rejecting the request.
+pub fn is_endpoint_failure(error: &NetworkError) -> bool {
+    matches!(
+        error,
+        NetworkError::Http(_) | NetworkError::Timeout { .. } | NetworkError::ServiceUnavailable(_)
+    )
+}
+
+pub struct ServerEndpoints {
+    /// Primary first, then standbys in failover order.
+    endpoints: Vec<ServerEndpoint>,
+    active: AtomicUsize,
+    consecutive_failures: AtomicU32,
+    failure_threshold: u32,
+    failback_interval: Duration,
+    /// When the primary was last left or probed.
+    last_failback_check: Mutex<Instant>,
+    probing: AtomicBool,
+    /// Incremented on every switch so holders of per-endpoint connections
+    /// (gRPC channels) know to reconnect.
+    generation: AtomicU64,
+}
+
+impl ServerEndpoints {
+    /// Primary followed by standbys in failover order.
+    pub fn new(primary: ServerEndpoint, standbys: Vec<ServerEndpoint>) -> Self {
+        let mut endpoints = Vec::with_capacity(standbys.len() + 1);
+        endpoints.push(primary);
+        endpoints.extend(standbys);
+        Self {
+            endpoints,
+            active: AtomicUsize::new(0),
+            consecutive_failures: AtomicU32::new(0),
+            failure_threshold: DEFAULT_FAILURE_THRESHOLD,
+            failback_interval: DEFAULT_FAILBACK_INTERVAL,
+            last_failback_check: Mutex::new(Instant::now()),
+            probing: AtomicBool::new(false),
+            generation: AtomicU64::new(0),
+        }
+    }
+
+    /// A single server; failures never switch endpoints.
+    pub fn single(base_url: &str) -> Self {
+        Self::new(ServerEndpoint::new(base_url), Vec::new())
+    }
+
+    pub fn from_config(server: &ServerConfig) -> Self {
+        let standbys = server
+            .standby_servers
+            .iter()
+            .map(|standby| {
+                ServerEndpoint::new(&standby.base_url)
+                    .with_grpc_endpoint(standby.grpc_endpoint.clone())
+            })
+            .collect();
+        Self::new(ServerEndpoint::new(&server.base_url), standbys)
+            .with_failure_threshold(server.failover_after_failures)
+            .with_failback_interval(Duration::from_secs(server.failback_probe_secs))
+    }
+
+    pub fn with_failure_threshold(mut self, threshold: u32) -> Self {
+        self.failure_threshold = threshold.max(1);
+        self
+    }
+
+    pub fn with_failback_interval(mut self, interval: Duration) -> Self {
+        self.failback_interval = interval;
+        self
+    }
+
+    pub fn active_index(&self) -> usize {
+        self.active.load(Ordering::Acquire)
+    }
+
+    pub fn active(&self) -> &ServerEndpoint {
+        &self.endpoints[self.active_index()]
+    }
+
+    pub fn active_base_url(&self) -> &str {
+        &self.active().base_url
+    }
+
+    pub fn primary(&self) -> &ServerEndpoint {
+        &self.endpoints[0]
+    }
+
+    pub fn is_primary_active(&self) -> bool {
+        self.active_index() == 0
+    }
+
+    /// Changes whenever the active endpoint switches.
+    pub fn generation(&self) -> u64 {
+        self.generation.load(Ordering::Acquire)
+    }
+
+    pub fn record_success(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+    }
+
+    /// Count a failed request against the active endpoint, switching to the
+    /// next one once the threshold is reached. Call once per request, after
+    /// its retries are exhausted.
+    pub fn record_failure(&self) {
+        if self.endpoints.len() < 2 {
+            return;
+        }
+        let failures = self.consecutive_failures.fetch_add(1, Ordering::AcqRel) + 1;
+        if failures < self.failure_threshold {
+            return;
+        }
+        let from = self.active_index();
+        let to = (from + 1) % self.endpoints.len();
+        if self
+            .active
+            .compare_exchange(from, to, Ordering::AcqRel, Ordering::Acquire)
+            .is_ok()
+        {
+            self.switched();
+            warn!(
+                failures,
+                from = %self.endpoints[from].base_url,
+                to = %self.endpoints[to].base_url,
+                "server unreachable — failing over"
+            );
+        }
+    }
+
+    fn switched(&self) {
+        self.consecutive_failures.store(0, Ordering::Relaxed);
+        *self.last_failback_check.lock() = Instant::now();
+        self.generation.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Whether a standby is active and the primary is due for a probe.
+    pub fn failback_due(&self) -> bool {
+        !self.is_primary_active()
+            && self.last_failback_check.lock().elapsed() >= self.failback_interval
+    }
+
+    /// Probe the primary with `probe` if due, and make it active again when
+    /// the probe succeeds. Returns whether the primary became active.
+    pub async fn probe_failback<F, Fut>(&self, probe: F) -> bool
+    where
+        F: FnOnce(String) -> Fut,
+        Fut: Future<Output = bool>,
+    {
+        if !self.failback_due() || self.probing.swap(true, Ordering::AcqRel) {
+            return false;
+        }
+        *self.last_failback_check.lock() = Instant::now();
+        let primary_up = probe(self.primary().base_url.clone()).await;
+        self.probing.store(false, Ordering::Release);
+        if !primary_up {
+            debug!(primary = %self.primary().base_url, "primary still unreachable");
+            return false;
+        }
+        let from = self.active_index();
+        if from == 0
+            || self
+                .active
+                .compare_exchange(from, 0, Ordering::AcqRel, Ordering::Acquire)
+                .is_err()
+        {
+            return false;
+        }
+        self.switched();
+        info!(primary = %self.primary().base_url, "primary server reachable again — failing back");
+        true
+    }
+
+    /// Start a background primary probe over `client` when one is due.
+    /// Cheap when no probe is due; call it from request paths.
+    pub fn spawn_failback_probe_if_due(self: &Arc<Self>, client: &reqwest::Client) {
+        if !self.failback_due() {
+            return;
+        }
+        let endpoints = Arc::clone(self);
+        let client = client.clone();
+        tokio::spawn(async move {
+            endpoints
+                .probe_failback(|url| server_reachable(client, url))
+                .await;
+        });
+    }
+}
+
+/// A server is reachable when it answers at all without a 5xx status.
+async fn server_reachable(client: reqwest::Client, base_url: String) -> bool {
+    match client.get(&base_url).timeout(PROBE_TIMEOUT).send().await {
+        Ok(resp) => !resp.status().is_server_error(),
+        Err(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn endpoints() -> ServerEndpoints {
+        ServerEndpoints::new(
+            ServerEndpoint::new("https://primary.example.com/"),
+            vec![ServerEndpoint::new("https://standby.example.com")
+                .with_grpc_endpoint(Some("https://standby.example.com:50051".to_string()))],
+        )
+        .with_failure_threshold(3)
+        .with_failback_interval(Duration::ZERO)
+    }
+
+    #[test]
+    fn sustained_primary_failures_switch_to_standby() {
+        let endpoints = endpoints();
+        assert_eq!(endpoints.active_base_url(), "https://primary.example.com");
+
+        endpoints.record_failure();
+        endpoints.record_failure();
+        endpoints.record_success();
+        endpoints.record_failure();
+        endpoints.record_failure();
+        assert!(endpoints.is_primary_active(), "a success resets the count");
+
+        endpoints.record_failure();
+        assert_eq!(endpoints.active_base_url(), "https://standby.example.com");
+        assert_eq!(
+            endpoints.active().grpc_endpoint.as_deref(),
+            Some("https://standby.example.com:50051")
+        );
+        assert_eq!(endpoints.generation(), 1);
+    }
+
+    #[test]
+    fn single_endpoint_never_switches() {
+        let endpoints = ServerEndpoints::single("https://only.example.com");
+        for _ in 0..10 {
+            endpoints.record_failure();
+        }
+        assert!(endpoints.is_primary_active());
+        assert!(!endpoints.failback_due());
+    }
+
+    #[tokio::test]
+    async fn recovered_primary_fails_back() {
+        let endpoints = endpoints();
+        assert!(!endpoints.probe_failback(|_| async { true }).await);
+
+        for _ in 0..3 {
+            endpoints.record_failure();
+        }
+        assert!(!endpoints.is_primary_active());
+
+        assert!(!endpoints.probe_failback(|_| async { false }).await);
+        assert!(!endpoints.is_primary_active());
+
+        let probed = endpoints
+            .probe_failback(|url| async move { url == "https://primary.example.com" })
+            .await;
+        assert!(probed);
+        assert!(endpoints.is_primary_active());
+        assert_eq!(endpoints.generation(), 2);
+    }
+
+    #[tokio::test]
+    async fn failback_waits_for_probe_interval() {
+        let endpoints = endpoints().with_failback_interval(Duration::from_secs(3600));
+        for _ in 0..3 {
+            endpoints.record_failure();
+        }
+        assert!(!endpoints.failback_due());
+        assert!(!endpoints.probe_failback(|_| async { true }).await);
+        assert!(!endpoints.is_primary_active());
+    }
+}
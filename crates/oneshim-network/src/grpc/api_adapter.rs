@@ -14,7 +14,7 @@ use tracing::debug;
 #[cfg(feature = "grpc")]
 use oneshim_core::error::CoreError;
 #[cfg(feature = "grpc")]
-use oneshim_core::models::event::EventBatch;
+use oneshim_core::models::event::{BatchUploadResult, EventBatch};
 #[cfg(feature = "grpc")]
 use oneshim_core::models::frame::ContextUpload;
 #[cfg(feature = "grpc")]
@@ -63,6 +63,14 @@ impl ApiClient for GrpcApiAdapter {
         self.http_fallback.upload_batch(batch).await
     }
 
+    async fn upload_batch_itemized(
+        &self,
+        batch: &EventBatch,
+    ) -> Result<BatchUploadResult, CoreError> {
+        debug!("GrpcApiAdapter: upload_batch_itemized via REST");
+        self.http_fallback.upload_batch_itemized(batch).await
+    }
+
     async fn upload_context(&self, upload: &ContextUpload) -> Result<(), CoreError> {
         debug!("GrpcApiAdapter: upload_context via REST");
         self.http_fallback.upload_context(upload).await
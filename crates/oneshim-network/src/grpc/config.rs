@@ -453,6 +453,7 @@ mod tests {
         let tls = TlsConfig {
             enabled: true,
             allow_self_signed: false,
+            ..TlsConfig::default()
         };
         let config = GrpcConfig::from_core_with_rest_tls(&core, "https://api.example.com", &tls);
 
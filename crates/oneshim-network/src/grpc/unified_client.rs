@@ -3,6 +3,7 @@
 use std::collections::HashMap;
 use std::future::Future;
 use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -17,6 +18,7 @@ use super::config::GrpcConfig;
 use super::context_client::GrpcContextClient;
 use super::session_client::GrpcSessionClient;
 use crate::auth::TokenManager;
+use crate::failover::ServerEndpoints;
 use crate::http_client::HttpApiClient;
 
 pub use crate::proto::client_v1::{
@@ -50,6 +52,11 @@ pub struct UnifiedClient {
 
     token_manager: Arc<TokenManager>,
     http_client: HttpApiClient,
+
+    /// Server selection shared with the REST and SSE clients.
+    endpoints: Arc<ServerEndpoints>,
+    /// `endpoints` generation the cached gRPC clients were connected under.
+    connected_generation: AtomicU64,
 }
 
 impl UnifiedClient {
@@ -69,6 +76,7 @@ impl UnifiedClient {
             HttpApiClient::new(&config.rest_endpoint, token_manager.clone(), timeout)?
         };
 
+        let endpoints = Arc::new(ServerEndpoints::single(&config.rest_endpoint));
         Ok(Self {
             config,
             grpc_auth: Mutex::new(None),
@@ -76,32 +84,72 @@ impl UnifiedClient {
             grpc_context: Mutex::new(None),
             token_manager,
             http_client,
+            endpoints,
+            connected_generation: AtomicU64::new(0),
         })
     }
 
+    /// Follow the server selected by `endpoints` for REST fallback and gRPC.
+    /// gRPC clients reconnect to the active server's `grpc_endpoint` (or the
+    /// configured one) after every switch.
+    pub fn with_endpoints(self, endpoints: Arc<ServerEndpoints>) -> Self {
+        Self {
+            http_client: self.http_client.with_endpoints(Arc::clone(&endpoints)),
+            connected_generation: AtomicU64::new(endpoints.generation()),
+            endpoints,
+            ..self
+        }
+    }
+
+    fn active_grpc_config(&self) -> GrpcConfig {
+        let mut config = self.config.clone();
+        if let Some(grpc_endpoint) = &self.endpoints.active().grpc_endpoint {
+            config.grpc_endpoint = grpc_endpoint.clone();
+        }
+        config
+    }
+
+    /// Drop cached gRPC clients connected to a server that is no longer active.
+    async fn drop_stale_grpc_clients(&self) {
+        let generation = self.endpoints.generation();
+        if self.connected_generation.swap(generation, Ordering::AcqRel) == generation {
+            return;
+        }
+        info!(
+            server = %self.endpoints.active_base_url(),
+            "active server changed, reconnecting gRPC clients"
+        );
+        *self.grpc_auth.lock().await = None;
+        *self.grpc_session.lock().await = None;
+        *self.grpc_context.lock().await = None;
+    }
+
     /// gRPC 인증 클라이언트 초기화 — Mutex로 TOCTOU 경쟁 조건을 방지한다.
     async fn ensure_grpc_auth(&self) -> Result<(), CoreError> {
+        self.drop_stale_grpc_clients().await;
         let mut guard = self.grpc_auth.lock().await;
         if guard.is_none() {
-            *guard = Some(GrpcAuthClient::connect(self.config.clone()).await?);
+            *guard = Some(GrpcAuthClient::connect(self.active_grpc_config()).await?);
         }
         Ok(())
     }
 
     /// gRPC 세션 클라이언트 초기화 — Mutex로 TOCTOU 경쟁 조건을 방지한다.
     async fn ensure_grpc_session(&self) -> Result<(), CoreError> {
+        self.drop_stale_grpc_clients().await;
         let mut guard = self.grpc_session.lock().await;
         if guard.is_none() {
-            *guard = Some(GrpcSessionClient::connect(self.config.clone()).await?);
+            *guard = Some(GrpcSessionClient::connect(self.active_grpc_config()).await?);
         }
         Ok(())
     }
 
     /// gRPC 컨텍스트 클라이언트 초기화 — Mutex로 TOCTOU 경쟁 조건을 방지한다.
     async fn ensure_grpc_context(&self) -> Result<(), CoreError> {
+        self.drop_stale_grpc_clients().await;
         let mut guard = self.grpc_context.lock().await;
         if guard.is_none() {
-            *guard = Some(GrpcContextClient::connect(self.config.clone()).await?);
+            *guard = Some(GrpcContextClient::connect(self.active_grpc_config()).await?);
         }
         Ok(())
     }
@@ -71,14 +71,23 @@ pub use circuit_breaker::{
 };
 pub mod compression;
 pub mod connectivity;
+pub mod content_crypto;
+pub mod egress;
+pub use egress::EgressPolicy;
+pub mod failover;
+pub use failover::{ServerEndpoint, ServerEndpoints};
 pub mod http_api_session;
 pub mod http_client;
 pub mod integration;
 pub mod local_llm_session;
+pub mod metrics;
+pub use metrics::{EndpointMetricsSnapshot, NetworkMetrics};
 pub mod oauth;
 pub mod remote_embedding_client;
 pub mod resilience;
 pub mod sse_client;
+pub mod suggestion_webhook;
+pub mod ws_client;
 
 pub mod sync;
 
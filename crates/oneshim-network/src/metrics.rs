@@ -0,0 +1,239 @@
+//! Per-endpoint request counts, error counts and latency percentiles for
+//! server calls.
+//!
+//! Latencies go into a log-linear histogram of atomic counters (HDR-style:
+//! 16 sub-buckets per power of two, so percentiles are within ~6% of the
+//! true value). Recording is a read-locked map lookup plus a few relaxed
+//! atomic adds; the map is only write-locked the first time an endpoint is
+//! seen.
+
+pub use oneshim_core::models::network_metrics::EndpointMetricsSnapshot;
+use oneshim_core::ports::network_metrics::NetworkMetricsProvider;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Sub-buckets per power of two; also the width of the exact linear range.
+const SUB_BUCKET_BITS: u32 = 4;
+const SUB_BUCKETS: u64 = 1 << SUB_BUCKET_BITS;
+/// Microsecond values up to 2^40 (~12.7 days) are bucketed; larger ones
+/// land in the last bucket.
+const MAX_EXPONENT: u32 = 40;
+const BUCKETS: usize =
+    (SUB_BUCKETS + (MAX_EXPONENT - SUB_BUCKET_BITS + 1) as u64 * SUB_BUCKETS) as usize;
+
+fn bucket_index(micros: u64) -> usize {
+    if micros < SUB_BUCKETS {
+        return micros as usize;
+    }
+    let exponent = (63 - micros.leading_zeros()).min(MAX_EXPONENT);
+    let shift = exponent - SUB_BUCKET_BITS;
+    let sub = (micros >> shift).min(2 * SUB_BUCKETS - 1) - SUB_BUCKETS;
+    (SUB_BUCKETS + u64::from(shift) * SUB_BUCKETS + sub) as usize
+}
+
+/// Upper bound (inclusive) of the values in bucket `index`, in microseconds.
+fn bucket_upper_bound(index: usize) -> u64 {
+    let index = index as u64;
+    if index < SUB_BUCKETS {
+        return index;
+    }
+    let shift = (index - SUB_BUCKETS) / SUB_BUCKETS;
+    let sub = (index - SUB_BUCKETS) % SUB_BUCKETS;
+    ((SUB_BUCKETS + sub + 1) << shift) - 1
+}
+
+/// Lock-free latency histogram.
+pub struct LatencyHistogram {
+    buckets: Box<[AtomicU64]>,
+    max_micros: AtomicU64,
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self {
+            buckets: (0..BUCKETS).map(|_| AtomicU64::new(0)).collect(),
+            max_micros: AtomicU64::new(0),
+        }
+    }
+}
+
+impl LatencyHistogram {
+    pub fn record(&self, latency: Duration) {
+        let micros = u64::try_from(latency.as_micros()).unwrap_or(u64::MAX);
+        self.buckets[bucket_index(micros)].fetch_add(1, Ordering::Relaxed);
+        self.max_micros.fetch_max(micros, Ordering::Relaxed);
+    }
+
+    /// Nearest-rank percentiles (`pcts` in 0..=100), reported as each
+    /// bucket's upper bound capped at the recorded maximum. `None` when
+    /// empty.
+    pub fn percentiles<const N: usize>(&self, pcts: [f64; N]) -> Option<[Duration; N]> {
+        let counts: Vec<u64> = self
+            .buckets
+            .iter()
+            .map(|bucket| bucket.load(Ordering::Relaxed))
+            .collect();
+        let total: u64 = counts.iter().sum();
+        if total == 0 {
+            return None;
+        }
+        let max = self.max_micros.load(Ordering::Relaxed);
+        Some(pcts.map(|pct| {
+            let rank = ((pct.clamp(0.0, 100.0) / 100.0) * total as f64)
+                .ceil()
+                .max(1.0) as u64;
+            let mut seen = 0;
+            let index = counts
+                .iter()
+                .position(|count| {
+                    seen += count;
+                    seen >= rank
+                })
+                .unwrap_or(BUCKETS - 1);
+            Duration::from_micros(bucket_upper_bound(index).min(max))
+        }))
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_micros(self.max_micros.load(Ordering::Relaxed))
+    }
+}
+
+#[derive(Default)]
+struct EndpointMetrics {
+    requests: AtomicU64,
+    errors: AtomicU64,
+    latency: LatencyHistogram,
+}
+
+/// Collector shared by the server clients, one entry per endpoint.
+#[derive(Default)]
+pub struct NetworkMetrics {
+    endpoints: RwLock<HashMap<&'static str, Arc<EndpointMetrics>>>,
+}
+
+impl NetworkMetrics {
+    pub fn new() -> Arc<Self> {
+        Arc::new(Self::default())
+    }
+
+    /// Record one request attempt to `endpoint`.
+    pub fn record(&self, endpoint: &'static str, latency: Duration, ok: bool) {
+        let existing = self.endpoints.read().get(endpoint).cloned();
+        let metrics = match existing {
+            Some(metrics) => metrics,
+            None => self.endpoints.write().entry(endpoint).or_default().clone(),
+        };
+        metrics.requests.fetch_add(1, Ordering::Relaxed);
+        if !ok {
+            metrics.errors.fetch_add(1, Ordering::Relaxed);
+        }
+        metrics.latency.record(latency);
+    }
+
+    /// Per-endpoint figures, sorted by endpoint.
+    pub fn snapshot(&self) -> Vec<EndpointMetricsSnapshot> {
+        let endpoints: Vec<_> = self
+            .endpoints
+            .read()
+            .iter()
+            .map(|(endpoint, metrics)| (*endpoint, metrics.clone()))
+            .collect();
+        let mut snapshot: Vec<_> = endpoints
+            .into_iter()
+            .filter_map(|(endpoint, metrics)| {
+                let [p50, p95, p99] = metrics.latency.percentiles([50.0, 95.0, 99.0])?;
+                Some(EndpointMetricsSnapshot {
+                    endpoint: endpoint.to_string(),
+                    requests: metrics.requests.load(Ordering::Relaxed),
+                    errors: metrics.errors.load(Ordering::Relaxed),
+                    p50_ms: as_ms(p50),
+                    p95_ms: as_ms(p95),
+                    p99_ms: as_ms(p99),
+                    max_ms: as_ms(metrics.latency.max()),
+                })
+            })
+            .collect();
+        snapshot.sort_by(|a, b| a.endpoint.cmp(&b.endpoint));
+        snapshot
+    }
+}
+
+impl NetworkMetricsProvider for NetworkMetrics {
+    fn endpoint_metrics(&self) -> Vec<EndpointMetricsSnapshot> {
+        self.snapshot()
+    }
+}
+
+fn as_ms(duration: Duration) -> f64 {
+    duration.as_secs_f64() * 1000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buckets_are_contiguous_and_bound_their_values() {
+        for micros in [0, 1, 15, 16, 17, 31, 32, 33, 1_000, 123_456, 9_999_999] {
+            let index = bucket_index(micros);
+            assert!(micros <= bucket_upper_bound(index), "{micros}");
+            if index > 0 {
+                assert!(micros > bucket_upper_bound(index - 1), "{micros}");
+            }
+        }
+        assert_eq!(bucket_index(u64::MAX), BUCKETS - 1);
+    }
+
+    #[test]
+    fn percentiles_stay_within_bucket_precision() {
+        let histogram = LatencyHistogram::default();
+        assert!(histogram.percentiles([50.0]).is_none());
+        for ms in 1..=100 {
+            histogram.record(Duration::from_millis(ms));
+        }
+        let [p50, p99, p100] = histogram.percentiles([50.0, 99.0, 100.0]).unwrap();
+        let within = |actual: Duration, expected_ms: f64| {
+            let error = (as_ms(actual) - expected_ms).abs() / expected_ms;
+            assert!(
+                error <= 1.0 / SUB_BUCKETS as f64,
+                "{actual:?} vs {expected_ms}ms"
+            );
+        };
+        within(p50, 50.0);
+        within(p99, 99.0);
+        assert_eq!(p100, Duration::from_millis(100));
+        assert_eq!(histogram.max(), Duration::from_millis(100));
+    }
+
+    #[test]
+    fn snapshot_breaks_down_by_endpoint() {
+        let metrics = NetworkMetrics::new();
+        metrics.record(
+            "POST /user_context/batches",
+            Duration::from_millis(20),
+            true,
+        );
+        metrics.record(
+            "POST /user_context/batches",
+            Duration::from_millis(40),
+            false,
+        );
+        metrics.record(
+            "GET /user_context/sessions/stream",
+            Duration::from_millis(5),
+            true,
+        );
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!(snapshot[0].endpoint, "GET /user_context/sessions/stream");
+        assert_eq!((snapshot[0].requests, snapshot[0].errors), (1, 0));
+        assert_eq!(snapshot[1].endpoint, "POST /user_context/batches");
+        assert_eq!((snapshot[1].requests, snapshot[1].errors), (2, 1));
+        assert_eq!(snapshot[1].max_ms, 40.0);
+    }
+}
@@ -27,13 +27,12 @@ pub fn scale_duration(duration: Duration, factor: u32) -> Duration {
 }
 
 pub fn jittered_backoff_delay(attempt: u32, base: Duration, max: Duration) -> Duration {
-    let base_ms = base.as_millis().min(u64::MAX as u128) as u64;
     let max_ms = max.as_millis().min(u64::MAX as u128) as u64;
-    if base_ms == 0 || max_ms == 0 {
+    let exp_ms = exponential_step_ms(attempt, base);
+    if exp_ms == 0 || max_ms == 0 {
         return Duration::from_millis(0);
     }
 
-    let exp_ms = base_ms.saturating_mul(2u64.saturating_pow(attempt.min(MAX_BACKOFF_EXPONENT)));
     let jitter_max_ms = exp_ms / 4;
     let jitter_ms = if jitter_max_ms == 0 {
         0
@@ -45,10 +44,71 @@ pub fn jittered_backoff_delay(attempt: u32, base: Duration, max: Duration) -> Du
     Duration::from_millis(exp_ms.saturating_add(jitter_ms).min(max_ms))
 }
 
-#[derive(Debug, Clone)]
+/// `base * 2^attempt` in milliseconds, saturating.
+fn exponential_step_ms(attempt: u32, base: Duration) -> u64 {
+    let base_ms = base.as_millis().min(u64::MAX as u128) as u64;
+    base_ms.saturating_mul(2u64.saturating_pow(attempt.min(MAX_BACKOFF_EXPONENT)))
+}
+
+/// Exponential backoff drawn from a randomized window around each step.
+///
+/// The step doubles from `min` per attempt. The returned delay is sampled from
+/// `[step, step * (1 + jitter_ratio)]`, pulled down to `[max * (1 - jitter_ratio), max]`
+/// once the step reaches the cap, so clients retrying after the same outage
+/// spread out even at the ceiling. The result always lies within `[min, max]`;
+/// `jitter_ratio = 0.0` yields plain capped exponential backoff.
+pub fn windowed_backoff_delay(
+    attempt: u32,
+    min: Duration,
+    max: Duration,
+    jitter_ratio: f64,
+) -> Duration {
+    let min_ms = min.as_millis().min(u64::MAX as u128) as u64;
+    let max_ms = (max.as_millis().min(u64::MAX as u128) as u64).max(min_ms);
+    if max_ms == 0 {
+        return Duration::from_millis(0);
+    }
+
+    let ratio = if jitter_ratio.is_finite() {
+        jitter_ratio.clamp(0.0, 1.0)
+    } else {
+        0.0
+    };
+    let step_ms = min_ms.saturating_mul(2u64.saturating_pow(attempt.min(MAX_BACKOFF_EXPONENT)));
+    let upper_ms = ((step_ms as f64) * (1.0 + ratio)).min(max_ms as f64) as u64;
+    let lower_ms = step_ms
+        .min(((max_ms as f64) * (1.0 - ratio)) as u64)
+        .max(min_ms)
+        .min(upper_ms);
+
+    let delay_ms = if upper_ms > lower_ms {
+        let mut rng = rand::rng();
+        rng.random_range(lower_ms..=upper_ms)
+    } else {
+        upper_ms
+    };
+    Duration::from_millis(delay_ms.max(min_ms))
+}
+
+/// How [`RetryBackoffPolicy::delay`] randomizes each exponential step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackoffJitter {
+    /// The step itself, capped at `max_delay`.
+    None,
+    /// Up to a quarter of the step on top, see [`jittered_backoff_delay`].
+    Additive,
+    /// Uniform in `[0, step]`, so a fleet that failed together does not
+    /// retry together.
+    Full,
+}
+
+/// Exponential backoff: retry `n` (from 0) waits `base_delay * 2^n`, capped
+/// at `max_delay` and randomized according to `jitter`.
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct RetryBackoffPolicy {
     pub base_delay: Duration,
     pub max_delay: Duration,
+    pub jitter: BackoffJitter,
 }
 
 impl RetryBackoffPolicy {
@@ -56,10 +116,73 @@ impl RetryBackoffPolicy {
         Self {
             base_delay,
             max_delay,
+            jitter: BackoffJitter::Additive,
+        }
+    }
+
+    pub fn with_jitter(mut self, jitter: BackoffJitter) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Wait before retry `attempt` (0 for the first retry).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        let max_ms = self.max_delay.as_millis().min(u64::MAX as u128) as u64;
+        let ceiling_ms = exponential_step_ms(attempt, self.base_delay).min(max_ms);
+        match self.jitter {
+            BackoffJitter::None => Duration::from_millis(ceiling_ms),
+            BackoffJitter::Additive => {
+                jittered_backoff_delay(attempt, self.base_delay, self.max_delay)
+            }
+            BackoffJitter::Full if ceiling_ms == 0 => Duration::from_millis(0),
+            BackoffJitter::Full => {
+                let mut rng = rand::rng();
+                Duration::from_millis(rng.random_range(0..=ceiling_ms))
+            }
         }
     }
 }
 
+/// Request retry schedule for [`crate::http_client::HttpApiClient`].
+///
+/// Retry `n` (from 0) waits `base_delay * 2^n`, capped at `max_delay`; with
+/// `jitter` the wait is drawn from zero up to that value instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+    pub max_retries: u32,
+    pub jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            base_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            max_retries: 3,
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// The backoff half of this policy: full jitter, or none.
+    pub fn backoff(&self) -> RetryBackoffPolicy {
+        let jitter = if self.jitter {
+            BackoffJitter::Full
+        } else {
+            BackoffJitter::None
+        };
+        RetryBackoffPolicy::new(self.base_delay, self.max_delay).with_jitter(jitter)
+    }
+
+    /// Wait before retry `attempt` (0 for the first retry).
+    pub fn delay(&self, attempt: u32) -> Duration {
+        self.backoff().delay(attempt)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct RetryBackoffGate {
     policy: RetryBackoffPolicy,
@@ -91,11 +214,9 @@ impl RetryBackoffGate {
             NetworkError::RateLimited { retry_after_secs } => {
                 Duration::from_secs(*retry_after_secs)
             }
-            _ => jittered_backoff_delay(
-                self.consecutive_failures.saturating_sub(1),
-                self.policy.base_delay,
-                self.policy.max_delay,
-            ),
+            _ => self
+                .policy
+                .delay(self.consecutive_failures.saturating_sub(1)),
         };
         self.blocked_until = Some(now + delay);
         delay
@@ -156,6 +277,7 @@ pub fn endpoint_authority(url: &str) -> Result<String, url::ParseError> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::collections::HashSet;
 
     #[test]
     fn jittered_backoff_is_bounded() {
@@ -164,6 +286,76 @@ mod tests {
         assert!(delay <= Duration::from_secs(20));
     }
 
+    #[test]
+    fn backoff_policy_grows_exponentially_up_to_the_cap() {
+        let policy =
+            RetryBackoffPolicy::new(Duration::from_millis(100), Duration::from_millis(500))
+                .with_jitter(BackoffJitter::None);
+        let delays: Vec<_> = (0..5).map(|attempt| policy.delay(attempt)).collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500].map(Duration::from_millis));
+
+        let jittered = policy.clone().with_jitter(BackoffJitter::Full);
+        for attempt in 0..5 {
+            let ceiling = delays[attempt as usize];
+            let samples: HashSet<_> = (0..64).map(|_| jittered.delay(attempt)).collect();
+            assert!(samples.iter().all(|delay| *delay <= ceiling));
+            assert!(samples.len() > 1, "attempt {attempt} must be jittered");
+        }
+    }
+
+    #[test]
+    fn retry_policy_delays_follow_its_backoff() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(500),
+            max_retries: 5,
+            jitter: false,
+        };
+        let delays: Vec<_> = (0..5).map(|attempt| policy.delay(attempt)).collect();
+        assert_eq!(delays, [100, 200, 400, 500, 500].map(Duration::from_millis));
+        assert_eq!(RetryPolicy::default().backoff().jitter, BackoffJitter::Full);
+    }
+
+    #[test]
+    fn windowed_backoff_stays_within_min_and_max() {
+        let min = Duration::from_secs(1);
+        let max = Duration::from_secs(30);
+        for attempt in 0..20 {
+            for _ in 0..50 {
+                let delay = windowed_backoff_delay(attempt, min, max, 0.5);
+                assert!(delay >= min, "attempt {attempt}: {delay:?} below min");
+                assert!(delay <= max, "attempt {attempt}: {delay:?} above max");
+            }
+        }
+    }
+
+    #[test]
+    fn windowed_backoff_varies_across_attempts_and_at_cap() {
+        let min = Duration::from_secs(1);
+        let max = Duration::from_secs(30);
+        let first: HashSet<_> = (0..64)
+            .map(|_| windowed_backoff_delay(0, min, max, 0.5))
+            .collect();
+        let capped: HashSet<_> = (0..64)
+            .map(|_| windowed_backoff_delay(15, min, max, 0.5))
+            .collect();
+        assert!(first.len() > 1, "first retry must be jittered");
+        assert!(capped.len() > 1, "capped retries must still be jittered");
+        assert!(capped.iter().all(|d| *d >= Duration::from_secs(15)));
+    }
+
+    #[test]
+    fn windowed_backoff_without_jitter_is_capped_exponential() {
+        let min = Duration::from_secs(1);
+        let max = Duration::from_secs(30);
+        assert_eq!(windowed_backoff_delay(0, min, max, 0.0), min);
+        assert_eq!(
+            windowed_backoff_delay(3, min, max, 0.0),
+            Duration::from_secs(8)
+        );
+        assert_eq!(windowed_backoff_delay(9, min, max, 0.0), max);
+    }
+
     #[test]
     fn scaled_duration_scales_millis() {
         let delay = scale_duration(Duration::from_millis(250), 8);
@@ -1,7 +1,7 @@
 use async_trait::async_trait;
 use eventsource_stream::Eventsource;
 use futures::stream::StreamExt;
-use oneshim_core::config::TlsConfig;
+use oneshim_core::config::{ServerConfig, TlsConfig};
 use oneshim_core::error::CoreError;
 use oneshim_core::models::suggestion::Suggestion;
 use oneshim_core::ports::api_client::{SseClient, SseEvent};
@@ -10,36 +10,121 @@ use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
-use tokio::time::timeout;
+use tokio::time::{timeout, Instant};
 use tracing::{debug, info, warn};
 
 use crate::auth::TokenManager;
+use crate::circuit_breaker::{CircuitBreaker, CircuitState};
+use crate::egress::EgressPolicy;
+use crate::failover::ServerEndpoints;
 use crate::http_client::build_reqwest_client;
+use crate::metrics::NetworkMetrics;
+use crate::resilience::windowed_backoff_delay;
 
 /// SSE 활동 타임아웃 기본값 — 5분 동안 메시지가 없으면 재연결을 트리거한다.
 const ACTIVITY_TIMEOUT_SECS: u64 = 300;
 
+/// A connection that stays up at least this long resets the reconnect backoff.
+const STABLE_CONNECTION_SECS: u64 = 60;
+
+const DEFAULT_RECONNECT_JITTER_RATIO: f64 = 0.5;
+
+/// [`NetworkMetrics`] key for stream connects.
+const STREAM_ENDPOINT: &str = "GET /user_context/sessions/stream";
+
+/// Reconnect backoff settings for the SSE stream.
+#[derive(Debug, Clone)]
+pub struct SseReconnectPolicy {
+    pub min_delay: Duration,
+    pub max_delay: Duration,
+    /// Randomized share of each delay (0.0–1.0); see [`windowed_backoff_delay`].
+    pub jitter_ratio: f64,
+    /// Minimum uptime for a connection to count as healthy and reset the backoff.
+    pub stable_after: Duration,
+}
+
+impl SseReconnectPolicy {
+    /// Default policy capped at `max_retry_secs` — 1s floor, 50% jitter.
+    pub fn with_max_secs(max_retry_secs: u64) -> Self {
+        Self {
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(max_retry_secs.max(1)),
+            jitter_ratio: DEFAULT_RECONNECT_JITTER_RATIO,
+            stable_after: Duration::from_secs(STABLE_CONNECTION_SECS),
+        }
+    }
+
+    pub fn from_config(server: &ServerConfig) -> Self {
+        let min_delay = Duration::from_secs(server.sse_min_retry_secs.max(1));
+        Self {
+            min_delay,
+            max_delay: Duration::from_secs(server.sse_max_retry_secs).max(min_delay),
+            jitter_ratio: server.sse_retry_jitter_ratio,
+            stable_after: Duration::from_secs(STABLE_CONNECTION_SECS),
+        }
+    }
+}
+
+/// Per-connection backoff state: counts consecutive failed attempts and only
+/// forgets them once a connection has proven stable.
+#[derive(Debug)]
+pub(crate) struct ReconnectBackoff {
+    policy: SseReconnectPolicy,
+    attempt: u32,
+}
+
+impl ReconnectBackoff {
+    pub(crate) fn new(policy: SseReconnectPolicy) -> Self {
+        Self { policy, attempt: 0 }
+    }
+
+    pub(crate) fn next_delay(&mut self) -> Duration {
+        let delay = windowed_backoff_delay(
+            self.attempt,
+            self.policy.min_delay,
+            self.policy.max_delay,
+            self.policy.jitter_ratio,
+        );
+        self.attempt = self.attempt.saturating_add(1);
+        delay
+    }
+
+    /// Called when an established stream ends. A short-lived connection keeps
+    /// escalating the backoff so a flapping server is not hammered.
+    pub(crate) fn on_stream_closed(&mut self, connected_for: Duration) {
+        if connected_for >= self.policy.stable_after {
+            self.attempt = 0;
+        }
+    }
+}
+
 pub struct SseStreamClient {
-    base_url: String,
+    endpoints: Arc<ServerEndpoints>,
     token_manager: Arc<TokenManager>,
-    max_retry_secs: u64,
+    reconnect_policy: SseReconnectPolicy,
     http_client: reqwest::Client,
     /// Tracks the last SSE event ID for automatic resume on reconnect (RFC 9110 §9.3.4)
     last_event_id: Mutex<Option<String>>,
     /// 누적 이벤트 ID 갭 카운터 — 수신 누락 추정치
     gap_count: Arc<AtomicU64>,
+    egress: EgressPolicy,
+    circuit_breaker: Option<Arc<CircuitBreaker>>,
+    metrics: Option<Arc<NetworkMetrics>>,
 }
 
 impl SseStreamClient {
     /// 기존 생성자 — TLS 미적용 (역호환성 보장, 테스트 전용)
     pub fn new(base_url: &str, token_manager: Arc<TokenManager>, max_retry_secs: u64) -> Self {
         Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+            endpoints: Arc::new(ServerEndpoints::single(base_url)),
             token_manager,
-            max_retry_secs,
+            reconnect_policy: SseReconnectPolicy::with_max_secs(max_retry_secs),
             http_client: reqwest::Client::new(),
             last_event_id: Mutex::new(None),
             gap_count: Arc::new(AtomicU64::new(0)),
+            egress: EgressPolicy::default(),
+            circuit_breaker: None,
+            metrics: None,
         }
     }
 
@@ -47,6 +132,7 @@ impl SseStreamClient {
     ///
     /// `tls.enabled=true` 이면 HTTPS 전용을 강제한다.
     /// `tls.allow_self_signed=true` 이면 자체 서명 인증서를 허용한다 (개발 전용).
+    /// `tls.client_cert_pem`/`tls.client_key_path` 가 있으면 mTLS 클라이언트 인증서를 제시한다.
     pub fn new_with_tls(
         base_url: &str,
         token_manager: Arc<TokenManager>,
@@ -57,16 +143,74 @@ impl SseStreamClient {
         // 전역 타임아웃 미적용(None): SSE는 장기 스트림 연결이므로 단일 타임아웃으로 끊기면 안 됨
         let http_client = build_reqwest_client(tls, None)?;
         Ok(Self {
-            base_url: base_url.trim_end_matches('/').to_string(),
+            endpoints: Arc::new(ServerEndpoints::single(base_url)),
             token_manager,
-            max_retry_secs,
+            reconnect_policy: SseReconnectPolicy::with_max_secs(max_retry_secs),
             http_client,
             last_event_id: Mutex::new(None),
             gap_count: Arc::new(AtomicU64::new(0)),
+            egress: EgressPolicy::default(),
+            circuit_breaker: None,
+            metrics: None,
         })
     }
 
-    /// Returns the last received SSE event ID, if any.
+    /// Replace the reconnect backoff policy (min/max/jitter).
+    pub fn with_reconnect_policy(mut self, policy: SseReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
+    /// Share server selection and failover with the other server clients.
+    /// Replaces the constructor's `base_url`; an open stream reconnects
+    /// when the active server changes.
+    pub fn with_endpoints(mut self, endpoints: Arc<ServerEndpoints>) -> Self {
+        self.endpoints = endpoints;
+        self
+    }
+
+    /// Apply the shared outbound host allowlist to the stream connection.
+    pub fn with_egress_policy(mut self, egress: EgressPolicy) -> Self {
+        self.egress = egress;
+        self
+    }
+
+    /// Hold off reconnecting while `breaker` is open rather than dialing an
+    /// unreachable server; the first attempt after the cooldown is the probe.
+    pub fn with_circuit_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.circuit_breaker = Some(breaker);
+        self
+    }
+
+    /// Record each stream connect attempt (time to response headers) in
+    /// `metrics`.
+    pub fn with_metrics(mut self, metrics: Arc<NetworkMetrics>) -> Self {
+        self.metrics = Some(metrics);
+        self
+    }
+
+    fn record_connect_attempt(&self, started: Instant, ok: bool) {
+        if let Some(metrics) = &self.metrics {
+            metrics.record(STREAM_ENDPOINT, started.elapsed(), ok);
+        }
+    }
+
+    fn record_connect_failure(&self) {
+        self.endpoints.record_failure();
+        if let Some(breaker) = &self.circuit_breaker {
+            breaker.record_failure();
+        }
+    }
+
+    /// Resume from `last_event_id` (e.g. persisted from [`Self::last_event_id`]
+    /// before a restart); the first connection sends it as `Last-Event-ID`.
+    pub fn with_last_event_id(self, last_event_id: Option<String>) -> Self {
+        *self.last_event_id.lock() = last_event_id;
+        self
+    }
+
+    /// Returns the last received SSE event ID, if any. Callers may persist
+    /// it and pass it to [`Self::with_last_event_id`] on the next start.
     pub fn last_event_id(&self) -> Option<String> {
         self.last_event_id.lock().clone()
     }
@@ -123,17 +267,31 @@ impl SseStreamClient {
 #[async_trait]
 impl SseClient for SseStreamClient {
     async fn connect(&self, session_id: &str, tx: mpsc::Sender<SseEvent>) -> Result<(), CoreError> {
-        let url = format!(
-            "{}/user_context/sessions/stream?session_id={}",
-            self.base_url, session_id
-        );
-        let max_retry = self.max_retry_secs;
-
-        info!("SSE connection started: {url}");
-
-        let mut retry_delay = 1u64;
+        let mut backoff = ReconnectBackoff::new(self.reconnect_policy.clone());
 
         loop {
+            if let Some(breaker) = &self.circuit_breaker {
+                if matches!(breaker.check(), CircuitState::Open { .. }) {
+                    if tx.is_closed() {
+                        return Ok(());
+                    }
+                    let wait = breaker.retry_in().unwrap_or_default();
+                    debug!("SSE circuit open, next attempt in {}ms", wait.as_millis());
+                    tokio::time::sleep(wait).await;
+                    continue;
+                }
+            }
+            self.endpoints
+                .spawn_failback_probe_if_due(&self.http_client);
+            let generation = self.endpoints.generation();
+            let url = format!(
+                "{}/user_context/sessions/stream?session_id={}",
+                self.endpoints.active_base_url(),
+                session_id
+            );
+            self.egress.check_url(&url)?;
+            info!("SSE connection started: {url}");
+
             let token = self.token_manager.get_token().await?;
 
             let mut request = self
@@ -146,18 +304,24 @@ impl SseClient for SseStreamClient {
                 debug!(last_event_id = %id, "SSE reconnecting with Last-Event-ID");
             }
 
+            let started = Instant::now();
             let response = match request.send().await {
-                Ok(response) => response,
+                Ok(response) => {
+                    self.record_connect_attempt(started, response.status().is_success());
+                    response
+                }
                 Err(e) => {
+                    self.record_connect_attempt(started, false);
                     warn!("SSE connection request failure: {e}");
+                    self.record_connect_failure();
 
                     if tx.is_closed() {
                         return Ok(());
                     }
 
-                    warn!("SSE reconnect waiting: {retry_delay}s");
-                    tokio::time::sleep(Duration::from_secs(retry_delay)).await;
-                    retry_delay = (retry_delay * 2).min(max_retry);
+                    let delay = backoff.next_delay();
+                    warn!("SSE reconnect waiting: {}ms", delay.as_millis());
+                    tokio::time::sleep(delay).await;
                     continue;
                 }
             };
@@ -168,24 +332,35 @@ impl SseClient for SseStreamClient {
                     response.status(),
                     url
                 );
+                if response.status().is_server_error() {
+                    self.record_connect_failure();
+                }
 
                 if tx.is_closed() {
                     return Ok(());
                 }
 
-                warn!("SSE reconnect waiting: {retry_delay}s");
-                tokio::time::sleep(Duration::from_secs(retry_delay)).await;
-                retry_delay = (retry_delay * 2).min(max_retry);
+                let delay = backoff.next_delay();
+                warn!("SSE reconnect waiting: {}ms", delay.as_millis());
+                tokio::time::sleep(delay).await;
                 continue;
             }
 
             let mut stream = response.bytes_stream().eventsource();
             debug!("SSE connection established");
-            retry_delay = 1;
+            self.endpoints.record_success();
+            if let Some(breaker) = &self.circuit_breaker {
+                breaker.record_success();
+            }
+            let connected_at = Instant::now();
 
             let activity_timeout = Duration::from_secs(ACTIVITY_TIMEOUT_SECS);
 
             loop {
+                if self.endpoints.generation() != generation {
+                    info!("active server changed, reconnecting SSE stream");
+                    break;
+                }
                 match timeout(activity_timeout, stream.next()).await {
                     Ok(Some(Ok(msg))) => {
                         let event_id = if msg.id.is_empty() {
@@ -253,9 +428,10 @@ impl SseClient for SseStreamClient {
                 return Ok(());
             }
 
-            warn!("SSE reconnect waiting: {retry_delay}s");
-            tokio::time::sleep(Duration::from_secs(retry_delay)).await;
-            retry_delay = (retry_delay * 2).min(max_retry);
+            backoff.on_stream_closed(connected_at.elapsed());
+            let delay = backoff.next_delay();
+            warn!("SSE reconnect waiting: {}ms", delay.as_millis());
+            tokio::time::sleep(delay).await;
         }
     }
 }
@@ -327,6 +503,68 @@ mod tests {
         assert!(event.is_none());
     }
 
+    fn test_policy() -> SseReconnectPolicy {
+        SseReconnectPolicy {
+            min_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            jitter_ratio: 0.5,
+            stable_after: Duration::from_secs(60),
+        }
+    }
+
+    #[test]
+    fn reconnect_backoff_stays_within_policy_bounds() {
+        let mut backoff = ReconnectBackoff::new(test_policy());
+        for _ in 0..32 {
+            let delay = backoff.next_delay();
+            assert!(delay >= Duration::from_secs(1));
+            assert!(delay <= Duration::from_secs(30));
+        }
+    }
+
+    #[test]
+    fn reconnect_backoff_resets_only_after_stable_connection() {
+        let mut backoff = ReconnectBackoff::new(test_policy());
+        for _ in 0..5 {
+            backoff.next_delay();
+        }
+
+        backoff.on_stream_closed(Duration::from_secs(5));
+        assert_eq!(
+            backoff.attempt, 5,
+            "brief connection must not reset backoff"
+        );
+
+        backoff.on_stream_closed(Duration::from_secs(60));
+        assert_eq!(backoff.attempt, 0);
+        assert!(backoff.next_delay() <= Duration::from_millis(1_500));
+    }
+
+    #[test]
+    fn reconnect_policy_from_config_keeps_max_above_min() {
+        let mut server = oneshim_core::config::AppConfig::default_config().server;
+        server.sse_min_retry_secs = 10;
+        server.sse_max_retry_secs = 5;
+        server.sse_retry_jitter_ratio = 0.25;
+
+        let policy = SseReconnectPolicy::from_config(&server);
+        assert_eq!(policy.min_delay, Duration::from_secs(10));
+        assert_eq!(policy.max_delay, Duration::from_secs(10));
+        assert!((policy.jitter_ratio - 0.25).abs() < f64::EPSILON);
+    }
+
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn connect_to_disallowed_host_is_blocked() {
+        let tm = Arc::new(TokenManager::new("https://blocked.example.com"));
+        let client = SseStreamClient::new("https://blocked.example.com", tm, 30)
+            .with_egress_policy(EgressPolicy::allow_only(["api.oneshim.dev"]));
+        let (tx, _rx) = mpsc::channel(1);
+
+        let err = client.connect("sess_1", tx).await.unwrap_err();
+        assert!(err.to_string().contains("egress allowlist"), "{err}");
+    }
+
     #[test]
     #[allow(deprecated)]
     fn last_event_id_initially_none() {
@@ -335,6 +573,81 @@ mod tests {
         assert!(client.last_event_id().is_none());
     }
 
+    #[tokio::test]
+    #[allow(deprecated)]
+    async fn reconnect_sends_last_event_id() {
+        use mockito::Matcher;
+
+        let mut server = mockito::Server::new_async().await;
+        let _login = server
+            .mock("POST", "/api/v1/auth/tokens")
+            .with_status(200)
+            .with_header("content-type", "application/json")
+            .with_body(r#"{"access_token":"test_jwt","refresh_token":"ref","expires_in":3600}"#)
+            .create_async()
+            .await;
+        let tm = Arc::new(TokenManager::new(&server.url()));
+        tm.login("test@test.com", "pass").await.unwrap();
+
+        let first = server
+            .mock("GET", "/user_context/sessions/stream")
+            .match_query(Matcher::Any)
+            .match_header("Last-Event-ID", "6")
+            .with_header("content-type", "text/event-stream")
+            .with_body(
+                "id: 7\nevent: heartbeat\ndata: {\"timestamp\":\"2026-03-18T09:00:00Z\"}\n\n",
+            )
+            .expect(1)
+            .create_async()
+            .await;
+        let resumed = server
+            .mock("GET", "/user_context/sessions/stream")
+            .match_query(Matcher::Any)
+            .match_header("Last-Event-ID", "7")
+            .with_header("content-type", "text/event-stream")
+            .with_body("id: 8\nevent: close\ndata: bye\n\n")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let client = Arc::new(
+            SseStreamClient::new(&server.url(), tm, 1)
+                .with_reconnect_policy(SseReconnectPolicy {
+                    min_delay: Duration::from_millis(10),
+                    max_delay: Duration::from_millis(10),
+                    jitter_ratio: 0.0,
+                    stable_after: Duration::from_secs(60),
+                })
+                .with_last_event_id(Some("6".to_string())),
+        );
+        let (tx, mut rx) = mpsc::channel(8);
+        let task = tokio::spawn({
+            let client = client.clone();
+            async move { client.connect("sess_1", tx).await }
+        });
+
+        let events = timeout(Duration::from_secs(5), async {
+            let mut events = Vec::new();
+            while let Some(event) = rx.recv().await {
+                let closed = matches!(event, SseEvent::Close);
+                events.push(event);
+                if closed {
+                    break;
+                }
+            }
+            events
+        })
+        .await
+        .expect("stream resumed after reconnect");
+        task.abort();
+
+        assert!(matches!(events[0], SseEvent::Heartbeat { .. }));
+        assert!(matches!(events[1], SseEvent::Close));
+        assert_eq!(client.last_event_id().as_deref(), Some("8"));
+        first.assert_async().await;
+        resumed.assert_async().await;
+    }
+
     #[test]
     #[allow(deprecated)]
     fn gap_count_initially_zero() {
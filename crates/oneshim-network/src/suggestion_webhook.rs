@@ -0,0 +1,121 @@
+//! Webhook delivery for suggestions routed to the `webhook` channel.
+//!
+//! Posts the suggestion as JSON to the configured URL. The URL goes through
+//! the same [`EgressPolicy`] as every other outbound request.
+
+use std::time::Duration;
+
+use async_trait::async_trait;
+use oneshim_core::error::CoreError;
+use oneshim_core::models::suggestion::Suggestion;
+use oneshim_core::ports::notifier::SuggestionWebhook;
+use tracing::debug;
+
+use crate::egress::EgressPolicy;
+use crate::error::NetworkError;
+
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+pub struct HttpSuggestionWebhook {
+    http_client: reqwest::Client,
+    url: String,
+    egress_policy: EgressPolicy,
+}
+
+impl HttpSuggestionWebhook {
+    pub fn new(url: impl Into<String>) -> Self {
+        let http_client = reqwest::Client::builder()
+            .timeout(WEBHOOK_TIMEOUT)
+            .build()
+            .unwrap_or_default();
+        Self {
+            http_client,
+            url: url.into(),
+            egress_policy: EgressPolicy::unrestricted(),
+        }
+    }
+
+    pub fn with_egress_policy(mut self, egress_policy: EgressPolicy) -> Self {
+        self.egress_policy = egress_policy;
+        self
+    }
+}
+
+#[async_trait]
+impl SuggestionWebhook for HttpSuggestionWebhook {
+    async fn deliver(&self, suggestion: &Suggestion) -> Result<(), CoreError> {
+        self.egress_policy.check_url(&self.url)?;
+
+        let response = self
+            .http_client
+            .post(&self.url)
+            .json(suggestion)
+            .send()
+            .await
+            .map_err(|e| {
+                if e.is_timeout() {
+                    NetworkError::Timeout {
+                        timeout_ms: WEBHOOK_TIMEOUT.as_millis() as u64,
+                    }
+                } else {
+                    NetworkError::Http(format!("suggestion webhook request failed: {e}"))
+                }
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            return Err(NetworkError::Http(format!("suggestion webhook returned {status}")).into());
+        }
+
+        debug!(id = %suggestion.suggestion_id, "suggestion delivered to webhook");
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use oneshim_core::models::suggestion::{Priority, SuggestionSource, SuggestionType};
+
+    fn make_suggestion() -> Suggestion {
+        Suggestion {
+            suggestion_id: "hook-1".to_string(),
+            suggestion_type: SuggestionType::WorkGuidance,
+            content: "Take a break".to_string(),
+            priority: Priority::Critical,
+            confidence_score: 0.9,
+            relevance_score: 0.8,
+            is_actionable: true,
+            created_at: Utc::now(),
+            expires_at: None,
+            source: SuggestionSource::RuleBased,
+            reasoning: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn posts_suggestion_json() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/hook")
+            .match_body(mockito::Matcher::PartialJsonString(
+                r#"{"suggestion_id":"hook-1"}"#.to_string(),
+            ))
+            .with_status(204)
+            .create_async()
+            .await;
+
+        let webhook = HttpSuggestionWebhook::new(format!("{}/hook", server.url()));
+        webhook.deliver(&make_suggestion()).await.unwrap();
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn egress_policy_blocks_unlisted_host() {
+        let webhook = HttpSuggestionWebhook::new("https://hooks.example.com/x")
+            .with_egress_policy(EgressPolicy::allow_only(["api.oneshim.dev"]));
+        let err = webhook.deliver(&make_suggestion()).await.unwrap_err();
+        assert!(matches!(err, CoreError::PolicyDenied { .. }), "{err:?}");
+    }
+}
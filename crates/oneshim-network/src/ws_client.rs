@@ -0,0 +1,678 @@
+//! WebSocket automation command channel — the server pushes
+//! `AutomationCommand`s and the client answers each one with a
+//! `CommandResult` frame on the same socket.
+//!
+//! Commands are handed to an [`AutomationCommandExecutor`], normally
+//! `AutomationPort::execute_command`, so policy and audit checks run exactly
+//! as they do for commands from any other source. The socket itself is a
+//! [`ReconnectingWsClient`].
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::{SinkExt, StreamExt};
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{mpsc, watch, Notify};
+use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderValue;
+use tokio_tungstenite::tungstenite::Message;
+use tracing::{debug, info, warn};
+
+use oneshim_core::error::CoreError;
+use oneshim_core::models::automation::{AutomationCommand, CommandResult};
+use oneshim_core::ports::automation::AutomationPort;
+
+use crate::auth::TokenManager;
+use crate::sse_client::{ReconnectBackoff, SseReconnectPolicy};
+
+/// Server path of the automation command socket.
+pub const AUTOMATION_CHANNEL_PATH: &str = "/api/v1/automation/ws";
+
+/// Runs one automation command and yields its result.
+pub type AutomationCommandExecutor = Arc<
+    dyn Fn(AutomationCommand) -> BoxFuture<'static, Result<CommandResult, CoreError>> + Send + Sync,
+>;
+
+/// Route commands through `port.execute_command`.
+pub fn executor_from_port(port: Arc<dyn AutomationPort>) -> AutomationCommandExecutor {
+    Arc::new(move |command| {
+        let port = port.clone();
+        Box::pin(async move { port.execute_command(&command).await })
+    })
+}
+
+/// One JSON text frame on the automation channel.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum AutomationChannelFrame {
+    /// Server → client.
+    Command { command: AutomationCommand },
+    /// Client → server, one per received command.
+    Result {
+        command_id: String,
+        result: CommandResult,
+    },
+}
+
+/// `ws(s)://` URL of the automation channel for an `http(s)://` server base.
+pub fn automation_channel_url(base_url: &str) -> String {
+    let base = base_url.trim_end_matches('/');
+    let base = if let Some(rest) = base.strip_prefix("https://") {
+        format!("wss://{rest}")
+    } else if let Some(rest) = base.strip_prefix("http://") {
+        format!("ws://{rest}")
+    } else {
+        base.to_string()
+    };
+    format!("{base}{AUTOMATION_CHANNEL_PATH}")
+}
+
+/// Outbound frames kept while disconnected before the oldest are dropped.
+pub const DEFAULT_OUTBOUND_CAPACITY: usize = 256;
+
+/// Connection state of a [`ReconnectingWsClient`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WsState {
+    /// First dial in progress.
+    Connecting,
+    Connected,
+    /// Waiting to re-dial; `attempt` counts consecutive failed or dropped
+    /// connections.
+    Reconnecting {
+        attempt: u32,
+    },
+    /// `run` has returned.
+    Closed,
+}
+
+/// Why a connection ended.
+#[derive(Debug, PartialEq, Eq)]
+enum SessionEnd {
+    Shutdown,
+    Disconnected,
+}
+
+/// Text-frame WebSocket connection that re-dials with backoff until shut
+/// down. Subscription frames are replayed on every connect, and frames sent
+/// while disconnected are buffered (oldest dropped past the capacity) and
+/// flushed once the socket is back.
+pub struct ReconnectingWsClient {
+    url: String,
+    token_manager: Option<Arc<TokenManager>>,
+    reconnect_policy: SseReconnectPolicy,
+    subscriptions: Vec<String>,
+    outbound: Mutex<VecDeque<String>>,
+    outbound_capacity: usize,
+    outbound_ready: Notify,
+    dropped: AtomicU64,
+    state_tx: watch::Sender<WsState>,
+}
+
+impl ReconnectingWsClient {
+    pub fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            token_manager: None,
+            reconnect_policy: SseReconnectPolicy::with_max_secs(30),
+            subscriptions: Vec::new(),
+            outbound: Mutex::new(VecDeque::new()),
+            outbound_capacity: DEFAULT_OUTBOUND_CAPACITY,
+            outbound_ready: Notify::new(),
+            dropped: AtomicU64::new(0),
+            state_tx: watch::channel(WsState::Connecting).0,
+        }
+    }
+
+    /// Send `Authorization: Bearer` on every (re)connect, fetching a fresh
+    /// token each time.
+    pub fn with_token_manager(mut self, token_manager: Arc<TokenManager>) -> Self {
+        self.token_manager = Some(token_manager);
+        self
+    }
+
+    pub fn with_reconnect_policy(mut self, policy: SseReconnectPolicy) -> Self {
+        self.reconnect_policy = policy;
+        self
+    }
+
+    /// Send `frame` first on every connection, ahead of buffered traffic.
+    pub fn with_subscription(mut self, frame: impl Into<String>) -> Self {
+        self.subscriptions.push(frame.into());
+        self
+    }
+
+    pub fn with_outbound_capacity(mut self, capacity: usize) -> Self {
+        self.outbound_capacity = capacity.max(1);
+        self
+    }
+
+    pub fn state(&self) -> WsState {
+        *self.state_tx.borrow()
+    }
+
+    pub fn subscribe_state(&self) -> watch::Receiver<WsState> {
+        self.state_tx.subscribe()
+    }
+
+    /// Outbound frames dropped because the buffer was full.
+    pub fn dropped_count(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    /// Check that the URL parses as a WebSocket request, so callers can
+    /// report a bad URL under their own config field before [`Self::run`]
+    /// starts retrying it.
+    pub fn validate_url(&self) -> Result<(), CoreError> {
+        self.client_request().map(drop)
+    }
+
+    /// Queue a text frame. It is written as soon as the socket is connected.
+    pub fn send(&self, frame: impl Into<String>) {
+        {
+            let mut outbound = self.outbound.lock();
+            if outbound.len() >= self.outbound_capacity {
+                outbound.pop_front();
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+                warn!(
+                    capacity = self.outbound_capacity,
+                    "websocket outbound buffer full, dropped oldest frame"
+                );
+            }
+            outbound.push_back(frame.into());
+        }
+        self.outbound_ready.notify_one();
+    }
+
+    /// Connect, forward received text frames to `inbound`, and reconnect with
+    /// backoff until `shutdown_rx` flips to `true` or `inbound` is closed.
+    pub async fn run(&self, inbound: mpsc::Sender<String>, mut shutdown_rx: watch::Receiver<bool>) {
+        let mut backoff = ReconnectBackoff::new(self.reconnect_policy.clone());
+        let mut attempt = 0;
+        loop {
+            if *shutdown_rx.borrow() {
+                break;
+            }
+            match self.connect().await {
+                Ok(stream) => {
+                    info!(url = %self.url, "websocket connected");
+                    attempt = 0;
+                    self.state_tx.send_replace(WsState::Connected);
+                    let connected_at = Instant::now();
+                    if self.serve(stream, &inbound, &mut shutdown_rx).await == SessionEnd::Shutdown
+                    {
+                        break;
+                    }
+                    backoff.on_stream_closed(connected_at.elapsed());
+                }
+                Err(error) => {
+                    warn!(url = %self.url, error = %error, "websocket connect failed");
+                }
+            }
+
+            attempt += 1;
+            self.state_tx
+                .send_replace(WsState::Reconnecting { attempt });
+            let delay = backoff.next_delay();
+            debug!(
+                delay_ms = delay.as_millis() as u64,
+                "websocket reconnecting"
+            );
+            tokio::select! {
+                _ = tokio::time::sleep(delay) => {}
+                _ = shutdown_rx.changed() => break,
+            }
+        }
+        self.state_tx.send_replace(WsState::Closed);
+    }
+
+    async fn connect(
+        &self,
+    ) -> Result<
+        tokio_tungstenite::WebSocketStream<
+            tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+        >,
+        CoreError,
+    > {
+        let mut request = self.client_request()?;
+        if let Some(token_manager) = &self.token_manager {
+            let token = token_manager.get_token().await?;
+            let value = HeaderValue::from_str(&format!("Bearer {token}")).map_err(|err| {
+                CoreError::Internal {
+                    code: oneshim_core::error_codes::InternalCode::Generic,
+                    message: format!("invalid authorization header: {err}"),
+                }
+            })?;
+            request.headers_mut().insert("Authorization", value);
+        }
+
+        let (stream, _) = tokio_tungstenite::connect_async(request)
+            .await
+            .map_err(|err| CoreError::Network {
+                code: oneshim_core::error_codes::NetworkCode::Generic,
+                message: format!("websocket connect failed: {err}"),
+            })?;
+        Ok(stream)
+    }
+
+    fn client_request(
+        &self,
+    ) -> Result<tokio_tungstenite::tungstenite::handshake::client::Request, CoreError> {
+        self.url
+            .as_str()
+            .into_client_request()
+            .map_err(|err| CoreError::Validation {
+                code: oneshim_core::error_codes::ValidationCode::InvalidField,
+                field: "url".to_string(),
+                message: format!("invalid websocket URL: {err}"),
+            })
+    }
+
+    async fn serve<S>(
+        &self,
+        stream: tokio_tungstenite::WebSocketStream<S>,
+        inbound: &mpsc::Sender<String>,
+        shutdown_rx: &mut watch::Receiver<bool>,
+    ) -> SessionEnd
+    where
+        S: tokio::io::AsyncRead + tokio::io::AsyncWrite + Unpin,
+    {
+        let (mut writer, mut reader) = stream.split();
+        for frame in &self.subscriptions {
+            if let Err(err) = writer.send(Message::Text(frame.clone().into())).await {
+                warn!("websocket subscribe failed: {err}");
+                return SessionEnd::Disconnected;
+            }
+        }
+
+        loop {
+            // A frame whose write fails goes back to the front of the buffer
+            // for the next connection.
+            loop {
+                let Some(frame) = self.outbound.lock().pop_front() else {
+                    break;
+                };
+                if let Err(err) = writer.send(Message::Text(frame.clone().into())).await {
+                    warn!("websocket send failed: {err}");
+                    self.outbound.lock().push_front(frame);
+                    return SessionEnd::Disconnected;
+                }
+            }
+
+            tokio::select! {
+                message = reader.next() => match message {
+                    Some(Ok(Message::Text(text))) => {
+                        if inbound.send(text.as_str().to_owned()).await.is_err() {
+                            let _ = writer.send(Message::Close(None)).await;
+                            return SessionEnd::Shutdown;
+                        }
+                    }
+                    Some(Ok(Message::Close(_))) | None => return SessionEnd::Disconnected,
+                    Some(Ok(_)) => {}
+                    Some(Err(err)) => {
+                        warn!("websocket read failed: {err}");
+                        return SessionEnd::Disconnected;
+                    }
+                },
+                _ = self.outbound_ready.notified() => {}
+                _ = shutdown_rx.changed() => {
+                    let _ = writer.send(Message::Close(None)).await;
+                    return SessionEnd::Shutdown;
+                }
+            }
+        }
+    }
+}
+
+pub struct AutomationCommandChannel {
+    transport: ReconnectingWsClient,
+    executor: AutomationCommandExecutor,
+}
+
+impl AutomationCommandChannel {
+    pub fn new(url: &str, executor: AutomationCommandExecutor) -> Self {
+        Self {
+            transport: ReconnectingWsClient::new(url),
+            executor,
+        }
+    }
+
+    /// Send `Authorization: Bearer` on every (re)connect, fetching a fresh
+    /// token each time.
+    pub fn with_token_manager(mut self, token_manager: Arc<TokenManager>) -> Self {
+        self.transport = self.transport.with_token_manager(token_manager);
+        self
+    }
+
+    pub fn with_reconnect_policy(mut self, policy: SseReconnectPolicy) -> Self {
+        self.transport = self.transport.with_reconnect_policy(policy);
+        self
+    }
+
+    pub fn subscribe_state(&self) -> watch::Receiver<WsState> {
+        self.transport.subscribe_state()
+    }
+
+    /// See [`ReconnectingWsClient::validate_url`].
+    pub fn validate_url(&self) -> Result<(), CoreError> {
+        self.transport.validate_url()
+    }
+
+    /// Connect, serve commands, and reconnect with backoff until `shutdown_rx`
+    /// flips to `true`. Commands run concurrently; a result that completes
+    /// while the socket is down is buffered and sent after the reconnect.
+    pub async fn run(&self, shutdown_rx: watch::Receiver<bool>) {
+        let (inbound_tx, mut inbound_rx) = mpsc::channel::<String>(32);
+        let (result_tx, mut result_rx) = mpsc::channel::<AutomationChannelFrame>(32);
+        let transport = self.transport.run(inbound_tx, shutdown_rx);
+        tokio::pin!(transport);
+
+        loop {
+            tokio::select! {
+                _ = &mut transport => return,
+                Some(text) = inbound_rx.recv() => self.dispatch(&text, &result_tx),
+                Some(frame) = result_rx.recv() => match serde_json::to_string(&frame) {
+                    Ok(text) => self.transport.send(text),
+                    Err(err) => warn!("automation result serialization failed: {err}"),
+                },
+            }
+        }
+    }
+
+    fn dispatch(&self, text: &str, result_tx: &mpsc::Sender<AutomationChannelFrame>) {
+        let command = match serde_json::from_str::<AutomationChannelFrame>(text) {
+            Ok(AutomationChannelFrame::Command { command }) => command,
+            Ok(AutomationChannelFrame::Result { .. }) => {
+                debug!("ignoring result frame sent by server");
+                return;
+            }
+            Err(err) => {
+                warn!("automation channel frame parse failed: {err}");
+                return;
+            }
+        };
+
+        let executor = self.executor.clone();
+        let result_tx = result_tx.clone();
+        tokio::spawn(async move {
+            let command_id = command.command_id.clone();
+            let result = executor(command)
+                .await
+                .unwrap_or_else(|err| CommandResult::Failed(err.to_string()));
+            let _ = result_tx
+                .send(AutomationChannelFrame::Result { command_id, result })
+                .await;
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oneshim_core::models::automation::AutomationAction;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::accept_async;
+
+    fn command(id: &str) -> AutomationCommand {
+        AutomationCommand {
+            command_id: id.to_string(),
+            session_id: "sess-1".to_string(),
+            action: AutomationAction::MouseMove { x: 10, y: 20 },
+            timeout_ms: None,
+            policy_token: "pol-1:nonce_1234".to_string(),
+        }
+    }
+
+    fn fast_policy() -> SseReconnectPolicy {
+        SseReconnectPolicy {
+            min_delay: Duration::from_millis(10),
+            max_delay: Duration::from_millis(50),
+            jitter_ratio: 0.0,
+            stable_after: Duration::from_secs(60),
+        }
+    }
+
+    fn counting_executor(calls: Arc<AtomicUsize>) -> AutomationCommandExecutor {
+        Arc::new(move |_command| {
+            calls.fetch_add(1, Ordering::SeqCst);
+            Box::pin(async { Ok(CommandResult::Success) })
+        })
+    }
+
+    async fn send_command(
+        socket: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+        id: &str,
+    ) {
+        let frame = AutomationChannelFrame::Command {
+            command: command(id),
+        };
+        socket
+            .send(Message::Text(serde_json::to_string(&frame).unwrap().into()))
+            .await
+            .unwrap();
+    }
+
+    async fn next_result(
+        socket: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+    ) -> (String, CommandResult) {
+        loop {
+            let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+                .await
+                .expect("result frame within 5s")
+                .expect("socket open")
+                .unwrap();
+            if let Message::Text(text) = message {
+                match serde_json::from_str::<AutomationChannelFrame>(&text).unwrap() {
+                    AutomationChannelFrame::Result { command_id, result } => {
+                        return (command_id, result)
+                    }
+                    other => panic!("unexpected frame: {other:?}"),
+                }
+            }
+        }
+    }
+
+    async fn next_text(
+        socket: &mut tokio_tungstenite::WebSocketStream<tokio::net::TcpStream>,
+    ) -> String {
+        loop {
+            let message = tokio::time::timeout(Duration::from_secs(5), socket.next())
+                .await
+                .expect("frame within 5s")
+                .expect("socket open")
+                .unwrap();
+            if let Message::Text(text) = message {
+                return text.as_str().to_owned();
+            }
+        }
+    }
+
+    #[test]
+    fn outbound_buffer_drops_oldest_past_capacity() {
+        let client = ReconnectingWsClient::new("ws://127.0.0.1:1").with_outbound_capacity(2);
+        client.send("a");
+        client.send("b");
+        client.send("c");
+        assert_eq!(client.dropped_count(), 1);
+        assert_eq!(*client.outbound.lock(), ["b", "c"]);
+        assert_eq!(client.state(), WsState::Connecting);
+    }
+
+    #[tokio::test]
+    async fn recovers_after_server_dies_and_flushes_buffered_frames() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let client = Arc::new(
+            ReconnectingWsClient::new(&format!("ws://{addr}"))
+                .with_reconnect_policy(fast_policy())
+                .with_subscription("subscribe"),
+        );
+        let mut state_rx = client.subscribe_state();
+        let (inbound_tx, mut inbound_rx) = mpsc::channel(8);
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let task = tokio::spawn({
+            let client = client.clone();
+            async move { client.run(inbound_tx, shutdown_rx).await }
+        });
+
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut server = accept_async(tcp).await.unwrap();
+        assert_eq!(next_text(&mut server).await, "subscribe");
+        server.send(Message::Text("one".into())).await.unwrap();
+        assert_eq!(inbound_rx.recv().await.as_deref(), Some("one"));
+
+        // Kill the server without a close handshake.
+        drop(server);
+        drop(listener);
+        tokio::time::timeout(
+            Duration::from_secs(5),
+            state_rx.wait_for(|state| matches!(state, WsState::Reconnecting { .. })),
+        )
+        .await
+        .expect("client notices the dropped connection")
+        .unwrap();
+        client.send("while-down");
+
+        let listener = TcpListener::bind(addr).await.unwrap();
+        let (tcp, _) = tokio::time::timeout(Duration::from_secs(5), listener.accept())
+            .await
+            .expect("client re-dials")
+            .unwrap();
+        let mut server = accept_async(tcp).await.unwrap();
+        assert_eq!(next_text(&mut server).await, "subscribe");
+        assert_eq!(next_text(&mut server).await, "while-down");
+        server.send(Message::Text("two".into())).await.unwrap();
+        assert_eq!(inbound_rx.recv().await.as_deref(), Some("two"));
+        assert_eq!(client.state(), WsState::Connected);
+
+        shutdown_tx.send(true).unwrap();
+        task.await.unwrap();
+        assert_eq!(client.state(), WsState::Closed);
+    }
+
+    #[test]
+    fn channel_url_swaps_scheme() {
+        assert_eq!(
+            automation_channel_url("https://api.example.com/"),
+            "wss://api.example.com/api/v1/automation/ws"
+        );
+        assert_eq!(
+            automation_channel_url("http://127.0.0.1:8000"),
+            "ws://127.0.0.1:8000/api/v1/automation/ws"
+        );
+    }
+
+    #[test]
+    fn unparseable_url_is_reported_without_a_config_path() {
+        assert!(ReconnectingWsClient::new("ws://127.0.0.1:9/ws")
+            .validate_url()
+            .is_ok());
+        let err = ReconnectingWsClient::new("not a url")
+            .validate_url()
+            .unwrap_err();
+        assert!(
+            matches!(&err, CoreError::Validation { field, .. } if field == "url"),
+            "unexpected error: {err:?}"
+        );
+    }
+
+    #[test]
+    fn frames_are_tagged_json() {
+        let frame = AutomationChannelFrame::Result {
+            command_id: "cmd-1".to_string(),
+            result: CommandResult::Denied,
+        };
+        let json = serde_json::to_value(&frame).unwrap();
+        assert_eq!(json["type"], "result");
+        assert_eq!(json["command_id"], "cmd-1");
+    }
+
+    #[tokio::test]
+    async fn command_from_server_gets_result_frame() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let channel = AutomationCommandChannel::new(&url, counting_executor(calls.clone()))
+            .with_reconnect_policy(fast_policy());
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let client = tokio::spawn(async move { channel.run(shutdown_rx).await });
+
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut socket = accept_async(tcp).await.unwrap();
+        send_command(&mut socket, "cmd-42").await;
+
+        let (command_id, result) = next_result(&mut socket).await;
+        assert_eq!(command_id, "cmd-42");
+        assert!(matches!(result, CommandResult::Success));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        shutdown_tx.send(true).unwrap();
+        tokio::time::timeout(Duration::from_secs(5), client)
+            .await
+            .expect("channel stops on shutdown")
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn executor_error_is_reported_as_failed() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let executor: AutomationCommandExecutor = Arc::new(|_command| {
+            Box::pin(async {
+                Err(CoreError::PolicyDenied {
+                    code: oneshim_core::error_codes::PolicyCode::Denied,
+                    message: "no policy".to_string(),
+                })
+            })
+        });
+        let channel = AutomationCommandChannel::new(&url, executor);
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let client = tokio::spawn(async move { channel.run(shutdown_rx).await });
+
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut socket = accept_async(tcp).await.unwrap();
+        send_command(&mut socket, "cmd-denied").await;
+
+        let (command_id, result) = next_result(&mut socket).await;
+        assert_eq!(command_id, "cmd-denied");
+        assert!(matches!(result, CommandResult::Failed(message) if message.contains("no policy")));
+
+        shutdown_tx.send(true).unwrap();
+        client.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn reconnects_after_server_drops_connection() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let calls = Arc::new(AtomicUsize::new(0));
+        let channel = AutomationCommandChannel::new(&url, counting_executor(calls.clone()))
+            .with_reconnect_policy(fast_policy());
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let client = tokio::spawn(async move { channel.run(shutdown_rx).await });
+
+        let (tcp, _) = listener.accept().await.unwrap();
+        let mut first = accept_async(tcp).await.unwrap();
+        first.close(None).await.unwrap();
+        drop(first);
+
+        let (tcp, _) = tokio::time::timeout(Duration::from_secs(5), listener.accept())
+            .await
+            .expect("client reconnects")
+            .unwrap();
+        let mut second = accept_async(tcp).await.unwrap();
+        send_command(&mut second, "cmd-after-reconnect").await;
+
+        let (command_id, _) = next_result(&mut second).await;
+        assert_eq!(command_id, "cmd-after-reconnect");
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+        shutdown_tx.send(true).unwrap();
+        client.await.unwrap();
+    }
+}
@@ -26,6 +26,7 @@ fn create_test_context_event(i: usize) -> Event {
         prev_app_name: Some(format!("PrevApp{}", (i + 1) % 5)),
         timestamp: chrono::Utc::now(),
         input_activity_level: 0.0,
+        repeat_count: 0,
     })
 }
 
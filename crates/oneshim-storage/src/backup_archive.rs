@@ -0,0 +1,451 @@
+//! Whole-installation backup: a database snapshot plus every frame file in
+//! one `.tar.gz`, for carrying history over to a new machine.
+//!
+//! The archive holds `manifest.json`, `oneshim.db` (taken through SQLite's
+//! online backup API, so the live database is only read) and `frames.zip`.
+//! [`restore_backup`] validates the manifest and the snapshot before it
+//! touches anything at the destination.
+
+use std::fs;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+
+use chrono::Utc;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::encryption::EncryptionKey;
+use crate::error::StorageError;
+use crate::frame_storage::FrameFileStorage;
+use crate::migration::{current_version, CURRENT_VERSION};
+use crate::sqlite::{apply_key_pragma, SqliteStorage};
+
+const MANIFEST_ENTRY: &str = "manifest.json";
+const DATABASE_ENTRY: &str = "oneshim.db";
+const FRAMES_ENTRY: &str = "frames.zip";
+/// Bumped when the archive layout changes.
+const FORMAT_VERSION: u32 = 1;
+const PARTIAL_SUFFIX: &str = ".partial";
+const PRE_RESTORE_SUFFIX: &str = ".pre-restore";
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub format_version: u32,
+    /// Schema version of the database snapshot.
+    pub schema_version: u32,
+    pub created_at: String,
+    pub frame_files: u64,
+}
+
+/// Write a backup of `db` and `frames` to `out`, replacing any existing
+/// file. The archive is assembled next to `out` and renamed into place.
+/// Blocking.
+pub fn create_backup(
+    db: &SqliteStorage,
+    frames: &FrameFileStorage,
+    out: &Path,
+) -> Result<BackupManifest, StorageError> {
+    let staging = staging_dir(out)?;
+    let db_snapshot = staging.path().join(DATABASE_ENTRY);
+    db.backup_to(&db_snapshot)?;
+    let frames_zip = staging.path().join(FRAMES_ENTRY);
+    let frame_files = frames.archive_to(&frames_zip)?;
+
+    let manifest = BackupManifest {
+        format_version: FORMAT_VERSION,
+        schema_version: db.schema_version()?,
+        created_at: Utc::now().to_rfc3339(),
+        frame_files,
+    };
+    let manifest_json = serde_json::to_vec_pretty(&manifest)
+        .map_err(|e| StorageError::Serialization(format!("backup manifest: {e}")))?;
+
+    let partial = with_suffix(out, PARTIAL_SUFFIX);
+    {
+        let file = fs::File::create(&partial)?;
+        let mut tar = tar::Builder::new(GzEncoder::new(BufWriter::new(file), Compression::fast()));
+        let mut header = tar::Header::new_gnu();
+        header.set_size(manifest_json.len() as u64);
+        header.set_mode(0o644);
+        header.set_mtime(Utc::now().timestamp() as u64);
+        tar.append_data(&mut header, MANIFEST_ENTRY, manifest_json.as_slice())?;
+        tar.append_path_with_name(&db_snapshot, DATABASE_ENTRY)?;
+        tar.append_path_with_name(&frames_zip, FRAMES_ENTRY)?;
+        tar.into_inner()?
+            .finish()?
+            .into_inner()
+            .map_err(|e| e.into_error())?
+            .sync_all()?;
+    }
+    fs::rename(&partial, out)?;
+
+    info!(
+        path = %out.display(),
+        schema_version = manifest.schema_version,
+        frame_files,
+        "full backup written"
+    );
+    Ok(manifest)
+}
+
+/// Restore a [`create_backup`] archive over `db_path` and `frames_dir`.
+///
+/// Nothing at the destination changes until the manifest, the snapshot's
+/// schema version and a `quick_check` pass. The database at `db_path` must
+/// not be open. `encryption_key` is the key the backed-up database was
+/// encrypted with. Blocking.
+pub fn restore_backup(
+    archive: &Path,
+    db_path: &Path,
+    frames_dir: &Path,
+    encryption_key: Option<&EncryptionKey>,
+) -> Result<BackupManifest, StorageError> {
+    let staging = staging_dir(db_path)?;
+    unpack_known_entries(archive, staging.path())?;
+
+    let manifest_bytes = fs::read(staging.path().join(MANIFEST_ENTRY))
+        .map_err(|_| invalid("archive has no manifest"))?;
+    let manifest: BackupManifest = serde_json::from_slice(&manifest_bytes)
+        .map_err(|e| invalid(format!("manifest is unreadable: {e}")))?;
+    if manifest.format_version != FORMAT_VERSION {
+        return Err(invalid(format!(
+            "unsupported archive format {} (expected {FORMAT_VERSION})",
+            manifest.format_version
+        )));
+    }
+    if manifest.schema_version > CURRENT_VERSION {
+        return Err(invalid(format!(
+            "backup schema version {} is newer than this client supports ({CURRENT_VERSION})",
+            manifest.schema_version
+        )));
+    }
+
+    let staged_db = staging.path().join(DATABASE_ENTRY);
+    let snapshot_version = verify_snapshot(&staged_db, encryption_key)?;
+    if snapshot_version != manifest.schema_version {
+        return Err(invalid(format!(
+            "snapshot schema version {snapshot_version} does not match manifest ({})",
+            manifest.schema_version
+        )));
+    }
+
+    let frames_staging = staging_dir(frames_dir)?;
+    let staged_frames = frames_staging.path().join("frames");
+    extract_frames(&staging.path().join(FRAMES_ENTRY), &staged_frames)?;
+
+    let old_frames = swap_in_dir(&staged_frames, frames_dir)?;
+    if let Err(e) = replace_database(&staged_db, db_path) {
+        // Put the old frames back so they stay paired with the old database.
+        if let Some(old_frames) = &old_frames {
+            let _ = fs::remove_dir_all(frames_dir);
+            let _ = fs::rename(old_frames, frames_dir);
+        }
+        return Err(e);
+    }
+    if let Some(old_frames) = old_frames {
+        if let Err(e) = fs::remove_dir_all(&old_frames) {
+            warn!(path = %old_frames.display(), "failed to delete replaced frames: {e}");
+        }
+    }
+
+    info!(
+        db = %db_path.display(),
+        schema_version = manifest.schema_version,
+        frame_files = manifest.frame_files,
+        "full backup restored"
+    );
+    Ok(manifest)
+}
+
+fn invalid(message: impl Into<String>) -> StorageError {
+    StorageError::Validation {
+        field: "backup".to_string(),
+        message: message.into(),
+    }
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Temp directory beside `path`, so staged files can be renamed into place.
+fn staging_dir(path: &Path) -> Result<tempfile::TempDir, StorageError> {
+    let parent = path
+        .parent()
+        .filter(|parent| !parent.as_os_str().is_empty())
+        .unwrap_or(Path::new("."));
+    fs::create_dir_all(parent)?;
+    Ok(tempfile::Builder::new()
+        .prefix(".oneshim-backup-")
+        .tempdir_in(parent)?)
+}
+
+/// Unpack only the entries this format defines; entries under any other
+/// name are skipped. A defined name that is not a regular file (a symlink
+/// or hardlink crafted to redirect the restore) fails the whole restore.
+fn unpack_known_entries(archive: &Path, dest: &Path) -> Result<(), StorageError> {
+    let file = fs::File::open(archive)?;
+    let mut tar = tar::Archive::new(GzDecoder::new(BufReader::new(file)));
+    for entry in tar.entries()? {
+        let mut entry = entry?;
+        let name = entry.path()?.to_string_lossy().into_owned();
+        if !matches!(
+            name.as_str(),
+            MANIFEST_ENTRY | DATABASE_ENTRY | FRAMES_ENTRY
+        ) {
+            continue;
+        }
+        if !entry.header().entry_type().is_file() {
+            return Err(invalid(format!(
+                "archive entry {name} is not a regular file"
+            )));
+        }
+        entry.unpack(dest.join(&name))?;
+    }
+    Ok(())
+}
+
+/// Open the staged snapshot, run `quick_check` and return its schema version.
+fn verify_snapshot(
+    path: &Path,
+    encryption_key: Option<&EncryptionKey>,
+) -> Result<u32, StorageError> {
+    if !path.exists() {
+        return Err(invalid("archive has no database snapshot"));
+    }
+    let conn = Connection::open(path)?;
+    if let Some(key) = encryption_key.filter(|_| cfg!(feature = "encryption")) {
+        apply_key_pragma(&conn, key)?;
+    }
+    let check: String = conn
+        .query_row("PRAGMA quick_check", [], |row| row.get(0))
+        .map_err(|e| invalid(format!("database snapshot is unreadable (wrong key?): {e}")))?;
+    if check != "ok" {
+        return Err(invalid(format!(
+            "database snapshot failed quick_check: {check}"
+        )));
+    }
+    Ok(current_version(&conn)?)
+}
+
+fn extract_frames(zip_path: &Path, dest: &Path) -> Result<(), StorageError> {
+    let file = fs::File::open(zip_path).map_err(|_| invalid("archive has no frame archive"))?;
+    let mut zip = zip::ZipArchive::new(BufReader::new(file))
+        .map_err(|e| invalid(format!("frame archive is unreadable: {e}")))?;
+    fs::create_dir_all(dest)?;
+    for i in 0..zip.len() {
+        let mut entry = zip
+            .by_index(i)
+            .map_err(|e| invalid(format!("frame archive entry {i}: {e}")))?;
+        if entry.is_dir() {
+            continue;
+        }
+        // enclosed_name rejects absolute paths and `..` components.
+        let Some(relative) = entry.enclosed_name() else {
+            warn!(
+                name = entry.name(),
+                "skipping frame archive entry outside frames/"
+            );
+            continue;
+        };
+        let target = dest.join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        std::io::copy(&mut entry, &mut fs::File::create(&target)?)?;
+    }
+    Ok(())
+}
+
+/// Move `staged` to `dest`, returning where the previous `dest` was moved.
+fn swap_in_dir(staged: &Path, dest: &Path) -> Result<Option<PathBuf>, StorageError> {
+    let aside = with_suffix(dest, PRE_RESTORE_SUFFIX);
+    let old = if dest.exists() {
+        if aside.exists() {
+            fs::remove_dir_all(&aside)?;
+        }
+        fs::rename(dest, &aside)?;
+        Some(aside)
+    } else {
+        None
+    };
+    if let Err(e) = fs::rename(staged, dest) {
+        if let Some(old) = &old {
+            let _ = fs::rename(old, dest);
+        }
+        return Err(e.into());
+    }
+    Ok(old)
+}
+
+/// Replace the database file. Existing `-wal`/`-shm` sidecars belong to the
+/// old database and would be replayed into the restored one, so they are
+/// moved aside with it and deleted once the snapshot is in place.
+fn replace_database(staged: &Path, db_path: &Path) -> Result<(), StorageError> {
+    let mut moved = Vec::new();
+    let result =
+        move_database_aside(db_path, &mut moved).and_then(|()| fs::rename(staged, db_path));
+    if let Err(e) = result {
+        for (current, aside) in moved {
+            let _ = fs::rename(aside, current);
+        }
+        return Err(e.into());
+    }
+    for (_, aside) in moved {
+        if let Err(e) = fs::remove_file(&aside) {
+            warn!(path = %aside.display(), "failed to delete replaced database file: {e}");
+        }
+    }
+    Ok(())
+}
+
+fn move_database_aside(db_path: &Path, moved: &mut Vec<(PathBuf, PathBuf)>) -> std::io::Result<()> {
+    for suffix in ["", "-wal", "-shm"] {
+        let current = with_suffix(db_path, suffix);
+        if current.exists() {
+            let aside = with_suffix(db_path, &format!("{PRE_RESTORE_SUFFIX}{suffix}"));
+            fs::rename(&current, &aside)?;
+            moved.push((current, aside));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn populated(dir: &Path, tag: &str) -> (SqliteStorage, FrameFileStorage) {
+        let db = SqliteStorage::open(&dir.join("oneshim.db"), 30, None).unwrap();
+        db.create_tag(tag, "#3b82f6").unwrap();
+        let frames = FrameFileStorage::new(dir.to_path_buf(), 100, 30)
+            .await
+            .unwrap();
+        (db, frames)
+    }
+
+    fn tag_names(db: &SqliteStorage) -> Vec<String> {
+        db.get_all_tags()
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn backup_restores_database_and_frames_over_another_install() {
+        let source = tempfile::tempdir().unwrap();
+        let (db, frames) = populated(source.path(), "Laptop history").await;
+        let frame = frames.save_frame(Utc::now(), b"frame-bytes").await.unwrap();
+        let archive = source.path().join("oneshim-backup.tar.gz");
+
+        let manifest = create_backup(&db, &frames, &archive).unwrap();
+        assert_eq!(manifest.frame_files, 1);
+        assert_eq!(manifest.schema_version, CURRENT_VERSION);
+        // The live database is untouched and still writable.
+        assert!(db.integrity_check().unwrap().is_healthy());
+        db.create_tag("After backup", "#000000").unwrap();
+
+        let target = tempfile::tempdir().unwrap();
+        let (fresh_db, fresh_frames) = populated(target.path(), "Fresh install").await;
+        fresh_frames
+            .save_frame(Utc::now(), b"fresh-frame")
+            .await
+            .unwrap();
+        drop((fresh_db, fresh_frames));
+
+        let db_path = target.path().join("oneshim.db");
+        let frames_dir = target.path().join("frames");
+        assert_eq!(
+            restore_backup(&archive, &db_path, &frames_dir, None).unwrap(),
+            manifest
+        );
+
+        let restored = SqliteStorage::open(&db_path, 30, None).unwrap();
+        assert_eq!(tag_names(&restored), ["Laptop history"]);
+        assert_eq!(
+            fs::read(target.path().join(&frame)).unwrap(),
+            b"frame-bytes"
+        );
+        let leftovers: Vec<PathBuf> = fs::read_dir(target.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .filter(|path| {
+                let name = path.file_name().unwrap().to_string_lossy();
+                name.contains(PRE_RESTORE_SUFFIX) || name.starts_with(".oneshim-backup-")
+            })
+            .collect();
+        assert!(leftovers.is_empty(), "{leftovers:?}");
+    }
+
+    #[tokio::test]
+    async fn newer_schema_backup_is_refused_without_touching_the_destination() {
+        let source = tempfile::tempdir().unwrap();
+        let (db, frames) = populated(source.path(), "From the future").await;
+        db.connection_pool()
+            .get()
+            .unwrap()
+            .execute(
+                "INSERT INTO schema_version (version) VALUES (?1)",
+                [CURRENT_VERSION + 1],
+            )
+            .unwrap();
+        let archive = source.path().join("oneshim-backup.tar.gz");
+        create_backup(&db, &frames, &archive).unwrap();
+
+        let target = tempfile::tempdir().unwrap();
+        let (existing, _frames) = populated(target.path(), "Current").await;
+        drop(existing);
+
+        let db_path = target.path().join("oneshim.db");
+        let err =
+            restore_backup(&archive, &db_path, &target.path().join("frames"), None).unwrap_err();
+        assert!(
+            matches!(err, StorageError::Validation { .. }),
+            "unexpected error: {err}"
+        );
+        let existing = SqliteStorage::open(&db_path, 30, None).unwrap();
+        assert_eq!(tag_names(&existing), ["Current"]);
+    }
+
+    #[tokio::test]
+    async fn symlinked_database_entry_is_refused() {
+        let source = tempfile::tempdir().unwrap();
+        let outside = source.path().join("outside.db");
+        fs::write(&outside, b"not a backup").unwrap();
+        let archive = source.path().join("crafted.tar.gz");
+        {
+            let gz = GzEncoder::new(fs::File::create(&archive).unwrap(), Compression::fast());
+            let mut builder = tar::Builder::new(gz);
+            let mut header = tar::Header::new_gnu();
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_size(0);
+            header.set_mode(0o644);
+            builder
+                .append_link(&mut header, DATABASE_ENTRY, &outside)
+                .unwrap();
+            builder.into_inner().unwrap().finish().unwrap();
+        }
+
+        let target = tempfile::tempdir().unwrap();
+        let (existing, _frames) = populated(target.path(), "Current").await;
+        drop(existing);
+
+        let db_path = target.path().join("oneshim.db");
+        let err =
+            restore_backup(&archive, &db_path, &target.path().join("frames"), None).unwrap_err();
+        assert!(
+            matches!(&err, StorageError::Validation { message, .. } if message.contains("not a regular file")),
+            "unexpected error: {err}"
+        );
+        let existing = SqliteStorage::open(&db_path, 30, None).unwrap();
+        assert_eq!(tag_names(&existing), ["Current"]);
+        assert_eq!(fs::read(&outside).unwrap(), b"not a backup");
+    }
+}
@@ -3,14 +3,24 @@
 //! # 키 저장 전략
 //! 1. 키 파일 (`app_data_dir/.db_key`): 32바이트 원시 키
 //! 2. 파일 권한: Unix에서 0o600 (소유자만 읽기/쓰기)
+//! 3. OS 키체인 (`encryption` feature): 랜덤 시크릿만 키체인에 저장하고
+//!    키는 Argon2id로 유도 — 디스크에 키가 남지 않음
 //!
-//! # 향후 작업
-//! SQLCipher 또는 at-rest 암호화 레이어와 연동 예정.
-//! 현재는 키 생성/저장/로드 인프라만 제공.
+//! 키는 `SqliteStorage::open`에서 SQLCipher `PRAGMA key`로 적용되고,
+//! 프레임 파일 AES-256-GCM 암호화에도 사용된다.
 
 use crate::error::StorageError;
 use std::path::{Path, PathBuf};
 
+/// DB 시크릿을 보관하는 키체인 서비스 / 계정 (`KeychainOps`와 같은 서비스)
+#[cfg(feature = "encryption")]
+const KEYRING_SERVICE: &str = "oneshim";
+#[cfg(feature = "encryption")]
+const KEYRING_DB_SECRET_USER: &str = "database.sqlcipher_secret";
+/// 고정 salt — 시크릿 자체가 32바이트 랜덤이므로 충분
+#[cfg(feature = "encryption")]
+const KEYRING_KDF_SALT: &[u8] = b"oneshim.sqlcipher.v1";
+
 /// 32바이트 AES-256 데이터베이스 암호화 키
 #[derive(Clone)]
 pub struct EncryptionKey([u8; 32]);
@@ -21,18 +31,58 @@ impl EncryptionKey {
     /// - 파일 존재 시: 로드 (32바이트 검증)
     /// - 파일 없을 시: 생성 후 파일에 저장 (Unix: 0o600 권한)
     pub fn load_or_create(app_data_dir: &Path) -> Result<Self, StorageError> {
-        let key_path = app_data_dir.join(".db_key");
+        Self::load_or_create_at(&app_data_dir.join(".db_key"))
+    }
 
+    /// `load_or_create`와 동일하나 키 파일 경로를 직접 지정
+    pub fn load_or_create_at(key_path: &Path) -> Result<Self, StorageError> {
+        let key_path = key_path.to_path_buf();
         if key_path.exists() {
             return Self::load_from_file(&key_path);
         }
 
+        if let Some(parent) = key_path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
         let key = Self::generate()?;
         key.save_to_file(&key_path)?;
-        tracing::info!("New DB encryption key generated: {:?}", key_path);
+        tracing::info!("New encryption key generated: {:?}", key_path);
         Ok(key)
     }
 
+    /// OS 키체인의 시크릿에서 키 유도
+    ///
+    /// - 시크릿 존재 시: 로드 후 유도
+    /// - 시크릿 없을 시: 32바이트 랜덤 시크릿을 생성해 키체인에 저장 후 유도
+    #[cfg(feature = "encryption")]
+    pub fn load_or_create_in_keyring() -> Result<Self, StorageError> {
+        let entry = keyring::Entry::new(KEYRING_SERVICE, KEYRING_DB_SECRET_USER)
+            .map_err(|e| StorageError::SecretStore(format!("keyring entry creation: {e}")))?;
+        let secret = match entry.get_password() {
+            Ok(secret) => secret,
+            Err(keyring::Error::NoEntry) => {
+                let secret = Self::generate()?.as_hex();
+                entry
+                    .set_password(&secret)
+                    .map_err(|e| StorageError::SecretStore(format!("keychain: {e}")))?;
+                tracing::info!("New database secret stored in OS keychain");
+                secret
+            }
+            Err(e) => return Err(StorageError::SecretStore(format!("keychain: {e}"))),
+        };
+        Self::derive_from_secret(secret.as_bytes())
+    }
+
+    /// 키체인 시크릿에서 Argon2id로 키 유도
+    #[cfg(feature = "encryption")]
+    fn derive_from_secret(secret: &[u8]) -> Result<Self, StorageError> {
+        let mut key = [0u8; 32];
+        argon2::Argon2::default()
+            .hash_password_into(secret, KEYRING_KDF_SALT, &mut key)
+            .map_err(|e| StorageError::Encryption(format!("Argon2 KDF failed: {e}")))?;
+        Ok(Self(key))
+    }
+
     /// 원시 바이트에서 키 생성
     pub fn from_bytes(bytes: [u8; 32]) -> Self {
         Self(bytes)
@@ -298,6 +348,16 @@ mod tests {
         assert_eq!(content.len(), 32);
     }
 
+    #[cfg(feature = "encryption")]
+    #[test]
+    fn keyring_secret_derivation_is_stable_per_secret() {
+        let a = EncryptionKey::derive_from_secret(b"secret-a").unwrap();
+        let again = EncryptionKey::derive_from_secret(b"secret-a").unwrap();
+        let b = EncryptionKey::derive_from_secret(b"secret-b").unwrap();
+        assert_eq!(a.as_hex(), again.as_hex());
+        assert_ne!(a.as_hex(), b.as_hex());
+    }
+
     #[test]
     fn debug_does_not_leak_key_bytes() {
         let key = EncryptionKey::from_bytes([0xAB; 32]);
@@ -10,6 +10,15 @@ pub enum StorageError {
     #[error("SQLite error: {0}")]
     Sqlite(#[from] rusqlite::Error),
 
+    #[error("database error: {0}")]
+    Database(String),
+
+    #[error("lock error: {0}")]
+    Lock(String),
+
+    #[error("serialization error: {0}")]
+    Serialization(String),
+
     #[error("I/O error: {0}")]
     Io(#[from] std::io::Error),
 
@@ -36,10 +45,27 @@ impl From<StorageError> for CoreError {
     fn from(err: StorageError) -> Self {
         match err {
             StorageError::Core(e) => e,
+            StorageError::Sqlite(rusqlite::Error::QueryReturnedNoRows) => CoreError::NotFound {
+                code: oneshim_core::error_codes::NotFoundCode::ResourceMissing,
+                resource_type: "row".to_string(),
+                id: String::new(),
+            },
             StorageError::Sqlite(e) => CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: e.to_string(),
             },
+            StorageError::Database(msg) => CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Database,
+                message: msg,
+            },
+            StorageError::Lock(msg) => CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Lock,
+                message: msg,
+            },
+            StorageError::Serialization(msg) => CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Serialization,
+                message: msg,
+            },
             StorageError::Io(e) => CoreError::Io(e),
             StorageError::SecretStore(msg) => CoreError::SecretStoreError {
                 code: oneshim_core::error_codes::SecretCode::Failed,
@@ -63,12 +89,9 @@ impl From<StorageError> for CoreError {
                 code: oneshim_core::error_codes::ConfigCode::Invalid,
                 message: msg,
             },
-            // StorageError::Internal is constructed almost exclusively at SQLite
-            // operation boundaries (400+ sites: query/execute/commit/prepare + KDF,
-            // AES, disk, lock poison). Wire code is therefore storage.failed —
-            // the observed failure IS a storage operation, even when the root
-            // cause is a panic (mutex poison) or a crypto library. Callers that
-            // need a different code should construct CoreError directly.
+            // SQLite, lock and serialization failures have their own variants;
+            // what is left here is crypto, disk and join failures around a
+            // storage operation, so the wire code stays storage.failed.
             StorageError::Internal(msg) => CoreError::Storage {
                 code: oneshim_core::error_codes::StorageCode::Failed,
                 message: msg,
@@ -76,3 +99,40 @@ impl From<StorageError> for CoreError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oneshim_core::error_codes::StorageCode;
+
+    #[test]
+    fn typed_variants_map_to_specific_storage_codes() {
+        let cases = [
+            (
+                StorageError::Database("prepare".into()),
+                StorageCode::Database,
+            ),
+            (StorageError::Lock("poisoned".into()), StorageCode::Lock),
+            (
+                StorageError::Serialization("bad json".into()),
+                StorageCode::Serialization,
+            ),
+            (StorageError::Internal("join".into()), StorageCode::Failed),
+        ];
+        for (err, expected) in cases {
+            match CoreError::from(err) {
+                CoreError::Storage { code, .. } => assert_eq!(code, expected),
+                other => panic!("expected CoreError::Storage, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn missing_row_maps_to_not_found() {
+        let err = CoreError::from(StorageError::Sqlite(rusqlite::Error::QueryReturnedNoRows));
+        assert!(matches!(err, CoreError::NotFound { .. }), "got {err:?}");
+
+        let err = CoreError::from(StorageError::Sqlite(rusqlite::Error::InvalidQuery));
+        assert_eq!(err.code(), "storage.database");
+    }
+}
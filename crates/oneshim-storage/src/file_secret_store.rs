@@ -1,12 +1,15 @@
 //! Explicit file-backed secret store.
 //!
 //! This backend is intended for headless, CI, or explicit fallback scenarios.
-//! It is not the desktop-default backend.
+//! It is not the desktop-default backend. [`FileSecretStore::encrypted`]
+//! keeps the registry AES-256-GCM encrypted at rest and upgrades a plaintext
+//! registry in place on first open.
 
 use std::collections::HashMap;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
 
+use crate::encryption::EncryptionKey;
 use crate::error::StorageError;
 use async_trait::async_trait;
 use oneshim_core::error::CoreError;
@@ -27,19 +30,44 @@ impl FileSecretRegistry {
         }
     }
 
-    fn load_or_default(path: &Path) -> Result<Self, StorageError> {
-        match std::fs::read_to_string(path) {
-            Ok(contents) => serde_json::from_str(&contents)
-                .map_err(|e| StorageError::SecretStore(format!("file secret registry parse: {e}"))),
-            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
-            Err(err) => Err(err.into()),
+    fn load_or_default(path: &Path, key: Option<&EncryptionKey>) -> Result<Self, StorageError> {
+        let bytes = match std::fs::read(path) {
+            Ok(bytes) => bytes,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::new()),
+            Err(err) => return Err(err.into()),
+        };
+        let Some(key) = key else {
+            return Self::parse(&bytes);
+        };
+        match key.decrypt(&bytes) {
+            Ok(plaintext) => Self::parse(&plaintext),
+            Err(decrypt_err) => {
+                // Plaintext registry written before encryption was enabled.
+                let registry = Self::parse(&bytes).map_err(|_| {
+                    StorageError::SecretStore(format!(
+                        "file secret registry decrypt: {decrypt_err}"
+                    ))
+                })?;
+                registry.save(path, Some(key))?;
+                tracing::info!("file secret registry upgraded to encrypted storage");
+                Ok(registry)
+            }
         }
     }
 
-    fn save(&self, path: &Path) -> Result<(), StorageError> {
-        let serialized = serde_json::to_string_pretty(self).map_err(|e| {
+    fn parse(bytes: &[u8]) -> Result<Self, StorageError> {
+        serde_json::from_slice(bytes)
+            .map_err(|e| StorageError::SecretStore(format!("file secret registry parse: {e}")))
+    }
+
+    fn save(&self, path: &Path, key: Option<&EncryptionKey>) -> Result<(), StorageError> {
+        let serialized = serde_json::to_vec_pretty(self).map_err(|e| {
             StorageError::SecretStore(format!("file secret registry serialization: {e}"))
         })?;
+        let serialized = match key {
+            Some(key) => key.encrypt(&serialized)?,
+            None => serialized,
+        };
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
@@ -95,24 +123,26 @@ impl FileSecretRegistry {
 struct FileSecretInner {
     registry_path: PathBuf,
     registry: parking_lot::Mutex<FileSecretRegistry>,
+    key: Option<EncryptionKey>,
 }
 
 impl FileSecretInner {
-    fn new(registry_path: PathBuf) -> Result<Self, StorageError> {
+    fn new(registry_path: PathBuf, key: Option<EncryptionKey>) -> Result<Self, StorageError> {
         if let Some(parent) = registry_path.parent() {
             std::fs::create_dir_all(parent)?;
         }
-        let registry = FileSecretRegistry::load_or_default(&registry_path)?;
+        let registry = FileSecretRegistry::load_or_default(&registry_path, key.as_ref())?;
         Ok(Self {
             registry_path,
             registry: parking_lot::Mutex::new(registry),
+            key,
         })
     }
 
     fn store_sync(&self, namespace: &str, key: &str, value: &str) -> Result<(), StorageError> {
         let mut registry = self.registry.lock();
         registry.store(namespace, key, value);
-        registry.save(&self.registry_path)
+        registry.save(&self.registry_path, self.key.as_ref())
     }
 
     fn retrieve_sync(&self, namespace: &str, key: &str) -> Option<String> {
@@ -122,13 +152,13 @@ impl FileSecretInner {
     fn delete_sync(&self, namespace: &str, key: &str) -> Result<(), StorageError> {
         let mut registry = self.registry.lock();
         registry.delete(namespace, key);
-        registry.save(&self.registry_path)
+        registry.save(&self.registry_path, self.key.as_ref())
     }
 
     fn delete_namespace_sync(&self, namespace: &str) -> Result<(), StorageError> {
         let mut registry = self.registry.lock();
         registry.delete_namespace(namespace);
-        registry.save(&self.registry_path)
+        registry.save(&self.registry_path, self.key.as_ref())
     }
 }
 
@@ -140,7 +170,14 @@ pub struct FileSecretStore {
 impl FileSecretStore {
     pub fn new(registry_path: PathBuf) -> Result<Self, StorageError> {
         Ok(Self {
-            inner: Arc::new(FileSecretInner::new(registry_path)?),
+            inner: Arc::new(FileSecretInner::new(registry_path, None)?),
+        })
+    }
+
+    /// Registry encrypted at rest with `key`.
+    pub fn encrypted(registry_path: PathBuf, key: EncryptionKey) -> Result<Self, StorageError> {
+        Ok(Self {
+            inner: Arc::new(FileSecretInner::new(registry_path, Some(key))?),
         })
     }
 }
@@ -240,6 +277,80 @@ mod tests {
         assert_eq!(value.as_deref(), Some("sk-test"));
     }
 
+    #[tokio::test]
+    async fn encrypted_store_stores_retrieves_and_deletes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("secrets.json");
+        let key = EncryptionKey::from_bytes([0x11; 32]);
+        let store = FileSecretStore::encrypted(path.clone(), key.clone()).unwrap();
+
+        store
+            .store("app/credentials", "gui_ticket_hmac_secret", "hmac-value")
+            .await
+            .unwrap();
+        let on_disk = std::fs::read(&path).unwrap();
+        assert!(!String::from_utf8_lossy(&on_disk).contains("hmac-value"));
+        assert!(!String::from_utf8_lossy(&on_disk).contains("app/credentials"));
+
+        let reloaded = FileSecretStore::encrypted(path.clone(), key).unwrap();
+        assert_eq!(
+            reloaded
+                .retrieve("app/credentials", "gui_ticket_hmac_secret")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("hmac-value")
+        );
+
+        reloaded
+            .delete("app/credentials", "gui_ticket_hmac_secret")
+            .await
+            .unwrap();
+        assert_eq!(
+            reloaded
+                .retrieve("app/credentials", "gui_ticket_hmac_secret")
+                .await
+                .unwrap(),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn encrypted_store_rejects_wrong_key() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("secrets.json");
+        FileSecretStore::encrypted(path.clone(), EncryptionKey::from_bytes([0x11; 32]))
+            .unwrap()
+            .store("app/credentials", "k", "v")
+            .await
+            .unwrap();
+
+        assert!(FileSecretStore::encrypted(path, EncryptionKey::from_bytes([0x22; 32])).is_err());
+    }
+
+    #[tokio::test]
+    async fn encrypted_store_upgrades_plaintext_registry() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let path = temp_dir.path().join("secrets.json");
+        FileSecretStore::new(path.clone())
+            .unwrap()
+            .store("provider/openai/default", "api_key", "sk-test")
+            .await
+            .unwrap();
+
+        let store = FileSecretStore::encrypted(path.clone(), EncryptionKey::from_bytes([0x11; 32]))
+            .unwrap();
+        assert_eq!(
+            store
+                .retrieve("provider/openai/default", "api_key")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("sk-test")
+        );
+        assert!(!String::from_utf8_lossy(&std::fs::read(&path).unwrap()).contains("sk-test"));
+    }
+
     #[tokio::test]
     async fn file_secret_store_delete_namespace_removes_only_target_namespace() {
         let temp_dir = tempfile::tempdir().unwrap();
@@ -1,13 +1,18 @@
 use crate::encryption::EncryptionKey;
 use crate::error::StorageError;
+use crate::sqlite::SqliteStorage;
 use async_trait::async_trait;
 use chrono::{DateTime, Utc};
 use crossbeam::queue::ArrayQueue;
+use oneshim_core::config::FrameShardGranularity;
 use oneshim_core::error::CoreError;
-use oneshim_core::ports::frame_storage::FrameStoragePort;
+use oneshim_core::models::frame::{is_truncated_image, CORRUPT_FRAME_FIELD};
+use oneshim_core::ports::frame_storage::{DiskPressureChange, FrameStoragePort};
 use parking_lot::Mutex as ParkingMutex;
-use std::path::{Path, PathBuf};
-use std::sync::atomic::{AtomicU32, AtomicU64, Ordering, Ordering as AtomicOrdering};
+use sha2::{Digest, Sha256};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+use std::sync::atomic::{AtomicBool, AtomicU32, AtomicU64, Ordering, Ordering as AtomicOrdering};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 use tokio::fs;
@@ -19,13 +24,30 @@ const DEFAULT_BUFFER_SIZE: usize = 256 * 1024;
 
 const PARALLEL_DELETE_LIMIT: usize = 8;
 
+/// Content hashes of recently saved frames kept in memory for deduplication.
+const RECENT_FRAME_HASHES: usize = 256;
+
+/// How long a soft-deleted frame stays restorable before retention purges it.
+const TRASH_GRACE_DAYS: i64 = 7;
+
+/// Suffix of the temp file a frame is written to before being renamed into
+/// place.
+const PARTIAL_SUFFIX: &str = ".partial";
+
 const DISK_CHECK_INTERVAL: Duration = Duration::from_secs(5);
-const DISK_SPACE_WARN_MB: u64 = 100;
-const DISK_SPACE_CRITICAL_MB: u64 = 50;
+const DEFAULT_DISK_CRITICAL_MB: u64 = 50;
+const DEFAULT_DISK_RESUME_MB: u64 = 100;
+
+/// Returned for frame image writes skipped while the disk is nearly full.
+const WRITES_SUSPENDED_MSG: &str = "frame image writes suspended: disk space critical";
+
+type FreeSpaceProbe = Arc<dyn Fn(&Path) -> u64 + Send + Sync>;
 
 struct DiskSpaceCache {
     last_check: ParkingMutex<Option<Instant>>,
     cached_free_mb: AtomicU64,
+    interval: Duration,
+    probe: FreeSpaceProbe,
 }
 
 impl DiskSpaceCache {
@@ -33,20 +55,51 @@ impl DiskSpaceCache {
         Self {
             last_check: ParkingMutex::new(None),
             cached_free_mb: AtomicU64::new(u64::MAX),
+            interval: DISK_CHECK_INTERVAL,
+            probe: Arc::new(query_disk_free_mb),
         }
     }
 
     fn get_free_mb(&self, path: &Path) -> u64 {
         let mut last = self.last_check.lock();
         let now = Instant::now();
-        if last.is_some_and(|t| now.duration_since(t) < DISK_CHECK_INTERVAL) {
+        if last.is_some_and(|t| now.duration_since(t) < self.interval) {
             return self.cached_free_mb.load(AtomicOrdering::Relaxed);
         }
-        let free = query_disk_free_mb(path);
+        let free = (self.probe)(path);
         self.cached_free_mb.store(free, AtomicOrdering::Relaxed);
         *last = Some(now);
         free
     }
+
+    /// Query free space now, bypassing and refreshing the cache.
+    fn refresh_free_mb(&self, path: &Path) -> u64 {
+        *self.last_check.lock() = None;
+        self.get_free_mb(path)
+    }
+}
+
+/// What to do with a frame image given free disk space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DiskDecision {
+    Write,
+    /// Space dropped below the critical threshold: stop writing images.
+    Suspend,
+    /// Still suspended; skip the image.
+    Skip,
+    /// Space recovered past the resume threshold: write again.
+    Resume,
+}
+
+/// Writes stop below `critical_mb` and only restart once `resume_mb` is
+/// free again, so a volume hovering at the threshold does not flap.
+fn disk_decision(free_mb: u64, suspended: bool, critical_mb: u64, resume_mb: u64) -> DiskDecision {
+    match (suspended, free_mb) {
+        (false, free) if free < critical_mb => DiskDecision::Suspend,
+        (false, _) => DiskDecision::Write,
+        (true, free) if free >= resume_mb => DiskDecision::Resume,
+        (true, _) => DiskDecision::Skip,
+    }
 }
 
 #[allow(clippy::unnecessary_cast)] // statvfs field types vary by platform
@@ -136,12 +189,27 @@ pub struct FrameFileStorage {
     buffer_pool: Arc<BufferPool>,
     disk_cache: DiskSpaceCache,
     encryption_key: Option<Arc<EncryptionKey>>,
+    shard_granularity: FrameShardGranularity,
     /// Approximate total size of all frame files, updated on save/delete.
-    /// Avoids O(n) directory stat on every `total_size_mb()` call.
-    /// Initialized lazily on the first call to `total_size_mb()`.
+    /// Avoids O(n) directory stat on every `total_size_bytes()` call.
+    /// Initialized lazily on the first call to `total_size_bytes()`.
     cached_size_bytes: AtomicU64,
     /// Whether `cached_size_bytes` has been initialized from a directory walk.
     cached_size_initialized: std::sync::atomic::AtomicBool,
+    /// Frame metadata index, for keep-tag lookups and corrupt-file cleanup.
+    index: Option<Arc<SqliteStorage>>,
+    /// Frames tagged with these names are skipped by retention passes.
+    keep_tags: Vec<String>,
+    disk_critical_mb: u64,
+    disk_resume_mb: u64,
+    /// Set while free space is below `disk_critical_mb`; cleared once it
+    /// recovers to `disk_resume_mb`.
+    images_suspended: AtomicBool,
+    /// Latest suspend/resume transition not yet taken by the pipeline.
+    pending_disk_change: ParkingMutex<Option<DiskPressureChange>>,
+    /// SHA-256 of recently saved images → relative path of their file.
+    /// Older hashes are looked up in the index.
+    recent_hashes: ParkingMutex<HashMap<String, PathBuf>>,
 }
 
 impl FrameFileStorage {
@@ -191,34 +259,308 @@ impl FrameFileStorage {
             buffer_pool: Arc::new(BufferPool::new(BUFFER_POOL_SIZE, DEFAULT_BUFFER_SIZE)),
             disk_cache: DiskSpaceCache::new(),
             encryption_key,
+            shard_granularity: FrameShardGranularity::default(),
             cached_size_bytes: AtomicU64::new(0),
             cached_size_initialized: std::sync::atomic::AtomicBool::new(false),
+            index: None,
+            keep_tags: Vec::new(),
+            disk_critical_mb: DEFAULT_DISK_CRITICAL_MB,
+            disk_resume_mb: DEFAULT_DISK_RESUME_MB,
+            images_suspended: AtomicBool::new(false),
+            pending_disk_change: ParkingMutex::new(None),
+            recent_hashes: ParkingMutex::new(HashMap::new()),
         })
     }
 
+    /// Stop image writes below `critical_mb` of free space and resume them
+    /// at `resume_mb`. `resume_mb` is raised above `critical_mb` if needed.
+    pub fn with_disk_thresholds(mut self, critical_mb: u64, resume_mb: u64) -> Self {
+        self.disk_critical_mb = critical_mb;
+        self.disk_resume_mb = resume_mb.max(critical_mb.saturating_add(1));
+        self
+    }
+
+    /// Set the date subdirectory layout for newly saved frames.
+    pub fn with_shard_granularity(mut self, granularity: FrameShardGranularity) -> Self {
+        self.shard_granularity = granularity;
+        self
+    }
+
+    /// Exempt frames tagged with any of `tags` in `index` from age and size
+    /// retention. Kept files still count toward `max_storage_mb`.
+    pub fn with_keep_tags(mut self, index: Arc<SqliteStorage>, tags: Vec<String>) -> Self {
+        self.index = Some(index);
+        self.keep_tags = tags;
+        self
+    }
+
+    /// Attach the frame metadata index without keep tags. Retention then
+    /// also deletes files the frames endpoint flagged as corrupt.
+    pub fn with_index(mut self, index: Arc<SqliteStorage>) -> Self {
+        self.index = Some(index);
+        self
+    }
+
+    /// Absolute paths of frame files that retention must not delete.
+    async fn kept_frame_paths(&self) -> Result<Arc<HashSet<PathBuf>>, StorageError> {
+        let Some(index) = self.index.as_ref().filter(|_| !self.keep_tags.is_empty()) else {
+            return Ok(Arc::default());
+        };
+        let index = Arc::clone(index);
+        let tags = self.keep_tags.clone();
+        let paths = tokio::task::spawn_blocking(move || index.get_frame_paths_with_tags(&tags))
+            .await
+            .map_err(|e| StorageError::Internal(format!("Task failed: {e}")))??;
+        Ok(Arc::new(
+            paths
+                .into_iter()
+                .map(|relative| self.base_dir.join(relative))
+                .collect(),
+        ))
+    }
+
+    /// Kept frames plus files shared by several frames through
+    /// deduplication, which size-limit eviction must not delete.
+    async fn storage_limit_protected_paths(&self) -> Result<Arc<HashSet<PathBuf>>, StorageError> {
+        let kept = self.kept_frame_paths().await?;
+        let Some(index) = self.index.as_ref() else {
+            return Ok(kept);
+        };
+        let index = Arc::clone(index);
+        let shared = tokio::task::spawn_blocking(move || index.get_shared_frame_paths())
+            .await
+            .map_err(|e| StorageError::Internal(format!("Task failed: {e}")))??;
+        if shared.is_empty() {
+            return Ok(kept);
+        }
+        let mut protected = (*kept).clone();
+        protected.extend(
+            shared
+                .into_iter()
+                .map(|relative| self.base_dir.join(relative)),
+        );
+        Ok(Arc::new(protected))
+    }
+
+    /// Kept frames plus files still referenced by frames taken on or after
+    /// `cutoff_date`, which age retention must not delete even when they
+    /// sit in an expired shard.
+    async fn retention_protected_paths(
+        &self,
+        cutoff_date: &str,
+    ) -> Result<Arc<HashSet<PathBuf>>, StorageError> {
+        let kept = self.kept_frame_paths().await?;
+        let Some(index) = self.index.as_ref() else {
+            return Ok(kept);
+        };
+        let index = Arc::clone(index);
+        let cutoff_date = cutoff_date.to_string();
+        let referenced =
+            tokio::task::spawn_blocking(move || index.get_frame_paths_since(&cutoff_date))
+                .await
+                .map_err(|e| StorageError::Internal(format!("Task failed: {e}")))??;
+        if referenced.is_empty() {
+            return Ok(kept);
+        }
+        let mut protected = (*kept).clone();
+        protected.extend(
+            referenced
+                .into_iter()
+                .map(|relative| self.base_dir.join(relative)),
+        );
+        Ok(Arc::new(protected))
+    }
+
+    /// Relative path of an existing frame file whose image hashes to
+    /// `sha256`, from recent saves or the index.
+    async fn find_duplicate(&self, sha256: &str) -> Option<PathBuf> {
+        let recent = self.recent_hashes.lock().get(sha256).cloned();
+        let candidate = match (recent, self.index.as_ref()) {
+            (Some(path), _) => path,
+            (None, Some(index)) => {
+                let index = Arc::clone(index);
+                let sha256 = sha256.to_string();
+                match tokio::task::spawn_blocking(move || index.find_frame_file_by_sha256(&sha256))
+                    .await
+                {
+                    Ok(Ok(Some(path))) => PathBuf::from(path),
+                    Ok(Ok(None)) => return None,
+                    Ok(Err(e)) => {
+                        warn!("frame hash lookup failed: {e}");
+                        return None;
+                    }
+                    Err(e) => {
+                        warn!("frame hash lookup task failed: {e}");
+                        return None;
+                    }
+                }
+            }
+            (None, None) => return None,
+        };
+        // Retention may have removed the file since it was recorded.
+        fs::try_exists(self.base_dir.join(&candidate))
+            .await
+            .unwrap_or(false)
+            .then_some(candidate)
+    }
+
+    /// Remember that `relative_path` holds the image hashing to `sha256`.
+    fn remember_hash(&self, sha256: &str, relative_path: &Path) {
+        {
+            let mut recent = self.recent_hashes.lock();
+            if recent.len() >= RECENT_FRAME_HASHES {
+                recent.clear();
+            }
+            recent.insert(sha256.to_string(), relative_path.to_path_buf());
+        }
+        if let Some(index) = &self.index {
+            index.note_frame_file_hash(&relative_path.to_string_lossy(), sha256);
+        }
+    }
+
+    /// Check free space before an image write, suspending or resuming
+    /// image writes on threshold crossings. Returns whether to write.
+    ///
+    /// Entering suspension logs once and immediately evicts old frames to
+    /// win space back.
+    async fn admit_image_write(&self) -> bool {
+        let free_mb = self.disk_cache.get_free_mb(&self.base_dir);
+        let suspended = self.images_suspended.load(Ordering::Acquire);
+        match disk_decision(
+            free_mb,
+            suspended,
+            self.disk_critical_mb,
+            self.disk_resume_mb,
+        ) {
+            DiskDecision::Write => true,
+            DiskDecision::Skip => false,
+            DiskDecision::Resume => {
+                if self.set_images_suspended(false, free_mb) {
+                    info!(free_mb, "disk space recovered — frame image writes resumed");
+                }
+                true
+            }
+            DiskDecision::Suspend => {
+                if self.set_images_suspended(true, free_mb) {
+                    error!(
+                        free_mb,
+                        critical_mb = self.disk_critical_mb,
+                        "disk space critical — frame image writes suspended, metadata still recorded"
+                    );
+                    self.evict_for_disk_space().await;
+                }
+                false
+            }
+        }
+    }
+
+    /// Flip the suspend flag and queue the transition. Returns `false` when
+    /// another caller made the same transition first.
+    fn set_images_suspended(&self, suspended: bool, free_mb: u64) -> bool {
+        if self
+            .images_suspended
+            .compare_exchange(!suspended, suspended, Ordering::AcqRel, Ordering::Acquire)
+            .is_err()
+        {
+            return false;
+        }
+        *self.pending_disk_change.lock() = Some(DiskPressureChange { suspended, free_mb });
+        true
+    }
+
+    /// Delete frames past retention, then the oldest day shards, until
+    /// `disk_resume_mb` is free. Today's shard and kept frames are spared.
+    /// Returns the number of deleted files.
+    async fn evict_for_disk_space(&self) -> usize {
+        let mut deleted = match self.enforce_retention().await {
+            Ok(count) => count,
+            Err(e) => {
+                warn!("retention during disk pressure failed: {e}");
+                0
+            }
+        };
+        let frames_dir = self.base_dir.join("frames");
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let mut shards = match list_day_shards(&frames_dir).await {
+            Ok(shards) => shards,
+            Err(e) => {
+                warn!("disk pressure eviction could not list frames: {e}");
+                return deleted;
+            }
+        };
+        shards.sort();
+        let kept = match self.kept_frame_paths().await {
+            Ok(kept) => kept,
+            Err(e) => {
+                warn!("disk pressure eviction could not load kept frames: {e}");
+                return deleted;
+            }
+        };
+
+        let mut deleted_bytes: u64 = 0;
+        for shard in shards.into_iter().filter(|shard| shard.date < today) {
+            if self.disk_cache.refresh_free_mb(&self.base_dir) >= self.disk_resume_mb {
+                break;
+            }
+            let (count, bytes) = remove_unkept(&shard.path, &kept).await;
+            deleted += count;
+            deleted_bytes += bytes;
+        }
+        prune_empty_shard_parents(&frames_dir).await;
+        if deleted_bytes > 0 {
+            let _ = self.cached_size_bytes.fetch_update(
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+                |current| Some(current.saturating_sub(deleted_bytes)),
+            );
+        }
+        self.disk_cache.refresh_free_mb(&self.base_dir);
+        if deleted > 0 {
+            info!("disk pressure eviction: deleted {deleted} frame files");
+        }
+        deleted
+    }
+
+    /// Whether image writes are suspended for lack of disk space.
+    pub fn image_writes_suspended(&self) -> bool {
+        self.images_suspended.load(Ordering::Acquire)
+    }
+
+    /// Take the latest suspend/resume transition, if one happened since the
+    /// last call.
+    pub fn take_disk_pressure_change(&self) -> Option<DiskPressureChange> {
+        self.pending_disk_change.lock().take()
+    }
+
     /// Save a frame image to disk.
     ///
-    /// Returns `CoreError::Storage` if free disk space is below the critical threshold (50 MB).
-    /// Logs a warning if free space is below the warn threshold (100 MB).
+    /// An image identical to one already stored is not written again; the
+    /// existing file's path is returned and the frames share it.
+    ///
+    /// While free space is below the critical threshold the image is not
+    /// written and `StorageError::Internal` is returned; callers keep
+    /// recording the frame's metadata without a file path.
     pub async fn save_frame(
         &self,
         timestamp: DateTime<Utc>,
         webp_data: &[u8],
     ) -> Result<PathBuf, StorageError> {
-        let free_mb = self.disk_cache.get_free_mb(&self.base_dir);
-        if free_mb < DISK_SPACE_CRITICAL_MB {
-            error!(free_mb, "disk space critical — skipping frame save");
-            return Err(StorageError::Internal("disk space critical".into()));
-        }
-        if free_mb < DISK_SPACE_WARN_MB {
-            warn!(
-                free_mb,
-                "disk space low — frame save proceeding with caution"
+        if !self.admit_image_write().await {
+            return Err(StorageError::Internal(WRITES_SUSPENDED_MSG.into()));
+        }
+
+        let sha256 = content_hash(webp_data);
+        if let Some(existing) = self.find_duplicate(&sha256).await {
+            debug!(
+                "frame save: {} (duplicate image reused)",
+                existing.display()
             );
+            self.remember_hash(&sha256, &existing);
+            return Ok(existing);
         }
 
-        let date_str = timestamp.format("%Y-%m-%d").to_string();
-        let day_dir = self.base_dir.join("frames").join(&date_str);
+        let shard = shard_dir(timestamp, self.shard_granularity);
+        let day_dir = self.base_dir.join("frames").join(&shard);
         fs::create_dir_all(&day_dir)
             .await
             .map_err(|e| StorageError::Internal(format!("Failed to create dated folder: {e}")))?;
@@ -235,7 +577,7 @@ impl FrameFileStorage {
         };
 
         let written_len = data_to_write.len() as u64;
-        fs::write(&file_path, &data_to_write)
+        write_atomic(&file_path, &data_to_write)
             .await
             .map_err(|e| StorageError::Internal(format!("frame file save failure: {e}")))?;
 
@@ -243,7 +585,8 @@ impl FrameFileStorage {
         self.cached_size_bytes
             .fetch_add(written_len, Ordering::Relaxed);
 
-        let relative_path = PathBuf::from("frames").join(&date_str).join(&filename);
+        let relative_path = PathBuf::from("frames").join(&shard).join(&filename);
+        self.remember_hash(&sha256, &relative_path);
 
         debug!(
             "frame save: {} ({}bytes raw, {}bytes on disk)",
@@ -262,29 +605,40 @@ impl FrameFileStorage {
         &self,
         frames: Vec<(DateTime<Utc>, Vec<u8>)>,
     ) -> Vec<Result<PathBuf, StorageError>> {
-        let free_mb = self.disk_cache.get_free_mb(&self.base_dir);
-        if free_mb < DISK_SPACE_CRITICAL_MB {
-            error!(
-                free_mb,
+        if !self.admit_image_write().await {
+            debug!(
                 batch_size = frames.len(),
-                "disk space critical — skipping batch save"
+                "skipping frame batch: {WRITES_SUSPENDED_MSG}"
             );
             return frames
                 .iter()
-                .map(|_| Err(StorageError::Internal("disk space critical".into())))
+                .map(|_| Err(StorageError::Internal(WRITES_SUSPENDED_MSG.into())))
                 .collect();
         }
 
-        let mut handles = Vec::with_capacity(frames.len());
+        let mut slots = Vec::with_capacity(frames.len());
+        let mut first_by_hash: HashMap<String, usize> = HashMap::new();
 
         for (timestamp, webp_data) in frames {
+            let sha256 = content_hash(&webp_data);
+            if let Some(&first) = first_by_hash.get(&sha256) {
+                slots.push(BatchSlot::SameAs(first));
+                continue;
+            }
+            first_by_hash.insert(sha256.clone(), slots.len());
+            if let Some(existing) = self.find_duplicate(&sha256).await {
+                slots.push(BatchSlot::Reused(existing, sha256));
+                continue;
+            }
+
             let base_dir = self.base_dir.clone();
             let counter = self.frame_counter.fetch_add(1, Ordering::SeqCst) % 1000;
             let enc_key = self.encryption_key.clone();
+            let granularity = self.shard_granularity;
 
-            handles.push(tokio::spawn(async move {
-                let date_str = timestamp.format("%Y-%m-%d").to_string();
-                let day_dir = base_dir.join("frames").join(&date_str);
+            let handle = tokio::spawn(async move {
+                let shard = shard_dir(timestamp, granularity);
+                let day_dir = base_dir.join("frames").join(&shard);
 
                 fs::create_dir_all(&day_dir).await.map_err(|e| {
                     StorageError::Internal(format!("Failed to create dated folder: {e}"))
@@ -301,27 +655,42 @@ impl FrameFileStorage {
                 };
 
                 let written_len = data_to_write.len() as u64;
-                fs::write(&file_path, &data_to_write)
+                write_atomic(&file_path, &data_to_write)
                     .await
                     .map_err(|e| StorageError::Internal(format!("frame file save failure: {e}")))?;
 
-                let relative_path = PathBuf::from("frames").join(&date_str).join(&filename);
+                let relative_path = PathBuf::from("frames").join(&shard).join(&filename);
 
                 Ok((relative_path, written_len))
-            }));
+            });
+            slots.push(BatchSlot::Written(handle, sha256));
         }
 
-        let mut results = Vec::with_capacity(handles.len());
+        let mut results: Vec<Result<PathBuf, StorageError>> = Vec::with_capacity(slots.len());
         let mut total_written: u64 = 0;
-        for handle in handles {
-            match handle.await {
-                Ok(Ok((path, size))) => {
-                    total_written += size;
-                    results.push(Ok(path));
+        for slot in slots {
+            let result = match slot {
+                BatchSlot::SameAs(first) => match &results[first] {
+                    Ok(path) => Ok(path.clone()),
+                    Err(e) => Err(StorageError::Internal(format!(
+                        "identical frame in batch failed to save: {e}"
+                    ))),
+                },
+                BatchSlot::Reused(path, sha256) => {
+                    self.remember_hash(&sha256, &path);
+                    Ok(path)
                 }
-                Ok(Err(e)) => results.push(Err(e)),
-                Err(e) => results.push(Err(StorageError::Internal(format!("Task failed: {e}")))),
-            }
+                BatchSlot::Written(handle, sha256) => match handle.await {
+                    Ok(Ok((path, size))) => {
+                        total_written += size;
+                        self.remember_hash(&sha256, &path);
+                        Ok(path)
+                    }
+                    Ok(Err(e)) => Err(e),
+                    Err(e) => Err(StorageError::Internal(format!("Task failed: {e}"))),
+                },
+            };
+            results.push(result);
         }
 
         // Update cached size tracker in a single atomic add
@@ -334,6 +703,9 @@ impl FrameFileStorage {
     }
 
     /// Load a frame from disk, decrypting if encryption is enabled.
+    ///
+    /// A file that is truncated or fails to decrypt is reported as
+    /// `StorageError::Validation` on [`CORRUPT_FRAME_FIELD`].
     pub async fn load_frame(&self, relative_path: &Path) -> Result<Vec<u8>, StorageError> {
         let full_path = self.base_dir.join(relative_path);
 
@@ -350,11 +722,18 @@ impl FrameFileStorage {
             .await
             .map_err(|e| StorageError::Internal(format!("frame file read failure: {e}")))?;
 
+        let corrupt = |message: String| StorageError::Validation {
+            field: CORRUPT_FRAME_FIELD.to_string(),
+            message: format!("{}: {message}", relative_path.display()),
+        };
         let data = if let Some(ref key) = self.encryption_key {
-            key.decrypt(&raw)?
+            key.decrypt(&raw).map_err(|e| corrupt(e.to_string()))?
         } else {
             raw
         };
+        if is_truncated_image(&data) {
+            return Err(corrupt(format!("truncated ({} bytes)", data.len())));
+        }
 
         buffer.extend_from_slice(&data);
         let result = buffer.clone();
@@ -370,35 +749,19 @@ impl FrameFileStorage {
             return Ok(None);
         }
 
-        let mut day_dirs = list_date_dirs(&frames_dir).await?;
-        day_dirs.sort_by(|a, b| b.cmp(a));
-
-        for day in day_dirs {
-            let day_path = frames_dir.join(&day);
-            if !day_path.exists() {
-                continue;
-            }
+        let mut shards = list_day_shards(&frames_dir).await?;
+        shards.sort_by(|a, b| b.cmp(a));
 
+        for shard in shards {
             let mut files = Vec::new();
-            let mut entries = fs::read_dir(&day_path)
-                .await
-                .map_err(|e| StorageError::Internal(format!("frame folder read failure: {e}")))?;
-
-            while let Some(entry) = entries
-                .next_entry()
-                .await
-                .map_err(|e| StorageError::Internal(format!("Failed to read frame entry: {e}")))?
-            {
-                let path = entry.path();
-                if path.is_file() {
-                    files.push(path);
-                }
-            }
-
+            collect_files(&shard.path, &mut files).await?;
+            files.retain(|path| !is_partial(path));
             if files.is_empty() {
                 continue;
             }
 
+            // File names start with HH-MM-SS, so they order correctly across
+            // hour shards within the same day.
             files.sort_by(|a, b| {
                 let a_name = a.file_name().and_then(|n| n.to_str()).unwrap_or_default();
                 let b_name = b.file_name().and_then(|n| n.to_str()).unwrap_or_default();
@@ -406,10 +769,10 @@ impl FrameFileStorage {
             });
 
             let latest = &files[0];
-            let Some(filename) = latest.file_name().and_then(|n| n.to_str()) else {
+            let Ok(within_frames) = latest.strip_prefix(&frames_dir) else {
                 continue;
             };
-            let relative_path = PathBuf::from("frames").join(&day).join(filename);
+            let relative_path = PathBuf::from("frames").join(within_frames);
             let bytes = self.load_frame(&relative_path).await?;
             let format = latest
                 .extension()
@@ -483,58 +846,46 @@ impl FrameFileStorage {
             return Ok(0);
         }
 
+        let index_removed =
+            self.remove_corrupt_files().await? + self.remove_trashed_files().await?;
+
         let cutoff_date = (Utc::now() - chrono::Duration::days(self.retention_days as i64))
             .format("%Y-%m-%d")
             .to_string();
 
-        let mut entries = fs::read_dir(&frames_dir)
-            .await
-            .map_err(|e| StorageError::Internal(format!("Failed to read frames directory: {e}")))?;
-
-        let mut dirs_to_delete = Vec::new();
-        while let Some(entry) = entries
-            .next_entry()
-            .await
-            .map_err(|e| StorageError::Internal(format!("Failed to read directory entry: {e}")))?
-        {
-            let path = entry.path();
-
-            if let Some(dir_name) = path.file_name().and_then(|n| n.to_str()) {
-                if dir_name.len() == 10 && dir_name < cutoff_date.as_str() {
-                    dirs_to_delete.push(path);
-                }
-            }
-        }
+        let dirs_to_delete: Vec<PathBuf> = list_day_shards(&frames_dir)
+            .await?
+            .into_iter()
+            .filter(|shard| shard.date < cutoff_date)
+            .map(|shard| shard.path)
+            .collect();
 
         if dirs_to_delete.is_empty() {
-            return Ok(0);
+            return Ok(index_removed);
         }
+        let kept = self.retention_protected_paths(&cutoff_date).await?;
 
-        let mut deleted_count = 0;
+        let mut deleted_count = index_removed;
         for chunk in dirs_to_delete.chunks(PARALLEL_DELETE_LIMIT) {
             let mut handles = Vec::with_capacity(chunk.len());
 
             for path in chunk {
                 let path = path.clone();
-                handles.push(tokio::spawn(async move {
-                    let count = count_files_in_dir(&path).await;
-                    match fs::remove_dir_all(&path).await {
-                        Ok(()) => Some(count),
-                        Err(e) => {
-                            warn!("frame folder delete failure: {e}");
-                            None
-                        }
-                    }
-                }));
+                let kept = Arc::clone(&kept);
+                handles.push(tokio::spawn(
+                    async move { remove_unkept(&path, &kept).await.0 },
+                ));
             }
 
             for handle in handles {
-                if let Ok(Some(count)) = handle.await {
+                if let Ok(count) = handle.await {
                     deleted_count += count;
                 }
             }
         }
 
+        prune_empty_shard_parents(&frames_dir).await;
+
         if deleted_count > 0 {
             info!(
                 "frame retention policy: deleted {deleted_count} files (>{} days)",
@@ -554,7 +905,87 @@ impl FrameFileStorage {
         Ok(deleted_count)
     }
 
+    /// Delete files flagged corrupt in the index and detach them from their
+    /// frames. Returns the number of files removed.
+    async fn remove_corrupt_files(&self) -> Result<usize, StorageError> {
+        let Some(index) = &self.index else {
+            return Ok(0);
+        };
+        let index = Arc::clone(index);
+        let paths = tokio::task::spawn_blocking(move || index.take_corrupt_frame_paths())
+            .await
+            .map_err(|e| StorageError::Internal(format!("Task failed: {e}")))??;
+
+        let removed = self.remove_index_paths(paths, "corrupt").await;
+        if removed > 0 {
+            info!("frame retention policy: deleted {removed} corrupt files");
+        }
+        Ok(removed)
+    }
+
+    /// Purge frames trashed more than [`TRASH_GRACE_DAYS`] ago and delete
+    /// the files only they referenced. Returns the number of files removed.
+    async fn remove_trashed_files(&self) -> Result<usize, StorageError> {
+        let Some(index) = &self.index else {
+            return Ok(0);
+        };
+        let index = Arc::clone(index);
+        let cutoff = Utc::now() - chrono::Duration::days(TRASH_GRACE_DAYS);
+        let paths = tokio::task::spawn_blocking(move || index.purge_trash(cutoff))
+            .await
+            .map_err(|e| StorageError::Internal(format!("Task failed: {e}")))??;
+
+        let removed = self.remove_index_paths(paths, "trashed").await;
+        if removed > 0 {
+            info!("frame retention policy: deleted {removed} trashed files");
+        }
+        Ok(removed)
+    }
+
+    /// Delete frame files at index-relative `paths` and return how many were
+    /// removed. `kind` only labels log lines.
+    async fn remove_index_paths(&self, paths: Vec<String>, kind: &str) -> usize {
+        let mut removed = 0;
+        let mut removed_bytes = 0;
+        for relative in paths {
+            // Paths come from the index; never follow one outside base_dir.
+            let relative = Path::new(&relative);
+            if !relative
+                .components()
+                .all(|component| matches!(component, Component::Normal(_)))
+            {
+                warn!(path = %relative.display(), "skipping {kind} frame outside frame storage");
+                continue;
+            }
+            let path = self.base_dir.join(relative);
+            let len = fs::metadata(&path).await.map(|m| m.len()).unwrap_or(0);
+            match fs::remove_file(&path).await {
+                Ok(()) => {
+                    removed += 1;
+                    removed_bytes += len;
+                }
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => warn!(path = %path.display(), "{kind} frame delete failure: {e}"),
+            }
+        }
+
+        if removed > 0 {
+            let _ = self.cached_size_bytes.fetch_update(
+                Ordering::Relaxed,
+                Ordering::Relaxed,
+                |current| Some(current.saturating_sub(removed_bytes)),
+            );
+        }
+        removed
+    }
+
     pub async fn total_size_mb(&self) -> Result<u64, StorageError> {
+        Ok(self.total_size_bytes().await? / 1024 / 1024)
+    }
+
+    /// Bytes under the frames directory. The directory is walked once; saves
+    /// and deletes keep the total current after that.
+    pub async fn total_size_bytes(&self) -> Result<u64, StorageError> {
         // Lazy-init: walk the directory once on the first call
         if !self.cached_size_initialized.load(Ordering::Acquire) {
             let frames_dir = self.base_dir.join("frames");
@@ -567,10 +998,17 @@ impl FrameFileStorage {
             self.cached_size_initialized.store(true, Ordering::Release);
         }
 
-        Ok(self.cached_size_bytes.load(Ordering::Relaxed) / 1024 / 1024)
+        Ok(self.cached_size_bytes.load(Ordering::Relaxed))
     }
 
+    /// While image writes are suspended this also evicts the oldest frames
+    /// until the disk resume threshold is free again.
     pub async fn enforce_storage_limit(&self) -> Result<usize, StorageError> {
+        if self.image_writes_suspended()
+            && self.disk_cache.refresh_free_mb(&self.base_dir) < self.disk_resume_mb
+        {
+            return Ok(self.evict_for_disk_space().await);
+        }
         let frames_dir = self.base_dir.join("frames");
 
         if !frames_dir.exists() {
@@ -586,36 +1024,31 @@ impl FrameFileStorage {
             self.cached_size_initialized.store(true, Ordering::Release);
             size
         };
-        let mut current_mb = total_bytes / 1024 / 1024;
-
-        if current_mb <= self.max_storage_mb {
+        if total_bytes / 1024 / 1024 <= self.max_storage_mb {
             return Ok(0);
         }
+        let kept = self.storage_limit_protected_paths().await?;
 
         let mut deleted_count = 0;
         let mut total_deleted_bytes: u64 = 0;
 
-        let mut dirs = list_date_dirs(&frames_dir).await?;
-        dirs.sort(); // YYYY-MM-DD ascending (oldest first)
-        for dir_name in dirs {
-            if current_mb <= self.max_storage_mb {
+        let mut shards = list_day_shards(&frames_dir).await?;
+        shards.sort(); // date ascending (oldest first)
+        for shard in shards {
+            // Kept frames stay in the total, so the limit may remain exceeded.
+            if total_bytes.saturating_sub(total_deleted_bytes) / 1024 / 1024 <= self.max_storage_mb
+            {
                 break;
             }
 
-            let dir_path = frames_dir.join(&dir_name);
-            let dir_size_bytes = calculate_dir_size(&dir_path).await.unwrap_or(0);
-            let count = count_files_in_dir(&dir_path).await;
+            let (count, bytes) = remove_unkept(&shard.path, &kept).await;
             deleted_count += count;
-
-            if let Err(e) = fs::remove_dir_all(&dir_path).await {
-                warn!("frame folder delete failure: {e}");
-            } else {
-                let dir_size_mb = dir_size_bytes / 1024 / 1024;
-                current_mb = current_mb.saturating_sub(dir_size_mb);
-                total_deleted_bytes += dir_size_bytes;
-                info!("frame folder delete: {} ({count} files)", dir_name);
+            total_deleted_bytes += bytes;
+            if count > 0 {
+                info!("frame folder delete: {} ({count} files)", shard.date);
             }
         }
+        prune_empty_shard_parents(&frames_dir).await;
 
         // Subtract deleted bytes from the cached size tracker using an atomic
         // fetch_update so that a concurrent save() cannot interleave between
@@ -634,7 +1067,7 @@ impl FrameFileStorage {
 
     /// Delete all frame files for GDPR compliance.
     ///
-    /// Removes every date-directory under `<base>/frames/`. This is best-effort:
+    /// Removes every day shard under `<base>/frames/`. This is best-effort:
     /// individual directory removal failures are logged as warnings but do not
     /// abort the overall operation, and the returned count reflects only the
     /// directories that were successfully removed.
@@ -644,7 +1077,7 @@ impl FrameFileStorage {
             return Ok(0);
         }
 
-        let dirs = list_date_dirs(&frames_dir).await?;
+        let dirs = list_day_shards(&frames_dir).await?;
         if dirs.is_empty() {
             return Ok(0);
         }
@@ -652,8 +1085,8 @@ impl FrameFileStorage {
         let mut deleted = 0usize;
         for chunk in dirs.chunks(PARALLEL_DELETE_LIMIT) {
             let mut handles = Vec::with_capacity(chunk.len());
-            for dir_name in chunk {
-                let dir_path = frames_dir.join(dir_name);
+            for shard in chunk {
+                let dir_path = shard.path.clone();
                 handles.push(tokio::spawn(async move {
                     let count = count_files_in_dir(&dir_path).await;
                     match fs::remove_dir_all(&dir_path).await {
@@ -675,6 +1108,7 @@ impl FrameFileStorage {
                 }
             }
         }
+        prune_empty_shard_parents(&frames_dir).await;
 
         if deleted > 0 {
             // Reset cached size to zero since all frames were deleted
@@ -712,6 +1146,28 @@ impl FrameFileStorage {
         self.base_dir.join("frames")
     }
 
+    /// Zip the frames directory to `path` and return the number of files
+    /// archived. Files are stored as-is (images are already compressed, and
+    /// encrypted frames stay encrypted under the same key). In-flight
+    /// `.partial` writes are skipped. Blocking.
+    pub fn archive_to(&self, path: &Path) -> Result<u64, StorageError> {
+        let mut partial = path.as_os_str().to_owned();
+        partial.push(PARTIAL_SUFFIX);
+        let partial = PathBuf::from(partial);
+        let file = std::fs::File::create(&partial)?;
+        let mut zip = zip::ZipWriter::new(std::io::BufWriter::new(file));
+        let frames_dir = self.frames_dir();
+        let archived = if frames_dir.exists() {
+            zip_dir_into(&mut zip, &frames_dir, &frames_dir)?
+        } else {
+            0
+        };
+        zip.finish()
+            .map_err(|e| StorageError::Internal(format!("frame archive finish failed: {e}")))?;
+        std::fs::rename(&partial, path)?;
+        Ok(archived)
+    }
+
     pub fn buffer_pool_stats(&self) -> BufferPoolStats {
         BufferPoolStats {
             pool_capacity: BUFFER_POOL_SIZE,
@@ -724,7 +1180,7 @@ impl FrameFileStorage {
         let free_mb = self.disk_cache.get_free_mb(&self.base_dir);
         DiskStatus {
             free_mb,
-            healthy: free_mb >= DISK_SPACE_CRITICAL_MB,
+            healthy: free_mb >= self.disk_critical_mb && !self.image_writes_suspended(),
         }
     }
 }
@@ -761,6 +1217,33 @@ impl FrameStoragePort for FrameFileStorage {
     async fn enforce_storage_limit(&self) -> Result<usize, CoreError> {
         self.enforce_storage_limit().await.map_err(Into::into)
     }
+
+    async fn total_size_bytes(&self) -> Result<u64, CoreError> {
+        self.total_size_bytes().await.map_err(Into::into)
+    }
+
+    fn image_writes_suspended(&self) -> bool {
+        self.image_writes_suspended()
+    }
+
+    fn take_disk_pressure_change(&self) -> Option<DiskPressureChange> {
+        self.take_disk_pressure_change()
+    }
+}
+
+/// One frame of `save_frames_batch`, in input order.
+enum BatchSlot {
+    Written(
+        tokio::task::JoinHandle<Result<(PathBuf, u64), StorageError>>,
+        String,
+    ),
+    Reused(PathBuf, String),
+    /// Same image as the frame at this earlier index.
+    SameAs(usize),
+}
+
+fn content_hash(data: &[u8]) -> String {
+    hex::encode(Sha256::digest(data))
 }
 
 #[derive(Debug, Clone)]
@@ -769,18 +1252,107 @@ pub struct BufferPoolStats {
     pub buffer_size: usize,
 }
 
+/// Write `data` to `path` through a sibling temp file and a rename, so a
+/// failed write (e.g. disk full) never leaves a truncated frame at `path`.
+async fn write_atomic(path: &Path, data: &[u8]) -> std::io::Result<()> {
+    let mut partial = path.as_os_str().to_owned();
+    partial.push(PARTIAL_SUFFIX);
+    let partial = PathBuf::from(partial);
+
+    let result = match fs::write(&partial, data).await {
+        Ok(()) => fs::rename(&partial, path).await,
+        Err(e) => Err(e),
+    };
+    if result.is_err() {
+        let _ = fs::remove_file(&partial).await;
+    }
+    result
+}
+
+fn is_partial(path: &Path) -> bool {
+    path.file_name()
+        .and_then(|name| name.to_str())
+        .is_some_and(|name| name.ends_with(PARTIAL_SUFFIX))
+}
+
 async fn count_files_in_dir(path: &Path) -> usize {
     let mut count = 0;
     if let Ok(mut entries) = fs::read_dir(path).await {
         while let Ok(Some(entry)) = entries.next_entry().await {
-            if entry.path().is_file() {
+            let entry_path = entry.path();
+            if entry_path.is_file() {
                 count += 1;
+            } else if entry_path.is_dir() {
+                count += Box::pin(count_files_in_dir(&entry_path)).await;
             }
         }
     }
     count
 }
 
+/// Delete everything under `dir` except the files in `kept`, removing
+/// directories left empty. Returns the number of files and bytes deleted.
+async fn remove_unkept(dir: &Path, kept: &HashSet<PathBuf>) -> (usize, u64) {
+    if !kept.iter().any(|path| path.starts_with(dir)) {
+        let count = count_files_in_dir(dir).await;
+        let bytes = calculate_dir_size(dir).await.unwrap_or(0);
+        return match fs::remove_dir_all(dir).await {
+            Ok(()) => (count, bytes),
+            Err(e) => {
+                warn!("frame folder delete failure: {e}");
+                (0, 0)
+            }
+        };
+    }
+
+    let mut removed = (0, 0);
+    let Ok(mut entries) = fs::read_dir(dir).await else {
+        return removed;
+    };
+    while let Ok(Some(entry)) = entries.next_entry().await {
+        let path = entry.path();
+        let Ok(metadata) = entry.metadata().await else {
+            continue;
+        };
+        if metadata.is_dir() {
+            let (count, bytes) = Box::pin(remove_unkept(&path, kept)).await;
+            removed.0 += count;
+            removed.1 += bytes;
+        } else if !kept.contains(&path) {
+            match fs::remove_file(&path).await {
+                Ok(()) => {
+                    removed.0 += 1;
+                    removed.1 += metadata.len();
+                }
+                Err(e) => warn!("frame file delete failure: {e}"),
+            }
+        }
+    }
+    remove_dir_if_empty(dir).await;
+    removed
+}
+
+async fn collect_files(path: &Path, files: &mut Vec<PathBuf>) -> Result<(), StorageError> {
+    let mut entries = fs::read_dir(path)
+        .await
+        .map_err(|e| StorageError::Internal(format!("frame folder read failure: {e}")))?;
+
+    while let Some(entry) = entries
+        .next_entry()
+        .await
+        .map_err(|e| StorageError::Internal(format!("Failed to read frame entry: {e}")))?
+    {
+        let entry_path = entry.path();
+        if entry_path.is_file() {
+            files.push(entry_path);
+        } else if entry_path.is_dir() {
+            Box::pin(collect_files(&entry_path, files)).await?;
+        }
+    }
+
+    Ok(())
+}
+
 async fn calculate_dir_size(path: &Path) -> Result<u64, StorageError> {
     let mut total = 0u64;
 
@@ -808,14 +1380,39 @@ async fn calculate_dir_size(path: &Path) -> Result<u64, StorageError> {
     Ok(total)
 }
 
-async fn list_date_dirs(frames_dir: &Path) -> Result<Vec<String>, StorageError> {
-    let mut dirs = Vec::with_capacity(365);
-
-    if !frames_dir.exists() {
-        return Ok(dirs);
+/// Directory under `frames/` that a frame captured at `timestamp` goes into.
+fn shard_dir(timestamp: DateTime<Utc>, granularity: FrameShardGranularity) -> PathBuf {
+    match granularity {
+        FrameShardGranularity::Flat => PathBuf::from(timestamp.format("%Y-%m-%d").to_string()),
+        FrameShardGranularity::Day => PathBuf::from(timestamp.format("%Y").to_string())
+            .join(timestamp.format("%m").to_string())
+            .join(timestamp.format("%d").to_string()),
+        FrameShardGranularity::Hour => shard_dir(timestamp, FrameShardGranularity::Day)
+            .join(timestamp.format("%H").to_string()),
     }
+}
+
+/// All frames of one calendar day, in either the flat `YYYY-MM-DD/` layout or
+/// the sharded `YYYY/MM/DD/` layout (which may hold hour subdirectories).
+/// Retention and eviction operate on whole day shards.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+struct DayShard {
+    /// `YYYY-MM-DD`, comparable as a string.
+    date: String,
+    path: PathBuf,
+}
 
-    let mut entries = fs::read_dir(frames_dir)
+fn is_digits(name: &str, len: usize) -> bool {
+    name.len() == len && name.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn is_flat_date_dir(name: &str) -> bool {
+    name.len() == 10 && name.chars().nth(4) == Some('-')
+}
+
+async fn list_subdirs(path: &Path) -> Result<Vec<(String, PathBuf)>, StorageError> {
+    let mut dirs = Vec::new();
+    let mut entries = fs::read_dir(path)
         .await
         .map_err(|e| StorageError::Internal(format!("Failed to read frames directory: {e}")))?;
 
@@ -824,12 +1421,10 @@ async fn list_date_dirs(frames_dir: &Path) -> Result<Vec<String>, StorageError>
         .await
         .map_err(|e| StorageError::Internal(format!("Failed to read entry: {e}")))?
     {
-        let path = entry.path();
-        if path.is_dir() {
-            if let Some(name) = path.file_name().and_then(|n| n.to_str()) {
-                if name.len() == 10 && name.chars().nth(4) == Some('-') {
-                    dirs.push(name.to_string());
-                }
+        let entry_path = entry.path();
+        if entry_path.is_dir() {
+            if let Some(name) = entry_path.file_name().and_then(|n| n.to_str()) {
+                dirs.push((name.to_string(), entry_path.clone()));
             }
         }
     }
@@ -837,6 +1432,105 @@ async fn list_date_dirs(frames_dir: &Path) -> Result<Vec<String>, StorageError>
     Ok(dirs)
 }
 
+async fn list_day_shards(frames_dir: &Path) -> Result<Vec<DayShard>, StorageError> {
+    let mut shards = Vec::with_capacity(365);
+
+    if !frames_dir.exists() {
+        return Ok(shards);
+    }
+
+    for (name, path) in list_subdirs(frames_dir).await? {
+        if is_flat_date_dir(&name) {
+            shards.push(DayShard { date: name, path });
+        } else if is_digits(&name, 4) {
+            for (month, month_path) in list_subdirs(&path).await? {
+                if !is_digits(&month, 2) {
+                    continue;
+                }
+                for (day, day_path) in list_subdirs(&month_path).await? {
+                    if is_digits(&day, 2) {
+                        shards.push(DayShard {
+                            date: format!("{name}-{month}-{day}"),
+                            path: day_path,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(shards)
+}
+
+/// Remove `YYYY/MM/` directories left empty after their day shards were deleted.
+async fn prune_empty_shard_parents(frames_dir: &Path) {
+    let Ok(years) = list_subdirs(frames_dir).await else {
+        return;
+    };
+    for (year, year_path) in years {
+        if !is_digits(&year, 4) {
+            continue;
+        }
+        if let Ok(months) = list_subdirs(&year_path).await {
+            for (_, month_path) in months {
+                remove_dir_if_empty(&month_path).await;
+            }
+        }
+        remove_dir_if_empty(&year_path).await;
+    }
+}
+
+async fn remove_dir_if_empty(path: &Path) {
+    let is_empty = match fs::read_dir(path).await {
+        Ok(mut entries) => matches!(entries.next_entry().await, Ok(None)),
+        Err(_) => false,
+    };
+    if is_empty {
+        if let Err(e) = fs::remove_dir(path).await {
+            debug!("empty shard folder remove failed: {e}");
+        }
+    }
+}
+
+/// Add every regular file under `dir` to `zip`, named by its `/`-separated
+/// path relative to `root`.
+fn zip_dir_into<W: std::io::Write + std::io::Seek>(
+    zip: &mut zip::ZipWriter<W>,
+    root: &Path,
+    dir: &Path,
+) -> Result<u64, StorageError> {
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Stored)
+        .large_file(true);
+    let mut archived = 0;
+    let mut entries: Vec<PathBuf> = std::fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    entries.sort();
+    for path in entries {
+        if path.is_dir() {
+            archived += zip_dir_into(zip, root, &path)?;
+            continue;
+        }
+        if !path.is_file() || is_partial(&path) {
+            continue;
+        }
+        let Ok(relative) = path.strip_prefix(root) else {
+            continue;
+        };
+        let name = relative
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/");
+        zip.start_file(name, options)
+            .map_err(|e| StorageError::Internal(format!("frame archive entry failed: {e}")))?;
+        std::io::copy(&mut std::fs::File::open(&path)?, zip)?;
+        archived += 1;
+    }
+    Ok(archived)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -885,11 +1579,10 @@ mod tests {
         let (storage, _temp) = create_test_storage().await;
 
         let timestamp = Utc::now();
-        let data = b"test";
 
-        let path1 = storage.save_frame(timestamp, data).await.unwrap();
-        let path2 = storage.save_frame(timestamp, data).await.unwrap();
-        let path3 = storage.save_frame(timestamp, data).await.unwrap();
+        let path1 = storage.save_frame(timestamp, b"test-1").await.unwrap();
+        let path2 = storage.save_frame(timestamp, b"test-2").await.unwrap();
+        let path3 = storage.save_frame(timestamp, b"test-3").await.unwrap();
 
         assert_ne!(path1, path2);
         assert_ne!(path2, path3);
@@ -1029,14 +1722,14 @@ mod tests {
 
         // Verify frames directory has date-dirs before deletion
         let frames_dir = storage.frames_dir();
-        let dirs_before = list_date_dirs(&frames_dir).await.unwrap();
+        let dirs_before = list_day_shards(&frames_dir).await.unwrap();
         assert!(!dirs_before.is_empty());
 
         let deleted = storage.delete_all_files().await.unwrap();
         assert_eq!(deleted, 3);
 
         // Verify frames directory is now empty (no date dirs left)
-        let remaining = list_date_dirs(&frames_dir).await.unwrap();
+        let remaining = list_day_shards(&frames_dir).await.unwrap();
         assert!(remaining.is_empty());
     }
 
@@ -1168,4 +1861,447 @@ mod tests {
         let result = storage2.load_frame(&rel_path).await;
         assert!(result.is_err());
     }
+
+    #[tokio::test]
+    async fn truncated_frame_load_reports_corrupt() {
+        let (storage, temp) = create_test_storage().await;
+        let mut webp = b"RIFF".to_vec();
+        webp.extend_from_slice(&64u32.to_le_bytes());
+        webp.extend_from_slice(b"WEBPVP8 cut short");
+        let path = storage.save_frame(Utc::now(), &webp).await.unwrap();
+        // The RIFF header promises 72 bytes; fewer are on disk.
+        assert!(std::fs::metadata(temp.path().join(&path)).unwrap().len() < 72);
+
+        match storage.load_frame(&path).await {
+            Err(StorageError::Validation { field, .. }) => assert_eq!(field, CORRUPT_FRAME_FIELD),
+            other => panic!("expected corrupt frame error, got {other:?}"),
+        }
+    }
+
+    // ── Date sharding tests ────────────────────────────────────────
+
+    fn utc(y: i32, m: u32, d: u32, h: u32) -> DateTime<Utc> {
+        use chrono::TimeZone;
+        Utc.with_ymd_and_hms(y, m, d, h, 15, 0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn frames_on_different_dates_land_in_different_shards() {
+        let (storage, _temp) = create_test_storage().await;
+
+        let first = storage
+            .save_frame(utc(2024, 3, 1, 9), b"march-first")
+            .await
+            .unwrap();
+        let second = storage
+            .save_frame(utc(2024, 3, 2, 9), b"march-second")
+            .await
+            .unwrap();
+
+        assert!(first.starts_with(Path::new("frames/2024/03/01")));
+        assert!(second.starts_with(Path::new("frames/2024/03/02")));
+        assert_eq!(storage.load_frame(&first).await.unwrap(), b"march-first");
+        assert_eq!(storage.load_frame(&second).await.unwrap(), b"march-second");
+    }
+
+    #[tokio::test]
+    async fn hour_granularity_adds_hour_shard() {
+        let (storage, _temp) = create_test_storage().await;
+        let storage = storage.with_shard_granularity(FrameShardGranularity::Hour);
+
+        let paths: Vec<_> = storage
+            .save_frames_batch(vec![
+                (utc(2024, 3, 1, 9), b"nine".to_vec()),
+                (utc(2024, 3, 1, 14), b"fourteen".to_vec()),
+            ])
+            .await
+            .into_iter()
+            .map(Result::unwrap)
+            .collect();
+
+        assert!(paths[0].starts_with(Path::new("frames/2024/03/01/09")));
+        assert!(paths[1].starts_with(Path::new("frames/2024/03/01/14")));
+        let loaded = storage.load_frames_batch(paths).await;
+        assert_eq!(loaded[1].as_ref().unwrap(), b"fourteen");
+    }
+
+    #[tokio::test]
+    async fn flat_granularity_keeps_original_layout() {
+        let (storage, _temp) = create_test_storage().await;
+        let storage = storage.with_shard_granularity(FrameShardGranularity::Flat);
+
+        let path = storage
+            .save_frame(utc(2024, 3, 1, 9), b"flat")
+            .await
+            .unwrap();
+        assert!(path.starts_with(Path::new("frames/2024-03-01")));
+    }
+
+    #[tokio::test]
+    async fn retention_traverses_flat_and_sharded_layouts() {
+        let (storage, temp) = create_test_storage().await;
+
+        // A frame written by an older build under the flat layout.
+        let legacy_dir = temp.path().join("frames/2020-01-01");
+        tokio::fs::create_dir_all(&legacy_dir).await.unwrap();
+        tokio::fs::write(legacy_dir.join("10-00-00-000.webp"), b"legacy")
+            .await
+            .unwrap();
+        assert_eq!(
+            storage
+                .load_frame(Path::new("frames/2020-01-01/10-00-00-000.webp"))
+                .await
+                .unwrap(),
+            b"legacy"
+        );
+
+        let hourly = storage.with_shard_granularity(FrameShardGranularity::Hour);
+        hourly
+            .save_frame(utc(2020, 1, 2, 8), b"old-sharded")
+            .await
+            .unwrap();
+        let recent = hourly.save_frame(Utc::now(), b"recent").await.unwrap();
+
+        let deleted = hourly.enforce_retention().await.unwrap();
+        assert_eq!(deleted, 2);
+        assert!(!legacy_dir.exists());
+        assert!(!temp.path().join("frames/2020").exists());
+        assert_eq!(hourly.load_frame(&recent).await.unwrap(), b"recent");
+    }
+
+    fn tagged_index(frames: &[(&Path, bool)]) -> Arc<SqliteStorage> {
+        let index = Arc::new(SqliteStorage::open_in_memory(30).unwrap());
+        let tag = index.create_tag("keep", "#00ff00").unwrap();
+        for (path, keep) in frames {
+            let metadata = oneshim_core::models::frame::FrameMetadata {
+                timestamp: utc(2020, 1, 1, 9),
+                trigger_type: "manual".to_string(),
+                app_name: "Code".to_string(),
+                window_title: "main.rs".to_string(),
+                resolution: (1920, 1080),
+                importance: 0.5,
+            };
+            let id = index
+                .save_frame_metadata(&metadata, Some(&path.to_string_lossy()), None)
+                .unwrap();
+            if *keep {
+                index.add_tag_to_frame(id, tag.id).unwrap();
+            }
+        }
+        index
+    }
+
+    #[tokio::test]
+    async fn keep_tagged_frame_survives_age_retention() {
+        let (storage, _temp) = create_test_storage().await;
+        let kept = storage
+            .save_frame(utc(2020, 1, 1, 9), b"kept")
+            .await
+            .unwrap();
+        let dropped = storage
+            .save_frame(utc(2020, 1, 1, 9), b"old")
+            .await
+            .unwrap();
+        let index = tagged_index(&[(&kept, true), (&dropped, false)]);
+        let storage = storage.with_keep_tags(index, vec!["Keep".to_string()]);
+
+        let deleted = storage.enforce_retention().await.unwrap();
+
+        assert_eq!(deleted, 1);
+        assert_eq!(storage.load_frame(&kept).await.unwrap(), b"kept");
+        assert!(storage.load_frame(&dropped).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn keep_tagged_frame_counts_toward_but_survives_size_limit() {
+        let temp = TempDir::new().unwrap();
+        let storage = FrameFileStorage::new(temp.path().to_path_buf(), 1, 3650)
+            .await
+            .unwrap();
+        let kept = storage
+            .save_frame(utc(2020, 1, 1, 9), &vec![0u8; 3 * 1024 * 1024 / 2])
+            .await
+            .unwrap();
+        let dropped = storage
+            .save_frame(utc(2020, 1, 1, 9), &vec![1u8; 3 * 1024 * 1024 / 2])
+            .await
+            .unwrap();
+        let index = tagged_index(&[(&kept, true), (&dropped, false)]);
+        let storage = storage.with_keep_tags(index, vec!["keep".to_string()]);
+
+        let deleted = storage.enforce_storage_limit().await.unwrap();
+
+        assert_eq!(deleted, 1);
+        assert!(storage.load_frame(&kept).await.is_ok());
+        assert!(storage.load_frame(&dropped).await.is_err());
+        // Still over the 1 MB limit: the kept frame is never purged.
+        assert_eq!(storage.total_size_mb().await.unwrap(), 1);
+        assert_eq!(storage.enforce_storage_limit().await.unwrap(), 0);
+    }
+
+    #[tokio::test]
+    async fn identical_images_share_one_file() {
+        let (storage, temp) = create_test_storage().await;
+        let now = Utc::now();
+
+        let first = storage.save_frame(now, b"same-screen").await.unwrap();
+        let second = storage
+            .save_frame(now + chrono::Duration::seconds(1), b"same-screen")
+            .await
+            .unwrap();
+        let batch = storage
+            .save_frames_batch(vec![
+                (now, b"same-screen".to_vec()),
+                (now, b"other-screen".to_vec()),
+                (now, b"other-screen".to_vec()),
+            ])
+            .await;
+
+        assert_eq!(first, second);
+        assert_eq!(batch[0].as_ref().unwrap(), &first);
+        assert_eq!(batch[1].as_ref().unwrap(), batch[2].as_ref().unwrap());
+        assert_eq!(count_files_in_dir(&temp.path().join("frames")).await, 2);
+        assert_eq!(storage.load_frame(&second).await.unwrap(), b"same-screen");
+    }
+
+    #[tokio::test]
+    async fn shared_frame_file_survives_size_limit() {
+        let temp = TempDir::new().unwrap();
+        let index = Arc::new(SqliteStorage::open_in_memory(30).unwrap());
+        let storage = FrameFileStorage::new(temp.path().to_path_buf(), 1, 3650)
+            .await
+            .unwrap()
+            .with_index(Arc::clone(&index));
+        let shared_payload = vec![0u8; 3 * 1024 * 1024 / 2];
+        let metadata = |hour| oneshim_core::models::frame::FrameMetadata {
+            timestamp: utc(2020, 1, 1, hour),
+            trigger_type: "manual".to_string(),
+            app_name: "Code".to_string(),
+            window_title: "main.rs".to_string(),
+            resolution: (1920, 1080),
+            importance: 0.5,
+        };
+
+        let shared = storage
+            .save_frame(utc(2020, 1, 1, 9), &shared_payload)
+            .await
+            .unwrap();
+        let reused = storage
+            .save_frame(utc(2020, 1, 1, 10), &shared_payload)
+            .await
+            .unwrap();
+        assert_eq!(reused, shared);
+        for hour in [9, 10] {
+            index
+                .save_frame_metadata(&metadata(hour), Some(&shared.to_string_lossy()), None)
+                .unwrap();
+        }
+        let single = storage
+            .save_frame(utc(2020, 1, 1, 11), &vec![1u8; 3 * 1024 * 1024 / 2])
+            .await
+            .unwrap();
+        index
+            .save_frame_metadata(&metadata(11), Some(&single.to_string_lossy()), None)
+            .unwrap();
+
+        let deleted = storage.enforce_storage_limit().await.unwrap();
+
+        assert_eq!(deleted, 1);
+        assert!(storage.load_frame(&shared).await.is_ok());
+        assert!(storage.load_frame(&single).await.is_err());
+        assert_eq!(
+            index
+                .find_frame_file_by_sha256(&content_hash(&shared_payload))
+                .unwrap(),
+            Some(shared.to_string_lossy().into_owned())
+        );
+    }
+
+    #[tokio::test]
+    async fn recent_frame_deduplicated_onto_expired_shard_keeps_its_file() {
+        let temp = TempDir::new().unwrap();
+        let index = Arc::new(SqliteStorage::open_in_memory(30).unwrap());
+        let storage = FrameFileStorage::new(temp.path().to_path_buf(), 500, 30)
+            .await
+            .unwrap()
+            .with_index(Arc::clone(&index));
+        let metadata = |timestamp| oneshim_core::models::frame::FrameMetadata {
+            timestamp,
+            trigger_type: "manual".to_string(),
+            app_name: "Code".to_string(),
+            window_title: "main.rs".to_string(),
+            resolution: (1920, 1080),
+            importance: 0.5,
+        };
+
+        let old_time = utc(2020, 1, 1, 9);
+        let old = storage.save_frame(old_time, b"same-screen").await.unwrap();
+        index
+            .save_frame_metadata(&metadata(old_time), Some(&old.to_string_lossy()), None)
+            .unwrap();
+        let now = Utc::now();
+        let fresh = storage.save_frame(now, b"same-screen").await.unwrap();
+        assert_eq!(fresh, old, "fresh frame reuses the file in the 2020 shard");
+        index
+            .save_frame_metadata(&metadata(now), Some(&fresh.to_string_lossy()), None)
+            .unwrap();
+        let expired = storage
+            .save_frame(utc(2020, 1, 1, 10), b"only-old")
+            .await
+            .unwrap();
+        index
+            .save_frame_metadata(
+                &metadata(utc(2020, 1, 1, 10)),
+                Some(&expired.to_string_lossy()),
+                None,
+            )
+            .unwrap();
+
+        storage.enforce_retention().await.unwrap();
+
+        assert_eq!(storage.load_frame(&fresh).await.unwrap(), b"same-screen");
+        assert!(storage.load_frame(&expired).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn failed_write_leaves_no_partial_file() {
+        let temp = TempDir::new().unwrap();
+        // A non-empty directory at the target makes the final rename fail
+        // after the data has been written.
+        let target = temp.path().join("12-00-00-000.webp");
+        std::fs::create_dir_all(target.join("occupied")).unwrap();
+
+        assert!(write_atomic(&target, b"frame bytes").await.is_err());
+
+        let names: Vec<String> = std::fs::read_dir(temp.path())
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, vec!["12-00-00-000.webp".to_string()]);
+        assert!(target.is_dir());
+    }
+
+    #[tokio::test]
+    async fn saved_frame_leaves_no_partial_file() {
+        let (storage, temp) = create_test_storage().await;
+        let path = storage.save_frame(Utc::now(), b"frame").await.unwrap();
+
+        let dir = temp.path().join(&path).parent().unwrap().to_path_buf();
+        let mut files = Vec::new();
+        collect_files(&dir, &mut files).await.unwrap();
+        assert_eq!(files, vec![temp.path().join(&path)]);
+    }
+
+    #[tokio::test]
+    async fn retention_removes_frames_flagged_corrupt() {
+        let (storage, _temp) = create_test_storage().await;
+        let corrupt = storage.save_frame(Utc::now(), b"RIFF").await.unwrap();
+        let healthy = storage.save_frame(Utc::now(), b"healthy").await.unwrap();
+        let index = tagged_index(&[(&corrupt, false), (&healthy, false)]);
+        let storage = storage.with_index(Arc::clone(&index));
+        // tagged_index inserts in order, so the first frame has id 1.
+        let corrupt_id = 1;
+        index.mark_frame_file_corrupt(corrupt_id).unwrap();
+
+        assert_eq!(storage.enforce_retention().await.unwrap(), 1);
+        assert!(storage.load_frame(&corrupt).await.is_err());
+        assert_eq!(storage.load_frame(&healthy).await.unwrap(), b"healthy");
+        assert_eq!(index.get_frame_file_path(corrupt_id).unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn load_latest_frame_spans_layouts() {
+        let (storage, temp) = create_test_storage().await;
+
+        let legacy_dir = temp.path().join("frames/2020-01-01");
+        tokio::fs::create_dir_all(&legacy_dir).await.unwrap();
+        tokio::fs::write(legacy_dir.join("23-59-59-000.webp"), b"legacy")
+            .await
+            .unwrap();
+        storage
+            .save_frame(utc(2024, 3, 1, 9), b"sharded")
+            .await
+            .unwrap();
+
+        let latest = storage.load_latest_frame().await.unwrap().unwrap();
+        assert_eq!(latest.0, b"sharded");
+    }
+    /// Report free space from `free_mb` instead of the filesystem, uncached.
+    fn with_fake_free_space(
+        mut storage: FrameFileStorage,
+        free_mb: Arc<AtomicU64>,
+    ) -> FrameFileStorage {
+        storage.disk_cache.interval = Duration::ZERO;
+        storage.disk_cache.probe = Arc::new(move |_| free_mb.load(Ordering::Relaxed));
+        storage
+    }
+
+    #[test]
+    fn disk_decision_suspends_below_critical_and_resumes_with_hysteresis() {
+        assert_eq!(disk_decision(500, false, 50, 100), DiskDecision::Write);
+        assert_eq!(disk_decision(60, false, 50, 100), DiskDecision::Write);
+        assert_eq!(disk_decision(49, false, 50, 100), DiskDecision::Suspend);
+        assert_eq!(disk_decision(80, true, 50, 100), DiskDecision::Skip);
+        assert_eq!(disk_decision(100, true, 50, 100), DiskDecision::Resume);
+    }
+
+    #[tokio::test]
+    async fn low_disk_suspends_image_writes_but_metadata_still_saves() {
+        let (storage, temp) = create_test_storage().await;
+        let free_mb = Arc::new(AtomicU64::new(10_000));
+        let storage = with_fake_free_space(storage, Arc::clone(&free_mb));
+        let index = SqliteStorage::open_in_memory(30).unwrap();
+
+        let yesterday = storage
+            .save_frame(Utc::now() - chrono::Duration::days(1), b"old")
+            .await
+            .unwrap();
+        let today = storage.save_frame(Utc::now(), b"new").await.unwrap();
+
+        free_mb.store(10, Ordering::Relaxed);
+        let err = storage.save_frame(Utc::now(), b"frame").await.unwrap_err();
+        assert!(err.to_string().contains(WRITES_SUSPENDED_MSG), "{err}");
+        assert!(storage.image_writes_suspended());
+        assert_eq!(
+            storage.take_disk_pressure_change(),
+            Some(DiskPressureChange {
+                suspended: true,
+                free_mb: 10
+            })
+        );
+        // Reported once per transition.
+        assert!(storage.save_frame(Utc::now(), b"frame").await.is_err());
+        assert_eq!(storage.take_disk_pressure_change(), None);
+
+        // Eviction drops older shards but spares today's.
+        assert!(!temp.path().join(&yesterday).exists());
+        assert!(temp.path().join(&today).exists());
+
+        // The pipeline records the frame without a file path.
+        let metadata = oneshim_core::models::frame::FrameMetadata {
+            timestamp: Utc::now(),
+            trigger_type: "AppSwitch".to_string(),
+            app_name: "Code".to_string(),
+            window_title: "main.rs".to_string(),
+            resolution: (1920, 1080),
+            importance: 0.7,
+        };
+        let id = index.save_frame_metadata(&metadata, None, None).unwrap();
+        assert_eq!(index.get_frame_file_path(id).unwrap(), None);
+
+        // Between the thresholds writes stay off.
+        free_mb.store(80, Ordering::Relaxed);
+        assert!(storage.save_frame(Utc::now(), b"frame").await.is_err());
+
+        free_mb.store(200, Ordering::Relaxed);
+        storage.save_frame(Utc::now(), b"frame").await.unwrap();
+        assert!(!storage.image_writes_suspended());
+        assert_eq!(
+            storage.take_disk_pressure_change(),
+            Some(DiskPressureChange {
+                suspended: false,
+                free_mb: 200
+            })
+        );
+    }
 }
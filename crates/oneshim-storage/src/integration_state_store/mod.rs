@@ -53,7 +53,7 @@ impl FileIntegrationStateRegistry {
     fn load_or_default(path: &Path) -> Result<Self, StorageError> {
         match std::fs::read_to_string(path) {
             Ok(contents) => serde_json::from_str(&contents).map_err(|err| {
-                StorageError::Internal(format!("integration state registry parse: {err}"))
+                StorageError::Serialization(format!("integration state registry parse: {err}"))
             }),
             Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::new()),
             Err(err) => Err(err.into()),
@@ -62,7 +62,7 @@ impl FileIntegrationStateRegistry {
 
     fn save(&self, path: &Path) -> Result<(), StorageError> {
         let serialized = serde_json::to_string_pretty(self).map_err(|err| {
-            StorageError::Internal(format!("integration state registry serialization: {err}"))
+            StorageError::Serialization(format!("integration state registry serialization: {err}"))
         })?;
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
@@ -32,6 +32,9 @@
 pub mod error;
 pub use error::StorageError;
 
+pub mod backup_archive;
+pub use backup_archive::{create_backup, restore_backup, BackupManifest};
+
 pub mod encryption;
 pub mod env_secret_store;
 pub mod file_secret_store;
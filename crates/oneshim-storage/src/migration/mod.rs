@@ -2,7 +2,8 @@
 //!
 //! ## Directory Module Structure (ADR-003)
 //!
-//! - `mod.rs` — orchestrator (`run_migrations`, `get_version`, version constant)
+//! - `mod.rs` — orchestrator (`run_migrations_transactional`, `pending_migrations`,
+//!   `current_version`, version constant)
 //! - `v01_v08.rs` — foundation tables (events, frames, metrics, sessions, tags, edge intelligence)
 //! - `v09_v18.rs` — tiered memory, vectors, sync, IVF index, coaching engine, trigram FTS, app_meta
 //! - `v19_v21.rs` — app_meta, session audit log, AI sessions, gui_interactions type_confidence
@@ -16,6 +17,19 @@
 //!   RegimeManager persistence across restart (Phase 3 C3c/X6)
 //! - `v32_audit_log_command_id_index.rs` — partial index on audit_log.command_id
 //!   for O(log n) entries_by_command_id lookups (D25)
+//! - `v33_work_session_gaps.rs` — merged gap counters on work_sessions
+//! - `v34_frame_file_corrupt.rs` — corrupt image-file flag on frames
+//! - `v35_capture_latency.rs` — per-stage capture latency snapshots
+//! - `v36_workflow_executions.rs` — workflow execution history for replay
+//! - `v37_bookmarks.rs` — user bookmarks with optional label and frame
+//! - `v38_event_quarantine.rs` — quarantine columns for rejected upload events
+//! - `v39_frame_tag_source.rs` — manual/auto source on frame tags
+//! - `v40_frame_ocr_fts.rs` — FTS5 index over frame OCR text
+//! - `v41_daily_metrics.rs` — daily system metrics rollup
+//! - `v42_frame_sha256.rs` — content hash for frame image deduplication
+//! - `v43_tag_rules.rs` — app-name rules that tag new frames
+//! - `v44_frame_soft_delete.rs` — `deleted_at` for frames in the trash
+//! - `v45_event_idempotency_key.rs` — upload idempotency key per event row
 
 #[cfg(test)]
 mod tests;
@@ -32,11 +46,24 @@ mod v29;
 mod v30;
 mod v31_regime_manager_state;
 mod v32_audit_log_command_id_index;
+mod v33_work_session_gaps;
+mod v34_frame_file_corrupt;
+mod v35_capture_latency;
+mod v36_workflow_executions;
+mod v37_bookmarks;
+mod v38_event_quarantine;
+mod v39_frame_tag_source;
+mod v40_frame_ocr_fts;
+mod v41_daily_metrics;
+mod v42_frame_sha256;
+mod v43_tag_rules;
+mod v44_frame_soft_delete;
+mod v45_event_idempotency_key;
 
 use rusqlite::Connection;
 use tracing::{error, info, warn};
 
-pub(crate) const CURRENT_VERSION: u32 = 32;
+pub(crate) const CURRENT_VERSION: u32 = 45;
 
 /// Back up the database file before running schema migrations.
 fn backup_if_needed(conn: &Connection, current_version: u32) -> Option<std::path::PathBuf> {
@@ -70,6 +97,8 @@ fn backup_if_needed(conn: &Connection, current_version: u32) -> Option<std::path
 }
 
 /// Execute a single migration step inside a SAVEPOINT for rollback safety.
+/// Outside a caller's transaction the savepoint is itself the transaction,
+/// committed on release.
 fn run_migration_step(
     conn: &Connection,
     version: u32,
@@ -84,7 +113,11 @@ fn run_migration_step(
         }
         Err(e) => {
             warn!("migration v{version} failed, rolling back: {e}");
-            if let Err(rb_err) = conn.execute_batch(&format!("ROLLBACK TO SAVEPOINT {sp_name}")) {
+            // ROLLBACK TO keeps the savepoint open; release it so an outermost
+            // savepoint does not leave its transaction behind.
+            if let Err(rb_err) = conn.execute_batch(&format!(
+                "ROLLBACK TO SAVEPOINT {sp_name}; RELEASE SAVEPOINT {sp_name}"
+            )) {
                 error!(
                     version,
                     "ROLLBACK TO SAVEPOINT failed — database may be in inconsistent state: {rb_err}"
@@ -104,7 +137,148 @@ fn future_schema_error(current_version: u32) -> rusqlite::Error {
     )
 }
 
-pub fn run_migrations(conn: &Connection) -> Result<(), rusqlite::Error> {
+/// A schema migration, as reported by [`pending_migrations`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationInfo {
+    pub version: u32,
+    pub description: &'static str,
+}
+
+struct Migration {
+    info: MigrationInfo,
+    migrate: fn(&Connection) -> Result<(), rusqlite::Error>,
+}
+
+const fn step(
+    version: u32,
+    description: &'static str,
+    migrate: fn(&Connection) -> Result<(), rusqlite::Error>,
+) -> Migration {
+    Migration {
+        info: MigrationInfo {
+            version,
+            description,
+        },
+        migrate,
+    }
+}
+
+/// Every migration in version order; the last entry is `CURRENT_VERSION`.
+const MIGRATIONS: &[Migration] = &[
+    step(1, "events and frames", v01_v08::migrate_v1),
+    step(2, "frame file paths", v01_v08::migrate_v2),
+    step(3, "system metrics and hourly rollup", v01_v08::migrate_v3),
+    step(
+        4,
+        "process snapshots, idle periods, session stats",
+        v01_v08::migrate_v4,
+    ),
+    step(5, "tags and frame tags", v01_v08::migrate_v5),
+    step(
+        6,
+        "work sessions, interruptions, focus metrics",
+        v01_v08::migrate_v6,
+    ),
+    step(7, "composite query indexes", v01_v08::migrate_v7),
+    step(8, "suggestions", v01_v08::migrate_v8),
+    step(
+        9,
+        "calibration log, regimes, activity segments",
+        v09_v18::migrate_v9,
+    ),
+    step(
+        10,
+        "embedding vectors and weekly digests",
+        v09_v18::migrate_v10,
+    ),
+    step(11, "search FTS and daily digests", v09_v18::migrate_v11),
+    step(12, "regime overrides", v09_v18::migrate_v12),
+    step(13, "GUI interactions", v09_v18::migrate_v13),
+    step(
+        14,
+        "sync clocks, soft deletes, sync peers",
+        v09_v18::migrate_v14,
+    ),
+    // V15 is reserved for Sync 3b (lan_peer_pins)
+    step(15, "LAN peer pins", v09_v18::migrate_v15),
+    step(16, "IVF vector index", v09_v18::migrate_v16),
+    step(17, "coaching engine", v09_v18::migrate_v17),
+    step(18, "trigram search", v09_v18::migrate_v18),
+    step(19, "app metadata", v09_v18::migrate_v19),
+    step(20, "session audit log", v19_v21::migrate_v20),
+    step(21, "AI sessions and messages", v19_v21::migrate_v21),
+    step(22, "GUI interaction type confidence", v19_v21::migrate_v22),
+    step(23, "suggestion queue state", v22_v23::migrate_v23),
+    step(24, "feedback retries", v23_v24::migrate_v24),
+    step(25, "audit log", v25::migrate_v25),
+    step(26, "AI session titles", v26::migrate_v26),
+    step(27, "habit streaks", v27::migrate_v27),
+    step(28, "suggestion feedback columns", v28::migrate_v28),
+    step(29, "automation presets", v29::migrate_v29),
+    step(30, "frame annotations", v30::migrate_v30),
+    step(
+        31,
+        "regime manager state",
+        v31_regime_manager_state::migrate_v31,
+    ),
+    step(
+        32,
+        "audit log command id index",
+        v32_audit_log_command_id_index::migrate_v32,
+    ),
+    step(
+        33,
+        "work session gap counters",
+        v33_work_session_gaps::migrate_v33,
+    ),
+    step(
+        34,
+        "corrupt frame file flag",
+        v34_frame_file_corrupt::migrate_v34,
+    ),
+    step(
+        35,
+        "capture latency snapshots",
+        v35_capture_latency::migrate_v35,
+    ),
+    step(
+        36,
+        "workflow executions",
+        v36_workflow_executions::migrate_v36,
+    ),
+    step(37, "bookmarks", v37_bookmarks::migrate_v37),
+    step(38, "event quarantine", v38_event_quarantine::migrate_v38),
+    step(39, "frame tag source", v39_frame_tag_source::migrate_v39),
+    step(40, "frame OCR FTS", v40_frame_ocr_fts::migrate_v40),
+    step(41, "daily metrics rollup", v41_daily_metrics::migrate_v41),
+    step(42, "frame content hash", v42_frame_sha256::migrate_v42),
+    step(43, "tag rules", v43_tag_rules::migrate_v43),
+    step(44, "frame soft delete", v44_frame_soft_delete::migrate_v44),
+    step(
+        45,
+        "event idempotency key",
+        v45_event_idempotency_key::migrate_v45,
+    ),
+];
+
+/// Migrations `run_migrations_transactional` would apply, oldest first.
+/// Nothing is written, not even the `schema_version` table.
+pub fn pending_migrations(conn: &Connection) -> Result<Vec<MigrationInfo>, rusqlite::Error> {
+    let current = current_version(conn)?;
+    if current > CURRENT_VERSION {
+        return Err(future_schema_error(current));
+    }
+    Ok(MIGRATIONS
+        .iter()
+        .filter(|m| m.info.version > current)
+        .map(|m| m.info)
+        .collect())
+}
+
+/// Bring the schema up to `CURRENT_VERSION`. Each migration commits in its
+/// own transaction, so a failure rolls back only that step and leaves the
+/// database at the last version that applied cleanly.
+pub fn run_migrations_transactional(conn: &Connection) -> Result<(), rusqlite::Error> {
     conn.execute_batch(
         "CREATE TABLE IF NOT EXISTS schema_version (
             version INTEGER PRIMARY KEY,
@@ -112,7 +286,7 @@ pub fn run_migrations(conn: &Connection) -> Result<(), rusqlite::Error> {
         );",
     )?;
 
-    let current = get_version(conn)?;
+    let current = current_version(conn)?;
     info!("current schema version: {current}, target: {CURRENT_VERSION}");
 
     if current > CURRENT_VERSION {
@@ -124,112 +298,32 @@ pub fn run_migrations(conn: &Connection) -> Result<(), rusqlite::Error> {
         return Err(future_schema_error(current));
     }
 
-    if current < CURRENT_VERSION && backup_if_needed(conn, current).is_none() {
+    let pending = pending_migrations(conn)?;
+    if pending.is_empty() {
+        return Ok(());
+    }
+    info!(
+        "pending migrations: {}",
+        pending
+            .iter()
+            .map(|m| format!("v{} ({})", m.version, m.description))
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    if backup_if_needed(conn, current).is_none() {
         warn!("proceeding with migration without backup");
     }
 
-    if current < 1 {
-        run_migration_step(conn, 1, v01_v08::migrate_v1)?;
-    }
-    if current < 2 {
-        run_migration_step(conn, 2, v01_v08::migrate_v2)?;
-    }
-    if current < 3 {
-        run_migration_step(conn, 3, v01_v08::migrate_v3)?;
-    }
-    if current < 4 {
-        run_migration_step(conn, 4, v01_v08::migrate_v4)?;
-    }
-    if current < 5 {
-        run_migration_step(conn, 5, v01_v08::migrate_v5)?;
-    }
-    if current < 6 {
-        run_migration_step(conn, 6, v01_v08::migrate_v6)?;
-    }
-    if current < 7 {
-        run_migration_step(conn, 7, v01_v08::migrate_v7)?;
-    }
-    if current < 8 {
-        run_migration_step(conn, 8, v01_v08::migrate_v8)?;
-    }
-    if current < 9 {
-        run_migration_step(conn, 9, v09_v18::migrate_v9)?;
-    }
-    if current < 10 {
-        run_migration_step(conn, 10, v09_v18::migrate_v10)?;
-    }
-    if current < 11 {
-        run_migration_step(conn, 11, v09_v18::migrate_v11)?;
-    }
-    if current < 12 {
-        run_migration_step(conn, 12, v09_v18::migrate_v12)?;
-    }
-    if current < 13 {
-        run_migration_step(conn, 13, v09_v18::migrate_v13)?;
-    }
-    if current < 14 {
-        run_migration_step(conn, 14, v09_v18::migrate_v14)?;
-    }
-    // V15 is reserved for Sync 3b (lan_peer_pins)
-    if current < 15 {
-        run_migration_step(conn, 15, v09_v18::migrate_v15)?;
-    }
-    if current < 16 {
-        run_migration_step(conn, 16, v09_v18::migrate_v16)?;
-    }
-    if current < 17 {
-        run_migration_step(conn, 17, v09_v18::migrate_v17)?;
-    }
-    if current < 18 {
-        run_migration_step(conn, 18, v09_v18::migrate_v18)?;
-    }
-    if current < 19 {
-        run_migration_step(conn, 19, v09_v18::migrate_v19)?;
-    }
-    if current < 20 {
-        run_migration_step(conn, 20, v19_v21::migrate_v20)?;
-    }
-    if current < 21 {
-        run_migration_step(conn, 21, v19_v21::migrate_v21)?;
-    }
-    if current < 22 {
-        run_migration_step(conn, 22, v19_v21::migrate_v22)?;
-    }
-    if current < 23 {
-        run_migration_step(conn, 23, v22_v23::migrate_v23)?;
-    }
-    if current < 24 {
-        run_migration_step(conn, 24, v23_v24::migrate_v24)?;
-    }
-    if current < 25 {
-        run_migration_step(conn, 25, v25::migrate_v25)?;
-    }
-    if current < 26 {
-        run_migration_step(conn, 26, v26::migrate_v26)?;
-    }
-    if current < 27 {
-        run_migration_step(conn, 27, v27::migrate_v27)?;
-    }
-    if current < 28 {
-        run_migration_step(conn, 28, v28::migrate_v28)?;
-    }
-    if current < 29 {
-        run_migration_step(conn, 29, v29::migrate_v29)?;
-    }
-    if current < 30 {
-        run_migration_step(conn, 30, v30::migrate_v30)?;
-    }
-    if current < 31 {
-        run_migration_step(conn, 31, v31_regime_manager_state::migrate_v31)?;
-    }
-    if current < 32 {
-        run_migration_step(conn, 32, v32_audit_log_command_id_index::migrate_v32)?;
+    for migration in MIGRATIONS.iter().filter(|m| m.info.version > current) {
+        run_migration_step(conn, migration.info.version, migration.migrate)?;
     }
 
     Ok(())
 }
 
-fn get_version(conn: &Connection) -> Result<u32, rusqlite::Error> {
+/// The highest applied schema version; `0` for a fresh database.
+pub fn current_version(conn: &Connection) -> Result<u32, rusqlite::Error> {
     let result: Result<u32, _> = conn.query_row(
         "SELECT COALESCE(MAX(version), 0) FROM schema_version",
         [],
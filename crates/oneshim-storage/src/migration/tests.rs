@@ -6,7 +6,7 @@ use rusqlite::Connection;
 #[test]
 fn migration_all_versions() {
     let conn = Connection::open_in_memory().unwrap();
-    run_migrations(&conn).unwrap();
+    run_migrations_transactional(&conn).unwrap();
 
     let count: i64 = conn
         .query_row(
@@ -453,7 +453,7 @@ fn backup_created_when_migration_needed() {
 
     // Now run migrations — should create backup since version 0 < CURRENT_VERSION
     let conn = Connection::open(&db_path).unwrap();
-    run_migrations(&conn).unwrap();
+    run_migrations_transactional(&conn).unwrap();
     conn.close().unwrap();
 
     let backup_files: Vec<_> = std::fs::read_dir(dir.path())
@@ -490,8 +490,8 @@ fn backup_skipped_when_already_current() {
 #[test]
 fn migration_idempotent() {
     let conn = Connection::open_in_memory().unwrap();
-    run_migrations(&conn).unwrap();
-    run_migrations(&conn).unwrap(); // execution error none
+    run_migrations_transactional(&conn).unwrap();
+    run_migrations_transactional(&conn).unwrap(); // execution error none
     let version: u32 = conn
         .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
             row.get(0)
@@ -513,10 +513,56 @@ fn migration_rejects_future_schema_version() {
     ))
     .unwrap();
 
-    let err = run_migrations(&conn).expect_err("future schema version must be rejected");
+    let err =
+        run_migrations_transactional(&conn).expect_err("future schema version must be rejected");
     let message = err.to_string();
     assert!(
         message.contains("newer than this client supports"),
         "error should explain the version mismatch, got: {message}"
     );
 }
+
+#[test]
+fn pending_migrations_lists_unapplied_versions_without_writing() {
+    let conn = Connection::open_in_memory().unwrap();
+    assert_eq!(current_version(&conn).unwrap(), 0);
+
+    let pending = pending_migrations(&conn).unwrap();
+    let versions: Vec<u32> = pending.iter().map(|m| m.version).collect();
+    assert_eq!(versions, (1..=CURRENT_VERSION).collect::<Vec<_>>());
+    assert!(pending.iter().all(|m| !m.description.is_empty()));
+    let tables: i64 = conn
+        .query_row("SELECT COUNT(*) FROM sqlite_master", [], |row| row.get(0))
+        .unwrap();
+    assert_eq!(tables, 0, "dry run must not create schema_version");
+
+    run_migrations_transactional(&conn).unwrap();
+    assert_eq!(current_version(&conn).unwrap(), CURRENT_VERSION);
+    assert!(pending_migrations(&conn).unwrap().is_empty());
+}
+
+#[test]
+fn failed_migration_step_rolls_back_its_changes() {
+    fn half_done(conn: &Connection) -> Result<(), rusqlite::Error> {
+        conn.execute_batch(
+            "CREATE TABLE half_done (id INTEGER);
+             INSERT INTO schema_version (version) VALUES (99);",
+        )?;
+        Err(rusqlite::Error::InvalidQuery)
+    }
+
+    let conn = Connection::open_in_memory().unwrap();
+    run_migrations_transactional(&conn).unwrap();
+    assert!(run_migration_step(&conn, 99, half_done).is_err());
+
+    let leftover: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE name = 'half_done'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(leftover, 0);
+    assert_eq!(current_version(&conn).unwrap(), CURRENT_VERSION);
+    assert!(conn.is_autocommit(), "no transaction left open");
+}
@@ -94,7 +94,7 @@ mod tests {
     use super::*;
     use rusqlite::Connection;
 
-    /// Create the prerequisite schema_version table (normally created by run_migrations).
+    /// Create the prerequisite schema_version table (normally created by run_migrations_transactional).
     fn setup_schema_version(conn: &Connection) {
         conn.execute_batch(
             "CREATE TABLE IF NOT EXISTS schema_version (
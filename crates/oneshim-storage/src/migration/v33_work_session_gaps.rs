@@ -0,0 +1,73 @@
+//! Migration V33: track short gaps merged into a work session.
+//!
+//! When the focus analyzer bridges a brief switch away (alt-tab to a chat or
+//! reference app) instead of splitting the session, it bumps
+//! `merged_gap_count` and adds the gap length to `merged_gap_secs`.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v33(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE work_sessions ADD COLUMN merged_gap_count INTEGER NOT NULL DEFAULT 0;
+         ALTER TABLE work_sessions ADD COLUMN merged_gap_secs INTEGER NOT NULL DEFAULT 0;
+         INSERT OR IGNORE INTO schema_version (version) VALUES (33);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn setup_schema(conn: &Connection) {
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (32);
+             CREATE TABLE work_sessions (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 started_at TEXT NOT NULL,
+                 ended_at TEXT,
+                 primary_app TEXT NOT NULL,
+                 category TEXT NOT NULL,
+                 state TEXT NOT NULL DEFAULT 'active',
+                 interruption_count INTEGER NOT NULL DEFAULT 0,
+                 deep_work_secs INTEGER NOT NULL DEFAULT 0,
+                 duration_secs INTEGER NOT NULL DEFAULT 0
+             );
+             INSERT INTO work_sessions (started_at, primary_app, category)
+             VALUES ('2026-01-01T00:00:00Z', 'Code', 'Development');",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn migrate_v33_adds_gap_columns_with_zero_default() {
+        let conn = Connection::open_in_memory().unwrap();
+        setup_schema(&conn);
+        migrate_v33(&conn).unwrap();
+
+        let (count, secs): (i64, i64) = conn
+            .query_row(
+                "SELECT merged_gap_count, merged_gap_secs FROM work_sessions",
+                [],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .unwrap();
+        assert_eq!((count, secs), (0, 0));
+    }
+
+    #[test]
+    fn migrate_v33_records_version() {
+        let conn = Connection::open_in_memory().unwrap();
+        setup_schema(&conn);
+        migrate_v33(&conn).unwrap();
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 33);
+    }
+}
@@ -0,0 +1,58 @@
+//! Migration V34: flag frames whose image file could not be read.
+//!
+//! The web frames endpoint sets `file_corrupt` when it finds a truncated or
+//! undecryptable file; the next frame retention pass deletes the file and
+//! clears the frame's `file_path`.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v34(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE frames ADD COLUMN file_corrupt INTEGER NOT NULL DEFAULT 0;
+         CREATE INDEX IF NOT EXISTS idx_frames_file_corrupt ON frames(file_corrupt)
+             WHERE file_corrupt = 1;
+         INSERT OR IGNORE INTO schema_version (version) VALUES (34);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    fn setup_schema(conn: &Connection) {
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (33);
+             CREATE TABLE frames (
+                 id INTEGER PRIMARY KEY AUTOINCREMENT,
+                 timestamp TEXT NOT NULL,
+                 has_image INTEGER NOT NULL DEFAULT 0,
+                 file_path TEXT
+             );
+             INSERT INTO frames (timestamp, has_image, file_path)
+             VALUES ('2026-01-01T00:00:00Z', 1, 'frames/a.webp');",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn migrate_v34_adds_flag_cleared_by_default() {
+        let conn = Connection::open_in_memory().unwrap();
+        setup_schema(&conn);
+        migrate_v34(&conn).unwrap();
+
+        let corrupt: i64 = conn
+            .query_row("SELECT file_corrupt FROM frames", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(corrupt, 0);
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 34);
+    }
+}
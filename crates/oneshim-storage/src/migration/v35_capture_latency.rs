@@ -0,0 +1,55 @@
+//! Migration V35: periodic snapshots of per-stage capture latency.
+//!
+//! One row per stage per snapshot, holding the p50/p95/max of the frame
+//! processor's sample window at `recorded_at`.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v35(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS capture_latency (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             recorded_at TEXT NOT NULL,
+             stage TEXT NOT NULL,
+             samples INTEGER NOT NULL,
+             total_samples INTEGER NOT NULL,
+             p50_ms REAL NOT NULL,
+             p95_ms REAL NOT NULL,
+             max_ms REAL NOT NULL
+         );
+         CREATE INDEX IF NOT EXISTS idx_capture_latency_recorded_at
+             ON capture_latency(recorded_at);
+         INSERT OR IGNORE INTO schema_version (version) VALUES (35);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn migrate_v35_creates_table_and_records_version() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (34);",
+        )
+        .unwrap();
+        migrate_v35(&conn).unwrap();
+        migrate_v35(&conn).unwrap();
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM capture_latency", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 35);
+    }
+}
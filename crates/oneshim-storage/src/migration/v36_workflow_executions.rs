@@ -0,0 +1,60 @@
+//! Migration V36: workflow execution history.
+//!
+//! One row per preset run. The preset snapshot and full result are kept as
+//! JSON so a run can be replayed with the exact steps it executed; the
+//! scalar columns back history listing without decoding the blobs.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v36(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS workflow_executions (
+             execution_id TEXT PRIMARY KEY,
+             preset_id TEXT NOT NULL,
+             started_at TEXT NOT NULL,
+             success INTEGER NOT NULL,
+             steps_executed INTEGER NOT NULL,
+             total_steps INTEGER NOT NULL,
+             total_elapsed_ms INTEGER NOT NULL,
+             preset_json TEXT NOT NULL,
+             result_json TEXT NOT NULL,
+             replay_of TEXT
+         );
+         CREATE INDEX IF NOT EXISTS idx_workflow_executions_started_at
+             ON workflow_executions(started_at);
+         INSERT OR IGNORE INTO schema_version (version) VALUES (36);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn migrate_v36_creates_table_and_records_version() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (35);",
+        )
+        .unwrap();
+        migrate_v36(&conn).unwrap();
+        migrate_v36(&conn).unwrap();
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM workflow_executions", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(count, 0);
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 36);
+    }
+}
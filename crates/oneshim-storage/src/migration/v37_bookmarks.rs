@@ -0,0 +1,52 @@
+//! Migration V37: user bookmarks.
+//!
+//! `frame_id` is nullable: bookmarks can be recorded without a capture, and
+//! frame retention may remove the frame while the bookmark stays.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v37(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS bookmarks (
+             bookmark_id TEXT PRIMARY KEY,
+             timestamp TEXT NOT NULL,
+             label TEXT,
+             frame_id INTEGER,
+             app_name TEXT,
+             window_title TEXT
+         );
+         CREATE INDEX IF NOT EXISTS idx_bookmarks_timestamp ON bookmarks(timestamp);
+         INSERT OR IGNORE INTO schema_version (version) VALUES (37);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn migrate_v37_creates_table_and_records_version() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (36);",
+        )
+        .unwrap();
+        migrate_v37(&conn).unwrap();
+        migrate_v37(&conn).unwrap();
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM bookmarks", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(count, 0);
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 37);
+    }
+}
@@ -0,0 +1,59 @@
+//! Migration V38: quarantine for events the server keeps rejecting.
+//!
+//! A quarantined event is marked sent so it leaves the upload backlog and
+//! ages out with retention; `quarantine_reason` keeps the server's reason.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v38(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE events ADD COLUMN quarantine_reason TEXT;
+         ALTER TABLE events ADD COLUMN quarantined_at TEXT;
+         CREATE INDEX IF NOT EXISTS idx_events_quarantined ON events(quarantined_at)
+             WHERE quarantine_reason IS NOT NULL;
+         INSERT OR IGNORE INTO schema_version (version) VALUES (38);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn migrate_v38_adds_quarantine_columns() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (37);
+             CREATE TABLE events (
+                 event_id TEXT PRIMARY KEY,
+                 event_type TEXT NOT NULL,
+                 timestamp TEXT NOT NULL,
+                 data TEXT NOT NULL,
+                 is_sent INTEGER NOT NULL DEFAULT 0
+             );
+             INSERT INTO events (event_id, event_type, timestamp, data)
+             VALUES ('e1', 'context', '2026-01-01T00:00:00Z', '{}');",
+        )
+        .unwrap();
+        migrate_v38(&conn).unwrap();
+
+        let reason: Option<String> = conn
+            .query_row(
+                "SELECT quarantine_reason FROM events WHERE event_id = 'e1'",
+                [],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(reason.is_none());
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 38);
+    }
+}
@@ -0,0 +1,50 @@
+//! Migration V39: record who applied a frame tag.
+//!
+//! `source` is `manual` for tags the user added and `auto` for tags applied
+//! by an auto-tag rule at capture time.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v39(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE frame_tags ADD COLUMN source TEXT NOT NULL DEFAULT 'manual';
+         INSERT OR IGNORE INTO schema_version (version) VALUES (39);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn migrate_v39_marks_existing_frame_tags_manual() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (38);
+             CREATE TABLE frame_tags (
+                 frame_id INTEGER NOT NULL,
+                 tag_id INTEGER NOT NULL,
+                 created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                 PRIMARY KEY (frame_id, tag_id)
+             );
+             INSERT INTO frame_tags (frame_id, tag_id) VALUES (1, 1);",
+        )
+        .unwrap();
+        migrate_v39(&conn).unwrap();
+
+        let source: String = conn
+            .query_row("SELECT source FROM frame_tags", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(source, "manual");
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 39);
+    }
+}
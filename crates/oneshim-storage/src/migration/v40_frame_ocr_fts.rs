@@ -0,0 +1,92 @@
+//! Migration V40: FTS5 index over frame OCR text.
+//!
+//! `frames_ocr_fts` keeps its own copy of the plain OCR text (rowid = frame
+//! id) because `frames.ocr_text` may hold zstd-compressed BLOBs. The trigram
+//! tokenizer matches inside Korean words, which rarely split on the
+//! boundaries unicode61 would use; builds without it fall back to unicode61.
+//! Rows are added by the frame insert paths and removed by a delete trigger.
+
+use rusqlite::Connection;
+use tracing::warn;
+
+pub(super) fn migrate_v40(conn: &Connection) -> rusqlite::Result<()> {
+    let created = conn
+        .execute_batch(
+            "CREATE VIRTUAL TABLE IF NOT EXISTS frames_ocr_fts
+                 USING fts5(ocr_text, tokenize='trigram');",
+        )
+        .or_else(|e| {
+            warn!("trigram tokenizer unavailable for frames_ocr_fts, using unicode61: {e}");
+            conn.execute_batch(
+                "CREATE VIRTUAL TABLE IF NOT EXISTS frames_ocr_fts USING fts5(ocr_text);",
+            )
+        });
+
+    match created {
+        Ok(()) => {
+            conn.execute_batch(
+                "CREATE TRIGGER IF NOT EXISTS frames_ocr_fts_delete AFTER DELETE ON frames
+                 BEGIN
+                     DELETE FROM frames_ocr_fts WHERE rowid = old.id;
+                 END;
+                 INSERT INTO frames_ocr_fts (rowid, ocr_text)
+                     SELECT id, ocr_text FROM frames
+                     WHERE typeof(ocr_text) = 'text' AND ocr_text <> '';",
+            )?;
+            // Compressed rows need `decompress_text`, which only storage
+            // connections register.
+            if let Err(e) = conn.execute_batch(
+                "INSERT INTO frames_ocr_fts (rowid, ocr_text)
+                     SELECT id, decompress_text(ocr_text) FROM frames
+                     WHERE typeof(ocr_text) = 'blob';",
+            ) {
+                warn!("compressed OCR text not backfilled into frames_ocr_fts: {e}");
+            }
+        }
+        Err(e) => warn!("frames_ocr_fts creation skipped (FTS5 not available): {e}"),
+    }
+
+    conn.execute_batch("INSERT OR IGNORE INTO schema_version (version) VALUES (40);")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn migrate_v40_backfills_plain_ocr_text_and_follows_deletes() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (39);
+             CREATE TABLE frames (id INTEGER PRIMARY KEY, ocr_text TEXT);
+             INSERT INTO frames (id, ocr_text) VALUES
+                 (1, 'invoice #42 due'), (2, NULL), (3, ''), (4, '견적서 송부');",
+        )
+        .unwrap();
+        migrate_v40(&conn).unwrap();
+
+        let indexed: Vec<i64> = conn
+            .prepare("SELECT rowid FROM frames_ocr_fts ORDER BY rowid")
+            .unwrap()
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(indexed, [1, 4]);
+
+        conn.execute("DELETE FROM frames WHERE id = 1", []).unwrap();
+        let remaining: i64 = conn
+            .query_row("SELECT COUNT(*) FROM frames_ocr_fts", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(remaining, 1);
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 40);
+    }
+}
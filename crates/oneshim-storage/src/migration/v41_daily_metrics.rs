@@ -0,0 +1,21 @@
+//! Migration V41: daily system metrics rollup.
+//!
+//! One row per UTC day, folded from `system_metrics_hourly` so week- and
+//! month-long charts read a few dozen rows instead of every hour.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v41(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS daily_metrics (
+             date TEXT PRIMARY KEY,
+             cpu_avg REAL NOT NULL,
+             cpu_max REAL NOT NULL,
+             memory_avg INTEGER NOT NULL,
+             memory_max INTEGER NOT NULL,
+             sample_count INTEGER NOT NULL
+         );
+         INSERT OR IGNORE INTO schema_version (version) VALUES (41);",
+    )?;
+    Ok(())
+}
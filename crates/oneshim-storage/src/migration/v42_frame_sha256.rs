@@ -0,0 +1,16 @@
+//! Migration V42: content hash of each frame's image file.
+//!
+//! Frames whose image bytes hash the same share one `file_path` instead of
+//! storing another copy. Existing rows keep a NULL hash.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v42(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE frames ADD COLUMN sha256 TEXT;
+         CREATE INDEX IF NOT EXISTS idx_frames_sha256 ON frames(sha256)
+             WHERE sha256 IS NOT NULL;
+         INSERT OR IGNORE INTO schema_version (version) VALUES (42);",
+    )?;
+    Ok(())
+}
@@ -0,0 +1,21 @@
+//! Migration V43: dashboard-managed tag rules.
+//!
+//! Each rule gives a new frame `tag_id` when its app name contains
+//! `app_pattern`, ignoring case. Applied tags are recorded as `auto`.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v43(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS tag_rules (
+             id INTEGER PRIMARY KEY AUTOINCREMENT,
+             app_pattern TEXT NOT NULL,
+             tag_id INTEGER NOT NULL,
+             created_at TEXT NOT NULL DEFAULT (datetime('now')),
+             FOREIGN KEY (tag_id) REFERENCES tags(id) ON DELETE CASCADE
+         );
+         CREATE INDEX IF NOT EXISTS idx_tag_rules_tag ON tag_rules(tag_id);
+         INSERT OR IGNORE INTO schema_version (version) VALUES (43);",
+    )?;
+    Ok(())
+}
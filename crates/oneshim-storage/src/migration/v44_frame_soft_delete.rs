@@ -0,0 +1,16 @@
+//! Migration V44: soft-deleted frames.
+//!
+//! Frames deleted from the dashboard get `deleted_at` set instead of being
+//! removed; frame queries skip them until they are restored or purged.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v44(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE frames ADD COLUMN deleted_at TEXT;
+         CREATE INDEX IF NOT EXISTS idx_frames_deleted_at ON frames(deleted_at)
+             WHERE deleted_at IS NOT NULL;
+         INSERT OR IGNORE INTO schema_version (version) VALUES (44);",
+    )?;
+    Ok(())
+}
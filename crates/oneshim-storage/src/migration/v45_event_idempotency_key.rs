@@ -0,0 +1,64 @@
+//! Migration V45: persisted upload idempotency keys.
+//!
+//! Each event row gets a random key at insert time so every upload of the
+//! row, including re-sends after a restart, carries the same key. Rows still
+//! waiting to upload are backfilled; sent rows never upload again.
+
+use rusqlite::Connection;
+
+pub(super) fn migrate_v45(conn: &Connection) -> rusqlite::Result<()> {
+    conn.execute_batch(
+        "ALTER TABLE events ADD COLUMN idempotency_key TEXT;
+         UPDATE events SET idempotency_key = lower(hex(randomblob(16))) WHERE is_sent = 0;
+         INSERT OR IGNORE INTO schema_version (version) VALUES (45);",
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rusqlite::Connection;
+
+    #[test]
+    fn migrate_v45_backfills_keys_for_pending_rows_only() {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "CREATE TABLE schema_version (version INTEGER PRIMARY KEY);
+             INSERT INTO schema_version VALUES (44);
+             CREATE TABLE events (
+                 event_id TEXT PRIMARY KEY,
+                 event_type TEXT NOT NULL,
+                 timestamp TEXT NOT NULL,
+                 data TEXT NOT NULL,
+                 is_sent INTEGER NOT NULL DEFAULT 0
+             );
+             INSERT INTO events (event_id, event_type, timestamp, data, is_sent) VALUES
+                 ('pending-1', 'context', '2026-01-01T00:00:00Z', '{}', 0),
+                 ('pending-2', 'context', '2026-01-01T00:00:01Z', '{}', 0),
+                 ('sent', 'context', '2026-01-01T00:00:02Z', '{}', 1);",
+        )
+        .unwrap();
+        migrate_v45(&conn).unwrap();
+
+        let key = |id: &str| -> Option<String> {
+            conn.query_row(
+                "SELECT idempotency_key FROM events WHERE event_id = ?1",
+                [id],
+                |row| row.get(0),
+            )
+            .unwrap()
+        };
+        let first = key("pending-1").expect("pending row backfilled");
+        assert_eq!(first.len(), 32);
+        assert_ne!(Some(first), key("pending-2"));
+        assert!(key("sent").is_none());
+
+        let version: u32 = conn
+            .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                row.get(0)
+            })
+            .unwrap();
+        assert_eq!(version, 45);
+    }
+}
@@ -4,118 +4,136 @@ use async_trait::async_trait;
 use oneshim_core::error::CoreError;
 use oneshim_core::models::tiered_memory::Regime;
 use oneshim_core::ports::regime_storage::RegimeStoragePort;
-use rusqlite::{Connection, OptionalExtension};
-use std::sync::{Arc, Mutex};
+use rusqlite::OptionalExtension;
 
-/// Uses `std::sync::Mutex` (not `parking_lot::Mutex`) so the same
-/// `Arc<Mutex<Connection>>` returned by `SqliteStorage::connection_arc()`
-/// can be shared — the adapter family elsewhere in `oneshim-storage`
-/// (vector store, session storage, etc.) picked `std::sync::Mutex`.
+use crate::sqlite::SqlitePool;
+
+/// Draws connections from the pool returned by
+/// `SqliteStorage::connection_pool()`, like the other adapters in
+/// `oneshim-storage` (vector store, sync extractor/merger).
 pub struct SqliteRegimeManagerStateStore {
-    conn: Arc<Mutex<Connection>>,
+    pool: SqlitePool,
 }
 
 impl SqliteRegimeManagerStateStore {
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
-        Self { conn }
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+}
+
+fn join_error(e: tokio::task::JoinError) -> CoreError {
+    CoreError::Internal {
+        code: oneshim_core::error_codes::InternalCode::Generic,
+        message: format!("spawn_blocking join error: {e}"),
     }
 }
 
 #[async_trait]
 impl RegimeStoragePort for SqliteRegimeManagerStateStore {
     async fn load_all(&self) -> Result<Vec<Regime>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Internal {
-            code: oneshim_core::error_codes::InternalCode::Generic,
-            message: format!("SQLite lock poisoned: {e}"),
-        })?;
-        let payload: Option<String> = conn
-            .query_row(
-                "SELECT payload FROM regime_manager_state WHERE id = 0",
-                [],
-                |r| r.get(0),
-            )
-            .optional()
-            .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
-                message: e.to_string(),
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let conn = pool.get().map_err(|e| CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Lock,
+                message: format!("SQLite connection unavailable: {e}"),
             })?;
+            let payload: Option<String> = conn
+                .query_row(
+                    "SELECT payload FROM regime_manager_state WHERE id = 0",
+                    [],
+                    |r| r.get(0),
+                )
+                .optional()
+                .map_err(|e| CoreError::Storage {
+                    code: oneshim_core::error_codes::StorageCode::Database,
+                    message: e.to_string(),
+                })?;
 
-        match payload {
-            Some(json) => match serde_json::from_str::<Vec<Regime>>(&json) {
-                Ok(regimes) => Ok(regimes),
-                Err(e) => {
-                    tracing::error!(
-                        error = %e,
-                        "regime_manager_state payload failed to parse; quarantining to payload_backup and starting fresh. Recover via manual inspection of the backup column."
-                    );
-                    if let Err(qe) = conn.execute(
-                        "UPDATE regime_manager_state
-                            SET payload_backup = payload,
-                                payload_backup_at = datetime('now'),
-                                payload = '[]',
-                                updated_at = datetime('now')
-                          WHERE id = 0",
-                        [],
-                    ) {
-                        // Second log line — DO NOT swallow. If quarantine
-                        // itself fails (disk full, WAL corruption), the only
-                        // user-visible signal that their curated state is
-                        // unrecoverable is this line. ADR-018 explicitly
-                        // rejects silent data loss.
+            match payload {
+                Some(json) => match serde_json::from_str::<Vec<Regime>>(&json) {
+                    Ok(regimes) => Ok(regimes),
+                    Err(e) => {
                         tracing::error!(
-                            error = %qe,
-                            "regime_manager_state quarantine UPDATE failed — corrupt payload may be lost"
+                            error = %e,
+                            "regime_manager_state payload failed to parse; quarantining to payload_backup and starting fresh. Recover via manual inspection of the backup column."
                         );
+                        if let Err(qe) = conn.execute(
+                            "UPDATE regime_manager_state
+                                SET payload_backup = payload,
+                                    payload_backup_at = datetime('now'),
+                                    payload = '[]',
+                                    updated_at = datetime('now')
+                              WHERE id = 0",
+                            [],
+                        ) {
+                            // Second log line — DO NOT swallow. If quarantine
+                            // itself fails (disk full, WAL corruption), the only
+                            // user-visible signal that their curated state is
+                            // unrecoverable is this line. ADR-018 explicitly
+                            // rejects silent data loss.
+                            tracing::error!(
+                                error = %qe,
+                                "regime_manager_state quarantine UPDATE failed — corrupt payload may be lost"
+                            );
+                        }
+                        Ok(Vec::new())
                     }
-                    Ok(Vec::new())
-                }
-            },
-            None => Ok(Vec::new()),
-        }
+                },
+                None => Ok(Vec::new()),
+            }
+
+        })
+        .await
+        .map_err(join_error)?
     }
 
     async fn save_all(&self, regimes: &[Regime]) -> Result<(), CoreError> {
-        let json = serde_json::to_string(regimes).map_err(|e| CoreError::Internal {
-            code: oneshim_core::error_codes::InternalCode::Generic,
-            message: e.to_string(),
-        })?;
-        let conn = self.conn.lock().map_err(|e| CoreError::Internal {
-            code: oneshim_core::error_codes::InternalCode::Generic,
-            message: format!("SQLite lock poisoned: {e}"),
-        })?;
-        conn.execute(
-            "INSERT OR REPLACE INTO regime_manager_state
-                (id, payload, payload_backup, payload_backup_at, updated_at)
-             VALUES (
-                0, ?1,
-                (SELECT payload_backup FROM regime_manager_state WHERE id = 0),
-                (SELECT payload_backup_at FROM regime_manager_state WHERE id = 0),
-                datetime('now')
-             )",
-            rusqlite::params![json],
-        )
-        .map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+        let json = serde_json::to_string(regimes).map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Serialization,
             message: e.to_string(),
         })?;
-        Ok(())
+        let pool = self.pool.clone();
+        tokio::task::spawn_blocking(move || {
+            let conn = pool.get().map_err(|e| CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Lock,
+                message: format!("SQLite connection unavailable: {e}"),
+            })?;
+            conn.execute(
+                "INSERT OR REPLACE INTO regime_manager_state
+                    (id, payload, payload_backup, payload_backup_at, updated_at)
+                 VALUES (
+                    0, ?1,
+                    (SELECT payload_backup FROM regime_manager_state WHERE id = 0),
+                    (SELECT payload_backup_at FROM regime_manager_state WHERE id = 0),
+                    datetime('now')
+                 )",
+                rusqlite::params![json],
+            )
+            .map_err(|e| CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Database,
+                message: e.to_string(),
+            })?;
+            Ok(())
+        })
+        .await
+        .map_err(join_error)?
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sqlite::SqliteStorage;
     use chrono::Utc;
     use oneshim_core::models::tiered_memory::{
         Regime, RegimeFeatures, RegimeStatus, TriggerParams,
     };
     use tempfile::TempDir;
 
-    fn open_db() -> (TempDir, Arc<Mutex<Connection>>) {
+    fn open_db() -> (TempDir, SqlitePool) {
         let dir = tempfile::tempdir().unwrap();
-        let conn = Connection::open(dir.path().join("t.db")).unwrap();
-        crate::migration::run_migrations(&conn).unwrap();
-        (dir, Arc::new(Mutex::new(conn)))
+        let storage = SqliteStorage::open(&dir.path().join("t.db"), 30, None).unwrap();
+        (dir, storage.connection_pool())
     }
 
     fn sample_regime(id: &str) -> Regime {
@@ -173,7 +191,7 @@ mod tests {
     async fn malformed_payload_quarantines_and_starts_fresh() {
         let (_d, conn) = open_db();
         {
-            let c = conn.lock().unwrap();
+            let c = conn.get().unwrap();
             c.execute(
                 "INSERT OR REPLACE INTO regime_manager_state (id, payload, updated_at) VALUES (0, '{not:valid json', datetime('now'))",
                 [],
@@ -185,7 +203,7 @@ mod tests {
         assert!(result.is_ok(), "quarantine must not return Err");
         assert_eq!(result.unwrap().len(), 0, "fresh start expected");
 
-        let c = conn.lock().unwrap();
+        let c = conn.get().unwrap();
         let (backup, backup_at): (Option<String>, Option<String>) = c
             .query_row(
                 "SELECT payload_backup, payload_backup_at FROM regime_manager_state WHERE id = 0",
@@ -207,22 +225,18 @@ mod tests {
 
         // Session 1: save.
         {
-            let conn = Connection::open(&db_path).unwrap();
-            crate::migration::run_migrations(&conn).unwrap();
-            let s = SqliteRegimeManagerStateStore::new(Arc::new(Mutex::new(conn)));
+            let storage = SqliteStorage::open(&db_path, 30, None).unwrap();
+            let s = SqliteRegimeManagerStateStore::new(storage.connection_pool());
             s.save_all(&[sample_regime("a"), sample_regime("b")])
                 .await
                 .unwrap();
         }
 
-        // Session 2: reload. run_migrations is idempotent (checks
-        // schema_version and short-circuits), so this documents intent
-        // — and protects against a future PRAGMA-on-open helper that
-        // would otherwise silently skip.
+        // Session 2: reload. Reopening runs migrations again; they are
+        // idempotent (checks schema_version and short-circuits).
         {
-            let conn = Connection::open(&db_path).unwrap();
-            crate::migration::run_migrations(&conn).unwrap();
-            let s = SqliteRegimeManagerStateStore::new(Arc::new(Mutex::new(conn)));
+            let storage = SqliteStorage::open(&db_path, 30, None).unwrap();
+            let s = SqliteRegimeManagerStateStore::new(storage.connection_pool());
             let loaded = s.load_all().await.unwrap();
             assert_eq!(loaded.len(), 2);
             assert_eq!(loaded[0].regime_id, "a");
@@ -7,9 +7,9 @@ use super::SqliteStorage;
 impl AnnotationStorage for SqliteStorage {
     /// List all annotations attached to a given frame, ordered by creation time.
     fn list_annotations(&self, frame_id: i64) -> Result<Vec<FrameAnnotation>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         let mut stmt = conn
@@ -19,7 +19,7 @@ impl AnnotationStorage for SqliteStorage {
                  WHERE frame_id = ?1
                  ORDER BY created_at",
             )
-            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("prepare: {e}") })?;
+            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("prepare: {e}") })?;
 
         let rows = stmt
             .query_map([frame_id], |row| {
@@ -37,14 +37,14 @@ impl AnnotationStorage for SqliteStorage {
                 })
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("query: {e}"),
             })?;
 
         let mut result = Vec::new();
         for row in rows {
             let row = row.map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("row: {e}"),
             })?;
             result.push(row.into_annotation()?);
@@ -54,9 +54,9 @@ impl AnnotationStorage for SqliteStorage {
 
     /// Persist a new annotation to the `frame_annotations` table.
     fn save_annotation(&self, annotation: &FrameAnnotation) -> Result<(), CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         let created_at_str = annotation.created_at.to_rfc3339();
@@ -78,16 +78,16 @@ impl AnnotationStorage for SqliteStorage {
                 created_at_str,
             ],
         )
-        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("insert: {e}") })?;
+        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("insert: {e}") })?;
 
         Ok(())
     }
 
     /// Delete an annotation by ID. No error if the ID does not exist.
     fn delete_annotation(&self, annotation_id: &str) -> Result<(), CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         conn.execute(
@@ -95,7 +95,7 @@ impl AnnotationStorage for SqliteStorage {
             [annotation_id],
         )
         .map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Database,
             message: format!("delete: {e}"),
         })?;
 
@@ -122,7 +122,7 @@ impl AnnotationRow {
         let created_at = chrono::DateTime::parse_from_rfc3339(&self.created_at)
             .map(|dt| dt.with_timezone(&chrono::Utc))
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("parse created_at: {e}"),
             })?;
 
@@ -0,0 +1,160 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use oneshim_core::error::CoreError;
+use oneshim_core::error_codes::StorageCode;
+use oneshim_core::models::bookmark::Bookmark;
+use oneshim_core::ports::bookmark_storage::BookmarkStorage;
+
+use super::SqliteStorage;
+
+/// Fixed-width UTC timestamps so `timestamp` compares correctly as text.
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+fn storage_err(stage: &str, e: impl std::fmt::Display) -> CoreError {
+    CoreError::Storage {
+        code: StorageCode::Database,
+        message: format!("{stage}: {e}"),
+    }
+}
+
+impl BookmarkStorage for SqliteStorage {
+    /// Insert a bookmark into the `bookmarks` table.
+    fn save_bookmark(&self, bookmark: &Bookmark) -> Result<(), CoreError> {
+        let conn = self.pool.get().map_err(|e| storage_err("connection", e))?;
+        conn.execute(
+            "INSERT INTO bookmarks
+             (bookmark_id, timestamp, label, frame_id, app_name, window_title)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            rusqlite::params![
+                bookmark.bookmark_id,
+                timestamp(bookmark.timestamp),
+                bookmark.label,
+                bookmark.frame_id,
+                bookmark.app_name,
+                bookmark.window_title,
+            ],
+        )
+        .map_err(|e| storage_err("insert", e))?;
+
+        Ok(())
+    }
+
+    /// Bookmarks recorded within `[from, to]`, oldest first.
+    fn get_bookmarks(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Bookmark>, CoreError> {
+        let conn = self.pool.get().map_err(|e| storage_err("connection", e))?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT bookmark_id, timestamp, label, frame_id, app_name, window_title
+                 FROM bookmarks
+                 WHERE timestamp >= ?1 AND timestamp <= ?2
+                 ORDER BY timestamp",
+            )
+            .map_err(|e| storage_err("prepare", e))?;
+
+        let rows = stmt
+            .query_map(rusqlite::params![timestamp(from), timestamp(to)], |row| {
+                Ok(BookmarkRow {
+                    bookmark_id: row.get(0)?,
+                    timestamp: row.get(1)?,
+                    label: row.get(2)?,
+                    frame_id: row.get(3)?,
+                    app_name: row.get(4)?,
+                    window_title: row.get(5)?,
+                })
+            })
+            .map_err(|e| storage_err("query", e))?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            let row = row.map_err(|e| storage_err("row", e))?;
+            result.push(row.into_bookmark()?);
+        }
+        Ok(result)
+    }
+}
+
+/// Internal helper struct for reading bookmark rows from SQLite.
+struct BookmarkRow {
+    bookmark_id: String,
+    timestamp: String,
+    label: Option<String>,
+    frame_id: Option<i64>,
+    app_name: Option<String>,
+    window_title: Option<String>,
+}
+
+impl BookmarkRow {
+    fn into_bookmark(self) -> Result<Bookmark, CoreError> {
+        let timestamp = DateTime::parse_from_rfc3339(&self.timestamp)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| storage_err("parse timestamp", e))?;
+
+        Ok(Bookmark {
+            bookmark_id: self.bookmark_id,
+            timestamp,
+            label: self.label,
+            frame_id: self.frame_id,
+            app_name: self.app_name,
+            window_title: self.window_title,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, SubsecRound};
+
+    fn bookmark(label: Option<&str>, at: DateTime<Utc>) -> Bookmark {
+        Bookmark {
+            // Stored at microsecond precision.
+            timestamp: at.trunc_subsecs(6),
+            ..Bookmark::new(label.map(str::to_string))
+        }
+    }
+
+    #[test]
+    fn bookmark_roundtrip_keeps_label_and_timestamp() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let at = Utc::now() - Duration::minutes(5);
+        let mut saved = bookmark(Some("bug reproduced here"), at);
+        saved.frame_id = Some(42);
+        saved.app_name = Some("Firefox".to_string());
+        storage.save_bookmark(&saved).unwrap();
+
+        let found = storage
+            .get_bookmarks(at - Duration::seconds(1), Utc::now())
+            .unwrap();
+        assert_eq!(found, vec![saved]);
+    }
+
+    #[test]
+    fn get_bookmarks_filters_window_oldest_first() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let now = Utc::now();
+        storage
+            .save_bookmark(&bookmark(Some("old"), now - Duration::days(2)))
+            .unwrap();
+        storage
+            .save_bookmark(&bookmark(Some("second"), now - Duration::minutes(10)))
+            .unwrap();
+        storage
+            .save_bookmark(&bookmark(None, now - Duration::minutes(30)))
+            .unwrap();
+
+        let found = storage
+            .get_bookmarks(now - Duration::hours(1), now)
+            .unwrap();
+        let labels: Vec<_> = found.iter().map(|b| b.label.as_deref()).collect();
+        assert_eq!(labels, [None, Some("second")]);
+        assert!(storage
+            .get_bookmarks(now + Duration::hours(1), now + Duration::hours(2))
+            .unwrap()
+            .is_empty());
+    }
+}
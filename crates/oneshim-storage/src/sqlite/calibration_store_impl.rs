@@ -22,15 +22,15 @@ impl CalibrationWriter for SqliteStorage {
             return Ok(());
         }
 
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
 
         let tx = conn
             .unchecked_transaction()
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to begin transaction: {e}"),
             })?;
 
@@ -44,7 +44,7 @@ impl CalibrationWriter for SqliteStorage {
                      VALUES (?1, ?2, ?3)",
                 )
                 .map_err(|e| CoreError::Storage {
-                    code: oneshim_core::error_codes::StorageCode::Failed,
+                    code: oneshim_core::error_codes::StorageCode::Database,
                     message: format!("prepare snapshot stmt: {e}"),
                 })?;
 
@@ -59,7 +59,7 @@ impl CalibrationWriter for SqliteStorage {
                     insert_snapshot
                         .execute(params![entry.params_version_id, "default", json])
                         .map_err(|e| CoreError::Storage {
-                            code: oneshim_core::error_codes::StorageCode::Failed,
+                            code: oneshim_core::error_codes::StorageCode::Database,
                             message: format!("insert params snapshot: {e}"),
                         })?;
                 }
@@ -78,7 +78,7 @@ impl CalibrationWriter for SqliteStorage {
                      VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
                 )
                 .map_err(|e| CoreError::Storage {
-                    code: oneshim_core::error_codes::StorageCode::Failed,
+                    code: oneshim_core::error_codes::StorageCode::Database,
                     message: format!("prepare calibration stmt: {e}"),
                 })?;
 
@@ -103,14 +103,14 @@ impl CalibrationWriter for SqliteStorage {
                     entry.is_noise as i32,
                 ])
                 .map_err(|e| CoreError::Storage {
-                    code: oneshim_core::error_codes::StorageCode::Failed,
+                    code: oneshim_core::error_codes::StorageCode::Database,
                     message: format!("insert calibration entry: {e}"),
                 })?;
             }
         }
 
         tx.commit().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Database,
             message: format!("commit calibration batch: {e}"),
         })?;
 
@@ -121,9 +121,9 @@ impl CalibrationWriter for SqliteStorage {
     fn flag_noise_range(&self, window: &TimeWindow) -> Result<u64, CoreError> {
         let from = window.start;
         let to = window.end;
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
 
         let updated = conn
@@ -133,7 +133,7 @@ impl CalibrationWriter for SqliteStorage {
                 params![from.to_rfc3339(), to.to_rfc3339()],
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("flag noise range: {e}"),
             })?;
 
@@ -177,16 +177,16 @@ impl CalibrationReader for SqliteStorage {
 
             let mut stmt = conn
                 .prepare(sql)
-                .map_err(|e| StorageError::Internal(format!("prepare get_entries: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("prepare get_entries: {e}")))?;
 
             let rows = stmt
                 .query_map(params![from_str, to_str], map_calibration_row)
-                .map_err(|e| StorageError::Internal(format!("query calibration entries: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("query calibration entries: {e}")))?;
 
             let mut entries = Vec::new();
             for row_result in rows {
                 let entry = row_result
-                    .map_err(|e| StorageError::Internal(format!("read calibration row: {e}")))?;
+                    .map_err(|e| StorageError::Database(format!("read calibration row: {e}")))?;
                 entries.push(entry);
             }
             Ok(entries)
@@ -208,13 +208,13 @@ impl CalibrationReader for SqliteStorage {
                     "DELETE FROM calibration_log WHERE timestamp < ?1",
                     params![cutoff_str],
                 )
-                .map_err(|e| StorageError::Internal(format!("retention age delete: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("retention age delete: {e}")))?;
             total_deleted += deleted_by_age as u64;
 
             // 2. If remaining rows exceed max_rows, delete oldest
             let remaining: i64 = conn
                 .query_row("SELECT COUNT(*) FROM calibration_log", [], |row| row.get(0))
-                .map_err(|e| StorageError::Internal(format!("count calibration rows: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("count calibration rows: {e}")))?;
 
             if remaining as u64 > max_rows {
                 let excess = remaining as u64 - max_rows;
@@ -225,7 +225,7 @@ impl CalibrationReader for SqliteStorage {
                          )",
                         params![excess as i64],
                     )
-                    .map_err(|e| StorageError::Internal(format!("retention count delete: {e}")))?;
+                    .map_err(|e| StorageError::Database(format!("retention count delete: {e}")))?;
                 total_deleted += deleted_by_count as u64;
             }
 
@@ -263,7 +263,7 @@ impl CalibrationReader for SqliteStorage {
                      WHERE start_time >= ?1 AND end_time <= ?2 \
                      ORDER BY start_time ASC",
                 )
-                .map_err(|e| StorageError::Internal(format!("prepare segment ranges: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("prepare segment ranges: {e}")))?;
 
             let rows = stmt
                 .query_map(params![from_str, to_str], |row| {
@@ -272,12 +272,12 @@ impl CalibrationReader for SqliteStorage {
                     let end_str: String = row.get(2)?;
                     Ok((id, start_str, end_str))
                 })
-                .map_err(|e| StorageError::Internal(format!("query segment ranges: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("query segment ranges: {e}")))?;
 
             let mut result = Vec::new();
             for row_result in rows {
                 let (id, start_str, end_str) =
-                    row_result.map_err(|e| StorageError::Internal(format!("read segment row: {e}")))?;
+                    row_result.map_err(|e| StorageError::Database(format!("read segment row: {e}")))?;
                 let start = DateTime::parse_from_rfc3339(&start_str)
                     .map(|dt| dt.with_timezone(&Utc))
                     .map_err(|e| StorageError::Internal(format!("invalid segment start: {e}")))?;
@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+use oneshim_core::models::capture_latency::{
+    CaptureLatencyRecord, CaptureStage, StageLatencySummary,
+};
+
+use super::SqliteStorage;
+use crate::error::StorageError;
+
+impl SqliteStorage {
+    /// Store one snapshot of the per-stage latency summaries.
+    pub fn save_capture_latency(
+        &self,
+        recorded_at: DateTime<Utc>,
+        summaries: &[StageLatencySummary],
+    ) -> Result<usize, StorageError> {
+        if summaries.is_empty() {
+            return Ok(0);
+        }
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| StorageError::Database(format!("Failed to begin transaction: {e}")))?;
+        let recorded_at = recorded_at.to_rfc3339();
+        for summary in summaries {
+            tx.execute(
+                "INSERT INTO capture_latency
+                     (recorded_at, stage, samples, total_samples, p50_ms, p95_ms, max_ms)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                rusqlite::params![
+                    recorded_at,
+                    summary.stage.as_str(),
+                    summary.samples as i64,
+                    summary.total_samples as i64,
+                    summary.p50_ms,
+                    summary.p95_ms,
+                    summary.max_ms,
+                ],
+            )
+            .map_err(|e| {
+                StorageError::Database(format!("Failed to insert capture latency: {e}"))
+            })?;
+        }
+        tx.commit()
+            .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
+        Ok(summaries.len())
+    }
+
+    /// Snapshots recorded at or after `since`, oldest first, optionally
+    /// limited to one stage.
+    pub fn list_capture_latency_since(
+        &self,
+        since: DateTime<Utc>,
+        stage: Option<CaptureStage>,
+    ) -> Result<Vec<CaptureLatencyRecord>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT recorded_at, stage, samples, total_samples, p50_ms, p95_ms, max_ms
+                 FROM capture_latency
+                 WHERE recorded_at >= ?1 AND (?2 IS NULL OR stage = ?2)
+                 ORDER BY recorded_at ASC, id ASC",
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+
+        let rows = stmt
+            .query_map(
+                rusqlite::params![since.to_rfc3339(), stage.map(|s| s.as_str())],
+                |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, i64>(2)?,
+                        row.get::<_, i64>(3)?,
+                        row.get::<_, f64>(4)?,
+                        row.get::<_, f64>(5)?,
+                        row.get::<_, f64>(6)?,
+                    ))
+                },
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            let (recorded_at, stage, samples, total_samples, p50_ms, p95_ms, max_ms) =
+                row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?;
+            let Some(stage) = CaptureStage::parse(&stage) else {
+                continue;
+            };
+            let recorded_at = DateTime::parse_from_rfc3339(&recorded_at)
+                .map_err(|e| StorageError::Internal(format!("Invalid recorded_at: {e}")))?
+                .with_timezone(&Utc);
+            result.push(CaptureLatencyRecord {
+                recorded_at,
+                summary: StageLatencySummary {
+                    stage,
+                    samples: samples.max(0) as u64,
+                    total_samples: total_samples.max(0) as u64,
+                    p50_ms,
+                    p95_ms,
+                    max_ms,
+                },
+            });
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use oneshim_core::ports::storage::MetricsStorage;
+
+    fn summary(stage: CaptureStage, p50_ms: f64) -> StageLatencySummary {
+        StageLatencySummary {
+            stage,
+            samples: 10,
+            total_samples: 40,
+            p50_ms,
+            p95_ms: p50_ms * 2.0,
+            max_ms: p50_ms * 3.0,
+        }
+    }
+
+    #[tokio::test]
+    async fn capture_latency_round_trips_and_expires_with_metrics() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let now = Utc::now();
+        let old = now - Duration::hours(2);
+        storage
+            .save_capture_latency(old, &[summary(CaptureStage::Capture, 5.0)])
+            .unwrap();
+        storage
+            .save_capture_latency(
+                now,
+                &[
+                    summary(CaptureStage::Capture, 8.0),
+                    summary(CaptureStage::Encode, 40.0),
+                ],
+            )
+            .unwrap();
+
+        let all = storage
+            .list_capture_latency_since(old - Duration::minutes(1), None)
+            .unwrap();
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].summary.p50_ms, 5.0);
+
+        let encode = storage
+            .list_capture_latency_since(old, Some(CaptureStage::Encode))
+            .unwrap();
+        assert_eq!(encode.len(), 1);
+        assert_eq!(encode[0].summary, summary(CaptureStage::Encode, 40.0));
+
+        storage
+            .cleanup_old_metrics(now - Duration::hours(1))
+            .await
+            .unwrap();
+        let remaining = storage
+            .list_capture_latency_since(old - Duration::minutes(1), None)
+            .unwrap();
+        assert_eq!(remaining.len(), 2);
+    }
+}
@@ -12,9 +12,9 @@ impl SqliteStorage {
         offset: u32,
     ) -> Result<Vec<CoachingEventRow>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -25,7 +25,7 @@ impl SqliteStorage {
                  ORDER BY shown_at DESC
                  LIMIT ?1 OFFSET ?2",
             )
-            .map_err(|e| StorageError::Internal(format!("prepare query_coaching_events: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare query_coaching_events: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![limit, offset], |row| {
@@ -43,11 +43,11 @@ impl SqliteStorage {
                     feedback_score: row.get(10)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("query_coaching_events: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query_coaching_events: {e}")))?;
 
         let mut results = Vec::new();
         for row in rows {
-            results.push(row.map_err(|e| StorageError::Internal(format!("row read: {e}")))?);
+            results.push(row.map_err(|e| StorageError::Database(format!("row read: {e}")))?);
         }
         Ok(results)
     }
@@ -58,9 +58,9 @@ impl SqliteStorage {
         since_date: &str,
     ) -> Result<Vec<CoachingEventRow>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -72,7 +72,7 @@ impl SqliteStorage {
                  ORDER BY shown_at DESC",
             )
             .map_err(|e| {
-                StorageError::Internal(format!("prepare query_coaching_events_since: {e}"))
+                StorageError::Database(format!("prepare query_coaching_events_since: {e}"))
             })?;
 
         let rows = stmt
@@ -91,11 +91,11 @@ impl SqliteStorage {
                     feedback_score: row.get(10)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("query_coaching_events_since: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query_coaching_events_since: {e}")))?;
 
         let mut results = Vec::new();
         for row in rows {
-            results.push(row.map_err(|e| StorageError::Internal(format!("row read: {e}")))?);
+            results.push(row.map_err(|e| StorageError::Database(format!("row read: {e}")))?);
         }
         Ok(results)
     }
@@ -103,9 +103,9 @@ impl SqliteStorage {
     /// Insert a coaching event record.
     pub fn insert_coaching_event(&self, event: &CoachingEventRow) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         conn.execute(
             "INSERT INTO coaching_events
@@ -127,7 +127,7 @@ impl SqliteStorage {
                 event.feedback_score,
             ],
         )
-        .map_err(|e| StorageError::Internal(format!("insert_coaching_event: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("insert_coaching_event: {e}")))?;
 
         Ok(())
     }
@@ -142,9 +142,9 @@ impl SqliteStorage {
         feedback_score: Option<f64>,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         conn.execute(
             "UPDATE coaching_events
@@ -161,7 +161,7 @@ impl SqliteStorage {
                 feedback_score
             ],
         )
-        .map_err(|e| StorageError::Internal(format!("update_coaching_event_feedback: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("update_coaching_event_feedback: {e}")))?;
 
         Ok(())
     }
@@ -173,15 +173,15 @@ impl SqliteStorage {
         personalized_text: &str,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         conn.execute(
             "UPDATE coaching_events SET personalized_message = ?2 WHERE event_id = ?1",
             rusqlite::params![event_id, personalized_text],
         )
-        .map_err(|e| StorageError::Internal(format!("update_coaching_event_personalized: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("update_coaching_event_personalized: {e}")))?;
 
         Ok(())
     }
@@ -189,13 +189,13 @@ impl SqliteStorage {
     /// Get all regime goals from the regime_goals table.
     pub fn get_regime_goals(&self) -> Result<HashMap<String, u32>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         let mut stmt = conn
             .prepare("SELECT regime_label, daily_target_minutes FROM regime_goals")
-            .map_err(|e| StorageError::Internal(format!("prepare get_regime_goals: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare get_regime_goals: {e}")))?;
 
         let rows = stmt
             .query_map([], |row| {
@@ -203,12 +203,12 @@ impl SqliteStorage {
                 let minutes: u32 = row.get(1)?;
                 Ok((label, minutes))
             })
-            .map_err(|e| StorageError::Internal(format!("get_regime_goals: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("get_regime_goals: {e}")))?;
 
         let mut goals = HashMap::new();
         for row in rows {
             let (label, minutes) =
-                row.map_err(|e| StorageError::Internal(format!("row read: {e}")))?;
+                row.map_err(|e| StorageError::Database(format!("row read: {e}")))?;
             goals.insert(label, minutes);
         }
         Ok(goals)
@@ -221,9 +221,9 @@ impl SqliteStorage {
         target_minutes: u32,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         conn.execute(
             "INSERT INTO regime_goals (regime_label, daily_target_minutes, updated_at)
@@ -232,7 +232,7 @@ impl SqliteStorage {
              DO UPDATE SET daily_target_minutes = ?2, updated_at = datetime('now')",
             rusqlite::params![regime_label, target_minutes],
         )
-        .map_err(|e| StorageError::Internal(format!("set_regime_goal: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("set_regime_goal: {e}")))?;
 
         Ok(())
     }
@@ -240,15 +240,15 @@ impl SqliteStorage {
     /// Delete a regime goal by label.
     pub fn delete_regime_goal(&self, regime_label: &str) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         conn.execute(
             "DELETE FROM regime_goals WHERE regime_label = ?1",
             rusqlite::params![regime_label],
         )
-        .map_err(|e| StorageError::Internal(format!("delete_regime_goal: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("delete_regime_goal: {e}")))?;
 
         Ok(())
     }
@@ -23,9 +23,9 @@ impl oneshim_core::ports::web_storage::DashboardStreamingStorage for SqliteStora
         from: DateTime<Utc>,
         to: DateTime<Utc>,
     ) -> Result<MetricBucketRecord, CoreError> {
-        let conn = self.conn.lock().map_err(|_| CoreError::Internal {
-            code: InternalCode::Generic,
-            message: "metrics mutex poisoned".to_string(),
+        let conn = self.pool.get().map_err(|_| CoreError::Storage {
+            code: StorageCode::Lock,
+            message: "metrics connection unavailable".to_string(),
         })?;
 
         let from_s = from.to_rfc3339();
@@ -38,7 +38,7 @@ impl oneshim_core::ports::web_storage::DashboardStreamingStorage for SqliteStora
                  WHERE timestamp >= ?1 AND timestamp < ?2",
             )
             .map_err(|e| CoreError::Storage {
-                code: StorageCode::Failed,
+                code: StorageCode::Database,
                 message: format!("prepare aggregate_metrics: {e}"),
             })?;
 
@@ -47,7 +47,7 @@ impl oneshim_core::ports::web_storage::DashboardStreamingStorage for SqliteStora
                 Ok((r.get(0)?, r.get(1)?, r.get(2)?))
             })
             .map_err(|e| CoreError::Storage {
-                code: StorageCode::Failed,
+                code: StorageCode::Database,
                 message: format!("aggregate_metrics query: {e}"),
             })?;
 
@@ -83,9 +83,9 @@ impl oneshim_core::ports::web_storage::DashboardStreamingStorage for SqliteStora
 
 impl SqliteStorage {
     fn fetch_frame_event(&self, frame_id: i64) -> Result<DashboardEventRecord, CoreError> {
-        let conn = self.conn.lock().map_err(|_| CoreError::Internal {
-            code: InternalCode::Generic,
-            message: "frames mutex poisoned".to_string(),
+        let conn = self.pool.get().map_err(|_| CoreError::Storage {
+            code: StorageCode::Lock,
+            message: "frames connection unavailable".to_string(),
         })?;
 
         // frames table uses `timestamp` (not `captured_at`) — confirmed in migration v01.
@@ -112,14 +112,14 @@ impl SqliteStorage {
                     id: frame_id.to_string(),
                 },
                 other => CoreError::Storage {
-                    code: StorageCode::Failed,
+                    code: StorageCode::Database,
                     message: format!("fetch_frame: {other}"),
                 },
             })?;
 
         let occurred_at = DateTime::parse_from_rfc3339(&row.0)
             .map_err(|e| CoreError::Storage {
-                code: StorageCode::Failed,
+                code: StorageCode::Database,
                 message: format!("frame timestamp parse: {e}"),
             })?
             .with_timezone(&Utc);
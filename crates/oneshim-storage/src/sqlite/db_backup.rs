@@ -0,0 +1,214 @@
+//! Point-in-time database snapshots.
+//!
+//! Snapshots go through SQLite's online backup API rather than a file copy,
+//! so they are consistent even while the scheduler is writing and never pick
+//! up a torn WAL. Encrypted databases are backed up under the same SQLCipher
+//! key; the result opens with the existing `.db_key`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use chrono::Utc;
+use oneshim_core::models::storage_records::DatabaseBackupRecord;
+use rusqlite::backup::Backup;
+use rusqlite::Connection;
+use tracing::{info, warn};
+
+use super::{apply_key_pragma, SqliteStorage};
+use crate::error::StorageError;
+
+/// Directory, next to the database file, that rotated snapshots live in.
+pub const BACKUP_DIR_NAME: &str = "backups";
+
+const BACKUP_FILE_PREFIX: &str = "oneshim-";
+const BACKUP_FILE_SUFFIX: &str = ".db";
+const PARTIAL_SUFFIX: &str = ".partial";
+
+/// Pages copied per backup step.
+const BACKUP_PAGES_PER_STEP: std::os::raw::c_int = 1024;
+
+impl SqliteStorage {
+    /// Write a consistent snapshot of the database to `path`, replacing any
+    /// existing file. The snapshot is written to a temporary file first and
+    /// renamed into place, so `path` is never left half-written.
+    pub fn backup_to(&self, path: &Path) -> Result<(), StorageError> {
+        let partial = PathBuf::from(format!("{}{PARTIAL_SUFFIX}", path.display()));
+        if partial.exists() {
+            fs::remove_file(&partial)?;
+        }
+
+        {
+            let conn = self
+                .pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+            let mut dest = Connection::open(&partial).map_err(|e| {
+                StorageError::Database(format!("Failed to open backup destination: {e}"))
+            })?;
+            if let Some(key) = &self.encryption_key {
+                apply_key_pragma(&dest, key).map_err(|e| {
+                    StorageError::Database(format!("Failed to key backup destination: {e}"))
+                })?;
+            }
+            Backup::new(&conn, &mut dest)
+                .and_then(|backup| {
+                    backup.run_to_completion(BACKUP_PAGES_PER_STEP, Duration::ZERO, None)
+                })
+                .map_err(|e| StorageError::Database(format!("Database backup failed: {e}")))?;
+        }
+
+        fs::rename(&partial, path)?;
+        Ok(())
+    }
+
+    /// Highest applied migration version.
+    pub fn schema_version(&self) -> Result<u32, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+        Ok(crate::migration::current_version(&conn)?)
+    }
+
+    /// Directory rotated snapshots are written to. `None` for in-memory
+    /// databases.
+    pub fn backup_dir(&self) -> Result<Option<PathBuf>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+        Ok(conn
+            .path()
+            .filter(|path| !path.is_empty())
+            .and_then(|path| Path::new(path).parent())
+            .map(|dir| dir.join(BACKUP_DIR_NAME)))
+    }
+
+    /// Snapshot into [`Self::backup_dir`] and delete all but the newest
+    /// `keep` snapshots.
+    pub fn create_database_backup(
+        &self,
+        keep: usize,
+    ) -> Result<DatabaseBackupRecord, StorageError> {
+        let dir = self.backup_dir()?.ok_or_else(|| {
+            StorageError::Internal("in-memory database has no backup directory".to_string())
+        })?;
+        fs::create_dir_all(&dir)?;
+
+        let created_at = Utc::now();
+        let path = dir.join(format!(
+            "{BACKUP_FILE_PREFIX}{}{BACKUP_FILE_SUFFIX}",
+            created_at.format("%Y%m%dT%H%M%S%.6fZ")
+        ));
+        self.backup_to(&path)?;
+        let size_bytes = fs::metadata(&path)?.len();
+        let rotated_out = rotate_backups(&dir, keep.max(1))?;
+
+        info!(
+            path = %path.display(),
+            size_bytes,
+            rotated_out,
+            "database backup written"
+        );
+        Ok(DatabaseBackupRecord {
+            path: path.display().to_string(),
+            size_bytes,
+            created_at: created_at.to_rfc3339(),
+            rotated_out,
+        })
+    }
+}
+
+/// Delete the oldest snapshots in `dir` beyond `keep`. Snapshot names embed
+/// their UTC timestamp, so name order is age order.
+fn rotate_backups(dir: &Path, keep: usize) -> Result<usize, StorageError> {
+    let mut snapshots: Vec<PathBuf> = fs::read_dir(dir)?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| {
+                    name.starts_with(BACKUP_FILE_PREFIX) && name.ends_with(BACKUP_FILE_SUFFIX)
+                })
+        })
+        .collect();
+    snapshots.sort();
+
+    let excess = snapshots.len().saturating_sub(keep);
+    let mut removed = 0;
+    for path in snapshots.into_iter().take(excess) {
+        match fs::remove_file(&path) {
+            Ok(()) => removed += 1,
+            Err(e) => warn!(path = %path.display(), "failed to delete old backup: {e}"),
+        }
+    }
+    Ok(removed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::encryption::EncryptionKey;
+
+    #[test]
+    fn backup_of_populated_in_memory_db_opens_with_rows() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        storage.create_tag("Work", "#3b82f6").unwrap();
+        storage.create_tag("Keep", "#ef4444").unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("snapshot.db");
+        storage.backup_to(&path).unwrap();
+
+        let restored = SqliteStorage::open(&path, 30, None).unwrap();
+        let names: Vec<String> = restored
+            .get_all_tags()
+            .unwrap()
+            .into_iter()
+            .map(|tag| tag.name)
+            .collect();
+        assert!(names.contains(&"Work".to_string()), "{names:?}");
+        assert!(names.contains(&"Keep".to_string()), "{names:?}");
+        assert!(restored.integrity_check().unwrap().is_healthy());
+    }
+
+    #[test]
+    fn rotated_backups_keep_only_newest() {
+        let dir = tempfile::tempdir().unwrap();
+        let storage = SqliteStorage::open(&dir.path().join("oneshim.db"), 30, None).unwrap();
+
+        let mut written = Vec::new();
+        for _ in 0..3 {
+            written.push(storage.create_database_backup(2).unwrap());
+        }
+        assert_eq!(written[2].rotated_out, 1);
+
+        let remaining: Vec<PathBuf> = fs::read_dir(dir.path().join(BACKUP_DIR_NAME))
+            .unwrap()
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        assert_eq!(remaining.len(), 2);
+        assert!(!Path::new(&written[0].path).exists());
+        assert!(Path::new(&written[2].path).exists());
+    }
+
+    #[test]
+    fn encrypted_backup_opens_with_same_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let key = EncryptionKey::from_bytes([0x42; 32]);
+        let storage = SqliteStorage::open(&dir.path().join("oneshim.db"), 30, Some(&key)).unwrap();
+        storage.create_tag("Secret", "#000000").unwrap();
+
+        let record = storage.create_database_backup(3).unwrap();
+        let restored = SqliteStorage::open(Path::new(&record.path), 30, Some(&key)).unwrap();
+        assert_eq!(restored.get_all_tags().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn in_memory_db_has_no_rotation_dir() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        assert!(storage.backup_dir().unwrap().is_none());
+        assert!(storage.create_database_backup(3).is_err());
+    }
+}
@@ -16,9 +16,9 @@ impl SqliteStorage {
         device_name: &str,
     ) -> Result<(String, String), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
 
         // Try to read existing identity first.
         let existing: Option<(String, String)> = conn
@@ -39,7 +39,7 @@ impl SqliteStorage {
             "INSERT INTO device_identity (id, device_id, device_name) VALUES (1, ?1, ?2)",
             rusqlite::params![device_id, device_name],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to insert device identity: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to insert device identity: {e}")))?;
 
         info!(
             device_id = %device_id,
@@ -59,13 +59,13 @@ impl SqliteStorage {
         device_name: &str,
     ) -> Result<(String, String), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
 
         conn.execute("DELETE FROM device_identity WHERE id = 1", [])
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to delete device identity: {e}"))
+                StorageError::Database(format!("Failed to delete device identity: {e}"))
             })?;
 
         drop(conn); // Release lock before calling ensure_device_identity
@@ -17,9 +17,9 @@ impl SqliteStorage {
 
     pub fn get_or_create_focus_metrics(&self, date: &str) -> Result<FocusMetrics, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let result = conn.query_row(
             "SELECT total_active_secs, deep_work_secs, communication_secs, context_switches,
@@ -71,7 +71,7 @@ impl SqliteStorage {
                     rusqlite::params![date],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to create focus metric: {e}"))
+                    StorageError::Database(format!("Failed to create focus metric: {e}"))
                 })?;
 
                 FocusMetrics::new(period_start, period_end).map_err(|e| {
@@ -80,7 +80,7 @@ impl SqliteStorage {
                     ))
                 })
             }
-            Err(e) => Err(StorageError::Internal(format!(
+            Err(e) => Err(StorageError::Database(format!(
                 "Failed to query focus metric: {e}"
             ))),
         }
@@ -92,9 +92,9 @@ impl SqliteStorage {
         metrics: &FocusMetrics,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE focus_metrics SET
@@ -120,7 +120,7 @@ impl SqliteStorage {
                 date,
             ],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to update focus metric: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to update focus metric: {e}")))?;
 
         debug!(
             "focus metrics updated: date={}, score={:.2}",
@@ -141,9 +141,9 @@ impl SqliteStorage {
         let _ = self.get_or_create_focus_metrics(date)?;
 
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE focus_metrics SET
@@ -163,7 +163,7 @@ impl SqliteStorage {
                 date,
             ],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to increment focus metric: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to increment focus metric: {e}")))?;
 
         Ok(())
     }
@@ -173,9 +173,9 @@ impl SqliteStorage {
         days: usize,
     ) -> Result<Vec<(String, FocusMetrics)>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -183,7 +183,7 @@ impl SqliteStorage {
                         interruption_count, avg_focus_duration_secs, max_focus_duration_secs, focus_score
                  FROM focus_metrics ORDER BY date DESC LIMIT ?1",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![days as i64], |row| {
@@ -199,7 +199,7 @@ impl SqliteStorage {
                     row.get::<_, f32>(8)?,
                 ))
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut results = Vec::new();
         for row in rows {
@@ -213,7 +213,7 @@ impl SqliteStorage {
                 avg_focus_duration_secs,
                 max_focus_duration_secs,
                 focus_score,
-            ) = row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?;
+            ) = row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?;
 
             let (period_start, period_end) = Self::date_to_period_range(&date);
             let period = oneshim_core::types::TimeWindow::new(period_start, period_end)
@@ -8,9 +8,9 @@ impl SqliteStorage {
     pub fn enforce_segment_retention(&self, max_days: u32) -> Result<usize, StorageError> {
         let cutoff = (Utc::now() - chrono::Duration::days(max_days as i64)).to_rfc3339();
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
         let table_exists: bool = conn
             .query_row(
                 "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='activity_segments'",
@@ -26,7 +26,7 @@ impl SqliteStorage {
                 "DELETE FROM activity_segments WHERE start_time < ?1 AND start_time IS NOT NULL",
                 rusqlite::params![cutoff],
             )
-            .map_err(|e| StorageError::Internal(format!("segment retention failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("segment retention failure: {e}")))?;
         tracing::debug!(
             "Enforced segment retention: deleted {deleted} rows older than {max_days} days"
         );
@@ -37,9 +37,9 @@ impl SqliteStorage {
     pub fn enforce_digest_retention(&self, max_weeks: u32) -> Result<usize, StorageError> {
         let cutoff = (Utc::now() - chrono::Duration::days(max_weeks as i64 * 7)).to_rfc3339();
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
         let table_exists: bool = conn
             .query_row(
                 "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='weekly_digests'",
@@ -55,7 +55,7 @@ impl SqliteStorage {
                 "DELETE FROM weekly_digests WHERE week_start < ?1",
                 rusqlite::params![cutoff],
             )
-            .map_err(|e| StorageError::Internal(format!("digest retention failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("digest retention failure: {e}")))?;
         tracing::debug!(
             "Enforced digest retention: deleted {deleted} rows older than {max_weeks} weeks"
         );
@@ -70,9 +70,9 @@ impl SqliteStorage {
     /// Returns the total number of rows deleted across all tables.
     pub fn enforce_all_retention(&self) -> Result<u64, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
 
         let mut total: u64 = 0;
 
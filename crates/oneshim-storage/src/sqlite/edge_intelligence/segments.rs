@@ -12,9 +12,9 @@ impl SqliteStorage {
         to: DateTime<Utc>,
     ) -> Result<Vec<oneshim_core::models::tiered_memory::SegmentSummary>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         // Check table existence (may not have run V9 migration yet)
         let table_exists: bool = conn
@@ -43,7 +43,7 @@ impl SqliteStorage {
                  ORDER BY start_time",
             )
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to prepare segments query: {e}"))
+                StorageError::Database(format!("Failed to prepare segments query: {e}"))
             })?;
 
         let segments: Vec<oneshim_core::models::tiered_memory::SegmentSummary> = stmt
@@ -83,7 +83,7 @@ impl SqliteStorage {
                     llm_summary,
                 ))
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to query segments: {e}")))?
+            .map_err(|e| StorageError::Database(format!("Failed to query segments: {e}")))?
             .filter_map(|r| r.ok())
             .filter_map(
                 |(
@@ -41,9 +41,9 @@ impl SqliteStorage {
         suggestion: &oneshim_core::models::suggestion::Suggestion,
     ) -> Result<String, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT OR REPLACE INTO suggestions \
@@ -65,7 +65,7 @@ impl SqliteStorage {
                 suggestion.expires_at.map(|t| t.to_rfc3339()),
             ],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to save suggestion: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to save suggestion: {e}")))?;
 
         debug!(id = %suggestion.suggestion_id, "rule-based suggestion persisted to SQLite");
         Ok(suggestion.suggestion_id.clone())
@@ -74,15 +74,15 @@ impl SqliteStorage {
     /// Mark a unified suggestion as shown by its string suggestion_id.
     pub fn mark_unified_suggestion_shown(&self, suggestion_id: &str) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE suggestions SET shown_at = datetime('now') WHERE suggestion_id = ?1",
             rusqlite::params![suggestion_id],
         )
-        .map_err(|e| StorageError::Internal(format!("suggestion shown record failure: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("suggestion shown record failure: {e}")))?;
 
         Ok(())
     }
@@ -94,9 +94,9 @@ impl SqliteStorage {
     #[allow(deprecated)]
     pub fn save_local_suggestion(&self, suggestion: &LocalSuggestion) -> Result<i64, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let (suggestion_type, payload) = Self::serialize_suggestion(suggestion);
 
@@ -104,7 +104,7 @@ impl SqliteStorage {
             "INSERT INTO local_suggestions (suggestion_type, payload) VALUES (?1, ?2)",
             rusqlite::params![suggestion_type, payload],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to save local suggestion: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to save local suggestion: {e}")))?;
 
         let id = conn.last_insert_rowid();
         debug!("suggestion save: id={}, type={}", id, suggestion_type);
@@ -113,31 +113,31 @@ impl SqliteStorage {
 
     pub fn mark_suggestion_shown(&self, suggestion_id: i64) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE local_suggestions SET shown_at = datetime('now') WHERE id = ?1",
             rusqlite::params![suggestion_id],
         )
-        .map_err(|e| StorageError::Internal(format!("suggestion display record failure: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("suggestion display record failure: {e}")))?;
 
         Ok(())
     }
 
     pub fn mark_suggestion_dismissed(&self, suggestion_id: i64) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE local_suggestions SET dismissed_at = datetime('now') WHERE id = ?1",
             rusqlite::params![suggestion_id],
         )
         .map_err(|e| {
-            StorageError::Internal(format!("Failed to record suggestion dismissal: {e}"))
+            StorageError::Database(format!("Failed to record suggestion dismissal: {e}"))
         })?;
 
         Ok(())
@@ -145,15 +145,15 @@ impl SqliteStorage {
 
     pub fn mark_suggestion_acted(&self, suggestion_id: i64) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE local_suggestions SET acted_at = datetime('now') WHERE id = ?1",
             rusqlite::params![suggestion_id],
         )
-        .map_err(|e| StorageError::Internal(format!("suggestion execution record failure: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("suggestion execution record failure: {e}")))?;
 
         Ok(())
     }
@@ -169,9 +169,9 @@ impl SqliteStorage {
         limit: usize,
     ) -> Result<Vec<oneshim_core::models::storage_records::SuggestionRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -183,7 +183,7 @@ impl SqliteStorage {
                  ORDER BY created_at DESC \
                  LIMIT ?1",
             )
-            .map_err(|e| StorageError::Internal(format!("prepare failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare failure: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![limit as i64], |row| {
@@ -206,12 +206,12 @@ impl SqliteStorage {
                     resurface_at: None,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("query failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query failure: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -220,16 +220,16 @@ impl SqliteStorage {
     /// Returns `true` if a row was updated, `false` otherwise.
     pub fn dismiss_unified_suggestion(&self, suggestion_id: &str) -> Result<bool, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let changed = conn
             .execute(
                 "UPDATE suggestions SET dismissed_at = datetime('now') WHERE suggestion_id = ?1 AND dismissed_at IS NULL",
                 rusqlite::params![suggestion_id],
             )
-            .map_err(|e| StorageError::Internal(format!("dismiss failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("dismiss failure: {e}")))?;
 
         Ok(changed > 0)
     }
@@ -240,9 +240,9 @@ impl SqliteStorage {
         limit: usize,
     ) -> Result<Vec<LocalSuggestionRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -252,19 +252,19 @@ impl SqliteStorage {
                  ORDER BY created_at DESC
                  LIMIT ?2",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(
                 rusqlite::params![cutoff, limit as i64],
                 map_local_suggestion_row,
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -275,9 +275,9 @@ impl SqliteStorage {
         limit: usize,
     ) -> Result<Vec<LocalSuggestionRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let sql = if after_id.is_some() {
             "SELECT id, suggestion_type, payload, created_at, shown_at, dismissed_at, acted_at
@@ -294,7 +294,7 @@ impl SqliteStorage {
 
         let mut stmt = conn
             .prepare(sql)
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = if let Some(after_id) = after_id {
             stmt.query_map(
@@ -304,12 +304,12 @@ impl SqliteStorage {
         } else {
             stmt.query_map(rusqlite::params![limit as i64], map_local_suggestion_row)
         }
-        .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -319,9 +319,9 @@ impl SqliteStorage {
     /// server is actively sending suggestions.
     pub fn has_recent_server_suggestions(&self, lookback_secs: u64) -> Result<bool, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let sql = "SELECT COUNT(*) FROM suggestions \
              WHERE source = ?1 \
@@ -335,7 +335,7 @@ impl SqliteStorage {
                 ],
                 |row| row.get(0),
             )
-            .map_err(|e| StorageError::Internal(format!("query failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query failure: {e}")))?;
 
         Ok(count > 0)
     }
@@ -352,9 +352,9 @@ impl SqliteStorage {
         resurface_at: Option<&str>,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT OR REPLACE INTO suggestions \
@@ -379,7 +379,7 @@ impl SqliteStorage {
             ],
         )
         .map_err(|e| {
-            StorageError::Internal(format!("Failed to save suggestion with state: {e}"))
+            StorageError::Database(format!("Failed to save suggestion with state: {e}"))
         })?;
 
         debug!(id = %suggestion.suggestion_id, state, "suggestion persisted with state");
@@ -393,9 +393,9 @@ impl SqliteStorage {
         limit: usize,
     ) -> Result<Vec<SuggestionRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -405,7 +405,7 @@ impl SqliteStorage {
                  FROM suggestions WHERE state = ?1 \
                  ORDER BY created_at DESC LIMIT ?2",
             )
-            .map_err(|e| StorageError::Internal(format!("prepare failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare failure: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![state, limit as i64], |row| {
@@ -428,12 +428,12 @@ impl SqliteStorage {
                     resurface_at: row.get(15)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("query failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query failure: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -444,9 +444,9 @@ impl SqliteStorage {
         record: &oneshim_core::models::storage_records::PendingFeedbackRecord,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
         conn.execute(
             "INSERT OR REPLACE INTO feedback_retries \
              (suggestion_id, feedback_type, comment, attempts, next_retry_at) \
@@ -459,7 +459,7 @@ impl SqliteStorage {
                 record.next_retry_at,
             ],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to save pending feedback: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to save pending feedback: {e}")))?;
         Ok(())
     }
 
@@ -470,15 +470,15 @@ impl SqliteStorage {
     ) -> Result<Vec<oneshim_core::models::storage_records::PendingFeedbackRecord>, StorageError>
     {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
         let mut stmt = conn
             .prepare(
                 "SELECT id, suggestion_id, feedback_type, comment, attempts, next_retry_at, created_at \
                  FROM feedback_retries ORDER BY created_at ASC LIMIT ?1",
             )
-            .map_err(|e| StorageError::Internal(format!("prepare failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare failure: {e}")))?;
         let rows = stmt
             .query_map(rusqlite::params![limit as i64], |row| {
                 Ok(
@@ -493,10 +493,10 @@ impl SqliteStorage {
                     },
                 )
             })
-            .map_err(|e| StorageError::Internal(format!("query failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query failure: {e}")))?;
         let mut records = Vec::new();
         for row in rows {
-            records.push(row.map_err(|e| StorageError::Internal(format!("row failure: {e}")))?);
+            records.push(row.map_err(|e| StorageError::Database(format!("row failure: {e}")))?);
         }
         Ok(records)
     }
@@ -505,9 +505,9 @@ impl SqliteStorage {
     /// Returns the number of rows deleted.
     pub fn cleanup_old_feedback_retries(&self, max_age_days: u32) -> Result<usize, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
         let cutoff = format!("-{max_age_days} days");
         let deleted = conn
             .execute(
@@ -515,7 +515,7 @@ impl SqliteStorage {
                 rusqlite::params![cutoff],
             )
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to cleanup old feedback retries: {e}"))
+                StorageError::Database(format!("Failed to cleanup old feedback retries: {e}"))
             })?;
         Ok(deleted)
     }
@@ -523,14 +523,14 @@ impl SqliteStorage {
     /// Delete a pending feedback after successful retry or exhaustion.
     pub fn delete_pending_feedback(&self, suggestion_id: &str) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
         conn.execute(
             "DELETE FROM feedback_retries WHERE suggestion_id = ?1",
             rusqlite::params![suggestion_id],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to delete pending feedback: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to delete pending feedback: {e}")))?;
         Ok(())
     }
 
@@ -540,9 +540,9 @@ impl SqliteStorage {
         days: u32,
     ) -> Result<Vec<oneshim_core::models::storage_records::DailyStatRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
         let cutoff = format!("-{days} days");
         let mut stmt = conn
             .prepare(
@@ -555,7 +555,7 @@ impl SqliteStorage {
                  GROUP BY day, suggestion_type, source \
                  ORDER BY day DESC",
             )
-            .map_err(|e| StorageError::Internal(format!("prepare failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare failure: {e}")))?;
         let rows = stmt
             .query_map(rusqlite::params![cutoff], |row| {
                 Ok(oneshim_core::models::storage_records::DailyStatRecord {
@@ -566,10 +566,10 @@ impl SqliteStorage {
                     source: row.get(4)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("query failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query failure: {e}")))?;
         let mut records = Vec::new();
         for row in rows {
-            records.push(row.map_err(|e| StorageError::Internal(format!("row failure: {e}")))?);
+            records.push(row.map_err(|e| StorageError::Database(format!("row failure: {e}")))?);
         }
         Ok(records)
     }
@@ -26,6 +26,51 @@ fn work_session_lifecycle() {
     assert!(active.is_none());
 }
 
+#[test]
+fn merged_gaps_are_queryable() {
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+
+    let merged = storage
+        .start_work_session("Code", AppCategory::Development)
+        .unwrap();
+    let plain = storage
+        .start_work_session("Terminal", AppCategory::Development)
+        .unwrap();
+    storage.record_work_session_gap(merged.id, 40).unwrap();
+    storage.record_work_session_gap(merged.id, 25).unwrap();
+
+    let from = (Utc::now() - chrono::Duration::hours(1)).to_rfc3339();
+    let to = (Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    let sessions = storage.list_merged_work_sessions(&from, &to, 10).unwrap();
+    assert_eq!(sessions.len(), 1);
+    assert_eq!(sessions[0].id, merged.id);
+    assert_eq!(sessions[0].merged_gap_count, 2);
+    assert_eq!(sessions[0].merged_gap_secs, 65);
+
+    let all = storage.list_work_sessions(&from, &to, 10).unwrap();
+    assert_eq!(all.len(), 2);
+    assert!(all
+        .iter()
+        .any(|session| session.id == plain.id && session.merged_gap_count == 0));
+}
+
+#[test]
+fn end_work_session_at_uses_given_end_time() {
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+    let session = storage
+        .start_work_session("Code", AppCategory::Development)
+        .unwrap();
+
+    let ended_at = session.started_at + chrono::Duration::minutes(10);
+    storage.end_work_session_at(session.id, ended_at).unwrap();
+
+    let from = (session.started_at - chrono::Duration::minutes(1)).to_rfc3339();
+    let to = (Utc::now() + chrono::Duration::hours(1)).to_rfc3339();
+    let record = &storage.list_work_sessions(&from, &to, 1).unwrap()[0];
+    assert_eq!(record.state, "completed");
+    assert!((599..=600).contains(&record.duration_secs));
+}
+
 #[test]
 fn interruption_tracking() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
@@ -107,7 +152,7 @@ fn segments_for_date_query() {
 
     // Insert a test segment
     {
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
         conn.execute(
             "INSERT INTO activity_segments (id, start_time, end_time, duration_secs, trigger_reason, dominant_category, event_count, avg_importance)
              VALUES ('seg-001', '2026-03-19T09:00:00Z', '2026-03-19T10:00:00Z', 3600, 'SCORE_HIGH', 'Development', 50, 0.8)",
@@ -27,9 +27,9 @@ impl SqliteStorage {
         category: AppCategory,
     ) -> Result<WorkSession, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let now = Utc::now();
         let category_str = enum_to_sql_str(&category);
@@ -39,7 +39,7 @@ impl SqliteStorage {
              VALUES (?1, ?2, ?3, 'active')",
             rusqlite::params![now.to_rfc3339(), primary_app, category_str],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to start work session: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to start work session: {e}")))?;
 
         let id = conn.last_insert_rowid();
         debug!(
@@ -62,9 +62,9 @@ impl SqliteStorage {
 
     pub fn get_active_work_session(&self) -> Result<Option<WorkSession>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let result = conn.query_row(
             "SELECT id, started_at, primary_app, category, interruption_count, deep_work_secs, duration_secs
@@ -112,20 +112,27 @@ impl SqliteStorage {
                 }))
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-            Err(e) => Err(StorageError::Internal(format!(
+            Err(e) => Err(StorageError::Database(format!(
                 "Failed to query work session: {e}"
             ))),
         }
     }
 
     pub fn end_work_session(&self, session_id: i64) -> Result<(), StorageError> {
+        self.end_work_session_at(session_id, Utc::now())
+    }
+
+    pub fn end_work_session_at(
+        &self,
+        session_id: i64,
+        ended_at: DateTime<Utc>,
+    ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
-        let now = Utc::now();
-        let now_str = now.to_rfc3339();
+        let now_str = ended_at.to_rfc3339();
 
         let duration_secs: i64 = conn
             .query_row(
@@ -138,7 +145,7 @@ impl SqliteStorage {
                 rusqlite::params![now_str, session_id],
                 |row| row.get(0),
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to end work session: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to end work session: {e}")))?;
 
         debug!(
             "work session ended: id={}, duration={}s",
@@ -149,32 +156,54 @@ impl SqliteStorage {
 
     pub fn increment_work_session_interruption(&self, session_id: i64) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE work_sessions SET interruption_count = interruption_count + 1 WHERE id = ?1",
             rusqlite::params![session_id],
         )
         .map_err(|e| {
-            StorageError::Internal(format!("Failed to increment interruption count: {e}"))
+            StorageError::Database(format!("Failed to increment interruption count: {e}"))
         })?;
 
         Ok(())
     }
 
+    pub fn record_work_session_gap(
+        &self,
+        session_id: i64,
+        gap_secs: u64,
+    ) -> Result<(), StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        conn.execute(
+            "UPDATE work_sessions
+             SET merged_gap_count = merged_gap_count + 1,
+                 merged_gap_secs = merged_gap_secs + ?1
+             WHERE id = ?2",
+            rusqlite::params![gap_secs as i64, session_id],
+        )
+        .map_err(|e| StorageError::Database(format!("Failed to record session gap: {e}")))?;
+
+        Ok(())
+    }
+
     pub fn add_deep_work_secs(&self, session_id: i64, secs: u64) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE work_sessions SET deep_work_secs = deep_work_secs + ?1 WHERE id = ?2",
             rusqlite::params![secs as i64, session_id],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to increment deep_work_secs: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to increment deep_work_secs: {e}")))?;
 
         Ok(())
     }
@@ -188,9 +217,9 @@ impl SqliteStorage {
         to: &str,
     ) -> Result<Vec<(String, i64)>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -201,13 +230,13 @@ impl SqliteStorage {
                  GROUP BY primary_app
                  ORDER BY total_secs DESC",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare SQL: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare SQL: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from, to], |row| {
                 Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
             })
-            .map_err(|e| StorageError::Internal(format!("Query failed: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Query failed: {e}")))?;
 
         let result: Vec<_> = rows.flatten().collect();
 
@@ -220,9 +249,9 @@ impl SqliteStorage {
     ) -> Result<Vec<(String, i64)>, StorageError> {
         let (from, to) = window.to_sql_pair();
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         // NG6: half-open `started_at < ?2` preserved (intentional — work_sessions
         // started_at is an instant; closing the upper bound would double-count at
@@ -236,13 +265,13 @@ impl SqliteStorage {
                  GROUP BY day
                  ORDER BY day",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare SQL: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare SQL: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from, to], |row| {
                 Ok((row.get::<_, String>(0)?, row.get::<_, i64>(1)?))
             })
-            .map_err(|e| StorageError::Internal(format!("Query failed: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Query failed: {e}")))?;
 
         let result: Vec<_> = rows.flatten().collect();
 
@@ -254,9 +283,9 @@ impl SqliteStorage {
 
     pub fn record_interruption(&self, interruption: &Interruption) -> Result<i64, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT INTO interruptions (interrupted_at, from_app, from_category, to_app, to_category, snapshot_frame_id)
@@ -270,7 +299,7 @@ impl SqliteStorage {
                 interruption.snapshot_frame_id,
             ],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to record interruption: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to record interruption: {e}")))?;
 
         let id = conn.last_insert_rowid();
         debug!(
@@ -286,16 +315,16 @@ impl SqliteStorage {
         resumed_to_app: &str,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "UPDATE interruptions SET resumed_at = ?1, resumed_to_app = ?2 WHERE id = ?3",
             rusqlite::params![Utc::now().to_rfc3339(), resumed_to_app, interruption_id],
         )
         .map_err(|e| {
-            StorageError::Internal(format!("Failed to record interruption resume: {e}"))
+            StorageError::Database(format!("Failed to record interruption resume: {e}"))
         })?;
 
         Ok(())
@@ -303,9 +332,9 @@ impl SqliteStorage {
 
     pub fn get_pending_interruption(&self) -> Result<Option<Interruption>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let result = conn.query_row(
             "SELECT id, interrupted_at, from_app, from_category, to_app, to_category, snapshot_frame_id
@@ -352,7 +381,7 @@ impl SqliteStorage {
                 }))
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-            Err(e) => Err(StorageError::Internal(format!(
+            Err(e) => Err(StorageError::Database(format!(
                 "Failed to query interruptions: {e}"
             ))),
         }
@@ -363,43 +392,70 @@ impl SqliteStorage {
         from: &str,
         to: &str,
         limit: usize,
+    ) -> Result<Vec<FocusWorkSessionRecord>, StorageError> {
+        self.query_work_sessions(from, to, limit, false)
+    }
+
+    /// Sessions in `[from, to]` that absorbed at least one short gap.
+    pub fn list_merged_work_sessions(
+        &self,
+        from: &str,
+        to: &str,
+        limit: usize,
+    ) -> Result<Vec<FocusWorkSessionRecord>, StorageError> {
+        self.query_work_sessions(from, to, limit, true)
+    }
+
+    fn query_work_sessions(
+        &self,
+        from: &str,
+        to: &str,
+        limit: usize,
+        merged_only: bool,
     ) -> Result<Vec<FocusWorkSessionRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
                 "SELECT id, started_at, ended_at, primary_app, category, state,
-                        interruption_count, deep_work_secs, duration_secs
+                        interruption_count, deep_work_secs, duration_secs,
+                        merged_gap_count, merged_gap_secs
                  FROM work_sessions
                  WHERE started_at >= ?1 AND started_at <= ?2
+                   AND (?4 = 0 OR merged_gap_count > 0)
                  ORDER BY started_at DESC
                  LIMIT ?3",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
-            .query_map(rusqlite::params![from, to, limit as i64], |row| {
-                Ok(FocusWorkSessionRecord {
-                    id: row.get(0)?,
-                    started_at: row.get(1)?,
-                    ended_at: row.get(2)?,
-                    primary_app: row.get(3)?,
-                    category: row.get(4)?,
-                    state: row.get(5)?,
-                    interruption_count: row.get(6)?,
-                    deep_work_secs: row.get(7)?,
-                    duration_secs: row.get(8)?,
-                })
-            })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .query_map(
+                rusqlite::params![from, to, limit as i64, merged_only],
+                |row| {
+                    Ok(FocusWorkSessionRecord {
+                        id: row.get(0)?,
+                        started_at: row.get(1)?,
+                        ended_at: row.get(2)?,
+                        primary_app: row.get(3)?,
+                        category: row.get(4)?,
+                        state: row.get(5)?,
+                        interruption_count: row.get(6)?,
+                        deep_work_secs: row.get(7)?,
+                        duration_secs: row.get(8)?,
+                        merged_gap_count: row.get(9)?,
+                        merged_gap_secs: row.get(10)?,
+                    })
+                },
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -411,9 +467,9 @@ impl SqliteStorage {
         limit: usize,
     ) -> Result<Vec<FocusInterruptionRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -427,7 +483,7 @@ impl SqliteStorage {
                  ORDER BY interrupted_at DESC
                  LIMIT ?3",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from, to, limit as i64], |row| {
@@ -443,12 +499,12 @@ impl SqliteStorage {
                     duration_secs: row.get::<_, Option<i64>>(8)?.map(|v| v as u64),
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
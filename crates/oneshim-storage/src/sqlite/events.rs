@@ -1,23 +1,26 @@
 use async_trait::async_trait;
 use chrono::{DateTime, Duration, Utc};
 use oneshim_core::error::CoreError;
-use oneshim_core::models::event::Event;
+use oneshim_core::models::event::{new_idempotency_key, Event, QuarantinedEvent};
 use oneshim_core::models::suggestion::Suggestion;
+use oneshim_core::models::work_session::AppCategory;
 use oneshim_core::ports::storage::StorageService;
 use oneshim_core::types::TimeWindow;
+use std::collections::HashMap;
+use std::sync::atomic::Ordering;
 use tracing::{debug, info, warn};
 
 use super::edge_intelligence::enum_to_sql_str;
-use super::SqliteStorage;
+use super::{RetentionOverrides, SqliteStorage};
 use crate::error::StorageError;
 
 impl SqliteStorage {
     pub fn count_events_in_range(&self, window: &TimeWindow) -> Result<u64, StorageError> {
         let (from, to) = window.to_sql_pair();
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let count: i64 = conn
             .query_row(
@@ -25,55 +28,13 @@ impl SqliteStorage {
                 rusqlite::params![from, to],
                 |row| row.get(0),
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to count events: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to count events: {e}")))?;
 
         Ok(count as u64)
     }
 
     pub(super) fn extract_event_id(event: &Event) -> String {
-        match event {
-            Event::User(user_event) => user_event.event_id.to_string(),
-            Event::System(system_event) => system_event.event_id.to_string(),
-            Event::Context(context_event) => {
-                format!(
-                    "ctx_{}_{}_{}",
-                    context_event.timestamp.timestamp_millis(),
-                    context_event.app_name,
-                    context_event
-                        .window_title
-                        .chars()
-                        .take(20)
-                        .collect::<String>()
-                )
-            }
-            Event::Input(input_event) => {
-                format!(
-                    "input_{}_{}",
-                    input_event.timestamp.timestamp_millis(),
-                    input_event.app_name
-                )
-            }
-            Event::Process(process_event) => {
-                format!("proc_{}", process_event.timestamp.timestamp_millis())
-            }
-            Event::Window(window_event) => {
-                format!(
-                    "win_{}_{:?}",
-                    window_event.timestamp.timestamp_millis(),
-                    window_event.event_type
-                )
-            }
-            Event::Clipboard(cb) => {
-                format!("clip_{}", cb.timestamp.timestamp_millis())
-            }
-            Event::FileAccess(fa) => {
-                format!(
-                    "fa_{}_{}",
-                    fa.timestamp.timestamp_millis(),
-                    fa.relative_path.display()
-                )
-            }
-        }
+        event.local_id()
     }
 
     pub(super) fn extract_event_type(event: &Event) -> String {
@@ -121,41 +82,54 @@ impl SqliteStorage {
         }
 
         let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+        Self::insert_events_in_transaction(&mut conn, events)
+    }
 
+    /// Shared by `save_events_batch` and `StorageService::save_events`.
+    fn insert_events_in_transaction(
+        conn: &mut rusqlite::Connection,
+        events: &[Event],
+    ) -> Result<usize, StorageError> {
         let tx = conn
             .transaction()
-            .map_err(|e| StorageError::Internal(format!("Failed to start transaction: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to start transaction: {e}")))?;
 
         {
             let mut stmt = tx
                 .prepare_cached(
-                    "INSERT OR IGNORE INTO events (event_id, event_type, timestamp, data) VALUES (?1, ?2, ?3, ?4)",
+                    "INSERT OR IGNORE INTO events (event_id, event_type, timestamp, data, idempotency_key) VALUES (?1, ?2, ?3, ?4, ?5)",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
             for event in events {
                 let event_id = Self::extract_event_id(event);
                 let event_type = Self::extract_event_type(event);
                 let timestamp = Self::extract_timestamp(event).to_rfc3339();
                 let data = serde_json::to_string(event).map_err(|e| {
-                    StorageError::Internal(format!("event serialization failed: {e}"))
+                    StorageError::Serialization(format!("event serialization failed: {e}"))
                 })?;
 
-                stmt.execute(rusqlite::params![event_id, event_type, timestamp, data])
-                    .map_err(|e| StorageError::Internal(format!("batch save failure: {e}")))?;
+                stmt.execute(rusqlite::params![
+                    event_id,
+                    event_type,
+                    timestamp,
+                    data,
+                    new_idempotency_key()
+                ])
+                .map_err(|e| StorageError::Database(format!("batch save failure: {e}")))?;
             }
         }
 
         tx.commit()
-            .map_err(|e| StorageError::Internal(format!("Failed to commit transaction: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
 
         // Refresh query planner statistics after large batch inserts (>100 events).
-        // Uses the already-held MutexGuard — do NOT re-lock self.conn.
+        // Reuses the caller's connection.
         if events.len() > 100 {
-            if let Err(e) = Self::run_analyze_with_conn(&conn) {
+            if let Err(e) = Self::run_analyze_with_conn(conn) {
                 warn!("ANALYZE after batch insert failed: {e}");
             }
         }
@@ -175,10 +149,10 @@ impl StorageService for SqliteStorage {
 
         self.with_conn(move |conn| {
             conn.execute(
-                "INSERT OR IGNORE INTO events (event_id, event_type, timestamp, data) VALUES (?1, ?2, ?3, ?4)",
-                rusqlite::params![event_id, event_type, timestamp, data],
+                "INSERT OR IGNORE INTO events (event_id, event_type, timestamp, data, idempotency_key) VALUES (?1, ?2, ?3, ?4, ?5)",
+                rusqlite::params![event_id, event_type, timestamp, data, new_idempotency_key()],
             )
-            .map_err(|e| StorageError::Internal(format!("event save failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("event save failure: {e}")))?;
             debug!("event save: {event_id}");
             Ok(())
         })
@@ -186,6 +160,17 @@ impl StorageService for SqliteStorage {
         .map_err(Into::into)
     }
 
+    async fn save_events(&self, events: &[Event]) -> Result<(), CoreError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+        let events = events.to_vec();
+        self.with_conn_mut(move |conn| Self::insert_events_in_transaction(conn, &events))
+            .await
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
     async fn get_events(
         &self,
         from: DateTime<Utc>,
@@ -200,14 +185,14 @@ impl StorageService for SqliteStorage {
                 .prepare_cached(
                     "SELECT data FROM events WHERE timestamp >= ?1 AND timestamp <= ?2 ORDER BY timestamp DESC LIMIT ?3",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
             let events = stmt
                 .query_map(rusqlite::params![from_str, to_str, limit as i64], |row| {
                     let data: String = row.get(0)?;
                     Ok(data)
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
                 .filter_map(|r| r.ok())
                 .filter_map(|data| {
                     serde_json::from_str::<Event>(&data)
@@ -225,30 +210,58 @@ impl StorageService for SqliteStorage {
     }
 
     async fn get_pending_events(&self, limit: usize) -> Result<Vec<Event>, CoreError> {
-        self.with_conn(move |conn| {
+        self.with_conn(move |conn| loop {
             let mut stmt = conn
                 .prepare_cached(
-                    "SELECT data FROM events WHERE is_sent = 0 ORDER BY timestamp ASC LIMIT ?1",
+                    "SELECT event_id, data FROM events WHERE is_sent = 0 \
+                     ORDER BY timestamp ASC LIMIT ?1",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
-            let events = stmt
+            let mut events = Vec::new();
+            let mut undecodable = Vec::new();
+            let rows = stmt
                 .query_map(rusqlite::params![limit as i64], |row| {
-                    let data: String = row.get(0)?;
-                    Ok(data)
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
-                .filter_map(|r| r.ok())
-                .filter_map(|data| {
-                    serde_json::from_str::<Event>(&data)
-                        .map_err(|e| {
-                            warn!("event deserialization failed, skipping row: {e}");
-                        })
-                        .ok()
-                })
-                .collect();
+                .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
+                .filter_map(|r| r.ok());
+            for (event_id, data) in rows {
+                match serde_json::from_str::<Event>(&data) {
+                    Ok(event) => events.push(event),
+                    Err(e) => undecodable.push((event_id, format!("undecodable: {e}"))),
+                }
+            }
+            if undecodable.is_empty() {
+                return Ok(events);
+            }
 
-            Ok(events)
+            // These rows can never be uploaded; left pending they would sit
+            // at the front of every page.
+
+            let quarantined_at = Utc::now().to_rfc3339();
+            let mut quarantine = conn
+                .prepare_cached(
+                    "UPDATE events SET is_sent = 1, quarantine_reason = ?1, quarantined_at = ?2 \
+                     WHERE event_id = ?3",
+                )
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+            for (event_id, reason) in &undecodable {
+                quarantine
+                    .execute(rusqlite::params![reason, quarantined_at, event_id])
+                    .map_err(|e| {
+                        StorageError::Database(format!("Failed to quarantine event: {e}"))
+                    })?;
+            }
+            warn!(
+                "{} pending events quarantined: stored data does not deserialize",
+                undecodable.len()
+            );
+            // A page of only corrupt rows would read as an empty backlog;
+            // those rows are gone now, so look again.
+            if !events.is_empty() {
+                return Ok(events);
+            }
         })
         .await
         .map_err(Into::into)
@@ -281,7 +294,7 @@ impl StorageService for SqliteStorage {
                 params.iter().map(|p| p.as_ref()).collect();
 
             conn.execute(&sql, param_refs.as_slice())
-                .map_err(|e| StorageError::Internal(format!("Failed to mark as sent: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to mark as sent: {e}")))?;
 
             debug!("{}items event sent completed", ids.len());
             Ok(())
@@ -290,6 +303,41 @@ impl StorageService for SqliteStorage {
         .map_err(Into::into)
     }
 
+    async fn get_idempotency_keys(
+        &self,
+        event_ids: &[String],
+    ) -> Result<HashMap<String, String>, CoreError> {
+        if event_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let ids: Vec<String> = event_ids.to_vec();
+
+        self.with_conn(move |conn| {
+            let placeholders: Vec<String> = (1..=ids.len()).map(|i| format!("?{i}")).collect();
+            let sql = format!(
+                "SELECT event_id, idempotency_key FROM events
+                 WHERE idempotency_key IS NOT NULL AND event_id IN ({})",
+                placeholders.join(", ")
+            );
+            let mut stmt = conn
+                .prepare(&sql)
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+            let keys = stmt
+                .query_map(rusqlite::params_from_iter(ids.iter()), |row| {
+                    Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?))
+                })
+                .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
+                .collect::<Result<HashMap<_, _>, _>>()
+                .map_err(|e| {
+                    StorageError::Database(format!("Failed to read idempotency keys: {e}"))
+                })?;
+            Ok(keys)
+        })
+        .await
+        .map_err(Into::into)
+    }
+
     async fn mark_unsent_as_sent_before(&self, before: DateTime<Utc>) -> Result<usize, CoreError> {
         let cutoff = before.to_rfc3339();
 
@@ -300,7 +348,7 @@ impl StorageService for SqliteStorage {
                     rusqlite::params![cutoff],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to mark unsent as sent: {e}"))
+                    StorageError::Database(format!("Failed to mark unsent as sent: {e}"))
                 })?;
 
             if updated > 0 {
@@ -312,19 +360,61 @@ impl StorageService for SqliteStorage {
         .map_err(Into::into)
     }
 
+    async fn quarantine_events(&self, events: &[QuarantinedEvent]) -> Result<(), CoreError> {
+        if events.is_empty() {
+            return Ok(());
+        }
+
+        let events: Vec<QuarantinedEvent> = events.to_vec();
+        let quarantined_at = Utc::now().to_rfc3339();
+
+        self.with_conn_mut(move |conn| {
+            let tx = conn
+                .transaction()
+                .map_err(|e| StorageError::Database(format!("Failed to start transaction: {e}")))?;
+            {
+                let mut stmt = tx
+                    .prepare_cached(
+                        "UPDATE events SET is_sent = 1, quarantine_reason = ?1, quarantined_at = ?2 \
+                         WHERE event_id = ?3",
+                    )
+                    .map_err(|e| {
+                        StorageError::Database(format!("Failed to prepare query: {e}"))
+                    })?;
+                for event in &events {
+                    stmt.execute(rusqlite::params![event.reason, quarantined_at, event.event_id])
+                        .map_err(|e| {
+                            StorageError::Database(format!("Failed to quarantine event: {e}"))
+                        })?;
+                }
+            }
+            tx.commit()
+                .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
+
+            warn!("{} events quarantined after upload rejection", events.len());
+            Ok(())
+        })
+        .await
+        .map_err(Into::into)
+    }
+
     async fn enforce_retention(&self) -> Result<usize, CoreError> {
-        let cutoff = (Utc::now() - Duration::days(self.retention_days as i64)).to_rfc3339();
-        let retention_days = self.retention_days;
+        let retention_days = self.retention_days.load(Ordering::Relaxed);
+        let overrides = self.retention_overrides.read().clone();
+        let now = Utc::now();
 
-        self.with_conn(move |conn| {
-            let deleted = conn
-                .execute(
+        self.with_conn_mut(move |conn| {
+            let deleted = if overrides.days_by_category.is_empty() {
+                conn.execute(
                     "DELETE FROM events WHERE timestamp < ?1 AND is_sent = 1",
-                    rusqlite::params![cutoff],
+                    rusqlite::params![retention_cutoff(now, retention_days)],
                 )
-                .map_err(|e| {
-                    StorageError::Internal(format!("Failed to apply retention policy: {e}"))
-                })?;
+            } else {
+                delete_expired_by_category(conn, now, retention_days, &overrides)
+            }
+            .map_err(|e| {
+                StorageError::Database(format!("Failed to apply retention policy: {e}"))
+            })?;
 
             if deleted > 0 {
                 info!(
@@ -351,7 +441,7 @@ impl StorageService for SqliteStorage {
                 rusqlite::params![summary, id],
             )
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to update segment summary: {e}"))
+                StorageError::Database(format!("Failed to update segment summary: {e}"))
             })?;
             Ok(())
         })
@@ -383,7 +473,7 @@ impl StorageService for SqliteStorage {
                     suggestion.expires_at.map(|t| t.to_rfc3339()),
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to save suggestion: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to save suggestion: {e}")))?;
             debug!(id = %suggestion.suggestion_id, "suggestion persisted to SQLite");
             Ok(())
         })
@@ -392,6 +482,57 @@ impl StorageService for SqliteStorage {
     }
 }
 
+fn retention_cutoff(now: DateTime<Utc>, days: u32) -> String {
+    (now - Duration::days(i64::from(days))).to_rfc3339()
+}
+
+/// Delete sent events past their app category's window, or the global one.
+/// Only apps with events older than the shortest window are classified.
+fn delete_expired_by_category(
+    conn: &mut rusqlite::Connection,
+    now: DateTime<Utc>,
+    global_days: u32,
+    overrides: &RetentionOverrides,
+) -> rusqlite::Result<usize> {
+    let shortest_days = overrides
+        .days_by_category
+        .values()
+        .copied()
+        .fold(global_days, u32::min);
+    let app_names: Vec<String> = conn
+        .prepare(
+            "SELECT DISTINCT json_extract(data, '$.app_name') FROM events
+             WHERE timestamp < ?1 AND is_sent = 1
+               AND json_extract(data, '$.app_name') IS NOT NULL",
+        )?
+        .query_map([retention_cutoff(now, shortest_days)], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+
+    let tx = conn.transaction()?;
+    let mut deleted = 0;
+    for app_name in app_names {
+        let category =
+            AppCategory::from_app_name_with_overrides(&app_name, &overrides.app_categories);
+        let days = overrides
+            .days_by_category
+            .get(&category)
+            .copied()
+            .unwrap_or(global_days);
+        deleted += tx.execute(
+            "DELETE FROM events WHERE timestamp < ?1 AND is_sent = 1
+               AND json_extract(data, '$.app_name') = ?2",
+            rusqlite::params![retention_cutoff(now, days), app_name],
+        )?;
+    }
+    deleted += tx.execute(
+        "DELETE FROM events WHERE timestamp < ?1 AND is_sent = 1
+           AND json_extract(data, '$.app_name') IS NULL",
+        [retention_cutoff(now, global_days)],
+    )?;
+    tx.commit()?;
+    Ok(deleted)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -13,9 +13,9 @@ impl FewShotStorage for SqliteStorage {
         &self,
         limit: usize,
     ) -> Result<Vec<SuggestionHistoryEntry>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         let mut stmt = conn
@@ -28,7 +28,7 @@ impl FewShotStorage for SqliteStorage {
                  LIMIT ?1",
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("prepare: {e}"),
             })?;
 
@@ -51,14 +51,14 @@ impl FewShotStorage for SqliteStorage {
                 })
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("query: {e}"),
             })?;
 
         let mut result = Vec::new();
         for row in rows {
             result.push(row.map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("row: {e}"),
             })?);
         }
@@ -77,9 +77,9 @@ impl FewShotStorage for SqliteStorage {
         context_window: &str,
         regime_label: Option<&str>,
     ) -> Result<(), CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         conn.execute(
@@ -100,7 +100,7 @@ impl FewShotStorage for SqliteStorage {
             ],
         )
         .map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Database,
             message: format!("update: {e}"),
         })?;
 
@@ -128,7 +128,7 @@ mod tests {
 
         // local_suggestions에 직접 삽입 (V28 이후 컬럼 포함, payload는 NOT NULL이므로 빈 JSON 사용)
         {
-            let conn = storage.conn.lock().unwrap();
+            let conn = storage.pool.get().unwrap();
             conn.execute(
                 "INSERT INTO local_suggestions
                  (suggestion_id, suggestion_type, content, confidence, payload, created_at)
@@ -171,7 +171,7 @@ mod tests {
 
         // 3개의 suggestion 삽입 (payload NOT NULL 요건 충족을 위해 빈 JSON 사용)
         {
-            let conn = storage.conn.lock().unwrap();
+            let conn = storage.pool.get().unwrap();
             for i in 1..=3u32 {
                 conn.execute(
                     "INSERT INTO local_suggestions
@@ -211,7 +211,7 @@ mod tests {
         let storage = SqliteStorage::open_in_memory(30).unwrap();
 
         {
-            let conn = storage.conn.lock().unwrap();
+            let conn = storage.pool.get().unwrap();
             // 피드백 있는 항목 (payload NOT NULL 요건 충족)
             conn.execute(
                 "INSERT INTO local_suggestions
@@ -1,3 +1,4 @@
+use chrono::{DateTime, Utc};
 use oneshim_core::error::CoreError;
 use oneshim_core::models::suggestion::Suggestion;
 use oneshim_core::models::work_session::{AppCategory, FocusMetrics, Interruption, WorkSession};
@@ -52,6 +53,18 @@ impl FocusStorage for SqliteStorage {
         SqliteStorage::end_work_session(self, session_id).map_err(Into::into)
     }
 
+    fn end_work_session_at(
+        &self,
+        session_id: i64,
+        ended_at: DateTime<Utc>,
+    ) -> Result<(), CoreError> {
+        SqliteStorage::end_work_session_at(self, session_id, ended_at).map_err(Into::into)
+    }
+
+    fn record_work_session_gap(&self, session_id: i64, gap_secs: u64) -> Result<(), CoreError> {
+        SqliteStorage::record_work_session_gap(self, session_id, gap_secs).map_err(Into::into)
+    }
+
     fn start_work_session(
         &self,
         primary_app: &str,
@@ -2,35 +2,104 @@ use crate::error::StorageError;
 use chrono::{DateTime, Utc};
 use oneshim_core::models::context::WindowBounds;
 use oneshim_core::models::frame::FrameMetadata;
+use oneshim_core::models::storage_records::NewFrameMetadata;
 use oneshim_core::types::TimeWindow;
-use tracing::debug;
+use std::sync::atomic::Ordering;
+use tracing::{debug, warn};
 
-use super::{FrameRecord, SqliteStorage};
+use super::tags::apply_tag_rules_with;
+use super::text_compression::{read_text, StoredText};
+use super::{FrameRecord, SqliteStorage, OCR_FTS_AVAILABLE};
+
+const INSERT_FRAME_SQL: &str = "INSERT INTO frames (timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, has_image, file_path, ocr_text, window_x, window_y, window_width, window_height, sha256)
+     VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15)";
+
+/// Bound on hashes noted for frame files whose rows never get inserted.
+const MAX_PENDING_FRAME_HASHES: usize = 1024;
+
+fn execute_frame_insert(
+    stmt: &mut rusqlite::CachedStatement<'_>,
+    frame: &NewFrameMetadata<'_>,
+    sha256: Option<&str>,
+    text_compression_min_bytes: Option<usize>,
+) -> rusqlite::Result<i64> {
+    let metadata = frame.metadata;
+    let bounds = frame.bounds;
+    stmt.insert(rusqlite::params![
+        metadata.timestamp.to_rfc3339(),
+        metadata.trigger_type,
+        metadata.app_name,
+        metadata.window_title,
+        metadata.importance,
+        metadata.resolution.0,
+        metadata.resolution.1,
+        frame.file_path.is_some(),
+        frame.file_path,
+        frame
+            .ocr_text
+            .map(|text| StoredText::encode(text, text_compression_min_bytes)),
+        bounds.map(|b| b.x),
+        bounds.map(|b| b.y),
+        bounds.map(|b| b.width as i32),
+        bounds.map(|b| b.height as i32),
+        sha256,
+    ])
+}
+
+/// Trigram FTS5 cannot match terms shorter than three characters.
+const MIN_FTS_TERM_CHARS: usize = 3;
+
+/// Mirror the plain OCR text of a new frame into `frames_ocr_fts`.
+pub(super) fn index_frame_ocr(
+    conn: &rusqlite::Connection,
+    frame_id: i64,
+    ocr_text: Option<&str>,
+) -> rusqlite::Result<()> {
+    let Some(text) = ocr_text.filter(|text| !text.trim().is_empty()) else {
+        return Ok(());
+    };
+    if !OCR_FTS_AVAILABLE.load(Ordering::Relaxed) {
+        return Ok(());
+    }
+    conn.prepare_cached("INSERT INTO frames_ocr_fts (rowid, ocr_text) VALUES (?1, ?2)")?
+        .execute(rusqlite::params![frame_id, text])?;
+    Ok(())
+}
+
+/// Escape `%`, `_` and `\` for `LIKE ... ESCAPE '\'`.
+fn like_pattern(term: &str) -> String {
+    let escaped = term
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_");
+    format!("%{escaped}%")
+}
 
 impl SqliteStorage {
     pub fn count_frames_in_range(&self, window: &TimeWindow) -> Result<u64, StorageError> {
         let (from, to) = window.to_sql_pair();
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let count: i64 = conn
             .query_row(
-                "SELECT COUNT(*) FROM frames WHERE timestamp >= ?1 AND timestamp <= ?2",
+                "SELECT COUNT(*) FROM frames
+                 WHERE timestamp >= ?1 AND timestamp <= ?2 AND deleted_at IS NULL",
                 rusqlite::params![from, to],
                 |row| row.get(0),
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to count frames: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to count frames: {e}")))?;
 
         Ok(count as u64)
     }
 
     pub fn get_frame_file_path(&self, frame_id: i64) -> Result<Option<String>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let result: Result<Option<String>, rusqlite::Error> = conn.query_row(
             "SELECT file_path FROM frames WHERE id = ?1",
@@ -41,12 +110,275 @@ impl SqliteStorage {
         match result {
             Ok(path) => Ok(path),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-            Err(e) => Err(StorageError::Internal(format!(
+            Err(e) => Err(StorageError::Database(format!(
                 "frame file path query failure: {e}"
             ))),
         }
     }
 
+    /// Record the content hash of a frame file written (or reused) by frame
+    /// storage. It is stored on frame rows later inserted for `file_path`.
+    pub fn note_frame_file_hash(&self, file_path: &str, sha256: &str) {
+        let mut pending = self.pending_frame_hashes.lock();
+        if pending.len() >= MAX_PENDING_FRAME_HASHES {
+            pending.clear();
+        }
+        pending.insert(file_path.to_string(), sha256.to_string());
+    }
+
+    /// A path's content never changes, so entries stay valid for every
+    /// frame that shares the file.
+    fn frame_file_hash(&self, file_path: Option<&str>) -> Option<String> {
+        file_path.and_then(|path| self.pending_frame_hashes.lock().get(path).cloned())
+    }
+
+    /// Relative path of the newest frame file with content hash `sha256`.
+    pub fn find_frame_file_by_sha256(&self, sha256: &str) -> Result<Option<String>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let result: Result<String, rusqlite::Error> = conn.query_row(
+            "SELECT file_path FROM frames
+             WHERE sha256 = ?1 AND file_path IS NOT NULL
+             ORDER BY id DESC LIMIT 1",
+            rusqlite::params![sha256],
+            |row| row.get(0),
+        );
+
+        match result {
+            Ok(path) => Ok(Some(path)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(StorageError::Database(format!(
+                "frame hash lookup failure: {e}"
+            ))),
+        }
+    }
+
+    /// Frame files referenced by more than one frame.
+    pub fn get_shared_frame_paths(&self) -> Result<Vec<String>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT file_path FROM frames WHERE file_path IS NOT NULL
+                 GROUP BY file_path HAVING COUNT(*) > 1",
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+        let rows = stmt
+            .query_map([], |row| row.get::<_, String>(0))
+            .map_err(|e| StorageError::Database(format!("Failed to query frames: {e}")))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| StorageError::Database(format!("Failed to read frame row: {e}")))
+    }
+
+    /// Files referenced by frames taken on or after `since` (an RFC 3339
+    /// prefix such as a `YYYY-MM-DD` date). Deduplication can point such a
+    /// frame at a file in an older day shard.
+    pub fn get_frame_paths_since(&self, since: &str) -> Result<Vec<String>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT DISTINCT file_path FROM frames
+                 WHERE file_path IS NOT NULL AND timestamp >= ?1",
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+        let rows = stmt
+            .query_map([since], |row| row.get::<_, String>(0))
+            .map_err(|e| StorageError::Database(format!("Failed to query frames: {e}")))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| StorageError::Database(format!("Failed to read frame row: {e}")))
+    }
+
+    /// Flag the image file of `frame_id` as unreadable so the next frame
+    /// retention pass removes it. Returns `false` if the frame does not exist.
+    pub fn mark_frame_file_corrupt(&self, frame_id: i64) -> Result<bool, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let updated = conn
+            .execute(
+                "UPDATE frames SET file_corrupt = 1 WHERE id = ?1 AND file_path IS NOT NULL",
+                rusqlite::params![frame_id],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to flag frame file: {e}")))?;
+        Ok(updated > 0)
+    }
+
+    /// Detach every flagged file from its frame and return the paths, for the
+    /// caller to delete. The frame rows (metadata, OCR text) are kept.
+    pub fn take_corrupt_frame_paths(&self) -> Result<Vec<String>, StorageError> {
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| StorageError::Database(format!("Failed to start transaction: {e}")))?;
+
+        let paths = {
+            let mut stmt = tx
+                .prepare(
+                    "SELECT DISTINCT file_path FROM frames WHERE file_corrupt = 1 AND file_path IS NOT NULL",
+                )
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+            let rows = stmt
+                .query_map([], |row| row.get::<_, String>(0))
+                .map_err(|e| StorageError::Database(format!("Failed to query frames: {e}")))?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(|e| StorageError::Database(format!("Failed to read frame row: {e}")))?
+        };
+
+        tx.execute(
+            "UPDATE frames SET file_path = NULL, has_image = 0, file_corrupt = 0
+             WHERE file_corrupt = 1
+                OR file_path IN (SELECT file_path FROM frames WHERE file_corrupt = 1)",
+            [],
+        )
+        .map_err(|e| StorageError::Database(format!("Failed to detach frame files: {e}")))?;
+        tx.commit()
+            .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
+
+        Ok(paths)
+    }
+
+    /// Move a frame to the trash. Returns `false` if it does not exist or is
+    /// already there.
+    pub fn soft_delete_frame(&self, frame_id: i64) -> Result<bool, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let updated = conn
+            .execute(
+                "UPDATE frames SET deleted_at = ?2 WHERE id = ?1 AND deleted_at IS NULL",
+                rusqlite::params![frame_id, Utc::now().to_rfc3339()],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to trash frame: {e}")))?;
+        Ok(updated > 0)
+    }
+
+    /// Take a frame back out of the trash. Returns `false` if it is not there.
+    pub fn restore_frame(&self, frame_id: i64) -> Result<bool, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let updated = conn
+            .execute(
+                "UPDATE frames SET deleted_at = NULL WHERE id = ?1 AND deleted_at IS NOT NULL",
+                rusqlite::params![frame_id],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to restore frame: {e}")))?;
+        Ok(updated > 0)
+    }
+
+    /// Frames in the trash, most recently deleted first.
+    pub fn get_trashed_frames(&self, limit: usize) -> Result<Vec<FrameRecord>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT id, timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, file_path, ocr_text
+                 FROM frames
+                 WHERE deleted_at IS NOT NULL
+                 ORDER BY deleted_at DESC
+                 LIMIT ?1",
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+
+        let frames = stmt
+            .query_map(rusqlite::params![limit as i64], |row| {
+                Ok(FrameRecord {
+                    id: row.get(0)?,
+                    timestamp: row.get(1)?,
+                    trigger_type: row.get(2)?,
+                    app_name: row.get(3)?,
+                    window_title: row.get(4)?,
+                    importance: row.get(5)?,
+                    resolution_w: row.get(6)?,
+                    resolution_h: row.get(7)?,
+                    file_path: row.get(8)?,
+                    ocr_text: read_text(row, 9)?,
+                })
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(frames)
+    }
+
+    /// Permanently delete frames trashed before `older_than` and return the
+    /// image files no remaining frame references, for the caller to delete.
+    pub fn purge_trash(&self, older_than: DateTime<Utc>) -> Result<Vec<String>, StorageError> {
+        let cutoff = older_than.to_rfc3339();
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| StorageError::Database(format!("Failed to start transaction: {e}")))?;
+
+        let paths = {
+            let mut stmt = tx
+                .prepare(
+                    "SELECT DISTINCT file_path FROM frames
+                     WHERE deleted_at < ?1 AND file_path IS NOT NULL
+                       AND file_path NOT IN (
+                           SELECT file_path FROM frames
+                           WHERE file_path IS NOT NULL
+                             AND (deleted_at IS NULL OR deleted_at >= ?1)
+                       )",
+                )
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+            let rows = stmt
+                .query_map(rusqlite::params![cutoff], |row| row.get::<_, String>(0))
+                .map_err(|e| StorageError::Database(format!("Failed to query frames: {e}")))?;
+            rows.collect::<Result<Vec<_>, _>>()
+                .map_err(|e| StorageError::Database(format!("Failed to read frame row: {e}")))?
+        };
+
+        // Foreign keys are not enforced, so frame tags are removed by hand.
+        tx.execute(
+            "DELETE FROM frame_tags
+             WHERE frame_id IN (SELECT id FROM frames WHERE deleted_at < ?1)",
+            rusqlite::params![cutoff],
+        )
+        .map_err(|e| StorageError::Database(format!("Failed to delete frame tags: {e}")))?;
+        let purged = tx
+            .execute(
+                "DELETE FROM frames WHERE deleted_at < ?1",
+                rusqlite::params![cutoff],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to purge trash: {e}")))?;
+        tx.commit()
+            .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
+
+        if purged > 0 {
+            debug!("trash purge: {purged} frames, {} files", paths.len());
+        }
+        Ok(paths)
+    }
+
     /// # Arguments
     pub fn save_frame_metadata(
         &self,
@@ -57,7 +389,7 @@ impl SqliteStorage {
         self.save_frame_metadata_with_bounds(metadata, file_path, ocr_text, None)
     }
 
-    /// # Arguments
+    /// Tag rules are applied to the new frame in the same transaction.
     pub fn save_frame_metadata_with_bounds(
         &self,
         metadata: &FrameMetadata,
@@ -65,34 +397,39 @@ impl SqliteStorage {
         ocr_text: Option<&str>,
         bounds: Option<&WindowBounds>,
     ) -> Result<i64, StorageError> {
-        let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
-
-        conn.execute(
-            "INSERT INTO frames (timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, has_image, file_path, ocr_text, window_x, window_y, window_width, window_height)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)",
-            rusqlite::params![
-                metadata.timestamp.to_rfc3339(),
-                metadata.trigger_type,
-                metadata.app_name,
-                metadata.window_title,
-                metadata.importance,
-                metadata.resolution.0,
-                metadata.resolution.1,
-                file_path.is_some(),
-                file_path,
-                ocr_text,
-                bounds.map(|b| b.x),
-                bounds.map(|b| b.y),
-                bounds.map(|b| b.width as i32),
-                bounds.map(|b| b.height as i32),
-            ],
-        )
-        .map_err(|e| StorageError::Internal(format!("Failed to save frame metadata: {e}")))?;
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let frame = NewFrameMetadata {
+            metadata,
+            file_path,
+            ocr_text,
+            bounds,
+        };
+        let tx = conn
+            .transaction()
+            .map_err(|e| StorageError::Database(format!("Failed to start transaction: {e}")))?;
+        let sha256 = self.frame_file_hash(file_path);
+        let frame_id = tx
+            .prepare_cached(INSERT_FRAME_SQL)
+            .and_then(|mut stmt| {
+                execute_frame_insert(
+                    &mut stmt,
+                    &frame,
+                    sha256.as_deref(),
+                    self.text_compression_min_bytes,
+                )
+            })
+            .and_then(|frame_id| index_frame_ocr(&tx, frame_id, ocr_text).map(|()| frame_id))
+            .and_then(|frame_id| {
+                apply_tag_rules_with(&tx, frame_id, &metadata.app_name).map(|_| frame_id)
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to save frame metadata: {e}")))?;
+        tx.commit()
+            .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
 
-        let frame_id = conn.last_insert_rowid();
         debug!(
             "frame metadata saved: id={}, app={}, file={}",
             frame_id,
@@ -103,6 +440,60 @@ impl SqliteStorage {
         Ok(frame_id)
     }
 
+    /// Insert several frames in one transaction with a single prepared statement.
+    /// Tag rules are applied as in [`Self::save_frame_metadata_with_bounds`].
+    ///
+    /// Returns the new row ids in input order. Either every row is written or,
+    /// on error, none are.
+    pub fn save_frames_metadata(
+        &self,
+        frames: &[NewFrameMetadata<'_>],
+    ) -> Result<Vec<i64>, StorageError> {
+        if frames.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| StorageError::Database(format!("Failed to start transaction: {e}")))?;
+
+        let mut frame_ids = Vec::with_capacity(frames.len());
+        {
+            let mut stmt = tx
+                .prepare_cached(INSERT_FRAME_SQL)
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+
+            for frame in frames {
+                let sha256 = self.frame_file_hash(frame.file_path);
+                let frame_id = execute_frame_insert(
+                    &mut stmt,
+                    frame,
+                    sha256.as_deref(),
+                    self.text_compression_min_bytes,
+                )
+                .and_then(|frame_id| {
+                    index_frame_ocr(&tx, frame_id, frame.ocr_text).map(|()| frame_id)
+                })
+                .and_then(|frame_id| {
+                    apply_tag_rules_with(&tx, frame_id, &frame.metadata.app_name).map(|_| frame_id)
+                })
+                .map_err(|e| StorageError::Database(format!("frame batch save failure: {e}")))?;
+                frame_ids.push(frame_id);
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
+
+        debug!("frame metadata batch save: {}items", frames.len());
+        Ok(frame_ids)
+    }
+
     /// # Arguments
     pub fn get_frames(
         &self,
@@ -114,19 +505,19 @@ impl SqliteStorage {
         let to_str = to.to_rfc3339();
 
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare_cached(
                 "SELECT id, timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, file_path, ocr_text
                  FROM frames
-                 WHERE timestamp >= ?1 AND timestamp <= ?2
+                 WHERE timestamp >= ?1 AND timestamp <= ?2 AND deleted_at IS NULL
                  ORDER BY timestamp DESC
                  LIMIT ?3",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let frames = stmt
             .query_map(rusqlite::params![from_str, to_str, limit as i64], |row| {
@@ -140,10 +531,94 @@ impl SqliteStorage {
                     resolution_w: row.get(6)?,
                     resolution_h: row.get(7)?,
                     file_path: row.get(8)?,
-                    ocr_text: row.get(9)?,
+                    ocr_text: read_text(row, 9)?,
+                })
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
+            .filter_map(|r| r.ok())
+            .collect();
+
+        Ok(frames)
+    }
+
+    /// Full-text search over OCR text, best bm25 match first.
+    ///
+    /// Whitespace-separated terms must all match, as substrings. Terms
+    /// shorter than the trigram minimum (common for two-syllable Korean
+    /// words) are matched with `LIKE` against the indexed text instead.
+    pub fn search_frames_fts(
+        &self,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<FrameRecord>, StorageError> {
+        let terms: Vec<&str> = query.split_whitespace().collect();
+        if terms.is_empty() || limit == 0 {
+            return Ok(Vec::new());
+        }
+        if !OCR_FTS_AVAILABLE.load(Ordering::Relaxed) {
+            warn!("frames_ocr_fts unavailable; OCR full-text search returns no results");
+            return Ok(Vec::new());
+        }
+
+        let (long, short): (Vec<&str>, Vec<&str>) = terms
+            .into_iter()
+            .partition(|term| term.chars().count() >= MIN_FTS_TERM_CHARS);
+        let mut clauses = Vec::new();
+        let mut params: Vec<String> = Vec::new();
+        if !long.is_empty() {
+            clauses.push("frames_ocr_fts MATCH ?");
+            params.push(
+                long.iter()
+                    .map(|term| format!("\"{}\"", term.replace('"', "\"\"")))
+                    .collect::<Vec<_>>()
+                    .join(" AND "),
+            );
+        }
+        for term in short {
+            clauses.push("frames_ocr_fts.ocr_text LIKE ? ESCAPE '\\'");
+            params.push(like_pattern(term));
+        }
+        let order_by = if long.is_empty() {
+            "f.timestamp DESC"
+        } else {
+            "bm25(frames_ocr_fts), f.timestamp DESC"
+        };
+        let sql = format!(
+            "SELECT f.id, f.timestamp, f.trigger_type, f.app_name, f.window_title, f.importance, f.resolution_w, f.resolution_h, f.file_path, f.ocr_text
+             FROM frames_ocr_fts
+             JOIN frames f ON f.id = frames_ocr_fts.rowid
+             WHERE f.deleted_at IS NULL AND {}
+             ORDER BY {order_by}
+             LIMIT {}",
+            clauses.join(" AND "),
+            limit as i64
+        );
+
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let mut stmt = conn
+            .prepare(&sql)
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+
+        let frames = stmt
+            .query_map(rusqlite::params_from_iter(params.iter()), |row| {
+                Ok(FrameRecord {
+                    id: row.get(0)?,
+                    timestamp: row.get(1)?,
+                    trigger_type: row.get(2)?,
+                    app_name: row.get(3)?,
+                    window_title: row.get(4)?,
+                    importance: row.get(5)?,
+                    resolution_w: row.get(6)?,
+                    resolution_h: row.get(7)?,
+                    file_path: row.get(8)?,
+                    ocr_text: read_text(row, 9)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -222,4 +697,333 @@ mod tests {
             .expect("get_frame_file_path failed");
         assert_eq!(path.as_deref(), Some("/tmp/frame.webp"));
     }
+
+    #[test]
+    fn corrupt_frame_file_is_detached_once_taken() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let meta = make_metadata();
+        let bad = storage
+            .save_frame_metadata(&meta, Some("frames/bad.webp"), Some("kept ocr"))
+            .expect("save_frame_metadata failed");
+        let good = storage
+            .save_frame_metadata(&meta, Some("frames/good.webp"), None)
+            .expect("save_frame_metadata failed");
+
+        assert!(storage.mark_frame_file_corrupt(bad).unwrap());
+        assert!(!storage.mark_frame_file_corrupt(99999).unwrap());
+
+        assert_eq!(
+            storage.take_corrupt_frame_paths().unwrap(),
+            vec!["frames/bad.webp".to_string()]
+        );
+        assert!(storage.take_corrupt_frame_paths().unwrap().is_empty());
+        assert_eq!(storage.get_frame_file_path(bad).unwrap(), None);
+        assert_eq!(
+            storage.get_frame_file_path(good).unwrap().as_deref(),
+            Some("frames/good.webp")
+        );
+    }
+
+    #[test]
+    fn large_ocr_text_is_compressed_at_rest_and_read_back_identically() {
+        let storage = SqliteStorage::open_in_memory(30)
+            .expect("open_in_memory failed")
+            .with_text_compression(Some(1024));
+        let meta = make_metadata();
+        let large = "Quarterly report — revenue 1,234,567 KRW, margin 12.5%\n".repeat(500);
+        let compressed_id = storage
+            .save_frame_metadata(&meta, None, Some(&large))
+            .expect("save large");
+        let plain_id = storage
+            .save_frame_metadata(&meta, None, Some("short text"))
+            .expect("save short");
+
+        {
+            let conn = storage.pool.get().expect("lock");
+            let stored: (String, i64) = conn
+                .query_row(
+                    "SELECT typeof(ocr_text), length(ocr_text) FROM frames WHERE id = ?1",
+                    [compressed_id],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .expect("stored row");
+            assert_eq!(stored.0, "blob");
+            assert!((stored.1 as usize) < large.len() / 10);
+        }
+
+        let frames = storage
+            .get_frames(
+                Utc::now() - Duration::hours(1),
+                Utc::now() + Duration::hours(1),
+                10,
+            )
+            .expect("get_frames");
+        let text_of = |id: i64| {
+            frames
+                .iter()
+                .find(|frame| frame.id == id)
+                .and_then(|frame| frame.ocr_text.clone())
+        };
+        assert_eq!(text_of(compressed_id).as_deref(), Some(large.as_str()));
+        assert_eq!(text_of(plain_id).as_deref(), Some("short text"));
+    }
+
+    #[test]
+    fn uncompressed_rows_stay_readable_after_enabling_compression() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let large = "legacy ocr row ".repeat(200);
+        storage
+            .save_frame_metadata(&make_metadata(), None, Some(&large))
+            .expect("save");
+
+        let storage = storage.with_text_compression(Some(64));
+        let frames = storage
+            .get_frames(
+                Utc::now() - Duration::hours(1),
+                Utc::now() + Duration::hours(1),
+                10,
+            )
+            .expect("get_frames");
+        assert_eq!(frames[0].ocr_text.as_deref(), Some(large.as_str()));
+    }
+
+    #[test]
+    fn save_frames_metadata_batch_stores_rows_and_bounds_in_order() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let metas: Vec<FrameMetadata> = (0..3).map(|_| make_metadata()).collect();
+        let bounds: Vec<WindowBounds> = (0..3)
+            .map(|i| WindowBounds {
+                x: i * 10,
+                y: i * 20,
+                width: 800 + i as u32,
+                height: 600 + i as u32,
+            })
+            .collect();
+        let batch: Vec<NewFrameMetadata<'_>> = metas
+            .iter()
+            .zip(&bounds)
+            .enumerate()
+            .map(|(i, (metadata, bounds))| NewFrameMetadata {
+                metadata,
+                file_path: (i == 0).then_some("frames/0.webp"),
+                ocr_text: None,
+                bounds: Some(bounds),
+            })
+            .collect();
+
+        let ids = storage
+            .save_frames_metadata(&batch)
+            .expect("save_frames_metadata failed");
+        assert_eq!(ids.len(), 3);
+        assert!(ids.windows(2).all(|pair| pair[1] == pair[0] + 1));
+
+        let conn = storage.pool.get().expect("lock");
+        for (id, expected) in ids.iter().zip(&bounds) {
+            let stored: (i32, i32, i32, i32) = conn
+                .query_row(
+                    "SELECT window_x, window_y, window_width, window_height FROM frames WHERE id = ?1",
+                    [id],
+                    |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+                )
+                .expect("frame row");
+            assert_eq!(
+                stored,
+                (
+                    expected.x,
+                    expected.y,
+                    expected.width as i32,
+                    expected.height as i32
+                )
+            );
+        }
+        drop(conn);
+        assert_eq!(
+            storage.get_frame_file_path(ids[0]).expect("path"),
+            Some("frames/0.webp".to_string())
+        );
+    }
+
+    #[test]
+    fn save_frames_metadata_empty_is_noop() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        assert!(storage
+            .save_frames_metadata(&[])
+            .expect("empty batch")
+            .is_empty());
+    }
+
+    fn search_ids(storage: &SqliteStorage, query: &str) -> Vec<i64> {
+        storage
+            .search_frames_fts(query, 10)
+            .expect("search_frames_fts failed")
+            .into_iter()
+            .map(|frame| frame.id)
+            .collect()
+    }
+
+    #[test]
+    fn search_frames_fts_ranks_matches_and_requires_all_terms() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let meta = make_metadata();
+        let once = storage
+            .save_frame_metadata(&meta, None, Some("Invoice draft for ACME, mostly notes"))
+            .unwrap();
+        let twice = storage
+            .save_frame_metadata(&meta, None, Some("invoice invoice total due"))
+            .unwrap();
+        storage
+            .save_frame_metadata(&meta, None, Some("unrelated terminal output"))
+            .unwrap();
+        storage.save_frame_metadata(&meta, None, None).unwrap();
+
+        assert_eq!(search_ids(&storage, "invoice"), [twice, once]);
+        assert_eq!(search_ids(&storage, "invoice acme"), [once]);
+        assert!(search_ids(&storage, "   ").is_empty());
+        assert!(search_ids(&storage, "\"quoted\" OR *").is_empty());
+    }
+
+    #[test]
+    fn search_frames_fts_matches_korean_substrings() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let meta = make_metadata();
+        let quote = storage
+            .save_frame_metadata(&meta, None, Some("고객사에 견적서를 송부했습니다"))
+            .unwrap();
+        let meeting = storage
+            .save_frame_metadata(&meta, None, Some("주간 회의 자료"))
+            .unwrap();
+
+        assert_eq!(search_ids(&storage, "견적서"), [quote]);
+        // Two-syllable terms fall below the trigram minimum.
+        assert_eq!(search_ids(&storage, "회의"), [meeting]);
+        assert_eq!(search_ids(&storage, "견적서 송부"), [quote]);
+    }
+
+    #[test]
+    fn search_frames_fts_covers_compressed_text_and_drops_deleted_frames() {
+        let storage = SqliteStorage::open_in_memory(30)
+            .expect("open_in_memory failed")
+            .with_text_compression(Some(64));
+        let meta = make_metadata();
+        let large = "Quarterly revenue forecast spreadsheet\n".repeat(20);
+        let batch = storage
+            .save_frames_metadata(&[NewFrameMetadata {
+                metadata: &meta,
+                file_path: None,
+                ocr_text: Some(&large),
+                bounds: None,
+            }])
+            .unwrap();
+        assert_eq!(search_ids(&storage, "forecast"), batch);
+
+        let from = (Utc::now() - Duration::hours(1)).to_rfc3339();
+        let to = (Utc::now() + Duration::hours(1)).to_rfc3339();
+        storage
+            .delete_data_in_range(
+                &TimeWindow::from_rfc3339_pair(&from, &to).expect("trusted test bounds"),
+                false,
+                true,
+                false,
+                false,
+                false,
+            )
+            .unwrap();
+        assert!(search_ids(&storage, "forecast").is_empty());
+    }
+
+    #[test]
+    fn tag_rules_tag_matching_captures() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let tag = storage.create_tag("communication", "#22c55e").unwrap();
+        storage.create_tag_rule("slack", tag.id).unwrap();
+        storage.create_tag_rule("SLACK", tag.id).unwrap();
+
+        let mut meta = make_metadata();
+        meta.app_name = "Slack Helper".to_string();
+        let matched = storage
+            .save_frame_metadata_with_bounds(&meta, None, None, None)
+            .unwrap();
+        meta.app_name = "Code".to_string();
+        let unmatched = storage
+            .save_frame_metadata_with_bounds(&meta, None, None, None)
+            .unwrap();
+
+        let tags = storage.get_tags_for_frame(matched).unwrap();
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].id, tag.id);
+        assert!(tags[0].auto_applied);
+        assert!(storage.get_tags_for_frame(unmatched).unwrap().is_empty());
+        assert_eq!(storage.apply_tag_rules(matched, "Slack").unwrap(), 0);
+    }
+
+    #[test]
+    fn trashed_frames_are_hidden_until_restored() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let meta = make_metadata();
+        let frame_id = storage
+            .save_frame_metadata(&meta, None, Some("quarterly invoice"))
+            .unwrap();
+        let tag = storage.create_tag("work", "#3b82f6").unwrap();
+        storage.add_tag_to_frame(frame_id, tag.id).unwrap();
+        let from = Utc::now() - Duration::hours(1);
+        let to = Utc::now() + Duration::hours(1);
+        let window = TimeWindow::from_rfc3339_pair(&from.to_rfc3339(), &to.to_rfc3339())
+            .expect("trusted test bounds");
+
+        assert!(storage.soft_delete_frame(frame_id).unwrap());
+        assert!(!storage.soft_delete_frame(frame_id).unwrap());
+        assert!(storage.get_frames(from, to, 10).unwrap().is_empty());
+        assert_eq!(storage.count_frames_in_range(&window).unwrap(), 0);
+        assert!(storage.get_frames_by_tag(tag.id, 10).unwrap().is_empty());
+        assert!(search_ids(&storage, "invoice").is_empty());
+        let trashed: Vec<i64> = storage
+            .get_trashed_frames(10)
+            .unwrap()
+            .iter()
+            .map(|frame| frame.id)
+            .collect();
+        assert_eq!(trashed, [frame_id]);
+
+        assert!(storage.restore_frame(frame_id).unwrap());
+        assert!(!storage.restore_frame(frame_id).unwrap());
+        assert_eq!(storage.get_frames(from, to, 10).unwrap().len(), 1);
+        assert_eq!(storage.get_frames_by_tag(tag.id, 10).unwrap().len(), 1);
+        assert_eq!(search_ids(&storage, "invoice"), [frame_id]);
+    }
+
+    #[test]
+    fn purge_trash_removes_old_trash_and_returns_unshared_files() {
+        let storage = SqliteStorage::open_in_memory(30).expect("open_in_memory failed");
+        let meta = make_metadata();
+        let own = storage
+            .save_frame_metadata(&meta, Some("frames/own.webp"), None)
+            .unwrap();
+        let shared = storage
+            .save_frame_metadata(&meta, Some("frames/shared.webp"), None)
+            .unwrap();
+        let live = storage
+            .save_frame_metadata(&meta, Some("frames/shared.webp"), None)
+            .unwrap();
+        let tag = storage.create_tag("work", "#3b82f6").unwrap();
+        storage.add_tag_to_frame(own, tag.id).unwrap();
+        assert!(storage.soft_delete_frame(own).unwrap());
+        assert!(storage.soft_delete_frame(shared).unwrap());
+
+        assert!(storage
+            .purge_trash(Utc::now() - Duration::days(7))
+            .unwrap()
+            .is_empty());
+        assert_eq!(storage.get_trashed_frames(10).unwrap().len(), 2);
+
+        let paths = storage
+            .purge_trash(Utc::now() + Duration::seconds(1))
+            .unwrap();
+        assert_eq!(paths, ["frames/own.webp"]);
+        assert!(storage.get_trashed_frames(10).unwrap().is_empty());
+        assert!(storage.get_tag_ids_for_frames(&[own]).unwrap().is_empty());
+        assert_eq!(
+            storage.get_frame_file_path(live).unwrap().as_deref(),
+            Some("frames/shared.webp")
+        );
+    }
 }
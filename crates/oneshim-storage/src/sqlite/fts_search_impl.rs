@@ -31,7 +31,7 @@ impl TextSearchProvider for SqliteStorage {
                      ORDER BY rank
                      LIMIT ?2",
                 )
-                .map_err(|e| StorageError::Internal(format!("FTS5 query prepare failed: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("FTS5 query prepare failed: {e}")))?;
 
             let results = stmt
                 .query_map(rusqlite::params![query, limit as i64], |row| {
@@ -42,7 +42,7 @@ impl TextSearchProvider for SqliteStorage {
                         rank: row.get(3)?,
                     })
                 })
-                .map_err(|e| StorageError::Internal(format!("FTS5 query failed: {e}")))?
+                .map_err(|e| StorageError::Database(format!("FTS5 query failed: {e}")))?
                 .filter_map(|r| r.ok())
                 .collect();
 
@@ -127,7 +127,7 @@ impl SqliteStorage {
             rusqlite::params![segment_id],
         )
         .map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Database,
             message: format!("FTS5 delete failed: {e}"),
         })?;
 
@@ -135,7 +135,7 @@ impl SqliteStorage {
             "INSERT INTO search_fts (segment_id, content_type, searchable_text) VALUES (?1, ?2, ?3)",
             rusqlite::params![segment_id, content_type, searchable_text],
         )
-        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("FTS5 insert failed: {e}") })?;
+        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("FTS5 insert failed: {e}") })?;
 
         Ok(())
     }
@@ -311,7 +311,7 @@ mod tests {
 
         // Insert events with window titles in the time range (data column stores JSON)
         {
-            let conn = storage.conn.lock().unwrap();
+            let conn = storage.pool.get().unwrap();
             conn.execute(
                 "INSERT INTO events (event_id, event_type, timestamp, data)
                  VALUES ('evt-1', 'window_change', '2026-03-01T10:00:00Z',
@@ -353,7 +353,7 @@ mod tests {
 
         // Insert a suggestion in the time range
         {
-            let conn = storage.conn.lock().unwrap();
+            let conn = storage.pool.get().unwrap();
             conn.execute(
                 "INSERT INTO suggestions (suggestion_id, suggestion_type, content, priority, source, created_at)
                  VALUES ('sugg-001', 'focus', 'Take a break after debugging session', 'Medium', 'local', '2026-03-01T10:15:00Z')",
@@ -384,7 +384,7 @@ mod tests {
         // Insert a gui_interaction for the segment (V13 schema: event_id, segment_id,
         // timestamp, element_text, element_type, interaction_type, app_name)
         {
-            let conn = storage.conn.lock().unwrap();
+            let conn = storage.pool.get().unwrap();
             conn.execute(
                 "INSERT INTO gui_interactions (event_id, segment_id, timestamp, element_type, element_text, interaction_type, app_name)
                  VALUES ('gui-evt-1', 'seg-enriched-3', '2026-03-01T10:00:00Z', 'button', 'Submit Pull Request', 'click', 'GitHub')",
@@ -15,9 +15,9 @@ impl SqliteStorage {
         met: bool,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         conn.execute(
             "INSERT INTO habit_streaks (regime_label, date, minutes_logged, target_minutes, met)
@@ -26,7 +26,7 @@ impl SqliteStorage {
              DO UPDATE SET minutes_logged = ?3, target_minutes = ?4, met = ?5",
             rusqlite::params![regime_label, date, minutes_logged, target_minutes, met],
         )
-        .map_err(|e| StorageError::Internal(format!("upsert_habit_streak: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("upsert_habit_streak: {e}")))?;
 
         Ok(())
     }
@@ -35,9 +35,9 @@ impl SqliteStorage {
     /// ordered by date descending then regime_label ascending.
     pub fn query_habit_streaks(&self, days: u32) -> Result<Vec<HabitStreakRow>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("connection unavailable: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -46,7 +46,7 @@ impl SqliteStorage {
                  WHERE date >= date('now', '-' || ?1 || ' days')
                  ORDER BY date DESC, regime_label ASC",
             )
-            .map_err(|e| StorageError::Internal(format!("prepare query_habit_streaks: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare query_habit_streaks: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![days], |row| {
@@ -58,11 +58,11 @@ impl SqliteStorage {
                     met: row.get(4)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("query_habit_streaks: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query_habit_streaks: {e}")))?;
 
         let mut results = Vec::new();
         for row in rows {
-            results.push(row.map_err(|e| StorageError::Internal(format!("row read: {e}")))?);
+            results.push(row.map_err(|e| StorageError::Database(format!("row read: {e}")))?);
         }
         Ok(results)
     }
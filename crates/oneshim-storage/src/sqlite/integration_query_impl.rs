@@ -13,11 +13,11 @@ impl LocalSuggestionQueryPort for SqliteStorage {
         after_id: Option<i64>,
         limit: usize,
     ) -> Result<Vec<LocalSuggestionRecord>, CoreError> {
-        let storage = self.conn.clone();
+        let pool = self.pool.clone();
         tokio::task::spawn_blocking(move || {
-            let guard = storage.lock().map_err(|err| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
-                message: format!("SQLite lock poisoned: {err}"),
+            let guard = pool.get().map_err(|err| CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Lock,
+                message: format!("SQLite connection unavailable: {err}"),
             })?;
 
             let sql = if after_id.is_some() {
@@ -34,7 +34,7 @@ impl LocalSuggestionQueryPort for SqliteStorage {
             };
 
             let mut stmt = guard.prepare(sql).map_err(|err| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to prepare query: {err}"),
             })?;
 
@@ -47,14 +47,14 @@ impl LocalSuggestionQueryPort for SqliteStorage {
                 stmt.query_map(rusqlite::params![limit as i64], map_local_suggestion_row)
             }
             .map_err(|err| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to execute query: {err}"),
             })?;
 
             let mut records = Vec::new();
             for row in rows {
                 records.push(row.map_err(|err| CoreError::Storage {
-                    code: oneshim_core::error_codes::StorageCode::Failed,
+                    code: oneshim_core::error_codes::StorageCode::Database,
                     message: format!("Failed to read row: {err}"),
                 })?);
             }
@@ -9,14 +9,14 @@ impl SqliteStorage {
     /// Returns `Some((fingerprint, trust_revoked))` if found, `None` otherwise.
     pub fn get_lan_pin(&self, device_id: &str) -> Result<Option<(String, bool)>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
         let mut stmt = conn
             .prepare(
                 "SELECT cert_fingerprint, trust_revoked FROM lan_peer_pins WHERE device_id = ?",
             )
-            .map_err(|e| StorageError::Internal(format!("prepare get_lan_pin: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare get_lan_pin: {e}")))?;
 
         let result = stmt
             .query_row([device_id], |row| {
@@ -25,7 +25,7 @@ impl SqliteStorage {
                 Ok((fingerprint, revoked))
             })
             .optional()
-            .map_err(|e| StorageError::Internal(format!("get_lan_pin: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("get_lan_pin: {e}")))?;
 
         Ok(result)
     }
@@ -38,9 +38,9 @@ impl SqliteStorage {
         cert_fingerprint: &str,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
         conn.execute(
             "INSERT INTO lan_peer_pins (device_id, cert_fingerprint)
              VALUES (?, ?)
@@ -49,7 +49,7 @@ impl SqliteStorage {
                 last_seen_at = datetime('now')",
             rusqlite::params![device_id, cert_fingerprint],
         )
-        .map_err(|e| StorageError::Internal(format!("upsert_lan_pin: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("upsert_lan_pin: {e}")))?;
 
         Ok(())
     }
@@ -57,14 +57,14 @@ impl SqliteStorage {
     /// Revoke trust for a peer device (TOFU violation).
     pub fn revoke_lan_pin(&self, device_id: &str) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
         conn.execute(
             "UPDATE lan_peer_pins SET trust_revoked = 1 WHERE device_id = ?",
             [device_id],
         )
-        .map_err(|e| StorageError::Internal(format!("revoke_lan_pin: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("revoke_lan_pin: {e}")))?;
 
         Ok(())
     }
@@ -1,8 +1,20 @@
+use crate::encryption::EncryptionKey;
 use crate::error::StorageError;
+use oneshim_core::models::event::new_idempotency_key;
+use oneshim_core::models::storage_records::{
+    CategoryStorageRecord, ForeignKeyViolationRecord, IntegrityCheckRecord, StorageReport,
+    TableRowCountRecord,
+};
+use oneshim_core::models::work_session::AppCategory;
 use oneshim_core::types::TimeWindow;
+use rusqlite::Connection;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
 use std::sync::atomic::Ordering;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
+use super::edge_intelligence::enum_to_sql_str;
+use super::text_compression::{read_text, StoredText};
 use super::{
     DeletedRangeCounts, EventExportRecord, FrameExportRecord, FrameTagLinkRecord,
     MetricExportRecord, SearchEventRow, SearchFrameRow, SqliteStorage, StorageStatsSummaryRecord,
@@ -12,13 +24,13 @@ use super::{
 impl SqliteStorage {
     pub fn list_backup_tags(&self) -> Result<Vec<super::TagRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare("SELECT id, name, color, created_at FROM tags ORDER BY id")
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map([], |row| {
@@ -27,29 +39,30 @@ impl SqliteStorage {
                     name: row.get(1)?,
                     color: row.get(2)?,
                     created_at: row.get(3)?,
+                    auto_applied: false,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
 
     pub fn list_backup_frame_tags(&self) -> Result<Vec<FrameTagLinkRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
                 "SELECT frame_id, tag_id, created_at FROM frame_tags ORDER BY frame_id, tag_id",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map([], |row| {
@@ -59,12 +72,12 @@ impl SqliteStorage {
                     created_at: row.get(2)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -77,15 +90,15 @@ impl SqliteStorage {
         created_at: &str,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT OR IGNORE INTO tags (id, name, color, created_at) VALUES (?1, ?2, ?3, ?4)",
             rusqlite::params![id, name, color, created_at],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to save tag: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to save tag: {e}")))?;
 
         Ok(())
     }
@@ -97,15 +110,15 @@ impl SqliteStorage {
         created_at: &str,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT OR IGNORE INTO frame_tags (frame_id, tag_id, created_at) VALUES (?1, ?2, ?3)",
             rusqlite::params![frame_id, tag_id, created_at],
         )
-        .map_err(|e| StorageError::Internal(format!("frame-Failed to save tag: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("frame-Failed to save tag: {e}")))?;
 
         Ok(())
     }
@@ -119,9 +132,9 @@ impl SqliteStorage {
         window_title: Option<&str>,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let data = serde_json::json!({
             "app_name": app_name,
@@ -130,11 +143,11 @@ impl SqliteStorage {
         .to_string();
 
         conn.execute(
-            "INSERT OR IGNORE INTO events (event_id, event_type, timestamp, data)
-             VALUES (?1, ?2, ?3, ?4)",
-            rusqlite::params![event_id, event_type, timestamp, data],
+            "INSERT OR IGNORE INTO events (event_id, event_type, timestamp, data, idempotency_key)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            rusqlite::params![event_id, event_type, timestamp, data, new_idempotency_key()],
         )
-        .map_err(|e| StorageError::Internal(format!("event save failure: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("event save failure: {e}")))?;
 
         Ok(())
     }
@@ -153,9 +166,9 @@ impl SqliteStorage {
         ocr_text: Option<&str>,
     ) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let exists: bool = conn
             .query_row(
@@ -180,10 +193,11 @@ impl SqliteStorage {
                     importance,
                     width,
                     height,
-                    ocr_text,
+                    ocr_text.map(|text| StoredText::encode(text, self.text_compression_min_bytes)),
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("frame save failure: {e}")))?;
+            .and_then(|_| super::frames::index_frame_ocr(&conn, id, ocr_text))
+            .map_err(|e| StorageError::Database(format!("frame save failure: {e}")))?;
         }
 
         Ok(())
@@ -191,9 +205,9 @@ impl SqliteStorage {
 
     pub fn get_storage_stats_summary(&self) -> Result<StorageStatsSummaryRecord, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let frame_count: u64 = conn
             .query_row("SELECT COUNT(*) FROM frames", [], |row| row.get(0))
@@ -251,30 +265,137 @@ impl SqliteStorage {
         })
     }
 
+    /// Break down local storage by table and by app category.
+    ///
+    /// `frames_dir_bytes` comes from frame storage, which keeps a running
+    /// total. Category bytes are the sizes of the referenced files under
+    /// `frames_dir`, and are zero without it. Apps are categorized with the
+    /// same overrides as event retention.
+    pub fn storage_report(
+        &self,
+        frames_dir: Option<&Path>,
+        frames_dir_bytes: u64,
+    ) -> Result<StorageReport, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let page_count: u64 = conn
+            .query_row("PRAGMA page_count", [], |row| row.get(0))
+            .map_err(|e| StorageError::Database(format!("Failed to read page count: {e}")))?;
+        let page_size: u64 = conn
+            .query_row("PRAGMA page_size", [], |row| row.get(0))
+            .map_err(|e| StorageError::Database(format!("Failed to read page size: {e}")))?;
+
+        // Virtual and FTS shadow tables are left out.
+        let table_names: Vec<String> = conn
+            .prepare(
+                "SELECT name FROM pragma_table_list
+                 WHERE schema = 'main' AND type = 'table' AND name NOT LIKE 'sqlite_%'",
+            )
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| row.get(0))?
+                    .collect::<Result<_, _>>()
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to list tables: {e}")))?;
+        let mut tables = Vec::with_capacity(table_names.len());
+        for table in table_names {
+            let rows: u64 = conn
+                .query_row(
+                    &format!("SELECT COUNT(*) FROM \"{}\"", table.replace('"', "\"\"")),
+                    [],
+                    |row| row.get(0),
+                )
+                .map_err(|e| StorageError::Database(format!("Failed to count {table}: {e}")))?;
+            tables.push(TableRowCountRecord { table, rows });
+        }
+        tables.sort_by(|a, b| b.rows.cmp(&a.rows).then_with(|| a.table.cmp(&b.table)));
+
+        let frame_groups: Vec<(String, Option<String>, u64)> = conn
+            .prepare(
+                "SELECT app_name, file_path, COUNT(*) FROM frames GROUP BY app_name, file_path",
+            )
+            .and_then(|mut stmt| {
+                stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+                    .collect::<Result<_, _>>()
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to group frames: {e}")))?;
+        drop(conn);
+
+        let overrides = self.retention_overrides.read().app_categories.clone();
+        let mut by_category: HashMap<AppCategory, CategoryStorageRecord> = HashMap::new();
+        let mut counted: HashSet<(AppCategory, String)> = HashSet::new();
+        let mut file_sizes: HashMap<String, u64> = HashMap::new();
+        for (app_name, file_path, frame_count) in frame_groups {
+            let category = AppCategory::from_app_name_with_overrides(&app_name, &overrides);
+            let entry = by_category
+                .entry(category)
+                .or_insert_with(|| CategoryStorageRecord {
+                    category: enum_to_sql_str(&category),
+                    frame_count: 0,
+                    bytes: 0,
+                });
+            entry.frame_count += frame_count;
+            let (Some(dir), Some(path)) = (frames_dir, file_path) else {
+                continue;
+            };
+            if counted.insert((category, path.clone())) {
+                entry.bytes += *file_sizes.entry(path).or_insert_with_key(|path| {
+                    std::fs::metadata(dir.join(path))
+                        .map(|m| m.len())
+                        .unwrap_or(0)
+                });
+            }
+        }
+        let mut app_categories: Vec<CategoryStorageRecord> = by_category.into_values().collect();
+        app_categories.sort_by(|a, b| {
+            b.bytes
+                .cmp(&a.bytes)
+                .then_with(|| b.frame_count.cmp(&a.frame_count))
+                .then_with(|| a.category.cmp(&b.category))
+        });
+
+        Ok(StorageReport {
+            db_size_bytes: page_count * page_size,
+            frames_dir_bytes,
+            tables,
+            app_categories,
+        })
+    }
+
     pub fn list_frame_file_paths_in_range(
         &self,
         window: &TimeWindow,
     ) -> Result<Vec<String>, StorageError> {
         let (from, to) = window.to_sql_pair();
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
-            .prepare("SELECT file_path FROM frames WHERE timestamp >= ?1 AND timestamp <= ?2")
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            // A deduplicated file may still back frames outside the range.
+            .prepare(
+                "SELECT DISTINCT file_path FROM frames
+                 WHERE timestamp >= ?1 AND timestamp <= ?2
+                   AND file_path NOT IN (
+                       SELECT file_path FROM frames
+                       WHERE (timestamp < ?1 OR timestamp > ?2) AND file_path IS NOT NULL
+                   )",
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from, to], |row| {
                 row.get::<_, Option<String>>(0)
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut paths = Vec::new();
         for row in rows {
             if let Some(path) = row
-                .map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?
                 .filter(|p| !p.is_empty())
             {
                 paths.push(path);
@@ -295,9 +416,9 @@ impl SqliteStorage {
     ) -> Result<DeletedRangeCounts, StorageError> {
         let (from, to) = window.to_sql_pair();
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut counts = DeletedRangeCounts::default();
 
@@ -307,7 +428,7 @@ impl SqliteStorage {
                     "DELETE FROM events WHERE timestamp >= ?1 AND timestamp <= ?2",
                     rusqlite::params![from, to],
                 )
-                .map_err(|e| StorageError::Internal(format!("event delete failure: {e}")))?
+                .map_err(|e| StorageError::Database(format!("event delete failure: {e}")))?
                 as u64;
         }
 
@@ -317,7 +438,7 @@ impl SqliteStorage {
                     "DELETE FROM frames WHERE timestamp >= ?1 AND timestamp <= ?2",
                     rusqlite::params![from, to],
                 )
-                .map_err(|e| StorageError::Internal(format!("frame delete failure: {e}")))?
+                .map_err(|e| StorageError::Database(format!("frame delete failure: {e}")))?
                 as u64;
         }
 
@@ -327,7 +448,7 @@ impl SqliteStorage {
                     "DELETE FROM system_metrics WHERE timestamp >= ?1 AND timestamp <= ?2",
                     rusqlite::params![from, to],
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to delete metrics: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to delete metrics: {e}")))?
                 as u64;
 
             let _ = conn.execute(
@@ -343,7 +464,7 @@ impl SqliteStorage {
                     rusqlite::params![from, to],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to delete process snapshots: {e}"))
+                    StorageError::Database(format!("Failed to delete process snapshots: {e}"))
                 })? as u64;
         }
 
@@ -353,7 +474,7 @@ impl SqliteStorage {
                     "DELETE FROM idle_periods WHERE start_time >= ?1 AND start_time <= ?2",
                     rusqlite::params![from, to],
                 )
-                .map_err(|e| StorageError::Internal(format!("idle record delete failure: {e}")))?
+                .map_err(|e| StorageError::Database(format!("idle record delete failure: {e}")))?
                 as u64;
         }
 
@@ -365,9 +486,9 @@ impl SqliteStorage {
     /// the database is never left in a partially-deleted state (GDPR compliance).
     pub fn delete_all_data(&self) -> Result<(), StorageError> {
         let mut conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         // All tables created by V1-V17 migrations (excluding schema_version).
         // Order: child/referencing tables before parent tables to avoid FK issues
@@ -419,17 +540,17 @@ impl SqliteStorage {
 
         let tx = conn
             .transaction()
-            .map_err(|e| StorageError::Internal(format!("Failed to begin transaction: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to begin transaction: {e}")))?;
 
         for table in ALL_TABLES {
             tx.execute(&format!("DELETE FROM {table}"), [])
                 .map_err(|e| {
-                    StorageError::Internal(format!("GDPR delete failed on table '{table}': {e}"))
+                    StorageError::Database(format!("GDPR delete failed on table '{table}': {e}"))
                 })?;
         }
 
         tx.commit()
-            .map_err(|e| StorageError::Internal(format!("Failed to commit GDPR deletion: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to commit GDPR deletion: {e}")))?;
 
         Ok(())
     }
@@ -440,9 +561,9 @@ impl SqliteStorage {
         to: &str,
     ) -> Result<Vec<EventExportRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -453,7 +574,7 @@ impl SqliteStorage {
                  WHERE timestamp >= ?1 AND timestamp <= ?2
                  ORDER BY timestamp ASC",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from, to], |row| {
@@ -465,12 +586,12 @@ impl SqliteStorage {
                     window_title: row.get(4)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -481,9 +602,9 @@ impl SqliteStorage {
         to: &str,
     ) -> Result<Vec<MetricExportRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -493,7 +614,7 @@ impl SqliteStorage {
                  WHERE timestamp >= ?1 AND timestamp <= ?2
                  ORDER BY timestamp ASC",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from, to], |row| {
@@ -508,12 +629,12 @@ impl SqliteStorage {
                     network_download: row.get(7)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -524,19 +645,19 @@ impl SqliteStorage {
         to: &str,
     ) -> Result<Vec<FrameExportRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
                 "SELECT id, timestamp, trigger_type, app_name, window_title, importance,
                         resolution_w, resolution_h, ocr_text
                  FROM frames
-                 WHERE timestamp >= ?1 AND timestamp <= ?2
+                 WHERE timestamp >= ?1 AND timestamp <= ?2 AND deleted_at IS NULL
                  ORDER BY timestamp ASC",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from, to], |row| {
@@ -549,15 +670,15 @@ impl SqliteStorage {
                     importance: row.get(5)?,
                     resolution_w: row.get(6)?,
                     resolution_h: row.get(7)?,
-                    ocr_text: row.get(8)?,
+                    ocr_text: read_text(row, 8)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -568,20 +689,20 @@ impl SqliteStorage {
         pattern: Option<&str>,
     ) -> Result<u64, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let count: i64 = match pattern {
             Some(p) => conn
                 .query_row(count_sql, rusqlite::params![p], |row| row.get(0))
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to count frame search results: {e}"))
+                    StorageError::Database(format!("Failed to count frame search results: {e}"))
                 })?,
             None => conn
                 .query_row(count_sql, [], |row| row.get(0))
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to count frame search results: {e}"))
+                    StorageError::Database(format!("Failed to count frame search results: {e}"))
                 })?,
         };
 
@@ -596,13 +717,13 @@ impl SqliteStorage {
         offset: usize,
     ) -> Result<Vec<SearchFrameRow>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(select_sql)
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         if let Some(p) = pattern {
             let rows = stmt
@@ -614,18 +735,18 @@ impl SqliteStorage {
                             timestamp: row.get(1)?,
                             app_name: row.get(2)?,
                             window_title: row.get(3)?,
-                            matched_text: row.get(4)?,
+                            matched_text: read_text(row, 4)?,
                             importance: row.get(5)?,
                             file_path: row.get(6)?,
                         })
                     },
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to query frames: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to query frames: {e}")))?;
 
             let mut records = Vec::new();
             for row in rows {
                 records.push(
-                    row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?,
+                    row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?,
                 );
             }
             Ok(records)
@@ -639,18 +760,18 @@ impl SqliteStorage {
                             timestamp: row.get(1)?,
                             app_name: row.get(2)?,
                             window_title: row.get(3)?,
-                            matched_text: row.get(4)?,
+                            matched_text: read_text(row, 4)?,
                             importance: row.get(5)?,
                             file_path: row.get(6)?,
                         })
                     },
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to query frames: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to query frames: {e}")))?;
 
             let mut records = Vec::new();
             for row in rows {
                 records.push(
-                    row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?,
+                    row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?,
                 );
             }
             Ok(records)
@@ -659,9 +780,9 @@ impl SqliteStorage {
 
     pub fn count_search_events(&self, pattern: &str) -> Result<u64, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let count: i64 = conn
             .query_row(
@@ -671,7 +792,7 @@ impl SqliteStorage {
                 |row| row.get(0),
             )
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to count event search results: {e}"))
+                StorageError::Database(format!("Failed to count event search results: {e}"))
             })?;
 
         Ok(count as u64)
@@ -684,9 +805,9 @@ impl SqliteStorage {
         offset: usize,
     ) -> Result<Vec<SearchEventRow>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -699,7 +820,7 @@ impl SqliteStorage {
                  ORDER BY timestamp DESC
                  LIMIT ?2 OFFSET ?3",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(
@@ -714,12 +835,12 @@ impl SqliteStorage {
                     })
                 },
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to query events: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to query events: {e}")))?;
 
         let mut records = Vec::new();
         for row in rows {
             records
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
         Ok(records)
     }
@@ -730,12 +851,12 @@ impl SqliteStorage {
     /// concurrent readers or writers to finish.
     pub fn wal_checkpoint_passive(&self) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute_batch("PRAGMA wal_checkpoint(PASSIVE)")
-            .map_err(|e| StorageError::Internal(format!("WAL checkpoint PASSIVE failed: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("WAL checkpoint PASSIVE failed: {e}")))?;
 
         debug!("WAL checkpoint PASSIVE completed");
         Ok(())
@@ -746,12 +867,12 @@ impl SqliteStorage {
     /// Intended for graceful shutdown after all background loops have stopped.
     pub fn wal_checkpoint_truncate(&self) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE)")
-            .map_err(|e| StorageError::Internal(format!("WAL checkpoint TRUNCATE failed: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("WAL checkpoint TRUNCATE failed: {e}")))?;
 
         debug!("WAL checkpoint TRUNCATE completed");
         Ok(())
@@ -761,9 +882,9 @@ impl SqliteStorage {
     /// Returns `true` when VACUUM was actually executed.
     pub fn maybe_vacuum(&self, threshold_percent: u64) -> Result<bool, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let freelist_count: u64 = conn
             .query_row("PRAGMA freelist_count", [], |row| row.get(0))
@@ -782,7 +903,7 @@ impl SqliteStorage {
                 "Running VACUUM: freelist={freelist_count} pages={page_count} ({free_pct}% free)"
             );
             conn.execute_batch("VACUUM")
-                .map_err(|e| StorageError::Internal(format!("VACUUM failed: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("VACUUM failed: {e}")))?;
             Ok(true)
         } else {
             debug!(
@@ -800,15 +921,15 @@ impl SqliteStorage {
         }
 
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT INTO search_fts(search_fts, rank) VALUES('merge', ?1)",
             rusqlite::params![pages as i64],
         )
-        .map_err(|e| StorageError::Internal(format!("FTS5 merge failed: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("FTS5 merge failed: {e}")))?;
 
         debug!("FTS5 incremental merge completed ({pages} pages)");
         Ok(())
@@ -823,41 +944,112 @@ impl SqliteStorage {
         }
 
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute("INSERT INTO search_fts(search_fts) VALUES('optimize')", [])
-            .map_err(|e| StorageError::Internal(format!("FTS5 optimize failed: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("FTS5 optimize failed: {e}")))?;
 
         info!("FTS5 full optimize completed");
         Ok(())
     }
 
+    /// Run `PRAGMA integrity_check` and `PRAGMA foreign_key_check`.
+    ///
+    /// Corruption is reported through the returned record rather than as an
+    /// error so callers can present recovery steps; `Err` means the checks
+    /// themselves could not run.
+    pub fn integrity_check(&self) -> Result<IntegrityCheckRecord, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+        run_integrity_check(&conn)
+    }
+
+    /// [`integrity_check`](Self::integrity_check) against the database file
+    /// at `path` over a read-only connection, without opening the storage:
+    /// no migration, no backup, no PRAGMA changes. `encryption_key` is used
+    /// when the file is keyed with it.
+    pub fn integrity_check_read_only(
+        path: &Path,
+        encryption_key: Option<&EncryptionKey>,
+    ) -> Result<IntegrityCheckRecord, StorageError> {
+        let conn = super::open_read_only(path, encryption_key)?;
+        run_integrity_check(&conn)
+    }
+
     /// Run ANALYZE to refresh query planner statistics for all tables.
     pub fn run_analyze(&self) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute_batch("ANALYZE")
-            .map_err(|e| StorageError::Internal(format!("ANALYZE failed: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("ANALYZE failed: {e}")))?;
 
         debug!("ANALYZE completed");
         Ok(())
     }
 
     /// Run ANALYZE using an already-held connection guard. Use this inside
-    /// methods that have already locked `self.conn` to avoid deadlocking.
+    /// methods that already hold a pooled connection instead of checking
+    /// out a second one.
     pub(super) fn run_analyze_with_conn(conn: &rusqlite::Connection) -> Result<(), StorageError> {
         conn.execute_batch("ANALYZE")
-            .map_err(|e| StorageError::Internal(format!("ANALYZE failed: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("ANALYZE failed: {e}")))?;
         debug!("ANALYZE (inline) completed");
         Ok(())
     }
 }
 
+/// `PRAGMA integrity_check` and `PRAGMA foreign_key_check` on `conn`.
+fn run_integrity_check(conn: &Connection) -> Result<IntegrityCheckRecord, StorageError> {
+    let mut stmt = conn
+        .prepare("PRAGMA integrity_check")
+        .map_err(|e| StorageError::Database(format!("integrity_check failed: {e}")))?;
+    let integrity_errors = stmt
+        .query_map([], |row| row.get::<_, String>(0))
+        .map_err(|e| StorageError::Database(format!("integrity_check failed: {e}")))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::Database(format!("integrity_check failed: {e}")))?
+        .into_iter()
+        .filter(|message| message != "ok")
+        .collect::<Vec<_>>();
+
+    let mut stmt = conn
+        .prepare("PRAGMA foreign_key_check")
+        .map_err(|e| StorageError::Database(format!("foreign_key_check failed: {e}")))?;
+    let foreign_key_violations = stmt
+        .query_map([], |row| {
+            Ok(ForeignKeyViolationRecord {
+                table: row.get(0)?,
+                rowid: row.get(1)?,
+                parent: row.get(2)?,
+            })
+        })
+        .map_err(|e| StorageError::Database(format!("foreign_key_check failed: {e}")))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| StorageError::Database(format!("foreign_key_check failed: {e}")))?;
+
+    let record = IntegrityCheckRecord {
+        integrity_errors,
+        foreign_key_violations,
+    };
+    if record.is_healthy() {
+        info!("SQLite integrity check passed");
+    } else {
+        warn!(
+            integrity_errors = record.integrity_errors.len(),
+            foreign_key_violations = record.foreign_key_violations.len(),
+            "SQLite integrity check found problems"
+        );
+    }
+    Ok(record)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -887,7 +1079,7 @@ mod tests {
     }
 
     fn insert_metric(storage: &SqliteStorage, timestamp: &str) {
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
         conn.execute(
             "INSERT INTO system_metrics (timestamp, cpu_usage, memory_used, memory_total, disk_used, disk_total, network_upload, network_download)
              VALUES (?1, 45.5, 8589934592, 17179869184, 107374182400, 536870912000, 1000, 5000)",
@@ -896,6 +1088,67 @@ mod tests {
         .unwrap();
     }
 
+    // ── integrity_check ─────────────────────────────────────────────
+
+    #[test]
+    fn integrity_check_passes_on_healthy_db() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        insert_events(&storage, &["2026-01-15T10:00:00Z"]);
+        insert_frame(&storage, 1, "2026-01-15T10:00:00Z");
+
+        let report = storage.integrity_check().unwrap();
+        assert!(report.is_healthy(), "unexpected problems: {report:?}");
+        assert!(report.recovery_steps().is_empty());
+    }
+
+    #[test]
+    fn integrity_check_reports_orphaned_rows() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        {
+            let conn = storage.pool.get().unwrap();
+            conn.execute_batch(
+                "PRAGMA foreign_keys = OFF;
+                 INSERT INTO frame_tags (frame_id, tag_id, created_at) VALUES (999, 999, '2026-01-15T10:00:00Z');
+                 PRAGMA foreign_keys = ON;",
+            )
+            .unwrap();
+        }
+
+        let report = storage.integrity_check().unwrap();
+        assert!(!report.is_healthy());
+        assert!(report
+            .foreign_key_violations
+            .iter()
+            .all(|v| v.table == "frame_tags"));
+        assert!(!report.recovery_steps().is_empty());
+    }
+
+    #[test]
+    fn integrity_check_read_only_reports_orphaned_rows_on_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("readonly_check.db");
+        {
+            let storage = SqliteStorage::open(&db_path, 30, None).unwrap();
+            assert!(SqliteStorage::integrity_check_read_only(&db_path, None)
+                .unwrap()
+                .is_healthy());
+            let conn = storage.pool.get().unwrap();
+            conn.execute_batch(
+                "PRAGMA foreign_keys = OFF;
+                 INSERT INTO frame_tags (frame_id, tag_id, created_at) VALUES (999, 999, '2026-01-15T10:00:00Z');
+                 PRAGMA foreign_keys = ON;",
+            )
+            .unwrap();
+        }
+
+        let report = SqliteStorage::integrity_check_read_only(&db_path, None).unwrap();
+        assert!(!report.is_healthy());
+        assert!(report
+            .foreign_key_violations
+            .iter()
+            .all(|v| v.table == "frame_tags"));
+    }
+
     // ── maybe_vacuum ────────────────────────────────────────────────
 
     #[test]
@@ -975,7 +1228,7 @@ mod tests {
     #[test]
     fn run_analyze_with_conn_on_fresh_db() {
         let storage = SqliteStorage::open_in_memory(30).unwrap();
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
         let result = SqliteStorage::run_analyze_with_conn(&conn);
         assert!(result.is_ok());
     }
@@ -1011,6 +1264,64 @@ mod tests {
         assert!(stats.newest_data_date.is_some());
     }
 
+    // ── storage_report ──────────────────────────────────────────────
+
+    #[test]
+    fn storage_report_counts_rows_and_bytes_by_category() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("shared.webp"), [0u8; 100]).unwrap();
+        std::fs::write(dir.path().join("chat.webp"), [0u8; 40]).unwrap();
+
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        insert_frame(&storage, 1, "2025-06-01T10:00:00Z");
+        insert_frame(&storage, 2, "2025-06-01T10:01:00Z");
+        storage
+            .upsert_backup_frame(
+                3,
+                "2025-06-01T10:02:00Z",
+                "manual",
+                "Slack",
+                "general",
+                0.5,
+                1920,
+                1080,
+                None,
+            )
+            .unwrap();
+        {
+            let conn = storage.pool.get().unwrap();
+            // Frames 1 and 2 share one deduplicated file.
+            conn.execute_batch(
+                "UPDATE frames SET file_path = 'shared.webp' WHERE id IN (1, 2);
+                 UPDATE frames SET file_path = 'chat.webp' WHERE id = 3;",
+            )
+            .unwrap();
+        }
+
+        let report = storage.storage_report(Some(dir.path()), 123).unwrap();
+        assert!(report.db_size_bytes > 0);
+        assert_eq!(report.frames_dir_bytes, 123);
+        let frames = report.tables.iter().find(|t| t.table == "frames").unwrap();
+        assert_eq!(frames.rows, 3);
+        assert!(report
+            .tables
+            .iter()
+            .all(|t| !t.table.starts_with("sqlite_")));
+
+        let categories: Vec<(&str, u64, u64)> = report
+            .app_categories
+            .iter()
+            .map(|c| (c.category.as_str(), c.frame_count, c.bytes))
+            .collect();
+        assert_eq!(
+            categories,
+            [("development", 2, 100), ("communication", 1, 40)]
+        );
+
+        let without_dir = storage.storage_report(None, 0).unwrap();
+        assert!(without_dir.app_categories.iter().all(|c| c.bytes == 0));
+    }
+
     // ── delete_data_in_range ────────────────────────────────────────
 
     #[test]
@@ -1323,7 +1634,7 @@ mod tests {
         assert_eq!(count, 1);
 
         // Verify event_id and event_type via direct SQL
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
         let (eid, etype): (String, String) = conn
             .query_row(
                 "SELECT event_id, event_type FROM events WHERE event_id = 'evt-100'",
@@ -6,17 +6,22 @@ use oneshim_core::models::activity::{
 };
 use oneshim_core::models::system::{NetworkInfo, SystemMetrics};
 use oneshim_core::ports::storage::MetricsStorage;
+use rusqlite::OptionalExtension;
 use tracing::{debug, info};
 
-use super::{HourlyMetricsRecord, SqliteStorage};
+use super::{DailyMetricsRecord, HourlyMetricsRecord, SqliteStorage};
 use crate::error::StorageError;
 
+/// Upper bound on `event_histogram` slots, so a tiny bucket over a wide
+/// range cannot allocate without limit.
+const MAX_HISTOGRAM_BUCKETS: i64 = 10_000;
+
 impl SqliteStorage {
     pub fn list_session_stats(&self, limit: usize) -> Result<Vec<SessionStats>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -25,7 +30,7 @@ impl SqliteStorage {
                  ORDER BY started_at DESC
                  LIMIT ?1",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![limit as i64], |row| {
@@ -38,12 +43,12 @@ impl SqliteStorage {
                     row.get::<_, i64>(5)?,
                 ))
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut sessions = Vec::new();
         for row in rows {
             let (session_id, started_str, ended_str, events, frames, idle) =
-                row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?;
+                row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?;
 
             let started_at = DateTime::parse_from_rfc3339(&started_str)
                 .map(|dt| dt.with_timezone(&Utc))
@@ -73,9 +78,9 @@ impl SqliteStorage {
         from_hour: &str,
     ) -> Result<Vec<HourlyMetricsRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -84,7 +89,7 @@ impl SqliteStorage {
                  WHERE hour >= ?1
                  ORDER BY hour ASC",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let rows = stmt
             .query_map(rusqlite::params![from_hour], |row| {
@@ -97,12 +102,52 @@ impl SqliteStorage {
                     sample_count: row.get::<_, i64>(5)? as u64,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut result = Vec::new();
         for row in rows {
             result
-                .push(row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?);
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
+        }
+
+        Ok(result)
+    }
+
+    pub fn list_daily_metrics_since(
+        &self,
+        from_date: &str,
+    ) -> Result<Vec<DailyMetricsRecord>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT date, cpu_avg, cpu_max, memory_avg, memory_max, sample_count
+                 FROM daily_metrics
+                 WHERE date >= ?1
+                 ORDER BY date ASC",
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+
+        let rows = stmt
+            .query_map(rusqlite::params![from_date], |row| {
+                Ok(DailyMetricsRecord {
+                    date: row.get(0)?,
+                    cpu_avg: row.get(1)?,
+                    cpu_max: row.get(2)?,
+                    memory_avg: row.get::<_, i64>(3)? as u64,
+                    memory_max: row.get::<_, i64>(4)? as u64,
+                    sample_count: row.get::<_, i64>(5)? as u64,
+                })
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            result
+                .push(row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?);
         }
 
         Ok(result)
@@ -143,7 +188,7 @@ impl MetricsStorage for SqliteStorage {
                     download,
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to save system metrics: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to save system metrics: {e}")))?;
 
             debug!(
                 "system metrics saved: CPU {:.1}%, memory {}MB",
@@ -174,7 +219,7 @@ impl MetricsStorage for SqliteStorage {
                      ORDER BY timestamp DESC
                      LIMIT ?3",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
             let metrics = stmt
                 .query_map(rusqlite::params![from_str, to_str, limit as i64], |row| {
@@ -200,7 +245,7 @@ impl MetricsStorage for SqliteStorage {
                         typing_wpm: 0.0,
                     })
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
                 .filter_map(|r| r.ok())
                 .collect();
 
@@ -254,7 +299,7 @@ impl MetricsStorage for SqliteStorage {
                         rusqlite::params![hour_str, cpu_avg, cpu_max, memory_avg_i64, memory_max, count],
                     )
                     .map_err(|e| {
-                        StorageError::Internal(format!("Failed to save hourly aggregate: {e}"))
+                        StorageError::Database(format!("Failed to save hourly aggregate: {e}"))
                     })?;
                     debug!("hour: {} ({count}items )", hour_str);
                 }
@@ -265,7 +310,7 @@ impl MetricsStorage for SqliteStorage {
                     // Propagate genuine query errors rather than silently
                     // swallowing — the previous `_` arm masked the
                     // InvalidColumnType bug fixed in this commit.
-                    return Err(StorageError::Internal(format!(
+                    return Err(StorageError::Database(format!(
                         "Failed to aggregate hourly metrics for {hour_str}: {e}"
                     )));
                 }
@@ -277,6 +322,59 @@ impl MetricsStorage for SqliteStorage {
         .map_err(Into::into)
     }
 
+    async fn aggregate_daily_metrics(&self, date: DateTime<Utc>) -> Result<(), CoreError> {
+        let day = date.date_naive();
+        let date_str = day.format("%Y-%m-%d").to_string();
+        let from_str = format!("{date_str}T00:00:00Z");
+        let to_str = (day + Duration::days(1))
+            .format("%Y-%m-%dT00:00:00Z")
+            .to_string();
+
+        self.with_conn(move |conn| {
+            // Folds the hourly rows rather than raw samples, which may already
+            // have been pruned. Averages are weighted by each hour's samples.
+            let (cpu_avg, cpu_max, memory_avg, memory_max, count): (f64, f64, f64, i64, i64) = conn
+                .query_row(
+                    "SELECT SUM(cpu_avg * sample_count) / SUM(sample_count), MAX(cpu_max),
+                            SUM(CAST(memory_avg AS REAL) * sample_count) / SUM(sample_count),
+                            MAX(memory_max), COALESCE(SUM(sample_count), 0)
+                     FROM system_metrics_hourly
+                     WHERE hour >= ?1 AND hour < ?2",
+                    rusqlite::params![from_str, to_str],
+                    |row| {
+                        Ok((
+                            row.get::<_, Option<f64>>(0)?.unwrap_or(0.0),
+                            row.get::<_, Option<f64>>(1)?.unwrap_or(0.0),
+                            row.get::<_, Option<f64>>(2)?.unwrap_or(0.0),
+                            row.get::<_, Option<i64>>(3)?.unwrap_or(0),
+                            row.get(4)?,
+                        ))
+                    },
+                )
+                .map_err(|e| {
+                    StorageError::Database(format!(
+                        "Failed to aggregate daily metrics for {date_str}: {e}"
+                    ))
+                })?;
+
+            if count == 0 {
+                debug!("day: {} (data none)", date_str);
+                return Ok(());
+            }
+
+            conn.execute(
+                "INSERT OR REPLACE INTO daily_metrics (date, cpu_avg, cpu_max, memory_avg, memory_max, sample_count)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                rusqlite::params![date_str, cpu_avg, cpu_max, memory_avg as i64, memory_max, count],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to save daily aggregate: {e}")))?;
+            debug!("day: {} ({count}items )", date_str);
+            Ok(())
+        })
+        .await
+        .map_err(Into::into)
+    }
+
     async fn cleanup_old_metrics(&self, before: DateTime<Utc>) -> Result<usize, CoreError> {
         let cutoff = before.to_rfc3339();
 
@@ -287,8 +385,15 @@ impl MetricsStorage for SqliteStorage {
                     rusqlite::params![cutoff],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to delete stale metrics: {e}"))
+                    StorageError::Database(format!("Failed to delete stale metrics: {e}"))
                 })?;
+            conn.execute(
+                "DELETE FROM capture_latency WHERE recorded_at < ?1",
+                rusqlite::params![cutoff],
+            )
+            .map_err(|e| {
+                StorageError::Database(format!("Failed to delete stale capture latency: {e}"))
+            })?;
 
             if deleted > 0 {
                 info!("{deleted}items delete");
@@ -312,7 +417,7 @@ impl MetricsStorage for SqliteStorage {
                 "INSERT INTO process_snapshots (timestamp, snapshot_data) VALUES (?1, ?2)",
                 rusqlite::params![timestamp, data],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to save process snapshot: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to save process snapshot: {e}")))?;
 
             debug!("process snapshot saved: {} processes", process_count);
             Ok(())
@@ -338,7 +443,7 @@ impl MetricsStorage for SqliteStorage {
                      ORDER BY timestamp DESC
                      LIMIT ?3",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
             let snapshots = stmt
                 .query_map(rusqlite::params![from_str, to_str, limit as i64], |row| {
@@ -357,7 +462,7 @@ impl MetricsStorage for SqliteStorage {
                         processes,
                     })
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
                 .filter_map(|r| r.ok())
                 .collect();
 
@@ -380,7 +485,7 @@ impl MetricsStorage for SqliteStorage {
                     rusqlite::params![cutoff],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to delete stale snapshots: {e}"))
+                    StorageError::Database(format!("Failed to delete stale snapshots: {e}"))
                 })?;
 
             if deleted > 0 {
@@ -395,16 +500,43 @@ impl MetricsStorage for SqliteStorage {
     // --------------------------------------------------------
     // --------------------------------------------------------
 
-    async fn start_idle_period(&self, start_time: DateTime<Utc>) -> Result<i64, CoreError> {
+    async fn start_idle_period(
+        &self,
+        start_time: DateTime<Utc>,
+        merge_threshold_secs: u64,
+    ) -> Result<i64, CoreError> {
         let start_time_str = start_time.to_rfc3339();
 
         self.with_conn(move |conn| {
+            if merge_threshold_secs > 0 {
+                let merged: Option<i64> = conn
+                    .query_row(
+                        "UPDATE idle_periods
+                         SET end_time = NULL, duration_secs = NULL
+                         WHERE id = (SELECT MAX(id) FROM idle_periods)
+                           AND end_time IS NOT NULL
+                           AND julianday(?1) >= julianday(end_time)
+                           AND (julianday(?1) - julianday(end_time)) * 86400 <= ?2
+                         RETURNING id",
+                        rusqlite::params![start_time_str, merge_threshold_secs as i64],
+                        |row| row.get(0),
+                    )
+                    .optional()
+                    .map_err(|e| {
+                        StorageError::Database(format!("idle period merge failure: {e}"))
+                    })?;
+                if let Some(id) = merged {
+                    debug!("idle period resumed: id={}", id);
+                    return Ok(id);
+                }
+            }
+
             conn.execute(
                 "INSERT INTO idle_periods (start_time) VALUES (?1)",
                 rusqlite::params![start_time_str],
             )
             .map_err(|e| {
-                StorageError::Internal(format!("idle period started record failure: {e}"))
+                StorageError::Database(format!("idle period started record failure: {e}"))
             })?;
 
             let id = conn.last_insert_rowid();
@@ -430,7 +562,7 @@ impl MetricsStorage for SqliteStorage {
                     |row| row.get(0),
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("idle period ended record failure: {e}"))
+                    StorageError::Database(format!("idle period ended record failure: {e}"))
                 })?;
 
             debug!("idle period ended: id={}, duration={}s", id, duration_secs);
@@ -464,7 +596,7 @@ impl MetricsStorage for SqliteStorage {
                     )))
                 }
                 Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-                Err(e) => Err(StorageError::Internal(format!(
+                Err(e) => Err(StorageError::Database(format!(
                     "진행 중 idle period query failure: {e}"
                 ))),
             }
@@ -488,7 +620,7 @@ impl MetricsStorage for SqliteStorage {
                      WHERE start_time >= ?1 AND start_time <= ?2
                      ORDER BY start_time DESC",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
             let periods = stmt
                 .query_map(rusqlite::params![from_str, to_str], |row| {
@@ -512,7 +644,7 @@ impl MetricsStorage for SqliteStorage {
                         duration_secs: duration.map(|d| d as u64),
                     })
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
                 .filter_map(|r| r.ok())
                 .collect();
 
@@ -532,7 +664,7 @@ impl MetricsStorage for SqliteStorage {
                     rusqlite::params![cutoff],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to delete stale idle periods: {e}"))
+                    StorageError::Database(format!("Failed to delete stale idle periods: {e}"))
                 })?;
 
             if deleted > 0 {
@@ -573,7 +705,7 @@ impl MetricsStorage for SqliteStorage {
                     total_idle_secs,
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to save session stats: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to save session stats: {e}")))?;
 
             debug!("session save: {}", session_id);
             Ok(())
@@ -624,7 +756,7 @@ impl MetricsStorage for SqliteStorage {
                     }))
                 }
                 Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-                Err(e) => Err(StorageError::Internal(format!(
+                Err(e) => Err(StorageError::Database(format!(
                     "session query failure: {e}"
                 ))),
             }
@@ -646,7 +778,7 @@ impl MetricsStorage for SqliteStorage {
                 "UPDATE session_stats SET ended_at = ?1 WHERE session_id = ?2",
                 rusqlite::params![ended_at_str, session_id],
             )
-            .map_err(|e| StorageError::Internal(format!("session ended record failure: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("session ended record failure: {e}")))?;
 
             debug!("session ended: {}", session_id);
             Ok(())
@@ -674,7 +806,7 @@ impl MetricsStorage for SqliteStorage {
                 rusqlite::params![events as i64, frames as i64, idle_secs as i64, session_id],
             )
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to increment session counter: {e}"))
+                StorageError::Database(format!("Failed to increment session counter: {e}"))
             })?;
 
             Ok(())
@@ -682,6 +814,73 @@ impl MetricsStorage for SqliteStorage {
         .await
         .map_err(Into::into)
     }
+
+    async fn event_histogram(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        bucket: Duration,
+    ) -> Result<Vec<(DateTime<Utc>, u64)>, CoreError> {
+        let bucket_secs = bucket.num_seconds();
+        if bucket_secs <= 0 {
+            return Err(StorageError::Validation {
+                field: "bucket".to_string(),
+                message: "bucket must be at least one second".to_string(),
+            }
+            .into());
+        }
+        let span_secs = (to - from).num_seconds().max(0);
+        let bucket_count = (span_secs + bucket_secs - 1) / bucket_secs;
+        if bucket_count > MAX_HISTOGRAM_BUCKETS {
+            return Err(StorageError::Validation {
+                field: "bucket".to_string(),
+                message: format!(
+                    "{bucket_count} buckets requested, at most {MAX_HISTOGRAM_BUCKETS} allowed"
+                ),
+            }
+            .into());
+        }
+        let from_str = from.to_rfc3339();
+        let to_str = to.to_rfc3339();
+        let from_epoch = from.timestamp();
+
+        self.with_conn(move |conn| {
+            let mut counts = vec![0u64; bucket_count as usize];
+            let mut stmt = conn
+                .prepare_cached(
+                    "SELECT (CAST(strftime('%s', timestamp) AS INTEGER) - ?3) / ?4 AS bucket,
+                            COUNT(*)
+                     FROM events
+                     WHERE timestamp >= ?1 AND timestamp < ?2
+                     GROUP BY bucket",
+                )
+                .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+            let rows = stmt
+                .query_map(
+                    rusqlite::params![from_str, to_str, from_epoch, bucket_secs],
+                    |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)),
+                )
+                .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
+            for row in rows {
+                let (index, count) =
+                    row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?;
+                if let Some(slot) = usize::try_from(index)
+                    .ok()
+                    .and_then(|index| counts.get_mut(index))
+                {
+                    *slot += count as u64;
+                }
+            }
+
+            Ok(counts
+                .into_iter()
+                .enumerate()
+                .map(|(index, count)| (from + Duration::seconds(index as i64 * bucket_secs), count))
+                .collect())
+        })
+        .await
+        .map_err(Into::into)
+    }
 }
 
 #[cfg(test)]
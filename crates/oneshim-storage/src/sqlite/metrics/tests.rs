@@ -6,34 +6,31 @@
 //! this helper into `test_utils.rs` — that is an explicit follow-up item.
 
 // `open_db()` is defined for future Err-branch tests that need a raw
-// Connection handle, but is currently unused because every active
-// test uses `open_storage()` + `storage.connection_arc()` instead.
+// connection pool, but is currently unused because every active
+// test uses `open_storage()` + `storage.connection_pool()` instead.
 // Keep the helper available for the follow-up phase.
 #![allow(dead_code)]
 
-use std::sync::{Arc, Mutex};
+use std::sync::Arc;
 
 use chrono::{DateTime, Duration, Timelike, Utc};
-use oneshim_core::error::CoreError;
 use oneshim_core::models::activity::{ProcessSnapshot, ProcessSnapshotEntry, SessionStats};
 use oneshim_core::models::system::{NetworkInfo, SystemMetrics};
 use oneshim_core::ports::storage::MetricsStorage;
-use rusqlite::Connection;
 use tempfile::TempDir;
 
-use crate::sqlite::SqliteStorage;
+use crate::sqlite::{SqlitePool, SqliteStorage};
 
 // ── Harness ─────────────────────────────────────────────────────
 
 /// Opens a fresh on-disk SQLite DB with all migrations applied.
 /// Returned `TempDir` must outlive the test; drop order matters
-/// (connection must drop before tempdir).
+/// (pool must drop before tempdir).
 #[allow(dead_code)]
-fn open_db() -> (TempDir, Arc<Mutex<Connection>>) {
+fn open_db() -> (TempDir, SqlitePool) {
     let dir = tempfile::tempdir().expect("tempdir");
-    let conn = Connection::open(dir.path().join("t.db")).expect("open sqlite");
-    crate::migration::run_migrations(&conn).expect("run_migrations");
-    (dir, Arc::new(Mutex::new(conn)))
+    let storage = SqliteStorage::open(&dir.path().join("t.db"), 30, None).expect("open sqlite");
+    (dir, storage.connection_pool())
 }
 
 /// Opens an in-memory `SqliteStorage` with the standard 30-day retention.
@@ -188,6 +185,66 @@ async fn aggregate_hourly_metrics_utc_midnight_boundary() {
     assert!(rows[1].hour.ends_with("T00:00:00Z"));
 }
 
+// ── aggregate_daily_metrics ───────────────────────────────────
+
+#[tokio::test]
+async fn aggregate_daily_metrics_weights_hours_and_is_idempotent() {
+    let storage = open_storage();
+    let yesterday = Utc::now().date_naive() - Duration::days(1);
+    let day_start: DateTime<Utc> = yesterday.and_hms_opt(0, 0, 0).unwrap().and_utc();
+    let date_key = yesterday.format("%Y-%m-%d").to_string();
+
+    // 09:00 holds three samples averaging 40%, 14:00 a single 80% sample.
+    let busy_hour = day_start + Duration::hours(9);
+    for (offset_min, cpu) in [(5, 20.0_f32), (20, 60.0_f32), (50, 40.0_f32)] {
+        storage
+            .save_metrics(&sample_metrics(
+                busy_hour + Duration::minutes(offset_min),
+                cpu,
+            ))
+            .await
+            .unwrap();
+    }
+    let quiet_hour = day_start + Duration::hours(14);
+    storage
+        .save_metrics(&sample_metrics(quiet_hour + Duration::minutes(10), 80.0))
+        .await
+        .unwrap();
+    storage.aggregate_hourly_metrics(busy_hour).await.unwrap();
+    storage.aggregate_hourly_metrics(quiet_hour).await.unwrap();
+
+    storage.aggregate_daily_metrics(day_start).await.unwrap();
+    storage
+        .aggregate_daily_metrics(day_start + Duration::hours(23))
+        .await
+        .unwrap();
+
+    let rows = storage.list_daily_metrics_since(&date_key).unwrap();
+    assert_eq!(rows.len(), 1, "rerunning the rollup must not duplicate");
+    let r = &rows[0];
+    assert_eq!(r.date, date_key);
+    assert_eq!(r.sample_count, 4);
+    assert!(
+        (r.cpu_avg - 50.0_f64).abs() < 0.1,
+        "cpu_avg should be sample-weighted (~50.0), got {}",
+        r.cpu_avg
+    );
+    assert!((r.cpu_max - 80.0_f64).abs() < 0.1);
+}
+
+#[tokio::test]
+async fn aggregate_daily_metrics_empty_day_writes_no_row() {
+    let storage = open_storage();
+    let tomorrow = Utc::now() + Duration::days(1);
+
+    storage.aggregate_daily_metrics(tomorrow).await.unwrap();
+
+    let rows = storage
+        .list_daily_metrics_since(&tomorrow.format("%Y-%m-%d").to_string())
+        .unwrap();
+    assert!(rows.is_empty());
+}
+
 // ── process_snapshots ──────────────────────────────────────────
 // save_process_snapshot + get_process_snapshots are covered by
 // sqlite/tests.rs:225 `save_and_get_process_snapshot`. Only the
@@ -240,13 +297,13 @@ async fn cleanup_old_idle_periods_preserves_active_even_if_start_is_old() {
 
     // An active period started 100 days ago — cleanup must NOT delete it.
     let _active_id = storage
-        .start_idle_period(now - Duration::days(100))
+        .start_idle_period(now - Duration::days(100), 0)
         .await
         .unwrap();
 
     // An ended period from 50 days ago — cleanup SHOULD delete.
     let ended_id = storage
-        .start_idle_period(now - Duration::days(50))
+        .start_idle_period(now - Duration::days(50), 0)
         .await
         .unwrap();
     storage
@@ -262,6 +319,40 @@ async fn cleanup_old_idle_periods_preserves_active_even_if_start_is_old() {
     assert!(ongoing.is_some(), "active period survived cleanup");
 }
 
+#[tokio::test]
+async fn start_idle_period_merges_across_short_activity_blip() {
+    for (threshold, expected_periods) in [(5, 1), (1, 2)] {
+        let storage = open_storage();
+        let t0 = Utc::now() - Duration::hours(2);
+
+        let first = storage.start_idle_period(t0, threshold).await.unwrap();
+        storage
+            .end_idle_period(first, t0 + Duration::minutes(60))
+            .await
+            .unwrap();
+        // A 3-second blip of activity, then idle again.
+        let second = storage
+            .start_idle_period(t0 + Duration::minutes(60) + Duration::seconds(3), threshold)
+            .await
+            .unwrap();
+        storage
+            .end_idle_period(second, t0 + Duration::minutes(120))
+            .await
+            .unwrap();
+
+        let periods = storage
+            .get_idle_periods(t0 - Duration::minutes(1), t0 + Duration::minutes(121))
+            .await
+            .unwrap();
+        assert_eq!(periods.len(), expected_periods, "threshold {threshold}s");
+        if expected_periods == 1 {
+            assert_eq!(second, first);
+            assert_eq!(periods[0].start_time, t0);
+            assert_eq!(periods[0].end_time, Some(t0 + Duration::minutes(120)));
+        }
+    }
+}
+
 // ── sessions ───────────────────────────────────────────────────
 // upsert/get/end + increment-on-existing covered by sqlite/tests.rs:267
 // `session_stats_lifecycle`. Residual gap: increment on nonexistent.
@@ -332,10 +423,10 @@ async fn list_hourly_metrics_since_filters_by_from_hour() {
     assert_eq!(results[0].hour, from_key);
 }
 
-// ── lock-contract regression ───────────────────────────────────
-// NOT race tests — Arc<Mutex<Connection>> already serializes. These
-// tests assert the Mutex contract survives across multiple async tasks
-// on a multi-thread tokio runtime. No overlap with
+// ── pool-contract regression ───────────────────────────────────
+// Writers on different pooled connections serialize on SQLite's write
+// lock; readers see committed snapshots. These tests assert that contract
+// across multiple async tasks on a multi-thread tokio runtime. No overlap with
 // sqlite/tests.rs:139 `concurrent_save_and_get` (that tests save_event,
 // not save_metrics / save_process_snapshot).
 
@@ -404,8 +495,8 @@ async fn concurrent_writer_plus_reader_yield_consistent_snapshot() {
                     )
                     .await
                     .unwrap();
-                // Invariant: count monotonic via Arc<Mutex> serialization;
-                // never observe more than the writer has committed.
+                // Invariant: each read sees a committed snapshot, so it
+                // never observes more than the writer has committed.
                 assert!(results.len() <= 50);
                 max_observed = max_observed.max(results.len());
                 // Yield so writer gets a chance to make progress.
@@ -457,11 +548,11 @@ async fn concurrent_writer_plus_reader_yield_consistent_snapshot() {
 #[tokio::test]
 async fn get_process_snapshots_invalid_json_in_column_silently_defaults_to_empty() {
     let storage = open_storage();
-    let arc = storage.connection_arc();
+    let pool = storage.connection_pool();
 
     // Direct-insert a row with malformed JSON in snapshot_data.
     {
-        let conn = arc.lock().unwrap();
+        let conn = pool.get().unwrap();
         conn.execute(
             "INSERT INTO process_snapshots (timestamp, snapshot_data) VALUES (?1, ?2)",
             rusqlite::params![Utc::now().to_rfc3339(), "{not:valid json"],
@@ -488,29 +579,24 @@ async fn get_process_snapshots_invalid_json_in_column_silently_defaults_to_empty
 }
 
 #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
-async fn save_metrics_mutex_poison_returns_err_variant_only() {
+async fn save_metrics_after_panic_holding_connection_still_succeeds() {
     let storage = open_storage();
-    let conn_arc = storage.connection_arc();
+    let pool = storage.connection_pool();
 
-    // Poison the lock from a blocking task.
-    let c = conn_arc.clone();
+    // Panic while holding a pooled connection. Unlike a
+    // `Mutex<Connection>`, the pool has no poisoning: the connection is
+    // simply returned.
+    let p = pool.clone();
     let _ = tokio::task::spawn_blocking(move || {
-        let _guard = c.lock().unwrap();
-        panic!("intentional poison");
+        let _conn = p.get().unwrap();
+        panic!("intentional panic");
     })
     .await;
 
-    // The subsequent save_metrics should propagate as CoreError::Storage.
-    // Variant-only match — error string varies per call site (see spec Section 7).
-    // iter-47: changed from Internal → Storage when StorageError::Internal
-    // mapping was redirected to storage.failed (observed failure IS a storage op).
-    let result = storage
+    storage
         .save_metrics(&sample_metrics(Utc::now(), 10.0))
-        .await;
-    assert!(
-        matches!(result, Err(CoreError::Storage { .. })),
-        "expected Err(CoreError::Storage {{ .. }}), got {result:?}"
-    );
+        .await
+        .unwrap();
 }
 
 // ── contract-covered edge cases ────────────────────────────────
@@ -597,3 +683,50 @@ async fn cleanup_old_metrics_deletes_before_cutoff_preserves_after() {
         .unwrap();
     assert_eq!(remaining.len(), 2);
 }
+
+// ── event_histogram ────────────────────────────────────────────
+
+#[tokio::test]
+async fn event_histogram_zero_fills_ten_minute_buckets() {
+    let storage = open_storage();
+    let from = current_hour_start() - Duration::hours(3);
+    let to = from + Duration::hours(3);
+
+    for (i, offset_min) in [1, 5, 9, 25, 179].into_iter().enumerate() {
+        let ts = (from + Duration::minutes(offset_min)).to_rfc3339();
+        storage
+            .upsert_backup_event(&format!("evt-{i}"), "WindowChange", &ts, None, None)
+            .unwrap();
+    }
+    // Outside the half-open range.
+    storage
+        .upsert_backup_event("evt-late", "WindowChange", &to.to_rfc3339(), None, None)
+        .unwrap();
+
+    let buckets = storage
+        .event_histogram(from, to, Duration::minutes(10))
+        .await
+        .unwrap();
+    assert_eq!(buckets.len(), 18);
+    assert_eq!(buckets[0], (from, 3));
+    assert_eq!(buckets[1], (from + Duration::minutes(10), 0));
+    assert_eq!(buckets[2].1, 1);
+    assert_eq!(buckets[17], (from + Duration::minutes(170), 1));
+    assert_eq!(buckets.iter().map(|(_, count)| count).sum::<u64>(), 5);
+
+    let empty = storage
+        .event_histogram(
+            to + Duration::hours(1),
+            to + Duration::hours(2),
+            Duration::minutes(10),
+        )
+        .await
+        .unwrap();
+    assert_eq!(empty.len(), 6);
+    assert!(empty.iter().all(|(_, count)| *count == 0));
+
+    assert!(storage
+        .event_histogram(from, to, Duration::zero())
+        .await
+        .is_err());
+}
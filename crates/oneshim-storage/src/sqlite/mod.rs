@@ -1,8 +1,11 @@
 mod annotation_storage_impl;
+mod bookmark_storage_impl;
 mod calibration_store_impl;
+mod capture_latency;
 mod coaching_storage;
 mod coaching_storage_port_impl;
 mod dashboard_streaming;
+mod db_backup;
 mod device_identity;
 pub(crate) mod edge_intelligence;
 mod events;
@@ -17,12 +20,15 @@ mod maintenance;
 mod metrics;
 mod override_store_impl;
 mod preset_storage_impl;
+pub mod query_api;
 mod session_context_store_impl;
 mod session_storage_impl;
 mod tags;
+mod text_compression;
 pub mod vector_index_impl;
 pub mod vector_store_impl;
 mod web_storage_impl;
+mod workflow_history_impl;
 
 #[cfg(test)]
 mod port_contract_tests;
@@ -33,10 +39,14 @@ mod tests;
 
 use crate::encryption::EncryptionKey;
 use crate::error::StorageError;
-use rusqlite::Connection;
+use oneshim_core::models::work_session::AppCategory;
+use parking_lot::{Mutex, RwLock};
+use r2d2::Pool;
+use r2d2_sqlite::SqliteConnectionManager;
+use rusqlite::{Connection, OpenFlags, TransactionBehavior};
+use std::collections::HashMap;
 use std::path::Path;
-use std::sync::atomic::{AtomicBool, Ordering};
-use std::sync::{Arc, Mutex};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
 use tracing::{info, warn};
 
 use crate::migration;
@@ -52,35 +62,65 @@ use crate::migration;
 /// and this global flag being `true` is correct for all concurrent tests.
 pub(super) static FTS_AVAILABLE: AtomicBool = AtomicBool::new(false);
 
+/// Process-global flag indicating whether the `frames_ocr_fts` table exists (V40 migration).
+///
+/// Same rationale and thread-safety guarantees as [`FTS_AVAILABLE`].
+pub(super) static OCR_FTS_AVAILABLE: AtomicBool = AtomicBool::new(false);
+
 /// Process-global flag indicating whether the `gui_interactions` table exists (V13 migration).
 ///
 /// Same rationale and thread-safety guarantees as [`FTS_AVAILABLE`].
 pub(super) static GUI_INTERACTIONS_AVAILABLE: AtomicBool = AtomicBool::new(false);
 
-/// Local SQLite storage with a single-connection, Mutex-guarded design.
+/// Pool of connections to one SQLite database, shared by [`SqliteStorage`]
+/// and the adapters built from [`SqliteStorage::connection_pool`].
+pub type SqlitePool = Pool<SqliteConnectionManager>;
+
+/// Connections per pool.
+const POOL_SIZE: u32 = 4;
+
+/// Local SQLite storage backed by a small connection pool.
 ///
 /// # Connection design
 ///
-/// This store uses a single `Connection` behind a `Mutex` rather than a
-/// connection pool. The rationale:
+/// The store holds a [`POOL_SIZE`]-connection r2d2 pool so that dashboard
+/// reads do not queue behind scheduler writes:
 ///
-/// 1. **WAL mode** (`PRAGMA journal_mode=WAL`) allows concurrent readers
-///    from the OS level, but rusqlite's `Connection` is not `Sync`, so we
-///    still need a Mutex for Rust's thread-safety requirements.
-/// 2. All blocking SQLite operations are offloaded to `spawn_blocking`,
-///    which prevents the Mutex from starving the async runtime.
-/// 3. A full read/write pool (e.g. r2d2 + separate read-only connections)
-///    adds complexity without measurable benefit for our workload profile:
-///    the scheduler ticks at 1-10 Hz and queries complete in <1ms.
+/// 1. **WAL mode** (`PRAGMA journal_mode=WAL`) lets pooled connections read
+///    concurrently while one of them writes.
+/// 2. Writes still serialize on SQLite's single write lock. Every
+///    connection begins transactions `IMMEDIATE` and waits up to
+///    `busy_timeout`, so a read-then-write transaction can never fail to
+///    upgrade. Multi-statement read-modify-write sequences must therefore
+///    run inside a transaction; holding a connection no longer excludes
+///    other callers.
+/// 3. All blocking SQLite operations are still offloaded to
+///    `spawn_blocking`; a connection is checked out for the duration of one
+///    operation and returned when its guard drops.
 ///
-/// If profiling reveals lock contention, the next step would be opening a
-/// second read-only connection (`SQLITE_OPEN_READ_ONLY`) and routing
-/// SELECT-only queries through it. The [`read_only_query`](Self::read_only_query)
-/// helper already enforces the "acquire lock, clone data out, release lock"
-/// pattern to minimise the critical section.
+/// In-memory databases use a uniquely named shared-cache URI so every
+/// pooled connection sees the same database; see [`Self::open_in_memory`].
 pub struct SqliteStorage {
-    pub(super) conn: Arc<Mutex<Connection>>,
-    pub(super) retention_days: u32,
+    pub(super) pool: SqlitePool,
+    /// Event retention window; see [`Self::set_retention_days`].
+    pub(super) retention_days: AtomicU32,
+    /// Per-category windows; see [`Self::set_retention_overrides`].
+    pub(super) retention_overrides: RwLock<RetentionOverrides>,
+    /// Image hashes awaiting their frame row; see
+    /// [`Self::note_frame_file_hash`].
+    pub(super) pending_frame_hashes: Mutex<HashMap<String, String>>,
+    /// See [`Self::with_text_compression`].
+    pub(super) text_compression_min_bytes: Option<usize>,
+    /// SQLCipher key the connection was opened with, reapplied to backup
+    /// destinations. `None` when the database is unencrypted.
+    pub(super) encryption_key: Option<EncryptionKey>,
+}
+
+/// See [`SqliteStorage::set_retention_overrides`].
+#[derive(Debug, Clone, Default)]
+pub(super) struct RetentionOverrides {
+    pub(super) days_by_category: HashMap<AppCategory, u32>,
+    pub(super) app_categories: HashMap<String, AppCategory>,
 }
 
 impl SqliteStorage {
@@ -89,54 +129,114 @@ impl SqliteStorage {
     /// When `encryption_key` is `Some`, SQLCipher `PRAGMA key` is applied after
     /// opening. If the database was previously unencrypted, the key verification
     /// will fail and the database is reopened **without** encryption so that
-    /// existing data is not lost. A warning is logged in this case.
+    /// existing data is not lost. A warning is logged in this case. Builds
+    /// without the `encryption` feature ignore the key.
     pub fn open(
         path: &Path,
         retention_days: u32,
         encryption_key: Option<&EncryptionKey>,
     ) -> Result<Self, StorageError> {
         let conn = Connection::open(path)
-            .map_err(|e| StorageError::Internal(format!("Failed to open SQLite database: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to open SQLite database: {e}")))?;
 
-        let conn = apply_sqlcipher_key(conn, path, encryption_key)?;
+        let (conn, keyed) = apply_sqlcipher_key(conn, path, encryption_key)?;
 
         configure_connection(&conn, true)?;
 
-        migration::run_migrations(&conn)
-            .map_err(|e| StorageError::Internal(format!("migration failure: {e}")))?;
+        migration::run_migrations_transactional(&conn)
+            .map_err(|e| StorageError::Database(format!("migration failure: {e}")))?;
 
         post_migration_setup(&conn)?;
 
+        // Migrations ran on the bootstrap connection; the pool only needs
+        // per-connection setup, with the same key decision.
+        let encryption_key = encryption_key.filter(|_| keyed).cloned();
+        let pool = build_pool(
+            SqliteConnectionManager::file(path),
+            true,
+            encryption_key.clone(),
+        )?;
+        drop(conn);
+
         info!("SQLite save initialize: {}", path.display());
 
         Ok(Self {
-            conn: Arc::new(Mutex::new(conn)),
-            retention_days,
+            pool,
+            retention_days: AtomicU32::new(retention_days),
+            retention_overrides: RwLock::default(),
+            pending_frame_hashes: Mutex::default(),
+            text_compression_min_bytes: None,
+            encryption_key,
         })
     }
 
+    /// Open a private in-memory database, mainly for tests.
+    ///
+    /// Each call gets a uniquely named `mode=memory&cache=shared` URI so the
+    /// pooled connections share one logical database. The database lives as
+    /// long as any pooled connection does; the pool keeps its connections
+    /// open indefinitely. Shared-cache table locks are waited out via
+    /// rusqlite's `unlock_notify` feature rather than failing.
     pub fn open_in_memory(retention_days: u32) -> Result<Self, StorageError> {
-        let conn = Connection::open_in_memory().map_err(|e| {
-            StorageError::Internal(format!("Failed to create in-memory SQLite database: {e}"))
-        })?;
-
-        configure_connection(&conn, false)?;
+        let uri = format!(
+            "file:oneshim-mem-{}?mode=memory&cache=shared",
+            uuid::Uuid::new_v4().simple()
+        );
+        let pool = build_pool(SqliteConnectionManager::file(uri), false, None)?;
+
+        {
+            let conn = pool.get().map_err(|e| {
+                StorageError::Database(format!("Failed to create in-memory SQLite database: {e}"))
+            })?;
 
-        migration::run_migrations(&conn)
-            .map_err(|e| StorageError::Internal(format!("migration failure: {e}")))?;
+            migration::run_migrations_transactional(&conn)
+                .map_err(|e| StorageError::Database(format!("migration failure: {e}")))?;
 
-        post_migration_setup(&conn)?;
+            post_migration_setup(&conn)?;
+        }
 
         Ok(Self {
-            conn: Arc::new(Mutex::new(conn)),
-            retention_days,
+            pool,
+            retention_days: AtomicU32::new(retention_days),
+            retention_overrides: RwLock::default(),
+            pending_frame_hashes: Mutex::default(),
+            text_compression_min_bytes: None,
+            encryption_key: None,
         })
     }
 
-    /// Expose the underlying connection Arc for shared-connection adapters
-    /// (e.g., `SqliteVectorStore`).
-    pub fn connection_arc(&self) -> Arc<Mutex<Connection>> {
-        self.conn.clone()
+    /// zstd-compress frame OCR text of at least `min_bytes` on write.
+    /// `None` (the default) writes plain text. Reads handle both forms.
+    pub fn with_text_compression(mut self, min_bytes: Option<usize>) -> Self {
+        self.text_compression_min_bytes = min_bytes;
+        self
+    }
+
+    /// Change the event retention window applied by later
+    /// `enforce_retention` runs, e.g. after a config hot-reload.
+    pub fn set_retention_days(&self, days: u32) {
+        self.retention_days.store(days, Ordering::Relaxed);
+    }
+
+    /// Give events of the listed app categories their own retention window
+    /// in later `enforce_retention` runs. Apps are classified with
+    /// `app_categories` (`monitor.category_overrides`) as work sessions are;
+    /// events of other categories, or with no app, keep the global window.
+    pub fn set_retention_overrides(
+        &self,
+        days_by_category: HashMap<AppCategory, u32>,
+        app_categories: HashMap<String, AppCategory>,
+    ) {
+        *self.retention_overrides.write() = RetentionOverrides {
+            days_by_category,
+            app_categories,
+        };
+    }
+
+    /// Expose the connection pool for adapters that share this database
+    /// (e.g., `SqliteVectorStore`). Cloning is cheap.
+    pub fn connection_pool(&self) -> SqlitePool {
+        self.pool.clone()
     }
 
     /// 동기 SQLite 읽기/단순 쓰기 연산을 spawn_blocking으로 격리한다.
@@ -146,11 +246,11 @@ impl SqliteStorage {
         F: FnOnce(&Connection) -> Result<T, StorageError> + Send + 'static,
         T: Send + 'static,
     {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         tokio::task::spawn_blocking(move || {
-            let guard = conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            let guard = pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
             f(&guard)
         })
         .await
@@ -165,23 +265,23 @@ impl SqliteStorage {
         F: FnOnce(&mut Connection) -> Result<T, StorageError> + Send + 'static,
         T: Send + 'static,
     {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         tokio::task::spawn_blocking(move || {
-            let mut guard = conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            let mut guard = pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
             f(&mut guard)
         })
         .await
         .map_err(|e| StorageError::Internal(format!("spawn_blocking join error: {e}")))?
     }
 
-    /// Execute a read-only query with a short-lived lock scope.
+    /// Execute a read-only query on a briefly checked-out connection.
     ///
     /// The closure `f` receives a `&Connection` and must clone/copy the
-    /// data it needs into a fully-owned `T`. The Mutex is released as soon
-    /// as `f` returns, before the `spawn_blocking` future completes, so
-    /// writers are not blocked while the caller processes the result.
+    /// data it needs into a fully-owned `T`. The connection returns to the
+    /// pool as soon as `f` returns, before the `spawn_blocking` future
+    /// completes, so it is not held while the caller processes the result.
     ///
     /// This is the recommended pattern for pure SELECT queries that return
     /// small to medium result sets (e.g., config lookups, aggregate stats).
@@ -201,16 +301,16 @@ impl SqliteStorage {
         F: FnOnce(&Connection) -> Result<T, StorageError> + Send + 'static,
         T: Send + 'static,
     {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         tokio::task::spawn_blocking(move || {
-            // Acquire lock, execute query, release lock -- all within the
-            // blocking thread. The result `T` is owned so the lock is not
+            // Check out, execute query, return -- all within the blocking
+            // thread. The result `T` is owned so the connection is not
             // held while the async runtime schedules the continuation.
-            let guard = conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            let guard = pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
             f(&guard)
-            // guard drops here, releasing the Mutex
+            // guard drops here, returning the connection to the pool
         })
         .await
         .map_err(|e| StorageError::Internal(format!("spawn_blocking join error: {e}")))?
@@ -220,7 +320,7 @@ impl SqliteStorage {
 
     /// Retrieve a value from the `app_meta` table, or `None` if the key does not exist.
     pub fn get_meta(&self, key: &str) -> Option<String> {
-        let conn = self.conn.lock().ok()?;
+        let conn = self.pool.get().ok()?;
         conn.query_row("SELECT value FROM app_meta WHERE key = ?1", [key], |row| {
             row.get(0)
         })
@@ -229,7 +329,7 @@ impl SqliteStorage {
 
     /// Insert or replace a value in the `app_meta` table.
     pub fn set_meta(&self, key: &str, value: &str) {
-        if let Ok(conn) = self.conn.lock() {
+        if let Ok(conn) = self.pool.get() {
             let _ = conn.execute(
                 "INSERT OR REPLACE INTO app_meta (key, value) VALUES (?1, ?2)",
                 rusqlite::params![key, value],
@@ -239,7 +339,7 @@ impl SqliteStorage {
 
     /// Delete a key from the `app_meta` table.
     pub fn delete_meta(&self, key: &str) {
-        if let Ok(conn) = self.conn.lock() {
+        if let Ok(conn) = self.pool.get() {
             let _ = conn.execute("DELETE FROM app_meta WHERE key = ?1", [key]);
         }
     }
@@ -253,8 +353,8 @@ impl SqliteStorage {
     /// Designed to be called from a persistence callback wired by `src-tauri`.
     /// Failures are logged and swallowed to avoid disrupting the audit buffer.
     pub fn save_audit_entry(&self, entry: &oneshim_core::models::audit::AuditEntry) {
-        let Ok(conn) = self.conn.lock() else {
-            warn!("audit persistence: failed to acquire SQLite lock");
+        let Ok(conn) = self.pool.get() else {
+            warn!("audit persistence: failed to acquire SQLite connection");
             return;
         };
 
@@ -299,10 +399,8 @@ impl SqliteStorage {
         command_id: &str,
         limit: usize,
     ) -> Vec<oneshim_core::models::audit::AuditEntry> {
-        use oneshim_core::models::audit::{AuditEntry, AuditStatus};
-
-        let Ok(conn) = self.conn.lock() else {
-            warn!("audit: entries_by_command_id failed to acquire SQLite lock");
+        let Ok(conn) = self.pool.get() else {
+            warn!("audit: entries_by_command_id failed to acquire SQLite connection");
             return Vec::new();
         };
 
@@ -321,40 +419,10 @@ impl SqliteStorage {
             }
         };
 
-        let mapped = stmt.query_map(rusqlite::params![command_id, limit as i64], |row| {
-            let ts_str: String = row.get("timestamp")?;
-            let timestamp = chrono::DateTime::parse_from_rfc3339(&ts_str)
-                .map(|dt| dt.with_timezone(&chrono::Utc))
-                .map_err(|e| {
-                    rusqlite::Error::FromSqlConversionFailure(
-                        1,
-                        rusqlite::types::Type::Text,
-                        Box::new(e),
-                    )
-                })?;
-
-            let status_str: String = row.get("status")?;
-            let status = match status_str.as_str() {
-                "Completed" => AuditStatus::Completed,
-                "Failed" => AuditStatus::Failed,
-                "Denied" => AuditStatus::Denied,
-                "Timeout" => AuditStatus::Timeout,
-                "Started" => AuditStatus::Started,
-                _ => AuditStatus::Completed, // forward-compat default
-            };
-
-            let etime: Option<i64> = row.get("execution_time_ms").ok();
-            Ok(AuditEntry {
-                entry_id: row.get("entry_id")?,
-                timestamp,
-                session_id: row.get("session_id")?,
-                command_id: row.get("command_id")?,
-                action_type: row.get("action_type")?,
-                status,
-                details: row.get("details").ok(),
-                execution_time_ms: etime.map(|v| v as u64),
-            })
-        });
+        let mapped = stmt.query_map(
+            rusqlite::params![command_id, limit as i64],
+            audit_entry_from_row,
+        );
 
         match mapped {
             Ok(iter) => iter.filter_map(|r| r.ok()).collect(),
@@ -366,6 +434,122 @@ impl SqliteStorage {
     }
 }
 
+impl SqliteStorage {
+    /// One page of audit entries matching `filter`, newest first, plus the
+    /// total match count. Infallible like [`Self::entries_by_command_id`]:
+    /// SQLite errors are logged and yield an empty result.
+    pub fn query_audit_entries(
+        &self,
+        filter: &oneshim_core::models::audit::AuditFilter,
+        page: oneshim_core::models::audit::AuditPage,
+    ) -> oneshim_core::models::audit::AuditQueryResult {
+        use oneshim_core::models::audit::AuditQueryResult;
+
+        let mut clauses = Vec::new();
+        let mut params: Vec<String> = Vec::new();
+        if let Some(from) = filter.from {
+            clauses.push("timestamp >= ?".to_string());
+            params.push(from.to_rfc3339());
+        }
+        if let Some(to) = filter.to {
+            clauses.push("timestamp <= ?".to_string());
+            params.push(to.to_rfc3339());
+        }
+        if !filter.outcomes.is_empty() {
+            let placeholders = vec!["?"; filter.outcomes.len()].join(", ");
+            clauses.push(format!("status IN ({placeholders})"));
+            params.extend(
+                filter
+                    .outcomes
+                    .iter()
+                    .map(|outcome| format!("{:?}", outcome.status())),
+            );
+        }
+        let where_sql = if clauses.is_empty() {
+            String::new()
+        } else {
+            format!("WHERE {}", clauses.join(" AND "))
+        };
+
+        let Ok(conn) = self.pool.get() else {
+            warn!("audit: query_audit_entries failed to acquire SQLite connection");
+            return AuditQueryResult::default();
+        };
+
+        let total = conn
+            .query_row(
+                &format!("SELECT COUNT(*) FROM audit_log {where_sql}"),
+                rusqlite::params_from_iter(params.iter()),
+                |row| row.get::<_, i64>(0),
+            )
+            .map_err(|e| warn!(err = %e, "audit: query_audit_entries count failed"))
+            .unwrap_or(0);
+
+        let sql = format!(
+            "SELECT entry_id, timestamp, session_id, command_id, action_type,
+                    status, details, execution_time_ms
+             FROM audit_log {where_sql}
+             ORDER BY timestamp DESC
+             LIMIT {} OFFSET {}",
+            page.limit.min(i64::MAX as usize),
+            page.offset.min(i64::MAX as usize)
+        );
+        let entries = conn.prepare(&sql).and_then(|mut stmt| {
+            stmt.query_map(
+                rusqlite::params_from_iter(params.iter()),
+                audit_entry_from_row,
+            )
+            .map(|iter| iter.filter_map(|r| r.ok()).collect())
+        });
+
+        match entries {
+            Ok(entries) => AuditQueryResult {
+                entries,
+                total: total as usize,
+            },
+            Err(e) => {
+                warn!(err = %e, "audit: query_audit_entries failed");
+                AuditQueryResult::default()
+            }
+        }
+    }
+}
+
+fn audit_entry_from_row(
+    row: &rusqlite::Row<'_>,
+) -> rusqlite::Result<oneshim_core::models::audit::AuditEntry> {
+    use oneshim_core::models::audit::{AuditEntry, AuditStatus};
+
+    let ts_str: String = row.get("timestamp")?;
+    let timestamp = chrono::DateTime::parse_from_rfc3339(&ts_str)
+        .map(|dt| dt.with_timezone(&chrono::Utc))
+        .map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(1, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+    let status_str: String = row.get("status")?;
+    let status = match status_str.as_str() {
+        "Completed" => AuditStatus::Completed,
+        "Failed" => AuditStatus::Failed,
+        "Denied" => AuditStatus::Denied,
+        "Timeout" => AuditStatus::Timeout,
+        "Started" => AuditStatus::Started,
+        _ => AuditStatus::Completed, // forward-compat default
+    };
+
+    let etime: Option<i64> = row.get("execution_time_ms").ok();
+    Ok(AuditEntry {
+        entry_id: row.get("entry_id")?,
+        timestamp,
+        session_id: row.get("session_id")?,
+        command_id: row.get("command_id")?,
+        action_type: row.get("action_type")?,
+        status,
+        details: row.get("details").ok(),
+        execution_time_ms: etime.map(|v| v as u64),
+    })
+}
+
 /// Apply SQLCipher `PRAGMA key` and verify the key works.
 ///
 /// If the key is rejected (e.g. database was previously unencrypted), falls back
@@ -374,38 +558,102 @@ fn apply_sqlcipher_key(
     conn: Connection,
     path: &Path,
     encryption_key: Option<&EncryptionKey>,
-) -> Result<Connection, StorageError> {
-    let Some(key) = encryption_key else {
-        return Ok(conn);
+) -> Result<(Connection, bool), StorageError> {
+    let Some(key) = encryption_key.filter(|_| cfg!(feature = "encryption")) else {
+        if encryption_key.is_some() {
+            warn!("built without the `encryption` feature — opening SQLite without a key");
+        }
+        return Ok((conn, false));
     };
 
-    // PRAGMA key must be the first statement after opening.
-    let pragma = format!("PRAGMA key = \"x'{}'\";", key.as_hex());
-    if let Err(e) = conn.execute_batch(&pragma) {
+    if let Err(e) = apply_key_pragma(&conn, key) {
         warn!("SQLCipher PRAGMA key execution failed: {e} — opening without encryption");
         drop(conn);
         let fallback = Connection::open(path).map_err(|e| {
-            StorageError::Internal(format!("Failed to reopen SQLite database: {e}"))
+            StorageError::Database(format!("Failed to reopen SQLite database: {e}"))
         })?;
-        return Ok(fallback);
+        return Ok((fallback, false));
     }
 
     // Verify the key actually works by reading sqlite_master.
     match conn.execute_batch("SELECT count(*) FROM sqlite_master;") {
-        Ok(()) => Ok(conn),
+        Ok(()) => Ok((conn, true)),
         Err(_) => {
             warn!(
                 "SQLCipher key verification failed — database may be unencrypted, reopening without encryption"
             );
             drop(conn);
             let fallback = Connection::open(path).map_err(|e| {
-                StorageError::Internal(format!("Failed to reopen SQLite database: {e}"))
+                StorageError::Database(format!("Failed to reopen SQLite database: {e}"))
             })?;
-            Ok(fallback)
+            Ok((fallback, false))
         }
     }
 }
 
+/// Open `path` read-only, keyed when `encryption_key` unlocks it. Never
+/// creates, migrates or otherwise writes to the database.
+pub(super) fn open_read_only(
+    path: &Path,
+    encryption_key: Option<&EncryptionKey>,
+) -> Result<Connection, StorageError> {
+    let open = || {
+        Connection::open_with_flags(
+            path,
+            OpenFlags::SQLITE_OPEN_READ_ONLY | OpenFlags::SQLITE_OPEN_NO_MUTEX,
+        )
+        .map_err(|e| StorageError::Database(format!("Failed to open SQLite database: {e}")))
+    };
+    let conn = open()?;
+    let Some(key) = encryption_key.filter(|_| cfg!(feature = "encryption")) else {
+        return Ok(conn);
+    };
+    if apply_key_pragma(&conn, key).is_ok()
+        && conn
+            .execute_batch("SELECT count(*) FROM sqlite_master;")
+            .is_ok()
+    {
+        return Ok(conn);
+    }
+    // Same fallback as `apply_sqlcipher_key`: the file is not keyed.
+    drop(conn);
+    open()
+}
+
+/// Run `PRAGMA key`. Must be the first statement after opening.
+pub(super) fn apply_key_pragma(conn: &Connection, key: &EncryptionKey) -> rusqlite::Result<()> {
+    conn.execute_batch(&format!("PRAGMA key = \"x'{}'\";", key.as_hex()))
+}
+
+/// Build a [`POOL_SIZE`]-connection pool whose connections are keyed (when
+/// `encryption_key` is set) and configured like the bootstrap connection.
+fn build_pool(
+    manager: SqliteConnectionManager,
+    is_disk: bool,
+    encryption_key: Option<EncryptionKey>,
+) -> Result<SqlitePool, StorageError> {
+    let manager = manager.with_init(move |conn| {
+        if let Some(key) = &encryption_key {
+            apply_key_pragma(conn, key)?;
+        }
+        configure_connection(conn, is_disk)
+            .map_err(|e| rusqlite::Error::UserFunctionError(e.to_string().into()))?;
+        // Take the write lock up front so read-then-write transactions on
+        // different connections wait on `busy_timeout` instead of failing
+        // with SQLITE_BUSY_SNAPSHOT.
+        conn.set_transaction_behavior(TransactionBehavior::Immediate);
+        Ok(())
+    });
+    Pool::builder()
+        .max_size(POOL_SIZE)
+        // Never reap idle connections: an in-memory database disappears
+        // with its last connection.
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .build(manager)
+        .map_err(|e| StorageError::Database(format!("Failed to build SQLite connection pool: {e}")))
+}
+
 /// Apply PRAGMA settings to a freshly opened connection.
 ///
 /// * `is_disk=true` — all PRAGMAs (WAL, synchronous, cache_size, temp_store,
@@ -427,7 +675,7 @@ fn configure_connection(conn: &Connection, is_disk: bool) -> Result<(), StorageE
             PRAGMA journal_size_limit=67108864;
             ",
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to apply PRAGMA settings: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to apply PRAGMA settings: {e}")))?;
     } else {
         conn.execute_batch(
             "
@@ -435,20 +683,20 @@ fn configure_connection(conn: &Connection, is_disk: bool) -> Result<(), StorageE
             PRAGMA temp_store=MEMORY;
             ",
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to apply PRAGMA settings: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to apply PRAGMA settings: {e}")))?;
     }
-    Ok(())
+    text_compression::register_functions(conn)
 }
 
 /// Post-migration one-time setup: PRAGMA optimize + table-existence caching.
 ///
-/// Called after `run_migrations()` completes in both `open()` and `open_in_memory()`.
+/// Called after `run_migrations_transactional()` completes in both `open()` and `open_in_memory()`.
 fn post_migration_setup(conn: &Connection) -> Result<(), StorageError> {
     // PRAGMA optimize with analysis_limit=1000 + optimize mask 0x10002:
     // - 0x2: run ANALYZE on tables that would benefit
     // - 0x10000: set an internal analysis_limit of 1000 rows
     conn.execute_batch("PRAGMA optimize=0x10002;")
-        .map_err(|e| StorageError::Internal(format!("PRAGMA optimize failed: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("PRAGMA optimize failed: {e}")))?;
 
     // Cache table existence flags so hot-path code avoids sqlite_master queries.
     let fts_exists: bool = conn
@@ -460,6 +708,15 @@ fn post_migration_setup(conn: &Connection) -> Result<(), StorageError> {
         .unwrap_or(false);
     FTS_AVAILABLE.store(fts_exists, Ordering::Release);
 
+    let ocr_fts_exists: bool = conn
+        .query_row(
+            "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='frames_ocr_fts'",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or(false);
+    OCR_FTS_AVAILABLE.store(ocr_fts_exists, Ordering::Release);
+
     let gui_exists: bool = conn
         .query_row(
             "SELECT COUNT(*) > 0 FROM sqlite_master WHERE type='table' AND name='gui_interactions'",
@@ -474,7 +731,8 @@ fn post_migration_setup(conn: &Connection) -> Result<(), StorageError> {
 
 // Record types are canonical in oneshim-core; re-exported here for backward compatibility.
 pub use oneshim_core::models::storage_records::{
-    DeletedRangeCounts, EventExportRecord, FocusInterruptionRecord, FocusWorkSessionRecord,
-    FrameExportRecord, FrameRecord, FrameTagLinkRecord, HourlyMetricsRecord, LocalSuggestionRecord,
-    MetricExportRecord, SearchEventRow, SearchFrameRow, StorageStatsSummaryRecord, TagRecord,
+    DailyMetricsRecord, DeletedRangeCounts, EventExportRecord, FocusInterruptionRecord,
+    FocusWorkSessionRecord, FrameExportRecord, FrameRecord, FrameTagLinkRecord,
+    HourlyMetricsRecord, LocalSuggestionRecord, MetricExportRecord, SearchEventRow, SearchFrameRow,
+    StorageStatsSummaryRecord, TagRecord, TagRuleRecord,
 };
@@ -43,7 +43,9 @@ fn deserialize_action(
                 StorageError::Internal("Missing action_data for REASSIGN_REGIME".to_string())
             })?;
             let parsed: serde_json::Value = serde_json::from_str(data).map_err(|e| {
-                StorageError::Internal(format!("Failed to parse REASSIGN_REGIME action_data: {e}"))
+                StorageError::Serialization(format!(
+                    "Failed to parse REASSIGN_REGIME action_data: {e}"
+                ))
             })?;
             let target = parsed["target_regime_id"]
                 .as_str()
@@ -60,7 +62,7 @@ fn deserialize_action(
                 StorageError::Internal("Missing action_data for MARK_AS_PERSONAL_TIME".to_string())
             })?;
             let parsed: serde_json::Value = serde_json::from_str(data).map_err(|e| {
-                StorageError::Internal(format!(
+                StorageError::Serialization(format!(
                     "Failed to parse MARK_AS_PERSONAL_TIME action_data: {e}"
                 ))
             })?;
@@ -99,7 +101,7 @@ impl OverrideStore for SqliteStorage {
                  VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
                 params![override_id, segment_id, original_regime_id, action_type, action_data, created_at],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to save override: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to save override: {e}")))?;
             Ok(())
         })
         .await
@@ -122,7 +124,7 @@ impl OverrideStore for SqliteStorage {
                      WHERE created_at >= ?1 AND created_at <= ?2
                      ORDER BY created_at ASC",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare list query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare list query: {e}")))?;
 
             let rows = stmt
                 .query_map(params![from_str, to_str], |row| {
@@ -135,12 +137,12 @@ impl OverrideStore for SqliteStorage {
                         row.get::<_, String>(5)?,
                     ))
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to query overrides: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to query overrides: {e}")))?;
 
             let mut overrides = Vec::new();
             for row in rows {
                 let (override_id, segment_id, original_regime_id, action_type, action_data, created_at_str) =
-                    row.map_err(|e| StorageError::Internal(format!("Row read error: {e}")))?;
+                    row.map_err(|e| StorageError::Database(format!("Row read error: {e}")))?;
 
                 let user_action =
                     deserialize_action(&action_type, action_data.as_deref())?;
@@ -172,7 +174,7 @@ impl OverrideStore for SqliteStorage {
                 "DELETE FROM regime_overrides WHERE override_id = ?1",
                 params![id],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to delete override: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to delete override: {e}")))?;
 
             if conn.changes() == 0 {
                 return Err(StorageError::NotFound {
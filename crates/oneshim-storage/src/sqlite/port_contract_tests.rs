@@ -60,7 +60,7 @@ fn make_metrics() -> SystemMetrics {
 #[tokio::test]
 async fn vs_store_empty_vector_returns_invalid_args() {
     let s = storage();
-    let store = SqliteVectorStore::new(s.connection_arc());
+    let store = SqliteVectorStore::new(s.connection_pool());
 
     let result = store.store(vec![], make_metadata("empty")).await;
 
@@ -75,7 +75,7 @@ async fn vs_store_empty_vector_returns_invalid_args() {
 #[tokio::test]
 async fn vs_store_and_search_roundtrip() {
     let s = storage();
-    let store = SqliteVectorStore::new(s.connection_arc());
+    let store = SqliteVectorStore::new(s.connection_pool());
 
     store
         .store(vec![1.0, 0.0, 0.0], make_metadata("rt-seg"))
@@ -90,7 +90,7 @@ async fn vs_store_and_search_roundtrip() {
 #[tokio::test]
 async fn vs_search_empty_store_returns_empty() {
     let s = storage();
-    let store = SqliteVectorStore::new(s.connection_arc());
+    let store = SqliteVectorStore::new(s.connection_pool());
 
     let results = store.search(&[1.0, 0.0], 10, 24.0).await.unwrap();
     assert!(results.is_empty());
@@ -99,7 +99,7 @@ async fn vs_search_empty_store_returns_empty() {
 #[tokio::test]
 async fn vs_enforce_retention_returns_count() {
     let s = storage();
-    let store = SqliteVectorStore::new(s.connection_arc());
+    let store = SqliteVectorStore::new(s.connection_pool());
 
     let deleted = store.enforce_retention(30).await.unwrap();
     assert_eq!(deleted, 0);
@@ -108,7 +108,7 @@ async fn vs_enforce_retention_returns_count() {
 #[tokio::test]
 async fn vs_store_quantized_empty_int8_rejected() {
     let s = storage();
-    let store = SqliteVectorStore::new(s.connection_arc());
+    let store = SqliteVectorStore::new(s.connection_pool());
 
     let empty_qv = QuantizedVector {
         data: vec![],
@@ -130,7 +130,7 @@ async fn vs_store_quantized_empty_int8_rejected() {
 #[tokio::test]
 async fn vs_store_quantized_dimension_mismatch_rejected() {
     let s = storage();
-    let store = SqliteVectorStore::new(s.connection_arc());
+    let store = SqliteVectorStore::new(s.connection_pool());
 
     let qv = ScalarQuantizer::quantize(&[0.1, 0.2, 0.3, 0.4, 0.5]).unwrap();
     let result = store
@@ -268,7 +268,7 @@ async fn ms_cleanup_old_metrics_returns_count() {
 #[tokio::test]
 async fn vi_build_ivf_index_on_empty_store_returns_error() {
     let s = storage();
-    let index = SqliteVectorIndex::new(s.connection_arc());
+    let index = SqliteVectorIndex::new(s.connection_pool());
 
     let result = index.build_ivf_index(4, 10).await;
     assert!(result.is_err(), "empty store should reject IVF build");
@@ -277,7 +277,7 @@ async fn vi_build_ivf_index_on_empty_store_returns_error() {
 #[tokio::test]
 async fn vi_count_unindexed_fresh_db() {
     let s = storage();
-    let index = SqliteVectorIndex::new(s.connection_arc());
+    let index = SqliteVectorIndex::new(s.connection_pool());
 
     let count = index.count_unindexed().await.unwrap();
     assert_eq!(count, 0);
@@ -286,7 +286,7 @@ async fn vi_count_unindexed_fresh_db() {
 #[tokio::test]
 async fn vi_get_index_meta_fresh_db() {
     let s = storage();
-    let index = SqliteVectorIndex::new(s.connection_arc());
+    let index = SqliteVectorIndex::new(s.connection_pool());
 
     let meta = index.get_index_meta().await.unwrap();
     assert!(meta.ivf_built_at.is_none());
@@ -8,9 +8,9 @@ use super::SqliteStorage;
 impl PresetStorage for SqliteStorage {
     /// List all custom presets from the `automation_presets` table.
     fn list_presets(&self) -> Result<Vec<WorkflowPreset>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         let mut stmt = conn
@@ -19,7 +19,7 @@ impl PresetStorage for SqliteStorage {
                  FROM automation_presets
                  ORDER BY name",
             )
-            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("prepare: {e}") })?;
+            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("prepare: {e}") })?;
 
         let rows = stmt
             .query_map([], |row| {
@@ -35,14 +35,14 @@ impl PresetStorage for SqliteStorage {
                 })
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("query: {e}"),
             })?;
 
         let mut result = Vec::new();
         for row in rows {
             let row = row.map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("row: {e}"),
             })?;
             result.push(row.into_preset()?);
@@ -52,9 +52,9 @@ impl PresetStorage for SqliteStorage {
 
     /// Get a single preset by ID.
     fn get_preset(&self, id: &str) -> Result<Option<WorkflowPreset>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         let result = conn.query_row(
@@ -80,7 +80,7 @@ impl PresetStorage for SqliteStorage {
             Ok(row) => Ok(Some(row.into_preset()?)),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("query: {e}"),
             }),
         }
@@ -89,18 +89,18 @@ impl PresetStorage for SqliteStorage {
     /// Insert or replace a preset. Sets `updated_at` to now; sets `created_at`
     /// only for new rows.
     fn save_preset(&self, preset: &WorkflowPreset) -> Result<(), CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         let steps_json = serde_json::to_string(&preset.steps).map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Serialization,
             message: format!("serialize steps: {e}"),
         })?;
         let category_str =
             serde_json::to_string(&preset.category).map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Serialization,
                 message: format!("serialize category: {e}"),
             })?;
         let now = Utc::now().to_rfc3339();
@@ -131,7 +131,7 @@ impl PresetStorage for SqliteStorage {
                 now,
             ],
         )
-        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("upsert: {e}") })?;
+        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("upsert: {e}") })?;
 
         Ok(())
     }
@@ -139,9 +139,9 @@ impl PresetStorage for SqliteStorage {
     /// Delete a preset by ID. Built-in presets (builtin=1) are protected and
     /// will not be deleted. Returns true if a row was actually removed.
     fn delete_preset(&self, id: &str) -> Result<bool, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("lock: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Database,
+            message: format!("connection: {e}"),
         })?;
 
         let affected = conn
@@ -150,7 +150,7 @@ impl PresetStorage for SqliteStorage {
                 [id],
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("delete: {e}"),
             })?;
 
@@ -174,7 +174,7 @@ impl PresetRow {
     fn into_preset(self) -> Result<WorkflowPreset, CoreError> {
         let steps: Vec<WorkflowStep> =
             serde_json::from_str(&self.steps_json).map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Serialization,
                 message: format!("deserialize steps: {e}"),
             })?;
         let category: PresetCategory =
@@ -0,0 +1,487 @@
+//! Read-only query facade for plugins and user analyses.
+//!
+//! Callers describe what they want with small filter builders and get typed
+//! rows back. The SQL behind each builder is fixed and every value is bound
+//! as a parameter, so the schema can change without breaking callers and no
+//! caller-supplied text ever reaches the SQL string.
+
+use chrono::{DateTime, Utc};
+use oneshim_core::models::event::Event;
+use oneshim_core::types::TimeWindow;
+use rusqlite::types::Value;
+use tracing::warn;
+
+use super::text_compression::read_text;
+use super::{FrameRecord, SqliteStorage};
+use crate::error::StorageError;
+
+/// Row limit applied when a query does not set one.
+pub const DEFAULT_QUERY_LIMIT: usize = 1_000;
+
+/// Upper bound on any query limit.
+pub const MAX_QUERY_LIMIT: usize = 10_000;
+
+/// Smallest metrics bucket; raw samples arrive every few seconds.
+pub const MIN_METRICS_BUCKET_SECS: u32 = 60;
+
+/// Filter for [`SqliteStorage::query_events`].
+#[derive(Debug, Clone)]
+pub struct EventQuery {
+    event_type: Option<String>,
+    app_name: Option<String>,
+    window: Option<TimeWindow>,
+    limit: usize,
+}
+
+impl Default for EventQuery {
+    fn default() -> Self {
+        Self {
+            event_type: None,
+            app_name: None,
+            window: None,
+            limit: DEFAULT_QUERY_LIMIT,
+        }
+    }
+}
+
+impl EventQuery {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stored event type, e.g. `context_change` or `input_activity`.
+    pub fn with_event_type(mut self, event_type: impl Into<String>) -> Self {
+        self.event_type = Some(event_type.into());
+        self
+    }
+
+    /// Events recorded for this app. Event kinds without an app name
+    /// (process snapshots, system events) never match.
+    pub fn with_app(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = Some(app_name.into());
+        self
+    }
+
+    pub fn with_window(mut self, window: TimeWindow) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Clamped to [`MAX_QUERY_LIMIT`].
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.min(MAX_QUERY_LIMIT);
+        self
+    }
+}
+
+/// An event as stored, newest first in query results.
+#[derive(Debug, Clone)]
+pub struct StoredEvent {
+    pub event_id: String,
+    pub event_type: String,
+    pub timestamp: DateTime<Utc>,
+    pub event: Event,
+}
+
+/// Filter for [`SqliteStorage::query_frames_by_tag`].
+#[derive(Debug, Clone)]
+pub struct FrameTagQuery {
+    tag_name: String,
+    window: Option<TimeWindow>,
+    limit: usize,
+}
+
+impl FrameTagQuery {
+    pub fn new(tag_name: impl Into<String>) -> Self {
+        Self {
+            tag_name: tag_name.into(),
+            window: None,
+            limit: DEFAULT_QUERY_LIMIT,
+        }
+    }
+
+    pub fn with_window(mut self, window: TimeWindow) -> Self {
+        self.window = Some(window);
+        self
+    }
+
+    /// Clamped to [`MAX_QUERY_LIMIT`].
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit.min(MAX_QUERY_LIMIT);
+        self
+    }
+}
+
+/// Filter for [`SqliteStorage::query_metrics_downsampled`].
+#[derive(Debug, Clone)]
+pub struct MetricsQuery {
+    window: TimeWindow,
+    bucket_secs: u32,
+}
+
+impl MetricsQuery {
+    /// Five-minute buckets over `window`.
+    pub fn new(window: TimeWindow) -> Self {
+        Self {
+            window,
+            bucket_secs: 300,
+        }
+    }
+
+    /// Raised to [`MIN_METRICS_BUCKET_SECS`] when smaller.
+    pub fn with_bucket_secs(mut self, bucket_secs: u32) -> Self {
+        self.bucket_secs = bucket_secs.max(MIN_METRICS_BUCKET_SECS);
+        self
+    }
+}
+
+/// System metrics averaged over one bucket.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricsBucket {
+    pub start: DateTime<Utc>,
+    pub samples: u64,
+    pub cpu_avg: f32,
+    pub cpu_max: f32,
+    pub memory_used_avg: u64,
+    pub memory_used_max: u64,
+}
+
+fn query_error(e: rusqlite::Error) -> StorageError {
+    StorageError::Database(format!("query facade failure: {e}"))
+}
+
+impl SqliteStorage {
+    /// Events matching `query`, newest first.
+    pub async fn query_events(&self, query: &EventQuery) -> Result<Vec<StoredEvent>, StorageError> {
+        let mut sql =
+            String::from("SELECT event_id, event_type, timestamp, data FROM events WHERE 1 = 1");
+        let mut params: Vec<Value> = Vec::new();
+        if let Some(event_type) = &query.event_type {
+            sql.push_str(" AND event_type = ?");
+            params.push(Value::Text(event_type.clone()));
+        }
+        if let Some(app_name) = &query.app_name {
+            sql.push_str(" AND json_extract(data, '$.app_name') = ?");
+            params.push(Value::Text(app_name.clone()));
+        }
+        if let Some(window) = &query.window {
+            sql.push_str(" AND timestamp >= ? AND timestamp <= ?");
+            params.push(Value::Text(window.start.to_rfc3339()));
+            params.push(Value::Text(window.end.to_rfc3339()));
+        }
+        sql.push_str(" ORDER BY timestamp DESC LIMIT ?");
+        params.push(Value::Integer(query.limit as i64));
+
+        self.read_only_query(move |conn| {
+            let mut stmt = conn.prepare(&sql).map_err(query_error)?;
+            let rows = stmt
+                .query_map(rusqlite::params_from_iter(params), |row| {
+                    Ok((
+                        row.get::<_, String>(0)?,
+                        row.get::<_, String>(1)?,
+                        row.get::<_, String>(2)?,
+                        row.get::<_, String>(3)?,
+                    ))
+                })
+                .map_err(query_error)?;
+
+            let mut events = Vec::new();
+            for row in rows {
+                let (event_id, event_type, timestamp, data) = row.map_err(query_error)?;
+                let parsed = DateTime::parse_from_rfc3339(&timestamp)
+                    .map(|ts| ts.with_timezone(&Utc))
+                    .map_err(|e| e.to_string())
+                    .and_then(|ts| {
+                        serde_json::from_str::<Event>(&data)
+                            .map(|event| (ts, event))
+                            .map_err(|e| e.to_string())
+                    });
+                match parsed {
+                    Ok((timestamp, event)) => events.push(StoredEvent {
+                        event_id,
+                        event_type,
+                        timestamp,
+                        event,
+                    }),
+                    Err(e) => warn!(event_id, "query facade: skipping unreadable event: {e}"),
+                }
+            }
+            Ok(events)
+        })
+        .await
+    }
+
+    /// Frames carrying the tag named in `query`, newest first.
+    pub async fn query_frames_by_tag(
+        &self,
+        query: &FrameTagQuery,
+    ) -> Result<Vec<FrameRecord>, StorageError> {
+        let mut sql = String::from(
+            "SELECT f.id, f.timestamp, f.trigger_type, f.app_name, f.window_title,
+                    f.importance, f.resolution_w, f.resolution_h, f.file_path, f.ocr_text
+             FROM frames f
+             INNER JOIN frame_tags ft ON f.id = ft.frame_id
+             INNER JOIN tags t ON t.id = ft.tag_id
+             WHERE t.name = ? AND f.deleted_at IS NULL",
+        );
+        let mut params = vec![Value::Text(query.tag_name.clone())];
+        if let Some(window) = &query.window {
+            sql.push_str(" AND f.timestamp >= ? AND f.timestamp <= ?");
+            params.push(Value::Text(window.start.to_rfc3339()));
+            params.push(Value::Text(window.end.to_rfc3339()));
+        }
+        sql.push_str(" ORDER BY f.timestamp DESC LIMIT ?");
+        params.push(Value::Integer(query.limit as i64));
+
+        self.read_only_query(move |conn| {
+            let mut stmt = conn.prepare(&sql).map_err(query_error)?;
+            let frames = stmt
+                .query_map(rusqlite::params_from_iter(params), |row| {
+                    Ok(FrameRecord {
+                        id: row.get(0)?,
+                        timestamp: row.get(1)?,
+                        trigger_type: row.get(2)?,
+                        app_name: row.get(3)?,
+                        window_title: row.get(4)?,
+                        importance: row.get(5)?,
+                        resolution_w: row.get(6)?,
+                        resolution_h: row.get(7)?,
+                        file_path: row.get(8)?,
+                        ocr_text: read_text(row, 9)?,
+                    })
+                })
+                .map_err(query_error)?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(query_error)?;
+            Ok(frames)
+        })
+        .await
+    }
+
+    /// System metrics in `query.window` averaged per bucket, oldest first.
+    /// Buckets without samples are omitted.
+    pub async fn query_metrics_downsampled(
+        &self,
+        query: &MetricsQuery,
+    ) -> Result<Vec<MetricsBucket>, StorageError> {
+        let bucket = i64::from(query.bucket_secs);
+        let from = query.window.start.to_rfc3339();
+        let to = query.window.end.to_rfc3339();
+
+        self.read_only_query(move |conn| {
+            let mut stmt = conn
+                .prepare(
+                    "SELECT (CAST(strftime('%s', timestamp) AS INTEGER) / ?1) * ?1 AS bucket,
+                            COUNT(*), AVG(cpu_usage), MAX(cpu_usage),
+                            AVG(memory_used), MAX(memory_used)
+                     FROM system_metrics
+                     WHERE timestamp >= ?2 AND timestamp <= ?3
+                     GROUP BY bucket
+                     ORDER BY bucket ASC
+                     LIMIT ?4",
+                )
+                .map_err(query_error)?;
+            let buckets = stmt
+                .query_map(
+                    rusqlite::params![bucket, from, to, MAX_QUERY_LIMIT as i64],
+                    |row| {
+                        let start: i64 = row.get(0)?;
+                        let cpu_avg: f64 = row.get(2)?;
+                        let cpu_max: f64 = row.get(3)?;
+                        let memory_avg: f64 = row.get(4)?;
+                        let memory_max: i64 = row.get(5)?;
+                        Ok(MetricsBucket {
+                            start: DateTime::from_timestamp(start, 0).unwrap_or_default(),
+                            samples: row.get::<_, i64>(1)? as u64,
+                            cpu_avg: cpu_avg as f32,
+                            cpu_max: cpu_max as f32,
+                            memory_used_avg: memory_avg.round() as u64,
+                            memory_used_max: memory_max as u64,
+                        })
+                    },
+                )
+                .map_err(query_error)?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(query_error)?;
+            Ok(buckets)
+        })
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+    use oneshim_core::models::event::ContextEvent;
+    use oneshim_core::models::frame::FrameMetadata;
+    use oneshim_core::models::system::SystemMetrics;
+    use oneshim_core::ports::storage::{MetricsStorage, StorageService};
+
+    fn at(hour: u32, minute: u32) -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2026, 3, 18, hour, minute, 0).unwrap()
+    }
+
+    fn day() -> TimeWindow {
+        TimeWindow::new(at(0, 0), at(23, 59)).unwrap()
+    }
+
+    fn context(app: &str, title: &str, timestamp: DateTime<Utc>) -> Event {
+        Event::Context(ContextEvent {
+            app_name: app.to_string(),
+            window_title: title.to_string(),
+            timestamp,
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn event_query_filters_by_type_app_and_window() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        storage
+            .save_event(&context("Code", "a.rs", at(9, 0)))
+            .await
+            .unwrap();
+        storage
+            .save_event(&context("Code", "b.rs", at(10, 0)))
+            .await
+            .unwrap();
+        storage
+            .save_event(&context("Slack", "general", at(10, 30)))
+            .await
+            .unwrap();
+        storage
+            .save_event(&context(
+                "Code",
+                "old.rs",
+                at(0, 0) - chrono::Duration::days(1),
+            ))
+            .await
+            .unwrap();
+        storage
+            .save_event(&super::super::test_utils::make_user_event())
+            .await
+            .unwrap();
+
+        let code_today = storage
+            .query_events(
+                &EventQuery::new()
+                    .with_event_type("context_change")
+                    .with_app("Code")
+                    .with_window(day()),
+            )
+            .await
+            .unwrap();
+        assert_eq!(code_today.len(), 2);
+        assert_eq!(code_today[0].timestamp, at(10, 0));
+        assert!(matches!(&code_today[0].event, Event::Context(c) if c.window_title == "b.rs"));
+
+        let contexts = storage
+            .query_events(
+                &EventQuery::new()
+                    .with_event_type("context_change")
+                    .with_limit(3),
+            )
+            .await
+            .unwrap();
+        assert_eq!(contexts.len(), 3);
+
+        let injected = storage
+            .query_events(&EventQuery::new().with_app("Code' OR 1=1 --"))
+            .await
+            .unwrap();
+        assert!(injected.is_empty());
+    }
+
+    #[tokio::test]
+    async fn frame_tag_query_returns_only_tagged_frames() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let frame = |app: &str, timestamp| FrameMetadata {
+            timestamp,
+            trigger_type: "AppSwitch".to_string(),
+            app_name: app.to_string(),
+            window_title: "w".to_string(),
+            resolution: (1920, 1080),
+            importance: 0.6,
+        };
+        let tagged_a = storage
+            .save_frame_metadata(&frame("Code", at(9, 0)), None, None)
+            .unwrap();
+        let tagged_b = storage
+            .save_frame_metadata(&frame("Figma", at(11, 0)), None, None)
+            .unwrap();
+        storage
+            .save_frame_metadata(&frame("Slack", at(10, 0)), None, None)
+            .unwrap();
+        let review = storage.create_tag("review", "#ff0000").unwrap();
+        storage.add_tag_to_frame(tagged_a, review.id).unwrap();
+        storage.add_tag_to_frame(tagged_b, review.id).unwrap();
+
+        let frames = storage
+            .query_frames_by_tag(&FrameTagQuery::new("review"))
+            .await
+            .unwrap();
+        let ids: Vec<i64> = frames.iter().map(|f| f.id).collect();
+        assert_eq!(ids, vec![tagged_b, tagged_a]);
+
+        let morning = TimeWindow::new(at(8, 0), at(10, 0)).unwrap();
+        let frames = storage
+            .query_frames_by_tag(&FrameTagQuery::new("review").with_window(morning))
+            .await
+            .unwrap();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].app_name, "Code");
+
+        assert!(storage
+            .query_frames_by_tag(&FrameTagQuery::new("missing"))
+            .await
+            .unwrap()
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn metrics_query_downsamples_into_buckets() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let sample = |timestamp, cpu_usage, memory_used| SystemMetrics {
+            timestamp,
+            cpu_usage,
+            memory_used,
+            memory_total: 16_000,
+            disk_used: 0,
+            disk_total: 0,
+            network: None,
+            typing_wpm: 0.0,
+        };
+        storage
+            .save_metrics(&sample(at(9, 0), 10.0, 1_000))
+            .await
+            .unwrap();
+        storage
+            .save_metrics(&sample(at(9, 3), 30.0, 3_000))
+            .await
+            .unwrap();
+        storage
+            .save_metrics(&sample(at(9, 7), 50.0, 5_000))
+            .await
+            .unwrap();
+
+        let buckets = storage
+            .query_metrics_downsampled(&MetricsQuery::new(day()).with_bucket_secs(300))
+            .await
+            .unwrap();
+        assert_eq!(buckets.len(), 2);
+        assert_eq!(buckets[0].start, at(9, 0));
+        assert_eq!(buckets[0].samples, 2);
+        assert!((buckets[0].cpu_avg - 20.0).abs() < f32::EPSILON);
+        assert!((buckets[0].cpu_max - 30.0).abs() < f32::EPSILON);
+        assert_eq!(buckets[0].memory_used_avg, 2_000);
+        assert_eq!(buckets[0].memory_used_max, 3_000);
+        assert_eq!(buckets[1].start, at(9, 5));
+
+        let hourly = storage
+            .query_metrics_downsampled(&MetricsQuery::new(day()).with_bucket_secs(1))
+            .await
+            .unwrap();
+        assert_eq!(hourly.len(), 3, "bucket clamped to the 60s minimum");
+    }
+}
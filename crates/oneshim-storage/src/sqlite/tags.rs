@@ -2,7 +2,24 @@ use crate::error::StorageError;
 use std::collections::HashMap;
 use tracing::debug;
 
-use super::{FrameRecord, SqliteStorage, TagRecord};
+use super::text_compression::read_text;
+use super::{FrameRecord, SqliteStorage, TagRecord, TagRuleRecord};
+
+/// Give `frame_id` the tag of every rule whose pattern occurs in `app_name`,
+/// ignoring ASCII case. Returns how many tags were newly applied.
+pub(super) fn apply_tag_rules_with(
+    conn: &rusqlite::Connection,
+    frame_id: i64,
+    app_name: &str,
+) -> rusqlite::Result<usize> {
+    conn.prepare_cached(
+        "INSERT OR IGNORE INTO frame_tags (frame_id, tag_id, source)
+         SELECT DISTINCT ?1, r.tag_id, 'auto' FROM tag_rules r
+         JOIN tags t ON t.id = r.tag_id
+         WHERE instr(lower(?2), lower(r.app_pattern)) > 0",
+    )?
+    .execute(rusqlite::params![frame_id, app_name])
+}
 
 impl SqliteStorage {
     pub fn get_tag_ids_for_frames(
@@ -14,9 +31,9 @@ impl SqliteStorage {
         }
 
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let placeholders: Vec<String> = frame_ids.iter().map(|_| "?".to_string()).collect();
         let sql = format!(
@@ -26,7 +43,7 @@ impl SqliteStorage {
 
         let mut stmt = conn
             .prepare(&sql)
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let params: Vec<Box<dyn rusqlite::types::ToSql>> = frame_ids
             .iter()
@@ -39,12 +56,12 @@ impl SqliteStorage {
             .query_map(param_refs.as_slice(), |row| {
                 Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?))
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?;
 
         let mut map: HashMap<i64, Vec<i64>> = HashMap::new();
         for row in rows {
             let (frame_id, tag_id) =
-                row.map_err(|e| StorageError::Internal(format!("Failed to read row: {e}")))?;
+                row.map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?;
             map.entry(frame_id).or_default().push(tag_id);
         }
 
@@ -54,15 +71,15 @@ impl SqliteStorage {
     /// # Arguments
     pub fn create_tag(&self, name: &str, color: &str) -> Result<TagRecord, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT INTO tags (name, color) VALUES (?1, ?2)",
             rusqlite::params![name, color],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to create tag: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to create tag: {e}")))?;
 
         let tag_id = conn.last_insert_rowid();
         let created_at: String = conn
@@ -71,7 +88,7 @@ impl SqliteStorage {
                 rusqlite::params![tag_id],
                 |row| row.get(0),
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to query tag: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to query tag: {e}")))?;
 
         debug!("create: id={}, name={}", tag_id, name);
 
@@ -80,18 +97,19 @@ impl SqliteStorage {
             name: name.to_string(),
             color: color.to_string(),
             created_at,
+            auto_applied: false,
         })
     }
 
     pub fn get_all_tags(&self) -> Result<Vec<TagRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare("SELECT id, name, color, created_at FROM tags ORDER BY name")
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let tags = stmt
             .query_map([], |row| {
@@ -100,9 +118,10 @@ impl SqliteStorage {
                     name: row.get(1)?,
                     color: row.get(2)?,
                     created_at: row.get(3)?,
+                    auto_applied: false,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -111,9 +130,9 @@ impl SqliteStorage {
 
     pub fn get_tag(&self, tag_id: i64) -> Result<Option<TagRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let result = conn.query_row(
             "SELECT id, name, color, created_at FROM tags WHERE id = ?1",
@@ -124,6 +143,7 @@ impl SqliteStorage {
                     name: row.get(1)?,
                     color: row.get(2)?,
                     created_at: row.get(3)?,
+                    auto_applied: false,
                 })
             },
         );
@@ -131,52 +151,214 @@ impl SqliteStorage {
         match result {
             Ok(tag) => Ok(Some(tag)),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
-            Err(e) => Err(StorageError::Internal(format!("Failed to query tag: {e}"))),
+            Err(e) => Err(StorageError::Database(format!("Failed to query tag: {e}"))),
         }
     }
 
     pub fn delete_tag(&self, tag_id: i64) -> Result<bool, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
+        // Foreign keys are not enforced, so rules are not cascaded.
+        conn.execute(
+            "DELETE FROM tag_rules WHERE tag_id = ?1",
+            rusqlite::params![tag_id],
+        )
+        .map_err(|e| StorageError::Database(format!("Failed to delete tag rules: {e}")))?;
         let deleted = conn
             .execute("DELETE FROM tags WHERE id = ?1", rusqlite::params![tag_id])
-            .map_err(|e| StorageError::Internal(format!("Failed to delete tag: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to delete tag: {e}")))?;
 
         debug!("delete: id={}, affected={}", tag_id, deleted);
         Ok(deleted > 0)
     }
 
+    pub fn get_tag_rules(&self) -> Result<Vec<TagRuleRecord>, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let mut stmt = conn
+            .prepare("SELECT id, app_pattern, tag_id FROM tag_rules ORDER BY id")
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+
+        let rules = stmt
+            .query_map([], |row| {
+                Ok(TagRuleRecord {
+                    id: row.get(0)?,
+                    app_pattern: row.get(1)?,
+                    tag_id: row.get(2)?,
+                })
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?;
+
+        Ok(rules)
+    }
+
+    /// An empty pattern would tag every frame, so it is rejected, as is a
+    /// tag that does not exist.
+    pub fn create_tag_rule(
+        &self,
+        app_pattern: &str,
+        tag_id: i64,
+    ) -> Result<TagRuleRecord, StorageError> {
+        let app_pattern = validate_tag_rule_pattern(app_pattern)?;
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let inserted = conn
+            .execute(
+                "INSERT INTO tag_rules (app_pattern, tag_id)
+                 SELECT ?1, id FROM tags WHERE id = ?2",
+                rusqlite::params![app_pattern, tag_id],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to create tag rule: {e}")))?;
+        if inserted == 0 {
+            return Err(unknown_tag(tag_id));
+        }
+
+        let id = conn.last_insert_rowid();
+        debug!("tag rule create: id={}, pattern={}", id, app_pattern);
+        Ok(TagRuleRecord {
+            id,
+            app_pattern: app_pattern.to_string(),
+            tag_id,
+        })
+    }
+
+    /// Returns `false` if the rule does not exist.
+    pub fn update_tag_rule(
+        &self,
+        rule_id: i64,
+        app_pattern: &str,
+        tag_id: i64,
+    ) -> Result<bool, StorageError> {
+        let app_pattern = validate_tag_rule_pattern(app_pattern)?;
+        if self.get_tag(tag_id)?.is_none() {
+            return Err(unknown_tag(tag_id));
+        }
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let updated = conn
+            .execute(
+                "UPDATE tag_rules SET app_pattern = ?2, tag_id = ?3 WHERE id = ?1",
+                rusqlite::params![rule_id, app_pattern, tag_id],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to update tag rule: {e}")))?;
+        Ok(updated > 0)
+    }
+
+    pub fn delete_tag_rule(&self, rule_id: i64) -> Result<bool, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let deleted = conn
+            .execute(
+                "DELETE FROM tag_rules WHERE id = ?1",
+                rusqlite::params![rule_id],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to delete tag rule: {e}")))?;
+        Ok(deleted > 0)
+    }
+
+    /// Apply the tag rules to a saved frame. Already-attached tags are left
+    /// as they are. Returns how many tags were newly applied.
+    pub fn apply_tag_rules(&self, frame_id: i64, app_name: &str) -> Result<usize, StorageError> {
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        apply_tag_rules_with(&conn, frame_id, app_name)
+            .map_err(|e| StorageError::Database(format!("Failed to apply tag rules: {e}")))
+    }
+
     pub fn add_tag_to_frame(&self, frame_id: i64, tag_id: i64) -> Result<(), StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         conn.execute(
             "INSERT OR IGNORE INTO frame_tags (frame_id, tag_id) VALUES (?1, ?2)",
             rusqlite::params![frame_id, tag_id],
         )
-        .map_err(|e| StorageError::Internal(format!("Failed to add frame tag: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("Failed to add frame tag: {e}")))?;
 
         debug!("frame add: frame_id={}, tag_id={}", frame_id, tag_id);
         Ok(())
     }
 
+    /// Apply `tag_names` to a frame as auto-applied tags, creating missing
+    /// tags with the default color. A tag the user already put on the frame
+    /// stays manual. Returns how many tags were newly applied.
+    pub fn auto_tag_frame(
+        &self,
+        frame_id: i64,
+        tag_names: &[String],
+    ) -> Result<usize, StorageError> {
+        if tag_names.is_empty() {
+            return Ok(0);
+        }
+
+        let mut conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+        let tx = conn
+            .transaction()
+            .map_err(|e| StorageError::Database(format!("Failed to begin transaction: {e}")))?;
+
+        let mut applied = 0;
+        for name in tag_names {
+            tx.execute(
+                "INSERT OR IGNORE INTO tags (name) VALUES (?1)",
+                rusqlite::params![name],
+            )
+            .map_err(|e| StorageError::Database(format!("Failed to create tag: {e}")))?;
+            applied += tx
+                .execute(
+                    "INSERT OR IGNORE INTO frame_tags (frame_id, tag_id, source)
+                     SELECT ?1, id, 'auto' FROM tags WHERE name = ?2",
+                    rusqlite::params![frame_id, name],
+                )
+                .map_err(|e| StorageError::Database(format!("Failed to add frame tag: {e}")))?;
+        }
+
+        tx.commit()
+            .map_err(|e| StorageError::Database(format!("Failed to commit transaction: {e}")))?;
+
+        debug!(
+            "frame auto-tagged: frame_id={}, applied={}",
+            frame_id, applied
+        );
+        Ok(applied)
+    }
+
     pub fn remove_tag_from_frame(&self, frame_id: i64, tag_id: i64) -> Result<bool, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let deleted = conn
             .execute(
                 "DELETE FROM frame_tags WHERE frame_id = ?1 AND tag_id = ?2",
                 rusqlite::params![frame_id, tag_id],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to remove frame tag: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to remove frame tag: {e}")))?;
 
         debug!(
             "frame tag removed: frame_id={}, tag_id={}, affected={}",
@@ -187,19 +369,19 @@ impl SqliteStorage {
 
     pub fn get_tags_for_frame(&self, frame_id: i64) -> Result<Vec<TagRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
-                "SELECT t.id, t.name, t.color, t.created_at
+                "SELECT t.id, t.name, t.color, t.created_at, ft.source = 'auto'
                  FROM tags t
                  INNER JOIN frame_tags ft ON t.id = ft.tag_id
                  WHERE ft.frame_id = ?1
                  ORDER BY t.name",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let tags = stmt
             .query_map(rusqlite::params![frame_id], |row| {
@@ -208,9 +390,10 @@ impl SqliteStorage {
                     name: row.get(1)?,
                     color: row.get(2)?,
                     created_at: row.get(3)?,
+                    auto_applied: row.get(4)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -223,9 +406,9 @@ impl SqliteStorage {
         limit: usize,
     ) -> Result<Vec<FrameRecord>, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let mut stmt = conn
             .prepare(
@@ -233,11 +416,11 @@ impl SqliteStorage {
                         f.importance, f.resolution_w, f.resolution_h, f.file_path, f.ocr_text
                  FROM frames f
                  INNER JOIN frame_tags ft ON f.id = ft.frame_id
-                 WHERE ft.tag_id = ?1
+                 WHERE ft.tag_id = ?1 AND f.deleted_at IS NULL
                  ORDER BY f.timestamp DESC
                  LIMIT ?2",
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to prepare query: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
 
         let frames = stmt
             .query_map(rusqlite::params![tag_id, limit as i64], |row| {
@@ -251,10 +434,10 @@ impl SqliteStorage {
                     resolution_w: row.get(6)?,
                     resolution_h: row.get(7)?,
                     file_path: row.get(8)?,
-                    ocr_text: row.get(9)?,
+                    ocr_text: read_text(row, 9)?,
                 })
             })
-            .map_err(|e| StorageError::Internal(format!("Failed to execute query: {e}")))?
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
             .filter_map(|r| r.ok())
             .collect();
 
@@ -263,20 +446,78 @@ impl SqliteStorage {
 
     pub fn update_tag(&self, tag_id: i64, name: &str, color: &str) -> Result<bool, StorageError> {
         let conn = self
-            .conn
-            .lock()
-            .map_err(|e| StorageError::Internal(format!("Failed to acquire lock: {e}")))?;
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
 
         let updated = conn
             .execute(
                 "UPDATE tags SET name = ?1, color = ?2 WHERE id = ?3",
                 rusqlite::params![name, color, tag_id],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to update tag: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to update tag: {e}")))?;
 
         debug!("update: id={}, affected={}", tag_id, updated);
         Ok(updated > 0)
     }
+
+    /// Stored file paths of frames tagged with any of `tag_names`
+    /// (case-insensitive). Used to exempt those frames from retention.
+    pub fn get_frame_paths_with_tags(
+        &self,
+        tag_names: &[String],
+    ) -> Result<Vec<String>, StorageError> {
+        if tag_names.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conn = self
+            .pool
+            .get()
+            .map_err(|e| StorageError::Lock(format!("Failed to acquire connection: {e}")))?;
+
+        let placeholders: Vec<String> = tag_names.iter().map(|_| "?".to_string()).collect();
+        let sql = format!(
+            "SELECT DISTINCT f.file_path FROM frames f \
+             JOIN frame_tags ft ON ft.frame_id = f.id \
+             JOIN tags t ON t.id = ft.tag_id \
+             WHERE f.file_path IS NOT NULL AND lower(t.name) IN ({})",
+            placeholders.join(",")
+        );
+
+        let mut stmt = conn
+            .prepare(&sql)
+            .map_err(|e| StorageError::Database(format!("Failed to prepare query: {e}")))?;
+
+        let names: Vec<String> = tag_names.iter().map(|n| n.to_lowercase()).collect();
+        let paths = stmt
+            .query_map(rusqlite::params_from_iter(names.iter()), |row| {
+                row.get::<_, String>(0)
+            })
+            .map_err(|e| StorageError::Database(format!("Failed to execute query: {e}")))?
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| StorageError::Database(format!("Failed to read row: {e}")))?;
+
+        Ok(paths)
+    }
+}
+
+fn validate_tag_rule_pattern(app_pattern: &str) -> Result<&str, StorageError> {
+    let trimmed = app_pattern.trim();
+    if trimmed.is_empty() {
+        return Err(StorageError::Validation {
+            field: "app_pattern".to_string(),
+            message: "must not be empty".to_string(),
+        });
+    }
+    Ok(trimmed)
+}
+
+fn unknown_tag(tag_id: i64) -> StorageError {
+    StorageError::Validation {
+        field: "tag_id".to_string(),
+        message: format!("tag {tag_id} does not exist"),
+    }
 }
 
 #[cfg(test)]
@@ -320,7 +561,7 @@ mod tests {
 
         // Seed 2 frames via direct SQL (test has no frame API handy).
         {
-            let conn = storage.conn.lock().unwrap();
+            let conn = storage.pool.get().unwrap();
             conn.execute(
                 "INSERT INTO frames (timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, has_image) \
                  VALUES ('2026-04-18T00:00:00Z', 'manual', 'a', 'a', 0.5, 1920, 1080, 0)",
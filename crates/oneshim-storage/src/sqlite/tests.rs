@@ -1,7 +1,7 @@
 use super::*;
 use chrono::{Duration, Utc};
 use oneshim_core::models::activity::{ProcessSnapshot, ProcessSnapshotEntry, SessionStats};
-use oneshim_core::models::event::{ContextEvent, Event};
+use oneshim_core::models::event::{ContextEvent, Event, QuarantinedEvent};
 use oneshim_core::models::system::{NetworkInfo, SystemMetrics};
 use oneshim_core::ports::storage::{MetricsStorage, StorageService};
 use std::sync::atomic::Ordering;
@@ -42,19 +42,112 @@ async fn pending_events() {
     assert_eq!(pending.len(), 2);
 }
 
+#[tokio::test]
+async fn idempotency_keys_persist_across_reopen() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("events.db");
+    let user = make_user_event();
+    let context = make_context_event();
+    let ids = vec![user.local_id(), context.local_id()];
+
+    let keys = {
+        let storage = SqliteStorage::open(&path, 30, None).unwrap();
+        storage.save_event(&user).await.unwrap();
+        storage
+            .save_events(std::slice::from_ref(&context))
+            .await
+            .unwrap();
+        // Re-saving an existing row must not issue it a new key.
+        storage.save_event(&user).await.unwrap();
+        storage.get_idempotency_keys(&ids).await.unwrap()
+    };
+    assert_eq!(keys.len(), 2);
+    assert_ne!(keys[&ids[0]], keys[&ids[1]]);
+
+    let reopened = SqliteStorage::open(&path, 30, None).unwrap();
+    assert_eq!(reopened.get_idempotency_keys(&ids).await.unwrap(), keys);
+}
+
 #[tokio::test]
 async fn enforce_retention() {
     let storage = SqliteStorage::open_in_memory(0).unwrap(); // 0-day retention triggers immediate cleanup
     storage.save_event(&make_user_event()).await.unwrap();
 
     {
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
         conn.execute("UPDATE events SET is_sent = 1", []).unwrap();
-    } // MutexGuard await
+    } // connection returned before await
     let deleted = storage.enforce_retention().await.unwrap();
     assert!(deleted >= 1);
 }
 
+#[tokio::test]
+async fn set_retention_days_applies_to_next_run() {
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+    storage.save_event(&make_user_event()).await.unwrap();
+    {
+        let conn = storage.pool.get().unwrap();
+        conn.execute("UPDATE events SET is_sent = 1", []).unwrap();
+    }
+    assert_eq!(storage.enforce_retention().await.unwrap(), 0);
+
+    storage.set_retention_days(0);
+    assert!(storage.enforce_retention().await.unwrap() >= 1);
+}
+
+#[tokio::test]
+async fn retention_overrides_apply_per_app_category() {
+    use oneshim_core::models::event::{UserEvent, UserEventType};
+    use oneshim_core::models::work_session::AppCategory;
+    use std::collections::HashMap;
+
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+    storage.set_retention_overrides(
+        HashMap::from([
+            (AppCategory::Communication, 7),
+            (AppCategory::Development, 90),
+        ]),
+        HashMap::new(),
+    );
+    let event = |app_name: &str, days_old: i64| {
+        Event::User(UserEvent {
+            event_id: uuid::Uuid::new_v4(),
+            event_type: UserEventType::WindowChange,
+            timestamp: Utc::now() - Duration::days(days_old),
+            app_name: app_name.to_string(),
+            window_title: format!("{app_name} {days_old}d"),
+        })
+    };
+    for (app_name, days_old) in [
+        ("Slack", 3),
+        ("Slack", 10),
+        ("Code", 40),
+        ("Code", 100),
+        ("Firefox", 20),
+        ("Firefox", 40),
+    ] {
+        storage
+            .save_event(&event(app_name, days_old))
+            .await
+            .unwrap();
+    }
+    {
+        let conn = storage.pool.get().unwrap();
+        conn.execute("UPDATE events SET is_sent = 1", []).unwrap();
+    }
+
+    assert_eq!(storage.enforce_retention().await.unwrap(), 3);
+    let conn = storage.pool.get().unwrap();
+    let remaining: Vec<String> = conn
+        .prepare("SELECT json_extract(data, '$.window_title') FROM events ORDER BY 1")
+        .unwrap()
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(remaining, ["Code 40d", "Firefox 20d", "Slack 3d"]);
+}
+
 #[tokio::test]
 async fn empty_storage() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
@@ -88,6 +181,70 @@ async fn mark_nonexistent_ids_no_error() {
     assert!(result.is_ok());
 }
 
+#[tokio::test]
+async fn quarantined_events_leave_backlog_with_reason() {
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+    let rejected = make_user_event();
+    storage.save_event(&rejected).await.unwrap();
+    storage.save_event(&make_user_event()).await.unwrap();
+
+    storage
+        .quarantine_events(&[QuarantinedEvent {
+            event_id: rejected.local_id(),
+            reason: "schema: missing field".to_string(),
+        }])
+        .await
+        .unwrap();
+
+    let pending = storage.get_pending_events(100).await.unwrap();
+    assert_eq!(pending.len(), 1);
+    assert_ne!(pending[0].local_id(), rejected.local_id());
+
+    let conn = storage.pool.get().unwrap();
+    let reason: Option<String> = conn
+        .query_row(
+            "SELECT quarantine_reason FROM events WHERE event_id = ?1",
+            [rejected.local_id()],
+            |row| row.get(0),
+        )
+        .unwrap();
+    assert_eq!(reason.as_deref(), Some("schema: missing field"));
+}
+
+#[tokio::test]
+async fn undecodable_pending_row_is_quarantined() {
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+    let corrupt = make_user_event();
+    let intact = make_user_event();
+    storage.save_event(&corrupt).await.unwrap();
+    storage.save_event(&intact).await.unwrap();
+    storage
+        .pool
+        .get()
+        .unwrap()
+        .execute(
+            "UPDATE events SET data = '{\"not\": \"an event\"}', \
+             timestamp = '2000-01-01T00:00:00+00:00' WHERE event_id = ?1",
+            [corrupt.local_id()],
+        )
+        .unwrap();
+
+    let pending = storage.get_pending_events(1).await.unwrap();
+    assert_eq!(pending.len(), 1, "the corrupt row must not fill the page");
+    assert_eq!(pending[0].local_id(), intact.local_id());
+
+    let conn = storage.pool.get().unwrap();
+    let (is_sent, reason): (bool, Option<String>) = conn
+        .query_row(
+            "SELECT is_sent, quarantine_reason FROM events WHERE event_id = ?1",
+            [corrupt.local_id()],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .unwrap();
+    assert!(is_sent);
+    assert!(reason.is_some_and(|reason| reason.starts_with("undecodable")));
+}
+
 #[tokio::test]
 async fn large_batch_insert() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
@@ -102,6 +259,30 @@ async fn large_batch_insert() {
     assert_eq!(events.len(), 100);
 }
 
+#[tokio::test]
+async fn batched_and_single_inserts_store_the_same_rows() {
+    let one_by_one = SqliteStorage::open_in_memory(30).unwrap();
+    let batched = SqliteStorage::open_in_memory(30).unwrap();
+    let events: Vec<Event> = (0..100).map(|_| make_user_event()).collect();
+
+    for event in &events {
+        one_by_one.save_event(event).await.unwrap();
+    }
+    batched.save_events(&events).await.unwrap();
+    batched.save_events(&[]).await.unwrap();
+
+    let from = Utc::now() - Duration::hours(1);
+    let to = Utc::now() + Duration::hours(1);
+    let single_rows = one_by_one.get_events(from, to, 200).await.unwrap();
+    let batch_rows = batched.get_events(from, to, 200).await.unwrap();
+    assert_eq!(single_rows.len(), 100);
+    assert_eq!(batch_rows.len(), single_rows.len());
+    assert_eq!(
+        batched.get_pending_events(200).await.unwrap().len(),
+        one_by_one.get_pending_events(200).await.unwrap().len()
+    );
+}
+
 #[tokio::test]
 async fn retention_does_not_delete_unsent() {
     let storage = SqliteStorage::open_in_memory(0).unwrap();
@@ -161,6 +342,40 @@ async fn concurrent_save_and_get() {
     assert_eq!(events.len(), 100);
 }
 
+#[tokio::test]
+async fn stalled_save_event_calls_do_not_starve_the_runtime() {
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("starve.db");
+    let storage = std::sync::Arc::new(SqliteStorage::open(&db_path, 30, None).unwrap());
+
+    // Hold the write lock from outside the pool so every save waits on
+    // busy_timeout. On this single-threaded runtime, a save blocking its
+    // worker would keep the sleep below from completing until then.
+    let blocker = rusqlite::Connection::open(&db_path).unwrap();
+    blocker.execute_batch("BEGIN IMMEDIATE").unwrap();
+
+    let saves: Vec<_> = (0..32)
+        .map(|_| {
+            let s = storage.clone();
+            tokio::spawn(async move { s.save_event(&make_user_event()).await })
+        })
+        .collect();
+
+    let started = std::time::Instant::now();
+    tokio::time::sleep(std::time::Duration::from_millis(20)).await;
+    assert!(
+        started.elapsed() < std::time::Duration::from_secs(1),
+        "sleep took {:?} while saves were stalled",
+        started.elapsed()
+    );
+
+    blocker.execute_batch("COMMIT").unwrap();
+    for save in saves {
+        save.await.unwrap().unwrap();
+    }
+    assert_eq!(storage.get_pending_events(100).await.unwrap().len(), 32);
+}
+
 fn make_system_metrics() -> SystemMetrics {
     SystemMetrics {
         timestamp: Utc::now(),
@@ -243,7 +458,7 @@ async fn idle_period_lifecycle() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
 
     let start = Utc::now();
-    let id = storage.start_idle_period(start).await.unwrap();
+    let id = storage.start_idle_period(start, 0).await.unwrap();
     assert!(id > 0);
 
     let ongoing = storage.get_ongoing_idle_period().await.unwrap();
@@ -363,7 +578,7 @@ fn frame_tag_operations() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
 
     {
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
         conn.execute(
             "INSERT INTO frames (timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, has_image)
              VALUES ('2024-01-01T00:00:00Z', 'manual', 'test', 'test', 0.5, 1920, 1080, 0)",
@@ -405,7 +620,7 @@ fn add_tag_to_frame_idempotent() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
 
     {
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
         conn.execute(
             "INSERT INTO frames (timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, has_image)
              VALUES ('2024-01-01T00:00:00Z', 'manual', 'test', 'test', 0.5, 1920, 1080, 0)",
@@ -423,6 +638,36 @@ fn add_tag_to_frame_idempotent() {
     assert_eq!(tags.len(), 1);
 }
 
+#[test]
+fn auto_tag_frame_marks_tags_auto_and_keeps_manual_ones() {
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+
+    {
+        let conn = storage.pool.get().unwrap();
+        conn.execute(
+            "INSERT INTO frames (timestamp, trigger_type, app_name, window_title, importance, resolution_w, resolution_h, has_image)
+             VALUES ('2024-01-01T00:00:00Z', 'manual', 'Figma', 'test', 0.5, 1920, 1080, 0)",
+            [],
+        )
+        .unwrap();
+    }
+
+    let manual = storage.create_tag("client", "#000000").unwrap();
+    storage.add_tag_to_frame(1, manual.id).unwrap();
+
+    let applied = storage
+        .auto_tag_frame(1, &["design".to_string(), "client".to_string()])
+        .unwrap();
+    assert_eq!(applied, 1);
+
+    let tags = storage.get_tags_for_frame(1).unwrap();
+    let flags: Vec<_> = tags
+        .iter()
+        .map(|t| (t.name.as_str(), t.auto_applied))
+        .collect();
+    assert_eq!(flags, [("client", false), ("design", true)]);
+}
+
 #[test]
 fn daily_digest_save_and_get_roundtrip() {
     use oneshim_core::models::daily_digest::{
@@ -583,7 +828,7 @@ fn enforce_all_retention_runs_without_error() {
 
     // Insert test data into tables covered by enforce_all_retention
     {
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
 
         // work_sessions — old closed session (schema: primary_app, category, started_at, ended_at)
         conn.execute(
@@ -636,7 +881,7 @@ fn enforce_all_retention_keeps_recent_data() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
 
     {
-        let conn = storage.conn.lock().unwrap();
+        let conn = storage.pool.get().unwrap();
 
         // Recent work_session (should NOT be deleted)
         conn.execute(
@@ -662,7 +907,7 @@ fn enforce_all_retention_keeps_recent_data() {
 #[test]
 fn in_memory_applies_cache_size_pragma() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let cache_size: i64 = conn
         .query_row("PRAGMA cache_size", [], |row| row.get(0))
         .unwrap();
@@ -672,7 +917,7 @@ fn in_memory_applies_cache_size_pragma() {
 #[test]
 fn in_memory_applies_temp_store_pragma() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let temp_store: i64 = conn
         .query_row("PRAGMA temp_store", [], |row| row.get(0))
         .unwrap();
@@ -688,7 +933,7 @@ fn disk_applies_wal_mode() {
     let dir = tempfile::tempdir().unwrap();
     let db_path = dir.path().join("test_wal.db");
     let storage = SqliteStorage::open(&db_path, 30, None).unwrap();
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let journal_mode: String = conn
         .query_row("PRAGMA journal_mode", [], |row| row.get(0))
         .unwrap();
@@ -700,7 +945,7 @@ fn disk_applies_synchronous_normal() {
     let dir = tempfile::tempdir().unwrap();
     let db_path = dir.path().join("test_sync.db");
     let storage = SqliteStorage::open(&db_path, 30, None).unwrap();
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let synchronous: i64 = conn
         .query_row("PRAGMA synchronous", [], |row| row.get(0))
         .unwrap();
@@ -715,7 +960,7 @@ fn disk_applies_journal_size_limit() {
     let dir = tempfile::tempdir().unwrap();
     let db_path = dir.path().join("test_journal_limit.db");
     let storage = SqliteStorage::open(&db_path, 30, None).unwrap();
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let limit: i64 = conn
         .query_row("PRAGMA journal_size_limit", [], |row| row.get(0))
         .unwrap();
@@ -728,7 +973,7 @@ fn disk_applies_journal_size_limit() {
 #[test]
 fn in_memory_does_not_set_journal_size_limit() {
     let storage = SqliteStorage::open_in_memory(30).unwrap();
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let limit: i64 = conn
         .query_row("PRAGMA journal_size_limit", [], |row| row.get(0))
         .unwrap();
@@ -753,6 +998,45 @@ fn pragma_optimize_runs_on_disk_without_error() {
     let _storage = SqliteStorage::open(&db_path, 30, None).unwrap();
 }
 
+// ── Connection pool ──────────────────────────────────────────────
+
+#[test]
+fn in_memory_pool_connections_share_one_database() {
+    let storage = SqliteStorage::open_in_memory(30).unwrap();
+    let writer = storage.pool.get().unwrap();
+    let reader = storage.pool.get().unwrap();
+    writer
+        .execute("INSERT INTO app_meta (key, value) VALUES ('k', 'v')", [])
+        .unwrap();
+    let value: String = reader
+        .query_row("SELECT value FROM app_meta WHERE key = 'k'", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(value, "v");
+
+    // A second in-memory store is a separate database.
+    assert_eq!(
+        SqliteStorage::open_in_memory(30).unwrap().get_meta("k"),
+        None
+    );
+}
+
+#[test]
+fn disk_reads_proceed_while_a_write_transaction_is_open() {
+    let dir = tempfile::tempdir().unwrap();
+    let storage = SqliteStorage::open(&dir.path().join("test_pool.db"), 30, None).unwrap();
+    let mut writer = storage.pool.get().unwrap();
+    let tx = writer.transaction().unwrap();
+    tx.execute("INSERT INTO app_meta (key, value) VALUES ('k', 'v')", [])
+        .unwrap();
+
+    // Served by another pooled connection without waiting for the writer.
+    assert_eq!(storage.get_meta("k"), None);
+    tx.commit().unwrap();
+    assert_eq!(storage.get_meta("k").as_deref(), Some("v"));
+}
+
 // ── Subtask C: FTS5 existence caching ────────────────────────────
 
 #[test]
@@ -863,6 +1147,26 @@ fn sqlcipher_fallback_for_unencrypted_db() {
     assert_eq!(storage.get_meta("hello"), Some("world".to_string()));
 }
 
+#[cfg(feature = "encryption")]
+#[test]
+fn sqlcipher_database_file_has_no_plain_sqlite_header() {
+    use crate::encryption::EncryptionKey;
+    let dir = tempfile::tempdir().unwrap();
+    let db_path = dir.path().join("encrypted.db");
+    let key = EncryptionKey::from_bytes([0x42; 32]);
+    {
+        let storage = SqliteStorage::open(&db_path, 30, Some(&key)).unwrap();
+        storage.set_meta("window_title", "Quarterly payroll.xlsx");
+    }
+
+    let bytes = std::fs::read(&db_path).unwrap();
+    assert!(bytes.len() >= 16);
+    assert_ne!(&bytes[..16], b"SQLite format 3\0");
+    assert!(!bytes
+        .windows(b"Quarterly payroll".len())
+        .any(|w| w == b"Quarterly payroll"));
+}
+
 // ── PR3 underlying-impl gap coverage ───────────────────────────────
 //
 // Phase 5-D8 PR3 audit identified 4 underlying SqliteStorage methods
@@ -915,7 +1219,7 @@ fn add_deep_work_secs_accumulates_on_work_session() {
     storage.add_deep_work_secs(session.id, 45).unwrap();
 
     // Verify via direct SQL that the deep_work_secs column reflects the sum.
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let total: i64 = conn
         .query_row(
             "SELECT deep_work_secs FROM work_sessions WHERE id = ?1",
@@ -942,7 +1246,7 @@ fn increment_work_session_interruption_increments_counter() {
         .increment_work_session_interruption(session.id)
         .unwrap();
 
-    let conn = storage.conn.lock().unwrap();
+    let conn = storage.pool.get().unwrap();
     let count: i64 = conn
         .query_row(
             "SELECT interruption_count FROM work_sessions WHERE id = ?1",
@@ -1032,3 +1336,69 @@ fn entries_by_command_id_respects_limit() {
     let results = storage.entries_by_command_id("cmd-Z", 3);
     assert_eq!(results.len(), 3);
 }
+
+// ── query_audit_entries ───────────────────────────────────────────────────
+
+#[test]
+fn query_audit_entries_filters_by_outcome_and_time_with_paging() {
+    use chrono::{Duration, Utc};
+    use oneshim_core::models::audit::{
+        AuditEntry, AuditFilter, AuditOutcome, AuditPage, AuditStatus,
+    };
+
+    let storage = SqliteStorage::open_in_memory(30).expect("sqlite");
+    let base = Utc::now() - Duration::hours(1);
+    let statuses = [
+        AuditStatus::Completed,
+        AuditStatus::Failed,
+        AuditStatus::Denied,
+        AuditStatus::Failed,
+        AuditStatus::Started,
+        AuditStatus::Failed,
+    ];
+    for (i, status) in statuses.into_iter().enumerate() {
+        storage.save_audit_entry(&AuditEntry {
+            entry_id: format!("id-{i}"),
+            timestamp: base + Duration::minutes(i as i64),
+            session_id: "s".to_string(),
+            command_id: format!("cmd-{i}"),
+            action_type: "test".to_string(),
+            status,
+            details: None,
+            execution_time_ms: None,
+        });
+    }
+
+    // Failed or denied, excluding the newest minute.
+    let filter = AuditFilter {
+        outcomes: vec![AuditOutcome::Failed, AuditOutcome::Denied],
+        from: Some(base),
+        to: Some(base + Duration::minutes(4)),
+    };
+    let first = storage.query_audit_entries(
+        &filter,
+        AuditPage {
+            offset: 0,
+            limit: 2,
+        },
+    );
+    let second = storage.query_audit_entries(
+        &filter,
+        AuditPage {
+            offset: 2,
+            limit: 2,
+        },
+    );
+
+    assert_eq!(first.total, 3);
+    let ids: Vec<_> = first
+        .entries
+        .iter()
+        .map(|e| e.command_id.as_str())
+        .collect();
+    assert_eq!(ids, ["cmd-3", "cmd-2"]);
+    assert_eq!(second.total, 3);
+    assert_eq!(second.entries.len(), 1);
+    assert_eq!(second.entries[0].command_id, "cmd-1");
+    assert_eq!(second.entries[0].outcome(), Some(AuditOutcome::Failed));
+}
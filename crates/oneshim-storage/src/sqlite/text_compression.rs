@@ -0,0 +1,143 @@
+//! Transparent zstd compression for large text columns (frame OCR text).
+//!
+//! Compressed values are stored as BLOBs holding a zstd frame; plain values
+//! stay TEXT. Readers tell them apart by SQLite storage class, so rows written
+//! before compression was enabled (or below the size threshold) read back
+//! unchanged. SQL that filters on a compressed column goes through the
+//! [`DECOMPRESS_TEXT_FN`] scalar function registered on every connection.
+
+use rusqlite::functions::FunctionFlags;
+use rusqlite::types::{ToSqlOutput, ValueRef};
+use rusqlite::{Connection, Row, ToSql};
+
+use crate::error::StorageError;
+
+/// SQL scalar function returning the plain text of a possibly-compressed
+/// column, e.g. `decompress_text(ocr_text) LIKE ?1`.
+pub const DECOMPRESS_TEXT_FN: &str = "decompress_text";
+
+/// Matches the level used for upload payloads in `oneshim-network`.
+const ZSTD_LEVEL: i32 = 3;
+
+/// A text value bound for a column that may hold compressed data.
+pub(super) enum StoredText<'a> {
+    Plain(&'a str),
+    Compressed(Vec<u8>),
+}
+
+impl<'a> StoredText<'a> {
+    /// Compress `text` when it is at least `min_bytes` long and compression
+    /// actually shrinks it; otherwise keep it as TEXT.
+    pub(super) fn encode(text: &'a str, min_bytes: Option<usize>) -> Self {
+        match min_bytes {
+            Some(min) if text.len() >= min => match zstd::encode_all(text.as_bytes(), ZSTD_LEVEL) {
+                Ok(compressed) if compressed.len() < text.len() => Self::Compressed(compressed),
+                _ => Self::Plain(text),
+            },
+            _ => Self::Plain(text),
+        }
+    }
+}
+
+impl ToSql for StoredText<'_> {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(match self {
+            Self::Plain(text) => ToSqlOutput::Borrowed(ValueRef::Text(text.as_bytes())),
+            Self::Compressed(bytes) => ToSqlOutput::Borrowed(ValueRef::Blob(bytes)),
+        })
+    }
+}
+
+fn decode(value: ValueRef<'_>) -> Result<Option<String>, String> {
+    match value {
+        ValueRef::Null => Ok(None),
+        ValueRef::Text(bytes) => String::from_utf8(bytes.to_vec())
+            .map(Some)
+            .map_err(|e| e.to_string()),
+        ValueRef::Blob(bytes) => {
+            let plain = zstd::decode_all(bytes).map_err(|e| e.to_string())?;
+            String::from_utf8(plain)
+                .map(Some)
+                .map_err(|e| e.to_string())
+        }
+        other => Err(format!("unexpected {:?} in text column", other.data_type())),
+    }
+}
+
+/// Read column `idx` of `row`, decompressing it if needed.
+pub(super) fn read_text(row: &Row<'_>, idx: usize) -> rusqlite::Result<Option<String>> {
+    decode(row.get_ref(idx)?).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(
+            idx,
+            rusqlite::types::Type::Blob,
+            format!("compressed text: {e}").into(),
+        )
+    })
+}
+
+/// Register [`DECOMPRESS_TEXT_FN`] on `conn`.
+pub(super) fn register_functions(conn: &Connection) -> Result<(), StorageError> {
+    conn.create_scalar_function(
+        DECOMPRESS_TEXT_FN,
+        1,
+        FunctionFlags::SQLITE_UTF8 | FunctionFlags::SQLITE_DETERMINISTIC,
+        |ctx| decode(ctx.get_raw(0)).map_err(|e| rusqlite::Error::UserFunctionError(e.into())),
+    )
+    .map_err(|e| StorageError::Internal(format!("Failed to register {DECOMPRESS_TEXT_FN}: {e}")))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(conn: &Connection, value: &StoredText<'_>) -> Option<String> {
+        conn.query_row("SELECT ?1", [value], |row| read_text(row, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn text_below_threshold_or_disabled_stays_plain() {
+        assert!(matches!(
+            StoredText::encode("short", Some(64)),
+            StoredText::Plain(_)
+        ));
+        let long = "a".repeat(1024);
+        assert!(matches!(
+            StoredText::encode(&long, None),
+            StoredText::Plain(_)
+        ));
+    }
+
+    #[test]
+    fn compressed_and_plain_values_read_back_identically() {
+        let conn = Connection::open_in_memory().unwrap();
+        let long = "invoice 2026-04 total 1,234.56 ".repeat(200);
+
+        let compressed = StoredText::encode(&long, Some(64));
+        assert!(matches!(compressed, StoredText::Compressed(_)));
+        assert_eq!(
+            roundtrip(&conn, &compressed).as_deref(),
+            Some(long.as_str())
+        );
+        assert_eq!(
+            roundtrip(&conn, &StoredText::Plain("plain")).as_deref(),
+            Some("plain")
+        );
+    }
+
+    #[test]
+    fn sql_function_decompresses_for_filtering() {
+        let conn = Connection::open_in_memory().unwrap();
+        register_functions(&conn).unwrap();
+        let long = "lorem ipsum needle dolor ".repeat(100);
+
+        let matched: bool = conn
+            .query_row(
+                "SELECT decompress_text(?1) LIKE '%needle%'",
+                [StoredText::encode(&long, Some(64))],
+                |row| row.get(0),
+            )
+            .unwrap();
+        assert!(matched);
+    }
+}
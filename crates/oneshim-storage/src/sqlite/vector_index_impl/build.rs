@@ -33,7 +33,7 @@ impl VectorIndex for SqliteVectorIndex {
                      WHERE is_stale = 0 AND vector_int8 IS NOT NULL",
                     )
                     .map_err(|e| {
-                        StorageError::Internal(format!("Failed to prepare vector load: {e}"))
+                        StorageError::Database(format!("Failed to prepare vector load: {e}"))
                     })?;
 
                 let rows: Vec<(i64, QuantizedVector)> = stmt
@@ -51,7 +51,7 @@ impl VectorIndex for SqliteVectorIndex {
                             },
                         ))
                     })
-                    .map_err(|e| StorageError::Internal(format!("Failed to load vectors: {e}")))?
+                    .map_err(|e| StorageError::Database(format!("Failed to load vectors: {e}")))?
                     .filter_map(|r| r.ok())
                     .collect();
 
@@ -105,14 +105,14 @@ impl VectorIndex for SqliteVectorIndex {
         self.with_conn(move |conn| {
             // Clear old data
             conn.execute("DELETE FROM ivf_assignments", [])
-                .map_err(|e| StorageError::Internal(format!("Failed to clear assignments: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to clear assignments: {e}")))?;
             conn.execute("DELETE FROM ivf_centroids", [])
-                .map_err(|e| StorageError::Internal(format!("Failed to clear centroids: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to clear centroids: {e}")))?;
 
             // Insert centroids
             {
                 let tx = conn.unchecked_transaction().map_err(|e| {
-                    StorageError::Internal(format!("Failed to begin transaction: {e}"))
+                    StorageError::Database(format!("Failed to begin transaction: {e}"))
                 })?;
 
                 let mut stmt = tx
@@ -121,27 +121,27 @@ impl VectorIndex for SqliteVectorIndex {
                          VALUES (?1, ?2, ?3, ?4, ?5)",
                     )
                     .map_err(|e| {
-                        StorageError::Internal(format!("Failed to prepare centroid insert: {e}"))
+                        StorageError::Database(format!("Failed to prepare centroid insert: {e}"))
                     })?;
 
                 for (id, blob, scale, offset, count) in &centroids_data {
                     stmt.execute(params![*id as i64, blob, scale, offset, *count as i64])
                         .map_err(|e| {
-                            StorageError::Internal(format!(
+                            StorageError::Database(format!(
                                 "Failed to insert centroid {id}: {e}"
                             ))
                         })?;
                 }
                 drop(stmt);
                 tx.commit().map_err(|e| {
-                    StorageError::Internal(format!("Failed to commit centroids: {e}"))
+                    StorageError::Database(format!("Failed to commit centroids: {e}"))
                 })?;
             }
 
             // Insert assignments in chunks of 1000
             for chunk in assignments.chunks(1000) {
                 let tx = conn.unchecked_transaction().map_err(|e| {
-                    StorageError::Internal(format!("Failed to begin assignment tx: {e}"))
+                    StorageError::Database(format!("Failed to begin assignment tx: {e}"))
                 })?;
 
                 let mut stmt = tx
@@ -150,7 +150,7 @@ impl VectorIndex for SqliteVectorIndex {
                          VALUES (?1, ?2)",
                     )
                     .map_err(|e| {
-                        StorageError::Internal(format!(
+                        StorageError::Database(format!(
                             "Failed to prepare assignment insert: {e}"
                         ))
                     })?;
@@ -158,14 +158,14 @@ impl VectorIndex for SqliteVectorIndex {
                 for (vid, cid) in chunk {
                     stmt.execute(params![vid, *cid as i64])
                         .map_err(|e| {
-                            StorageError::Internal(format!(
+                            StorageError::Database(format!(
                                 "Failed to insert assignment for vector {vid}: {e}"
                             ))
                         })?;
                 }
                 drop(stmt);
                 tx.commit().map_err(|e| {
-                    StorageError::Internal(format!("Failed to commit assignments: {e}"))
+                    StorageError::Database(format!("Failed to commit assignments: {e}"))
                 })?;
             }
 
@@ -174,12 +174,12 @@ impl VectorIndex for SqliteVectorIndex {
             conn.execute(
                 "INSERT OR REPLACE INTO vector_index_meta (key, value, updated_at) VALUES ('ivf_built_at', ?1, ?1)",
                 params![now],
-            ).map_err(|e| StorageError::Internal(format!("Failed to update index meta: {e}")))?;
+            ).map_err(|e| StorageError::Database(format!("Failed to update index meta: {e}")))?;
 
             conn.execute(
                 "INSERT OR REPLACE INTO vector_index_meta (key, value, updated_at) VALUES ('ivf_vector_count', ?1, ?2)",
                 params![n_vectors.to_string(), now],
-            ).map_err(|e| StorageError::Internal(format!("Failed to update vector count meta: {e}")))?;
+            ).map_err(|e| StorageError::Database(format!("Failed to update vector count meta: {e}")))?;
 
             // WAL checkpoint
             if let Err(e) = conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE)") {
@@ -215,7 +215,7 @@ impl VectorIndex for SqliteVectorIndex {
                      WHERE is_stale = 0 AND vector_int8 IS NOT NULL",
                     )
                     .map_err(|e| {
-                        StorageError::Internal(format!("Failed to prepare vector load: {e}"))
+                        StorageError::Database(format!("Failed to prepare vector load: {e}"))
                     })?;
 
                 let rows: Vec<(i64, Vec<f32>)> = stmt
@@ -231,7 +231,7 @@ impl VectorIndex for SqliteVectorIndex {
                         };
                         Ok((id, ScalarQuantizer::dequantize(&qv)))
                     })
-                    .map_err(|e| StorageError::Internal(format!("Failed to load vectors: {e}")))?
+                    .map_err(|e| StorageError::Database(format!("Failed to load vectors: {e}")))?
                     .filter_map(|r| r.ok())
                     .collect();
 
@@ -263,8 +263,8 @@ impl VectorIndex for SqliteVectorIndex {
 
         // Store thresholds as JSON
         let thresholds_json =
-            serde_json::to_string(&thresholds).map_err(|e| CoreError::Internal {
-                code: oneshim_core::error_codes::InternalCode::Generic,
+            serde_json::to_string(&thresholds).map_err(|e| CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Serialization,
                 message: format!("Failed to serialize thresholds: {e}"),
             })?;
 
@@ -272,7 +272,7 @@ impl VectorIndex for SqliteVectorIndex {
         self.with_conn(move |conn| {
             for chunk in codes.chunks(1000) {
                 let tx = conn.unchecked_transaction().map_err(|e| {
-                    StorageError::Internal(format!("Failed to begin binary code tx: {e}"))
+                    StorageError::Database(format!("Failed to begin binary code tx: {e}"))
                 })?;
 
                 let mut stmt = tx
@@ -281,21 +281,21 @@ impl VectorIndex for SqliteVectorIndex {
                          VALUES (?1, ?2)",
                     )
                     .map_err(|e| {
-                        StorageError::Internal(format!(
+                        StorageError::Database(format!(
                             "Failed to prepare binary code insert: {e}"
                         ))
                     })?;
 
                 for (vid, code_data) in chunk {
                     stmt.execute(params![vid, code_data]).map_err(|e| {
-                        StorageError::Internal(format!(
+                        StorageError::Database(format!(
                             "Failed to insert binary code for vector {vid}: {e}"
                         ))
                     })?;
                 }
                 drop(stmt);
                 tx.commit().map_err(|e| {
-                    StorageError::Internal(format!("Failed to commit binary codes: {e}"))
+                    StorageError::Database(format!("Failed to commit binary codes: {e}"))
                 })?;
             }
 
@@ -304,12 +304,12 @@ impl VectorIndex for SqliteVectorIndex {
             conn.execute(
                 "INSERT OR REPLACE INTO vector_index_meta (key, value, updated_at) VALUES ('binary_quantile_thresholds', ?1, ?2)",
                 params![thresholds_json, now],
-            ).map_err(|e| StorageError::Internal(format!("Failed to store thresholds: {e}")))?;
+            ).map_err(|e| StorageError::Database(format!("Failed to store thresholds: {e}")))?;
 
             conn.execute(
                 "INSERT OR REPLACE INTO vector_index_meta (key, value, updated_at) VALUES ('binary_built_at', ?1, ?1)",
                 params![now],
-            ).map_err(|e| StorageError::Internal(format!("Failed to update binary build time: {e}")))?;
+            ).map_err(|e| StorageError::Database(format!("Failed to update binary build time: {e}")))?;
 
             if let Err(e) = conn.execute_batch("PRAGMA wal_checkpoint(TRUNCATE)") {
                 debug!("execute_batch failed: {e}");
@@ -48,7 +48,7 @@ pub(super) async fn assign_to_cluster_impl(
                 params![vector_id, cluster_id as i64],
             )
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to assign vector to cluster: {e}"))
+                StorageError::Database(format!("Failed to assign vector to cluster: {e}"))
             })?;
             Ok(())
         })
@@ -68,7 +68,7 @@ pub(super) async fn store_binary_code_impl(
                 params![vector_id, code_data],
             )
             .map_err(|e| {
-                StorageError::Internal(format!("Failed to store binary code: {e}"))
+                StorageError::Database(format!("Failed to store binary code: {e}"))
             })?;
             Ok(())
         })
@@ -134,7 +134,7 @@ pub(super) async fn count_unindexed_impl(index: &SqliteVectorIndex) -> Result<u6
                     |row| row.get(0),
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to count unindexed vectors: {e}"))
+                    StorageError::Database(format!("Failed to count unindexed vectors: {e}"))
                 })?;
             Ok(count as u64)
         })
@@ -158,7 +158,7 @@ pub(super) async fn load_quantile_thresholds_impl(
                 Some(json) => {
                     let thresholds: QuantileThresholds =
                         serde_json::from_str(&json).map_err(|e| {
-                            StorageError::Internal(format!(
+                            StorageError::Serialization(format!(
                                 "Failed to deserialize quantile thresholds: {e}"
                             ))
                         })?;
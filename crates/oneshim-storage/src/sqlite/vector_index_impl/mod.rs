@@ -13,14 +13,14 @@ mod tests;
 use oneshim_core::models::embedding::{EmbeddingContentType, SearchFilters, SearchResult};
 use oneshim_core::quantization::{QuantizedVector, ScalarQuantizer};
 use rusqlite::Connection;
-use std::sync::{Arc, Mutex};
 use tokio::sync::RwLock;
 
 use crate::error::StorageError;
+use crate::sqlite::SqlitePool;
 
 /// SQLite-backed vector index supporting IVF clustering and binary code search.
 pub struct SqliteVectorIndex {
-    conn: Arc<Mutex<Connection>>,
+    pool: SqlitePool,
     /// Cached centroids for query-time probe selection.
     /// Uses `tokio::sync::RwLock` so the guard is `Send` — safe to hold
     /// briefly inside async methods without blocking the executor.
@@ -34,10 +34,10 @@ struct CachedCentroid {
 }
 
 impl SqliteVectorIndex {
-    /// Create a new index implementation sharing the same DB connection.
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
+    /// Create a new index implementation sharing the storage connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
         Self {
-            conn,
+            pool,
             centroid_cache: RwLock::new(None),
         }
     }
@@ -48,11 +48,11 @@ impl SqliteVectorIndex {
         F: FnOnce(&Connection) -> Result<T, StorageError> + Send + 'static,
         T: Send + 'static,
     {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         tokio::task::spawn_blocking(move || {
-            let guard = conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            let guard = pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
             f(&guard)
         })
         .await
@@ -72,13 +72,13 @@ impl SqliteVectorIndex {
         // before the tokio RwLock write-await below.
         let centroids = {
             let conn = self
-                .conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+                .pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
 
             let mut stmt = conn
                 .prepare("SELECT id, centroid_int8, centroid_scale, centroid_offset FROM ivf_centroids ORDER BY id")
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare centroid query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare centroid query: {e}")))?;
 
             let rows: Vec<CachedCentroid> = stmt
                 .query_map([], |row| {
@@ -95,7 +95,7 @@ impl SqliteVectorIndex {
                         },
                     })
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to query centroids: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to query centroids: {e}")))?
                 .filter_map(|r| r.ok())
                 .collect();
             rows
@@ -87,7 +87,7 @@ pub(super) async fn search_ivf_impl(
             );
 
             let mut stmt = conn.prepare(&sql).map_err(|e| {
-                StorageError::Internal(format!("Failed to prepare IVF search: {e}"))
+                StorageError::Database(format!("Failed to prepare IVF search: {e}"))
             })?;
 
             let params_ref: Vec<&dyn rusqlite::types::ToSql> =
@@ -107,7 +107,7 @@ pub(super) async fn search_ivf_impl(
                         row.get::<_, String>(7)?,
                     ))
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to query IVF vectors: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to query IVF vectors: {e}")))?
                 .filter_map(|r| r.ok())
                 .collect();
 
@@ -195,7 +195,7 @@ pub(super) async fn search_ivf_binary_impl(
             );
 
             let mut stmt = conn.prepare(&sql).map_err(|e| {
-                StorageError::Internal(format!("Failed to prepare binary search: {e}"))
+                StorageError::Database(format!("Failed to prepare binary search: {e}"))
             })?;
 
             let params: Vec<Box<dyn rusqlite::types::ToSql>> = probe_ids
@@ -213,7 +213,7 @@ pub(super) async fn search_ivf_binary_impl(
                         BinaryQuantizer::hamming_distance(&qb, &BinaryCode { data: code_data });
                     Ok((vid, hamming))
                 })
-                .map_err(|e| StorageError::Internal(format!("Failed to query binary codes: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to query binary codes: {e}")))?
                 .filter_map(|r| r.ok())
                 .collect();
 
@@ -251,7 +251,7 @@ pub(super) async fn search_ivf_binary_impl(
             );
 
             let mut stmt2 = conn.prepare(&rerank_sql).map_err(|e| {
-                StorageError::Internal(format!("Failed to prepare rerank query: {e}"))
+                StorageError::Database(format!("Failed to prepare rerank query: {e}"))
             })?;
 
             let params_ref2: Vec<&dyn rusqlite::types::ToSql> =
@@ -272,7 +272,7 @@ pub(super) async fn search_ivf_binary_impl(
                     ))
                 })
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to query rerank vectors: {e}"))
+                    StorageError::Database(format!("Failed to query rerank vectors: {e}"))
                 })?
                 .filter_map(|r| r.ok())
                 .collect();
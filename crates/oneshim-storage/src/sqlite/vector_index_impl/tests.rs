@@ -1,17 +1,15 @@
 use super::*;
-use crate::migration;
+use crate::sqlite::SqliteStorage;
 use oneshim_core::models::embedding::EmbeddingMetadata;
 use oneshim_core::ports::vector_index::VectorIndex;
 use oneshim_core::ports::vector_store::VectorStore;
 
-fn setup_db() -> Arc<Mutex<Connection>> {
-    let conn = Connection::open_in_memory().unwrap();
-    migration::run_migrations(&conn).unwrap();
-    Arc::new(Mutex::new(conn))
+fn setup_db() -> SqlitePool {
+    SqliteStorage::open_in_memory(30).unwrap().connection_pool()
 }
 
 /// Store a quantized vector via SqliteVectorStore.
-async fn store_quantized_vector(conn: &Arc<Mutex<Connection>>, segment_id: &str, vector: &[f32]) {
+async fn store_quantized_vector(conn: &SqlitePool, segment_id: &str, vector: &[f32]) {
     let store = super::super::vector_store_impl::SqliteVectorStore::new(conn.clone());
     let qv = ScalarQuantizer::quantize(vector).unwrap();
     store
@@ -32,7 +30,6 @@ async fn store_quantized_vector(conn: &Arc<Mutex<Connection>>, segment_id: &str,
         .unwrap();
 }
 
-#[allow(clippy::await_holding_lock)]
 #[tokio::test]
 async fn build_ivf_and_search_roundtrip() {
     let conn = setup_db();
@@ -63,7 +60,7 @@ async fn build_ivf_and_search_roundtrip() {
     assert!(n_clusters > 0);
 
     // Verify centroids stored
-    let guard = conn.lock().unwrap();
+    let guard = conn.get().unwrap();
     let centroid_count: i64 = guard
         .query_row("SELECT COUNT(*) FROM ivf_centroids", [], |row| row.get(0))
         .unwrap();
@@ -92,7 +89,6 @@ async fn build_ivf_and_search_roundtrip() {
     );
 }
 
-#[allow(clippy::await_holding_lock)]
 #[tokio::test]
 async fn build_binary_codes_and_search() {
     let conn = setup_db();
@@ -110,7 +106,7 @@ async fn build_binary_codes_and_search() {
     assert_eq!(count, 20);
 
     // Verify codes stored
-    let guard = conn.lock().unwrap();
+    let guard = conn.get().unwrap();
     let code_count: i64 = guard
         .query_row("SELECT COUNT(*) FROM vector_binary_codes", [], |row| {
             row.get(0)
@@ -147,7 +143,6 @@ async fn build_binary_codes_and_search() {
     tracing::debug!("IVF+binary search returned {} results", results.len());
 }
 
-#[allow(clippy::await_holding_lock)]
 #[tokio::test]
 async fn assign_to_cluster_incremental() {
     let conn = setup_db();
@@ -164,7 +159,7 @@ async fn assign_to_cluster_incremental() {
     store_quantized_vector(&conn, "new-vec", &[1.0, 0.0, 0.0, 0.0]).await;
 
     // Get its ID
-    let guard = conn.lock().unwrap();
+    let guard = conn.get().unwrap();
     let new_id: i64 = guard
         .query_row(
             "SELECT id FROM embedding_vectors WHERE segment_id = 'new-vec'",
@@ -179,7 +174,7 @@ async fn assign_to_cluster_incremental() {
     index.assign_to_cluster(new_id, &qv).await.unwrap();
 
     // Verify assignment exists
-    let guard = conn.lock().unwrap();
+    let guard = conn.get().unwrap();
     let assigned: i64 = guard
         .query_row(
             "SELECT COUNT(*) FROM ivf_assignments WHERE vector_id = ?1",
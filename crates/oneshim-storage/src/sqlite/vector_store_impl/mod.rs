@@ -5,9 +5,9 @@ mod trait_impl;
 mod tests;
 
 use rusqlite::Connection;
-use std::sync::{Arc, Mutex};
 
 use crate::error::StorageError;
+use crate::sqlite::SqlitePool;
 
 pub use helpers::{
     brute_force_search, brute_force_search_quantized, bytes_to_f32_vec, bytes_to_i8_vec,
@@ -21,13 +21,13 @@ pub use helpers::{
 /// Search is performed in-memory via brute-force cosine similarity with optional
 /// exponential time decay weighting.
 pub struct SqliteVectorStore {
-    conn: Arc<Mutex<Connection>>,
+    pool: SqlitePool,
 }
 
 impl SqliteVectorStore {
-    /// Create a new `SqliteVectorStore` sharing the same connection as `SqliteStorage`.
-    pub fn new(conn: Arc<Mutex<Connection>>) -> Self {
-        Self { conn }
+    /// Create a new `SqliteVectorStore` sharing `SqliteStorage`'s connection pool.
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
     }
 
     /// Wrap a synchronous closure on the connection via `spawn_blocking`.
@@ -36,11 +36,11 @@ impl SqliteVectorStore {
         F: FnOnce(&Connection) -> Result<T, StorageError> + Send + 'static,
         T: Send + 'static,
     {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         tokio::task::spawn_blocking(move || {
-            let guard = conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            let guard = pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
             f(&guard)
         })
         .await
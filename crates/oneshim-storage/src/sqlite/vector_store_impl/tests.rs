@@ -3,15 +3,11 @@ use super::SqliteVectorStore;
 use chrono::{Duration, Utc};
 use oneshim_core::models::embedding::{EmbeddingContentType, EmbeddingMetadata, SearchFilters};
 use oneshim_core::ports::vector_store::VectorStore;
-use rusqlite::Connection;
-use std::sync::{Arc, Mutex};
 
-use crate::migration;
+use crate::sqlite::{SqlitePool, SqliteStorage};
 
-fn setup_db() -> Arc<Mutex<Connection>> {
-    let conn = Connection::open_in_memory().unwrap();
-    migration::run_migrations(&conn).unwrap();
-    Arc::new(Mutex::new(conn))
+fn setup_db() -> SqlitePool {
+    SqliteStorage::open_in_memory(30).unwrap().connection_pool()
 }
 
 #[test]
@@ -384,7 +380,7 @@ async fn store_quantized_roundtrip() {
         .unwrap();
 
     // Verify both f32 and INT8 columns are populated
-    let guard = conn.lock().unwrap();
+    let guard = conn.get().unwrap();
     let (has_f32, has_int8): (bool, bool) = guard
         .query_row(
             "SELECT vector IS NOT NULL, vector_int8 IS NOT NULL FROM embedding_vectors WHERE segment_id = 'seg-q001'",
@@ -665,7 +661,7 @@ async fn store_quantized_skip_float32_nulls_vector_column() {
         .unwrap();
 
     // Verify: f32 column is empty BLOB (len=0), INT8 column is populated
-    let guard = conn.lock().unwrap();
+    let guard = conn.get().unwrap();
     let (f32_blob_len, has_int8): (usize, bool) = guard
         .query_row(
             "SELECT LENGTH(vector), vector_int8 IS NOT NULL FROM embedding_vectors WHERE segment_id = 'seg-skip-f32'",
@@ -1004,8 +1000,8 @@ async fn store_quantized_skip_float32_bypasses_dimension_check() {
 
 /// Seed a row into `regimes` so the FK on `activity_segments.regime_id` is
 /// satisfied when FKs are enforced. Idempotent via `INSERT OR IGNORE`.
-fn ensure_regime(conn: &Arc<Mutex<Connection>>, regime_id: &str) {
-    let guard = conn.lock().unwrap();
+fn ensure_regime(conn: &SqlitePool, regime_id: &str) {
+    let guard = conn.get().unwrap();
     guard
         .execute(
             "INSERT OR IGNORE INTO regimes
@@ -1019,11 +1015,11 @@ fn ensure_regime(conn: &Arc<Mutex<Connection>>, regime_id: &str) {
 /// Inserts a row into `activity_segments` so the regime_id subquery
 /// (`segment_id IN (SELECT id FROM activity_segments WHERE regime_id = ?)`)
 /// can resolve.
-fn insert_segment_row(conn: &Arc<Mutex<Connection>>, seg_id: &str, regime_id: Option<&str>) {
+fn insert_segment_row(conn: &SqlitePool, seg_id: &str, regime_id: Option<&str>) {
     if let Some(r) = regime_id {
         ensure_regime(conn, r);
     }
-    let guard = conn.lock().unwrap();
+    let guard = conn.get().unwrap();
     guard
         .execute(
             "INSERT INTO activity_segments
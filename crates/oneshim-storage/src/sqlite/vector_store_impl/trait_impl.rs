@@ -45,7 +45,7 @@ impl VectorStore for SqliteVectorStore {
                     timestamp_str,
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to store embedding vector: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to store embedding vector: {e}")))?;
 
             debug!(
                 "Stored embedding vector for segment {} (type={})",
@@ -72,11 +72,11 @@ impl VectorStore for SqliteVectorStore {
                      FROM embedding_vectors
                      WHERE is_stale = 0",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare search query: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare search query: {e}")))?;
 
             let rows: Vec<super::helpers::VectorRow> = stmt
                 .query_map([], map_vector_row)
-                .map_err(|e| StorageError::Internal(format!("Failed to query vectors: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to query vectors: {e}")))?
                 .filter_map(|r| match r {
                     Ok(row) => Some(row),
                     Err(e) => { warn!("Skipping corrupted row in search: {e}"); None }
@@ -161,7 +161,7 @@ impl VectorStore for SqliteVectorStore {
             );
 
             let mut stmt = conn.prepare(&sql).map_err(|e| {
-                StorageError::Internal(format!("Failed to prepare filtered query: {e}"))
+                StorageError::Database(format!("Failed to prepare filtered query: {e}"))
             })?;
 
             let params_ref: Vec<&dyn rusqlite::types::ToSql> =
@@ -170,7 +170,7 @@ impl VectorStore for SqliteVectorStore {
             let rows: Vec<super::helpers::VectorRow> = stmt
                 .query_map(params_ref.as_slice(), map_vector_row)
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to query filtered vectors: {e}"))
+                    StorageError::Database(format!("Failed to query filtered vectors: {e}"))
                 })?
                 .filter_map(|r| match r {
                     Ok(row) => Some(row),
@@ -196,7 +196,7 @@ impl VectorStore for SqliteVectorStore {
                     params![cutoff],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to enforce vector retention: {e}"))
+                    StorageError::Database(format!("Failed to enforce vector retention: {e}"))
                 })?;
             debug!("Enforced vector retention: deleted {deleted} rows older than {max_days} days");
             Ok(deleted as u64)
@@ -214,7 +214,7 @@ impl VectorStore for SqliteVectorStore {
                     params![model_id],
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to mark vectors stale: {e}"))
+                    StorageError::Database(format!("Failed to mark vectors stale: {e}"))
                 })?;
             debug!("Marked {updated} vectors as stale for model_id={model_id}");
             Ok(updated as u64)
@@ -245,11 +245,11 @@ impl VectorStore for SqliteVectorStore {
                     "SELECT id, original_text FROM embedding_vectors WHERE is_stale = 1 LIMIT ?1",
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to prepare stale query: {e}"))
+                    StorageError::Database(format!("Failed to prepare stale query: {e}"))
                 })?;
             let rows: Vec<(i64, String)> = stmt
                 .query_map(params![limit as i64], |row| Ok((row.get(0)?, row.get(1)?)))
-                .map_err(|e| StorageError::Internal(format!("Failed to query stale vectors: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to query stale vectors: {e}")))?
                 .filter_map(|r| match r {
                     Ok(row) => Some(row),
                     Err(e) => {
@@ -277,7 +277,7 @@ impl VectorStore for SqliteVectorStore {
                 "UPDATE embedding_vectors SET vector = ?1, model_id = ?2, is_stale = 0 WHERE id = ?3",
                 params![blob, model_id, id],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to update vector: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to update vector: {e}")))?;
             Ok(())
         })
         .await
@@ -343,7 +343,7 @@ impl VectorStore for SqliteVectorStore {
                     offset,
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("Failed to store quantized vector: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("Failed to store quantized vector: {e}")))?;
 
             debug!(
                 "Stored quantized vector for segment {} (type={}, skip_f32={})",
@@ -432,7 +432,7 @@ impl VectorStore for SqliteVectorStore {
             );
 
             let mut stmt = conn.prepare(&sql).map_err(|e| {
-                StorageError::Internal(format!("Failed to prepare quantized search: {e}"))
+                StorageError::Database(format!("Failed to prepare quantized search: {e}"))
             })?;
 
             let params_ref: Vec<&dyn rusqlite::types::ToSql> =
@@ -445,7 +445,7 @@ impl VectorStore for SqliteVectorStore {
             // is released before the brute-force scan.
             let rows: Vec<super::helpers::QuantizedVectorRow> = stmt
                 .query_map(params_ref.as_slice(), map_quantized_row)
-                .map_err(|e| StorageError::Internal(format!("Failed to query quantized vectors: {e}")))?
+                .map_err(|e| StorageError::Database(format!("Failed to query quantized vectors: {e}")))?
                 .filter_map(|r| match r {
                     Ok(row) => Some(row),
                     Err(e) => { warn!("Skipping corrupted row in search_quantized: {e}"); None }
@@ -464,7 +464,7 @@ impl VectorStore for SqliteVectorStore {
                     "SELECT id, vector FROM embedding_vectors WHERE vector_int8 IS NULL LIMIT ?1",
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to prepare backfill query: {e}"))
+                    StorageError::Database(format!("Failed to prepare backfill query: {e}"))
                 })?;
 
             let rows: Vec<(i64, Vec<u8>)> = stmt
@@ -472,7 +472,7 @@ impl VectorStore for SqliteVectorStore {
                     Ok((row.get(0)?, row.get::<_, Vec<u8>>(1)?))
                 })
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to query backfill rows: {e}"))
+                    StorageError::Database(format!("Failed to query backfill rows: {e}"))
                 })?
                 .filter_map(|r| match r {
                     Ok(row) => Some(row),
@@ -486,34 +486,34 @@ impl VectorStore for SqliteVectorStore {
 
             // Wrap batch updates in a transaction for performance.
             conn.execute_batch("BEGIN")
-                .map_err(|e| StorageError::Internal(format!("Backfill BEGIN failed: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Backfill BEGIN failed: {e}")))?;
 
             let mut update_stmt = conn
                 .prepare(
                     "UPDATE embedding_vectors SET vector_int8 = ?1, quant_scale = ?2, quant_offset = ?3 WHERE id = ?4",
                 )
-                .map_err(|e| StorageError::Internal(format!("Failed to prepare backfill update: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Failed to prepare backfill update: {e}")))?;
 
             let mut count: u64 = 0;
             for (id, blob) in &rows {
                 let f32_vec = bytes_to_f32_vec(blob);
                 let quantized =
                     oneshim_core::quantization::ScalarQuantizer::quantize(&f32_vec).map_err(
-                        |e| StorageError::Internal(format!("Backfill quantize failed for id={id}: {e}")),
+                        |e| StorageError::Database(format!("Backfill quantize failed for id={id}: {e}")),
                     )?;
                 let int8_blob = i8_vec_to_bytes(&quantized.data);
 
                 update_stmt
                     .execute(params![int8_blob, quantized.scale, quantized.offset, id])
                     .map_err(|e| {
-                        StorageError::Internal(format!("Backfill update failed for id={id}: {e}"))
+                        StorageError::Database(format!("Backfill update failed for id={id}: {e}"))
                     })?;
 
                 count += 1;
             }
 
             conn.execute_batch("COMMIT")
-                .map_err(|e| StorageError::Internal(format!("Backfill COMMIT failed: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("Backfill COMMIT failed: {e}")))?;
 
             debug!("Backfilled {count} vectors to INT8 quantized format");
             Ok(count)
@@ -531,7 +531,7 @@ impl VectorStore for SqliteVectorStore {
                     |row| row.get(0),
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to count unquantized vectors: {e}"))
+                    StorageError::Database(format!("Failed to count unquantized vectors: {e}"))
                 })?;
             Ok(count as u64)
         })
@@ -548,7 +548,7 @@ impl VectorStore for SqliteVectorStore {
                     |row| row.get(0),
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to count active vectors: {e}"))
+                    StorageError::Database(format!("Failed to count active vectors: {e}"))
                 })?;
             Ok(count as u64)
         })
@@ -575,7 +575,7 @@ impl VectorStore for SqliteVectorStore {
             );
 
             let mut stmt = conn.prepare(&sql).map_err(|e| {
-                StorageError::Internal(format!("Failed to prepare metadata batch query: {e}"))
+                StorageError::Database(format!("Failed to prepare metadata batch query: {e}"))
             })?;
 
             let params_boxed: Vec<Box<dyn rusqlite::types::ToSql>> =
@@ -596,13 +596,13 @@ impl VectorStore for SqliteVectorStore {
                     Ok((id, segment_id, content_type_str, content_label, ts_str, original_text, model_id))
                 })
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to query metadata by ids: {e}"))
+                    StorageError::Database(format!("Failed to query metadata by ids: {e}"))
                 })?;
 
             for row_result in rows {
                 let (id, segment_id, content_type_str, content_label, ts_str, original_text, model_id) =
                     row_result.map_err(|e| {
-                        StorageError::Internal(format!("Failed to read metadata row: {e}"))
+                        StorageError::Database(format!("Failed to read metadata row: {e}"))
                     })?;
                 let timestamp = DateTime::parse_from_rfc3339(&ts_str)
                     .map(|dt| dt.with_timezone(&Utc))
@@ -634,7 +634,7 @@ impl VectorStore for SqliteVectorStore {
                      WHERE is_stale = 0 AND LENGTH(vector) > 0",
                 )
                 .map_err(|e| {
-                    StorageError::Internal(format!(
+                    StorageError::Database(format!(
                         "Failed to prepare get_all_vectors_for_rebuild: {e}"
                     ))
                 })?;
@@ -646,7 +646,7 @@ impl VectorStore for SqliteVectorStore {
                     Ok((id as u64, bytes_to_f32_vec(&blob)))
                 })
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to query vectors for rebuild: {e}"))
+                    StorageError::Database(format!("Failed to query vectors for rebuild: {e}"))
                 })?
                 .filter_map(|r| match r {
                     Ok(row) => Some(row),
@@ -670,7 +670,7 @@ impl VectorStore for SqliteVectorStore {
             let mut stmt = conn
                 .prepare("SELECT id FROM embedding_vectors WHERE timestamp < ?1")
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to prepare get_expired_ids: {e}"))
+                    StorageError::Database(format!("Failed to prepare get_expired_ids: {e}"))
                 })?;
             let ids: Vec<u64> = stmt
                 .query_map(params![cutoff], |row| {
@@ -678,7 +678,7 @@ impl VectorStore for SqliteVectorStore {
                     Ok(id as u64)
                 })
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to query expired vector ids: {e}"))
+                    StorageError::Database(format!("Failed to query expired vector ids: {e}"))
                 })?
                 .filter_map(|r| match r {
                     Ok(row) => Some(row),
@@ -699,7 +699,7 @@ impl VectorStore for SqliteVectorStore {
             let id: i64 = conn
                 .query_row("SELECT last_insert_rowid()", [], |row| row.get(0))
                 .map_err(|e| {
-                    StorageError::Internal(format!("Failed to get last_insert_rowid: {e}"))
+                    StorageError::Database(format!("Failed to get last_insert_rowid: {e}"))
                 })?;
             Ok(id as u64)
         })
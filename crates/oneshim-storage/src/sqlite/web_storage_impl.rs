@@ -3,11 +3,12 @@ use oneshim_core::error::CoreError;
 use oneshim_core::models::activity::SessionStats;
 use oneshim_core::models::daily_digest::DailyDigest;
 use oneshim_core::models::storage_records::{
-    DeletedRangeCounts, EventExportRecord, FocusInterruptionRecord, FocusWorkSessionRecord,
-    FrameExportRecord, FrameRecord, FrameTagLinkRecord, GuiInteractionRecord, HourlyMetricsRecord,
+    DailyMetricsRecord, DatabaseBackupRecord, DeletedRangeCounts, EventExportRecord,
+    FocusInterruptionRecord, FocusWorkSessionRecord, FrameExportRecord, FrameRecord,
+    FrameTagLinkRecord, GuiInteractionRecord, HourlyMetricsRecord, IntegrityCheckRecord,
     LocalSuggestionRecord, MetricExportRecord, NewGuiInteraction, SearchEventRow, SearchFrameRow,
-    SegmentDetailRecord, SegmentSummaryRecord, StorageStatsSummaryRecord, SuggestionRecord,
-    TagRecord,
+    SegmentDetailRecord, SegmentSummaryRecord, StorageReport, StorageStatsSummaryRecord,
+    SuggestionRecord, TagRecord, TagRuleRecord,
 };
 use oneshim_core::models::work_session::FocusMetrics;
 use oneshim_core::ports::web_storage::{
@@ -16,6 +17,7 @@ use oneshim_core::ports::web_storage::{
     StorageMaintenanceStorage, SuggestionQueryStorage, TagStorage,
 };
 use oneshim_core::types::TimeWindow;
+use std::path::Path;
 
 use super::SqliteStorage;
 
@@ -49,15 +51,15 @@ impl SqliteStorage {
             .map(serde_json::from_str)
             .transpose()
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Serialization,
                 message: format!("Failed to deserialize insight: {e}"),
             })?;
         let timeline = serde_json::from_str(timeline_json).map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Serialization,
             message: format!("Failed to deserialize timeline: {e}"),
         })?;
         let statistics = serde_json::from_str(statistics_json).map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Serialization,
             message: format!("Failed to deserialize statistics: {e}"),
         })?;
         let generated_at = chrono::DateTime::parse_from_rfc3339(generated_at_str)
@@ -119,6 +121,10 @@ impl FrameQueryStorage for SqliteStorage {
         SqliteStorage::get_frame_file_path(self, frame_id).map_err(Into::into)
     }
 
+    fn get_trashed_frames(&self, limit: usize) -> Result<Vec<FrameRecord>, CoreError> {
+        SqliteStorage::get_trashed_frames(self, limit).map_err(Into::into)
+    }
+
     fn list_frame_file_paths_in_range(
         &self,
         window: &TimeWindow,
@@ -155,6 +161,36 @@ impl StorageMaintenanceStorage for SqliteStorage {
         SqliteStorage::get_storage_stats_summary(self).map_err(Into::into)
     }
 
+    fn storage_report(
+        &self,
+        frames_dir: Option<&Path>,
+        frames_dir_bytes: u64,
+    ) -> Result<StorageReport, CoreError> {
+        SqliteStorage::storage_report(self, frames_dir, frames_dir_bytes).map_err(Into::into)
+    }
+
+    fn check_integrity(&self) -> Result<IntegrityCheckRecord, CoreError> {
+        SqliteStorage::integrity_check(self).map_err(Into::into)
+    }
+
+    fn create_database_backup(&self, keep: usize) -> Result<DatabaseBackupRecord, CoreError> {
+        SqliteStorage::create_database_backup(self, keep).map_err(Into::into)
+    }
+
+    fn mark_frame_file_corrupt(&self, frame_id: i64) -> Result<(), CoreError> {
+        SqliteStorage::mark_frame_file_corrupt(self, frame_id)
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    fn soft_delete_frame(&self, frame_id: i64) -> Result<bool, CoreError> {
+        SqliteStorage::soft_delete_frame(self, frame_id).map_err(Into::into)
+    }
+
+    fn restore_frame(&self, frame_id: i64) -> Result<bool, CoreError> {
+        SqliteStorage::restore_frame(self, frame_id).map_err(Into::into)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn delete_data_in_range(
         &self,
@@ -225,6 +261,27 @@ impl TagStorage for SqliteStorage {
     fn remove_tag_from_frame(&self, frame_id: i64, tag_id: i64) -> Result<bool, CoreError> {
         SqliteStorage::remove_tag_from_frame(self, frame_id, tag_id).map_err(Into::into)
     }
+
+    fn get_tag_rules(&self) -> Result<Vec<TagRuleRecord>, CoreError> {
+        SqliteStorage::get_tag_rules(self).map_err(Into::into)
+    }
+
+    fn create_tag_rule(&self, app_pattern: &str, tag_id: i64) -> Result<TagRuleRecord, CoreError> {
+        SqliteStorage::create_tag_rule(self, app_pattern, tag_id).map_err(Into::into)
+    }
+
+    fn update_tag_rule(
+        &self,
+        rule_id: i64,
+        app_pattern: &str,
+        tag_id: i64,
+    ) -> Result<bool, CoreError> {
+        SqliteStorage::update_tag_rule(self, rule_id, app_pattern, tag_id).map_err(Into::into)
+    }
+
+    fn delete_tag_rule(&self, rule_id: i64) -> Result<bool, CoreError> {
+        SqliteStorage::delete_tag_rule(self, rule_id).map_err(Into::into)
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -331,15 +388,15 @@ impl DigestStorage for SqliteStorage {
         &self,
         limit: usize,
     ) -> Result<Vec<oneshim_core::models::weekly_digest::WeeklyDigest>, CoreError> {
-        let guard = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let guard = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
         let mut stmt = guard
             .prepare(
                 "SELECT stats_json, comparison_json, llm_narrative FROM weekly_digests ORDER BY week_start DESC LIMIT ?1",
             )
-            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("Failed to prepare weekly_digests query: {e}") })?;
+            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("Failed to prepare weekly_digests query: {e}") })?;
         let digests: Vec<oneshim_core::models::weekly_digest::WeeklyDigest> = stmt
             .query_map(rusqlite::params![limit as i64], |row| {
                 let stats_json: String = row.get(0)?;
@@ -348,7 +405,7 @@ impl DigestStorage for SqliteStorage {
                 Ok((stats_json, comparison_json, llm_narrative))
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to query weekly_digests: {e}"),
             })?
             .filter_map(|r| r.ok())
@@ -377,12 +434,12 @@ impl DigestStorage for SqliteStorage {
         &self,
         digest: &oneshim_core::models::weekly_digest::WeeklyDigest,
     ) -> Result<(), CoreError> {
-        let guard = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let guard = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
         let stats_json = serde_json::to_string(digest).map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
+            code: oneshim_core::error_codes::StorageCode::Serialization,
             message: format!("Failed to serialize digest: {e}"),
         })?;
         let comparison_json = digest
@@ -398,14 +455,14 @@ impl DigestStorage for SqliteStorage {
                  VALUES (?1, ?2, ?3, ?4, ?5)",
                 rusqlite::params![week_start, week_end, stats_json, comparison_json, digest.llm_narrative],
             )
-            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("Failed to save weekly digest: {e}") })?;
+            .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("Failed to save weekly digest: {e}") })?;
         Ok(())
     }
 
     fn save_daily_digest(&self, digest: &DailyDigest) -> Result<(), CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
 
         let date_str = digest.date.to_string(); // YYYY-MM-DD
@@ -415,17 +472,17 @@ impl DigestStorage for SqliteStorage {
             .map(serde_json::to_string)
             .transpose()
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Serialization,
                 message: format!("Failed to serialize insight: {e}"),
             })?;
         let timeline_json =
             serde_json::to_string(&digest.timeline).map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Serialization,
                 message: format!("Failed to serialize timeline: {e}"),
             })?;
         let statistics_json =
             serde_json::to_string(&digest.statistics).map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Serialization,
                 message: format!("Failed to serialize statistics: {e}"),
             })?;
         let generated_at = digest.generated_at.to_rfc3339();
@@ -435,15 +492,15 @@ impl DigestStorage for SqliteStorage {
              VALUES (?1, ?2, ?3, ?4, ?5)",
             rusqlite::params![date_str, insight_json, timeline_json, statistics_json, generated_at],
         )
-        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("Failed to save daily digest: {e}") })?;
+        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("Failed to save daily digest: {e}") })?;
 
         Ok(())
     }
 
     fn get_daily_digest(&self, date: &str) -> Result<Option<DailyDigest>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
 
         let result = conn.query_row(
@@ -479,16 +536,16 @@ impl DigestStorage for SqliteStorage {
             }
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to get daily digest: {e}"),
             }),
         }
     }
 
     fn list_daily_digests(&self, limit: usize) -> Result<Vec<DailyDigest>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
 
         let mut stmt = conn
@@ -497,7 +554,7 @@ impl DigestStorage for SqliteStorage {
                  FROM daily_digests ORDER BY date DESC LIMIT ?1",
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to prepare daily_digests query: {e}"),
             })?;
 
@@ -517,7 +574,7 @@ impl DigestStorage for SqliteStorage {
                 ))
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to query daily_digests: {e}"),
             })?
             .filter_map(|r| r.ok())
@@ -539,9 +596,9 @@ impl DigestStorage for SqliteStorage {
     }
 
     fn get_segments_for_date(&self, date: &str) -> Result<Vec<SegmentSummaryRecord>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
 
         // Check if the activity_segments table exists
@@ -571,7 +628,7 @@ impl DigestStorage for SqliteStorage {
                  ORDER BY start_time ASC",
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to prepare segments query: {e}"),
             })?;
 
@@ -595,7 +652,7 @@ impl DigestStorage for SqliteStorage {
                 })
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to query segments: {e}"),
             })?
             .filter_map(|r| r.ok())
@@ -646,6 +703,10 @@ impl BackupStorage for SqliteStorage {
         SqliteStorage::list_hourly_metrics_since(self, from).map_err(Into::into)
     }
 
+    fn list_daily_metrics_since(&self, from: &str) -> Result<Vec<DailyMetricsRecord>, CoreError> {
+        SqliteStorage::list_daily_metrics_since(self, from).map_err(Into::into)
+    }
+
     fn upsert_backup_tag(
         &self,
         id: i64,
@@ -725,9 +786,9 @@ impl SegmentQueryStorage for SqliteStorage {
         if segment_ids.is_empty() {
             return Ok(std::collections::HashMap::new());
         }
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
 
         // Check if the activity_segments table exists
@@ -780,9 +841,9 @@ impl GuiInteractionStorage for SqliteStorage {
         let scrubbed_text = input.element_text.map(scrub_basic_pii);
         let scrubbed_ref = scrubbed_text.as_deref();
 
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
         conn.execute(
             "INSERT INTO gui_interactions (event_id, segment_id, timestamp, element_text, element_type, interaction_type, bbox_json, app_name, type_confidence)
@@ -799,7 +860,7 @@ impl GuiInteractionStorage for SqliteStorage {
                 input.type_confidence,
             ],
         )
-        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Failed, message: format!("Failed to save GUI interaction: {e}") })?;
+        .map_err(|e| CoreError::Storage { code: oneshim_core::error_codes::StorageCode::Database, message: format!("Failed to save GUI interaction: {e}") })?;
         Ok(())
     }
 
@@ -807,9 +868,9 @@ impl GuiInteractionStorage for SqliteStorage {
         &self,
         segment_id: &str,
     ) -> Result<Vec<GuiInteractionRecord>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
         let mut stmt = conn
             .prepare(
@@ -820,7 +881,7 @@ impl GuiInteractionStorage for SqliteStorage {
                  ORDER BY timestamp ASC",
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to prepare GUI interaction query: {e}"),
             })?;
 
@@ -841,7 +902,7 @@ impl GuiInteractionStorage for SqliteStorage {
                 })
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to query GUI interactions: {e}"),
             })?
             .filter_map(|r| r.ok())
@@ -855,9 +916,9 @@ impl GuiInteractionStorage for SqliteStorage {
         start: &str,
         end: &str,
     ) -> Result<Vec<(String, u32)>, CoreError> {
-        let conn = self.conn.lock().map_err(|e| CoreError::Storage {
-            code: oneshim_core::error_codes::StorageCode::Failed,
-            message: format!("SQLite lock poisoned: {e}"),
+        let conn = self.pool.get().map_err(|e| CoreError::Storage {
+            code: oneshim_core::error_codes::StorageCode::Lock,
+            message: format!("SQLite connection unavailable: {e}"),
         })?;
         let mut stmt = conn
             .prepare(
@@ -868,7 +929,7 @@ impl GuiInteractionStorage for SqliteStorage {
                  ORDER BY hour",
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("Failed to prepare GUI interaction density query: {e}"),
             })?;
 
@@ -877,7 +938,7 @@ impl GuiInteractionStorage for SqliteStorage {
                 Ok((row.get::<_, String>(0)?, row.get::<_, u32>(1)?))
             })
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("GUI interaction density query failed: {e}"),
             })?
             .filter_map(|r| r.ok())
@@ -0,0 +1,224 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+use oneshim_core::error::CoreError;
+use oneshim_core::error_codes::StorageCode;
+use oneshim_core::models::automation::WorkflowExecutionRecord;
+use oneshim_core::ports::workflow_history::WorkflowHistoryStorage;
+
+use super::SqliteStorage;
+
+/// Fixed-width UTC timestamps so `started_at` compares correctly as text.
+fn timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+fn storage_err(stage: &str, e: impl std::fmt::Display) -> CoreError {
+    CoreError::Storage {
+        code: StorageCode::Database,
+        message: format!("{stage}: {e}"),
+    }
+}
+
+impl WorkflowHistoryStorage for SqliteStorage {
+    /// Insert (or overwrite) a run in the `workflow_executions` table.
+    fn save_workflow_execution(&self, record: &WorkflowExecutionRecord) -> Result<(), CoreError> {
+        let preset_json = serde_json::to_string(&record.preset)
+            .map_err(|e| storage_err("serialize preset", e))?;
+        let result_json = serde_json::to_string(&record.result)
+            .map_err(|e| storage_err("serialize result", e))?;
+
+        let conn = self.pool.get().map_err(|e| storage_err("connection", e))?;
+        conn.execute(
+            "INSERT OR REPLACE INTO workflow_executions
+             (execution_id, preset_id, started_at, success, steps_executed, total_steps,
+              total_elapsed_ms, preset_json, result_json, replay_of)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)",
+            rusqlite::params![
+                record.execution_id,
+                record.result.preset_id,
+                timestamp(record.started_at),
+                record.result.success,
+                record.result.steps_executed as i64,
+                record.result.total_steps as i64,
+                record.result.total_elapsed_ms as i64,
+                preset_json,
+                result_json,
+                record.replay_of,
+            ],
+        )
+        .map_err(|e| storage_err("insert", e))?;
+
+        Ok(())
+    }
+
+    /// Runs started within `[from, to]`, newest first.
+    fn get_workflow_history(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<WorkflowExecutionRecord>, CoreError> {
+        let conn = self.pool.get().map_err(|e| storage_err("connection", e))?;
+        let mut stmt = conn
+            .prepare(
+                "SELECT execution_id, started_at, preset_json, result_json, replay_of
+                 FROM workflow_executions
+                 WHERE started_at >= ?1 AND started_at <= ?2
+                 ORDER BY started_at DESC",
+            )
+            .map_err(|e| storage_err("prepare", e))?;
+
+        let rows = stmt
+            .query_map(rusqlite::params![timestamp(from), timestamp(to)], |row| {
+                Ok(ExecutionRow {
+                    execution_id: row.get(0)?,
+                    started_at: row.get(1)?,
+                    preset_json: row.get(2)?,
+                    result_json: row.get(3)?,
+                    replay_of: row.get(4)?,
+                })
+            })
+            .map_err(|e| storage_err("query", e))?;
+
+        let mut result = Vec::new();
+        for row in rows {
+            let row = row.map_err(|e| storage_err("row", e))?;
+            result.push(row.into_record()?);
+        }
+        Ok(result)
+    }
+
+    /// Look up one run by execution id.
+    fn get_workflow_execution(
+        &self,
+        execution_id: &str,
+    ) -> Result<Option<WorkflowExecutionRecord>, CoreError> {
+        let conn = self.pool.get().map_err(|e| storage_err("connection", e))?;
+        let row = conn.query_row(
+            "SELECT execution_id, started_at, preset_json, result_json, replay_of
+             FROM workflow_executions
+             WHERE execution_id = ?1",
+            [execution_id],
+            |row| {
+                Ok(ExecutionRow {
+                    execution_id: row.get(0)?,
+                    started_at: row.get(1)?,
+                    preset_json: row.get(2)?,
+                    result_json: row.get(3)?,
+                    replay_of: row.get(4)?,
+                })
+            },
+        );
+
+        match row {
+            Ok(row) => row.into_record().map(Some),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(storage_err("query", e)),
+        }
+    }
+}
+
+/// Internal helper struct for reading execution rows from SQLite.
+struct ExecutionRow {
+    execution_id: String,
+    started_at: String,
+    preset_json: String,
+    result_json: String,
+    replay_of: Option<String>,
+}
+
+impl ExecutionRow {
+    fn into_record(self) -> Result<WorkflowExecutionRecord, CoreError> {
+        let started_at = DateTime::parse_from_rfc3339(&self.started_at)
+            .map(|dt| dt.with_timezone(&Utc))
+            .map_err(|e| storage_err("parse started_at", e))?;
+
+        Ok(WorkflowExecutionRecord {
+            execution_id: self.execution_id,
+            started_at,
+            preset: serde_json::from_str(&self.preset_json)
+                .map_err(|e| storage_err("parse preset_json", e))?,
+            result: serde_json::from_str(&self.result_json)
+                .map_err(|e| storage_err("parse result_json", e))?,
+            replay_of: self.replay_of,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+    use oneshim_core::models::automation::{WorkflowResult, WorkflowStepResult};
+    use oneshim_core::models::intent::{
+        AutomationIntent, PresetCategory, WorkflowPreset, WorkflowStep,
+    };
+
+    fn record(id: &str, started_at: DateTime<Utc>) -> WorkflowExecutionRecord {
+        WorkflowExecutionRecord {
+            execution_id: id.to_string(),
+            started_at,
+            preset: WorkflowPreset {
+                id: "save-file".to_string(),
+                name: "Save".to_string(),
+                description: String::new(),
+                category: PresetCategory::Custom,
+                steps: vec![WorkflowStep {
+                    name: "Save".to_string(),
+                    intent: AutomationIntent::ExecuteHotkey {
+                        keys: vec!["Ctrl".to_string(), "S".to_string()],
+                    },
+                    delay_ms: 0,
+                    stop_on_failure: true,
+                }],
+                builtin: false,
+                platform: None,
+                ai_profile_id: None,
+            },
+            result: WorkflowResult {
+                preset_id: "save-file".to_string(),
+                success: true,
+                steps_executed: 1,
+                total_steps: 1,
+                total_elapsed_ms: 12,
+                step_results: vec![WorkflowStepResult {
+                    step_name: "Save".to_string(),
+                    step_index: 0,
+                    success: true,
+                    elapsed_ms: 12,
+                    error: None,
+                    before_frame_id: None,
+                    after_frame_id: None,
+                }],
+                message: "done".to_string(),
+            },
+            replay_of: None,
+        }
+    }
+
+    #[test]
+    fn workflow_execution_roundtrip_and_window() {
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let now = Utc::now();
+        storage
+            .save_workflow_execution(&record("old", now - Duration::days(3)))
+            .unwrap();
+        storage
+            .save_workflow_execution(&record("recent", now - Duration::minutes(5)))
+            .unwrap();
+        let mut replay = record("replay", now);
+        replay.replay_of = Some("recent".to_string());
+        storage.save_workflow_execution(&replay).unwrap();
+
+        let history = storage
+            .get_workflow_history(now - Duration::hours(1), now)
+            .unwrap();
+        let ids: Vec<_> = history.iter().map(|r| r.execution_id.as_str()).collect();
+        assert_eq!(ids, ["replay", "recent"]);
+        assert_eq!(history[0].replay_of.as_deref(), Some("recent"));
+        assert_eq!(history[1].preset.steps.len(), 1);
+        assert_eq!(history[1].result.step_results[0].step_name, "Save");
+
+        let fetched = storage.get_workflow_execution("old").unwrap().unwrap();
+        assert_eq!(fetched.result.total_elapsed_ms, 12);
+        assert!(storage.get_workflow_execution("missing").unwrap().is_none());
+    }
+}
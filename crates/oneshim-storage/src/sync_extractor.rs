@@ -6,10 +6,10 @@
 
 use async_trait::async_trait;
 use rusqlite::Connection;
-use std::sync::{Arc, Mutex};
 use tracing::debug;
 
 use crate::error::StorageError;
+use crate::sqlite::SqlitePool;
 use oneshim_core::config::SyncConfig;
 use oneshim_core::error::CoreError;
 use oneshim_core::models::sync::{ChangeSet, ChangeSetKind};
@@ -18,7 +18,7 @@ use oneshim_core::sync::Hlc;
 
 /// SQLite-backed ChangeExtractor adapter.
 pub struct SqliteSyncExtractor {
-    conn: Arc<Mutex<Connection>>,
+    pool: SqlitePool,
     device_id: String,
     device_name: String,
     sync_config: SyncConfig,
@@ -26,13 +26,13 @@ pub struct SqliteSyncExtractor {
 
 impl SqliteSyncExtractor {
     pub fn new(
-        conn: Arc<Mutex<Connection>>,
+        pool: SqlitePool,
         device_id: String,
         device_name: String,
         sync_config: SyncConfig,
     ) -> Self {
         Self {
-            conn,
+            pool,
             device_id,
             device_name,
             sync_config,
@@ -57,7 +57,7 @@ impl SqliteSyncExtractor {
             let updated = conn
                 .execute(&sql, rusqlite::params![device_id])
                 .map_err(|e| {
-                    StorageError::Internal(format!("backfill origin_device_id on {table}: {e}"))
+                    StorageError::Database(format!("backfill origin_device_id on {table}: {e}"))
                 })?;
             total += updated as u64;
         }
@@ -83,7 +83,7 @@ impl SqliteSyncExtractor {
         );
         let mut stmt = conn
             .prepare(&sql)
-            .map_err(|e| StorageError::Internal(format!("prepare query for {table}: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("prepare query for {table}: {e}")))?;
 
         let rows = stmt
             .query_map(
@@ -93,14 +93,14 @@ impl SqliteSyncExtractor {
                     Ok(json_str)
                 },
             )
-            .map_err(|e| StorageError::Internal(format!("query {table}: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("query {table}: {e}")))?;
 
         let mut results = Vec::new();
         for row in rows {
             let json_str =
-                row.map_err(|e| StorageError::Internal(format!("row read {table}: {e}")))?;
+                row.map_err(|e| StorageError::Database(format!("row read {table}: {e}")))?;
             let value: serde_json::Value = serde_json::from_str(&json_str)
-                .map_err(|e| StorageError::Internal(format!("json parse {table}: {e}")))?;
+                .map_err(|e| StorageError::Serialization(format!("json parse {table}: {e}")))?;
             results.push(value);
         }
         Ok(results)
@@ -128,7 +128,7 @@ impl SqliteSyncExtractor {
             );
             let (wall_ms, counter): (u64, u32) = conn
                 .query_row(&sql, [], |row| Ok((row.get(0)?, row.get(1)?)))
-                .map_err(|e| StorageError::Internal(format!("max HLC query on {table}: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("max HLC query on {table}: {e}")))?;
 
             let candidate = Hlc {
                 wall_ms,
@@ -146,7 +146,7 @@ impl SqliteSyncExtractor {
 #[async_trait]
 impl ChangeExtractor for SqliteSyncExtractor {
     async fn get_changes_since(&self, since: &Hlc) -> Result<ChangeSet, CoreError> {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         let since = since.clone();
         let device_id = self.device_id.clone();
         let device_name = self.device_name.clone();
@@ -154,9 +154,9 @@ impl ChangeExtractor for SqliteSyncExtractor {
         let include_embed_text = self.sync_config.include_embedding_text;
 
         tokio::task::spawn_blocking(move || {
-            let guard = conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            let guard = pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
 
             // Backfill on first extraction
             Self::backfill_origin_device_id(&guard, &device_id)?;
@@ -281,13 +281,13 @@ impl ChangeExtractor for SqliteSyncExtractor {
     }
 
     async fn local_watermark(&self) -> Result<Hlc, CoreError> {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         let device_id = self.device_id.clone();
 
         tokio::task::spawn_blocking(move || {
-            let guard = conn
-                .lock()
-                .map_err(|e| StorageError::Internal(format!("SQLite lock poisoned: {e}")))?;
+            let guard = pool
+                .get()
+                .map_err(|e| StorageError::Lock(format!("SQLite connection unavailable: {e}")))?;
             Self::compute_max_hlc(&guard, &device_id)
         })
         .await
@@ -314,7 +314,7 @@ mod tests {
     async fn empty_db_returns_empty_changeset() {
         let (storage, device_id) = setup();
         let extractor = SqliteSyncExtractor::new(
-            storage.connection_arc(),
+            storage.connection_pool(),
             device_id,
             "Test".to_string(),
             SyncConfig::default(),
@@ -328,7 +328,7 @@ mod tests {
     async fn local_watermark_returns_default_on_empty_db() {
         let (storage, device_id) = setup();
         let extractor = SqliteSyncExtractor::new(
-            storage.connection_arc(),
+            storage.connection_pool(),
             device_id,
             "Test".to_string(),
             SyncConfig::default(),
@@ -343,8 +343,8 @@ mod tests {
         let (storage, device_id) = setup();
         // Insert a segment with empty origin_device_id (simulating pre-V14 data)
         {
-            let conn = storage.connection_arc();
-            let guard = conn.lock().unwrap();
+            let pool = storage.connection_pool();
+            let guard = pool.get().unwrap();
             guard
                 .execute(
                     "INSERT INTO activity_segments \
@@ -358,7 +358,7 @@ mod tests {
         }
 
         let extractor = SqliteSyncExtractor::new(
-            storage.connection_arc(),
+            storage.connection_pool(),
             device_id.clone(),
             "Test".to_string(),
             SyncConfig::default(),
@@ -367,8 +367,8 @@ mod tests {
         assert_eq!(cs.segments.len(), 1);
 
         // Verify backfill happened
-        let conn = storage.connection_arc();
-        let guard = conn.lock().unwrap();
+        let pool = storage.connection_pool();
+        let guard = pool.get().unwrap();
         let origin: String = guard
             .query_row(
                 "SELECT origin_device_id FROM activity_segments WHERE id = 'seg-1'",
@@ -383,8 +383,8 @@ mod tests {
     async fn watermark_filters_old_rows() {
         let (storage, device_id) = setup();
         {
-            let conn = storage.connection_arc();
-            let guard = conn.lock().unwrap();
+            let pool = storage.connection_pool();
+            let guard = pool.get().unwrap();
             // Row with HLC (100, 1)
             guard
                 .execute(
@@ -410,7 +410,7 @@ mod tests {
         }
 
         let extractor = SqliteSyncExtractor::new(
-            storage.connection_arc(),
+            storage.connection_pool(),
             device_id,
             "Test".to_string(),
             SyncConfig::default(),
@@ -8,10 +8,10 @@
 
 use async_trait::async_trait;
 use rusqlite::Connection;
-use std::sync::{Arc, Mutex};
 use tracing::{debug, info, warn};
 
 use crate::error::StorageError;
+use crate::sqlite::SqlitePool;
 use oneshim_core::error::CoreError;
 use oneshim_core::models::sync::{ChangeSet, ChangeSetKind, SyncResult};
 use oneshim_core::ports::change_merger::ChangeMerger;
@@ -19,14 +19,14 @@ use oneshim_core::sync::Hlc;
 
 /// SQLite-backed ChangeMerger adapter.
 pub struct SqliteSyncMerger {
-    conn: Arc<Mutex<Connection>>,
+    pool: SqlitePool,
     local_device_id: String,
 }
 
 impl SqliteSyncMerger {
-    pub fn new(conn: Arc<Mutex<Connection>>, local_device_id: String) -> Self {
+    pub fn new(pool: SqlitePool, local_device_id: String) -> Self {
         Self {
-            conn,
+            pool,
             local_device_id,
         }
     }
@@ -64,7 +64,7 @@ impl SqliteSyncMerger {
             let sql = format!("DELETE FROM {table} WHERE origin_device_id = ?1");
             let deleted = conn
                 .execute(&sql, rusqlite::params![origin_device_id])
-                .map_err(|e| StorageError::Internal(format!("GDPR deletion on {table}: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("GDPR deletion on {table}: {e}")))?;
             total_deleted += deleted;
         }
         info!(
@@ -79,13 +79,13 @@ impl SqliteSyncMerger {
 #[async_trait]
 impl ChangeMerger for SqliteSyncMerger {
     async fn apply_changes(&self, changes: ChangeSet) -> Result<SyncResult, CoreError> {
-        let conn = self.conn.clone();
+        let pool = self.pool.clone();
         let local_device_id = self.local_device_id.clone();
 
         tokio::task::spawn_blocking(move || {
-            let mut guard = conn.lock().map_err(|e| CoreError::Internal {
-                code: oneshim_core::error_codes::InternalCode::Generic,
-                message: format!("SQLite lock poisoned: {e}"),
+            let mut guard = pool.get().map_err(|e| CoreError::Storage {
+                code: oneshim_core::error_codes::StorageCode::Lock,
+                message: format!("SQLite connection unavailable: {e}"),
             })?;
 
             // Handle GDPR deletion event
@@ -111,7 +111,7 @@ impl ChangeMerger for SqliteSyncMerger {
 
             // All merge operations run inside a single transaction
             let tx = guard.transaction().map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("begin transaction: {e}"),
             })?;
 
@@ -157,12 +157,12 @@ impl ChangeMerger for SqliteSyncMerger {
                 ],
             )
             .map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("update sync_peers: {e}"),
             })?;
 
             tx.commit().map_err(|e| CoreError::Storage {
-                code: oneshim_core::error_codes::StorageCode::Failed,
+                code: oneshim_core::error_codes::StorageCode::Database,
                 message: format!("commit transaction: {e}"),
             })?;
 
@@ -200,7 +200,7 @@ fn merge_segment(
             rusqlite::params![id],
             |r| r.get(0),
         )
-        .map_err(|e| StorageError::Internal(format!("check segment: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("check segment: {e}")))?;
 
     if exists {
         result.skipped_dup += 1;
@@ -228,7 +228,7 @@ fn merge_segment(
             json_str(row, "origin_device_id")?,
         ],
     )
-    .map_err(|e| StorageError::Internal(format!("insert segment: {e}")))?;
+    .map_err(|e| StorageError::Database(format!("insert segment: {e}")))?;
 
     result.applied += 1;
     Ok(())
@@ -277,7 +277,7 @@ fn merge_regime(
                     json_str(row, "origin_device_id")?,
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("insert regime: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("insert regime: {e}")))?;
             result.applied += 1;
         }
         Some((lw, lc, ld)) => {
@@ -320,7 +320,7 @@ fn merge_regime(
                         json_str(row, "origin_device_id")?,
                     ],
                 )
-                .map_err(|e| StorageError::Internal(format!("update regime: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("update regime: {e}")))?;
 
                 let is_tombstone = json_i64_or_default(row, "is_deleted", 0) == 1;
                 if is_tombstone {
@@ -354,7 +354,7 @@ fn merge_override(
             rusqlite::params![id],
             |r| r.get(0),
         )
-        .map_err(|e| StorageError::Internal(format!("check override: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("check override: {e}")))?;
 
     if exists {
         result.skipped_dup += 1;
@@ -378,7 +378,7 @@ fn merge_override(
             json_str(row, "origin_device_id")?,
         ],
     )
-    .map_err(|e| StorageError::Internal(format!("insert override: {e}")))?;
+    .map_err(|e| StorageError::Database(format!("insert override: {e}")))?;
     result.applied += 1;
     Ok(())
 }
@@ -430,7 +430,7 @@ fn merge_embedding(
                     json_str(row, "origin_device_id")?,
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("insert embedding: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("insert embedding: {e}")))?;
             result.applied += 1;
         }
         Some((local_id, lw, lc, ld)) => {
@@ -473,7 +473,7 @@ fn merge_embedding(
                         json_str(row, "origin_device_id")?,
                     ],
                 )
-                .map_err(|e| StorageError::Internal(format!("update embedding: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("update embedding: {e}")))?;
 
                 let is_tombstone = json_i64_or_default(row, "is_deleted", 0) == 1;
                 if is_tombstone {
@@ -557,7 +557,7 @@ fn merge_suggestion(
                     json_str(row, "origin_device_id")?,
                 ],
             )
-            .map_err(|e| StorageError::Internal(format!("insert suggestion: {e}")))?;
+            .map_err(|e| StorageError::Database(format!("insert suggestion: {e}")))?;
             result.applied += 1;
         }
         Some((lw, lc, ld, shown, dismissed, acted)) => {
@@ -615,7 +615,7 @@ fn merge_suggestion(
                         json_str(row, "origin_device_id")?,
                     ],
                 )
-                .map_err(|e| StorageError::Internal(format!("update suggestion: {e}")))?;
+                .map_err(|e| StorageError::Database(format!("update suggestion: {e}")))?;
                 result.applied += 1;
             } else {
                 result.skipped_lww += 1;
@@ -637,7 +637,7 @@ fn merge_param_snapshot(
             rusqlite::params![id],
             |r| r.get(0),
         )
-        .map_err(|e| StorageError::Internal(format!("check param_snapshot: {e}")))?;
+        .map_err(|e| StorageError::Database(format!("check param_snapshot: {e}")))?;
 
     if exists {
         result.skipped_dup += 1;
@@ -658,7 +658,7 @@ fn merge_param_snapshot(
             json_str(row, "origin_device_id")?,
         ],
     )
-    .map_err(|e| StorageError::Internal(format!("insert param_snapshot: {e}")))?;
+    .map_err(|e| StorageError::Database(format!("insert param_snapshot: {e}")))?;
     result.applied += 1;
     Ok(())
 }
@@ -727,7 +727,7 @@ mod tests {
     #[tokio::test]
     async fn empty_changeset_returns_zero_counts() {
         let (storage, device_id) = setup();
-        let merger = SqliteSyncMerger::new(storage.connection_arc(), device_id);
+        let merger = SqliteSyncMerger::new(storage.connection_pool(), device_id);
         let cs = ChangeSet {
             origin_device_id: "remote-dev".to_string(),
             origin_device_name: "Remote".to_string(),
@@ -742,7 +742,7 @@ mod tests {
     #[tokio::test]
     async fn self_originated_changeset_is_skipped() {
         let (storage, device_id) = setup();
-        let merger = SqliteSyncMerger::new(storage.connection_arc(), device_id.clone());
+        let merger = SqliteSyncMerger::new(storage.connection_pool(), device_id.clone());
         let cs = ChangeSet {
             origin_device_id: device_id,
             origin_device_name: "Local".to_string(),
@@ -760,8 +760,8 @@ mod tests {
 
         // Insert a segment from the remote device
         {
-            let conn = storage.connection_arc();
-            let guard = conn.lock().unwrap();
+            let pool = storage.connection_pool();
+            let guard = pool.get().unwrap();
             guard
                 .execute(
                     "INSERT INTO activity_segments \
@@ -774,7 +774,7 @@ mod tests {
                 .unwrap();
         }
 
-        let merger = SqliteSyncMerger::new(storage.connection_arc(), local_id);
+        let merger = SqliteSyncMerger::new(storage.connection_pool(), local_id);
         let cs = ChangeSet {
             kind: ChangeSetKind::DeletionEvent,
             origin_device_id: remote_id.to_string(),
@@ -785,8 +785,8 @@ mod tests {
         assert!(result.tombstoned > 0);
 
         // Verify row is gone
-        let conn = storage.connection_arc();
-        let guard = conn.lock().unwrap();
+        let pool = storage.connection_pool();
+        let guard = pool.get().unwrap();
         let count: i64 = guard
             .query_row(
                 "SELECT COUNT(*) FROM activity_segments WHERE id = 'seg-r1'",
@@ -803,8 +803,8 @@ mod tests {
 
         // Insert a local suggestion at status "dismissed"
         {
-            let conn = storage.connection_arc();
-            let guard = conn.lock().unwrap();
+            let pool = storage.connection_pool();
+            let guard = pool.get().unwrap();
             guard
                 .execute(
                     "INSERT INTO suggestions \
@@ -820,7 +820,7 @@ mod tests {
                 .unwrap();
         }
 
-        let merger = SqliteSyncMerger::new(storage.connection_arc(), local_id);
+        let merger = SqliteSyncMerger::new(storage.connection_pool(), local_id);
 
         // Remote has same suggestion at status "acted" with LOWER HLC
         // Monotonic merge should still pick "acted" because acted(3) > dismissed(2)
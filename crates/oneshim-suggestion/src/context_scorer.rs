@@ -0,0 +1,171 @@
+use async_trait::async_trait;
+use oneshim_core::config::SuggestionRescoringConfig;
+use oneshim_core::models::suggestion::SuggestionType;
+use oneshim_core::models::work_session::AppCategory;
+
+/// Snapshot of local activity used to re-score a suggestion on arrival.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LocalContext {
+    pub current_category: Option<AppCategory>,
+    /// Seconds spent in the current app.
+    pub current_app_secs: u64,
+    /// Uninterrupted deep-work seconds, including the current app.
+    pub continuous_deep_work_secs: u64,
+}
+
+/// Supplies the current [`LocalContext`]. Implemented by the focus tracker.
+#[async_trait]
+pub trait LocalContextSource: Send + Sync {
+    async fn local_context(&self) -> LocalContext;
+}
+
+/// Adjusts server-assigned relevance using local focus data, so the same
+/// suggestion is more urgent after hours in an editor than right after a
+/// context switch.
+pub struct ContextScorer {
+    config: SuggestionRescoringConfig,
+}
+
+impl ContextScorer {
+    pub fn new(config: SuggestionRescoringConfig) -> Self {
+        Self { config }
+    }
+
+    /// Additive relevance adjustment for `suggestion_type` under `context`.
+    /// Returns 0.0 when re-scoring is disabled.
+    pub fn score(&self, suggestion_type: &SuggestionType, context: &LocalContext) -> f64 {
+        if !self.config.enabled {
+            return 0.0;
+        }
+
+        let in_deep_work = context
+            .current_category
+            .is_some_and(|category| category.is_deep_work());
+        let communicating = context
+            .current_category
+            .is_some_and(|category| category.is_communication());
+        let dwell = if in_deep_work {
+            self.dwell_factor(context.continuous_deep_work_secs)
+        } else {
+            0.0
+        };
+
+        match suggestion_type {
+            SuggestionType::WorkGuidance
+            | SuggestionType::WorkflowOptimization
+            | SuggestionType::ProductivityTip => self.config.dwell_weight * dwell,
+            SuggestionType::EmailDraft | SuggestionType::ContextBased => {
+                if communicating {
+                    self.config.category_match_weight
+                } else {
+                    -self.config.interruption_penalty * dwell
+                }
+            }
+        }
+    }
+
+    /// Apply [`Self::score`] to `relevance` in place, clamped to [0, 1].
+    pub fn adjust(
+        &self,
+        suggestion_type: &SuggestionType,
+        context: &LocalContext,
+        relevance: &mut f64,
+    ) {
+        let delta = self.score(suggestion_type, context);
+        *relevance = (*relevance + delta).clamp(0.0, 1.0);
+    }
+
+    fn dwell_factor(&self, deep_work_secs: u64) -> f64 {
+        let saturation_secs = self.config.dwell_saturation_mins.max(1) * 60;
+        (deep_work_secs as f64 / saturation_secs as f64).min(1.0)
+    }
+}
+
+impl Default for ContextScorer {
+    fn default() -> Self {
+        Self::new(SuggestionRescoringConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn editor_for(secs: u64) -> LocalContext {
+        LocalContext {
+            current_category: Some(AppCategory::Development),
+            current_app_secs: secs,
+            continuous_deep_work_secs: secs,
+        }
+    }
+
+    #[test]
+    fn long_editor_session_raises_work_guidance() {
+        let scorer = ContextScorer::default();
+        let fresh = scorer.score(&SuggestionType::WorkGuidance, &editor_for(60));
+        let long = scorer.score(&SuggestionType::WorkGuidance, &editor_for(3 * 3600));
+
+        assert!(long > fresh);
+        assert!((long - 0.2).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn identical_suggestions_get_different_effective_scores() {
+        let scorer = ContextScorer::default();
+        let mut after_commit = 0.5;
+        let mut after_hours = 0.5;
+
+        scorer.adjust(
+            &SuggestionType::WorkGuidance,
+            &editor_for(0),
+            &mut after_commit,
+        );
+        scorer.adjust(
+            &SuggestionType::WorkGuidance,
+            &editor_for(2 * 3600),
+            &mut after_hours,
+        );
+
+        assert!((after_commit - 0.5).abs() < f64::EPSILON);
+        assert!((after_hours - 0.7).abs() < 1e-9);
+    }
+
+    #[test]
+    fn email_draft_is_penalised_during_deep_work() {
+        let scorer = ContextScorer::default();
+        let deep = scorer.score(&SuggestionType::EmailDraft, &editor_for(2 * 3600));
+        let chatting = scorer.score(
+            &SuggestionType::EmailDraft,
+            &LocalContext {
+                current_category: Some(AppCategory::Communication),
+                current_app_secs: 300,
+                continuous_deep_work_secs: 0,
+            },
+        );
+
+        assert!(deep < 0.0);
+        assert!(chatting > 0.0);
+    }
+
+    #[test]
+    fn disabled_rescoring_is_neutral() {
+        let scorer = ContextScorer::new(SuggestionRescoringConfig {
+            enabled: false,
+            ..SuggestionRescoringConfig::default()
+        });
+        let score = scorer.score(&SuggestionType::WorkGuidance, &editor_for(4 * 3600));
+        assert!((score - 0.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn adjust_clamps_to_unit_range() {
+        let scorer = ContextScorer::default();
+        let mut relevance = 0.95;
+        scorer.adjust(
+            &SuggestionType::ProductivityTip,
+            &editor_for(4 * 3600),
+            &mut relevance,
+        );
+        assert!((relevance - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -22,6 +22,7 @@
 pub mod error;
 pub use error::SuggestionError;
 
+pub mod context_scorer;
 pub mod feedback;
 pub mod history;
 pub mod presenter;
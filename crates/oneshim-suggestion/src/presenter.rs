@@ -1,3 +1,4 @@
+use oneshim_core::config::{SuggestionDeliveryChannel, SuggestionDeliveryConfig};
 use oneshim_core::models::suggestion::{Priority, Suggestion, SuggestionType};
 
 #[derive(Debug, Clone)]
@@ -31,6 +32,24 @@ pub fn present_all(suggestions: &[Suggestion]) -> Vec<SuggestionView> {
     suggestions.iter().map(present).collect()
 }
 
+/// Interrupting surfaces an accepted suggestion goes to. The dashboard queue
+/// always receives it and is not part of the plan.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DeliveryPlan {
+    pub notification: bool,
+    pub tray_badge: bool,
+    pub webhook: bool,
+}
+
+pub fn delivery_plan(suggestion: &Suggestion, config: &SuggestionDeliveryConfig) -> DeliveryPlan {
+    let priority = &suggestion.priority;
+    DeliveryPlan {
+        notification: config.delivers_to(priority, SuggestionDeliveryChannel::Notification),
+        tray_badge: config.delivers_to(priority, SuggestionDeliveryChannel::TrayBadge),
+        webhook: config.delivers_to(priority, SuggestionDeliveryChannel::Webhook),
+    }
+}
+
 pub fn type_to_title(st: &SuggestionType) -> String {
     match st {
         SuggestionType::WorkGuidance => "Work Guidance".to_string(),
@@ -131,6 +150,43 @@ mod tests {
         assert_eq!(priority_to_color(&Priority::Low), "#6B7280");
     }
 
+    #[test]
+    fn default_delivery_notifies_every_priority() {
+        let config = SuggestionDeliveryConfig::default();
+        let mut suggestion = make_suggestion();
+        for priority in [Priority::Critical, Priority::Low] {
+            suggestion.priority = priority;
+            let plan = delivery_plan(&suggestion, &config);
+            assert!(plan.notification && plan.tray_badge && !plan.webhook);
+        }
+    }
+
+    #[test]
+    fn delivery_plan_follows_priority_mapping() {
+        let config = SuggestionDeliveryConfig {
+            critical: vec![
+                SuggestionDeliveryChannel::Notification,
+                SuggestionDeliveryChannel::Webhook,
+            ],
+            low: vec![SuggestionDeliveryChannel::Dashboard],
+            ..Default::default()
+        };
+        let mut suggestion = make_suggestion();
+
+        suggestion.priority = Priority::Critical;
+        assert_eq!(
+            delivery_plan(&suggestion, &config),
+            DeliveryPlan {
+                notification: true,
+                tray_badge: false,
+                webhook: true,
+            }
+        );
+
+        suggestion.priority = Priority::Low;
+        assert_eq!(delivery_plan(&suggestion, &config), DeliveryPlan::default());
+    }
+
     #[test]
     fn type_titles() {
         assert_eq!(type_to_title(&SuggestionType::EmailDraft), "Email Draft");
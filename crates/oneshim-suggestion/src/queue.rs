@@ -1,3 +1,4 @@
+use oneshim_core::config::SuggestionOverflowPolicy;
 use oneshim_core::models::suggestion::Suggestion;
 use std::cmp::Ordering;
 use std::collections::BTreeSet;
@@ -56,6 +57,7 @@ pub struct SuggestionQueue {
     items: BTreeSet<PrioritizedSuggestion>,
     fingerprints: HashSet<u64>,
     max_size: usize,
+    overflow_policy: SuggestionOverflowPolicy,
 }
 
 impl SuggestionQueue {
@@ -64,9 +66,15 @@ impl SuggestionQueue {
             items: BTreeSet::new(),
             fingerprints: HashSet::new(),
             max_size,
+            overflow_policy: SuggestionOverflowPolicy::default(),
         }
     }
 
+    pub fn with_overflow_policy(mut self, policy: SuggestionOverflowPolicy) -> Self {
+        self.overflow_policy = policy;
+        self
+    }
+
     pub fn push(&mut self, suggestion: Suggestion) -> bool {
         let fp = content_fingerprint(&suggestion);
         if self.fingerprints.contains(&fp) {
@@ -80,37 +88,60 @@ impl SuggestionQueue {
         let item = PrioritizedSuggestion { suggestion };
 
         if self.items.len() >= self.max_size {
-            if let Some(last) = self.items.iter().next_back() {
-                if item < *last {
-                    let last_clone = last.clone();
-                    self.items.remove(&last_clone);
-                    self.fingerprints
-                        .remove(&content_fingerprint(&last_clone.suggestion));
-                    tracing::warn!(
-                        evicted_id = %last_clone.suggestion.suggestion_id,
-                        evicted_priority = ?last_clone.suggestion.priority,
-                        new_id = %item.suggestion.suggestion_id,
-                        new_priority = ?item.suggestion.priority,
-                        queue_size = self.max_size,
-                        "suggestion queue full — evicted lower-priority item"
-                    );
-                } else {
-                    tracing::warn!(
-                        rejected_id = %item.suggestion.suggestion_id,
-                        rejected_priority = ?item.suggestion.priority,
-                        queue_size = self.max_size,
-                        "suggestion queue full — rejected (priority too low)"
-                    );
-                    return false;
-                }
-            }
+            let Some(victim) = self.overflow_victim(&item) else {
+                tracing::warn!(
+                    rejected_id = %item.suggestion.suggestion_id,
+                    rejected_priority = ?item.suggestion.priority,
+                    queue_size = self.max_size,
+                    policy = ?self.overflow_policy,
+                    "suggestion queue full — rejected"
+                );
+                return false;
+            };
+            self.items.remove(&victim);
+            self.fingerprints
+                .remove(&content_fingerprint(&victim.suggestion));
+            tracing::warn!(
+                evicted_id = %victim.suggestion.suggestion_id,
+                evicted_priority = ?victim.suggestion.priority,
+                new_id = %item.suggestion.suggestion_id,
+                new_priority = ?item.suggestion.priority,
+                queue_size = self.max_size,
+                policy = ?self.overflow_policy,
+                "suggestion queue full — evicted item"
+            );
         }
 
         self.fingerprints.insert(fp);
         self.items.insert(item)
     }
 
+    /// Item to evict so `incoming` fits, or `None` to reject `incoming`.
+    fn overflow_victim(&self, incoming: &PrioritizedSuggestion) -> Option<PrioritizedSuggestion> {
+        match self.overflow_policy {
+            SuggestionOverflowPolicy::EvictLowestPriority => self
+                .items
+                .iter()
+                .next_back()
+                .filter(|last| incoming < *last)
+                .cloned(),
+            SuggestionOverflowPolicy::DropNewest => None,
+            SuggestionOverflowPolicy::DropOldest => self
+                .items
+                .iter()
+                .min_by_key(|p| p.suggestion.created_at)
+                .cloned(),
+        }
+    }
+
     pub fn pop(&mut self) -> Option<Suggestion> {
+        self.pop_at(chrono::Utc::now())
+    }
+
+    /// Pop the top suggestion still valid at `now`, purging expired ones
+    /// first so a stale high-priority item cannot hold the head.
+    pub fn pop_at(&mut self, now: chrono::DateTime<chrono::Utc>) -> Option<Suggestion> {
+        self.purge_expired(now);
         let first = self.items.iter().next()?.clone();
         self.items.remove(&first);
         self.fingerprints
@@ -157,26 +188,28 @@ impl SuggestionQueue {
     }
 
     pub fn remove_expired(&mut self) -> usize {
-        let now = chrono::Utc::now();
+        self.remove_expired_at(chrono::Utc::now())
+    }
+
+    /// Remove suggestions whose `expires_at` is at or before `now`. Pass a
+    /// server-skew-corrected time, since `expires_at` is set by the server.
+    pub fn remove_expired_at(&mut self, now: chrono::DateTime<chrono::Utc>) -> usize {
+        self.purge_expired(now)
+    }
+
+    /// Drop suggestions expired at `now`; returns how many were removed.
+    pub fn purge_expired(&mut self, now: chrono::DateTime<chrono::Utc>) -> usize {
         let expired_fps: Vec<u64> = self
             .items
             .iter()
-            .filter(|p| {
-                p.suggestion
-                    .expires_at
-                    .is_some_and(|expires| expires <= now)
-            })
+            .filter(|p| p.suggestion.is_expired(now))
             .map(|p| content_fingerprint(&p.suggestion))
             .collect();
         for fp in &expired_fps {
             self.fingerprints.remove(fp);
         }
         let before = self.items.len();
-        self.items.retain(|p| {
-            p.suggestion
-                .expires_at
-                .map_or(true, |expires| expires > now)
-        });
+        self.items.retain(|p| !p.suggestion.is_expired(now));
         before - self.items.len()
     }
 }
@@ -229,6 +262,61 @@ mod tests {
         assert_eq!(queue.peek().unwrap().suggestion_id, "3");
     }
 
+    fn make_suggestion_at(id: &str, priority: Priority, age_secs: i64) -> Suggestion {
+        Suggestion {
+            created_at: Utc::now() - chrono::Duration::seconds(age_secs),
+            ..make_suggestion(id, priority)
+        }
+    }
+
+    fn full_queue(policy: SuggestionOverflowPolicy) -> SuggestionQueue {
+        let mut queue = SuggestionQueue::new(2).with_overflow_policy(policy);
+        queue.push(make_suggestion_at("old-high", Priority::High, 60));
+        queue.push(make_suggestion_at("new-low", Priority::Low, 10));
+        queue
+    }
+
+    fn ids(queue: &SuggestionQueue) -> Vec<&str> {
+        let mut ids: Vec<&str> = queue.iter().map(|s| s.suggestion_id.as_str()).collect();
+        ids.sort_unstable();
+        ids
+    }
+
+    #[test]
+    fn evict_lowest_priority_replaces_outranked_item() {
+        let mut queue = full_queue(SuggestionOverflowPolicy::EvictLowestPriority);
+
+        assert!(queue.push(make_suggestion("incoming-medium", Priority::Medium)));
+        assert_eq!(ids(&queue), vec!["incoming-medium", "old-high"]);
+
+        assert!(!queue.push(make_suggestion("incoming-low", Priority::Low)));
+        assert_eq!(ids(&queue), vec!["incoming-medium", "old-high"]);
+    }
+
+    #[test]
+    fn drop_newest_rejects_incoming_even_if_higher_priority() {
+        let mut queue = full_queue(SuggestionOverflowPolicy::DropNewest);
+
+        assert!(!queue.push(make_suggestion("incoming-critical", Priority::Critical)));
+        assert_eq!(ids(&queue), vec!["new-low", "old-high"]);
+    }
+
+    #[test]
+    fn drop_oldest_evicts_oldest_regardless_of_priority() {
+        let mut queue = full_queue(SuggestionOverflowPolicy::DropOldest);
+
+        assert!(queue.push(make_suggestion("incoming-low", Priority::Low)));
+        assert_eq!(ids(&queue), vec!["incoming-low", "new-low"]);
+    }
+
+    #[test]
+    fn evicted_content_can_be_queued_again() {
+        let mut queue = full_queue(SuggestionOverflowPolicy::DropOldest);
+        queue.push(make_suggestion("incoming", Priority::Low));
+
+        assert!(queue.push(make_suggestion_at("old-high", Priority::High, 0)));
+    }
+
     #[test]
     fn empty_queue() {
         let mut queue = SuggestionQueue::new(50);
@@ -265,6 +353,23 @@ mod tests {
         assert!(queue.remove_by_id("nonexistent").is_none());
     }
 
+    #[test]
+    fn remove_expired_at_uses_given_time() {
+        let mut queue = SuggestionQueue::new(50);
+        let local_now = Utc::now();
+        let mut s = make_suggestion("s1", Priority::High);
+        s.expires_at = Some(local_now + chrono::Duration::seconds(30));
+        queue.push(s);
+
+        assert_eq!(queue.remove_expired_at(local_now), 0);
+        // Server clock 60 s ahead of local: already expired on its clock.
+        assert_eq!(
+            queue.remove_expired_at(local_now + chrono::Duration::seconds(60)),
+            1
+        );
+        assert!(queue.is_empty());
+    }
+
     #[test]
     fn remove_expired() {
         let mut queue = SuggestionQueue::new(50);
@@ -282,6 +387,37 @@ mod tests {
         assert_eq!(queue.peek().unwrap().suggestion_id, "valid");
     }
 
+    #[test]
+    fn purge_expired_treats_expiry_at_now_as_expired() {
+        let mut queue = SuggestionQueue::new(50);
+        let now = Utc::now();
+        let mut at_now = make_suggestion("at-now", Priority::High);
+        at_now.expires_at = Some(now);
+        assert!(at_now.is_expired(now));
+        queue.push(at_now);
+        let mut later = make_suggestion("later", Priority::Low);
+        later.expires_at = Some(now + chrono::Duration::seconds(1));
+        assert!(!later.is_expired(now));
+        queue.push(later);
+
+        assert_eq!(queue.purge_expired(now), 1);
+        assert_eq!(queue.peek().unwrap().suggestion_id, "later");
+        assert_eq!(queue.purge_expired(now), 0);
+    }
+
+    #[test]
+    fn pop_skips_expired_high_priority_item() {
+        let mut queue = SuggestionQueue::new(50);
+        let now = Utc::now();
+        let mut stale = make_suggestion("stale", Priority::Critical);
+        stale.expires_at = Some(now - chrono::Duration::minutes(5));
+        queue.push(stale);
+        queue.push(make_suggestion("fresh", Priority::Low));
+
+        assert_eq!(queue.pop_at(now).unwrap().suggestion_id, "fresh");
+        assert!(queue.pop_at(now).is_none());
+    }
+
     #[test]
     fn duplicate_content_rejected() {
         let mut queue = SuggestionQueue::new(50);
@@ -1,11 +1,15 @@
+use oneshim_core::config::SuggestionDeliveryConfig;
 use oneshim_core::models::suggestion::Suggestion;
 use oneshim_core::ports::api_client::{SseClient, SseEvent};
-use oneshim_core::ports::notifier::DesktopNotifier;
+use oneshim_core::ports::clock::{Clock, SystemClock};
+use oneshim_core::ports::notifier::{DesktopNotifier, SuggestionWebhook};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 use tracing::{debug, error, info, warn};
 
+use crate::context_scorer::{ContextScorer, LocalContextSource};
 use crate::error::SuggestionError;
+use crate::presenter::delivery_plan;
 use crate::queue::SuggestionQueue;
 use crate::scorer::FeedbackScorer;
 
@@ -16,9 +20,14 @@ pub type OnNewSuggestion = Arc<dyn Fn(usize) + Send + Sync>;
 pub struct SuggestionReceiver {
     sse_client: Arc<dyn SseClient>,
     notifier: Option<Arc<dyn DesktopNotifier>>,
+    webhook: Option<Arc<dyn SuggestionWebhook>>,
+    delivery: SuggestionDeliveryConfig,
     queue: Arc<Mutex<SuggestionQueue>>,
     scorer: Arc<Mutex<FeedbackScorer>>,
+    context_scoring: Option<(ContextScorer, Arc<dyn LocalContextSource>)>,
     on_new: Mutex<Option<OnNewSuggestion>>,
+    /// Checks `expires_at`; server-skew-corrected when wired.
+    clock: Arc<dyn Clock>,
 }
 
 impl SuggestionReceiver {
@@ -31,12 +40,47 @@ impl SuggestionReceiver {
         Self {
             sse_client,
             notifier,
+            webhook: None,
+            delivery: SuggestionDeliveryConfig::default(),
             queue,
             scorer,
+            context_scoring: None,
             on_new: Mutex::new(None),
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Re-score incoming suggestions against local activity before the
+    /// feedback adjustment and suppression check.
+    pub fn with_context_scoring(
+        mut self,
+        scorer: ContextScorer,
+        source: Arc<dyn LocalContextSource>,
+    ) -> Self {
+        self.context_scoring = Some((scorer, source));
+        self
+    }
+
+    /// Compare server-issued `expires_at` against `clock` instead of the
+    /// system clock.
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Route accepted suggestions to channels by priority instead of
+    /// notifying for every one.
+    pub fn with_delivery(mut self, delivery: SuggestionDeliveryConfig) -> Self {
+        self.delivery = delivery;
+        self
+    }
+
+    /// Target for priorities mapped to the webhook channel.
+    pub fn with_webhook(mut self, webhook: Arc<dyn SuggestionWebhook>) -> Self {
+        self.webhook = Some(webhook);
+        self
+    }
+
     /// Set the on-new callback after construction.
     /// Called when the overlay handle becomes available.
     pub async fn set_on_new(&self, callback: OnNewSuggestion) {
@@ -92,7 +136,24 @@ impl SuggestionReceiver {
     // races where the queue is pushed to twice with stale expiry state.
     #[allow(clippy::significant_drop_tightening)]
     async fn handle_suggestion(&self, mut suggestion: Suggestion) {
-        // 1. Feedback-based relevance adjustment
+        // 1. Local-context re-scoring
+        if let Some((context_scorer, source)) = &self.context_scoring {
+            let context = source.local_context().await;
+            let server_relevance = suggestion.relevance_score;
+            context_scorer.adjust(
+                &suggestion.suggestion_type,
+                &context,
+                &mut suggestion.relevance_score,
+            );
+            debug!(
+                id = %suggestion.suggestion_id,
+                server_relevance,
+                relevance = suggestion.relevance_score,
+                "suggestion re-scored with local context"
+            );
+        }
+
+        // 2. Feedback-based relevance adjustment
         let should_queue = {
             let scorer = self.scorer.lock().await;
             scorer.adjust(
@@ -110,10 +171,10 @@ impl SuggestionReceiver {
             return;
         }
 
-        // 2. Opportunistic expiry + dedup + push (single queue lock)
+        // 3. Opportunistic expiry + dedup + push (single queue lock)
         let (accepted, queue_count) = {
             let mut queue = self.queue.lock().await;
-            let expired_count = queue.remove_expired();
+            let expired_count = queue.purge_expired(self.clock.now());
             if expired_count > 0 {
                 debug!(expired_count, "expired suggestions removed from queue");
             }
@@ -126,15 +187,30 @@ impl SuggestionReceiver {
             return;
         }
 
-        if let Some(notifier) = &self.notifier {
-            if let Err(e) = notifier.show_suggestion(&suggestion).await {
-                warn!("notification display failure: {e}");
+        // 4. Deliver to the channels mapped for this priority
+        let plan = delivery_plan(&suggestion, &self.delivery);
+
+        if plan.notification {
+            if let Some(notifier) = &self.notifier {
+                if let Err(e) = notifier.show_suggestion(&suggestion).await {
+                    warn!("notification display failure: {e}");
+                }
+            }
+        }
+
+        if plan.webhook {
+            if let Some(webhook) = &self.webhook {
+                if let Err(e) = webhook.deliver(&suggestion).await {
+                    warn!("suggestion webhook failure: {e}");
+                }
             }
         }
 
         // Notify overlay of new suggestion (badge count update)
-        if let Some(on_new) = self.on_new.lock().await.as_ref() {
-            on_new(queue_count);
+        if plan.tray_badge {
+            if let Some(on_new) = self.on_new.lock().await.as_ref() {
+                on_new(queue_count);
+            }
         }
     }
 
@@ -142,8 +218,11 @@ impl SuggestionReceiver {
         self.queue.lock().await.len()
     }
 
+    /// Top suggestion that has not expired; expired entries are purged.
     pub async fn peek_top(&self) -> Option<Suggestion> {
-        self.queue.lock().await.peek().cloned()
+        let mut queue = self.queue.lock().await;
+        queue.purge_expired(self.clock.now());
+        queue.peek().cloned()
     }
 }
 
@@ -226,6 +305,72 @@ mod tests {
         assert_eq!(queue.lock().await.len(), 1);
     }
 
+    struct CountingWebhook {
+        count: AtomicUsize,
+    }
+    #[async_trait::async_trait]
+    impl SuggestionWebhook for CountingWebhook {
+        async fn deliver(&self, _suggestion: &Suggestion) -> Result<(), CoreError> {
+            self.count.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn delivery_mapping_notifies_critical_but_not_low() {
+        use oneshim_core::config::SuggestionDeliveryChannel;
+
+        let queue = Arc::new(Mutex::new(SuggestionQueue::new(50)));
+        let scorer = Arc::new(Mutex::new(FeedbackScorer::new()));
+        let notifier = Arc::new(CountingNotifier {
+            count: AtomicUsize::new(0),
+        });
+        let webhook = Arc::new(CountingWebhook {
+            count: AtomicUsize::new(0),
+        });
+        let badge_updates = Arc::new(AtomicUsize::new(0));
+        let badge_clone = badge_updates.clone();
+
+        let receiver = SuggestionReceiver::new(
+            Arc::new(MockSseClient) as Arc<dyn SseClient>,
+            Some(notifier.clone() as Arc<dyn DesktopNotifier>),
+            queue.clone(),
+            scorer,
+        )
+        .with_delivery(SuggestionDeliveryConfig {
+            critical: vec![
+                SuggestionDeliveryChannel::Notification,
+                SuggestionDeliveryChannel::TrayBadge,
+                SuggestionDeliveryChannel::Webhook,
+            ],
+            low: vec![SuggestionDeliveryChannel::Dashboard],
+            ..Default::default()
+        })
+        .with_webhook(webhook.clone() as Arc<dyn SuggestionWebhook>);
+        receiver
+            .set_on_new(Arc::new(move |_| {
+                badge_clone.fetch_add(1, Ordering::SeqCst);
+            }))
+            .await;
+
+        let mut critical = make_suggestion();
+        critical.suggestion_id = "critical-1".to_string();
+        critical.priority = Priority::Critical;
+        receiver.handle_suggestion(critical).await;
+
+        let mut low = make_suggestion();
+        low.suggestion_id = "low-1".to_string();
+        low.content = "Low priority content".to_string();
+        low.priority = Priority::Low;
+        receiver.handle_suggestion(low).await;
+
+        assert_eq!(notifier.count.load(Ordering::SeqCst), 1);
+        assert_eq!(webhook.count.load(Ordering::SeqCst), 1);
+        assert_eq!(badge_updates.load(Ordering::SeqCst), 1);
+        // Dashboard-only still queues the suggestion.
+        assert_eq!(queue.lock().await.len(), 2);
+    }
+
     #[tokio::test]
     async fn handle_suggestion_works_without_notifier() {
         let queue = Arc::new(Mutex::new(SuggestionQueue::new(50)));
@@ -344,4 +489,40 @@ mod tests {
 
         assert_eq!(queue.lock().await.len(), 0);
     }
+
+    struct FixedContext(crate::context_scorer::LocalContext);
+
+    #[async_trait::async_trait]
+    impl LocalContextSource for FixedContext {
+        async fn local_context(&self) -> crate::context_scorer::LocalContext {
+            self.0.clone()
+        }
+    }
+
+    #[tokio::test]
+    async fn handle_suggestion_applies_local_context() {
+        use oneshim_core::models::work_session::AppCategory;
+
+        let queue = Arc::new(Mutex::new(SuggestionQueue::new(50)));
+        let scorer = Arc::new(Mutex::new(FeedbackScorer::new()));
+        let context = crate::context_scorer::LocalContext {
+            current_category: Some(AppCategory::Development),
+            current_app_secs: 3 * 3600,
+            continuous_deep_work_secs: 3 * 3600,
+        };
+        let receiver = SuggestionReceiver::new(
+            Arc::new(MockSseClient) as Arc<dyn SseClient>,
+            None,
+            queue.clone(),
+            scorer,
+        )
+        .with_context_scoring(ContextScorer::default(), Arc::new(FixedContext(context)));
+
+        let mut suggestion = make_suggestion();
+        suggestion.relevance_score = 0.5;
+        receiver.handle_suggestion(suggestion).await;
+
+        let q = queue.lock().await;
+        assert!((q.peek().unwrap().relevance_score - 0.7).abs() < 1e-9);
+    }
 }
@@ -28,6 +28,40 @@ pub struct MonitorInfo {
     pub name: String,
 }
 
+/// Position and size of one display, used to key delta bases.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DisplayGeometry {
+    pub x: i32,
+    pub y: i32,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl DisplayGeometry {
+    fn of(monitor: &Monitor) -> Self {
+        Self {
+            x: monitor.x().unwrap_or(0),
+            y: monitor.y().unwrap_or(0),
+            width: monitor.width().unwrap_or(0),
+            height: monitor.height().unwrap_or(0),
+        }
+    }
+}
+
+/// All attached displays at capture time. Any change in count, position or
+/// resolution makes two layouts unequal.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisplayLayout {
+    pub displays: Vec<DisplayGeometry>,
+}
+
+/// A captured frame together with the display it came from.
+pub struct DisplayCapture {
+    pub image: DynamicImage,
+    pub display: DisplayGeometry,
+    pub layout: DisplayLayout,
+}
+
 #[derive(Clone)]
 pub struct ScreenCapture;
 
@@ -69,30 +103,37 @@ impl ScreenCapture {
         &self,
         bounds: Option<&WindowBounds>,
     ) -> Result<DynamicImage, VisionError> {
+        self.capture_for_window_with_layout(bounds)
+            .map(|capture| capture.image)
+    }
+
+    /// Like [`Self::capture_for_window`], but also reports which display was
+    /// captured and the full display layout, so callers can detect
+    /// resolution or monitor-count changes.
+    pub fn capture_for_window_with_layout(
+        &self,
+        bounds: Option<&WindowBounds>,
+    ) -> Result<DisplayCapture, VisionError> {
         let monitors = Monitor::all()
             .map_err(|e| VisionError::Internal(format!("Failed to query monitor list: {e}")))?;
-
-        // Single monitor — primary capture
-        if monitors.len() <= 1 {
-            return self.capture_primary();
-        }
-
-        let Some(bounds) = bounds else {
-            return self.capture_primary();
+        let layout = DisplayLayout {
+            displays: monitors.iter().map(DisplayGeometry::of).collect(),
         };
-        // Window center point
-        let cx = bounds.x + (bounds.width as i32 / 2);
-        let cy = bounds.y + (bounds.height as i32 / 2);
-
-        // Find the monitor whose rect contains the window center
-        let target = monitors.iter().find(|m| {
-            let Ok(mx) = m.x() else { return false };
-            let Ok(my) = m.y() else { return false };
-            let Ok(mw) = m.width() else { return false };
-            let Ok(mh) = m.height() else { return false };
-            let mw = mw as i32;
-            let mh = mh as i32;
-            cx >= mx && cx < mx + mw && cy >= my && cy < my + mh
+
+        // Find the monitor whose rect contains the window center. Single
+        // monitor or no bounds — primary capture.
+        let target = bounds.filter(|_| monitors.len() > 1).and_then(|bounds| {
+            let cx = bounds.x + (bounds.width as i32 / 2);
+            let cy = bounds.y + (bounds.height as i32 / 2);
+            monitors.iter().find(|m| {
+                let Ok(mx) = m.x() else { return false };
+                let Ok(my) = m.y() else { return false };
+                let Ok(mw) = m.width() else { return false };
+                let Ok(mh) = m.height() else { return false };
+                let mw = mw as i32;
+                let mh = mh as i32;
+                cx >= mx && cx < mx + mw && cy >= my && cy < my + mh
+            })
         });
 
         let monitor = target
@@ -112,7 +153,11 @@ impl ScreenCapture {
             image.height()
         );
 
-        Ok(DynamicImage::ImageRgba8(image))
+        Ok(DisplayCapture {
+            image: DynamicImage::ImageRgba8(image),
+            display: DisplayGeometry::of(monitor),
+            layout,
+        })
     }
 
     /// Capture the monitor at the given zero-based `index`.
@@ -1,7 +1,12 @@
 use image::{DynamicImage, GenericImageView};
+use oneshim_core::frame_delta::{tile_changed, CHANGE_THRESHOLD, TILE_SIZE};
 use oneshim_core::models::frame::Rect;
+use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::debug;
 
+use crate::capture::{DisplayGeometry, DisplayLayout};
+
 #[derive(Debug, Clone)]
 pub struct DeltaRegion {
     pub region: Rect,
@@ -10,10 +15,6 @@ pub struct DeltaRegion {
     pub total_tiles: u32,
 }
 
-const TILE_SIZE: u32 = 16;
-
-const CHANGE_THRESHOLD: u32 = 30;
-
 pub fn compute_delta(prev: &DynamicImage, curr: &DynamicImage) -> Option<DeltaRegion> {
     let (pw, ph) = prev.dimensions();
     let (cw, ch) = curr.dimensions();
@@ -100,42 +101,16 @@ fn is_tile_changed_fast(
     end_x: u32,
     end_y: u32,
 ) -> bool {
-    let mut diff_sum = 0u64;
-    let mut pixel_count = 0u64;
-
-    let start_x = start_x as usize;
-    let start_y = start_y as usize;
-    let end_x = end_x as usize;
-    let end_y = end_y as usize;
-
-    for y in start_y..end_y {
-        let row_offset = y * stride;
-        for x in start_x..end_x {
-            let pixel_offset = row_offset + x * 4;
-
-            let pr = prev[pixel_offset] as i32;
-            let pg = prev[pixel_offset + 1] as i32;
-            let pb = prev[pixel_offset + 2] as i32;
-
-            let cr = curr[pixel_offset] as i32;
-            let cg = curr[pixel_offset + 1] as i32;
-            let cb = curr[pixel_offset + 2] as i32;
-
-            let dr = (pr - cr).unsigned_abs();
-            let dg = (pg - cg).unsigned_abs();
-            let db = (pb - cb).unsigned_abs();
-
-            diff_sum += (dr + dg + db) as u64;
-            pixel_count += 1;
-        }
-    }
-
-    if pixel_count == 0 {
-        return false;
-    }
-
-    let avg_diff = diff_sum / pixel_count;
-    avg_diff > CHANGE_THRESHOLD as u64
+    is_tile_changed_with_threshold(
+        prev,
+        curr,
+        stride,
+        start_x,
+        start_y,
+        end_x,
+        end_y,
+        CHANGE_THRESHOLD as u64,
+    )
 }
 
 /// Compute delta with adjustable sensitivity threshold.
@@ -231,41 +206,62 @@ fn is_tile_changed_with_threshold(
     end_y: u32,
     threshold: u64,
 ) -> bool {
-    let mut diff_sum = 0u64;
-    let mut pixel_count = 0u64;
-
-    let start_x = start_x as usize;
-    let start_y = start_y as usize;
-    let end_x = end_x as usize;
-    let end_y = end_y as usize;
-
-    for y in start_y..end_y {
-        let row_offset = y * stride;
-        for x in start_x..end_x {
-            let pixel_offset = row_offset + x * 4;
-
-            let pr = prev[pixel_offset] as i32;
-            let pg = prev[pixel_offset + 1] as i32;
-            let pb = prev[pixel_offset + 2] as i32;
-
-            let cr = curr[pixel_offset] as i32;
-            let cg = curr[pixel_offset + 1] as i32;
-            let cb = curr[pixel_offset + 2] as i32;
+    let tile = Rect {
+        x: start_x,
+        y: start_y,
+        w: end_x - start_x,
+        h: end_y - start_y,
+    };
+    tile_changed(prev, curr, stride, tile, threshold)
+}
 
-            let dr = (pr - cr).unsigned_abs();
-            let dg = (pg - cg).unsigned_abs();
-            let db = (pb - cb).unsigned_abs();
+/// Last full frame per display, used as the delta base. Cleared whenever
+/// the display layout changes so a replugged monitor or new resolution
+/// starts from a keyframe instead of a delta against stale geometry.
+#[derive(Default)]
+pub struct DeltaBases {
+    layout: Option<DisplayLayout>,
+    bases: HashMap<DisplayGeometry, Arc<DynamicImage>>,
+}
 
-            diff_sum += (dr + dg + db) as u64;
-            pixel_count += 1;
+impl DeltaBases {
+    /// Record the current layout. Returns `true` and drops every base when
+    /// it differs from the previously observed layout.
+    pub fn observe_layout(&mut self, layout: &DisplayLayout) -> bool {
+        let changed = self.layout.as_ref().is_some_and(|prev| prev != layout);
+        if changed {
+            debug!(
+                displays = layout.displays.len(),
+                "display configuration changed, resetting delta bases"
+            );
+            self.bases.clear();
+        }
+        if self.layout.as_ref() != Some(layout) {
+            self.layout = Some(layout.clone());
         }
+        changed
     }
 
-    if pixel_count == 0 {
-        return false;
+    /// Base frame for `display`, or `None` when the next frame must be a
+    /// keyframe (no base yet, or the base size no longer matches `frame`).
+    pub fn base_for(
+        &self,
+        display: &DisplayGeometry,
+        frame: &DynamicImage,
+    ) -> Option<Arc<DynamicImage>> {
+        self.bases
+            .get(display)
+            .filter(|base| base.dimensions() == frame.dimensions())
+            .cloned()
     }
 
-    diff_sum / pixel_count > threshold
+    pub fn set_base(&mut self, display: DisplayGeometry, frame: Arc<DynamicImage>) {
+        self.bases.insert(display, frame);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bases.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -273,6 +269,71 @@ mod tests {
     use super::*;
     use image::{DynamicImage, RgbaImage};
 
+    fn display(width: u32, height: u32) -> DisplayGeometry {
+        DisplayGeometry {
+            x: 0,
+            y: 0,
+            width,
+            height,
+        }
+    }
+
+    fn layout_of(displays: &[DisplayGeometry]) -> DisplayLayout {
+        DisplayLayout {
+            displays: displays.to_vec(),
+        }
+    }
+
+    fn solid(width: u32, height: u32) -> Arc<DynamicImage> {
+        Arc::new(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+            width,
+            height,
+            image::Rgba([40, 40, 40, 255]),
+        )))
+    }
+
+    #[test]
+    fn resolution_change_forces_keyframe_on_next_capture() {
+        let mut bases = DeltaBases::default();
+        let before = display(1920, 1080);
+        assert!(!bases.observe_layout(&layout_of(&[before])));
+        bases.set_base(before, solid(1920, 1080));
+        assert!(bases.base_for(&before, &solid(1920, 1080)).is_some());
+
+        let after = display(2560, 1440);
+        assert!(bases.observe_layout(&layout_of(&[after])));
+        assert!(bases.is_empty());
+        assert!(bases.base_for(&after, &solid(2560, 1440)).is_none());
+
+        bases.set_base(after, solid(2560, 1440));
+        assert!(!bases.observe_layout(&layout_of(&[after])));
+        assert!(bases.base_for(&after, &solid(2560, 1440)).is_some());
+    }
+
+    #[test]
+    fn monitor_count_change_resets_all_displays() {
+        let mut bases = DeltaBases::default();
+        let main = display(1920, 1080);
+        let side = DisplayGeometry {
+            x: 1920,
+            ..display(1280, 1024)
+        };
+        bases.observe_layout(&layout_of(&[main, side]));
+        bases.set_base(main, solid(1920, 1080));
+        bases.set_base(side, solid(1280, 1024));
+
+        assert!(bases.observe_layout(&layout_of(&[main])));
+        assert!(bases.base_for(&main, &solid(1920, 1080)).is_none());
+    }
+
+    #[test]
+    fn base_with_mismatched_size_is_not_used() {
+        let mut bases = DeltaBases::default();
+        let main = display(1920, 1080);
+        bases.set_base(main, solid(1920, 1080));
+        assert!(bases.base_for(&main, &solid(1280, 720)).is_none());
+    }
+
     #[test]
     fn identical_images_no_delta() {
         let img = DynamicImage::ImageRgba8(RgbaImage::from_pixel(
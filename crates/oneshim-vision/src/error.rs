@@ -7,6 +7,9 @@ pub enum VisionError {
     Core(#[from] CoreError),
     #[error("OCR error: {0}")]
     Ocr(String),
+    /// The OCR engine could not start (bad tessdata path, missing language).
+    #[error("OCR init error: {0}")]
+    OcrInit(String),
     #[error("internal error: {0}")]
     Internal(String),
 }
@@ -15,7 +18,7 @@ impl From<VisionError> for CoreError {
     fn from(err: VisionError) -> Self {
         match err {
             VisionError::Core(e) => e,
-            VisionError::Ocr(msg) => CoreError::OcrError {
+            VisionError::Ocr(msg) | VisionError::OcrInit(msg) => CoreError::OcrError {
                 code: oneshim_core::error_codes::ProviderCode::OcrFailed,
                 message: msg,
             },
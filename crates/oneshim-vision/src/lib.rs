@@ -35,10 +35,13 @@ pub mod gui_detector;
 /// Backward-compatible re-export for Phase 1 callers.
 pub use gui_detector as input_correlator;
 pub mod local_ocr_provider;
+pub mod notification_mask;
 #[cfg(feature = "ocr")]
 pub mod ocr;
+pub mod ocr_gate;
 pub mod privacy;
 pub mod privacy_gateway;
+pub mod privacy_preview;
 pub mod processor;
 pub mod ring_buffer;
 pub mod thumbnail;
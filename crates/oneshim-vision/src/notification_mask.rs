@@ -0,0 +1,177 @@
+//! Blurs the screen area where the OS shows notification banners.
+//!
+//! Banner positions are estimated per platform from the frame size: macOS
+//! stacks banners in the top-right corner below the menu bar, Windows shows
+//! toasts bottom-right above the taskbar. Linux desktops differ too much to
+//! guess, so there only a configured top-right region is masked.
+
+use image::DynamicImage;
+use oneshim_core::config::{NotificationMaskRegion, PrivacyConfig};
+use oneshim_core::models::frame::Rect;
+
+/// Blur strength; strong enough that preview text is unreadable.
+const MASK_BLUR_SIGMA: f32 = 12.0;
+
+/// Where the current OS draws notification banners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BannerPlatform {
+    MacOs,
+    Windows,
+    /// No known banner position.
+    Other,
+}
+
+impl BannerPlatform {
+    pub fn current() -> Self {
+        if cfg!(target_os = "macos") {
+            Self::MacOs
+        } else if cfg!(target_os = "windows") {
+            Self::Windows
+        } else {
+            Self::Other
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct NotificationMask {
+    platform: BannerPlatform,
+    region: Option<NotificationMaskRegion>,
+}
+
+impl NotificationMask {
+    pub fn new(platform: BannerPlatform, region: Option<NotificationMaskRegion>) -> Self {
+        Self { platform, region }
+    }
+
+    /// Mask for the running OS, or `None` when masking is turned off.
+    pub fn from_config(privacy: &PrivacyConfig) -> Option<Self> {
+        privacy
+            .mask_notification_banners
+            .then(|| Self::new(BannerPlatform::current(), privacy.notification_mask_region))
+    }
+
+    /// Frame area to blur, clamped to the frame. A configured region takes
+    /// precedence over the platform estimate.
+    pub fn region(&self, frame_w: u32, frame_h: u32) -> Option<Rect> {
+        if frame_w == 0 || frame_h == 0 {
+            return None;
+        }
+        if let Some(region) = self.region {
+            let w = region.width.min(frame_w);
+            let h = region.height.min(frame_h);
+            return Some(Rect {
+                x: frame_w - w,
+                y: 0,
+                w,
+                h,
+            });
+        }
+
+        let w = (frame_w / 4).max(360).min(frame_w);
+        match self.platform {
+            BannerPlatform::MacOs => {
+                // Menu bar plus up to two stacked banners.
+                let h = (frame_h / 5).max(160).min(frame_h);
+                Some(Rect {
+                    x: frame_w - w,
+                    y: 0,
+                    w,
+                    h,
+                })
+            }
+            BannerPlatform::Windows => {
+                // Toast stack above a ~5% taskbar.
+                let h = (frame_h / 4).max(200).min(frame_h);
+                let y = frame_h.saturating_sub(h + frame_h / 20);
+                Some(Rect {
+                    x: frame_w - w,
+                    y,
+                    w,
+                    h,
+                })
+            }
+            BannerPlatform::Other => None,
+        }
+    }
+
+    /// Blur the banner region in place. Returns the masked area, if any.
+    pub fn apply(&self, frame: &mut DynamicImage) -> Option<Rect> {
+        let rect = self.region(frame.width(), frame.height())?;
+        let blurred = frame
+            .crop_imm(rect.x, rect.y, rect.w, rect.h)
+            .blur(MASK_BLUR_SIGMA);
+        image::imageops::replace(frame, &blurred, i64::from(rect.x), i64::from(rect.y));
+        Some(rect)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, Rgba, RgbaImage};
+
+    #[test]
+    fn macos_region_is_top_right() {
+        let mask = NotificationMask::new(BannerPlatform::MacOs, None);
+        let rect = mask.region(2880, 1800).unwrap();
+        assert_eq!((rect.x + rect.w, rect.y), (2880, 0));
+        assert_eq!((rect.w, rect.h), (720, 360));
+    }
+
+    #[test]
+    fn windows_region_sits_above_taskbar() {
+        let mask = NotificationMask::new(BannerPlatform::Windows, None);
+        let rect = mask.region(1920, 1080).unwrap();
+        assert_eq!(rect.x + rect.w, 1920);
+        assert_eq!(rect.y + rect.h, 1080 - 54);
+    }
+
+    #[test]
+    fn configured_region_overrides_platform_and_is_clamped() {
+        let custom = NotificationMaskRegion {
+            width: 500,
+            height: 150,
+        };
+        assert!(NotificationMask::new(BannerPlatform::Other, None)
+            .region(1920, 1080)
+            .is_none());
+        let rect = NotificationMask::new(BannerPlatform::Windows, Some(custom))
+            .region(1920, 1080)
+            .unwrap();
+        assert_eq!((rect.x, rect.y, rect.w, rect.h), (1420, 0, 500, 150));
+
+        let small = NotificationMask::new(BannerPlatform::Other, Some(custom))
+            .region(320, 100)
+            .unwrap();
+        assert_eq!((small.x, small.y, small.w, small.h), (0, 0, 320, 100));
+    }
+
+    #[test]
+    fn apply_blurs_only_the_target_region() {
+        // Alternating black/white columns: blurring turns them gray.
+        let striped = RgbaImage::from_fn(800, 400, |x, _| {
+            if x % 2 == 0 {
+                Rgba([0, 0, 0, 255])
+            } else {
+                Rgba([255, 255, 255, 255])
+            }
+        });
+        let mut frame = DynamicImage::ImageRgba8(striped.clone());
+        let mask = NotificationMask::new(
+            BannerPlatform::Other,
+            Some(NotificationMaskRegion {
+                width: 200,
+                height: 100,
+            }),
+        );
+
+        let rect = mask.apply(&mut frame).unwrap();
+        assert_eq!((rect.x, rect.y), (600, 0));
+
+        let inside = frame.get_pixel(700, 50);
+        assert!(inside[0] > 60 && inside[0] < 195, "{inside:?}");
+        assert_eq!(frame.get_pixel(100, 50), *striped.get_pixel(100, 50));
+        assert_eq!(frame.get_pixel(700, 300), *striped.get_pixel(700, 300));
+    }
+}
@@ -54,7 +54,7 @@ impl OcrExtractor {
                 .as_ref()
                 .map(|p| p.to_string_lossy().to_string());
             leptess::LepTess::new(tessdata.as_deref(), "eng")
-                .map_err(|e| VisionError::Ocr(format!("OCR initialize failure: {e}")))
+                .map_err(|e| VisionError::OcrInit(format!("OCR initialize failure: {e}")))
         };
 
         let lt = if let Some(ref mut guard) = cached_guard {
@@ -124,7 +124,7 @@ impl OcrExtractor {
             let tessdata_ref = tessdata.as_deref();
 
             let mut lt = leptess::LepTess::new(tessdata_ref, "eng")
-                .map_err(|e| VisionError::Ocr(format!("OCR initialize failure: {e}")))?;
+                .map_err(|e| VisionError::OcrInit(format!("OCR initialize failure: {e}")))?;
 
             lt.set_image_from_mem(&raw_data, w as i32, h as i32, 4, (w * 4) as i32)
                 .map_err(|_| {
@@ -224,7 +224,7 @@ impl OcrExtractor {
             let tessdata_ref = tessdata.as_deref();
 
             let mut lt = leptess::LepTess::new(tessdata_ref, "eng")
-                .map_err(|e| VisionError::Ocr(format!("OCR initialize failure: {e}")))?;
+                .map_err(|e| VisionError::OcrInit(format!("OCR initialize failure: {e}")))?;
 
             lt.set_image_from_mem(&raw_data, w as i32, h as i32, 4, (w * 4) as i32)
                 .map_err(|_| {
@@ -355,7 +355,7 @@ impl OcrExtractor {
                 .as_ref()
                 .map(|p| p.to_string_lossy().to_string());
             leptess::LepTess::new(tessdata.as_deref(), "eng")
-                .map_err(|e| VisionError::Ocr(format!("OCR initialize failure: {e}")))
+                .map_err(|e| VisionError::OcrInit(format!("OCR initialize failure: {e}")))
         };
 
         let mut cached_guard = self.cached_leptess.lock().ok();
@@ -409,7 +409,7 @@ impl OcrExtractor {
             let tessdata_ref = tessdata.as_deref();
 
             let mut lt = leptess::LepTess::new(tessdata_ref, "eng")
-                .map_err(|e| VisionError::Ocr(format!("OCR initialize failure: {e}")))?;
+                .map_err(|e| VisionError::OcrInit(format!("OCR initialize failure: {e}")))?;
 
             lt.set_image_from_mem(&raw_data, w as i32, h as i32, 4, (w * 4) as i32)
                 .map_err(|_| {
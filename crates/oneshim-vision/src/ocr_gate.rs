@@ -0,0 +1,91 @@
+//! Session-wide OCR kill switch.
+//!
+//! A broken Tesseract install (wrong `ONESHIM_TESSDATA`, missing language
+//! data) fails identically on every frame. [`OcrGate`] turns the first engine
+//! init failure into a single warning and skips OCR for the rest of the
+//! session, so capture continues image-only. [`OcrGate::reset`] re-arms it,
+//! e.g. after a config reload.
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use tracing::{info, warn};
+
+use crate::error::VisionError;
+
+#[derive(Debug, Default)]
+pub struct OcrGate {
+    disabled: AtomicBool,
+}
+
+impl OcrGate {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.disabled.load(Ordering::Acquire)
+    }
+
+    /// Run one OCR operation. Returns `None` when OCR is disabled or `op`
+    /// failed to initialise the engine (which disables it); other errors are
+    /// passed through for the caller to log.
+    pub fn run<T>(
+        &self,
+        op: impl FnOnce() -> Result<T, VisionError>,
+    ) -> Option<Result<T, VisionError>> {
+        if !self.is_enabled() {
+            return None;
+        }
+        match op() {
+            Err(VisionError::OcrInit(message)) => {
+                if !self.disabled.swap(true, Ordering::AcqRel) {
+                    warn!("OCR disabled for this session, capturing images only: {message}");
+                }
+                None
+            }
+            result => Some(result),
+        }
+    }
+
+    /// Allow the next frame to retry engine initialisation.
+    pub fn reset(&self) {
+        if self.disabled.swap(false, Ordering::AcqRel) {
+            info!("OCR re-enabled; engine init will be retried on the next capture");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    #[test]
+    fn init_failure_disables_ocr_until_reset() {
+        let gate = OcrGate::new();
+        let calls = Cell::new(0);
+        let failing_init = || {
+            calls.set(calls.get() + 1);
+            Err::<String, _>(VisionError::OcrInit("no tessdata".to_string()))
+        };
+
+        assert!(gate.run(failing_init).is_none());
+        assert!(!gate.is_enabled());
+
+        // Later frames skip OCR entirely instead of retrying the init.
+        assert!(gate.run(failing_init).is_none());
+        assert_eq!(calls.get(), 1);
+
+        gate.reset();
+        assert!(gate.is_enabled());
+        assert!(matches!(gate.run(|| Ok("text")), Some(Ok("text"))));
+    }
+
+    #[test]
+    fn extraction_errors_do_not_disable_ocr() {
+        let gate = OcrGate::new();
+        let result = gate.run(|| Err::<String, _>(VisionError::Ocr("blurry".to_string())));
+
+        assert!(matches!(result, Some(Err(VisionError::Ocr(_)))));
+        assert!(gate.is_enabled());
+    }
+}
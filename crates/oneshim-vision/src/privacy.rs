@@ -1,4 +1,5 @@
 use oneshim_core::config::PiiFilterLevel;
+use oneshim_core::title_pattern::PatternSet;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum PiiMarker {
@@ -122,22 +123,10 @@ pub fn is_sensitive_app(app_name: &str) -> bool {
     SENSITIVE_APP_KEYWORDS.iter().any(|kw| lower.contains(kw))
 }
 
-pub fn matches_exclusion_pattern(app_name: &str, patterns: &[String]) -> bool {
-    let lower = app_name.to_lowercase();
-    patterns.iter().any(|pattern| {
-        let pat = pattern.to_lowercase();
-        if let Some(rest) = pat.strip_prefix('*') {
-            if let Some(keyword) = rest.strip_suffix('*') {
-                lower.contains(keyword)
-            } else {
-                lower.ends_with(rest)
-            }
-        } else if let Some(prefix) = pat.strip_suffix('*') {
-            lower.starts_with(prefix)
-        } else {
-            lower == pat
-        }
-    })
+/// Whether `text` matches any exclusion pattern (glob or `re:` regex, see
+/// [`oneshim_core::title_pattern`]). Invalid patterns never match.
+pub fn matches_exclusion_pattern(text: &str, patterns: &[String]) -> bool {
+    !patterns.is_empty() && PatternSet::compile_lenient(patterns).is_match(text)
 }
 
 pub fn should_exclude(
@@ -807,6 +796,16 @@ mod tests {
         assert!(!matches_exclusion_pattern("Chrome", &patterns));
     }
 
+    #[test]
+    fn exclusion_pattern_regex_title() {
+        let patterns = vec![r"re:^(?i)bank\b".to_string()];
+        assert!(matches_exclusion_pattern(
+            "Bank of Korea - Login",
+            &patterns
+        ));
+        assert!(!matches_exclusion_pattern("Banking news", &patterns));
+    }
+
     #[test]
     fn should_exclude_comprehensive() {
         assert!(should_exclude(
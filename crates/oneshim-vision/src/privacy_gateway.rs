@@ -2,6 +2,7 @@ use std::sync::Arc;
 
 use oneshim_core::config::{ExternalDataPolicy, PiiFilterLevel, PrivacyConfig};
 use oneshim_core::consent::ConsentManager;
+use oneshim_core::models::event::Event;
 
 use crate::privacy::{is_sensitive_app, sanitize_title_with_level, should_exclude};
 
@@ -10,6 +11,8 @@ pub enum PrivacyDenied {
     NoConsent,
     SensitiveApp(String),
     ExcludedByPolicy,
+    /// `privacy.local_only` keeps captured content on the device.
+    LocalOnly,
 }
 
 impl std::fmt::Display for PrivacyDenied {
@@ -18,10 +21,24 @@ impl std::fmt::Display for PrivacyDenied {
             Self::NoConsent => write!(f, "OCR consent is required"),
             Self::SensitiveApp(app) => write!(f, "Blocked sensitive app: {}", app),
             Self::ExcludedByPolicy => write!(f, "Excluded by policy"),
+            Self::LocalOnly => write!(f, "Local-only mode keeps content on this device"),
         }
     }
 }
 
+/// Refuse content-bearing events when `privacy.local_only` is on. Metrics
+/// and activity metadata pass; whether they upload at all is still up to
+/// the caller's upload settings.
+pub fn permit_event_upload(
+    privacy_config: &PrivacyConfig,
+    event: &Event,
+) -> Result<(), PrivacyDenied> {
+    if privacy_config.local_only && event.carries_content() {
+        return Err(PrivacyDenied::LocalOnly);
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct SanitizedImage {
     pub image_data: Vec<u8>,
@@ -103,6 +120,10 @@ impl PrivacyGateway {
         window_title: &str,
         allow_unredacted_external_ocr: bool,
     ) -> Result<SanitizedImage, PrivacyDenied> {
+        if self.privacy_config.local_only {
+            return Err(PrivacyDenied::LocalOnly);
+        }
+
         if !self.consent_manager.is_permitted(|p| p.ocr_processing) {
             return Err(PrivacyDenied::NoConsent);
         }
@@ -144,6 +165,10 @@ impl PrivacyGateway {
         &self,
         texts: &[String],
     ) -> Result<Vec<String>, PrivacyDenied> {
+        if self.privacy_config.local_only {
+            return Err(PrivacyDenied::LocalOnly);
+        }
+
         if !self.consent_manager.is_permitted(|p| p.ocr_processing) {
             return Err(PrivacyDenied::NoConsent);
         }
@@ -420,6 +445,50 @@ mod tests {
         )
     }
 
+    fn make_local_only_gateway() -> PrivacyGateway {
+        PrivacyGateway::new(
+            make_consent_manager(true),
+            PiiFilterLevel::Off,
+            ExternalDataPolicy::AllowFiltered,
+            PrivacyConfig {
+                local_only: true,
+                ..PrivacyConfig::default()
+            },
+        )
+    }
+
+    #[tokio::test]
+    async fn local_only_blocks_content_uploads() {
+        let gw = make_local_only_gateway();
+        let image = gw
+            .prepare_image_for_external_with_override(b"img", "VSCode", "main.rs", true)
+            .await;
+        assert!(matches!(image, Err(PrivacyDenied::LocalOnly)));
+        let text = gw.prepare_text_for_external(&["hello".to_string()]);
+        assert!(matches!(text, Err(PrivacyDenied::LocalOnly)));
+
+        let context = Event::Context(oneshim_core::models::event::ContextEvent::default());
+        assert!(matches!(
+            permit_event_upload(&gw.privacy_config, &context),
+            Err(PrivacyDenied::LocalOnly)
+        ));
+        assert!(permit_event_upload(&PrivacyConfig::default(), &context).is_ok());
+    }
+
+    #[test]
+    fn local_only_still_lets_metrics_flow() {
+        use oneshim_core::models::event::{SystemEvent, SystemEventType};
+
+        let gw = make_local_only_gateway();
+        let metrics = Event::System(SystemEvent {
+            event_id: uuid::Uuid::new_v4(),
+            event_type: SystemEventType::MetricsUpdate,
+            timestamp: chrono::Utc::now(),
+            data: serde_json::json!({ "cpu": 12.5 }),
+        });
+        assert!(permit_event_upload(&gw.privacy_config, &metrics).is_ok());
+    }
+
     #[tokio::test]
     async fn deny_without_consent() {
         let gw = make_gateway(false, ExternalDataPolicy::PiiFilterStrict);
@@ -563,5 +632,7 @@ mod tests {
         assert!(d2.to_string().contains("Bank"));
         let d3 = PrivacyDenied::ExcludedByPolicy;
         assert!(d3.to_string().contains("policy"));
+        let d4 = PrivacyDenied::LocalOnly;
+        assert!(d4.to_string().contains("Local-only"));
     }
 }
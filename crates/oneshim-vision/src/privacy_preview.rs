@@ -0,0 +1,155 @@
+//! Low-detail frame previews for live viewers.
+//!
+//! The frame is shrunk so its longest side fits `max_dimension`, then
+//! blurred. Window layout and dominant colours stay recognisable; text does
+//! not. Previews are only broadcast, never stored or uploaded.
+
+use image::DynamicImage;
+use oneshim_core::config::PrivacyConfig;
+use oneshim_core::models::frame::FramePreview;
+
+use crate::encoder::{self, WebPQuality};
+use crate::error::VisionError;
+use crate::thumbnail;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PrivacyPreview {
+    max_dimension: u32,
+    blur_sigma: f32,
+}
+
+impl PrivacyPreview {
+    pub fn new(max_dimension: u32, blur_sigma: f32) -> Self {
+        Self {
+            max_dimension: max_dimension.max(1),
+            blur_sigma,
+        }
+    }
+
+    /// Preview settings, or `None` when the preview stream is turned off.
+    pub fn from_config(privacy: &PrivacyConfig) -> Option<Self> {
+        let preview = &privacy.preview;
+        preview
+            .enabled
+            .then(|| Self::new(preview.max_dimension, preview.blur_sigma))
+    }
+
+    /// Preview size for a `frame_w` x `frame_h` frame, keeping the aspect
+    /// ratio. Frames already within `max_dimension` keep their size.
+    pub fn preview_size(&self, frame_w: u32, frame_h: u32) -> (u32, u32) {
+        let longest = frame_w.max(frame_h);
+        if longest <= self.max_dimension {
+            return (frame_w, frame_h);
+        }
+        let scale = f64::from(self.max_dimension) / f64::from(longest);
+        let scaled =
+            |side: u32| ((f64::from(side) * scale).round() as u32).clamp(1, self.max_dimension);
+        (scaled(frame_w), scaled(frame_h))
+    }
+
+    /// Downscale, then blur.
+    pub fn render(&self, frame: &DynamicImage) -> Result<DynamicImage, VisionError> {
+        let (w, h) = self.preview_size(frame.width(), frame.height());
+        let small = thumbnail::fast_resize(frame, w, h)?;
+        Ok(small.blur(self.blur_sigma))
+    }
+
+    pub fn encode(&self, frame: &DynamicImage) -> Result<FramePreview, VisionError> {
+        let preview = self.render(frame)?;
+        Ok(FramePreview {
+            data: encoder::encode_webp_base64(&preview, WebPQuality::Low)?,
+            format: "webp".to_string(),
+            width: preview.width(),
+            height: preview.height(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use image::{GenericImageView, Rgba, RgbaImage};
+    use oneshim_core::config::PrivacyPreviewConfig;
+
+    /// Mean absolute per-channel difference of two same-sized images.
+    fn mean_abs_diff(a: &DynamicImage, b: &DynamicImage) -> f64 {
+        assert_eq!(a.dimensions(), b.dimensions());
+        let (mut total, mut count) = (0u64, 0u64);
+        for (x, y, p) in a.pixels() {
+            let q = b.get_pixel(x, y);
+            for c in 0..3 {
+                total += u64::from(p[c].abs_diff(q[c]));
+                count += 1;
+            }
+        }
+        total as f64 / count as f64
+    }
+
+    #[test]
+    fn preview_fits_within_max_dimension() {
+        let preview = PrivacyPreview::new(96, 2.0);
+        assert_eq!(preview.preview_size(1920, 1080), (96, 54));
+        assert_eq!(preview.preview_size(600, 2000), (29, 96));
+        assert_eq!(preview.preview_size(5120, 10), (96, 1));
+        assert_eq!(preview.preview_size(40, 30), (40, 30));
+
+        let frame = DynamicImage::ImageRgba8(RgbaImage::new(2560, 1440));
+        let encoded = preview.encode(&frame).unwrap();
+        assert!(encoded.width <= 96 && encoded.height <= 96);
+        assert_eq!((encoded.width, encoded.height), (96, 54));
+        assert_eq!(encoded.format, "webp");
+        assert!(!encoded.data.is_empty());
+    }
+
+    #[test]
+    fn preview_differs_substantially_from_source() {
+        // Text-like strokes: sampling the source at preview size keeps them,
+        // the preview must wash them out.
+        let source = DynamicImage::ImageRgba8(RgbaImage::from_fn(1920, 1080, |x, y| {
+            let stroke = (y % 24) < 12 && (x % 6) < 2 && (y % 4) < 3;
+            if stroke {
+                Rgba([20, 20, 20, 255])
+            } else {
+                Rgba([250, 250, 250, 255])
+            }
+        }));
+        let preview = PrivacyPreview::new(96, 2.0).render(&source).unwrap();
+        let sampled = source.resize_exact(
+            preview.width(),
+            preview.height(),
+            image::imageops::FilterType::Nearest,
+        );
+        assert!(mean_abs_diff(&preview, &sampled) > 30.0);
+    }
+
+    #[test]
+    fn preview_blurs_on_top_of_downscaling() {
+        // 40px checkerboard survives a 10x downscale as 4px cells; the blur
+        // must still flatten it.
+        let source = DynamicImage::ImageRgba8(RgbaImage::from_fn(960, 960, |x, y| {
+            if ((x / 40) + (y / 40)) % 2 == 0 {
+                Rgba([0, 0, 0, 255])
+            } else {
+                Rgba([255, 255, 255, 255])
+            }
+        }));
+        let preview = PrivacyPreview::new(96, 2.0).render(&source).unwrap();
+        let downscaled_only = thumbnail::fast_resize(&source, 96, 96).unwrap();
+        assert!(mean_abs_diff(&preview, &downscaled_only) > 60.0);
+    }
+
+    #[test]
+    fn from_config_respects_enabled_flag() {
+        let mut privacy = PrivacyConfig::default();
+        assert!(PrivacyPreview::from_config(&privacy).is_none());
+        privacy.preview = PrivacyPreviewConfig {
+            enabled: true,
+            max_dimension: 64,
+            blur_sigma: 3.0,
+        };
+        assert_eq!(
+            PrivacyPreview::from_config(&privacy),
+            Some(PrivacyPreview::new(64, 3.0))
+        );
+    }
+}
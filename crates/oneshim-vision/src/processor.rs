@@ -1,26 +1,46 @@
 use async_trait::async_trait;
 use chrono::Utc;
 use image::DynamicImage;
+use oneshim_core::consent::{Capability, ConsentRegistry};
 use oneshim_core::error::CoreError;
-use oneshim_core::models::frame::{FrameMetadata, ImagePayload, ProcessedFrame};
+use oneshim_core::models::capture_latency::{CaptureLatencyRecorder, CaptureStage};
+use oneshim_core::models::frame::{FrameMetadata, ImagePayload, OcrRegion, ProcessedFrame};
 use oneshim_core::ports::vision::{CaptureRequest, FrameProcessor};
+use std::collections::HashMap;
 use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
+use std::time::Instant;
 use tracing::debug;
 
-use crate::capture::ScreenCapture;
+use crate::capture::{DisplayGeometry, ScreenCapture};
 use crate::delta;
 use crate::encoder::{self, WebPQuality};
+use crate::notification_mask::NotificationMask;
+use crate::ocr_gate::OcrGate;
 use crate::privacy;
+use crate::privacy_preview::PrivacyPreview;
 use crate::thumbnail;
 
 pub struct EdgeFrameProcessor {
     capture: ScreenCapture,
-    prev_frame: Mutex<Option<Arc<DynamicImage>>>,
+    delta_bases: Mutex<delta::DeltaBases>,
     thumbnail_width: u32,
     thumbnail_height: u32,
     #[cfg(feature = "ocr")]
     ocr_extractor: Option<crate::ocr::OcrExtractor>,
+    /// OCR runs on a copy downscaled to this width; `None` uses the full frame.
+    #[cfg_attr(not(feature = "ocr"), allow(dead_code))]
+    ocr_downscale_width: Option<u32>,
+    /// Shut off after the first engine init failure; see [`OcrGate`].
+    ocr_gate: Arc<OcrGate>,
+    ocr_cache: Mutex<OcrCache>,
+    /// Blurs notification banners before anything else sees the frame.
+    notification_mask: Option<NotificationMask>,
+    /// Low-detail rendition attached to every frame for live viewers.
+    privacy_preview: Option<PrivacyPreview>,
+    latency: Option<Arc<CaptureLatencyRecorder>>,
+    /// Checked on every frame so a revoked capability stops immediately.
+    consent_registry: Option<Arc<ConsentRegistry>>,
 }
 
 impl EdgeFrameProcessor {
@@ -28,15 +48,200 @@ impl EdgeFrameProcessor {
     pub fn new(thumbnail_width: u32, thumbnail_height: u32, ocr_tessdata: Option<PathBuf>) -> Self {
         Self {
             capture: ScreenCapture::new(),
-            prev_frame: Mutex::new(None),
+            delta_bases: Mutex::new(delta::DeltaBases::default()),
             thumbnail_width,
             thumbnail_height,
             #[cfg(feature = "ocr")]
             ocr_extractor: ocr_tessdata
                 .map(|p| crate::ocr::OcrExtractor::new(Some(p)))
                 .or_else(|| Some(crate::ocr::OcrExtractor::new(None))),
+            ocr_downscale_width: None,
+            ocr_gate: Arc::new(OcrGate::new()),
+            ocr_cache: Mutex::new(OcrCache::default()),
+            notification_mask: None,
+            privacy_preview: None,
+            latency: None,
+            consent_registry: None,
         }
     }
+
+    pub fn with_notification_mask(mut self, mask: Option<NotificationMask>) -> Self {
+        self.notification_mask = mask;
+        self
+    }
+
+    pub fn with_privacy_preview(mut self, preview: Option<PrivacyPreview>) -> Self {
+        self.privacy_preview = preview;
+        self
+    }
+
+    /// Record capture, encode and OCR durations of every processed frame.
+    pub fn with_latency_recorder(mut self, recorder: Option<Arc<CaptureLatencyRecorder>>) -> Self {
+        self.latency = recorder;
+        self
+    }
+
+    /// Trade some OCR accuracy for speed on large frames. Frames already
+    /// narrower than `width` are OCR'd as-is.
+    pub fn with_ocr_downscale_width(mut self, width: Option<u32>) -> Self {
+        self.ocr_downscale_width = width.filter(|w| *w > 0);
+        self
+    }
+
+    /// Refuse to capture without `ScreenCapture` consent and skip OCR without
+    /// `Ocr` consent. `None` leaves gating to the caller.
+    pub fn with_consent_registry(mut self, registry: Option<Arc<ConsentRegistry>>) -> Self {
+        self.consent_registry = registry;
+        self
+    }
+
+    fn consent_allows(&self, capability: Capability) -> bool {
+        self.consent_registry
+            .as_ref()
+            .map_or(true, |registry| registry.is_granted(capability))
+    }
+
+    fn require_consent(&self, capability: Capability) -> Result<(), CoreError> {
+        if self.consent_allows(capability) {
+            Ok(())
+        } else {
+            Err(CoreError::ConsentRequired {
+                code: oneshim_core::error_codes::ConsentCode::Required,
+                message: format!("{} consent not granted", capability.as_str()),
+            })
+        }
+    }
+
+    /// Handle for re-enabling OCR (e.g. on config reload) after the engine
+    /// failed to initialise.
+    pub fn ocr_gate(&self) -> Arc<OcrGate> {
+        Arc::clone(&self.ocr_gate)
+    }
+
+    fn record_latency(&self, stage: CaptureStage, started: Instant) {
+        if let Some(recorder) = &self.latency {
+            recorder.record(stage, started.elapsed());
+        }
+    }
+}
+
+/// Frames whose tile change ratio against the cached OCR frame stays below
+/// this reuse the cached text instead of running OCR again.
+const OCR_REUSE_MAX_CHANGE: f32 = 0.02;
+
+type OcrOutput = (Option<String>, Vec<OcrRegion>);
+
+struct CachedOcr {
+    frame: Arc<DynamicImage>,
+    output: OcrOutput,
+}
+
+/// Last OCR result per display. Cleared with the delta bases when the
+/// display layout changes, so a keyframe always gets fresh OCR.
+#[derive(Default)]
+struct OcrCache {
+    entries: HashMap<DisplayGeometry, CachedOcr>,
+}
+
+impl OcrCache {
+    /// Cached output when `frame` barely differs from the frame it was
+    /// computed for; otherwise runs `ocr` and caches a non-empty result.
+    fn get_or_run(
+        &mut self,
+        display: DisplayGeometry,
+        frame: &Arc<DynamicImage>,
+        ocr: impl FnOnce() -> OcrOutput,
+    ) -> OcrOutput {
+        if let Some(cached) = self.entries.get(&display) {
+            let reusable = match delta::compute_delta(&cached.frame, frame) {
+                Some(changed) => changed.changed_ratio < OCR_REUSE_MAX_CHANGE,
+                None => true,
+            };
+            if reusable {
+                debug!("OCR reused from cached frame");
+                return cached.output.clone();
+            }
+        }
+
+        let output = ocr();
+        if output.0.is_some() || !output.1.is_empty() {
+            self.entries.insert(
+                display,
+                CachedOcr {
+                    frame: Arc::clone(frame),
+                    output: output.clone(),
+                },
+            );
+        } else {
+            self.entries.remove(&display);
+        }
+        output
+    }
+
+    fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// OCR input image plus the factors that map its pixels back to the frame.
+#[cfg(feature = "ocr")]
+struct OcrInput<'a> {
+    image: std::borrow::Cow<'a, DynamicImage>,
+    scale_x: f64,
+    scale_y: f64,
+}
+
+#[cfg(feature = "ocr")]
+fn prepare_ocr_input(frame: &DynamicImage, downscale_width: Option<u32>) -> OcrInput<'_> {
+    let (w, h) = (frame.width(), frame.height());
+    let full = || OcrInput {
+        image: std::borrow::Cow::Borrowed(frame),
+        scale_x: 1.0,
+        scale_y: 1.0,
+    };
+    let Some(target_w) = downscale_width.filter(|tw| *tw < w && h > 0) else {
+        return full();
+    };
+    let target_h = ((u64::from(h) * u64::from(target_w)) / u64::from(w)).max(1) as u32;
+    match thumbnail::fast_resize(frame, target_w, target_h) {
+        Ok(image) => OcrInput {
+            scale_x: f64::from(w) / f64::from(target_w),
+            scale_y: f64::from(h) / f64::from(target_h),
+            image: std::borrow::Cow::Owned(image),
+        },
+        Err(e) => {
+            tracing::warn!("OCR downscale failed, using full frame: {e}");
+            full()
+        }
+    }
+}
+
+/// Map OCR boxes from the downscaled image back to full-frame pixels,
+/// clamped to the frame bounds.
+#[cfg(feature = "ocr")]
+fn scale_regions_to_frame(
+    regions: Vec<oneshim_core::models::frame::OcrRegion>,
+    input: &OcrInput<'_>,
+    frame_w: u32,
+    frame_h: u32,
+) -> Vec<oneshim_core::models::frame::OcrRegion> {
+    if matches!(input.image, std::borrow::Cow::Borrowed(_)) {
+        return regions;
+    }
+    let scale =
+        |v: u32, factor: f64, limit: u32| ((f64::from(v) * factor).round() as u32).min(limit);
+    regions
+        .into_iter()
+        .map(|mut region| {
+            let x = scale(region.bbox.x, input.scale_x, frame_w);
+            let y = scale(region.bbox.y, input.scale_y, frame_h);
+            region.bbox.width = scale(region.bbox.width, input.scale_x, frame_w - x);
+            region.bbox.height = scale(region.bbox.height, input.scale_y, frame_h - y);
+            region.bbox.x = x;
+            region.bbox.y = y;
+            region
+        })
+        .collect()
 }
 
 #[cfg(not(feature = "ocr"))]
@@ -47,7 +252,7 @@ fn extract_ocr_text(_frame: &DynamicImage, _processor: &EdgeFrameProcessor) -> O
 #[cfg(feature = "ocr")]
 fn extract_ocr_text(frame: &DynamicImage, processor: &EdgeFrameProcessor) -> Option<String> {
     let extractor = processor.ocr_extractor.as_ref()?;
-    match extractor.extract(frame) {
+    match processor.ocr_gate.run(|| extractor.extract(frame))? {
         Ok(text) if !text.is_empty() => {
             let sanitized = privacy::sanitize_title(&text);
             Some(sanitized)
@@ -60,6 +265,36 @@ fn extract_ocr_text(frame: &DynamicImage, processor: &EdgeFrameProcessor) -> Opt
     }
 }
 
+/// OCR text and regions for a captured frame. Recognition runs on the
+/// (optionally downscaled) OCR input; regions come back in frame coordinates.
+#[cfg(not(feature = "ocr"))]
+fn run_ocr(
+    frame: &DynamicImage,
+    processor: &EdgeFrameProcessor,
+) -> (Option<String>, Vec<oneshim_core::models::frame::OcrRegion>) {
+    (
+        extract_ocr_text(frame, processor),
+        extract_ocr_regions(frame, processor),
+    )
+}
+
+#[cfg(feature = "ocr")]
+fn run_ocr(
+    frame: &DynamicImage,
+    processor: &EdgeFrameProcessor,
+) -> (Option<String>, Vec<oneshim_core::models::frame::OcrRegion>) {
+    if processor.ocr_extractor.is_none() || !processor.ocr_gate.is_enabled() {
+        return (None, Vec::new());
+    }
+    let input = prepare_ocr_input(frame, processor.ocr_downscale_width);
+    let text = extract_ocr_text(&input.image, processor);
+    let regions = extract_ocr_regions(&input.image, processor);
+    (
+        text,
+        scale_regions_to_frame(regions, &input, frame.width(), frame.height()),
+    )
+}
+
 /// Extract OCR regions with bounding boxes from the frame.
 /// Returns empty Vec when OCR feature is disabled.
 #[cfg(not(feature = "ocr"))]
@@ -79,7 +314,10 @@ fn extract_ocr_regions(
     let Some(extractor) = processor.ocr_extractor.as_ref() else {
         return Vec::new();
     };
-    match extractor.extract_regions(frame) {
+    let Some(result) = processor.ocr_gate.run(|| extractor.extract_regions(frame)) else {
+        return Vec::new();
+    };
+    match result {
         Ok(regions) => {
             debug!("OCR extracted {} regions", regions.len());
             regions
@@ -97,15 +335,45 @@ impl FrameProcessor for EdgeFrameProcessor {
         &self,
         capture_request: &CaptureRequest,
     ) -> Result<ProcessedFrame, CoreError> {
+        self.require_consent(Capability::ScreenCapture)?;
         let sanitized_title = privacy::sanitize_title(&capture_request.window_title);
         let importance = capture_request.importance;
 
-        let current_frame = Arc::new(
-            self.capture
-                .capture_for_window(capture_request.window_bounds.as_ref())?,
-        );
+        let capture_started = Instant::now();
+        let capture = self
+            .capture
+            .capture_for_window_with_layout(capture_request.window_bounds.as_ref())?;
+        self.record_latency(CaptureStage::Capture, capture_started);
+        let display = capture.display;
+        let mut image = capture.image;
+        if let Some(mask) = &self.notification_mask {
+            if let Some(rect) = mask.apply(&mut image) {
+                debug!(?rect, "notification banner area masked");
+            }
+        }
+        let current_frame = Arc::new(image);
         let (w, h) = (current_frame.width(), current_frame.height());
 
+        // A changed display layout invalidates every delta base; the next
+        // medium-importance frame is sent as a keyframe.
+        let layout_changed = self
+            .delta_bases
+            .lock()
+            .map_err(|e| CoreError::Internal {
+                code: oneshim_core::error_codes::InternalCode::Generic,
+                message: format!("delta_bases lock poisoned: {e}"),
+            })?
+            .observe_layout(&capture.layout);
+        if layout_changed {
+            self.ocr_cache
+                .lock()
+                .map_err(|e| CoreError::Internal {
+                    code: oneshim_core::error_codes::InternalCode::Generic,
+                    message: format!("ocr_cache lock poisoned: {e}"),
+                })?
+                .clear();
+        }
+
         let metadata = FrameMetadata {
             timestamp: Utc::now(),
             trigger_type: capture_request.trigger_type.clone(),
@@ -122,6 +390,7 @@ impl FrameProcessor for EdgeFrameProcessor {
             debug!("frame (in progress {:.1})", importance);
             // Offload heavy High-quality encoding to blocking thread
             let frame_ref = Arc::clone(&current_frame);
+            let encode_started = Instant::now();
             let encoded = tokio::task::spawn_blocking(move || {
                 encoder::encode_webp_base64(&frame_ref, WebPQuality::High)
             })
@@ -130,8 +399,24 @@ impl FrameProcessor for EdgeFrameProcessor {
                 code: oneshim_core::error_codes::InternalCode::Generic,
                 message: format!("encode task panicked: {e}"),
             })??;
-            let ocr_text = extract_ocr_text(&current_frame, self);
-            ocr_regions = extract_ocr_regions(&current_frame, self);
+            self.record_latency(CaptureStage::Encode, encode_started);
+            let (ocr_text, regions) = if self.consent_allows(Capability::Ocr) {
+                self.ocr_cache
+                    .lock()
+                    .map_err(|e| CoreError::Internal {
+                        code: oneshim_core::error_codes::InternalCode::Generic,
+                        message: format!("ocr_cache lock poisoned: {e}"),
+                    })?
+                    .get_or_run(display, &current_frame, || {
+                        let ocr_started = Instant::now();
+                        let output = run_ocr(&current_frame, self);
+                        self.record_latency(CaptureStage::Ocr, ocr_started);
+                        output
+                    })
+            } else {
+                (None, Vec::new())
+            };
+            ocr_regions = regions;
             // Preserve raw RGBA for ML classifier (before current_frame is moved)
             if !ocr_regions.is_empty() {
                 raw_rgba = Some(current_frame.to_rgba8().into_vec());
@@ -143,29 +428,20 @@ impl FrameProcessor for EdgeFrameProcessor {
             })
         } else if importance >= 0.5 {
             debug!("(in progress {:.1})", importance);
-            // Compute delta while holding the lock, then drop before .await
-            let delta_result = {
-                let prev = self.prev_frame.lock().map_err(|e| CoreError::Internal {
+            // Look up the base while holding the lock, then drop before .await
+            let base = self
+                .delta_bases
+                .lock()
+                .map_err(|e| CoreError::Internal {
                     code: oneshim_core::error_codes::InternalCode::Generic,
-                    message: format!("prev_frame lock poisoned: {e}"),
-                })?;
-                match prev.as_ref() {
-                    Some(prev) => delta::compute_delta(prev, &current_frame),
-                    None => None, // marker: no prev frame
-                }
-            }; // MutexGuard dropped here
+                    message: format!("delta_bases lock poisoned: {e}"),
+                })?
+                .base_for(&display, &current_frame);
 
-            let has_prev = {
-                let prev = self.prev_frame.lock().map_err(|e| CoreError::Internal {
-                    code: oneshim_core::error_codes::InternalCode::Generic,
-                    message: format!("prev_frame lock poisoned: {e}"),
-                })?;
-                prev.is_some()
-            };
-
-            if has_prev {
-                if let Some(delta_region) = delta_result {
+            if let Some(base) = base {
+                if let Some(delta_region) = delta::compute_delta(&base, &current_frame) {
                     let frame_ref = Arc::clone(&current_frame);
+                    let encode_started = Instant::now();
                     let encoded = tokio::task::spawn_blocking(move || {
                         encoder::encode_webp_base64(&frame_ref, WebPQuality::Medium)
                     })
@@ -174,6 +450,7 @@ impl FrameProcessor for EdgeFrameProcessor {
                         code: oneshim_core::error_codes::InternalCode::Generic,
                         message: format!("encode task panicked: {e}"),
                     })??;
+                    self.record_latency(CaptureStage::Encode, encode_started);
                     Some(ImagePayload::Delta {
                         data: encoded,
                         region: delta_region.region,
@@ -184,6 +461,7 @@ impl FrameProcessor for EdgeFrameProcessor {
                 }
             } else {
                 let frame_ref = Arc::clone(&current_frame);
+                let encode_started = Instant::now();
                 let encoded = tokio::task::spawn_blocking(move || {
                     encoder::encode_webp_base64(&frame_ref, WebPQuality::Medium)
                 })
@@ -192,6 +470,7 @@ impl FrameProcessor for EdgeFrameProcessor {
                     code: oneshim_core::error_codes::InternalCode::Generic,
                     message: format!("encode task panicked: {e}"),
                 })??;
+                self.record_latency(CaptureStage::Encode, encode_started);
                 Some(ImagePayload::Full {
                     data: encoded,
                     format: "webp".to_string(),
@@ -203,6 +482,7 @@ impl FrameProcessor for EdgeFrameProcessor {
             let tw = self.thumbnail_width;
             let th = self.thumbnail_height;
             let frame_ref = Arc::clone(&current_frame);
+            let encode_started = Instant::now();
             let encoded = tokio::task::spawn_blocking(move || {
                 let thumb = thumbnail::fast_resize(&frame_ref, tw, th)?;
                 encoder::encode_webp_base64(&thumb, WebPQuality::Low)
@@ -212,6 +492,7 @@ impl FrameProcessor for EdgeFrameProcessor {
                 code: oneshim_core::error_codes::InternalCode::Generic,
                 message: format!("encode task panicked: {e}"),
             })??;
+            self.record_latency(CaptureStage::Encode, encode_started);
             Some(ImagePayload::Thumbnail {
                 data: encoded,
                 width: self.thumbnail_width,
@@ -222,20 +503,43 @@ impl FrameProcessor for EdgeFrameProcessor {
             None
         };
 
-        *self.prev_frame.lock().map_err(|e| CoreError::Internal {
-            code: oneshim_core::error_codes::InternalCode::Generic,
-            message: format!("prev_frame lock poisoned: {e}"),
-        })? = Some(current_frame);
+        let preview = match self.privacy_preview {
+            Some(settings) => {
+                let frame_ref = Arc::clone(&current_frame);
+                match tokio::task::spawn_blocking(move || settings.encode(&frame_ref)).await {
+                    Ok(Ok(preview)) => Some(preview),
+                    Ok(Err(e)) => {
+                        debug!("privacy preview skipped: {e}");
+                        None
+                    }
+                    Err(e) => {
+                        debug!("privacy preview task panicked: {e}");
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
+        self.delta_bases
+            .lock()
+            .map_err(|e| CoreError::Internal {
+                code: oneshim_core::error_codes::InternalCode::Generic,
+                message: format!("delta_bases lock poisoned: {e}"),
+            })?
+            .set_base(display, current_frame);
 
         Ok(ProcessedFrame {
             metadata,
             image_payload,
             ocr_regions,
             raw_rgba,
+            preview,
         })
     }
 
     async fn capture_thumbnail(&self) -> Result<Vec<u8>, CoreError> {
+        self.require_consent(Capability::ScreenCapture)?;
         let capture = self.capture.clone();
         let tw = self.thumbnail_width;
         let th = self.thumbnail_height;
@@ -278,7 +582,35 @@ mod tests {
         let proc = EdgeFrameProcessor::new(480, 270, None);
         assert_eq!(proc.thumbnail_width, 480);
         assert_eq!(proc.thumbnail_height, 270);
-        assert!(proc.prev_frame.lock().unwrap().is_none());
+        assert!(proc.delta_bases.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn capture_refused_once_screen_capture_consent_revoked() {
+        let dir = tempfile::tempdir().unwrap();
+        let registry = Arc::new(ConsentRegistry::open(dir.path().join("consent.json")));
+        registry.revoke(Capability::ScreenCapture).unwrap();
+        let proc =
+            EdgeFrameProcessor::new(480, 270, None).with_consent_registry(Some(registry.clone()));
+
+        let result = proc
+            .capture_and_process(&CaptureRequest {
+                trigger_type: "test".to_string(),
+                importance: 0.9,
+                app_name: "Editor".to_string(),
+                window_title: "notes.md".to_string(),
+                window_bounds: None,
+            })
+            .await;
+        assert!(matches!(result, Err(CoreError::ConsentRequired { .. })));
+        assert!(matches!(
+            proc.capture_thumbnail().await,
+            Err(CoreError::ConsentRequired { .. })
+        ));
+        assert!(!proc.consent_allows(Capability::ScreenCapture));
+
+        registry.grant(Capability::ScreenCapture).unwrap();
+        assert!(proc.consent_allows(Capability::ScreenCapture));
     }
 
     #[test]
@@ -314,6 +646,139 @@ mod tests {
         assert_eq!(thumb.height(), 270);
     }
 
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn failing_ocr_init_degrades_to_image_only() {
+        let proc = EdgeFrameProcessor::new(
+            480,
+            270,
+            Some(PathBuf::from("/nonexistent/oneshim-tessdata")),
+        );
+        let frame = make_test_image(640, 480);
+
+        let (text, regions) = run_ocr(&frame, &proc);
+        assert!(text.is_none());
+        assert!(regions.is_empty());
+        assert!(!proc.ocr_gate().is_enabled());
+
+        // Later frames skip OCR instead of retrying the broken engine.
+        assert_eq!(run_ocr(&frame, &proc), (None, Vec::new()));
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn ocr_input_is_downscaled_to_configured_width() {
+        let proc = EdgeFrameProcessor::new(480, 270, None).with_ocr_downscale_width(Some(1280));
+        let frame = make_test_image(3840, 2160);
+        let input = prepare_ocr_input(&frame, proc.ocr_downscale_width);
+        assert_eq!((input.image.width(), input.image.height()), (1280, 720));
+        assert!((input.scale_x - 3.0).abs() < f64::EPSILON);
+        assert!((input.scale_y - 3.0).abs() < f64::EPSILON);
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn ocr_input_keeps_frames_narrower_than_target() {
+        let frame = make_test_image(1024, 768);
+        let input = prepare_ocr_input(&frame, Some(1280));
+        assert_eq!(input.image.width(), 1024);
+        assert!((input.scale_x - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn ocr_boxes_map_back_to_full_frame() {
+        use oneshim_core::models::frame::{BoundingBox, OcrRegion};
+
+        let frame = make_test_image(2560, 1440);
+        let input = prepare_ocr_input(&frame, Some(1000));
+        assert_eq!(input.image.width(), 1000);
+
+        // Box drawn at (1000, 500)-(1400, 560) in the full frame, as the
+        // recognizer would report it on the downscaled copy.
+        let (fx, fy, fw, fh) = (1000.0_f64, 500.0_f64, 400.0_f64, 60.0_f64);
+        let small = OcrRegion {
+            text: "Invoice".to_string(),
+            bbox: BoundingBox {
+                x: (fx / input.scale_x).round() as u32,
+                y: (fy / input.scale_y).round() as u32,
+                width: (fw / input.scale_x).round() as u32,
+                height: (fh / input.scale_y).round() as u32,
+            },
+            confidence: 0.9,
+        };
+
+        let mapped = scale_regions_to_frame(vec![small], &input, 2560, 1440);
+        let bbox = &mapped[0].bbox;
+        let tolerance = input.scale_x.ceil() as i64;
+        assert!((i64::from(bbox.x) - fx as i64).abs() <= tolerance);
+        assert!((i64::from(bbox.y) - fy as i64).abs() <= tolerance);
+        assert!((i64::from(bbox.width) - fw as i64).abs() <= tolerance);
+        assert!((i64::from(bbox.height) - fh as i64).abs() <= tolerance);
+        assert!(bbox.x + bbox.width <= 2560 && bbox.y + bbox.height <= 1440);
+    }
+
+    #[cfg(feature = "ocr")]
+    fn ocr_display() -> DisplayGeometry {
+        DisplayGeometry {
+            x: 0,
+            y: 0,
+            width: 640,
+            height: 480,
+        }
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn low_change_frame_reuses_cached_ocr() {
+        let mut cache = OcrCache::default();
+        let runs = std::cell::Cell::new(0);
+        let ocr = || {
+            runs.set(runs.get() + 1);
+            (Some(format!("run {}", runs.get())), Vec::new())
+        };
+
+        let first = Arc::new(make_test_image(640, 480));
+        assert_eq!(
+            cache.get_or_run(ocr_display(), &first, ocr).0.as_deref(),
+            Some("run 1")
+        );
+
+        let mut nearly_same = make_test_image(640, 480).to_rgba8();
+        nearly_same.put_pixel(10, 10, image::Rgba([0, 0, 0, 255]));
+        let nearly_same = Arc::new(DynamicImage::ImageRgba8(nearly_same));
+        let reused = cache.get_or_run(ocr_display(), &nearly_same, ocr);
+        assert_eq!(reused.0.as_deref(), Some("run 1"));
+        assert_eq!(runs.get(), 1);
+    }
+
+    #[cfg(feature = "ocr")]
+    #[test]
+    fn high_change_frame_runs_fresh_ocr() {
+        let mut cache = OcrCache::default();
+        let runs = std::cell::Cell::new(0);
+        let ocr = || {
+            runs.set(runs.get() + 1);
+            (Some(format!("run {}", runs.get())), Vec::new())
+        };
+
+        let first = Arc::new(make_test_image(640, 480));
+        cache.get_or_run(ocr_display(), &first, ocr);
+
+        let changed = Arc::new(DynamicImage::ImageRgba8(RgbaImage::from_pixel(
+            640,
+            480,
+            image::Rgba([10, 20, 30, 255]),
+        )));
+        let fresh = cache.get_or_run(ocr_display(), &changed, ocr);
+        assert_eq!(fresh.0.as_deref(), Some("run 2"));
+
+        // A keyframe after a layout change clears the cache.
+        cache.clear();
+        cache.get_or_run(ocr_display(), &changed, ocr);
+        assert_eq!(runs.get(), 3);
+    }
+
     #[test]
     fn privacy_sanitization_in_pipeline() {
         let title = "Login - admin@company.com - Firefox";
@@ -1,7 +1,9 @@
 use chrono::{DateTime, Duration, Utc};
+use oneshim_core::config::CaptureSignalTriggers;
 use oneshim_core::models::event::ContextEvent;
 use oneshim_core::ports::vision::CaptureRequest;
-use oneshim_core::ports::vision::CaptureTrigger;
+use oneshim_core::ports::vision::{CaptureSignal, CaptureTrigger};
+use oneshim_core::title_pattern::PatternSet;
 use std::sync::Mutex;
 use tracing::{debug, error};
 
@@ -13,6 +15,10 @@ pub enum TriggerType {
     FormSubmission,
     ContextSwitch,
     TitleChange,
+    ClipboardChange,
+    FileSave,
+    /// Long continuous focus on one window with no capture in between.
+    Dwell,
     Regular,
 }
 
@@ -20,11 +26,17 @@ struct TriggerState {
     last_capture: Option<DateTime<Utc>>,
     prev_app_name: Option<String>,
     prev_window_title: Option<String>,
+    /// When the current window last produced a non-`Regular` capture.
+    dwell_anchor: Option<DateTime<Utc>>,
 }
 
 pub struct SmartCaptureTrigger {
     state: Mutex<TriggerState>,
     throttle_ms: u64,
+    signal_triggers: CaptureSignalTriggers,
+    /// Windows whose title matches are never captured.
+    excluded_titles: PatternSet,
+    dwell: Option<Duration>,
 }
 
 impl SmartCaptureTrigger {
@@ -34,11 +46,34 @@ impl SmartCaptureTrigger {
                 last_capture: None,
                 prev_app_name: None,
                 prev_window_title: None,
+                dwell_anchor: None,
             }),
             throttle_ms,
+            signal_triggers: CaptureSignalTriggers::default(),
+            excluded_titles: PatternSet::default(),
+            dwell: None,
         }
     }
 
+    /// Capture after `dwell_secs` of unchanged focus since the last
+    /// non-`Regular` capture. `None` disables the dwell trigger.
+    pub fn with_dwell_secs(mut self, dwell_secs: Option<u64>) -> Self {
+        self.dwell = dwell_secs.map(|secs| Duration::seconds(secs as i64));
+        self
+    }
+
+    /// Skip capture for windows whose title matches `excluded_titles`.
+    pub fn with_title_exclusions(mut self, excluded_titles: PatternSet) -> Self {
+        self.excluded_titles = excluded_titles;
+        self
+    }
+
+    /// Enable capture on clipboard and file-save signals.
+    pub fn with_signal_triggers(mut self, signal_triggers: CaptureSignalTriggers) -> Self {
+        self.signal_triggers = signal_triggers;
+        self
+    }
+
     fn classify_event(event: &ContextEvent, state: &TriggerState) -> TriggerType {
         let title_lower = event.window_title.to_lowercase();
         if title_lower.contains("error") || title_lower.contains("exception") {
@@ -77,6 +112,10 @@ impl SmartCaptureTrigger {
             TriggerType::WindowChange => 0.6,
             TriggerType::TitleChange => 0.5,
             TriggerType::SignificantAction => 0.5,
+            TriggerType::ClipboardChange => 0.5,
+            TriggerType::FileSave => 0.5,
+            // Needs >= 0.5 so the processor stores an image, not just metadata.
+            TriggerType::Dwell => 0.5,
             TriggerType::Regular => 0.2,
         }
     }
@@ -94,14 +133,35 @@ impl SmartCaptureTrigger {
             None => false,
         }
     }
-}
 
-impl CaptureTrigger for SmartCaptureTrigger {
+    fn dwell_elapsed(&self, state: &TriggerState, now: DateTime<Utc>) -> bool {
+        match (self.dwell, state.dwell_anchor) {
+            (Some(dwell), Some(anchor)) => now - anchor >= dwell,
+            _ => false,
+        }
+    }
+
+    fn signal_enabled(&self, signal: CaptureSignal) -> bool {
+        match signal {
+            CaptureSignal::ClipboardChange => self.signal_triggers.clipboard,
+            CaptureSignal::FileSave => self.signal_triggers.file_save,
+        }
+    }
+
     // P2 PR-A: state mutex is held across classify + importance calc + throttle
     // check + mutation as one atomic "observe-then-update" window. Tightening
     // would create a race where two events see the same prev_app_name.
     #[allow(clippy::significant_drop_tightening)]
-    fn should_capture(&self, event: &ContextEvent) -> Option<CaptureRequest> {
+    fn decide(
+        &self,
+        event: &ContextEvent,
+        signal: Option<CaptureSignal>,
+    ) -> Option<CaptureRequest> {
+        if self.excluded_titles.is_match(&event.window_title) {
+            debug!("capture skipped: window title excluded by pattern");
+            return None;
+        }
+
         let mut state = self
             .state
             .lock()
@@ -110,7 +170,14 @@ impl CaptureTrigger for SmartCaptureTrigger {
             })
             .ok()?;
         let now = event.timestamp;
-        let trigger_type = Self::classify_event(event, &state);
+        let trigger_type = match signal {
+            Some(CaptureSignal::ClipboardChange) => TriggerType::ClipboardChange,
+            Some(CaptureSignal::FileSave) => TriggerType::FileSave,
+            None => match Self::classify_event(event, &state) {
+                TriggerType::Regular if self.dwell_elapsed(&state, now) => TriggerType::Dwell,
+                other => other,
+            },
+        };
         let base_importance = self.compute_importance(&trigger_type);
         // Boost importance based on input activity: up to +0.3 when user is active
         let input_boost = (event.input_activity_level * 0.3).min(0.3);
@@ -127,6 +194,9 @@ impl CaptureTrigger for SmartCaptureTrigger {
         state.last_capture = Some(now);
         state.prev_app_name = Some(event.app_name.clone());
         state.prev_window_title = Some(event.window_title.clone());
+        if trigger_type != TriggerType::Regular || state.dwell_anchor.is_none() {
+            state.dwell_anchor = Some(now);
+        }
 
         let trigger_type_str = format!("{:?}", trigger_type);
         debug!(
@@ -146,6 +216,23 @@ impl CaptureTrigger for SmartCaptureTrigger {
     }
 }
 
+impl CaptureTrigger for SmartCaptureTrigger {
+    fn should_capture(&self, event: &ContextEvent) -> Option<CaptureRequest> {
+        self.decide(event, None)
+    }
+
+    fn should_capture_signal(
+        &self,
+        signal: CaptureSignal,
+        event: &ContextEvent,
+    ) -> Option<CaptureRequest> {
+        if !self.signal_enabled(signal) {
+            return None;
+        }
+        self.decide(event, Some(signal))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -180,6 +267,26 @@ mod tests {
         assert!(req.unwrap().importance >= 0.8);
     }
 
+    #[test]
+    fn excluded_title_suppresses_capture_within_same_app() {
+        let excluded = PatternSet::compile(&["*bank*".to_string()]).unwrap();
+        let trigger = SmartCaptureTrigger::new(0)
+            .with_signal_triggers(CaptureSignalTriggers {
+                clipboard: true,
+                file_save: true,
+            })
+            .with_title_exclusions(excluded);
+
+        let bank_tab = make_event("Google Chrome", "My Bank - Login", Some("Code"));
+        assert!(trigger.should_capture(&bank_tab).is_none());
+        assert!(trigger
+            .should_capture_signal(CaptureSignal::FileSave, &bank_tab)
+            .is_none());
+
+        let other_tab = make_event("Google Chrome", "Rust docs", Some("Code"));
+        assert!(trigger.should_capture(&other_tab).is_some());
+    }
+
     #[test]
     fn throttle_low_importance() {
         let trigger = SmartCaptureTrigger::new(5000);
@@ -249,6 +356,7 @@ mod tests {
             prev_app_name: None,
             timestamp: Utc::now(),
             input_activity_level: 1.0, // max activity
+            repeat_count: 0,
         };
         let req = trigger.should_capture(&event2).unwrap();
         // Regular (0.2) + boost (1.0 * 0.3 = 0.3) = 0.5
@@ -265,12 +373,88 @@ mod tests {
             prev_app_name: None,
             timestamp: Utc::now(),
             input_activity_level: 0.0,
+            repeat_count: 0,
         };
         let req = trigger.should_capture(&event).unwrap();
         // Regular (0.2) + no boost = 0.2
         assert!((req.importance - 0.2).abs() < f32::EPSILON);
     }
 
+    #[test]
+    fn dwell_captures_after_interval_and_resets() {
+        let trigger = SmartCaptureTrigger::new(1_000).with_dwell_secs(Some(60));
+        let t0 = Utc::now();
+        let at = |secs: i64| ContextEvent {
+            app_name: "Preview".to_string(),
+            window_title: "spec.pdf".to_string(),
+            timestamp: t0 + Duration::seconds(secs),
+            ..Default::default()
+        };
+
+        assert!(trigger.should_capture(&at(0)).is_some());
+        let before = trigger.should_capture(&at(30)).unwrap();
+        assert_eq!(before.trigger_type, "Regular");
+
+        let dwell = trigger.should_capture(&at(60)).unwrap();
+        assert_eq!(dwell.trigger_type, "Dwell");
+        assert!(dwell.importance >= 0.5);
+
+        // Timer restarts from the dwell capture.
+        assert_eq!(
+            trigger.should_capture(&at(90)).unwrap().trigger_type,
+            "Regular"
+        );
+        assert_eq!(
+            trigger.should_capture(&at(120)).unwrap().trigger_type,
+            "Dwell"
+        );
+    }
+
+    #[test]
+    fn dwell_timer_restarts_on_window_change() {
+        let trigger = SmartCaptureTrigger::new(1_000).with_dwell_secs(Some(60));
+        let t0 = Utc::now();
+        let at = |title: &str, secs: i64| ContextEvent {
+            app_name: "Preview".to_string(),
+            window_title: title.to_string(),
+            timestamp: t0 + Duration::seconds(secs),
+            ..Default::default()
+        };
+
+        trigger.should_capture(&at("a.pdf", 0));
+        let switched = trigger.should_capture(&at("b.pdf", 50)).unwrap();
+        assert_eq!(switched.trigger_type, "TitleChange");
+        assert_eq!(
+            trigger
+                .should_capture(&at("b.pdf", 70))
+                .unwrap()
+                .trigger_type,
+            "Regular"
+        );
+        assert_eq!(
+            trigger
+                .should_capture(&at("b.pdf", 110))
+                .unwrap()
+                .trigger_type,
+            "Dwell"
+        );
+    }
+
+    #[test]
+    fn dwell_disabled_by_default() {
+        let trigger = SmartCaptureTrigger::new(1_000);
+        let t0 = Utc::now();
+        let at = |secs: i64| ContextEvent {
+            app_name: "Preview".to_string(),
+            window_title: "spec.pdf".to_string(),
+            timestamp: t0 + Duration::seconds(secs),
+            ..Default::default()
+        };
+        trigger.should_capture(&at(0));
+        let later = trigger.should_capture(&at(3_600)).unwrap();
+        assert_eq!(later.trigger_type, "Regular");
+    }
+
     #[test]
     fn importance_scores() {
         let trigger = SmartCaptureTrigger::new(5000);
@@ -322,4 +506,35 @@ mod tests {
             "second call within the throttle window for a low-importance event must return None"
         );
     }
+
+    #[test]
+    fn clipboard_signal_captures_with_its_trigger_type_when_enabled() {
+        let trigger = SmartCaptureTrigger::new(0).with_signal_triggers(CaptureSignalTriggers {
+            clipboard: true,
+            file_save: false,
+        });
+
+        let event = make_event("Code", "main.rs", None);
+        let req = trigger
+            .should_capture_signal(CaptureSignal::ClipboardChange, &event)
+            .expect("enabled clipboard signal should capture");
+        assert_eq!(req.trigger_type, "ClipboardChange");
+        assert_eq!(req.app_name, "Code");
+
+        assert!(trigger
+            .should_capture_signal(CaptureSignal::FileSave, &event)
+            .is_none());
+    }
+
+    #[test]
+    fn signals_are_ignored_by_default() {
+        let trigger = SmartCaptureTrigger::new(0);
+        let event = make_event("Code", "main.rs", None);
+        assert!(trigger
+            .should_capture_signal(CaptureSignal::ClipboardChange, &event)
+            .is_none());
+        assert!(trigger
+            .should_capture_signal(CaptureSignal::FileSave, &event)
+            .is_none());
+    }
 }
@@ -8,12 +8,16 @@ import type {
   AutomationStatus,
   BackupArchive,
   BackupParams,
+  Bookmark,
   CoachingStatsToday,
   CoachingTemplateListDto,
+  CreateBookmarkRequest,
   CreateOverrideRequest,
   CreateTagRequest,
   DailyDigestResponse,
+  SchedulerLoopsResponse,
   DailySummary,
+  DatabaseBackupResult,
   DeleteRangeRequest,
   DeleteResult,
   DesktopPermissionSnapshot,
@@ -78,6 +82,7 @@ import type {
   SemanticSearchResult,
   Session,
   StartPomodoroRequest,
+  StorageIntegrityReport,
   StorageStats,
   SuggestionFeedbackAction,
   SystemMetrics,
@@ -93,6 +98,7 @@ import type {
   UpdateStatus,
   UpdateTagRequest,
   WeeklyDigest,
+  WorkflowExecutionRecord,
   WorkflowPreset,
   WorkSession,
 } from './contracts'
@@ -226,6 +232,28 @@ export async function fetchIdlePeriods(from?: string, to?: string): Promise<Idle
   return res.json()
 }
 
+export async function fetchBookmarks(from?: string, to?: string): Promise<Bookmark[]> {
+  const params = new URLSearchParams()
+  if (from) params.set('from', from)
+  if (to) params.set('to', to)
+  const res = await fetchWithRetry(`${BASE_URL}/bookmarks?${params}`)
+  if (!res.ok) throw new Error('bookmark query failure')
+  return res.json()
+}
+
+export async function createBookmark(request: CreateBookmarkRequest): Promise<Bookmark> {
+  const res = await fetchWithRetry(`${BASE_URL}/bookmarks`, {
+    method: 'POST',
+    headers: { 'Content-Type': 'application/json' },
+    body: JSON.stringify(request),
+  })
+  if (!res.ok) {
+    const err = await res.json().catch(() => ({ error: 'Failed to create bookmark' }))
+    throw new Error(err.error || 'Failed to create bookmark')
+  }
+  return res.json()
+}
+
 export async function fetchSessions(): Promise<Session[]> {
   const res = await fetchWithRetry(`${BASE_URL}/sessions`)
   if (!res.ok) throw new Error('session query failure')
@@ -246,6 +274,12 @@ export async function fetchStorageStats(): Promise<StorageStats> {
   return res.json()
 }
 
+export async function checkStorageIntegrity(): Promise<StorageIntegrityReport> {
+  const res = await fetchWithRetry(`${BASE_URL}/storage/integrity`)
+  if (!res.ok) throw new Error('Storage integrity check failed')
+  return res.json()
+}
+
 export async function fetchSettings(): Promise<AppSettings> {
   const res = await fetchWithRetry(`${BASE_URL}/settings`)
   if (!res.ok) throw new Error('Settings query failed')
@@ -617,6 +651,9 @@ export async function downloadBackup(params: BackupParams = {}): Promise<Blob> {
   if (params.include_frames !== undefined) {
     searchParams.set('include_frames', String(params.include_frames))
   }
+  if (params.redaction_profile !== undefined) {
+    searchParams.set('redaction_profile', params.redaction_profile)
+  }
 
   const res = await fetchWithRetry(`${BASE_URL}/backup?${searchParams}`)
   if (!res.ok) {
@@ -639,6 +676,15 @@ export async function restoreBackup(archive: BackupArchive): Promise<RestoreResu
   return res.json()
 }
 
+export async function createDatabaseBackup(): Promise<DatabaseBackupResult> {
+  const res = await fetchWithRetry(`${BASE_URL}/backup/database`, { method: 'POST' })
+  if (!res.ok) {
+    const err = await res.json().catch(() => ({ error: 'Database backup failed' }))
+    throw new Error(err.error || 'Database backup failed')
+  }
+  return res.json()
+}
+
 export async function fetchTimeline(params: TimelineParams = {}): Promise<TimelineResponse> {
   const searchParams = new URLSearchParams()
   if (params.from) searchParams.set('from', params.from)
@@ -784,6 +830,26 @@ export async function runPreset(id: string): Promise<PresetRunResult> {
   return res.json()
 }
 
+export async function fetchWorkflowHistory(from?: string, to?: string): Promise<WorkflowExecutionRecord[]> {
+  const params = new URLSearchParams()
+  if (from) params.set('from', from)
+  if (to) params.set('to', to)
+  const res = await fetchWithRetry(`${BASE_URL}/automation/history?${params}`)
+  if (!res.ok) throw new Error('Workflow history query failed')
+  return res.json()
+}
+
+export async function replayWorkflowExecution(executionId: string): Promise<WorkflowExecutionRecord> {
+  const res = await fetchWithRetry(`${BASE_URL}/automation/history/${executionId}/replay`, {
+    method: 'POST',
+  })
+  if (!res.ok) {
+    const err = await res.json().catch(() => ({ error: 'Workflow replay failed' }))
+    throw new Error(err.error || 'Workflow replay failed')
+  }
+  return res.json()
+}
+
 export async function executeIntentHint(payload: ExecuteIntentHintRequest): Promise<ExecuteIntentHintResponse> {
   const res = await fetchWithRetry(`${BASE_URL}/automation/execute-hint`, {
     method: 'POST',
@@ -897,6 +963,15 @@ export async function fetchDailyDigest(date: string): Promise<DailyDigestRespons
   return res.json()
 }
 
+export async function fetchSchedulerLoops(): Promise<SchedulerLoopsResponse> {
+  const res = await fetchWithRetry(`${BASE_URL}/dashboard/loops`)
+  if (!res.ok) {
+    const err = await res.json().catch(() => ({ error: 'Scheduler loop health query failed' }))
+    throw new Error(err.error || 'Scheduler loop health query failed')
+  }
+  return res.json()
+}
+
 // ── Recalibration API ────────────────────────────────────────
 
 export async function createOverride(req: CreateOverrideRequest): Promise<{ ok: boolean; override_id: string }> {
@@ -32,6 +32,7 @@ export interface SystemMetrics {
 
 export interface HourlyMetrics {
   hour: string
+  granularity: 'hour' | 'day'
   cpu_avg: number
   cpu_max: number
   memory_avg: number
@@ -71,6 +72,20 @@ export interface IdlePeriod {
   duration_secs: number | null
 }
 
+export interface Bookmark {
+  bookmark_id: string
+  timestamp: string
+  label: string | null
+  frame_id: number | null
+  app_name: string | null
+  window_title: string | null
+}
+
+export interface CreateBookmarkRequest {
+  label?: string
+  frame_id?: number
+}
+
 export interface Session {
   session_id: string
   started_at: string
@@ -92,6 +107,39 @@ export interface StorageStats {
   newest_data_date: string | null
 }
 
+export interface TableRowCount {
+  table: string
+  rows: number
+}
+
+export interface CategoryStorageUsage {
+  category: string
+  frame_count: number
+  bytes: number
+}
+
+/** Storage usage breakdown from `GET /api/storage/report`. */
+export interface StorageReport {
+  db_size_bytes: number
+  frames_dir_bytes: number
+  total_size_bytes: number
+  tables: TableRowCount[]
+  app_categories: CategoryStorageUsage[]
+}
+
+export interface ForeignKeyViolation {
+  table: string
+  rowid?: number
+  parent: string
+}
+
+export interface StorageIntegrityReport {
+  healthy: boolean
+  integrity_errors: string[]
+  foreign_key_violations: ForeignKeyViolation[]
+  recovery_steps: string[]
+}
+
 export interface NotificationSettings {
   enabled: boolean
   idle_notification: boolean
@@ -756,6 +804,15 @@ export interface Tag {
   name: string
   color: string
   created_at: string
+  /** Applied to the frame by an auto-tag rule (frame tag listings only). */
+  auto_applied?: boolean
+}
+
+/** Tags frames whose app name contains `app_pattern` (case-insensitive). */
+export interface TagRule {
+  id: number
+  app_pattern: string
+  tag_id: number
 }
 
 export interface CreateTagRequest {
@@ -824,6 +881,23 @@ export interface ReportParams {
   to?: string
 }
 
+export interface CreateShareLinkRequest {
+  from: string
+  to?: string
+  ttl_hours?: number
+}
+
+export interface ShareLinkResponse {
+  id: string
+  token: string
+  path: string
+  from: string
+  to: string
+  expires_at: string
+}
+
+export type RedactionProfile = 'full' | 'strip_ocr' | 'external'
+
 export interface BackupMetadata {
   version: string
   created_at: string
@@ -834,6 +908,7 @@ export interface BackupMetadata {
     events: boolean
     frames: boolean
   }
+  redaction_profile?: RedactionProfile
 }
 
 export interface SettingsBackup {
@@ -895,6 +970,7 @@ export interface BackupParams {
   include_tags?: boolean
   include_events?: boolean
   include_frames?: boolean
+  redaction_profile?: RedactionProfile
 }
 
 export interface RestoreResult {
@@ -909,6 +985,13 @@ export interface RestoreResult {
   errors: string[]
 }
 
+export interface DatabaseBackupResult {
+  path: string
+  size_bytes: number
+  created_at: string
+  rotated_out: number
+}
+
 export interface TimelineSessionInfo {
   start: string
   end: string
@@ -1230,6 +1313,7 @@ export interface ExecutionPolicyConfig {
   allow_network?: boolean | null
   require_signed_token: boolean
   confirmation: string
+  expires_at?: string | null
 }
 
 export type IntentDefinition = Record<string, unknown>
@@ -1261,6 +1345,34 @@ export interface PresetRunResult {
   total_elapsed_ms?: number
 }
 
+export interface WorkflowStepResult {
+  step_name: string
+  step_index: number
+  success: boolean
+  elapsed_ms: number
+  error: string | null
+  before_frame_id?: number | null
+  after_frame_id?: number | null
+}
+
+export interface WorkflowResult {
+  preset_id: string
+  success: boolean
+  steps_executed: number
+  total_steps: number
+  total_elapsed_ms: number
+  step_results: WorkflowStepResult[]
+  message: string
+}
+
+export interface WorkflowExecutionRecord {
+  execution_id: string
+  started_at: string
+  preset: WorkflowPreset
+  result: WorkflowResult
+  replay_of?: string | null
+}
+
 export interface ExecuteIntentHintRequest {
   command_id?: string
   session_id: string
@@ -1475,6 +1587,22 @@ export interface DailyDigestResponse {
   statistics: DailyDigestStatistics
 }
 
+export interface SchedulerLoopHealth {
+  name: string
+  status: 'healthy' | 'stalled'
+  interval_secs: number
+  last_tick_at: string
+  seconds_since_last_tick: number
+  tick_count: number
+  restart_count: number
+}
+
+export interface SchedulerLoopsResponse {
+  available: boolean
+  stall_multiplier: number
+  loops: SchedulerLoopHealth[]
+}
+
 // ── GUI V2 Session types ─────────────────────────────────────────
 
 export interface GuiCreateSessionRequest {
@@ -1659,6 +1787,15 @@ export interface DiagnosticsHealth {
   update_control_configured: boolean
 }
 
+export interface StageLatencySummary {
+  stage: 'capture' | 'encode' | 'ocr'
+  samples: number
+  total_samples: number
+  p50_ms: number
+  p95_ms: number
+  max_ms: number
+}
+
 export interface DiagnosticsBundleResponse {
   schema_version: string
   generated_at: string
@@ -1667,6 +1804,7 @@ export interface DiagnosticsBundleResponse {
   storage_stats: StorageStats | null
   recent_audit_entries: AuditEntry[]
   recent_policy_events: AuditEntry[]
+  capture_latency?: StageLatencySummary[]
 }
 
 // ── Coaching Stats types ────────────────────────────────────────
@@ -6,6 +6,7 @@ import type {
   AutomationStats,
   AutomationStatus,
   BackupArchive,
+  Bookmark,
   DailySummary,
   DeleteResult,
   ExecuteSceneActionRequest,
@@ -476,6 +477,7 @@ function makeDefaultAutomationScene(appName?: string, screenId?: string, frameId
 type StandaloneState = {
   settings: AppSettings
   updateStatus: UpdateStatus
+  bookmarks: Bookmark[]
   tags: Tag[]
   frameTags: Map<number, Set<number>>
   suggestions: LocalSuggestion[]
@@ -488,6 +490,7 @@ type StandaloneState = {
 const state: StandaloneState = {
   settings: makeDefaultSettings(),
   updateStatus: makeDefaultUpdateStatus(),
+  bookmarks: [],
   tags: [],
   frameTags: new Map(),
   suggestions: [],
@@ -651,6 +654,22 @@ export async function handleStandaloneRequest(
   if (path === '/api/idle' && method === 'GET') {
     return jsonResponse([])
   }
+  if (path === '/api/bookmarks' && method === 'GET') {
+    return jsonResponse(state.bookmarks)
+  }
+  if (path === '/api/bookmarks' && method === 'POST') {
+    const payload = body as { label?: string; frame_id?: number } | null
+    const bookmark: Bookmark = {
+      bookmark_id: `bookmark-${Date.now()}-${state.bookmarks.length}`,
+      timestamp: new Date().toISOString(),
+      label: payload?.label?.trim() || null,
+      frame_id: payload?.frame_id ?? null,
+      app_name: null,
+      window_title: null,
+    }
+    state.bookmarks = [...state.bookmarks, bookmark]
+    return jsonResponse(bookmark)
+  }
   if (path === '/api/sessions' && method === 'GET') {
     return jsonResponse([])
   }
@@ -660,6 +679,9 @@ export async function handleStandaloneRequest(
   if (path === '/api/storage/stats' && method === 'GET') {
     return jsonResponse(makeDefaultStorageStats())
   }
+  if (path === '/api/storage/integrity' && method === 'GET') {
+    return jsonResponse({ healthy: true, integrity_errors: [], foreign_key_violations: [], recovery_steps: [] })
+  }
   if (path === '/api/settings' && method === 'GET') {
     return jsonResponse(state.settings)
   }
@@ -1038,6 +1060,15 @@ export async function handleStandaloneRequest(
     })
   }
 
+  if (path === '/api/automation/history' && method === 'GET') {
+    return jsonResponse([])
+  }
+
+  const historyReplayMatch = path.match(/^\/api\/automation\/history\/([^/]+)\/replay$/)
+  if (historyReplayMatch && method === 'POST') {
+    return jsonResponse({ error: 'Workflow execution not found' }, 404)
+  }
+
   if (path === '/api/automation/execute-hint' && method === 'POST') {
     const payload = body as { command_id?: string; session_id?: string; intent_hint?: string } | null
     const now = Date.now()
@@ -1128,6 +1159,11 @@ export async function handleStandaloneRequest(
     return jsonResponse(null)
   }
 
+  // ── Scheduler loop health ───────────────────────────────────
+  if (path === '/api/dashboard/loops' && method === 'GET') {
+    return jsonResponse({ available: false, stall_multiplier: 0, loops: [] })
+  }
+
   // ── Dashboard Day ───────────────────────────────────────────
   if (path === '/api/dashboard/day' && method === 'GET') {
     return jsonResponse({
@@ -1197,6 +1233,7 @@ export async function handleStandaloneRequest(
       storage_stats: makeDefaultStorageStats(),
       recent_audit_entries: [],
       recent_policy_events: [],
+      capture_latency: [],
     })
   }
 
@@ -19,6 +19,7 @@ function generateMockData(hours: number): HourlyMetrics[] {
 
     return {
       hour: date.toISOString(),
+      granularity: 'hour' as const,
       cpu_avg: Math.round(baseCpu * 10) / 10,
       cpu_max: Math.round((baseCpu + 15 + Math.random() * 10) * 10) / 10,
       memory_avg: baseMem * 1024 * 1024 * 1024,
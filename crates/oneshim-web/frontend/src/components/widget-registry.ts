@@ -6,6 +6,7 @@ import {
   Focus,
   Grid3x3,
   LineChart,
+  HeartPulse,
   List,
   type LucideIcon,
   RefreshCw,
@@ -28,6 +29,7 @@ export const WIDGET_REGISTRY: WidgetDef[] = [
   { id: 'monitoring.metrics-chart', section: 'monitoring', labelKey: 'widgets.metricsChart', icon: LineChart },
   { id: 'monitoring.app-usage', section: 'monitoring', labelKey: 'widgets.appUsage', icon: AppWindow },
   { id: 'monitoring.process-list', section: 'monitoring', labelKey: 'widgets.processList', icon: List },
+  { id: 'monitoring.loop-health', section: 'monitoring', labelKey: 'widgets.loopHealth', icon: HeartPulse },
   { id: 'insights.focus-widget', section: 'insights', labelKey: 'widgets.focusWidget', icon: Focus },
   { id: 'insights.update-panel', section: 'insights', labelKey: 'widgets.updatePanel', icon: RefreshCw },
   { id: 'insights.heatmap', section: 'insights', labelKey: 'widgets.heatmap', icon: Grid3x3 },
@@ -17,6 +17,15 @@ export interface FrameUpdate {
   window_title: string
   importance: number
   trigger_type: string
+  /** Downscaled, blurred preview; present only in privacy-preview mode. */
+  preview?: FramePreview
+}
+
+export interface FramePreview {
+  data: string
+  format: string
+  width: number
+  height: number
 }
 
 export interface IdleUpdate {
@@ -24,10 +33,17 @@ export interface IdleUpdate {
   idle_secs: number
 }
 
+export interface ConnectivityUpdate {
+  timestamp: string
+  from: 'online' | 'offline' | 'captive'
+  to: 'online' | 'offline' | 'captive'
+}
+
 export type RealtimeEvent =
   | { type: 'metrics'; data: MetricsUpdate }
   | { type: 'frame'; data: FrameUpdate }
   | { type: 'idle'; data: IdleUpdate }
+  | { type: 'connectivity'; data: ConnectivityUpdate }
   | { type: 'ping' }
 
 export type ConnectionStatus = 'connecting' | 'connected' | 'disconnected' | 'error'
@@ -1,15 +1,16 @@
 /**
- * Monitoring section — CPU/Memory chart, process list, and app usage chart.
- * Owns its own queries for hourly metrics and processes.
+ * Monitoring section — CPU/Memory chart, process list, app usage chart, and
+ * scheduler loop health. Owns its own queries for hourly metrics, processes,
+ * and loop heartbeats.
  */
 
 import { useQuery } from '@tanstack/react-query'
 import { useTranslation } from 'react-i18next'
-import { fetchHourlyMetrics, fetchProcesses } from '../../api/client'
+import { fetchHourlyMetrics, fetchProcesses, fetchSchedulerLoops } from '../../api/client'
 import AppUsageChart from '../../components/AppUsageChart'
 import MetricsChart from '../../components/MetricsChart'
 import ProcessList from '../../components/ProcessList'
-import { Card, CardTitle } from '../../components/ui'
+import { Badge, Card, CardTitle } from '../../components/ui'
 import { useTypedOutletContext } from '../../routes'
 import { colors } from '../../styles/tokens'
 import { cn } from '../../utils/cn'
@@ -31,6 +32,13 @@ export default function MonitoringSection() {
     refetchInterval: 30_000, // process list — refresh every 30s
   })
 
+  const { data: schedulerLoops } = useQuery({
+    queryKey: ['schedulerLoops'],
+    queryFn: fetchSchedulerLoops,
+    refetchInterval: 30_000, // matches the default watchdog check interval
+    enabled: isWidgetVisible('monitoring.loop-health'),
+  })
+
   return (
     <>
       {isWidgetVisible('monitoring.metrics-chart') && (
@@ -61,6 +69,30 @@ export default function MonitoringSection() {
           )}
         </div>
       )}
+
+      {isWidgetVisible('monitoring.loop-health') && schedulerLoops?.available && (
+        <Card id="section-loop-health" variant="default" padding="lg">
+          <CardTitle className="mb-4">{t('dashboard.loopHealth')}</CardTitle>
+          {schedulerLoops.loops.length > 0 ? (
+            <ul className="space-y-2">
+              {schedulerLoops.loops.map((loop) => (
+                <li key={loop.name} className="flex items-center justify-between gap-4 text-sm">
+                  <span className="font-mono">{loop.name}</span>
+                  <span className={cn(colors.text.secondary, 'ml-auto')}>
+                    {t('dashboard.loopLastTick', { seconds: loop.seconds_since_last_tick })}
+                    {loop.restart_count > 0 && ` · ${t('dashboard.loopRestarts', { count: loop.restart_count })}`}
+                  </span>
+                  <Badge color={loop.status === 'healthy' ? 'success' : 'error'} size="sm">
+                    {loop.status === 'healthy' ? t('dashboard.loopHealthy') : t('dashboard.loopStalled')}
+                  </Badge>
+                </li>
+              ))}
+            </ul>
+          ) : (
+            <div className={cn(colors.text.secondary, 'py-8 text-center')}>{t('common.noData')}</div>
+          )}
+        </Card>
+      )}
     </>
   )
 }
@@ -2,9 +2,11 @@
  * Privacy data section — storage stats cards + delete by date range.
  */
 
+import { useMutation } from '@tanstack/react-query'
 import { BarChart3, Calendar, Camera, FileText, HardDrive } from 'lucide-react'
 import { useTranslation } from 'react-i18next'
-import { Button, Card, CardTitle, Input } from '../../components/ui'
+import { checkStorageIntegrity } from '../../api/client'
+import { Alert, Button, Card, CardTitle, Input } from '../../components/ui'
 import { useTypedOutletContext } from '../../routes'
 import { iconSize, typography } from '../../styles/tokens'
 import { formatBytes, formatNumber } from '../../utils/formatters'
@@ -28,6 +30,8 @@ export default function DataSection() {
     DATA_TYPE_LABELS,
     getDateRangeText,
   } = useTypedOutletContext<PrivacyContext>('Privacy')
+  const integrityMutation = useMutation({ mutationFn: checkStorageIntegrity })
+  const integrityReport = integrityMutation.data
 
   return (
     <>
@@ -72,6 +76,49 @@ export default function DataSection() {
         )}
       </Card>
 
+      {/* Integrity check */}
+      <Card variant="default" padding="lg">
+        <CardTitle className="mb-4">{t('privacy.integrityTitle')}</CardTitle>
+        <p className="mb-4 text-content-secondary text-sm">{t('privacy.integrityDesc')}</p>
+        {integrityReport && (
+          <Alert
+            variant={integrityReport.healthy ? 'success' : 'warning'}
+            title={integrityReport.healthy ? t('privacy.integrityHealthy') : t('privacy.integrityProblems')}
+            className="mb-4"
+          >
+            {!integrityReport.healthy && (
+              <div className="mt-2 space-y-1 text-sm">
+                {integrityReport.integrity_errors.map((message) => (
+                  <div key={message}>{message}</div>
+                ))}
+                {integrityReport.foreign_key_violations.map((violation) => (
+                  <div key={`${violation.table}-${violation.rowid ?? ''}-${violation.parent}`}>
+                    {violation.table} → {violation.parent}
+                    {violation.rowid !== undefined && ` (rowid ${violation.rowid})`}
+                  </div>
+                ))}
+                <div className={`mt-2 ${typography.weight.medium}`}>{t('privacy.integrityRecovery')}</div>
+                <ol className="list-inside list-decimal">
+                  {integrityReport.recovery_steps.map((step) => (
+                    <li key={step}>{step}</li>
+                  ))}
+                </ol>
+              </div>
+            )}
+          </Alert>
+        )}
+        {integrityMutation.isError && (
+          <Alert variant="error" title={(integrityMutation.error as Error).message} className="mb-4" />
+        )}
+        <Button
+          variant="secondary"
+          onClick={() => integrityMutation.mutate()}
+          isLoading={integrityMutation.isPending}
+        >
+          {integrityMutation.isPending ? t('privacy.integrityChecking') : t('privacy.integrityCheckButton')}
+        </Button>
+      </Card>
+
       {/* Delete by range */}
       <Card variant="default" padding="lg">
         <CardTitle className="mb-4">{t('privacy.deleteByRangeTitle')}</CardTitle>
@@ -88,6 +88,7 @@ export function createMockHourlyMetrics(count = 24): HourlyMetrics[] {
     const memBase = 55 + Math.random() * 20
     return {
       hour: `${hour}:00`,
+      granularity: 'hour' as const,
       cpu_avg: Math.round(cpuBase * 10) / 10,
       cpu_max: Math.round((cpuBase + 15 + Math.random() * 20) * 10) / 10,
       memory_avg: Math.round(memBase * 10) / 10,
@@ -12,6 +12,8 @@ interface ConnectionStatus {
   server: boolean
   llm: boolean
   cli: boolean
+  /** Seconds until the next server attempt while the circuit breaker is open. */
+  server_retry_in_secs?: number | null
 }
 
 interface SceneAnalysisResult {
@@ -343,7 +345,14 @@ export function App() {
             )}
             <div data-tauri-drag-region className="flex items-center justify-between text-[10px] text-white/60">
               <div className="flex items-center gap-3">
-                <StatusDot connected={conn.server} label={t('trackingPanel.server')} />
+                <StatusDot
+                  connected={conn.server}
+                  label={
+                    conn.server_retry_in_secs
+                      ? t('trackingPanel.serverRetrying', { secs: conn.server_retry_in_secs })
+                      : t('trackingPanel.server')
+                  }
+                />
                 <StatusDot connected={conn.llm} label="LLM" />
                 <StatusDot connected={conn.cli} label="CLI" />
               </div>
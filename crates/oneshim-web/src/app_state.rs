@@ -10,6 +10,7 @@ use oneshim_api_contracts::bug_report::BugReportBundleDto;
 use oneshim_api_contracts::integration::IntegrationOutboundRuntimeStatus;
 use oneshim_core::config::CredentialBackendKind;
 use oneshim_core::config_manager::ConfigManager;
+use oneshim_core::models::capture_latency::CaptureLatencyRecorder;
 use oneshim_core::ports::adaptive_search::AdaptiveSearchPort;
 use oneshim_core::ports::audit_log::AuditLogPort;
 use oneshim_core::ports::automation::AutomationPort;
@@ -21,6 +22,8 @@ use oneshim_core::ports::integration::{
     IntegrationAuditPort, IntegrationAuthPort, IntegrationInboxPort, IntegrationInboxStorePort,
     IntegrationOutboxPort, IntegrationRuntimeTelemetryPort, IntegrationSessionPort,
 };
+use oneshim_core::ports::loop_health::LoopHealthProvider;
+use oneshim_core::ports::network_metrics::NetworkMetricsProvider;
 use oneshim_core::ports::override_store::OverrideStore;
 use oneshim_core::ports::pii_sanitizer::PiiSanitizer;
 use oneshim_core::ports::runtime_log_provider::RuntimeLogProvider;
@@ -30,6 +33,7 @@ use oneshim_core::ports::text_search::TextSearchProvider;
 use oneshim_core::ports::vector_store::VectorStore;
 use tokio::sync::broadcast;
 
+use crate::frame_image_cache::FrameImageCache;
 use crate::update_control::UpdateControl;
 use crate::{AiRuntimeStatus, RealtimeEvent, WebStorage};
 
@@ -47,6 +51,7 @@ pub struct CoreState {
     pub frame_storage: Option<Arc<dyn FrameStoragePort>>,
     pub config_manager: Option<ConfigManager>,
     pub update_control: Option<UpdateControl>,
+    pub frame_image_cache: Arc<FrameImageCache>,
 }
 
 /// Secret management — credential backends and stores.
@@ -123,6 +128,9 @@ pub struct DiagnosticsState {
     pub latest_bug_report: Arc<parking_lot::RwLock<Option<BugReportBundleDto>>>,
     pub runtime_log_provider: Option<Arc<dyn RuntimeLogProvider>>,
     pub system_info_provider: Option<Arc<dyn SystemInfoProvider>>,
+    pub loop_health: Option<Arc<dyn LoopHealthProvider>>,
+    pub capture_latency: Option<Arc<CaptureLatencyRecorder>>,
+    pub network_metrics: Option<Arc<dyn NetworkMetricsProvider>>,
 
     // Task 7.1 — live-config REST endpoint (GET /api/external-grpc/live-config).
     // Populated from build_external_spawn_config return value when external gRPC is enabled.
@@ -139,6 +147,9 @@ impl Default for DiagnosticsState {
             latest_bug_report: Arc::new(parking_lot::RwLock::new(None)),
             runtime_log_provider: None,
             system_info_provider: None,
+            loop_health: None,
+            capture_latency: None,
+            network_metrics: None,
             #[cfg(feature = "grpc-dashboard-external")]
             external_grpc_live: None,
             #[cfg(feature = "grpc-dashboard-external")]
@@ -177,6 +188,7 @@ impl AppState {
                 frame_storage: None,
                 config_manager: None,
                 update_control: None,
+                frame_image_cache: Arc::new(FrameImageCache::default()),
             },
             secrets: Default::default(),
             automation: Default::default(),
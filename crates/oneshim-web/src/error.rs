@@ -197,4 +197,28 @@ mod tests {
             "TimeWindow::ParseFailed must map to 400 BadRequest, got: {api:?}"
         );
     }
+
+    /// Regression guard: a storage lookup that finds nothing must surface as
+    /// HTTP 404, while lock/database failures stay 500.
+    #[test]
+    fn storage_not_found_and_lock_failures_map_separately() {
+        let core: oneshim_core::error::CoreError = oneshim_storage::error::StorageError::NotFound {
+            resource_type: "frame".to_string(),
+            id: "42".to_string(),
+        }
+        .into();
+        let api: ApiError = core.into();
+        assert!(
+            matches!(api, ApiError::NotFound(_)),
+            "storage NotFound must map to 404 NotFound, got: {api:?}"
+        );
+
+        let core: oneshim_core::error::CoreError =
+            oneshim_storage::error::StorageError::Lock("poisoned".to_string()).into();
+        let api: ApiError = core.into();
+        assert!(
+            matches!(api, ApiError::Internal(_)),
+            "lock failure must map to 500 Internal, got: {api:?}"
+        );
+    }
 }
@@ -0,0 +1,192 @@
+//! Visual "what changed" diff between two stored frames.
+//!
+//! Uses the capture delta pipeline's tile comparison
+//! ([`oneshim_core::frame_delta`]) so the highlighted regions match what the
+//! pipeline itself would treat as changed.
+
+use std::io::Cursor;
+
+use image::{ImageFormat, Rgba, RgbaImage};
+use oneshim_core::frame_delta::{pixel_diff, tile_changed, tiles, CHANGE_THRESHOLD};
+use oneshim_core::models::frame::Rect;
+
+/// Highlight colour blended over changed tiles.
+const HIGHLIGHT: [u8; 3] = [255, 32, 32];
+
+#[derive(Debug, Clone)]
+pub struct FrameDiff {
+    /// Share of pixels whose RGB difference exceeds the change threshold.
+    pub changed_pixel_ratio: f32,
+    pub changed_tiles: u32,
+    pub total_tiles: u32,
+    /// Bounding box of the changed tiles; `None` when nothing changed.
+    pub changed_region: Option<Rect>,
+    /// The later frame as PNG — changed tiles tinted, the rest dimmed.
+    pub image_png: Vec<u8>,
+}
+
+/// Decode both encoded frames and diff `after` against `before`. Frames of
+/// different sizes count as fully changed, as in the delta pipeline.
+pub fn render_frame_diff(before: &[u8], after: &[u8]) -> Result<FrameDiff, String> {
+    let before = image::load_from_memory(before)
+        .map_err(|e| format!("decode failed: {e}"))?
+        .to_rgba8();
+    let after = image::load_from_memory(after)
+        .map_err(|e| format!("decode failed: {e}"))?
+        .to_rgba8();
+    let (width, height) = after.dimensions();
+    let same_size = before.dimensions() == after.dimensions();
+
+    let stride = width as usize * 4;
+    let (prev, curr) = (before.as_raw(), after.as_raw());
+    let changed: Vec<Rect> = tiles(width, height)
+        .filter(|tile| {
+            !same_size || tile_changed(prev, curr, stride, *tile, u64::from(CHANGE_THRESHOLD))
+        })
+        .collect();
+    let total_tiles = tiles(width, height).count() as u32;
+
+    let total_pixels = (width as usize * height as usize).max(1);
+    let changed_pixels = if same_size {
+        (0..total_pixels)
+            .filter(|pixel| pixel_diff(prev, curr, pixel * 4) > CHANGE_THRESHOLD)
+            .count()
+    } else {
+        total_pixels
+    };
+
+    Ok(FrameDiff {
+        changed_pixel_ratio: changed_pixels as f32 / total_pixels as f32,
+        changed_tiles: changed.len() as u32,
+        total_tiles,
+        changed_region: bounding_box(&changed),
+        image_png: encode_png(&highlight(after, &changed))?,
+    })
+}
+
+fn bounding_box(tiles: &[Rect]) -> Option<Rect> {
+    let first = tiles.first()?;
+    let (mut min_x, mut min_y) = (first.x, first.y);
+    let (mut max_x, mut max_y) = (first.x + first.w, first.y + first.h);
+    for tile in &tiles[1..] {
+        min_x = min_x.min(tile.x);
+        min_y = min_y.min(tile.y);
+        max_x = max_x.max(tile.x + tile.w);
+        max_y = max_y.max(tile.y + tile.h);
+    }
+    Some(Rect {
+        x: min_x,
+        y: min_y,
+        w: max_x - min_x,
+        h: max_y - min_y,
+    })
+}
+
+/// Dim the whole frame, then tint the changed tiles.
+fn highlight(mut image: RgbaImage, changed: &[Rect]) -> RgbaImage {
+    for pixel in image.pixels_mut() {
+        let Rgba([r, g, b, a]) = *pixel;
+        *pixel = Rgba([r / 2, g / 2, b / 2, a]);
+    }
+    for tile in changed {
+        for y in tile.y..tile.y + tile.h {
+            for x in tile.x..tile.x + tile.w {
+                let Rgba([r, g, b, a]) = *image.get_pixel(x, y);
+                let blend =
+                    |dimmed: u8, tint: u8| ((u16::from(dimmed) * 2 + u16::from(tint)) / 3) as u8;
+                image.put_pixel(
+                    x,
+                    y,
+                    Rgba([
+                        blend(r, HIGHLIGHT[0]),
+                        blend(g, HIGHLIGHT[1]),
+                        blend(b, HIGHLIGHT[2]),
+                        a,
+                    ]),
+                );
+            }
+        }
+    }
+    image
+}
+
+fn encode_png(image: &RgbaImage) -> Result<Vec<u8>, String> {
+    let mut out = Cursor::new(Vec::new());
+    image
+        .write_to(&mut out, ImageFormat::Png)
+        .map_err(|e| format!("encode failed: {e}"))?;
+    Ok(out.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(image: &RgbaImage) -> Vec<u8> {
+        encode_png(image).expect("encode")
+    }
+
+    fn base() -> RgbaImage {
+        RgbaImage::from_pixel(64, 64, Rgba([40, 80, 120, 255]))
+    }
+
+    #[test]
+    fn changed_region_is_highlighted_with_nonzero_ratio() {
+        let before = base();
+        let mut after = base();
+        // Known change: a 16x16 block aligned to the tile at (32, 16).
+        for y in 16..32 {
+            for x in 32..48 {
+                after.put_pixel(x, y, Rgba([240, 240, 240, 255]));
+            }
+        }
+
+        let diff = render_frame_diff(&png(&before), &png(&after)).expect("diff");
+
+        assert_eq!(diff.changed_tiles, 1);
+        assert_eq!(diff.total_tiles, 16);
+        assert_eq!(
+            diff.changed_region,
+            Some(Rect {
+                x: 32,
+                y: 16,
+                w: 16,
+                h: 16
+            })
+        );
+        assert!((diff.changed_pixel_ratio - 256.0 / 4096.0).abs() < f32::EPSILON);
+
+        let rendered = image::load_from_memory(&diff.image_png)
+            .expect("decode diff")
+            .to_rgba8();
+        let inside = rendered.get_pixel(40, 24);
+        let outside = rendered.get_pixel(8, 8);
+        assert!(inside[0] > outside[0], "changed tile should be tinted red");
+        assert_eq!(
+            *outside,
+            Rgba([20, 40, 60, 255]),
+            "unchanged area is dimmed"
+        );
+    }
+
+    #[test]
+    fn identical_frames_have_no_change() {
+        let diff = render_frame_diff(&png(&base()), &png(&base())).expect("diff");
+        assert_eq!(diff.changed_tiles, 0);
+        assert_eq!(diff.changed_region, None);
+        assert_eq!(diff.changed_pixel_ratio, 0.0);
+    }
+
+    #[test]
+    fn size_mismatch_counts_as_full_change() {
+        let small = RgbaImage::from_pixel(32, 32, Rgba([40, 80, 120, 255]));
+        let diff = render_frame_diff(&png(&small), &png(&base())).expect("diff");
+        assert_eq!(diff.changed_tiles, diff.total_tiles);
+        assert_eq!(diff.changed_pixel_ratio, 1.0);
+    }
+
+    #[test]
+    fn undecodable_input_is_an_error() {
+        assert!(render_frame_diff(b"not an image", &png(&base())).is_err());
+    }
+}
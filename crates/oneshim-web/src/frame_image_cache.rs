@@ -0,0 +1,233 @@
+//! In-memory LRU cache for resized/re-encoded frame images.
+//!
+//! Every dashboard tab requesting the same thumbnail would otherwise decode,
+//! resize and re-encode the stored frame again. Entries are keyed by
+//! (frame id, requested dimensions, format) and dropped when frames are
+//! deleted through the dashboard.
+
+use std::io::Cursor;
+use std::num::NonZeroUsize;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use axum::body::Bytes;
+use image::imageops::FilterType;
+use image::ImageFormat;
+use lru::LruCache;
+use parking_lot::Mutex;
+
+/// Output encoding for a rendered frame image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FrameImageFormat {
+    Webp,
+    Png,
+    Jpeg,
+}
+
+impl FrameImageFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "webp" => Some(Self::Webp),
+            "png" => Some(Self::Png),
+            "jpeg" | "jpg" => Some(Self::Jpeg),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(self) -> &'static str {
+        match self {
+            Self::Webp => "image/webp",
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+        }
+    }
+
+    fn image_format(self) -> ImageFormat {
+        match self {
+            Self::Webp => ImageFormat::WebP,
+            Self::Png => ImageFormat::Png,
+            Self::Jpeg => ImageFormat::Jpeg,
+        }
+    }
+}
+
+/// Cache key. A missing dimension is stored as `0` (unconstrained).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FrameImageKey {
+    pub frame_id: i64,
+    pub width: u32,
+    pub height: u32,
+    pub format: FrameImageFormat,
+}
+
+/// Hit/miss counters, mostly for tests and diagnostics.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FrameImageCacheStats {
+    pub hits: u64,
+    pub misses: u64,
+    pub entries: usize,
+}
+
+pub struct FrameImageCache {
+    /// `None` when the cache is disabled (capacity 0).
+    entries: Option<Mutex<LruCache<FrameImageKey, Bytes>>>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl FrameImageCache {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: NonZeroUsize::new(capacity).map(|cap| Mutex::new(LruCache::new(cap))),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+        }
+    }
+
+    pub fn get(&self, key: &FrameImageKey) -> Option<Bytes> {
+        let cached = self
+            .entries
+            .as_ref()
+            .and_then(|entries| entries.lock().get(key).cloned());
+        let counter = if cached.is_some() {
+            &self.hits
+        } else {
+            &self.misses
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+        cached
+    }
+
+    pub fn insert(&self, key: FrameImageKey, bytes: Vec<u8>) -> Bytes {
+        let bytes = Bytes::from(bytes);
+        if let Some(entries) = &self.entries {
+            entries.lock().put(key, bytes.clone());
+        }
+        bytes
+    }
+
+    /// Drop every entry. Called whenever frames are deleted.
+    pub fn clear(&self) {
+        if let Some(entries) = &self.entries {
+            entries.lock().clear();
+        }
+    }
+
+    pub fn stats(&self) -> FrameImageCacheStats {
+        FrameImageCacheStats {
+            hits: self.hits.load(Ordering::Relaxed),
+            misses: self.misses.load(Ordering::Relaxed),
+            entries: self
+                .entries
+                .as_ref()
+                .map(|entries| entries.lock().len())
+                .unwrap_or(0),
+        }
+    }
+}
+
+impl Default for FrameImageCache {
+    fn default() -> Self {
+        Self::new(oneshim_core::config::WebConfig::default().frame_image_cache_entries)
+    }
+}
+
+/// Decode `source`, downscale it to fit `key.width` x `key.height` (never
+/// upscaling) and encode it as `key.format`.
+pub fn render_frame_image(source: &[u8], key: &FrameImageKey) -> Result<Vec<u8>, String> {
+    let decoded = image::load_from_memory(source).map_err(|e| format!("decode failed: {e}"))?;
+    let max_w = if key.width == 0 { u32::MAX } else { key.width };
+    let max_h = if key.height == 0 {
+        u32::MAX
+    } else {
+        key.height
+    };
+    let resized = if decoded.width() > max_w || decoded.height() > max_h {
+        decoded.resize(
+            max_w.min(decoded.width()),
+            max_h.min(decoded.height()),
+            FilterType::Triangle,
+        )
+    } else {
+        decoded
+    };
+    // JPEG has no alpha channel.
+    let resized = match key.format {
+        FrameImageFormat::Jpeg => image::DynamicImage::ImageRgb8(resized.to_rgb8()),
+        _ => resized,
+    };
+
+    let mut out = Cursor::new(Vec::new());
+    resized
+        .write_to(&mut out, key.format.image_format())
+        .map_err(|e| format!("encode failed: {e}"))?;
+    Ok(out.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(frame_id: i64, width: u32) -> FrameImageKey {
+        FrameImageKey {
+            frame_id,
+            width,
+            height: 0,
+            format: FrameImageFormat::Png,
+        }
+    }
+
+    fn png(width: u32, height: u32) -> Vec<u8> {
+        let img = image::RgbImage::from_pixel(width, height, image::Rgb([10, 20, 30]));
+        let mut out = Cursor::new(Vec::new());
+        image::DynamicImage::ImageRgb8(img)
+            .write_to(&mut out, ImageFormat::Png)
+            .expect("encode png");
+        out.into_inner()
+    }
+
+    #[test]
+    fn parse_format_is_case_insensitive() {
+        assert_eq!(
+            FrameImageFormat::parse("WebP"),
+            Some(FrameImageFormat::Webp)
+        );
+        assert_eq!(FrameImageFormat::parse("jpg"), Some(FrameImageFormat::Jpeg));
+        assert_eq!(FrameImageFormat::parse("gif"), None);
+    }
+
+    #[test]
+    fn second_lookup_hits_and_clear_evicts() {
+        let cache = FrameImageCache::new(8);
+        assert!(cache.get(&key(1, 100)).is_none());
+        cache.insert(key(1, 100), vec![1]);
+        assert_eq!(cache.get(&key(1, 100)).as_deref(), Some(&[1u8][..]));
+        assert!(cache.get(&key(1, 200)).is_none());
+
+        cache.clear();
+
+        assert!(cache.get(&key(1, 100)).is_none());
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 3, 0));
+    }
+
+    #[test]
+    fn zero_capacity_disables_cache() {
+        let cache = FrameImageCache::new(0);
+        cache.insert(key(1, 100), vec![1]);
+        assert!(cache.get(&key(1, 100)).is_none());
+        assert_eq!(cache.stats().entries, 0);
+    }
+
+    #[test]
+    fn render_downscales_without_upscaling() {
+        let source = png(64, 32);
+
+        let small = render_frame_image(&source, &key(1, 16)).expect("render");
+        let small = image::load_from_memory(&small).expect("decode");
+        assert_eq!((small.width(), small.height()), (16, 8));
+
+        let same = render_frame_image(&source, &key(1, 640)).expect("render");
+        let same = image::load_from_memory(&same).expect("decode");
+        assert_eq!((same.width(), same.height()), (64, 32));
+    }
+}
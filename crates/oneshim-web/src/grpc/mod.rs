@@ -44,6 +44,7 @@ use std::time::{Duration, Instant};
 
 use async_stream::stream;
 use oneshim_api_contracts::stream::{AiRuntimeStatus, RealtimeEvent};
+use oneshim_core::models::activity::SessionStats;
 use oneshim_core::ports::monitor::SystemMonitor;
 use oneshim_core::ports::pii_sanitizer::PiiSanitizer;
 use tokio::net::TcpListener;
@@ -283,39 +284,14 @@ impl DashboardService for DashboardServiceImpl {
             .map_err(|e| Status::internal(format!("spawn_blocking join: {e}")))?
             .map_err(|e| Status::internal(format!("list_session_stats: {e}")))?;
 
-        // Aggregate in-memory. For very large result sets this is O(n) and
-        // cheap — small structs, no string allocation.
-        let total_sessions = rows.len() as u32;
-        let mut ended_sessions: u32 = 0;
-        let mut total_events: u64 = 0;
-        let mut total_frames: u64 = 0;
-        let mut total_idle_secs: u64 = 0;
-        let mut total_duration_secs: i64 = 0;
-        for row in &rows {
-            total_events += row.total_events;
-            total_frames += row.total_frames;
-            total_idle_secs += row.total_idle_secs;
-            if let Some(ended_at) = row.ended_at {
-                ended_sessions += 1;
-                let duration = (ended_at - row.started_at).num_seconds();
-                if duration > 0 {
-                    total_duration_secs += duration;
-                }
-            }
-        }
-        let avg_duration_secs = if ended_sessions > 0 {
-            total_duration_secs as f64 / f64::from(ended_sessions)
-        } else {
-            0.0
-        };
-
+        let aggregate = SessionStats::merge(rows);
         Ok(Response::new(SessionStatsResponse {
-            total_sessions,
-            ended_sessions,
-            avg_duration_secs,
-            total_events,
-            total_frames,
-            total_idle_secs,
+            total_sessions: aggregate.session_count as u32,
+            ended_sessions: aggregate.ended_sessions as u32,
+            avg_duration_secs: aggregate.avg_duration_secs(),
+            total_events: aggregate.total_events,
+            total_frames: aggregate.total_frames,
+            total_idle_secs: aggregate.total_idle_secs,
         }))
     }
 
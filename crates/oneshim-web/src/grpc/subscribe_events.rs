@@ -205,6 +205,10 @@ pub async fn subscribe_events(
                         Ok(RealtimeEvent::Metrics(_)) => continue,
                         // AiRuntimeStatus: snapshot-only — emitted once at Step 4a.
                         Ok(RealtimeEvent::AiRuntimeStatus(_)) => continue,
+                        // Connectivity: SSE dashboard timeline only.
+                        Ok(RealtimeEvent::Connectivity(_)) => continue,
+                        // DiskSpace: SSE dashboard banner only.
+                        Ok(RealtimeEvent::DiskSpace(_)) => continue,
                         // Ping: transport-layer liveness. Not surfaced.
                         Ok(RealtimeEvent::Ping) => continue,
                         Err(RecvError::Lagged(n)) => {
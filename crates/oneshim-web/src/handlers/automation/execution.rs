@@ -5,9 +5,11 @@ use axum::{
 };
 
 use oneshim_api_contracts::automation::{
-    AuditQuery, ExecuteIntentHintRequest, ExecuteSceneActionRequest, PolicyEventQuery,
+    AuditQuery, AuditSearchQuery, ExecuteIntentHintRequest, ExecuteSceneActionRequest,
+    PolicyEventQuery,
 };
-use oneshim_core::models::automation::ExecutionPolicyDto;
+use oneshim_api_contracts::common::TimeRangeQuery;
+use oneshim_core::models::automation::{ExecutionPolicyDto, WorkflowExecutionRecord};
 
 use crate::error::ApiError;
 use crate::services::automation_service::{AutomationCommandService, AutomationQueryService};
@@ -39,6 +41,24 @@ pub async fn get_audit_logs(
     ))
 }
 
+pub async fn search_audit_logs(
+    State(context): State<AutomationWebContext>,
+    Query(query): Query<AuditSearchQuery>,
+) -> Result<
+    Json<
+        oneshim_api_contracts::common::PaginatedResponse<
+            oneshim_api_contracts::automation::AuditEntryDto,
+        >,
+    >,
+    ApiError,
+> {
+    Ok(Json(
+        AutomationQueryService::new(context)
+            .search_audit_logs(&query)
+            .await?,
+    ))
+}
+
 pub async fn get_policy_events(
     State(context): State<AutomationWebContext>,
     Query(query): Query<PolicyEventQuery>,
@@ -111,6 +131,26 @@ pub async fn run_preset(
     ))
 }
 
+pub async fn get_workflow_history(
+    State(context): State<AutomationWebContext>,
+    Query(query): Query<TimeRangeQuery>,
+) -> Result<Json<Vec<WorkflowExecutionRecord>>, ApiError> {
+    Ok(Json(
+        AutomationQueryService::new(context).workflow_history(&query)?,
+    ))
+}
+
+pub async fn replay_workflow_execution(
+    State(context): State<AutomationWebContext>,
+    Path(id): Path<String>,
+) -> Result<Json<WorkflowExecutionRecord>, ApiError> {
+    Ok(Json(
+        AutomationCommandService::new(context)
+            .replay_execution(id)
+            .await?,
+    ))
+}
+
 pub async fn execute_intent_hint(
     State(context): State<AutomationWebContext>,
     Json(req): Json<ExecuteIntentHintRequest>,
@@ -5,7 +5,8 @@ pub use execution::{
     create_execution_policy, create_preset, delete_execution_policy, delete_preset,
     execute_intent_hint, execute_scene_action, get_audit_logs, get_automation_stats,
     get_automation_status, get_contract_versions, get_policies, get_policy_events,
-    list_execution_policies, list_presets, run_preset, update_execution_policy, update_preset,
+    get_workflow_history, list_execution_policies, list_presets, replay_workflow_execution,
+    run_preset, search_audit_logs, update_execution_policy, update_preset,
 };
 pub use scene::{get_automation_scene, get_automation_scene_calibration};
 
@@ -62,6 +63,7 @@ mod tests {
             status: "Completed".to_string(),
             details: Some("OK".to_string()),
             elapsed_ms: Some(150),
+            outcome: Some("success".to_string()),
         };
         let json = serde_json::to_string(&dto).unwrap();
         assert!(json.contains("cmd-001"));
@@ -4,9 +4,13 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use oneshim_api_contracts::backup::{BackupArchive, BackupQuery, RestoreResult};
+use oneshim_api_contracts::backup::{
+    BackupArchive, BackupQuery, DatabaseBackupResult, RestoreResult,
+};
 #[cfg(test)]
-use oneshim_api_contracts::backup::{BackupIncludes, BackupMetadata, RestoredCounts, TagBackup};
+use oneshim_api_contracts::backup::{
+    BackupIncludes, BackupMetadata, RedactionProfile, RestoredCounts, TagBackup,
+};
 
 use crate::error::ApiError;
 use crate::services::backup_service::{BackupCommandService, BackupDownload, BackupQueryService};
@@ -41,6 +45,14 @@ pub async fn restore_backup(
     ))
 }
 
+pub async fn create_database_backup(
+    State(context): State<BackupWebContext>,
+) -> Result<Json<DatabaseBackupResult>, ApiError> {
+    Ok(Json(
+        BackupCommandService::new(context).create_database_backup()?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,6 +64,7 @@ mod tests {
         assert!(query.include_tags);
         assert!(!query.include_events);
         assert!(!query.include_frames);
+        assert_eq!(query.redaction_profile, RedactionProfile::Full);
     }
 
     #[test]
@@ -67,6 +80,7 @@ mod tests {
                     events: false,
                     frames: false,
                 },
+                redaction_profile: RedactionProfile::Full,
             },
             settings: None,
             tags: Some(vec![TagBackup {
@@ -0,0 +1,118 @@
+use axum::extract::{Query, State};
+use axum::Json;
+use chrono::Duration;
+use oneshim_api_contracts::bookmarks::CreateBookmarkRequest;
+use oneshim_api_contracts::common::TimeRangeQuery;
+use oneshim_core::models::bookmark::Bookmark;
+
+use crate::error::ApiError;
+use crate::services::web_contexts::StorageWebContext;
+
+/// GET /api/bookmarks?from=&to=
+///
+/// Defaults to the last 7 days; oldest first.
+pub async fn list_bookmarks(
+    State(context): State<StorageWebContext>,
+    Query(params): Query<TimeRangeQuery>,
+) -> Result<Json<Vec<Bookmark>>, ApiError> {
+    let window = params
+        .to_time_window(Duration::days(7))
+        .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+    let bookmarks = context.storage.get_bookmarks(window.start, window.end)?;
+    Ok(Json(bookmarks))
+}
+
+/// POST /api/bookmarks
+pub async fn create_bookmark(
+    State(context): State<StorageWebContext>,
+    Json(req): Json<CreateBookmarkRequest>,
+) -> Result<Json<Bookmark>, ApiError> {
+    let mut bookmark = Bookmark::new(req.label);
+    bookmark.frame_id = req.frame_id;
+
+    context.storage.save_bookmark(&bookmark)?;
+    Ok(Json(bookmark))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AppState;
+    use oneshim_storage::sqlite::SqliteStorage;
+    use std::sync::Arc;
+    use tokio::sync::broadcast;
+
+    fn test_context() -> StorageWebContext {
+        let storage = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let (event_tx, _) = broadcast::channel(8);
+        StorageWebContext::from_state(&AppState::with_core(storage, event_tx))
+    }
+
+    #[tokio::test]
+    async fn created_bookmark_is_listed_with_label_and_timestamp() {
+        let context = test_context();
+        let created = create_bookmark(
+            State(context.clone()),
+            Json(CreateBookmarkRequest {
+                label: Some("bug reproduced here".to_string()),
+                frame_id: Some(42),
+            }),
+        )
+        .await
+        .expect("create bookmark")
+        .0;
+
+        let listed = list_bookmarks(State(context), Query(TimeRangeQuery::default()))
+            .await
+            .expect("list bookmarks")
+            .0;
+
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].bookmark_id, created.bookmark_id);
+        assert_eq!(listed[0].label.as_deref(), Some("bug reproduced here"));
+        assert_eq!(listed[0].frame_id, Some(42));
+        assert_eq!(
+            listed[0].timestamp.timestamp_micros(),
+            created.timestamp.timestamp_micros()
+        );
+    }
+
+    #[tokio::test]
+    async fn list_excludes_bookmarks_outside_window() {
+        let context = test_context();
+        create_bookmark(
+            State(context.clone()),
+            Json(CreateBookmarkRequest::default()),
+        )
+        .await
+        .expect("create bookmark");
+
+        let listed = list_bookmarks(
+            State(context),
+            Query(TimeRangeQuery {
+                from: Some("2020-01-01T00:00:00Z".to_string()),
+                to: Some("2020-01-02T00:00:00Z".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await
+        .expect("list bookmarks")
+        .0;
+
+        assert!(listed.is_empty());
+    }
+
+    #[tokio::test]
+    async fn list_rejects_unparseable_bounds() {
+        let result = list_bookmarks(
+            State(test_context()),
+            Query(TimeRangeQuery {
+                from: Some("not-a-date".to_string()),
+                ..Default::default()
+            }),
+        )
+        .await;
+
+        assert!(matches!(result, Err(ApiError::BadRequest(_))));
+    }
+}
@@ -3,7 +3,7 @@ use axum::Json;
 use chrono::{NaiveDate, Utc};
 use tracing::debug;
 
-use oneshim_api_contracts::dashboard::DashboardDayQuery;
+use oneshim_api_contracts::dashboard::{DashboardDayQuery, SchedulerLoopsResponse};
 use oneshim_core::models::daily_digest::DailyDigest;
 
 use crate::error::ApiError;
@@ -30,6 +30,14 @@ pub async fn get_dashboard_day(
     Ok(Json(digest))
 }
 
+/// GET /api/dashboard/loops — per-loop scheduler heartbeat status.
+pub async fn get_scheduler_loops(State(state): State<AppState>) -> Json<SchedulerLoopsResponse> {
+    Json(dashboard_service::scheduler_loop_health(
+        state.diagnostics.loop_health.as_deref(),
+        Utc::now(),
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
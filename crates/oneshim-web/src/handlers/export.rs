@@ -95,6 +95,8 @@ mod tests {
             interruption_count: 2,
             deep_work_secs: 3000,
             duration_secs: duration,
+            merged_gap_count: 0,
+            merged_gap_secs: 0,
         }
     }
 
@@ -1,10 +1,12 @@
 use axum::extract::{Path, Query, State};
 use axum::response::Response;
 use axum::Json;
-use oneshim_api_contracts::frames::FrameResponse;
+use oneshim_api_contracts::frames::{
+    FrameDiffQuery, FrameDiffResponse, FrameImageQuery, FrameResponse,
+};
 
 use crate::error::ApiError;
-use crate::services::frames_service::FramesQueryService;
+use crate::services::frames_service::{FramesCommandService, FramesQueryService};
 use crate::services::web_contexts::StorageWebContext;
 
 use super::{PaginatedResponse, TimeRangeQuery};
@@ -17,16 +19,56 @@ pub async fn get_frames(
     Ok(Json(FramesQueryService::new(context).get_frames(&params)?))
 }
 
-/// GET /api/frames/:id/image
+/// GET /api/frames/trash
+pub async fn get_trashed_frames(
+    State(context): State<StorageWebContext>,
+) -> Result<Json<Vec<FrameResponse>>, ApiError> {
+    Ok(Json(FramesQueryService::new(context).get_trashed_frames()?))
+}
+
+/// DELETE /api/frames/:id
+pub async fn delete_frame(
+    State(context): State<StorageWebContext>,
+    Path(frame_id): Path<i64>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    Ok(Json(
+        FramesCommandService::new(context).delete_frame(frame_id)?,
+    ))
+}
+
+/// POST /api/frames/:id/restore
+pub async fn restore_frame(
+    State(context): State<StorageWebContext>,
+    Path(frame_id): Path<i64>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    Ok(Json(
+        FramesCommandService::new(context).restore_frame(frame_id)?,
+    ))
+}
+
+/// GET /api/frames/:id/image?width=&height=&format=
 pub async fn get_frame_image(
     State(context): State<StorageWebContext>,
     Path(frame_id): Path<i64>,
+    Query(query): Query<FrameImageQuery>,
 ) -> Response {
     FramesQueryService::new(context)
-        .get_frame_image(frame_id)
+        .get_frame_image(frame_id, &query)
         .await
 }
 
+/// GET /api/frames/diff?from=&to=
+pub async fn get_frame_diff(
+    State(context): State<StorageWebContext>,
+    Query(query): Query<FrameDiffQuery>,
+) -> Result<Json<FrameDiffResponse>, ApiError> {
+    Ok(Json(
+        FramesQueryService::new(context)
+            .get_frame_diff(&query)
+            .await?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -151,4 +193,279 @@ mod tests {
             .expect("body bytes");
         assert_eq!(bytes.as_ref(), plaintext);
     }
+
+    fn write_png(dir: &std::path::Path, name: &str, width: u32, height: u32) {
+        let img = image::RgbImage::from_pixel(width, height, image::Rgb([200, 100, 50]));
+        img.save(dir.join(name)).expect("write png");
+    }
+
+    fn save_frame(sqlite: &SqliteStorage, path: &str) -> i64 {
+        sqlite
+            .save_frame_metadata(
+                &FrameMetadata {
+                    timestamp: Utc::now(),
+                    trigger_type: "manual".to_string(),
+                    app_name: "Code".to_string(),
+                    window_title: String::new(),
+                    resolution: (64, 48),
+                    importance: 1.0,
+                },
+                Some(path),
+                None,
+            )
+            .expect("frame metadata")
+    }
+
+    async fn get(app: &axum::Router, uri: &str) -> axum::response::Response {
+        app.clone()
+            .oneshot(
+                Request::builder()
+                    .uri(uri)
+                    .body(Body::empty())
+                    .expect("request build"),
+            )
+            .await
+            .expect("response")
+    }
+
+    #[tokio::test]
+    async fn resized_frame_image_is_served_from_cache_on_repeat() {
+        let data_dir = tempfile::tempdir().expect("temp data dir");
+        write_png(data_dir.path(), "frame.png", 64, 48);
+        let sqlite = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let frame_id = save_frame(&sqlite, "frame.png");
+
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(sqlite, event_tx);
+        state.core.frames_dir = Some(data_dir.path().to_path_buf());
+        let cache = state.core.frame_image_cache.clone();
+        let app = loopback_app(state);
+
+        let uri = format!("/api/frames/{frame_id}/image?width=32&format=png");
+        let first = get(&app, &uri).await;
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(
+            first.headers()[axum::http::header::CONTENT_TYPE],
+            "image/png"
+        );
+        let first = axum::body::to_bytes(first.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let decoded = image::load_from_memory(&first).expect("decode resized");
+        assert_eq!((decoded.width(), decoded.height()), (32, 24));
+
+        // Overwrite the source: a re-encode would now produce a different image.
+        write_png(data_dir.path(), "frame.png", 16, 16);
+        let second = get(&app, &uri).await;
+        let second = axum::body::to_bytes(second.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+
+        assert_eq!(first, second);
+        let stats = cache.stats();
+        assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+    }
+
+    #[tokio::test]
+    async fn deleting_frames_invalidates_cached_images() {
+        let data_dir = tempfile::tempdir().expect("temp data dir");
+        write_png(data_dir.path(), "frame.png", 64, 48);
+        let sqlite = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let frame_id = save_frame(&sqlite, "frame.png");
+
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(sqlite, event_tx);
+        state.core.frames_dir = Some(data_dir.path().to_path_buf());
+        let cache = state.core.frame_image_cache.clone();
+        let context = StorageWebContext::from_state(&state);
+        let app = loopback_app(state);
+
+        let uri = format!("/api/frames/{frame_id}/image?width=32");
+        assert_eq!(get(&app, &uri).await.status(), StatusCode::OK);
+        assert_eq!(cache.stats().entries, 1);
+
+        let deleted = crate::services::data_web_service::DataCommandService::new(context)
+            .delete_data_range(&oneshim_api_contracts::data::DeleteRangeRequest {
+                from: (Utc::now() - chrono::Duration::hours(1)).to_rfc3339(),
+                to: (Utc::now() + chrono::Duration::hours(1)).to_rfc3339(),
+                data_types: vec!["frames".to_string()],
+            })
+            .expect("delete range");
+        assert_eq!(deleted.frames_deleted, 1);
+
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(get(&app, &uri).await.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn unsupported_image_format_is_rejected() {
+        let data_dir = tempfile::tempdir().expect("temp data dir");
+        write_png(data_dir.path(), "frame.png", 8, 8);
+        let sqlite = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let frame_id = save_frame(&sqlite, "frame.png");
+
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(sqlite, event_tx);
+        state.core.frames_dir = Some(data_dir.path().to_path_buf());
+        let app = loopback_app(state);
+
+        let response = get(&app, &format!("/api/frames/{frame_id}/image?format=gif")).await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[tokio::test]
+    async fn frame_diff_reports_changed_region_between_two_frames() {
+        let data_dir = tempfile::tempdir().expect("temp data dir");
+        write_png(data_dir.path(), "before.png", 64, 48);
+        let mut after = image::RgbImage::from_pixel(64, 48, image::Rgb([200, 100, 50]));
+        for y in 16..32 {
+            for x in 0..16 {
+                after.put_pixel(x, y, image::Rgb([0, 0, 0]));
+            }
+        }
+        after
+            .save(data_dir.path().join("after.png"))
+            .expect("write png");
+        let sqlite = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let from = save_frame(&sqlite, "before.png");
+        let to = save_frame(&sqlite, "after.png");
+
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(sqlite, event_tx);
+        state.core.frames_dir = Some(data_dir.path().to_path_buf());
+        let app = loopback_app(state);
+
+        let response = get(&app, &format!("/api/frames/diff?from={from}&to={to}")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let json: serde_json::Value = serde_json::from_slice(&bytes).expect("json");
+
+        assert_eq!(json["changed_tiles"], 1);
+        assert_eq!(
+            json["changed_region"],
+            serde_json::json!({ "x": 0, "y": 16, "width": 16, "height": 16 })
+        );
+        assert!(json["changed_pixel_ratio"].as_f64().expect("ratio") > 0.0);
+        assert!(!json["diff_image_png"].as_str().expect("image").is_empty());
+
+        let missing = get(&app, &format!("/api/frames/diff?from={from}&to=99999")).await;
+        assert_eq!(missing.status(), StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn truncated_frame_file_serves_placeholder_and_is_flagged() {
+        use crate::services::frames_service::FRAME_CORRUPT_HEADER;
+
+        let data_dir = tempfile::tempdir().expect("temp data dir");
+        write_png(data_dir.path(), "frame.png", 64, 48);
+        let full = std::fs::read(data_dir.path().join("frame.png")).expect("read png");
+        std::fs::write(data_dir.path().join("frame.png"), &full[..full.len() / 2])
+            .expect("truncate png");
+        let sqlite = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let frame_id = save_frame(&sqlite, "frame.png");
+
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(sqlite.clone(), event_tx);
+        state.core.frames_dir = Some(data_dir.path().to_path_buf());
+        let cache = state.core.frame_image_cache.clone();
+        let app = loopback_app(state);
+
+        for uri in [
+            format!("/api/frames/{frame_id}/image"),
+            format!("/api/frames/{frame_id}/image?width=32"),
+        ] {
+            let response = get(&app, &uri).await;
+            assert_eq!(response.status(), StatusCode::OK);
+            assert_eq!(response.headers()[FRAME_CORRUPT_HEADER], "1");
+            let bytes = axum::body::to_bytes(response.into_body(), usize::MAX)
+                .await
+                .expect("body bytes");
+            image::load_from_memory(&bytes).expect("placeholder decodes");
+        }
+        assert_eq!(cache.stats().entries, 0);
+        assert_eq!(
+            sqlite.take_corrupt_frame_paths().expect("corrupt paths"),
+            vec!["frame.png".to_string()]
+        );
+    }
+
+    #[tokio::test]
+    async fn undecryptable_frame_file_serves_placeholder() {
+        use crate::services::frames_service::FRAME_CORRUPT_HEADER;
+
+        let data_dir = tempfile::tempdir().expect("temp data dir");
+        let key = Arc::new(EncryptionKey::from_bytes([0x42; 32]));
+        let frame_storage = Arc::new(
+            FrameFileStorage::with_encryption(data_dir.path().to_path_buf(), 100, 7, Some(key))
+                .await
+                .expect("encrypted frame storage"),
+        );
+        let relative_path = frame_storage
+            .save_frame(Utc::now(), b"RIFF\x00\x00\x00\x00WEBPVP8 bytes")
+            .await
+            .expect("save encrypted frame");
+        let on_disk = data_dir.path().join(&relative_path);
+        let ciphertext = std::fs::read(&on_disk).expect("read frame");
+        std::fs::write(&on_disk, &ciphertext[..ciphertext.len() - 4]).expect("truncate frame");
+
+        let sqlite = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let frame_id = save_frame(&sqlite, &relative_path.to_string_lossy());
+
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(sqlite.clone(), event_tx);
+        state.core.frames_dir = Some(data_dir.path().to_path_buf());
+        state.core.frame_storage = Some(frame_storage);
+        let app = loopback_app(state);
+
+        let response = get(&app, &format!("/api/frames/{frame_id}/image")).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[FRAME_CORRUPT_HEADER], "1");
+        assert_eq!(
+            sqlite
+                .take_corrupt_frame_paths()
+                .expect("corrupt paths")
+                .len(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn deleted_frame_moves_to_trash_until_restored() {
+        let sqlite = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let frame_id = save_frame(&sqlite, "frame.png");
+        let (event_tx, _) = broadcast::channel(16);
+        let context = StorageWebContext::from_state(&AppState::with_core(sqlite, event_tx));
+        let listed = |context: StorageWebContext| async move {
+            get_frames(State(context), Query(TimeRangeQuery::default()))
+                .await
+                .expect("list frames")
+                .0
+                .data
+                .len()
+        };
+
+        delete_frame(State(context.clone()), Path(frame_id))
+            .await
+            .expect("delete frame");
+        assert_eq!(listed(context.clone()).await, 0);
+        let trash = get_trashed_frames(State(context.clone())).await.unwrap().0;
+        assert_eq!(trash.len(), 1);
+        assert_eq!(trash[0].id, frame_id);
+        let err = delete_frame(State(context.clone()), Path(frame_id))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::NotFound(_)), "got: {err:?}");
+
+        restore_frame(State(context.clone()), Path(frame_id))
+            .await
+            .expect("restore frame");
+        assert_eq!(listed(context.clone()).await, 1);
+        assert!(get_trashed_frames(State(context))
+            .await
+            .unwrap()
+            .0
+            .is_empty());
+    }
 }
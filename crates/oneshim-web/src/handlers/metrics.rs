@@ -1,12 +1,17 @@
 use axum::extract::{Query, State};
+use axum::http::header;
+use axum::response::IntoResponse;
 use axum::Json;
 #[cfg(test)]
 use oneshim_api_contracts::metrics::MetricsResponse;
 use oneshim_api_contracts::metrics::{HourlyMetricsResponse, HourlyQuery};
+use oneshim_core::models::network_metrics::EndpointMetricsSnapshot;
 
 use crate::error::ApiError;
+use crate::services::metrics_assembler::render_capture_latency_exposition;
 use crate::services::metrics_service::MetricsQueryService;
 use crate::services::web_contexts::StorageWebContext;
+use crate::AppState;
 
 use super::TimeRangeQuery;
 
@@ -32,10 +37,39 @@ pub async fn get_hourly_metrics(
     ))
 }
 
+/// GET /api/metrics/prometheus — capture/encode/OCR latency in Prometheus
+/// text format. Empty apart from the metric headers until frames are captured.
+pub async fn get_prometheus_metrics(State(state): State<AppState>) -> impl IntoResponse {
+    let summaries = state
+        .diagnostics
+        .capture_latency
+        .as_ref()
+        .map(|recorder| recorder.summaries())
+        .unwrap_or_default();
+    (
+        [(header::CONTENT_TYPE, "text/plain; version=0.0.4")],
+        render_capture_latency_exposition(&summaries),
+    )
+}
+
+/// GET /api/metrics/network — per-endpoint request counts, error counts and
+/// latency percentiles of server calls. Empty in standalone mode.
+pub async fn get_network_metrics(
+    State(state): State<AppState>,
+) -> Json<Vec<EndpointMetricsSnapshot>> {
+    Json(
+        state
+            .diagnostics
+            .network_metrics
+            .as_ref()
+            .map(|provider| provider.endpoint_metrics())
+            .unwrap_or_default(),
+    )
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::AppState;
     use axum::body::Body;
     use axum::extract::connect_info::MockConnectInfo;
     use axum::http::{Request, StatusCode};
@@ -97,4 +131,103 @@ mod tests {
         assert!(parsed.is_array());
         assert_eq!(parsed.as_array().expect("array").len(), 0);
     }
+
+    #[tokio::test]
+    async fn prometheus_endpoint_exposes_stage_percentiles() {
+        use oneshim_core::models::capture_latency::{CaptureLatencyRecorder, CaptureStage};
+        use std::time::Duration;
+
+        let recorder = Arc::new(CaptureLatencyRecorder::new(64));
+        for ms in [10, 20, 30, 40] {
+            recorder.record(CaptureStage::Encode, Duration::from_millis(ms));
+        }
+        let mut state = test_app_state();
+        state.diagnostics.capture_latency = Some(recorder);
+
+        let response = loopback_app(state)
+            .oneshot(
+                Request::builder()
+                    .uri("/api/metrics/prometheus")
+                    .body(Body::empty())
+                    .expect("request build"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let text = String::from_utf8(body.to_vec()).expect("utf8");
+        assert!(text.contains(
+            "oneshim_capture_stage_latency_seconds{stage=\"encode\",quantile=\"0.5\"} 0.02"
+        ));
+        assert!(text.contains(
+            "oneshim_capture_stage_latency_seconds{stage=\"encode\",quantile=\"0.95\"} 0.04"
+        ));
+        assert!(text.contains("oneshim_capture_stage_samples_total{stage=\"encode\"} 4"));
+        assert!(!text.contains("stage=\"capture\""));
+    }
+
+    #[tokio::test]
+    async fn network_endpoint_serves_provider_snapshot() {
+        use oneshim_core::ports::network_metrics::NetworkMetricsProvider;
+
+        struct FixedNetworkMetrics;
+        impl NetworkMetricsProvider for FixedNetworkMetrics {
+            fn endpoint_metrics(&self) -> Vec<EndpointMetricsSnapshot> {
+                vec![EndpointMetricsSnapshot {
+                    endpoint: "POST /user_context/batches".to_string(),
+                    requests: 3,
+                    errors: 1,
+                    p50_ms: 20.0,
+                    p95_ms: 40.0,
+                    p99_ms: 40.0,
+                    max_ms: 40.0,
+                }]
+            }
+        }
+
+        let mut state = test_app_state();
+        state.diagnostics.network_metrics = Some(Arc::new(FixedNetworkMetrics));
+
+        let response = loopback_app(state)
+            .oneshot(
+                Request::builder()
+                    .uri("/api/metrics/network")
+                    .body(Body::empty())
+                    .expect("request build"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        let parsed: Vec<EndpointMetricsSnapshot> =
+            serde_json::from_slice(&body).expect("json parse");
+        assert_eq!(parsed.len(), 1);
+        assert_eq!(parsed[0].endpoint, "POST /user_context/batches");
+        assert_eq!((parsed[0].requests, parsed[0].errors), (3, 1));
+    }
+
+    #[tokio::test]
+    async fn network_endpoint_is_empty_without_provider() {
+        let response = loopback_app(test_app_state())
+            .oneshot(
+                Request::builder()
+                    .uri("/api/metrics/network")
+                    .body(Body::empty())
+                    .expect("request build"),
+            )
+            .await
+            .expect("response");
+        assert_eq!(response.status(), StatusCode::OK);
+
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .expect("body bytes");
+        assert_eq!(&body[..], b"[]");
+    }
 }
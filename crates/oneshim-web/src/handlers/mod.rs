@@ -12,6 +12,7 @@ pub mod digests;
 pub mod recalibration;
 
 pub mod backup;
+pub mod bookmarks;
 pub mod coaching;
 pub mod data;
 pub mod events;
@@ -31,6 +32,7 @@ pub mod search;
 pub mod semantic_search;
 pub mod sessions;
 pub mod settings;
+pub mod share_links;
 pub mod stats;
 pub mod stream;
 pub mod suggestions;
@@ -1,5 +1,7 @@
 use axum::extract::{Query, State};
 use axum::Json;
+use chrono::Utc;
+use oneshim_api_contracts::dashboard::DashboardDayQuery;
 #[cfg(test)]
 use oneshim_api_contracts::reports::{AppStat, DailyStat, ProductivityMetrics, ReportPeriod};
 use oneshim_api_contracts::reports::{ReportQuery, ReportResponse};
@@ -19,9 +21,107 @@ pub async fn generate_report(
     ))
 }
 
+/// GET /integration/v1/summary/daily?date=YYYY-MM-DD — single-day work
+/// summary for the server to pull. Defaults to today (UTC).
+pub async fn get_daily_summary(
+    State(context): State<StorageWebContext>,
+    Query(params): Query<DashboardDayQuery>,
+) -> Result<Json<ReportResponse>, ApiError> {
+    let date = params
+        .date
+        .unwrap_or_else(|| Utc::now().format("%Y-%m-%d").to_string());
+    Ok(Json(
+        ReportQueryService::new(context)
+            .daily_summary(&date)
+            .await?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::AppState;
+    use axum::body::Body;
+    use axum::extract::connect_info::MockConnectInfo;
+    use axum::http::{Request, StatusCode};
+    use chrono::TimeZone;
+    use oneshim_core::config::AppConfig;
+    use oneshim_core::config_manager::ConfigManager;
+    use oneshim_core::models::frame::FrameMetadata;
+    use oneshim_storage::sqlite::SqliteStorage;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use tokio::sync::broadcast;
+    use tower::ServiceExt;
+
+    const TOKEN: &str = "summary-secret";
+
+    fn summary_app(storage: Arc<SqliteStorage>, config_dir: &std::path::Path) -> axum::Router {
+        let manager = ConfigManager::with_path(config_dir.join("config.json")).unwrap();
+        let mut config = AppConfig::default_config();
+        config.web.integration_auth_token = Some(TOKEN.to_string());
+        manager.update(config).unwrap();
+
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(storage, event_tx);
+        state.core.config_manager = Some(manager);
+        crate::WebServer::build_router(state)
+            .layer(MockConnectInfo(SocketAddr::from(([10, 0, 0, 24], 44000))))
+    }
+
+    async fn pull_summary(app: axum::Router, date: &str) -> axum::response::Response {
+        app.oneshot(
+            Request::builder()
+                .uri(format!("/integration/v1/summary/daily?date={date}"))
+                .header("authorization", format!("Bearer {TOKEN}"))
+                .body(Body::empty())
+                .unwrap(),
+        )
+        .await
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn daily_summary_returns_report_for_populated_date() {
+        let storage = Arc::new(SqliteStorage::open_in_memory(30).unwrap());
+        for hour in [9, 14] {
+            let metadata = FrameMetadata {
+                timestamp: Utc.with_ymd_and_hms(2026, 3, 18, hour, 0, 0).unwrap(),
+                trigger_type: "AppSwitch".to_string(),
+                app_name: "Code".to_string(),
+                window_title: "main.rs".to_string(),
+                resolution: (1920, 1080),
+                importance: 0.5,
+            };
+            storage.save_frame_metadata(&metadata, None, None).unwrap();
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let app = summary_app(storage, dir.path());
+
+        let response = pull_summary(app, "2026-03-18").await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["from_date"], "2026-03-18");
+        assert_eq!(json["to_date"], "2026-03-18");
+        assert_eq!(json["days"], 1);
+        assert_eq!(json["total_captures"], 2);
+    }
+
+    #[tokio::test]
+    async fn daily_summary_is_not_found_for_empty_date() {
+        let storage = Arc::new(SqliteStorage::open_in_memory(30).unwrap());
+        let dir = tempfile::tempdir().unwrap();
+        let app = summary_app(storage, dir.path());
+
+        let response = pull_summary(app.clone(), "2026-03-19").await;
+        assert_eq!(response.status(), StatusCode::NOT_FOUND);
+
+        let response = pull_summary(app, "19-03-2026").await;
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    }
 
     #[test]
     fn report_period_deserializes() {
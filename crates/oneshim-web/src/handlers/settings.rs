@@ -6,7 +6,9 @@ use crate::{
     },
 };
 use axum::{extract::State, Json};
-use oneshim_api_contracts::settings::{AppSettings, StorageStats};
+use oneshim_api_contracts::settings::{
+    AppSettings, StorageIntegrityReport, StorageReportResponse, StorageStats,
+};
 
 pub async fn get_storage_stats(
     State(context): State<SettingsWebContext>,
@@ -16,6 +18,24 @@ pub async fn get_storage_stats(
     ))
 }
 
+pub async fn get_storage_report(
+    State(context): State<SettingsWebContext>,
+) -> Result<Json<StorageReportResponse>, ApiError> {
+    Ok(Json(
+        SettingsQueryService::new(context)
+            .get_storage_report()
+            .await?,
+    ))
+}
+
+pub async fn check_storage_integrity(
+    State(context): State<SettingsWebContext>,
+) -> Result<Json<StorageIntegrityReport>, ApiError> {
+    Ok(Json(
+        SettingsQueryService::new(context).check_storage_integrity()?,
+    ))
+}
+
 pub async fn get_settings(
     State(context): State<SettingsWebContext>,
 ) -> Result<Json<AppSettings>, ApiError> {
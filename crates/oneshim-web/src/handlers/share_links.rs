@@ -0,0 +1,212 @@
+use axum::extract::{Path, Query, State};
+use axum::http::StatusCode;
+use axum::{Extension, Json};
+use chrono::Utc;
+use oneshim_api_contracts::reports::ReportResponse;
+use oneshim_api_contracts::share_links::{
+    CreateShareLinkRequest, ShareLinkResponse, SharedReportQuery,
+};
+use tracing::debug;
+
+use crate::error::ApiError;
+use crate::services::reports_service::ReportQueryService;
+use crate::services::share_links_service::{scoped_report_query, ShareLinkService};
+use crate::services::web_contexts::{ConfigWebContext, StorageWebContext};
+use crate::share_link::ShareLinkClaims;
+
+pub async fn create_share_link(
+    State(context): State<ConfigWebContext>,
+    Json(request): Json<CreateShareLinkRequest>,
+) -> Result<Json<ShareLinkResponse>, ApiError> {
+    debug!("POST /api/share-links from={}", request.from);
+    Ok(Json(
+        ShareLinkService::new(context).create(&request, Utc::now())?,
+    ))
+}
+
+pub async fn revoke_share_link(
+    State(context): State<ConfigWebContext>,
+    Path(id): Path<String>,
+) -> Result<StatusCode, ApiError> {
+    debug!("DELETE /api/share-links/{id}");
+    ShareLinkService::new(context).revoke(&id)?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+/// GET /share/v1/report?token=… — the report a share link was issued for.
+/// The token is validated by `require_share_link` before this runs.
+pub async fn get_shared_report(
+    State(context): State<StorageWebContext>,
+    Extension(claims): Extension<ShareLinkClaims>,
+    Query(params): Query<SharedReportQuery>,
+) -> Result<Json<ReportResponse>, ApiError> {
+    let query = scoped_report_query(&claims, &params)?;
+    Ok(Json(
+        ReportQueryService::new(context)
+            .generate_report(&query)
+            .await?,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::AppState;
+    use axum::body::Body;
+    use axum::extract::connect_info::MockConnectInfo;
+    use axum::http::Request;
+    use chrono::Duration;
+    use oneshim_core::config::AppConfig;
+    use oneshim_core::config_manager::ConfigManager;
+    use oneshim_storage::sqlite::SqliteStorage;
+    use std::net::SocketAddr;
+    use std::sync::Arc;
+    use tokio::sync::broadcast;
+    use tower::ServiceExt;
+
+    struct Apps {
+        manager: ConfigManager,
+        local: axum::Router,
+        remote: axum::Router,
+    }
+
+    fn apps(config_dir: &std::path::Path) -> Apps {
+        let manager = ConfigManager::with_path(config_dir.join("config.json")).unwrap();
+        let mut config = AppConfig::default_config();
+        config.web.integration_auth_token = Some("integration-secret".to_string());
+        manager.update(config).unwrap();
+
+        let storage = Arc::new(SqliteStorage::open_in_memory(30).unwrap());
+        let (event_tx, _) = broadcast::channel(16);
+        let mut state = AppState::with_core(storage, event_tx);
+        state.core.config_manager = Some(manager.clone());
+        let router = crate::WebServer::build_router(state);
+        Apps {
+            manager,
+            local: router
+                .clone()
+                .layer(MockConnectInfo(SocketAddr::from(([127, 0, 0, 1], 44000)))),
+            remote: router.layer(MockConnectInfo(SocketAddr::from(([10, 0, 0, 24], 44000)))),
+        }
+    }
+
+    async fn send(app: &axum::Router, request: Request<Body>) -> axum::response::Response {
+        app.clone().oneshot(request).await.unwrap()
+    }
+
+    async fn get(app: &axum::Router, uri: &str) -> axum::response::Response {
+        send(app, Request::get(uri).body(Body::empty()).unwrap()).await
+    }
+
+    async fn create_link(app: &axum::Router, from: &str) -> ShareLinkResponse {
+        let response = send(
+            app,
+            Request::post("/api/share-links")
+                .header("content-type", "application/json")
+                .body(Body::from(format!(r#"{{"from":"{from}"}}"#)))
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[tokio::test]
+    async fn share_token_serves_only_its_report() {
+        let dir = tempfile::tempdir().unwrap();
+        let apps = apps(dir.path());
+        let link = create_link(&apps.local, "2026-03-18").await;
+        assert_eq!(
+            (link.from.as_str(), link.to.as_str()),
+            ("2026-03-18", "2026-03-18")
+        );
+
+        let response = get(&apps.remote, &link.path).await;
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+            .await
+            .unwrap();
+        let json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(json["from_date"], "2026-03-18");
+        assert_eq!(json["to_date"], "2026-03-18");
+
+        let other_day = format!("{}&from=2026-03-01&to=2026-03-31", link.path);
+        assert_eq!(
+            get(&apps.remote, &other_day).await.status(),
+            StatusCode::FORBIDDEN
+        );
+
+        // The share token is not a general credential.
+        let integration = Request::get("/integration/v1/summary/daily?date=2026-03-18")
+            .header("authorization", format!("Bearer {}", link.token))
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(
+            send(&apps.remote, integration).await.status(),
+            StatusCode::UNAUTHORIZED
+        );
+        let internal = format!(
+            "/api/reports?period=custom&from=2026-03-18&to=2026-03-18&token={}",
+            link.token
+        );
+        assert_eq!(
+            get(&apps.remote, &internal).await.status(),
+            StatusCode::FORBIDDEN
+        );
+    }
+
+    #[tokio::test]
+    async fn expired_revoked_and_missing_tokens_are_rejected() {
+        let dir = tempfile::tempdir().unwrap();
+        let apps = apps(dir.path());
+        let link = create_link(&apps.local, "2026-03-18").await;
+
+        let secret = apps.manager.get().web.share_link_secret.unwrap();
+        let expired = crate::share_link::sign(
+            secret.as_bytes(),
+            &ShareLinkClaims {
+                id: "expired".to_string(),
+                from: "2026-03-18".to_string(),
+                to: "2026-03-18".to_string(),
+                exp: (Utc::now() - Duration::minutes(1)).timestamp(),
+            },
+        );
+        let response = get(&apps.remote, &format!("/share/v1/report?token={expired}")).await;
+        assert_eq!(response.status(), StatusCode::UNAUTHORIZED);
+        assert_eq!(
+            get(&apps.remote, "/share/v1/report").await.status(),
+            StatusCode::UNAUTHORIZED
+        );
+
+        let revoke = Request::delete(format!("/api/share-links/{}", link.id))
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(
+            send(&apps.local, revoke).await.status(),
+            StatusCode::NO_CONTENT
+        );
+        assert_eq!(
+            get(&apps.remote, &link.path).await.status(),
+            StatusCode::UNAUTHORIZED
+        );
+    }
+
+    #[tokio::test]
+    async fn share_links_cannot_be_created_remotely() {
+        let dir = tempfile::tempdir().unwrap();
+        let apps = apps(dir.path());
+        let response = send(
+            &apps.remote,
+            Request::post("/api/share-links")
+                .header("content-type", "application/json")
+                .body(Body::from(r#"{"from":"2026-03-18"}"#))
+                .unwrap(),
+        )
+        .await;
+        assert_eq!(response.status(), StatusCode::FORBIDDEN);
+        assert!(apps.manager.get().web.share_link_secret.is_none());
+    }
+}
@@ -97,6 +97,7 @@ mod tests {
             window_title: "zsh".to_string(),
             importance: 0.7,
             trigger_type: "active_change".to_string(),
+            preview: None,
         });
         let json = serde_json::to_string(&event).unwrap();
         assert!(json.contains("\"type\":\"frame\""));
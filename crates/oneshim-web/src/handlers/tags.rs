@@ -1,7 +1,8 @@
 use axum::extract::{Path, State};
 use axum::Json;
 use oneshim_api_contracts::tags::{
-    BatchTagRequest, BatchTagResponse, CreateTagRequest, TagResponse, UpdateTagRequest,
+    BatchTagRequest, BatchTagResponse, CreateTagRequest, TagResponse, TagRuleRequest,
+    TagRuleResponse, UpdateTagRequest,
 };
 
 use crate::error::ApiError;
@@ -97,9 +98,71 @@ pub async fn batch_add_tag(
     Ok(Json(BatchTagResponse { tagged_count }))
 }
 
+/// GET /api/tag-rules
+pub async fn list_tag_rules(
+    State(context): State<StorageWebContext>,
+) -> Result<Json<Vec<TagRuleResponse>>, ApiError> {
+    Ok(Json(TagsQueryService::new(context).list_tag_rules()?))
+}
+
+/// POST /api/tag-rules
+pub async fn create_tag_rule(
+    State(context): State<StorageWebContext>,
+    Json(req): Json<TagRuleRequest>,
+) -> Result<Json<TagRuleResponse>, ApiError> {
+    Ok(Json(
+        TagsCommandService::new(context).create_tag_rule(&req)?,
+    ))
+}
+
+/// PUT /api/tag-rules/:id
+pub async fn update_tag_rule(
+    State(context): State<StorageWebContext>,
+    Path(rule_id): Path<i64>,
+    Json(req): Json<TagRuleRequest>,
+) -> Result<Json<TagRuleResponse>, ApiError> {
+    Ok(Json(
+        TagsCommandService::new(context).update_tag_rule(rule_id, &req)?,
+    ))
+}
+
+/// DELETE /api/tag-rules/:id
+pub async fn delete_tag_rule(
+    State(context): State<StorageWebContext>,
+    Path(rule_id): Path<i64>,
+) -> Result<Json<serde_json::Value>, ApiError> {
+    Ok(Json(
+        TagsCommandService::new(context).delete_tag_rule(rule_id)?,
+    ))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::AppState;
+    use oneshim_storage::sqlite::SqliteStorage;
+    use std::sync::Arc;
+    use tokio::sync::broadcast;
+
+    fn test_context() -> StorageWebContext {
+        let storage = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"));
+        let (event_tx, _) = broadcast::channel(8);
+        StorageWebContext::from_state(&AppState::with_core(storage, event_tx))
+    }
+
+    fn create_request(name: &str, color: Option<&str>) -> CreateTagRequest {
+        CreateTagRequest {
+            name: name.to_string(),
+            color: color.map(str::to_string),
+        }
+    }
+
+    async fn create(context: &StorageWebContext, name: &str) -> TagResponse {
+        create_tag(State(context.clone()), Json(create_request(name, None)))
+            .await
+            .expect("create tag")
+            .0
+    }
 
     #[test]
     fn tag_response_serializes() {
@@ -108,6 +171,7 @@ mod tests {
             name: "important".to_string(),
             color: "#ef4444".to_string(),
             created_at: "2024-01-01T00:00:00Z".to_string(),
+            auto_applied: false,
         };
         let json = serde_json::to_string(&tag).unwrap();
         assert!(json.contains("important"));
@@ -129,4 +193,122 @@ mod tests {
         assert_eq!(req.name, "work");
         assert!(req.color.is_none());
     }
+
+    #[tokio::test]
+    async fn duplicate_add_to_frame_succeeds_once() {
+        let context = test_context();
+        let tag = create(&context, "work").await;
+
+        for _ in 0..2 {
+            add_tag_to_frame(State(context.clone()), Path((1, tag.id)))
+                .await
+                .expect("adding an attached tag is a no-op");
+        }
+
+        let tags = get_frame_tags(State(context), Path(1)).await.unwrap().0;
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].id, tag.id);
+    }
+
+    #[tokio::test]
+    async fn duplicate_name_returns_existing_tag() {
+        let context = test_context();
+        let first = create(&context, "work").await;
+
+        let again = create_tag(
+            State(context.clone()),
+            Json(create_request("work", Some("#ef4444"))),
+        )
+        .await
+        .expect("duplicate name resolves to the existing tag")
+        .0;
+        assert_eq!(again.id, first.id);
+        assert_eq!(again.color, first.color);
+
+        let tags = list_tags(State(context)).await.unwrap().0;
+        assert_eq!(tags.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn renaming_onto_existing_name_conflicts() {
+        let context = test_context();
+        create(&context, "work").await;
+        let other = create(&context, "home").await;
+
+        let req = UpdateTagRequest {
+            name: "work".to_string(),
+            color: "#000000".to_string(),
+        };
+        let err = update_tag(State(context), Path(other.id), Json(req))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::Conflict(_)), "got: {err:?}");
+    }
+
+    #[tokio::test]
+    async fn repeated_remove_is_idempotent() {
+        let context = test_context();
+        let tag = create(&context, "work").await;
+        add_tag_to_frame(State(context.clone()), Path((1, tag.id)))
+            .await
+            .unwrap();
+
+        let first = remove_tag_from_frame(State(context.clone()), Path((1, tag.id)))
+            .await
+            .expect("first remove")
+            .0;
+        assert_eq!(first["removed"], true);
+
+        let second = remove_tag_from_frame(State(context.clone()), Path((1, tag.id)))
+            .await
+            .expect("repeated remove still succeeds")
+            .0;
+        assert_eq!(second["removed"], false);
+        assert!(get_frame_tags(State(context), Path(1))
+            .await
+            .unwrap()
+            .0
+            .is_empty());
+    }
+
+    #[tokio::test]
+    async fn tag_rule_crud_round_trips() {
+        let context = test_context();
+        let tag = create(&context, "communication").await;
+        let rule_request = |app_pattern: &str, tag_id| TagRuleRequest {
+            app_pattern: app_pattern.to_string(),
+            tag_id,
+        };
+
+        let rule = create_tag_rule(State(context.clone()), Json(rule_request("slack", tag.id)))
+            .await
+            .expect("create rule")
+            .0;
+        let updated = update_tag_rule(
+            State(context.clone()),
+            Path(rule.id),
+            Json(rule_request(" Discord ", tag.id)),
+        )
+        .await
+        .expect("update rule")
+        .0;
+        assert_eq!(updated.app_pattern, "Discord");
+
+        let rules = list_tag_rules(State(context.clone())).await.unwrap().0;
+        assert_eq!(rules.len(), 1);
+        assert_eq!(rules[0].app_pattern, "Discord");
+
+        let err = create_tag_rule(State(context.clone()), Json(rule_request("zoom", 999)))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::BadRequest(_)), "got: {err:?}");
+
+        delete_tag_rule(State(context.clone()), Path(rule.id))
+            .await
+            .expect("delete rule");
+        let err = delete_tag_rule(State(context), Path(rule.id))
+            .await
+            .unwrap_err();
+        assert!(matches!(err, ApiError::NotFound(_)), "got: {err:?}");
+    }
 }
@@ -51,6 +51,8 @@
 pub mod app_state;
 pub mod embedded;
 pub mod error;
+pub mod frame_diff;
+pub mod frame_image_cache;
 #[cfg(feature = "grpc-dashboard")]
 pub mod grpc;
 pub mod handlers;
@@ -59,6 +61,7 @@ pub mod proto;
 pub mod routes;
 pub mod runtime_bindings;
 pub mod services;
+pub mod share_link;
 pub mod storage_port;
 pub mod update_control;
 
@@ -71,14 +74,18 @@ use axum::response::IntoResponse;
 use axum::response::Response;
 use axum::Router;
 use oneshim_api_contracts::integration::IntegrationOutboundRuntimeStatus;
+use oneshim_api_contracts::share_links::SharedReportQuery;
 use oneshim_core::config::{CredentialBackendKind, WebConfig};
 use oneshim_core::config_manager::ConfigManager;
+use oneshim_core::models::capture_latency::CaptureLatencyRecorder;
 use oneshim_core::ports::audit_log::AuditLogPort;
 use oneshim_core::ports::automation::AutomationPort;
 use oneshim_core::ports::integration::{
     IntegrationAuditPort, IntegrationAuthPort, IntegrationInboxPort, IntegrationInboxStorePort,
     IntegrationOutboxPort, IntegrationSessionPort,
 };
+use oneshim_core::ports::loop_health::LoopHealthProvider;
+use oneshim_core::ports::network_metrics::NetworkMetricsProvider;
 use oneshim_core::ports::pii_sanitizer::PiiSanitizer;
 use oneshim_core::ports::runtime_log_provider::RuntimeLogProvider;
 use oneshim_core::ports::secret_store::{SecretStore, SecretStoreSet};
@@ -95,7 +102,8 @@ use tracing::debug;
 use tracing::{error, info, warn};
 
 pub use oneshim_api_contracts::stream::{
-    AiRuntimeStatus, FrameUpdate, IdleUpdate, MetricsUpdate, RealtimeEvent,
+    AiRuntimeStatus, ConnectivityUpdate, DiskSpaceUpdate, FrameUpdate, IdleUpdate, MetricsUpdate,
+    RealtimeEvent,
 };
 
 pub use oneshim_core::config::WebConfig as CoreWebConfig;
@@ -120,9 +128,13 @@ pub struct WebServer {
 impl WebServer {
     pub fn new(storage: Arc<dyn WebStorage>, config: WebConfig) -> Self {
         let (event_tx, _) = broadcast::channel(EVENT_CHANNEL_CAPACITY);
+        let mut state = AppState::with_core(storage, event_tx);
+        state.core.frame_image_cache = Arc::new(frame_image_cache::FrameImageCache::new(
+            config.frame_image_cache_entries,
+        ));
         Self {
             config,
-            state: AppState::with_core(storage, event_tx),
+            state,
             bound_port_state: None,
             bound_port_notifier: None,
         }
@@ -186,6 +198,24 @@ impl WebServer {
         self
     }
 
+    pub fn with_capture_latency_recorder(mut self, recorder: Arc<CaptureLatencyRecorder>) -> Self {
+        self.state.diagnostics.capture_latency = Some(recorder);
+        self
+    }
+
+    pub fn with_loop_health_provider(mut self, provider: Arc<dyn LoopHealthProvider>) -> Self {
+        self.state.diagnostics.loop_health = Some(provider);
+        self
+    }
+
+    pub fn with_network_metrics_provider(
+        mut self,
+        provider: Arc<dyn NetworkMetricsProvider>,
+    ) -> Self {
+        self.state.diagnostics.network_metrics = Some(provider);
+        self
+    }
+
     /// Wire the `LiveExternalConfig` Arc into `DiagnosticsState` so the
     /// `GET /api/external-grpc/live-config` endpoint can serve live snapshots.
     /// Only available when the `grpc-dashboard-external` feature is enabled.
@@ -396,10 +426,15 @@ impl WebServer {
         let integration_api = routes::integration_routes().route_layer(
             middleware::from_fn_with_state(state.clone(), require_integration_auth),
         );
+        let share_api = routes::share_routes().route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            require_share_link,
+        ));
 
         Router::new()
             .nest("/api", internal_api)
             .nest("/integration/v1", integration_api)
+            .nest("/share/v1", share_api)
             .fallback(loopback_only_static)
             .layer(CompressionLayer::new())
             .layer(cors)
@@ -591,6 +626,57 @@ async fn require_integration_auth(
     next.run(request).await
 }
 
+/// Accept a share token from `?token=` or a bearer header and hand its
+/// claims to the handler. Share routes never consult the integration token.
+async fn require_share_link(
+    State(state): State<AppState>,
+    mut request: Request,
+    next: Next,
+) -> Response {
+    let Some(config_manager) = state.core.config_manager.as_ref() else {
+        return crate::error::ApiError::ServiceUnavailable(
+            "Share links are unavailable because config management is not initialized.".to_string(),
+        )
+        .into_response();
+    };
+    let web = config_manager.snapshot().web.clone();
+    let Some(secret) = web.share_link_secret.filter(|secret| !secret.is_empty()) else {
+        return crate::error::ApiError::Unauthorized(
+            "No share links have been issued.".to_string(),
+        )
+        .into_response();
+    };
+
+    let query_token = axum::extract::Query::<SharedReportQuery>::try_from_uri(request.uri())
+        .ok()
+        .and_then(|axum::extract::Query(params)| params.token);
+    let token = query_token.or_else(|| {
+        request
+            .headers()
+            .get(axum::http::header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .map(|value| value.trim().to_string())
+    });
+    let Some(token) = token.filter(|token| !token.is_empty()) else {
+        return crate::error::ApiError::Unauthorized("A share token is required.".to_string())
+            .into_response();
+    };
+
+    match share_link::verify(
+        secret.as_bytes(),
+        &token,
+        &web.revoked_share_links,
+        chrono::Utc::now(),
+    ) {
+        Ok(claims) => {
+            request.extensions_mut().insert(claims);
+            next.run(request).await
+        }
+        Err(err) => crate::error::ApiError::Unauthorized(err.to_string()).into_response(),
+    }
+}
+
 async fn loopback_only_static(
     ConnectInfo(addr): ConnectInfo<SocketAddr>,
     uri: axum::http::Uri,
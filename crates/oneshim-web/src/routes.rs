@@ -11,11 +11,26 @@ pub fn api_routes() -> Router<AppState> {
             "/metrics/hourly",
             get(handlers::metrics::get_hourly_metrics),
         )
+        .route(
+            "/metrics/prometheus",
+            get(handlers::metrics::get_prometheus_metrics),
+        )
+        .route(
+            "/metrics/network",
+            get(handlers::metrics::get_network_metrics),
+        )
         .route("/processes", get(handlers::processes::get_processes))
         .route("/idle", get(handlers::idle::get_idle_periods))
         .route("/sessions", get(handlers::sessions::list_sessions))
         .route("/sessions/{id}", get(handlers::sessions::get_session))
         .route("/frames", get(handlers::frames::get_frames))
+        .route("/frames/diff", get(handlers::frames::get_frame_diff))
+        .route("/frames/trash", get(handlers::frames::get_trashed_frames))
+        .route("/frames/{id}", delete(handlers::frames::delete_frame))
+        .route(
+            "/frames/{id}/restore",
+            post(handlers::frames::restore_frame),
+        )
         .route("/frames/{id}/image", get(handlers::frames::get_frame_image))
         .route(
             "/frames/{frame_id}/annotations",
@@ -26,12 +41,24 @@ pub fn api_routes() -> Router<AppState> {
             "/frames/{frame_id}/annotations/{annotation_id}",
             delete(handlers::annotations::delete_annotation),
         )
+        .route(
+            "/bookmarks",
+            get(handlers::bookmarks::list_bookmarks).post(handlers::bookmarks::create_bookmark),
+        )
         .route("/events", get(handlers::events::get_events))
         .route("/stats/summary", get(handlers::stats::get_summary))
         .route("/stats/apps", get(handlers::stats::get_app_usage))
         .route("/stats/heatmap", get(handlers::stats::get_heatmap))
         .route("/stats/gui-heatmap", get(handlers::stats::get_gui_heatmap))
         .route("/reports", get(handlers::reports::generate_report))
+        .route(
+            "/share-links",
+            post(handlers::share_links::create_share_link),
+        )
+        .route(
+            "/share-links/{id}",
+            delete(handlers::share_links::revoke_share_link),
+        )
         .route("/settings", get(handlers::settings::get_settings))
         .route("/settings", post(handlers::settings::update_settings))
         .route(
@@ -93,6 +120,14 @@ pub fn api_routes() -> Router<AppState> {
             post(handlers::integration::dismiss_inbox_prompt),
         )
         .route("/storage/stats", get(handlers::settings::get_storage_stats))
+        .route(
+            "/storage/report",
+            get(handlers::settings::get_storage_report),
+        )
+        .route(
+            "/storage/integrity",
+            get(handlers::settings::check_storage_integrity),
+        )
         .route("/data/range", delete(handlers::data::delete_data_range))
         .route("/data/all", delete(handlers::data::delete_all_data))
         .route("/search", get(handlers::search::search))
@@ -104,11 +139,19 @@ pub fn api_routes() -> Router<AppState> {
         .route("/export/toggl", get(handlers::export::export_toggl))
         .route("/backup", get(handlers::backup::create_backup))
         .route("/backup/restore", post(handlers::backup::restore_backup))
+        .route(
+            "/backup/database",
+            post(handlers::backup::create_database_backup),
+        )
         .route("/tags", get(handlers::tags::list_tags))
         .route("/tags", post(handlers::tags::create_tag))
         .route("/tags/{id}", get(handlers::tags::get_tag))
         .route("/tags/{id}", put(handlers::tags::update_tag))
         .route("/tags/{id}", delete(handlers::tags::delete_tag))
+        .route("/tag-rules", get(handlers::tags::list_tag_rules))
+        .route("/tag-rules", post(handlers::tags::create_tag_rule))
+        .route("/tag-rules/{id}", put(handlers::tags::update_tag_rule))
+        .route("/tag-rules/{id}", delete(handlers::tags::delete_tag_rule))
         .route(
             "/frames/{frame_id}/tags",
             get(handlers::tags::get_frame_tags),
@@ -151,6 +194,10 @@ pub fn api_routes() -> Router<AppState> {
             "/automation/audit",
             get(handlers::automation::get_audit_logs),
         )
+        .route(
+            "/automation/audit/search",
+            get(handlers::automation::search_audit_logs),
+        )
         .route(
             "/automation/policy-events",
             get(handlers::automation::get_policy_events),
@@ -193,6 +240,14 @@ pub fn api_routes() -> Router<AppState> {
             "/automation/presets/{id}/run",
             post(handlers::automation::run_preset),
         )
+        .route(
+            "/automation/history",
+            get(handlers::automation::get_workflow_history),
+        )
+        .route(
+            "/automation/history/{id}/replay",
+            post(handlers::automation::replay_workflow_execution),
+        )
         .route(
             "/automation/execute-hint",
             post(handlers::automation::execute_intent_hint),
@@ -275,6 +330,10 @@ pub fn api_routes() -> Router<AppState> {
             "/dashboard/day",
             get(handlers::dashboard::get_dashboard_day),
         )
+        .route(
+            "/dashboard/loops",
+            get(handlers::dashboard::get_scheduler_loops),
+        )
         // Recalibration endpoints
         .route(
             "/recalibration/override",
@@ -349,6 +408,7 @@ pub fn integration_routes() -> Router<AppState> {
     Router::new()
         .route("/status", get(handlers::integration::get_status))
         .route("/audit", get(handlers::integration::get_audit))
+        .route("/summary/daily", get(handlers::reports::get_daily_summary))
         .route(
             "/ai/provider-surfaces",
             get(handlers::ai_provider_surfaces::list_provider_surfaces),
@@ -359,6 +419,11 @@ pub fn integration_routes() -> Router<AppState> {
         )
 }
 
+/// Read-only routes reachable with a report share token.
+pub fn share_routes() -> Router<AppState> {
+    Router::new().route("/report", get(handlers::share_links::get_shared_report))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -5,6 +5,7 @@ use oneshim_core::models::audit::AuditEntry;
 use crate::services::automation_service::AUTOMATION_AUDIT_SCHEMA_VERSION;
 
 pub fn map_audit_entry(entry: AuditEntry) -> AuditEntryDto {
+    let outcome = entry.outcome().map(|outcome| outcome.as_str().to_string());
     AuditEntryDto {
         schema_version: AUTOMATION_AUDIT_SCHEMA_VERSION.to_string(),
         entry_id: entry.entry_id,
@@ -15,5 +16,6 @@ pub fn map_audit_entry(entry: AuditEntry) -> AuditEntryDto {
         status: format!("{:?}", entry.status),
         details: entry.details,
         elapsed_ms: entry.execution_time_ms,
+        outcome,
     }
 }
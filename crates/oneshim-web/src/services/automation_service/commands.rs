@@ -5,6 +5,7 @@ use oneshim_api_contracts::automation::{
 };
 use oneshim_core::error::CoreError;
 use oneshim_core::models::audit::AuditLevel;
+use oneshim_core::models::automation::WorkflowExecutionRecord;
 use oneshim_core::models::intent::{builtin_presets, IntentCommand, IntentResult, WorkflowPreset};
 use oneshim_core::ports::audit_log::AuditLogPort;
 
@@ -142,36 +143,85 @@ impl AutomationCommandService {
         };
         self.validate_preset_ai_profile_binding(&preset)?;
 
+        self.ensure_automation_enabled()?;
+
+        let record = self.run_and_record(preset, None).await?;
+        Ok(PresetRunResult {
+            preset_id: record.result.preset_id,
+            success: true,
+            message: record.result.message,
+            steps_executed: Some(record.result.steps_executed),
+            total_steps: Some(record.result.total_steps),
+            total_elapsed_ms: Some(record.result.total_elapsed_ms),
+        })
+    }
+
+    /// Re-run a recorded execution with the preset snapshot it ran with,
+    /// not the preset's current definition.
+    pub async fn replay_execution(
+        &self,
+        execution_id: String,
+    ) -> Result<WorkflowExecutionRecord, ApiError> {
+        let Some(original) = self.ctx.storage.get_workflow_execution(&execution_id)? else {
+            return Err(ApiError::NotFound(format!(
+                "Workflow execution '{}' not found",
+                execution_id
+            )));
+        };
+        self.validate_preset_ai_profile_binding(&original.preset)?;
+        self.ensure_automation_enabled()?;
+
+        self.run_and_record(original.preset, Some(execution_id))
+            .await
+    }
+
+    fn ensure_automation_enabled(&self) -> Result<(), ApiError> {
         if let Some(ref config_manager) = self.ctx.config_manager {
             let config = config_manager.get();
             if !config.automation.enabled {
                 return Err(ApiError::BadRequest("Automation is disabled.".to_string()));
             }
         }
+        Ok(())
+    }
 
-        if let Some(ref controller) = self.ctx.automation_controller {
-            match controller.run_workflow(&preset).await {
-                Ok(result) => {
-                    if !result.success {
-                        return Err(ApiError::BadRequest(result.message));
-                    }
-
-                    Ok(PresetRunResult {
-                        preset_id: result.preset_id,
-                        success: true,
-                        message: result.message,
-                        steps_executed: Some(result.steps_executed),
-                        total_steps: Some(result.total_steps),
-                        total_elapsed_ms: Some(result.total_elapsed_ms),
-                    })
-                }
-                Err(e) => Err(ApiError::Internal(format!("execution failure: {}", e))),
-            }
-        } else {
-            Err(ApiError::BadRequest(
+    /// Run `preset` and persist the outcome to workflow history. Failed runs
+    /// are recorded too, then surfaced as `BadRequest`.
+    async fn run_and_record(
+        &self,
+        preset: WorkflowPreset,
+        replay_of: Option<String>,
+    ) -> Result<WorkflowExecutionRecord, ApiError> {
+        let Some(ref controller) = self.ctx.automation_controller else {
+            return Err(ApiError::BadRequest(
                 "Automation controller is not active.".to_string(),
-            ))
+            ));
+        };
+
+        let started_at = Utc::now();
+        let result = controller
+            .run_workflow(&preset)
+            .await
+            .map_err(|e| ApiError::Internal(format!("execution failure: {}", e)))?;
+
+        let record = WorkflowExecutionRecord {
+            execution_id: uuid::Uuid::new_v4().to_string(),
+            started_at,
+            preset,
+            result,
+            replay_of,
+        };
+        if let Err(e) = self.ctx.storage.save_workflow_execution(&record) {
+            tracing::warn!(
+                "Failed to record workflow execution {}: {e}",
+                record.execution_id
+            );
         }
+
+        if !record.result.success {
+            return Err(ApiError::BadRequest(record.result.message));
+        }
+        Ok(record)
     }
 
     fn validate_preset_ai_profile_binding(&self, preset: &WorkflowPreset) -> Result<(), ApiError> {
@@ -533,12 +583,173 @@ mod tests {
     use super::*;
     use crate::services::web_contexts::AutomationWebContext;
     use crate::storage_port::WebStorage;
+    use async_trait::async_trait;
+    use oneshim_api_contracts::common::TimeRangeQuery;
     use oneshim_core::config::{AiProviderProfileConfig, SavedAiProviderProfile};
     use oneshim_core::config_manager::ConfigManager;
+    use oneshim_core::error::GuiInteractionError;
+    use oneshim_core::models::automation::{
+        AutomationCommand, CommandResult, ExecutionPolicyDto, GuiExecutionResult,
+        PendingConfirmation, PlannedIntentResult, WorkflowResult, WorkflowStepResult,
+    };
+    use oneshim_core::models::gui::{
+        GuiConfirmRequest, GuiCreateSessionRequest, GuiCreateSessionResponse, GuiExecutionRequest,
+        GuiExecutionTicket, GuiHighlightRequest, GuiInteractionSession, GuiSessionEvent,
+    };
     use oneshim_core::models::intent::{AutomationIntent, PresetCategory, WorkflowStep};
+    use oneshim_core::models::ui_scene::UiScene;
+    use oneshim_core::ports::automation::AutomationPort;
     use oneshim_storage::sqlite::SqliteStorage;
-    use std::sync::Arc;
+    use std::sync::{Arc, Mutex};
     use tempfile::TempDir;
+    use tokio::sync::broadcast;
+
+    use crate::services::automation_service::AutomationQueryService;
+
+    /// Runs every workflow successfully and remembers the step names it ran.
+    #[derive(Default)]
+    struct RecordingAutomation {
+        runs: Mutex<Vec<Vec<String>>>,
+    }
+
+    #[async_trait]
+    impl AutomationPort for RecordingAutomation {
+        async fn execute_command(
+            &self,
+            _cmd: &AutomationCommand,
+        ) -> Result<CommandResult, CoreError> {
+            unimplemented!()
+        }
+        async fn execute_intent(&self, _cmd: &IntentCommand) -> Result<IntentResult, CoreError> {
+            unimplemented!()
+        }
+        async fn execute_intent_hint(
+            &self,
+            _command_id: &str,
+            _session_id: &str,
+            _intent_hint: &str,
+        ) -> Result<PlannedIntentResult, CoreError> {
+            unimplemented!()
+        }
+        async fn run_workflow(&self, preset: &WorkflowPreset) -> Result<WorkflowResult, CoreError> {
+            self.runs
+                .lock()
+                .unwrap()
+                .push(preset.steps.iter().map(|step| step.name.clone()).collect());
+            Ok(WorkflowResult {
+                preset_id: preset.id.clone(),
+                success: true,
+                steps_executed: preset.steps.len(),
+                total_steps: preset.steps.len(),
+                total_elapsed_ms: 5,
+                step_results: preset
+                    .steps
+                    .iter()
+                    .enumerate()
+                    .map(|(index, step)| WorkflowStepResult {
+                        step_name: step.name.clone(),
+                        step_index: index,
+                        success: true,
+                        elapsed_ms: 5,
+                        error: None,
+                        before_frame_id: None,
+                        after_frame_id: None,
+                    })
+                    .collect(),
+                message: "ok".to_string(),
+            })
+        }
+        async fn analyze_scene(
+            &self,
+            _app_name: Option<&str>,
+            _screen_id: Option<&str>,
+        ) -> Result<UiScene, CoreError> {
+            unimplemented!()
+        }
+        async fn analyze_scene_from_image(
+            &self,
+            _image_data: Vec<u8>,
+            _image_format: String,
+            _app_name: Option<&str>,
+            _screen_id: Option<&str>,
+        ) -> Result<UiScene, CoreError> {
+            unimplemented!()
+        }
+        async fn gui_create_session(
+            &self,
+            _req: GuiCreateSessionRequest,
+        ) -> Result<GuiCreateSessionResponse, GuiInteractionError> {
+            unimplemented!()
+        }
+        async fn gui_get_session(
+            &self,
+            _session_id: &str,
+            _capability_token: &str,
+        ) -> Result<GuiInteractionSession, GuiInteractionError> {
+            unimplemented!()
+        }
+        async fn gui_highlight_session(
+            &self,
+            _session_id: &str,
+            _capability_token: &str,
+            _req: GuiHighlightRequest,
+        ) -> Result<GuiInteractionSession, GuiInteractionError> {
+            unimplemented!()
+        }
+        async fn gui_confirm_candidate(
+            &self,
+            _session_id: &str,
+            _capability_token: &str,
+            _req: GuiConfirmRequest,
+        ) -> Result<GuiExecutionTicket, GuiInteractionError> {
+            unimplemented!()
+        }
+        async fn gui_execute(
+            &self,
+            _session_id: &str,
+            _capability_token: &str,
+            _req: GuiExecutionRequest,
+        ) -> Result<GuiExecutionResult, GuiInteractionError> {
+            unimplemented!()
+        }
+        async fn gui_cancel_session(
+            &self,
+            _session_id: &str,
+            _capability_token: &str,
+        ) -> Result<GuiInteractionSession, GuiInteractionError> {
+            unimplemented!()
+        }
+        async fn gui_subscribe_events(
+            &self,
+            _session_id: &str,
+            _capability_token: &str,
+        ) -> Result<broadcast::Receiver<GuiSessionEvent>, GuiInteractionError> {
+            unimplemented!()
+        }
+        async fn list_pending_confirmations(&self) -> Result<Vec<PendingConfirmation>, CoreError> {
+            unimplemented!()
+        }
+        async fn submit_confirmation(
+            &self,
+            _command_id: &str,
+            _nonce: &str,
+            _approved: bool,
+        ) -> Result<(), CoreError> {
+            unimplemented!()
+        }
+        async fn list_execution_policies(&self) -> Result<Vec<ExecutionPolicyDto>, CoreError> {
+            unimplemented!()
+        }
+        async fn add_execution_policy(
+            &self,
+            _policy: ExecutionPolicyDto,
+        ) -> Result<ExecutionPolicyDto, CoreError> {
+            unimplemented!()
+        }
+        async fn remove_execution_policy(&self, _policy_id: &str) -> Result<bool, CoreError> {
+            unimplemented!()
+        }
+    }
 
     fn test_context(config_manager: ConfigManager) -> AutomationWebContext {
         let storage = Arc::new(SqliteStorage::open_in_memory(30).expect("in-memory sqlite"))
@@ -650,4 +861,78 @@ mod tests {
             other => panic!("unexpected error: {other:?}"),
         }
     }
+
+    #[tokio::test]
+    async fn run_preset_records_history_and_replay_reruns_recorded_steps() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let config_manager =
+            ConfigManager::with_path(temp_dir.path().join("config.json")).expect("config manager");
+        config_manager
+            .update_with(|config| {
+                config.automation.enabled = true;
+                config.automation.custom_presets = vec![test_preset(None)];
+                Ok(())
+            })
+            .expect("save config");
+
+        let automation = Arc::new(RecordingAutomation::default());
+        let mut ctx = test_context(config_manager.clone());
+        ctx.automation_controller = Some(automation.clone() as Arc<dyn AutomationPort>);
+        let service = AutomationCommandService::new(ctx.clone());
+
+        service
+            .run_preset("preset-1".to_string())
+            .await
+            .expect("preset run");
+
+        let history = AutomationQueryService::new(ctx.clone())
+            .workflow_history(&TimeRangeQuery::default())
+            .expect("history");
+        assert_eq!(history.len(), 1);
+        let original = &history[0];
+        assert_eq!(original.result.preset_id, "preset-1");
+        assert_eq!(original.result.step_results[0].step_name, "Save");
+        assert!(original.replay_of.is_none());
+
+        // Editing the preset afterwards must not change what a replay runs.
+        config_manager
+            .update_with(|config| {
+                config.automation.custom_presets[0].steps[0].name = "Save As".to_string();
+                Ok(())
+            })
+            .expect("edit preset");
+
+        let replay = service
+            .replay_execution(original.execution_id.clone())
+            .await
+            .expect("replay");
+        assert_eq!(
+            replay.replay_of.as_deref(),
+            Some(original.execution_id.as_str())
+        );
+        assert_ne!(replay.execution_id, original.execution_id);
+        assert_eq!(
+            *automation.runs.lock().unwrap(),
+            vec![vec!["Save".to_string()], vec!["Save".to_string()]]
+        );
+
+        let history = AutomationQueryService::new(ctx)
+            .workflow_history(&TimeRangeQuery::default())
+            .expect("history");
+        assert_eq!(history.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn replay_unknown_execution_is_not_found() {
+        let temp_dir = TempDir::new().expect("temp dir");
+        let config_manager =
+            ConfigManager::with_path(temp_dir.path().join("config.json")).expect("config manager");
+        let service = AutomationCommandService::new(test_context(config_manager));
+
+        let err = service
+            .replay_execution("missing".to_string())
+            .await
+            .expect_err("unknown execution");
+        assert!(matches!(err, ApiError::NotFound(_)));
+    }
 }
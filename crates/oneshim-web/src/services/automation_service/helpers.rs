@@ -9,7 +9,7 @@ use oneshim_core::config::{
     SceneActionOverrideConfig, SceneIntelligenceConfig,
 };
 use oneshim_core::config_manager::ConfigManager;
-use oneshim_core::models::audit::AuditStatus;
+use oneshim_core::models::audit::{AuditOutcome, AuditStatus};
 use oneshim_core::models::automation::AutomationAction;
 use oneshim_core::models::intent::AutomationIntent;
 use oneshim_core::models::ui_scene::UiScene;
@@ -132,6 +132,20 @@ pub(crate) fn parse_audit_status(status_filter: &str) -> Result<AuditStatus, Api
     }
 }
 
+/// Parses a comma-separated outcome list (`"failed,denied"`). Empty or
+/// missing means no outcome filter.
+pub(crate) fn parse_audit_outcomes(raw: Option<&str>) -> Result<Vec<AuditOutcome>, ApiError> {
+    raw.unwrap_or_default()
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| {
+            AuditOutcome::parse(value)
+                .ok_or_else(|| ApiError::BadRequest(format!("Invalid outcome filter: {value}")))
+        })
+        .collect()
+}
+
 pub(crate) fn build_scene_action_intents(
     req: &ExecuteSceneActionRequest,
 ) -> Result<Vec<AutomationIntent>, ApiError> {
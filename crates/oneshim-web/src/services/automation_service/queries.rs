@@ -1,17 +1,21 @@
 use chrono::Utc;
 
 use oneshim_api_contracts::automation::{
-    AuditQuery, AutomationContractsDto, AutomationStatsDto, AutomationStatusDto, PoliciesDto,
-    PolicyEventQuery, PresetListDto,
+    AuditEntryDto, AuditQuery, AuditSearchQuery, AutomationContractsDto, AutomationStatsDto,
+    AutomationStatusDto, PoliciesDto, PolicyEventQuery, PresetListDto,
 };
 
+use oneshim_api_contracts::common::{PaginatedResponse, PaginationMeta, TimeRangeQuery};
+use oneshim_core::models::audit::{AuditFilter, AuditPage};
+use oneshim_core::models::automation::WorkflowExecutionRecord;
+
 use crate::error::ApiError;
 use crate::services::automation_assembler::map_audit_entry;
 use crate::services::web_contexts::AutomationWebContext;
 
 use super::helpers::{
     default_automation_status, default_policies, evaluate_scene_action_override,
-    parse_audit_status, resolve_ai_runtime_status,
+    parse_audit_outcomes, parse_audit_status, resolve_ai_runtime_status,
 };
 use super::{AUTOMATION_AUDIT_SCHEMA_VERSION, AUTOMATION_SCENE_ACTION_SCHEMA_VERSION};
 
@@ -67,10 +71,7 @@ impl AutomationQueryService {
         }
     }
 
-    pub async fn audit_logs(
-        &self,
-        query: AuditQuery,
-    ) -> Result<Vec<oneshim_api_contracts::automation::AuditEntryDto>, ApiError> {
+    pub async fn audit_logs(&self, query: AuditQuery) -> Result<Vec<AuditEntryDto>, ApiError> {
         let Some(ref logger) = self.ctx.audit_logger else {
             return Ok(Vec::new());
         };
@@ -85,10 +86,44 @@ impl AutomationQueryService {
         Ok(entries.into_iter().map(map_audit_entry).collect())
     }
 
+    pub async fn search_audit_logs(
+        &self,
+        query: &AuditSearchQuery,
+    ) -> Result<PaginatedResponse<AuditEntryDto>, ApiError> {
+        let range = query.time_range();
+        let window = range
+            .to_time_window(chrono::Duration::hours(24))
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        let filter = AuditFilter {
+            outcomes: parse_audit_outcomes(query.outcome.as_deref())?,
+            from: Some(window.start),
+            to: Some(window.end),
+        };
+        let page = AuditPage {
+            offset: range.offset_or_default(),
+            limit: range.limit_or_default().clamp(1, 500),
+        };
+
+        let result = match self.ctx.audit_logger {
+            Some(ref logger) => logger.query(&filter, page).await,
+            None => Default::default(),
+        };
+        let data: Vec<_> = result.entries.into_iter().map(map_audit_entry).collect();
+        Ok(PaginatedResponse {
+            pagination: PaginationMeta {
+                total: result.total as u64,
+                offset: page.offset,
+                limit: page.limit,
+                has_more: page.offset + data.len() < result.total,
+            },
+            data,
+        })
+    }
+
     pub async fn policy_events(
         &self,
         query: PolicyEventQuery,
-    ) -> Result<Vec<oneshim_api_contracts::automation::AuditEntryDto>, ApiError> {
+    ) -> Result<Vec<AuditEntryDto>, ApiError> {
         let Some(ref logger) = self.ctx.audit_logger else {
             return Ok(Vec::new());
         };
@@ -208,4 +243,19 @@ impl AutomationQueryService {
         }
         PresetListDto { presets }
     }
+
+    /// Recorded workflow runs in the query window (default: last 7 days),
+    /// newest first.
+    pub fn workflow_history(
+        &self,
+        query: &TimeRangeQuery,
+    ) -> Result<Vec<WorkflowExecutionRecord>, ApiError> {
+        let window = query
+            .to_time_window(chrono::Duration::days(7))
+            .map_err(|e| ApiError::BadRequest(e.to_string()))?;
+        Ok(self
+            .ctx
+            .storage
+            .get_workflow_history(window.start, window.end)?)
+    }
 }
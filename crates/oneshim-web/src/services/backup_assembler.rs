@@ -1,7 +1,7 @@
 use chrono::Utc;
 use oneshim_api_contracts::backup::{
     BackupArchive, BackupIncludes, BackupMetadata, EventBackup, FrameBackup, FrameTagBackup,
-    RestoreResult, RestoredCounts, SettingsBackup, TagBackup,
+    RedactionProfile, RestoreResult, RestoredCounts, SettingsBackup, TagBackup,
 };
 use oneshim_core::models::storage_records::{
     EventExportRecord, FrameExportRecord, FrameTagLinkRecord, TagRecord,
@@ -9,13 +9,20 @@ use oneshim_core::models::storage_records::{
 
 use crate::services::web_contexts::BackupWebContext;
 
-pub(crate) fn new_backup_archive(includes: BackupIncludes) -> BackupArchive {
+/// Event type stored for `Event::Input` rows.
+const INPUT_EVENT_TYPE: &str = "input_activity";
+
+pub(crate) fn new_backup_archive(
+    includes: BackupIncludes,
+    redaction_profile: RedactionProfile,
+) -> BackupArchive {
     BackupArchive {
         metadata: BackupMetadata {
             version: "1.0".to_string(),
             created_at: Utc::now().to_rfc3339(),
             app_version: env!("CARGO_PKG_VERSION").to_string(),
             includes,
+            redaction_profile,
         },
         settings: None,
         tags: None,
@@ -100,6 +107,33 @@ pub(crate) fn to_frame_backup(frame: FrameExportRecord) -> FrameBackup {
     }
 }
 
+/// Apply `profile` to an event; `None` when the profile drops it entirely.
+pub(crate) fn redact_event_backup(
+    mut event: EventBackup,
+    profile: RedactionProfile,
+) -> Option<EventBackup> {
+    if profile.drops_input_events() && event.event_type == INPUT_EVENT_TYPE {
+        return None;
+    }
+    if profile.strips_window_titles() {
+        event.window_title = None;
+    }
+    Some(event)
+}
+
+pub(crate) fn redact_frame_backup(
+    mut frame: FrameBackup,
+    profile: RedactionProfile,
+) -> FrameBackup {
+    if profile.strips_ocr() {
+        frame.ocr_text = None;
+    }
+    if profile.strips_window_titles() {
+        frame.window_title = String::new();
+    }
+    frame
+}
+
 pub(crate) fn empty_restore_counts() -> RestoredCounts {
     RestoredCounts {
         settings: false,
@@ -120,3 +154,76 @@ pub(crate) fn assemble_restore_result(
         errors,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn frame_with_ocr() -> FrameBackup {
+        FrameBackup {
+            id: 7,
+            timestamp: "2026-04-11T09:00:00Z".to_string(),
+            trigger_type: "WindowChange".to_string(),
+            app_name: "Code".to_string(),
+            window_title: "payroll.xlsx".to_string(),
+            importance: 0.6,
+            width: 1920,
+            height: 1080,
+            ocr_text: Some("salary 120000".to_string()),
+        }
+    }
+
+    fn event(event_type: &str) -> EventBackup {
+        EventBackup {
+            event_id: "evt-1".to_string(),
+            event_type: event_type.to_string(),
+            timestamp: "2026-04-11T09:00:00Z".to_string(),
+            app_name: Some("Code".to_string()),
+            window_title: Some("payroll.xlsx".to_string()),
+        }
+    }
+
+    #[test]
+    fn strip_ocr_profile_removes_ocr_text() {
+        let frame = redact_frame_backup(frame_with_ocr(), RedactionProfile::StripOcr);
+        assert!(frame.ocr_text.is_none());
+        assert_eq!(frame.window_title, "payroll.xlsx");
+    }
+
+    #[test]
+    fn full_profile_preserves_ocr_text() {
+        let frame = redact_frame_backup(frame_with_ocr(), RedactionProfile::Full);
+        assert_eq!(frame.ocr_text.as_deref(), Some("salary 120000"));
+    }
+
+    #[test]
+    fn external_profile_drops_input_and_titles() {
+        assert!(redact_event_backup(event(INPUT_EVENT_TYPE), RedactionProfile::External).is_none());
+
+        let context =
+            redact_event_backup(event("context_change"), RedactionProfile::External).unwrap();
+        assert!(context.window_title.is_none());
+        assert_eq!(context.app_name.as_deref(), Some("Code"));
+
+        let frame = redact_frame_backup(frame_with_ocr(), RedactionProfile::External);
+        assert!(frame.ocr_text.is_none());
+        assert!(frame.window_title.is_empty());
+    }
+
+    #[test]
+    fn archive_metadata_records_profile() {
+        let archive = new_backup_archive(
+            BackupIncludes {
+                settings: false,
+                tags: false,
+                events: false,
+                frames: true,
+            },
+            RedactionProfile::StripOcr,
+        );
+        assert_eq!(
+            archive.metadata.redaction_profile,
+            RedactionProfile::StripOcr
+        );
+    }
+}
@@ -1,11 +1,13 @@
 use oneshim_api_contracts::backup::{
-    BackupArchive, BackupIncludes, BackupQuery, RestoreResult, SettingsBackup,
+    BackupArchive, BackupIncludes, BackupQuery, DatabaseBackupResult, RestoreResult, SettingsBackup,
 };
+use oneshim_core::config::AppConfig;
 
 use crate::error::ApiError;
 use crate::services::backup_assembler::{
     assemble_restore_result, backup_filename, backup_settings_from_context, empty_restore_counts,
-    new_backup_archive, to_event_backup, to_frame_backup, to_frame_tag_backup, to_tag_backup,
+    new_backup_archive, redact_event_backup, redact_frame_backup, to_event_backup, to_frame_backup,
+    to_frame_tag_backup, to_tag_backup,
 };
 use crate::services::web_contexts::BackupWebContext;
 
@@ -39,12 +41,16 @@ impl BackupQueryService {
     }
 
     fn create_backup_archive(&self, params: &BackupQuery) -> Result<BackupArchive, ApiError> {
-        let mut archive = new_backup_archive(BackupIncludes {
-            settings: params.include_settings,
-            tags: params.include_tags,
-            events: params.include_events,
-            frames: params.include_frames,
-        });
+        let mut archive = new_backup_archive(
+            BackupIncludes {
+                settings: params.include_settings,
+                tags: params.include_tags,
+                events: params.include_events,
+                frames: params.include_frames,
+            },
+            params.redaction_profile,
+        );
+        let profile = params.redaction_profile;
 
         if params.include_settings {
             archive.settings = Some(backup_settings_from_context(&self.ctx));
@@ -78,7 +84,7 @@ impl BackupQueryService {
                     .list_event_exports(BACKUP_RANGE_START, BACKUP_RANGE_END)
                     .map_err(|error| ApiError::Internal(error.to_string()))?
                     .into_iter()
-                    .map(to_event_backup)
+                    .filter_map(|row| redact_event_backup(to_event_backup(row), profile))
                     .collect(),
             );
         }
@@ -90,7 +96,7 @@ impl BackupQueryService {
                     .list_frame_exports(BACKUP_RANGE_START, BACKUP_RANGE_END)
                     .map_err(|error| ApiError::Internal(error.to_string()))?
                     .into_iter()
-                    .map(to_frame_backup)
+                    .map(|row| redact_frame_backup(to_frame_backup(row), profile))
                     .collect(),
             );
         }
@@ -187,6 +193,24 @@ impl BackupCommandService {
 
         Ok(assemble_restore_result(restored, errors))
     }
+
+    /// Snapshot the live database with rotation per `storage.db_backup_keep`.
+    pub fn create_database_backup(&self) -> Result<DatabaseBackupResult, ApiError> {
+        let keep = self
+            .ctx
+            .config_manager
+            .as_ref()
+            .map(|config_manager| config_manager.get().storage.db_backup_keep)
+            .unwrap_or_else(|| AppConfig::default_config().storage.db_backup_keep);
+        let record = self.ctx.storage.create_database_backup(keep)?;
+
+        Ok(DatabaseBackupResult {
+            path: record.path,
+            size_bytes: record.size_bytes,
+            created_at: record.created_at,
+            rotated_out: record.rotated_out,
+        })
+    }
 }
 
 fn restore_settings_to_context(
@@ -296,8 +296,10 @@ mod tests {
                     status: "ok".to_string(),
                     details: Some("contact user@example.com".to_string()),
                     elapsed_ms: None,
+                    outcome: None,
                 }],
                 recent_policy_events: vec![],
+                capture_latency: vec![],
             },
             system: SystemInfoDto {
                 app_version: "0.4.16".to_string(),
@@ -357,6 +359,7 @@ mod tests {
                 storage_stats: None,
                 recent_audit_entries: vec![],
                 recent_policy_events: vec![],
+                capture_latency: vec![],
             },
             system: SystemInfoDto {
                 app_version: "0.4.16".to_string(),
@@ -1,16 +1,20 @@
 //! Dashboard service — digest generation, statistics, and caching.
 
-use chrono::{NaiveDate, Utc};
+use chrono::{DateTime, NaiveDate, Utc};
 use std::collections::HashMap;
 use tracing::warn;
 
-use oneshim_api_contracts::dashboard::{RawContentActivity, RawContentActivityBrief};
+use oneshim_api_contracts::dashboard::{
+    RawContentActivity, RawContentActivityBrief, SchedulerLoopHealth, SchedulerLoopsResponse,
+};
 use oneshim_core::error::CoreError;
 use oneshim_core::models::daily_digest::{
     self, ContentBrief, DailyDigest, DailyStatistics, DayComparison, TimelineEntry,
 };
+use oneshim_core::models::loop_health::LoopHealthStatus;
 use oneshim_core::models::storage_records::SegmentSummaryRecord;
 use oneshim_core::models::tiered_memory::WorkType;
+use oneshim_core::ports::loop_health::LoopHealthProvider;
 
 use crate::AppState;
 
@@ -44,6 +48,47 @@ pub fn get_or_generate_digest(
     Ok(digest)
 }
 
+/// Evaluate every scheduler loop heartbeat against the watchdog threshold.
+pub fn scheduler_loop_health(
+    provider: Option<&dyn LoopHealthProvider>,
+    now: DateTime<Utc>,
+) -> SchedulerLoopsResponse {
+    let Some(provider) = provider else {
+        return SchedulerLoopsResponse {
+            available: false,
+            stall_multiplier: 0,
+            loops: Vec::new(),
+        };
+    };
+
+    let stall_multiplier = provider.stall_multiplier();
+    let loops = provider
+        .heartbeats()
+        .into_iter()
+        .map(|hb| {
+            let status = match hb.status_at(now, stall_multiplier) {
+                LoopHealthStatus::Healthy => "healthy",
+                LoopHealthStatus::Stalled => "stalled",
+            };
+            SchedulerLoopHealth {
+                status: status.to_string(),
+                interval_secs: hb.interval_secs,
+                last_tick_at: hb.last_tick_at.to_rfc3339(),
+                seconds_since_last_tick: (now - hb.last_tick_at).num_seconds().max(0) as u64,
+                tick_count: hb.tick_count,
+                restart_count: hb.restart_count,
+                name: hb.name,
+            }
+        })
+        .collect();
+
+    SchedulerLoopsResponse {
+        available: true,
+        stall_multiplier,
+        loops,
+    }
+}
+
 fn build_daily_digest(
     records: &[SegmentSummaryRecord],
     date: NaiveDate,
@@ -267,4 +312,46 @@ mod tests {
         let briefs = parse_content_briefs("");
         assert!(briefs.is_empty());
     }
+
+    struct FixedLoopHealth(Vec<oneshim_core::models::loop_health::LoopHeartbeat>);
+
+    impl LoopHealthProvider for FixedLoopHealth {
+        fn heartbeats(&self) -> Vec<oneshim_core::models::loop_health::LoopHeartbeat> {
+            self.0.clone()
+        }
+
+        fn stall_multiplier(&self) -> u32 {
+            3
+        }
+    }
+
+    #[test]
+    fn scheduler_loop_health_flags_stalled_loop() {
+        use oneshim_core::models::loop_health::LoopHeartbeat;
+        use std::time::Duration;
+
+        let now = Utc::now();
+        let provider = FixedLoopHealth(vec![
+            LoopHeartbeat::new("metrics", Duration::from_secs(5), now),
+            LoopHeartbeat::new(
+                "process",
+                Duration::from_secs(10),
+                now - chrono::Duration::seconds(90),
+            ),
+        ]);
+
+        let report = scheduler_loop_health(Some(&provider), now);
+        assert!(report.available);
+        assert_eq!(report.stall_multiplier, 3);
+        assert_eq!(report.loops[0].status, "healthy");
+        assert_eq!(report.loops[1].status, "stalled");
+        assert_eq!(report.loops[1].seconds_since_last_tick, 90);
+    }
+
+    #[test]
+    fn scheduler_loop_health_without_scheduler() {
+        let report = scheduler_loop_health(None, Utc::now());
+        assert!(!report.available);
+        assert!(report.loops.is_empty());
+    }
 }
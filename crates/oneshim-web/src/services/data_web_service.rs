@@ -62,6 +62,10 @@ impl DataCommandService {
             )
             .map_err(|error| ApiError::Internal(error.to_string()))?;
 
+        if deleted.frames_deleted > 0 {
+            self.ctx.frame_image_cache.clear();
+        }
+
         result.events_deleted = deleted.events_deleted;
         result.frames_deleted = deleted.frames_deleted;
         result.metrics_deleted = deleted.metrics_deleted;
@@ -79,6 +83,8 @@ impl DataCommandService {
             .delete_all_data()
             .map_err(|error| ApiError::Internal(error.to_string()))?;
 
+        self.ctx.frame_image_cache.clear();
+
         // Phase 2: Best-effort frame file deletion (after DB commit)
         if let Some(ref frames_dir) = self.ctx.frames_dir {
             if frames_dir.exists() {
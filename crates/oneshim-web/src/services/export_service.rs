@@ -340,6 +340,8 @@ mod tests {
             interruption_count: 2,
             deep_work_secs: 4800,
             duration_secs: 5400,
+            merged_gap_count: 0,
+            merged_gap_secs: 0,
         }
     }
 
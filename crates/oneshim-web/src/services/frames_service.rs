@@ -1,14 +1,28 @@
 use axum::http::{header, StatusCode};
 use axum::response::{IntoResponse, Response};
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
 use chrono::Duration;
-use oneshim_api_contracts::frames::FrameResponse;
+use oneshim_api_contracts::frames::{
+    FrameDiffQuery, FrameDiffResponse, FrameImageQuery, FrameRegionDto, FrameResponse,
+};
+use oneshim_core::error::CoreError;
+use oneshim_core::models::frame::{is_truncated_image, CORRUPT_FRAME_FIELD};
 use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+use tracing::warn;
 
 use crate::error::ApiError;
+use crate::frame_diff::render_frame_diff;
+use crate::frame_image_cache::{render_frame_image, FrameImageFormat, FrameImageKey};
 use crate::services::frames_assembler::assemble_frame_response;
 use crate::services::web_contexts::StorageWebContext;
 use oneshim_api_contracts::common::{PaginatedResponse, PaginationMeta, TimeRangeQuery};
 
+/// Set on image responses that carry the placeholder for an unreadable frame.
+pub const FRAME_CORRUPT_HEADER: &str = "x-oneshim-frame-corrupt";
+
+const TRASH_LIST_LIMIT: usize = 500;
+
 #[derive(Clone)]
 pub struct FramesQueryService {
     ctx: StorageWebContext,
@@ -88,7 +102,15 @@ impl FramesQueryService {
         })
     }
 
-    pub async fn get_frame_image(&self, frame_id: i64) -> Response {
+    pub fn get_trashed_frames(&self) -> Result<Vec<FrameResponse>, ApiError> {
+        self.ctx
+            .storage
+            .get_trashed_frames(TRASH_LIST_LIMIT)
+            .map_err(ApiError::from)
+            .map(|frames| frames.into_iter().map(assemble_frame_response).collect())
+    }
+
+    pub async fn get_frame_image(&self, frame_id: i64, query: &FrameImageQuery) -> Response {
         let file_path = match self.ctx.storage.get_frame_file_path(frame_id) {
             Ok(Some(path)) => path,
             Ok(None) => {
@@ -103,30 +125,212 @@ impl FramesQueryService {
             Err(error) => return error.into_response(),
         };
 
-        let data = if let Some(ref frame_storage) = self.ctx.frame_storage {
-            match frame_storage.load_frame(Path::new(&file_path)).await {
-                Ok(bytes) => bytes,
-                Err(error) => {
-                    return ApiError::Internal(format!("frame load failure: {error}"))
+        if query.is_original() {
+            let data = match self
+                .load_frame_bytes(frame_id, &file_path, &full_path)
+                .await
+            {
+                Ok(Some(bytes)) => bytes,
+                Ok(None) => return corrupt_frame_response(),
+                Err(error) => return error.into_response(),
+            };
+            let content_type = mime_guess::from_path(&full_path)
+                .first_or_octet_stream()
+                .to_string();
+            return (StatusCode::OK, [(header::CONTENT_TYPE, content_type)], data).into_response();
+        }
+
+        let format = match query.format.as_deref() {
+            None => FrameImageFormat::Webp,
+            Some(value) => match FrameImageFormat::parse(value) {
+                Some(format) => format,
+                None => {
+                    return ApiError::BadRequest(format!("unsupported image format: {value}"))
                         .into_response();
                 }
+            },
+        };
+        let key = FrameImageKey {
+            frame_id,
+            width: query.width.unwrap_or(0),
+            height: query.height.unwrap_or(0),
+            format,
+        };
+
+        let cache = &self.ctx.frame_image_cache;
+        let data = match cache.get(&key) {
+            Some(cached) => cached,
+            None => {
+                let source = match self
+                    .load_frame_bytes(frame_id, &file_path, &full_path)
+                    .await
+                {
+                    Ok(Some(bytes)) => bytes,
+                    Ok(None) => return corrupt_frame_response(),
+                    Err(error) => return error.into_response(),
+                };
+                let rendered =
+                    tokio::task::spawn_blocking(move || render_frame_image(&source, &key)).await;
+                match rendered {
+                    Ok(Ok(bytes)) => cache.insert(key, bytes),
+                    Ok(Err(error)) => {
+                        return ApiError::Internal(format!("frame resize failure: {error}"))
+                            .into_response();
+                    }
+                    Err(error) => {
+                        return ApiError::Internal(format!("frame resize task failed: {error}"))
+                            .into_response();
+                    }
+                }
             }
-        } else {
-            match std::fs::read(&full_path) {
-                Ok(bytes) => bytes,
+        };
+
+        (
+            StatusCode::OK,
+            [(header::CONTENT_TYPE, format.content_type())],
+            data,
+        )
+            .into_response()
+    }
+
+    pub async fn get_frame_diff(
+        &self,
+        query: &FrameDiffQuery,
+    ) -> Result<FrameDiffResponse, ApiError> {
+        let before = self.load_frame_image(query.from).await?;
+        let after = self.load_frame_image(query.to).await?;
+        let diff = tokio::task::spawn_blocking(move || render_frame_diff(&before, &after))
+            .await
+            .map_err(|error| ApiError::Internal(format!("frame diff task failed: {error}")))?
+            .map_err(|error| ApiError::Internal(format!("frame diff failure: {error}")))?;
+
+        Ok(FrameDiffResponse {
+            from_frame_id: query.from,
+            to_frame_id: query.to,
+            changed_pixel_ratio: diff.changed_pixel_ratio,
+            changed_tiles: diff.changed_tiles,
+            total_tiles: diff.total_tiles,
+            changed_region: diff.changed_region.map(|region| FrameRegionDto {
+                x: region.x,
+                y: region.y,
+                width: region.w,
+                height: region.h,
+            }),
+            diff_image_png: BASE64.encode(diff.image_png),
+        })
+    }
+
+    /// Stored (decrypted) image bytes of `frame_id`.
+    async fn load_frame_image(&self, frame_id: i64) -> Result<Vec<u8>, ApiError> {
+        let file_path = self
+            .ctx
+            .storage
+            .get_frame_file_path(frame_id)?
+            .ok_or_else(|| ApiError::NotFound(format!("frame {frame_id} has no image")))?;
+        let full_path = resolve_frame_image_path(self.ctx.frames_dir.as_deref(), &file_path)?;
+        self.load_frame_bytes(frame_id, &file_path, &full_path)
+            .await?
+            .ok_or_else(|| ApiError::NotFound(format!("frame {frame_id} image is corrupt")))
+    }
+
+    /// Read the image file of `frame_id`. `Ok(None)` means the file is
+    /// truncated or undecryptable; the frame has been flagged so retention
+    /// removes the file.
+    async fn load_frame_bytes(
+        &self,
+        frame_id: i64,
+        file_path: &str,
+        full_path: &Path,
+    ) -> Result<Option<Vec<u8>>, ApiError> {
+        let loaded = if let Some(ref frame_storage) = self.ctx.frame_storage {
+            match frame_storage.load_frame(Path::new(file_path)).await {
+                Ok(bytes) => Ok(bytes),
+                Err(CoreError::Validation { field, message, .. })
+                    if field == CORRUPT_FRAME_FIELD =>
+                {
+                    Err(message)
+                }
                 Err(error) => {
-                    return ApiError::Internal(format!("file read failure: {error}"))
-                        .into_response();
+                    return Err(ApiError::Internal(format!("frame load failure: {error}")));
                 }
             }
+        } else {
+            let bytes = std::fs::read(full_path)
+                .map_err(|error| ApiError::Internal(format!("file read failure: {error}")))?;
+            if is_truncated_image(&bytes) {
+                Err(format!("{file_path}: truncated ({} bytes)", bytes.len()))
+            } else {
+                Ok(bytes)
+            }
         };
 
-        let content_type = mime_guess::from_path(&full_path)
-            .first_or_octet_stream()
-            .to_string();
+        match loaded {
+            Ok(bytes) => Ok(Some(bytes)),
+            Err(reason) => {
+                warn!(
+                    frame_id,
+                    "serving placeholder for corrupt frame file: {reason}"
+                );
+                if let Err(error) = self.ctx.storage.mark_frame_file_corrupt(frame_id) {
+                    warn!(frame_id, "failed to flag corrupt frame file: {error}");
+                }
+                Ok(None)
+            }
+        }
+    }
+}
 
-        (StatusCode::OK, [(header::CONTENT_TYPE, content_type)], data).into_response()
+#[derive(Clone)]
+pub struct FramesCommandService {
+    ctx: StorageWebContext,
+}
+
+impl FramesCommandService {
+    pub fn new(ctx: StorageWebContext) -> Self {
+        Self { ctx }
+    }
+
+    /// Moves the frame to the trash; it can be restored until frame
+    /// retention purges it.
+    pub fn delete_frame(&self, frame_id: i64) -> Result<serde_json::Value, ApiError> {
+        if !self.ctx.storage.soft_delete_frame(frame_id)? {
+            return Err(ApiError::NotFound(format!("Frame ID: {frame_id}")));
+        }
+        Ok(serde_json::json!({ "message": "Frame moved to trash." }))
     }
+
+    pub fn restore_frame(&self, frame_id: i64) -> Result<serde_json::Value, ApiError> {
+        if !self.ctx.storage.restore_frame(frame_id)? {
+            return Err(ApiError::NotFound(format!("Trashed frame ID: {frame_id}")));
+        }
+        Ok(serde_json::json!({ "message": "Frame restored." }))
+    }
+}
+
+/// Stand-in for a frame file that cannot be served, so the timeline keeps
+/// rendering.
+fn corrupt_frame_response() -> Response {
+    (
+        StatusCode::OK,
+        [
+            (header::CONTENT_TYPE.as_str(), "image/png"),
+            (header::CACHE_CONTROL.as_str(), "no-store"),
+            (FRAME_CORRUPT_HEADER, "1"),
+        ],
+        placeholder_png(),
+    )
+        .into_response()
+}
+
+fn placeholder_png() -> &'static [u8] {
+    static PLACEHOLDER: OnceLock<Vec<u8>> = OnceLock::new();
+    PLACEHOLDER.get_or_init(|| {
+        let image = image::RgbImage::from_pixel(160, 90, image::Rgb([0x9c, 0xa3, 0xaf]));
+        let mut png = std::io::Cursor::new(Vec::new());
+        // In-memory PNG encoding of a solid image does not fail in practice.
+        let _ = image.write_to(&mut png, image::ImageFormat::Png);
+        png.into_inner()
+    })
 }
 
 fn resolve_frame_image_path(
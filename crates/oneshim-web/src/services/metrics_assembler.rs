@@ -1,5 +1,6 @@
-use oneshim_api_contracts::metrics::{HourlyMetricsResponse, MetricsResponse};
-use oneshim_core::models::storage_records::HourlyMetricsRecord;
+use oneshim_api_contracts::metrics::{HourlyMetricsResponse, MetricsGranularity, MetricsResponse};
+use oneshim_core::models::capture_latency::StageLatencySummary;
+use oneshim_core::models::storage_records::{DailyMetricsRecord, HourlyMetricsRecord};
 use oneshim_core::models::system::SystemMetrics;
 
 pub(crate) fn memory_percent(metric: &SystemMetrics) -> f64 {
@@ -38,6 +39,7 @@ pub(crate) fn assemble_metrics_response(metric: SystemMetrics) -> MetricsRespons
 pub(crate) fn assemble_hourly_metrics_response(row: HourlyMetricsRecord) -> HourlyMetricsResponse {
     HourlyMetricsResponse {
         hour: row.hour,
+        granularity: MetricsGranularity::Hour,
         cpu_avg: row.cpu_avg,
         cpu_max: row.cpu_max,
         memory_avg: row.memory_avg,
@@ -45,3 +47,53 @@ pub(crate) fn assemble_hourly_metrics_response(row: HourlyMetricsRecord) -> Hour
         sample_count: row.sample_count,
     }
 }
+
+pub(crate) fn assemble_daily_metrics_response(row: DailyMetricsRecord) -> HourlyMetricsResponse {
+    HourlyMetricsResponse {
+        hour: format!("{}T00:00:00Z", row.date),
+        granularity: MetricsGranularity::Day,
+        cpu_avg: row.cpu_avg,
+        cpu_max: row.cpu_max,
+        memory_avg: row.memory_avg,
+        memory_max: row.memory_max,
+        sample_count: row.sample_count,
+    }
+}
+
+/// Prometheus text exposition of per-stage capture latency, in seconds.
+pub(crate) fn render_capture_latency_exposition(summaries: &[StageLatencySummary]) -> String {
+    use std::fmt::Write;
+
+    let mut out = String::new();
+    out.push_str(
+        "# HELP oneshim_capture_stage_latency_seconds Frame pipeline stage latency over the recent sample window.\n\
+         # TYPE oneshim_capture_stage_latency_seconds gauge\n",
+    );
+    for summary in summaries {
+        let stage = summary.stage.as_str();
+        for (quantile, ms) in [
+            ("0.5", summary.p50_ms),
+            ("0.95", summary.p95_ms),
+            ("1", summary.max_ms),
+        ] {
+            let _ = writeln!(
+                out,
+                "oneshim_capture_stage_latency_seconds{{stage=\"{stage}\",quantile=\"{quantile}\"}} {}",
+                ms / 1000.0
+            );
+        }
+    }
+    out.push_str(
+        "# HELP oneshim_capture_stage_samples_total Stage durations recorded since start.\n\
+         # TYPE oneshim_capture_stage_samples_total counter\n",
+    );
+    for summary in summaries {
+        let _ = writeln!(
+            out,
+            "oneshim_capture_stage_samples_total{{stage=\"{}\"}} {}",
+            summary.stage.as_str(),
+            summary.total_samples
+        );
+    }
+    out
+}
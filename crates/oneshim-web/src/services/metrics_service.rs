@@ -3,11 +3,15 @@ use oneshim_api_contracts::metrics::{HourlyMetricsResponse, HourlyQuery, Metrics
 
 use crate::error::ApiError;
 use crate::services::metrics_assembler::{
-    assemble_hourly_metrics_response, assemble_metrics_response,
+    assemble_daily_metrics_response, assemble_hourly_metrics_response, assemble_metrics_response,
 };
 use crate::services::web_contexts::StorageWebContext;
 use oneshim_api_contracts::common::TimeRangeQuery;
 
+/// Spans longer than this are served from `daily_metrics` instead of
+/// `system_metrics_hourly`.
+const DAILY_GRANULARITY_THRESHOLD_HOURS: usize = 72;
+
 #[derive(Clone)]
 pub struct MetricsQueryService {
     ctx: StorageWebContext,
@@ -42,6 +46,22 @@ impl MetricsQueryService {
     ) -> Result<Vec<HourlyMetricsResponse>, ApiError> {
         let hours = params.hours.unwrap_or(24);
         let now = Utc::now();
+        if hours > DAILY_GRANULARITY_THRESHOLD_HOURS {
+            let from = (now - Duration::hours(hours as i64))
+                .format("%Y-%m-%d")
+                .to_string();
+            return self
+                .ctx
+                .storage
+                .list_daily_metrics_since(&from)
+                .map_err(|error| ApiError::Internal(error.to_string()))
+                .map(|rows| {
+                    rows.into_iter()
+                        .map(assemble_daily_metrics_response)
+                        .collect()
+                });
+        }
+
         let from = (now - Duration::hours(hours as i64))
             .format("%Y-%m-%dT%H:00:00Z")
             .to_string();
@@ -50,6 +50,7 @@ pub mod settings_service;
 pub(crate) mod settings_update_flow;
 pub(crate) mod settings_validation;
 pub mod settings_web_service;
+pub mod share_links_service;
 pub(crate) mod stats_assembler;
 pub(crate) mod stats_query_support;
 pub mod stats_service;
@@ -1,5 +1,5 @@
 use chrono::Utc;
-use oneshim_api_contracts::reports::{ReportQuery, ReportResponse};
+use oneshim_api_contracts::reports::{ReportPeriod, ReportQuery, ReportResponse};
 
 use crate::error::ApiError;
 use crate::services::reports_assembler::{
@@ -94,4 +94,21 @@ impl ReportQueryService {
             productivity,
         }))
     }
+
+    /// Precomputed single-day summary for server pull. A day with no events,
+    /// captures or idle time is `NotFound` rather than an all-zero report.
+    pub async fn daily_summary(&self, date: &str) -> Result<ReportResponse, ApiError> {
+        let query = ReportQuery {
+            period: ReportPeriod::Custom,
+            from: Some(date.to_string()),
+            to: Some(date.to_string()),
+        };
+        let report = self.generate_report(&query).await?;
+        if report.total_events == 0 && report.total_captures == 0 && report.total_idle_secs == 0 {
+            return Err(ApiError::NotFound(format!(
+                "no activity recorded on {date}"
+            )));
+        }
+        Ok(report)
+    }
 }
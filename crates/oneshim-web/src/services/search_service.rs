@@ -137,8 +137,10 @@ pub(crate) fn build_frame_queries(
         .collect::<Vec<_>>()
         .join(",");
 
+    // `decompress_text` is registered by the SQLite adapter; OCR text may be
+    // stored zstd-compressed.
     let text_condition = if has_text {
-        "(app_name LIKE ?1 OR window_title LIKE ?1 OR ocr_text LIKE ?1)"
+        "(app_name LIKE ?1 OR window_title LIKE ?1 OR decompress_text(ocr_text) LIKE ?1)"
     } else {
         "1=1"
     };
@@ -159,7 +161,10 @@ pub(crate) fn build_frame_queries(
         "1=1".to_string()
     };
 
-    let where_clause = format!("{} AND {}", text_condition, tag_condition);
+    let where_clause = format!(
+        "deleted_at IS NULL AND {} AND {}",
+        text_condition, tag_condition
+    );
 
     let count_sql = format!("SELECT COUNT(*) FROM frames WHERE {}", where_clause);
 
@@ -62,6 +62,10 @@ fn apply_general_settings(config: &mut AppConfig, settings: &AppSettings) -> Res
     config.privacy.excluded_title_patterns = settings.privacy.excluded_title_patterns.clone();
     config.privacy.auto_exclude_sensitive = settings.privacy.auto_exclude_sensitive;
     config.privacy.pii_filter_level = parse_pii_filter_level(&settings.privacy.pii_filter_level)?;
+    config
+        .privacy
+        .validate_bounds()
+        .map_err(ApiError::BadRequest)?;
     config.schedule.active_hours_enabled = settings.schedule.active_hours_enabled;
     config.schedule.active_start_hour = settings.schedule.active_start_hour;
     config.schedule.active_end_hour = settings.schedule.active_end_hour;
@@ -1,4 +1,7 @@
-use oneshim_api_contracts::settings::{AppSettings, StorageStats};
+use oneshim_api_contracts::settings::{
+    AppSettings, CategoryStorageUsage, ForeignKeyViolation, StorageIntegrityReport,
+    StorageReportResponse, StorageStats, TableRowCount,
+};
 
 use crate::error::ApiError;
 use crate::services::settings_assembler::config_to_settings;
@@ -49,6 +52,72 @@ impl SettingsQueryService {
             newest_data_date: stats.newest_data_date,
         })
     }
+
+    pub async fn get_storage_report(&self) -> Result<StorageReportResponse, ApiError> {
+        let frames_dir_bytes = match (&self.ctx.frame_storage, self.ctx.frames_dir.as_deref()) {
+            (Some(frame_storage), _) => frame_storage
+                .total_size_bytes()
+                .await
+                .map_err(|error| ApiError::Internal(error.to_string()))?,
+            (None, Some(frames_dir)) => calculate_dir_size(&frames_dir.join("frames")),
+            (None, None) => 0,
+        };
+        let report = self
+            .ctx
+            .storage
+            .storage_report(self.ctx.frames_dir.as_deref(), frames_dir_bytes)
+            .map_err(|error| ApiError::Internal(error.to_string()))?;
+
+        Ok(StorageReportResponse {
+            db_size_bytes: report.db_size_bytes,
+            frames_dir_bytes: report.frames_dir_bytes,
+            total_size_bytes: report.db_size_bytes + report.frames_dir_bytes,
+            tables: report
+                .tables
+                .into_iter()
+                .map(|table| TableRowCount {
+                    table: table.table,
+                    rows: table.rows,
+                })
+                .collect(),
+            app_categories: report
+                .app_categories
+                .into_iter()
+                .map(|usage| CategoryStorageUsage {
+                    category: usage.category,
+                    frame_count: usage.frame_count,
+                    bytes: usage.bytes,
+                })
+                .collect(),
+        })
+    }
+
+    pub fn check_storage_integrity(&self) -> Result<StorageIntegrityReport, ApiError> {
+        let record = self
+            .ctx
+            .storage
+            .check_integrity()
+            .map_err(|error| ApiError::Internal(error.to_string()))?;
+
+        Ok(StorageIntegrityReport {
+            healthy: record.is_healthy(),
+            recovery_steps: record
+                .recovery_steps()
+                .into_iter()
+                .map(str::to_string)
+                .collect(),
+            integrity_errors: record.integrity_errors,
+            foreign_key_violations: record
+                .foreign_key_violations
+                .into_iter()
+                .map(|violation| ForeignKeyViolation {
+                    table: violation.table,
+                    rowid: violation.rowid,
+                    parent: violation.parent,
+                })
+                .collect(),
+        })
+    }
 }
 
 fn calculate_dir_size(path: &std::path::Path) -> u64 {
@@ -0,0 +1,138 @@
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use oneshim_api_contracts::reports::{ReportPeriod, ReportQuery};
+use oneshim_api_contracts::share_links::{
+    CreateShareLinkRequest, ShareLinkResponse, SharedReportQuery,
+};
+use oneshim_core::config_manager::ConfigManager;
+
+use crate::error::ApiError;
+use crate::services::web_contexts::ConfigWebContext;
+use crate::share_link::{self, ShareLinkClaims};
+
+const DEFAULT_TTL_HOURS: u32 = 72;
+const MAX_TTL_HOURS: u32 = 24 * 30;
+
+#[derive(Clone)]
+pub struct ShareLinkService {
+    ctx: ConfigWebContext,
+}
+
+impl ShareLinkService {
+    pub fn new(ctx: ConfigWebContext) -> Self {
+        Self { ctx }
+    }
+
+    /// Issue a link for `request`'s range, generating the signing secret on
+    /// first use.
+    pub fn create(
+        &self,
+        request: &CreateShareLinkRequest,
+        now: DateTime<Utc>,
+    ) -> Result<ShareLinkResponse, ApiError> {
+        let manager = self.config_manager()?;
+        let from = parse_date("from", &request.from)?;
+        let to = match request.to.as_deref() {
+            Some(to) => parse_date("to", to)?,
+            None => from,
+        };
+        if to < from {
+            return Err(ApiError::BadRequest(
+                "to date must not be before from date".to_string(),
+            ));
+        }
+        let ttl_hours = request.ttl_hours.unwrap_or(DEFAULT_TTL_HOURS);
+        if !(1..=MAX_TTL_HOURS).contains(&ttl_hours) {
+            return Err(ApiError::BadRequest(format!(
+                "ttl_hours must be between 1 and {MAX_TTL_HOURS}"
+            )));
+        }
+
+        let secret = match manager.get().web.share_link_secret {
+            Some(secret) if !secret.is_empty() => secret,
+            _ => manager
+                .update_with(|config| {
+                    config
+                        .web
+                        .share_link_secret
+                        .get_or_insert_with(|| hex::encode(rand::random::<[u8; 32]>()));
+                    Ok(())
+                })?
+                .web
+                .share_link_secret
+                .unwrap_or_default(),
+        };
+
+        let expires_at = now + Duration::hours(i64::from(ttl_hours));
+        let claims = ShareLinkClaims {
+            id: uuid::Uuid::new_v4().simple().to_string(),
+            from: from.format("%Y-%m-%d").to_string(),
+            to: to.format("%Y-%m-%d").to_string(),
+            exp: expires_at.timestamp(),
+        };
+        let token = share_link::sign(secret.as_bytes(), &claims);
+        Ok(ShareLinkResponse {
+            path: format!("/share/v1/report?token={token}"),
+            id: claims.id,
+            token,
+            from: claims.from,
+            to: claims.to,
+            expires_at: expires_at.to_rfc3339(),
+        })
+    }
+
+    /// Revoke link `id`. Tokens are stateless, so unknown ids are recorded too.
+    pub fn revoke(&self, id: &str) -> Result<(), ApiError> {
+        let id = id.trim();
+        if id.is_empty() {
+            return Err(ApiError::BadRequest(
+                "share link id is required".to_string(),
+            ));
+        }
+        self.config_manager()?.update_with(|config| {
+            let revoked = &mut config.web.revoked_share_links;
+            if !revoked.iter().any(|existing| existing == id) {
+                revoked.push(id.to_string());
+            }
+            Ok(())
+        })?;
+        Ok(())
+    }
+
+    fn config_manager(&self) -> Result<&ConfigManager, ApiError> {
+        self.ctx.config_manager.as_ref().ok_or_else(|| {
+            ApiError::ServiceUnavailable(
+                "Share links are unavailable because config management is not initialized."
+                    .to_string(),
+            )
+        })
+    }
+}
+
+/// The report query a share link grants. A requested range other than the
+/// link's own is refused rather than clamped.
+pub(crate) fn scoped_report_query(
+    claims: &ShareLinkClaims,
+    params: &SharedReportQuery,
+) -> Result<ReportQuery, ApiError> {
+    let from_matches = params
+        .from
+        .as_ref()
+        .map_or(true, |from| *from == claims.from);
+    let to_matches = params.to.as_ref().map_or(true, |to| *to == claims.to);
+    if !(from_matches && to_matches) {
+        return Err(ApiError::Forbidden(format!(
+            "This link only shares the report for {} to {}.",
+            claims.from, claims.to
+        )));
+    }
+    Ok(ReportQuery {
+        period: ReportPeriod::Custom,
+        from: Some(claims.from.clone()),
+        to: Some(claims.to.clone()),
+    })
+}
+
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate, ApiError> {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .map_err(|_| ApiError::BadRequest(format!("Invalid {field} date: {value}")))
+}
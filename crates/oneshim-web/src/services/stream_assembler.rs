@@ -25,6 +25,8 @@ pub(crate) fn event_type_name(event: &RealtimeEvent) -> &'static str {
         RealtimeEvent::Frame(_) => "frame",
         RealtimeEvent::Idle(_) => "idle",
         RealtimeEvent::AiRuntimeStatus(_) => "ai_runtime_status",
+        RealtimeEvent::Connectivity(_) => "connectivity",
+        RealtimeEvent::DiskSpace(_) => "disk_space",
         RealtimeEvent::Ping => "ping",
     }
 }
@@ -57,6 +59,7 @@ mod tests {
             window_title: "main.rs".to_string(),
             importance: 0.85,
             trigger_type: "timer".to_string(),
+            preview: None,
         });
         let json = serde_json::to_string(&event).expect("frame event should serialize");
         assert!(json.contains("\"type\":\"frame\""));
@@ -3,6 +3,7 @@ use oneshim_api_contracts::automation::AuditEntryDto;
 use oneshim_api_contracts::settings::{AppSettings, StorageStats};
 use oneshim_api_contracts::support::{DiagnosticsBundleDto, DiagnosticsHealthDto};
 use oneshim_core::models::audit::AuditEntry;
+use oneshim_core::models::capture_latency::StageLatencySummary;
 
 const SUPPORT_DIAGNOSTICS_SCHEMA_VERSION: &str = "support.diagnostics.v1";
 const SUPPORT_AUDIT_SCHEMA_VERSION: &str = "automation.audit.v1";
@@ -30,6 +31,7 @@ pub(crate) fn assemble_diagnostics_health(input: DiagnosticsHealthInput) -> Diag
 }
 
 pub(crate) fn to_audit_entry_dto(entry: AuditEntry) -> AuditEntryDto {
+    let outcome = entry.outcome().map(|outcome| outcome.as_str().to_string());
     AuditEntryDto {
         schema_version: SUPPORT_AUDIT_SCHEMA_VERSION.to_string(),
         entry_id: entry.entry_id,
@@ -40,6 +42,7 @@ pub(crate) fn to_audit_entry_dto(entry: AuditEntry) -> AuditEntryDto {
         status: format!("{:?}", entry.status),
         details: entry.details,
         elapsed_ms: entry.execution_time_ms,
+        outcome,
     }
 }
 
@@ -49,6 +52,7 @@ pub(crate) fn assemble_diagnostics_bundle(
     storage_stats: Option<StorageStats>,
     recent_audit_entries: Vec<AuditEntryDto>,
     recent_policy_events: Vec<AuditEntryDto>,
+    capture_latency: Vec<StageLatencySummary>,
 ) -> DiagnosticsBundleDto {
     DiagnosticsBundleDto {
         schema_version: SUPPORT_DIAGNOSTICS_SCHEMA_VERSION.to_string(),
@@ -58,5 +62,6 @@ pub(crate) fn assemble_diagnostics_bundle(
         storage_stats,
         recent_audit_entries,
         recent_policy_events,
+        capture_latency,
     }
 }
@@ -67,6 +67,11 @@ impl SupportDiagnosticsQueryService {
             storage_stats,
             recent_audit_entries,
             recent_policy_events,
+            self.ctx
+                .capture_latency
+                .as_ref()
+                .map(|recorder| recorder.summaries())
+                .unwrap_or_default(),
         )
     }
 }
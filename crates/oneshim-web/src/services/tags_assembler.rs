@@ -1,5 +1,5 @@
-use oneshim_api_contracts::tags::TagResponse;
-use oneshim_core::models::storage_records::TagRecord;
+use oneshim_api_contracts::tags::{TagResponse, TagRuleResponse};
+use oneshim_core::models::storage_records::{TagRecord, TagRuleRecord};
 
 pub(crate) fn assemble_tag_response(tag: TagRecord) -> TagResponse {
     TagResponse {
@@ -7,5 +7,14 @@ pub(crate) fn assemble_tag_response(tag: TagRecord) -> TagResponse {
         name: tag.name,
         color: tag.color,
         created_at: tag.created_at,
+        auto_applied: tag.auto_applied,
+    }
+}
+
+pub(crate) fn assemble_tag_rule_response(rule: TagRuleRecord) -> TagRuleResponse {
+    TagRuleResponse {
+        id: rule.id,
+        app_pattern: rule.app_pattern,
+        tag_id: rule.tag_id,
     }
 }
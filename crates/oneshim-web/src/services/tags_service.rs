@@ -1,7 +1,10 @@
-use oneshim_api_contracts::tags::{CreateTagRequest, TagResponse, UpdateTagRequest};
+use oneshim_api_contracts::tags::{
+    CreateTagRequest, TagResponse, TagRuleRequest, TagRuleResponse, UpdateTagRequest,
+};
+use oneshim_core::models::storage_records::TagRecord;
 
 use crate::error::ApiError;
-use crate::services::tags_assembler::assemble_tag_response;
+use crate::services::tags_assembler::{assemble_tag_response, assemble_tag_rule_response};
 use crate::services::web_contexts::StorageWebContext;
 
 #[derive(Clone)]
@@ -39,6 +42,14 @@ impl TagsQueryService {
             .map_err(ApiError::from)
             .map(|tags| tags.into_iter().map(assemble_tag_response).collect())
     }
+
+    pub fn list_tag_rules(&self) -> Result<Vec<TagRuleResponse>, ApiError> {
+        self.ctx
+            .storage
+            .get_tag_rules()
+            .map_err(ApiError::from)
+            .map(|rules| rules.into_iter().map(assemble_tag_rule_response).collect())
+    }
 }
 
 #[derive(Clone)]
@@ -57,8 +68,15 @@ impl TagsCommandService {
             .clone()
             .unwrap_or_else(|| "#3b82f6".to_string());
 
-        let tag = self.ctx.storage.create_tag(&request.name, &color)?;
-        Ok(assemble_tag_response(tag))
+        match self.ctx.storage.create_tag(&request.name, &color) {
+            Ok(tag) => Ok(assemble_tag_response(tag)),
+            // UNIQUE(name): another tab may have created it first, so hand
+            // back the existing tag instead of surfacing the constraint.
+            Err(e) => match self.find_tag_by_name(&request.name)? {
+                Some(existing) => Ok(assemble_tag_response(existing)),
+                None => Err(e.into()),
+            },
+        }
     }
 
     pub fn update_tag(
@@ -66,10 +84,21 @@ impl TagsCommandService {
         tag_id: i64,
         request: &UpdateTagRequest,
     ) -> Result<TagResponse, ApiError> {
-        let updated = self
+        let updated = match self
             .ctx
             .storage
-            .update_tag(tag_id, &request.name, &request.color)?;
+            .update_tag(tag_id, &request.name, &request.color)
+        {
+            Ok(updated) => updated,
+            Err(e) => {
+                return Err(match self.find_tag_by_name(&request.name)? {
+                    Some(other) if other.id != tag_id => {
+                        ApiError::Conflict(format!("Tag name already exists: {}", request.name))
+                    }
+                    _ => e.into(),
+                });
+            }
+        };
 
         if !updated {
             return Err(ApiError::NotFound(format!("Tag ID: {tag_id}")));
@@ -88,6 +117,7 @@ impl TagsCommandService {
         Ok(serde_json::json!({ "message": "Tag deleted." }))
     }
 
+    /// Already-attached tags are a no-op (`INSERT OR IGNORE`).
     pub fn add_tag_to_frame(
         &self,
         frame_id: i64,
@@ -97,6 +127,7 @@ impl TagsCommandService {
         Ok(serde_json::json!({ "message": "Tag added to frame." }))
     }
 
+    /// Idempotent: removing a tag that is not attached still succeeds.
     pub fn remove_tag_from_frame(
         &self,
         frame_id: i64,
@@ -104,12 +135,58 @@ impl TagsCommandService {
     ) -> Result<serde_json::Value, ApiError> {
         let removed = self.ctx.storage.remove_tag_from_frame(frame_id, tag_id)?;
 
-        if !removed {
-            return Err(ApiError::NotFound(format!(
-                "Tag {tag_id} is not attached to frame {frame_id}."
-            )));
+        Ok(serde_json::json!({
+            "message": "Tag removed from frame.",
+            "removed": removed,
+        }))
+    }
+
+    /// Rules only affect frames captured after they are created.
+    pub fn create_tag_rule(&self, request: &TagRuleRequest) -> Result<TagRuleResponse, ApiError> {
+        let rule = self
+            .ctx
+            .storage
+            .create_tag_rule(&request.app_pattern, request.tag_id)?;
+        Ok(assemble_tag_rule_response(rule))
+    }
+
+    pub fn update_tag_rule(
+        &self,
+        rule_id: i64,
+        request: &TagRuleRequest,
+    ) -> Result<TagRuleResponse, ApiError> {
+        let updated =
+            self.ctx
+                .storage
+                .update_tag_rule(rule_id, &request.app_pattern, request.tag_id)?;
+
+        if !updated {
+            return Err(ApiError::NotFound(format!("Tag rule ID: {rule_id}")));
         }
 
-        Ok(serde_json::json!({ "message": "Tag removed from frame." }))
+        Ok(TagRuleResponse {
+            id: rule_id,
+            app_pattern: request.app_pattern.trim().to_string(),
+            tag_id: request.tag_id,
+        })
+    }
+
+    pub fn delete_tag_rule(&self, rule_id: i64) -> Result<serde_json::Value, ApiError> {
+        let deleted = self.ctx.storage.delete_tag_rule(rule_id)?;
+
+        if !deleted {
+            return Err(ApiError::NotFound(format!("Tag rule ID: {rule_id}")));
+        }
+
+        Ok(serde_json::json!({ "message": "Tag rule deleted." }))
+    }
+
+    fn find_tag_by_name(&self, name: &str) -> Result<Option<TagRecord>, ApiError> {
+        Ok(self
+            .ctx
+            .storage
+            .get_all_tags()?
+            .into_iter()
+            .find(|tag| tag.name == name))
     }
 }
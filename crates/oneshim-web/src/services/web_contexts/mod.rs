@@ -6,6 +6,7 @@ use oneshim_api_contracts::bug_report::BugReportBundleDto;
 use oneshim_api_contracts::stream::RealtimeEvent;
 use oneshim_core::config::CredentialBackendKind;
 use oneshim_core::config_manager::ConfigManager;
+use oneshim_core::models::capture_latency::CaptureLatencyRecorder;
 use oneshim_core::ports::audit_log::AuditLogPort;
 use oneshim_core::ports::automation::AutomationPort;
 use oneshim_core::ports::frame_storage::FrameStoragePort;
@@ -16,6 +17,7 @@ use oneshim_core::ports::system_info_provider::SystemInfoProvider;
 
 use oneshim_core::ports::conversation_session::SessionManager;
 
+use crate::frame_image_cache::FrameImageCache;
 use crate::services::integration_assembler::IntegrationStatusConfigSnapshot;
 use crate::storage_port::WebStorage;
 use crate::update_control::UpdateControl;
@@ -31,6 +33,7 @@ pub struct StorageWebContext {
     /// sanitization. Cloned from AppState.diagnostics.pii_sanitizer at
     /// context construction time.
     pub pii_sanitizer: Option<Arc<dyn PiiSanitizer>>,
+    pub frame_image_cache: Arc<FrameImageCache>,
 }
 
 impl StorageWebContext {
@@ -40,6 +43,7 @@ impl StorageWebContext {
             frames_dir: state.core.frames_dir.clone(),
             frame_storage: state.core.frame_storage.clone(),
             pii_sanitizer: state.diagnostics.pii_sanitizer.clone(),
+            frame_image_cache: state.core.frame_image_cache.clone(),
         }
     }
 }
@@ -113,6 +117,7 @@ impl FromRef<AppState> for BackupWebContext {
 pub struct SettingsWebContext {
     pub(crate) storage: Arc<dyn WebStorage>,
     pub(crate) frames_dir: Option<PathBuf>,
+    pub(crate) frame_storage: Option<Arc<dyn FrameStoragePort>>,
     pub(crate) config_manager: Option<ConfigManager>,
     pub(crate) default_secret_backend_kind: CredentialBackendKind,
     pub(crate) secret_store: Option<Arc<dyn SecretStore>>,
@@ -125,6 +130,7 @@ impl SettingsWebContext {
         Self {
             storage: state.core.storage.clone(),
             frames_dir: state.core.frames_dir.clone(),
+            frame_storage: state.core.frame_storage.clone(),
             config_manager: state.core.config_manager.clone(),
             default_secret_backend_kind: state.secrets.default_backend_kind,
             secret_store: state.secrets.store.clone(),
@@ -171,6 +177,7 @@ pub struct SupportDiagnosticsContext {
     pub automation_controller_configured: bool,
     pub update_control_configured: bool,
     pub audit_logger: Option<Arc<dyn AuditLogPort>>,
+    pub capture_latency: Option<Arc<CaptureLatencyRecorder>>,
 }
 
 impl SupportDiagnosticsContext {
@@ -182,6 +189,7 @@ impl SupportDiagnosticsContext {
             automation_controller_configured: state.automation.controller.is_some(),
             update_control_configured: state.core.update_control.is_some(),
             audit_logger: state.automation.audit_logger.clone(),
+            capture_latency: state.diagnostics.capture_latency.clone(),
         }
     }
 }
@@ -0,0 +1,157 @@
+//! Signed, expiring tokens that grant read-only access to one report range.
+//!
+//! Format: `v1.<base64url(claims json)>.<base64url(HMAC-SHA256)>`, keyed by
+//! `web.share_link_secret`. Revocation is by claim id against
+//! `web.revoked_share_links`.
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine as _;
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, KeyInit, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const TOKEN_VERSION: &str = "v1";
+
+/// What a share token grants.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ShareLinkClaims {
+    pub id: String,
+    /// Inclusive `%Y-%m-%d` range.
+    pub from: String,
+    pub to: String,
+    /// Unix seconds.
+    pub exp: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, thiserror::Error)]
+pub enum ShareLinkError {
+    #[error("share link is malformed")]
+    Malformed,
+    #[error("share link signature is invalid")]
+    BadSignature,
+    #[error("share link has expired")]
+    Expired,
+    #[error("share link has been revoked")]
+    Revoked,
+}
+
+pub fn sign(secret: &[u8], claims: &ShareLinkClaims) -> String {
+    let payload = URL_SAFE_NO_PAD
+        .encode(serde_json::to_vec(claims).expect("share link claims serialize to JSON"));
+    let signature = URL_SAFE_NO_PAD.encode(mac(secret, &payload).finalize().into_bytes());
+    format!("{TOKEN_VERSION}.{payload}.{signature}")
+}
+
+/// Check signature, expiry and revocation, in that order.
+pub fn verify(
+    secret: &[u8],
+    token: &str,
+    revoked: &[String],
+    now: DateTime<Utc>,
+) -> Result<ShareLinkClaims, ShareLinkError> {
+    let mut parts = token.trim().split('.');
+    let (Some(TOKEN_VERSION), Some(payload), Some(signature), None) =
+        (parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return Err(ShareLinkError::Malformed);
+    };
+    let signature = URL_SAFE_NO_PAD
+        .decode(signature)
+        .map_err(|_| ShareLinkError::Malformed)?;
+    // verify_slice compares in constant time.
+    mac(secret, payload)
+        .verify_slice(&signature)
+        .map_err(|_| ShareLinkError::BadSignature)?;
+
+    let claims: ShareLinkClaims = URL_SAFE_NO_PAD
+        .decode(payload)
+        .ok()
+        .and_then(|json| serde_json::from_slice(&json).ok())
+        .ok_or(ShareLinkError::Malformed)?;
+    if claims.exp <= now.timestamp() {
+        return Err(ShareLinkError::Expired);
+    }
+    if revoked.contains(&claims.id) {
+        return Err(ShareLinkError::Revoked);
+    }
+    Ok(claims)
+}
+
+fn mac(secret: &[u8], payload: &str) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(payload.as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    const SECRET: &[u8] = b"share-secret";
+
+    fn claims(now: DateTime<Utc>) -> ShareLinkClaims {
+        ShareLinkClaims {
+            id: "link-1".to_string(),
+            from: "2026-03-18".to_string(),
+            to: "2026-03-18".to_string(),
+            exp: (now + Duration::hours(1)).timestamp(),
+        }
+    }
+
+    #[test]
+    fn signed_token_round_trips() {
+        let now = Utc::now();
+        let token = sign(SECRET, &claims(now));
+        assert_eq!(verify(SECRET, &token, &[], now), Ok(claims(now)));
+    }
+
+    #[test]
+    fn tampered_or_foreign_tokens_are_rejected() {
+        let now = Utc::now();
+        let token = sign(SECRET, &claims(now));
+        assert_eq!(
+            verify(b"other-secret", &token, &[], now),
+            Err(ShareLinkError::BadSignature)
+        );
+
+        let mut widened = claims(now);
+        widened.to = "2026-12-31".to_string();
+        let forged_payload = sign(b"other-secret", &widened)
+            .split('.')
+            .nth(1)
+            .unwrap()
+            .to_string();
+        let signature = token.rsplit('.').next().unwrap();
+        assert_eq!(
+            verify(
+                SECRET,
+                &format!("v1.{forged_payload}.{signature}"),
+                &[],
+                now
+            ),
+            Err(ShareLinkError::BadSignature)
+        );
+        assert_eq!(
+            verify(SECRET, "v1.only-two", &[], now),
+            Err(ShareLinkError::Malformed)
+        );
+    }
+
+    #[test]
+    fn expired_and_revoked_tokens_are_rejected() {
+        let now = Utc::now();
+        let token = sign(SECRET, &claims(now));
+        assert_eq!(
+            verify(SECRET, &token, &[], now + Duration::hours(2)),
+            Err(ShareLinkError::Expired)
+        );
+        assert_eq!(
+            verify(SECRET, &token, &["link-1".to_string()], now),
+            Err(ShareLinkError::Revoked)
+        );
+    }
+}
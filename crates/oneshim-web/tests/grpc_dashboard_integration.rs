@@ -987,6 +987,7 @@ mod subscribe_events_tests {
                 window_title: "w1".to_string(),
                 importance: 0.75,
                 trigger_type: "timer".to_string(),
+                preview: None,
             }));
             tokio::time::sleep(Duration::from_millis(50)).await;
         }
@@ -1112,6 +1113,7 @@ mod subscribe_events_tests {
                 window_title: "t".to_string(),
                 importance: 0.5,
                 trigger_type: "timer".to_string(),
+                preview: None,
             }));
             tokio::time::sleep(Duration::from_millis(30)).await;
             let _ = event_tx.send(RealtimeEvent::Idle(IdleUpdate {
@@ -1194,6 +1196,7 @@ mod subscribe_events_tests {
                 window_title: "t".to_string(),
                 importance: 0.5,
                 trigger_type: "timer".to_string(),
+                preview: None,
             }));
         }
 
@@ -1294,6 +1297,7 @@ mod subscribe_events_tests {
                 window_title: "t".to_string(),
                 importance: 0.5,
                 trigger_type: "timer".to_string(),
+                preview: None,
             }));
         }
 
@@ -1387,6 +1391,7 @@ mod subscribe_events_tests {
             window_title: "t".to_string(),
             importance: 0.5,
             trigger_type: "timer".to_string(),
+            preview: None,
         }));
 
         // Wait up to 4s for a Hint payload.
@@ -1467,6 +1472,7 @@ mod subscribe_events_tests {
                 window_title: "all3".to_string(),
                 importance: 0.9,
                 trigger_type: "user_action".to_string(),
+                preview: None,
             }));
             tokio::time::sleep(Duration::from_millis(40)).await;
         }
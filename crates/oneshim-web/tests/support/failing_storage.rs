@@ -14,6 +14,7 @@
 // when the error type is already `CoreError`. This is intentional boilerplate.
 #![allow(clippy::useless_conversion)]
 
+use std::path::Path;
 use std::sync::Arc;
 
 use async_trait::async_trait;
@@ -21,21 +22,26 @@ use chrono::{DateTime, Utc};
 use oneshim_core::error::CoreError;
 use oneshim_core::models::activity::{IdlePeriod, ProcessSnapshot, SessionStats};
 use oneshim_core::models::annotation::FrameAnnotation;
+use oneshim_core::models::automation::WorkflowExecutionRecord;
+use oneshim_core::models::bookmark::Bookmark;
 use oneshim_core::models::daily_digest::DailyDigest;
 use oneshim_core::models::dashboard_streaming::{
     DashboardEventRecord, DashboardEventSignal, MetricBucketRecord,
 };
-use oneshim_core::models::event::Event;
+use oneshim_core::models::event::{Event, QuarantinedEvent};
 use oneshim_core::models::storage_records::{
-    DeletedRangeCounts, EventExportRecord, FocusInterruptionRecord, FocusWorkSessionRecord,
-    FrameExportRecord, FrameRecord, FrameTagLinkRecord, GuiInteractionRecord, HourlyMetricsRecord,
+    DailyMetricsRecord, DatabaseBackupRecord, DeletedRangeCounts, EventExportRecord,
+    FocusInterruptionRecord, FocusWorkSessionRecord, FrameExportRecord, FrameRecord,
+    FrameTagLinkRecord, GuiInteractionRecord, HourlyMetricsRecord, IntegrityCheckRecord,
     LocalSuggestionRecord, MetricExportRecord, NewGuiInteraction, SearchEventRow, SearchFrameRow,
-    SegmentSummaryRecord, StorageStatsSummaryRecord, SuggestionRecord, TagRecord,
+    SegmentSummaryRecord, StorageReport, StorageStatsSummaryRecord, SuggestionRecord, TagRecord,
+    TagRuleRecord,
 };
 use oneshim_core::models::suggestion::Suggestion;
 use oneshim_core::models::system::SystemMetrics;
 use oneshim_core::models::work_session::FocusMetrics;
 use oneshim_core::ports::annotation_storage::AnnotationStorage;
+use oneshim_core::ports::bookmark_storage::BookmarkStorage;
 use oneshim_core::ports::storage::{MetricsStorage, StorageService};
 use oneshim_core::ports::web_storage::{
     ActivityStatsStorage, BackupStorage, CoachingQueryStorage, DashboardStreamingStorage,
@@ -43,6 +49,7 @@ use oneshim_core::ports::web_storage::{
     HabitStorage, SegmentQueryStorage, StorageMaintenanceStorage, SuggestionQueryStorage,
     TagStorage,
 };
+use oneshim_core::ports::workflow_history::WorkflowHistoryStorage;
 use oneshim_core::types::TimeWindow;
 use oneshim_storage::sqlite::SqliteStorage;
 
@@ -75,6 +82,10 @@ impl StorageService for FailingStorage {
         self.inner.save_event(event).await
     }
 
+    async fn save_events(&self, events: &[Event]) -> Result<(), CoreError> {
+        self.inner.save_events(events).await
+    }
+
     async fn get_events(
         &self,
         from: DateTime<Utc>,
@@ -92,10 +103,21 @@ impl StorageService for FailingStorage {
         self.inner.mark_as_sent(event_ids).await
     }
 
+    async fn get_idempotency_keys(
+        &self,
+        event_ids: &[String],
+    ) -> Result<std::collections::HashMap<String, String>, CoreError> {
+        self.inner.get_idempotency_keys(event_ids).await
+    }
+
     async fn mark_unsent_as_sent_before(&self, before: DateTime<Utc>) -> Result<usize, CoreError> {
         self.inner.mark_unsent_as_sent_before(before).await
     }
 
+    async fn quarantine_events(&self, events: &[QuarantinedEvent]) -> Result<(), CoreError> {
+        self.inner.quarantine_events(events).await
+    }
+
     async fn enforce_retention(&self) -> Result<usize, CoreError> {
         self.inner.enforce_retention().await
     }
@@ -136,6 +158,10 @@ impl MetricsStorage for FailingStorage {
         self.inner.aggregate_hourly_metrics(hour).await
     }
 
+    async fn aggregate_daily_metrics(&self, date: DateTime<Utc>) -> Result<(), CoreError> {
+        self.inner.aggregate_daily_metrics(date).await
+    }
+
     async fn cleanup_old_metrics(&self, before: DateTime<Utc>) -> Result<usize, CoreError> {
         self.inner.cleanup_old_metrics(before).await
     }
@@ -161,14 +187,20 @@ impl MetricsStorage for FailingStorage {
     }
 
     /// Injected fault: returns Storage error when `fail_start_idle` is set.
-    async fn start_idle_period(&self, start_time: DateTime<Utc>) -> Result<i64, CoreError> {
+    async fn start_idle_period(
+        &self,
+        start_time: DateTime<Utc>,
+        merge_threshold_secs: u64,
+    ) -> Result<i64, CoreError> {
         if self.fail_start_idle {
             return Err(CoreError::Storage {
                 message: "injected: start_idle_period forced failure".to_string(),
                 code: oneshim_core::error_codes::StorageCode::Failed,
             });
         }
-        self.inner.start_idle_period(start_time).await
+        self.inner
+            .start_idle_period(start_time, merge_threshold_secs)
+            .await
     }
 
     async fn end_idle_period(&self, id: i64, end_time: DateTime<Utc>) -> Result<(), CoreError> {
@@ -218,6 +250,15 @@ impl MetricsStorage for FailingStorage {
             .increment_session_counters(session_id, events, frames, idle_secs)
             .await
     }
+
+    async fn event_histogram(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+        bucket: chrono::Duration,
+    ) -> Result<Vec<(DateTime<Utc>, u64)>, CoreError> {
+        self.inner.event_histogram(from, to, bucket).await
+    }
 }
 
 // ── TagStorage ────────────────────────────────────────────────────────────────
@@ -269,6 +310,31 @@ impl TagStorage for FailingStorage {
             .remove_tag_from_frame(frame_id, tag_id)
             .map_err(Into::into)
     }
+
+    fn get_tag_rules(&self) -> Result<Vec<TagRuleRecord>, CoreError> {
+        self.inner.get_tag_rules().map_err(Into::into)
+    }
+
+    fn create_tag_rule(&self, app_pattern: &str, tag_id: i64) -> Result<TagRuleRecord, CoreError> {
+        self.inner
+            .create_tag_rule(app_pattern, tag_id)
+            .map_err(Into::into)
+    }
+
+    fn update_tag_rule(
+        &self,
+        rule_id: i64,
+        app_pattern: &str,
+        tag_id: i64,
+    ) -> Result<bool, CoreError> {
+        self.inner
+            .update_tag_rule(rule_id, app_pattern, tag_id)
+            .map_err(Into::into)
+    }
+
+    fn delete_tag_rule(&self, rule_id: i64) -> Result<bool, CoreError> {
+        self.inner.delete_tag_rule(rule_id).map_err(Into::into)
+    }
 }
 
 // ── FrameQueryStorage ────────────────────────────────────────────────────────
@@ -291,6 +357,10 @@ impl FrameQueryStorage for FailingStorage {
         self.inner.get_frame_file_path(frame_id).map_err(Into::into)
     }
 
+    fn get_trashed_frames(&self, limit: usize) -> Result<Vec<FrameRecord>, CoreError> {
+        self.inner.get_trashed_frames(limit).map_err(Into::into)
+    }
+
     fn list_frame_file_paths_in_range(
         &self,
         window: &TimeWindow,
@@ -353,6 +423,39 @@ impl StorageMaintenanceStorage for FailingStorage {
         self.inner.get_storage_stats_summary().map_err(Into::into)
     }
 
+    fn storage_report(
+        &self,
+        frames_dir: Option<&Path>,
+        frames_dir_bytes: u64,
+    ) -> Result<StorageReport, CoreError> {
+        self.inner
+            .storage_report(frames_dir, frames_dir_bytes)
+            .map_err(Into::into)
+    }
+
+    fn check_integrity(&self) -> Result<IntegrityCheckRecord, CoreError> {
+        self.inner.integrity_check().map_err(Into::into)
+    }
+
+    fn create_database_backup(&self, keep: usize) -> Result<DatabaseBackupRecord, CoreError> {
+        self.inner.create_database_backup(keep).map_err(Into::into)
+    }
+
+    fn mark_frame_file_corrupt(&self, frame_id: i64) -> Result<(), CoreError> {
+        self.inner
+            .mark_frame_file_corrupt(frame_id)
+            .map(|_| ())
+            .map_err(Into::into)
+    }
+
+    fn soft_delete_frame(&self, frame_id: i64) -> Result<bool, CoreError> {
+        self.inner.soft_delete_frame(frame_id).map_err(Into::into)
+    }
+
+    fn restore_frame(&self, frame_id: i64) -> Result<bool, CoreError> {
+        self.inner.restore_frame(frame_id).map_err(Into::into)
+    }
+
     fn delete_data_in_range(
         &self,
         window: &TimeWindow,
@@ -571,6 +674,12 @@ impl BackupStorage for FailingStorage {
             .map_err(Into::into)
     }
 
+    fn list_daily_metrics_since(&self, from: &str) -> Result<Vec<DailyMetricsRecord>, CoreError> {
+        self.inner
+            .list_daily_metrics_since(from)
+            .map_err(Into::into)
+    }
+
     fn upsert_backup_tag(
         &self,
         id: i64,
@@ -769,6 +878,51 @@ impl DashboardStreamingStorage for FailingStorage {
     }
 }
 
+// ── WorkflowHistoryStorage ───────────────────────────────────────────────────
+
+impl WorkflowHistoryStorage for FailingStorage {
+    fn save_workflow_execution(&self, record: &WorkflowExecutionRecord) -> Result<(), CoreError> {
+        self.inner
+            .save_workflow_execution(record)
+            .map_err(Into::into)
+    }
+
+    fn get_workflow_history(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<WorkflowExecutionRecord>, CoreError> {
+        self.inner
+            .get_workflow_history(from, to)
+            .map_err(Into::into)
+    }
+
+    fn get_workflow_execution(
+        &self,
+        execution_id: &str,
+    ) -> Result<Option<WorkflowExecutionRecord>, CoreError> {
+        self.inner
+            .get_workflow_execution(execution_id)
+            .map_err(Into::into)
+    }
+}
+
+// ── BookmarkStorage ──────────────────────────────────────────────────────────
+
+impl BookmarkStorage for FailingStorage {
+    fn save_bookmark(&self, bookmark: &Bookmark) -> Result<(), CoreError> {
+        self.inner.save_bookmark(bookmark).map_err(Into::into)
+    }
+
+    fn get_bookmarks(
+        &self,
+        from: DateTime<Utc>,
+        to: DateTime<Utc>,
+    ) -> Result<Vec<Bookmark>, CoreError> {
+        self.inner.get_bookmarks(from, to).map_err(Into::into)
+    }
+}
+
 // ── WebStorage blanket impl fires automatically via the above. ────────────────
-// (WebStorage is implemented for any T that satisfies all 17 sub-traits +
+// (WebStorage is implemented for any T that satisfies all 19 sub-traits +
 //  Send + Sync; FailingStorage satisfies all of them.)
@@ -38,7 +38,7 @@ if ! jq -e '
   and (.groups | type == "array" and length > 0)
   and (
     [.groups[].operations[] |
-      ((.path | startswith("/api/")) or (.path | startswith("/integration/v1/")))
+      ((.path | startswith("/api/")) or (.path | startswith("/integration/v1/")) or (.path | startswith("/share/v1/")))
       and (.method | IN("GET", "POST", "PUT", "DELETE"))
     ] | all
   )
@@ -67,7 +67,7 @@ readarray_compat route_paths < <(
 
 readarray_compat manifest_paths < <(
   jq -r '.groups[].operations[].path' "$MANIFEST_PATH" \
-    | sed -e 's#^/api##' -e 's#^/integration/v1##' \
+    | sed -e 's#^/api##' -e 's#^/integration/v1##' -e 's#^/share/v1##' \
     | sort -u
 )
 
@@ -0,0 +1,343 @@
+//! Embeddable agent — runs the monitoring pipeline without the Tauri shell.
+//!
+//! [`AgentBuilder`] assembles the same scheduler the desktop app runs, with
+//! every port replaceable. Ports that are not injected fall back to the
+//! platform adapters the desktop app uses.
+
+use anyhow::{anyhow, Result};
+use oneshim_core::config::AppConfig;
+use oneshim_core::monitor_feed::{MonitorFeed, MonitorStream};
+use oneshim_core::ports::api_client::ApiClient;
+use oneshim_core::ports::batch_sink::BatchSink;
+use oneshim_core::ports::clock::Clock;
+use oneshim_core::ports::frame_storage::FrameStoragePort;
+use oneshim_core::ports::monitor::{ActivityMonitor, ProcessMonitor, SystemMonitor};
+use oneshim_core::ports::storage::StorageService;
+use oneshim_core::ports::vision::{CaptureTrigger, FrameProcessor};
+use oneshim_core::title_pattern::PatternSet;
+use oneshim_storage::sqlite::SqliteStorage;
+use oneshim_vision::processor::EdgeFrameProcessor;
+use oneshim_vision::trigger::SmartCaptureTrigger;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tokio::task::JoinHandle;
+use tracing::{info, warn};
+
+use crate::agent_runtime_support::generate_session_id;
+use crate::scheduler::{Scheduler, SchedulerConfig};
+use crate::simulate::{
+    MetadataOnlyFrameProcessor, SimulatedBatchSink, SimulationStats, SimulationSummary,
+};
+
+/// Where the agent keeps its local database.
+enum AgentStorage {
+    InMemory,
+    Path(PathBuf),
+    Shared(Arc<SqliteStorage>),
+}
+
+/// Builder for a headless [`Agent`].
+pub struct AgentBuilder {
+    config: AppConfig,
+    storage: AgentStorage,
+    offline: bool,
+    simulate: bool,
+    system_monitor: Option<Arc<dyn SystemMonitor>>,
+    activity_monitor: Option<Arc<dyn ActivityMonitor>>,
+    process_monitor: Option<Arc<dyn ProcessMonitor>>,
+    capture_trigger: Option<Arc<dyn CaptureTrigger>>,
+    frame_processor: Option<Arc<dyn FrameProcessor>>,
+    frame_storage: Option<Arc<dyn FrameStoragePort>>,
+    api_client: Option<Arc<dyn ApiClient>>,
+    batch_sink: Option<Arc<dyn BatchSink>>,
+    clock: Option<Arc<dyn Clock>>,
+}
+
+impl AgentBuilder {
+    /// Start from `config` with in-memory storage. Use
+    /// [`Self::with_db_path`] or [`Self::with_storage`] to persist data.
+    pub fn new(config: AppConfig) -> Self {
+        Self {
+            config,
+            storage: AgentStorage::InMemory,
+            offline: false,
+            simulate: false,
+            system_monitor: None,
+            activity_monitor: None,
+            process_monitor: None,
+            capture_trigger: None,
+            frame_processor: None,
+            frame_storage: None,
+            api_client: None,
+            batch_sink: None,
+            clock: None,
+        }
+    }
+
+    pub fn with_in_memory_storage(mut self) -> Self {
+        self.storage = AgentStorage::InMemory;
+        self
+    }
+
+    /// Persist to an unencrypted SQLite file at `path`. Do not point this at
+    /// the desktop app's database, which is encrypted and already open.
+    pub fn with_db_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.storage = AgentStorage::Path(path.into());
+        self
+    }
+
+    pub fn with_storage(mut self, storage: Arc<SqliteStorage>) -> Self {
+        self.storage = AgentStorage::Shared(storage);
+        self
+    }
+
+    /// Never talk to the server: injected API clients and batch sinks are
+    /// ignored and upload stays local.
+    pub fn offline(mut self) -> Self {
+        self.offline = true;
+        self
+    }
+
+    /// Dry run: collect as usual but keep everything in memory, log uploads
+    /// instead of sending them and capture frame metadata only. Overrides the
+    /// storage, frame processor, frame storage, API client and batch sink.
+    /// See [`Agent::simulation_summary`].
+    pub fn simulate(mut self) -> Self {
+        self.simulate = true;
+        self
+    }
+
+    pub fn with_system_monitor(mut self, monitor: Arc<dyn SystemMonitor>) -> Self {
+        self.system_monitor = Some(monitor);
+        self
+    }
+
+    pub fn with_activity_monitor(mut self, monitor: Arc<dyn ActivityMonitor>) -> Self {
+        self.activity_monitor = Some(monitor);
+        self
+    }
+
+    pub fn with_process_monitor(mut self, monitor: Arc<dyn ProcessMonitor>) -> Self {
+        self.process_monitor = Some(monitor);
+        self
+    }
+
+    pub fn with_capture_trigger(mut self, trigger: Arc<dyn CaptureTrigger>) -> Self {
+        self.capture_trigger = Some(trigger);
+        self
+    }
+
+    pub fn with_frame_processor(mut self, processor: Arc<dyn FrameProcessor>) -> Self {
+        self.frame_processor = Some(processor);
+        self
+    }
+
+    pub fn with_frame_storage(mut self, storage: Arc<dyn FrameStoragePort>) -> Self {
+        self.frame_storage = Some(storage);
+        self
+    }
+
+    pub fn with_api_client(mut self, client: Arc<dyn ApiClient>) -> Self {
+        self.api_client = Some(client);
+        self
+    }
+
+    pub fn with_batch_sink(mut self, sink: Arc<dyn BatchSink>) -> Self {
+        self.batch_sink = Some(sink);
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = Some(clock);
+        self
+    }
+
+    /// Open storage and wire the scheduler. Nothing runs until
+    /// [`Agent::start`].
+    pub fn build(mut self) -> Result<Agent> {
+        let retention_days = self.config.storage.retention_days;
+        let simulation = self.simulate.then(|| Arc::new(SimulationStats::default()));
+        if simulation.is_some() {
+            self.storage = AgentStorage::InMemory;
+        }
+        let sqlite = match self.storage {
+            AgentStorage::InMemory => Arc::new(SqliteStorage::open_in_memory(retention_days)?),
+            AgentStorage::Path(path) => Arc::new(SqliteStorage::open(&path, retention_days, None)?),
+            AgentStorage::Shared(storage) => storage,
+        };
+
+        let process_monitor = self
+            .process_monitor
+            .unwrap_or_else(|| Arc::new(oneshim_monitor::process::ProcessTracker::new()));
+        let activity_monitor = self.activity_monitor.unwrap_or_else(|| {
+            Arc::new(oneshim_monitor::activity::ActivityTracker::new(
+                process_monitor.clone(),
+            ))
+        });
+        let system_monitor = self
+            .system_monitor
+            .unwrap_or_else(|| Arc::new(oneshim_monitor::system::SysInfoMonitor::new()));
+        let capture_trigger = self.capture_trigger.unwrap_or_else(|| {
+            Arc::new(
+                SmartCaptureTrigger::new(self.config.vision.capture_throttle_ms)
+                    .with_signal_triggers(self.config.vision.signal_triggers.clone())
+                    .with_dwell_secs(self.config.vision.dwell_capture_secs)
+                    .with_title_exclusions(PatternSet::compile_lenient(
+                        &self.config.privacy.excluded_title_patterns,
+                    )),
+            )
+        });
+        if let Some(stats) = &simulation {
+            self.frame_processor = Some(Arc::new(MetadataOnlyFrameProcessor::new(stats.clone())));
+            self.frame_storage = None;
+        }
+        let frame_processor = self.frame_processor.unwrap_or_else(|| {
+            Arc::new(
+                EdgeFrameProcessor::new(
+                    self.config.vision.thumbnail_width,
+                    self.config.vision.thumbnail_height,
+                    None,
+                )
+                .with_ocr_downscale_width(self.config.vision.ocr_downscale_width)
+                .with_notification_mask(
+                    oneshim_vision::notification_mask::NotificationMask::from_config(
+                        &self.config.privacy,
+                    ),
+                )
+                .with_privacy_preview(
+                    oneshim_vision::privacy_preview::PrivacyPreview::from_config(
+                        &self.config.privacy,
+                    ),
+                ),
+            )
+        });
+
+        let (api_client, batch_sink) = if let Some(stats) = &simulation {
+            let sink: Arc<dyn BatchSink> = Arc::new(SimulatedBatchSink::new(stats.clone()));
+            (None, Some(sink))
+        } else if self.offline {
+            (None, None)
+        } else {
+            (self.api_client, self.batch_sink)
+        };
+
+        let mut scheduler_config =
+            SchedulerConfig::from_app_config(&self.config, generate_session_id());
+        if simulation.is_some() {
+            // Run the full upload path so the summary shows what would leave the machine.
+            scheduler_config.upload_enabled = true;
+        } else if self.offline {
+            scheduler_config.upload_enabled = false;
+        }
+
+        let mut scheduler = Scheduler::new(
+            scheduler_config,
+            system_monitor,
+            activity_monitor,
+            process_monitor,
+            capture_trigger,
+            frame_processor,
+            sqlite.clone() as Arc<dyn StorageService>,
+            sqlite.clone(),
+            self.frame_storage,
+            batch_sink,
+            api_client,
+        );
+        if let Some(clock) = self.clock {
+            scheduler = scheduler.with_clock(clock);
+        }
+        let monitor_feed = MonitorFeed::default();
+        scheduler = scheduler.with_monitor_feed(monitor_feed.clone());
+
+        Ok(Agent {
+            scheduler: Arc::new(scheduler),
+            monitor_feed,
+            storage: sqlite,
+            offline: self.offline,
+            simulation,
+            shutdown_tx: None,
+            task: None,
+        })
+    }
+}
+
+/// A wired monitoring pipeline. Start and stop it from inside a tokio
+/// runtime; the scheduler loops run on that runtime.
+pub struct Agent {
+    scheduler: Arc<Scheduler>,
+    monitor_feed: MonitorFeed,
+    storage: Arc<SqliteStorage>,
+    offline: bool,
+    simulation: Option<Arc<SimulationStats>>,
+    shutdown_tx: Option<watch::Sender<bool>>,
+    task: Option<JoinHandle<()>>,
+}
+
+impl Agent {
+    /// Spawn the scheduler loops. Errors if the agent is already running.
+    pub fn start(&mut self) -> Result<()> {
+        if self.is_running() {
+            return Err(anyhow!("agent is already running"));
+        }
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let scheduler = self.scheduler.clone();
+        self.task = Some(tokio::spawn(async move {
+            scheduler.run(shutdown_rx, None).await;
+        }));
+        self.shutdown_tx = Some(shutdown_tx);
+        info!(
+            offline = self.offline,
+            simulate = self.is_simulated(),
+            "embedded agent started"
+        );
+        Ok(())
+    }
+
+    /// Signal shutdown and wait for every loop to exit. A no-op when the
+    /// agent is not running.
+    pub async fn stop(&mut self) -> Result<()> {
+        let Some(shutdown_tx) = self.shutdown_tx.take() else {
+            return Ok(());
+        };
+        if shutdown_tx.send(true).is_err() {
+            warn!("embedded agent shutdown signal had no receivers");
+        }
+        if let Some(task) = self.task.take() {
+            task.await
+                .map_err(|e| anyhow!("embedded agent task failed: {e}"))?;
+        }
+        info!("embedded agent stopped");
+        Ok(())
+    }
+
+    pub fn is_running(&self) -> bool {
+        self.task.as_ref().is_some_and(|task| !task.is_finished())
+    }
+
+    pub fn is_offline(&self) -> bool {
+        self.offline
+    }
+
+    pub fn is_simulated(&self) -> bool {
+        self.simulation.is_some()
+    }
+
+    /// What a simulated agent would have uploaded and captured so far.
+    /// `None` unless built with [`AgentBuilder::simulate`].
+    pub fn simulation_summary(&self) -> Option<SimulationSummary> {
+        self.simulation.as_ref().map(|stats| stats.summary())
+    }
+
+    /// Stream of every context and metrics sample the scheduler collects
+    /// from now on. The scheduler's own collection feeds it, so subscribing
+    /// adds no extra polling; drop the stream to unsubscribe.
+    pub fn subscribe(&self) -> MonitorStream {
+        self.monitor_feed.subscribe()
+    }
+
+    /// The local database the agent writes to.
+    pub fn storage(&self) -> Arc<SqliteStorage> {
+        self.storage.clone()
+    }
+}
@@ -6,11 +6,15 @@ mod sync_setup;
 use anyhow::Result;
 use oneshim_core::config::AppConfig;
 use oneshim_core::config_manager::ConfigManager;
-use oneshim_core::consent::ConsentManager;
+use oneshim_core::consent::{ConsentManager, ConsentRegistry};
 use oneshim_core::ports::calibration_store::{CalibrationReader, CalibrationWriter};
 use oneshim_core::ports::coaching_storage::CoachingStoragePort;
 use oneshim_core::ports::storage::StorageService;
 #[cfg(feature = "server")]
+use oneshim_network::circuit_breaker::CircuitBreaker;
+#[cfg(feature = "server")]
+use oneshim_network::metrics::NetworkMetrics;
+#[cfg(feature = "server")]
 use oneshim_network::oauth::refresh_coordinator::TokenRefreshCoordinator;
 use oneshim_web::RealtimeEvent;
 use std::path::{Path, PathBuf};
@@ -23,6 +27,7 @@ use tracing::{error, info};
 use crate::agent_runtime_support::AgentSupportContextBuilder;
 use crate::capture_services::SharedCaptureServices;
 use crate::focus_analyzer::FocusStorage;
+use crate::scheduler::loop_health::LoopHealthRegistry;
 use crate::scheduler::shared_regime_state::SharedRegimeState;
 use crate::scheduler::{Scheduler, SchedulerStorage};
 
@@ -42,12 +47,19 @@ pub(crate) struct AgentRuntimeBundle {
     config: AppConfig,
     config_manager: ConfigManager,
     consent_manager: Option<Arc<ConsentManager>>,
+    consent_registry: Option<Arc<ConsentRegistry>>,
     /// Concrete SQLite storage for sync engine wiring.
     sqlite_storage_concrete: Arc<oneshim_storage::sqlite::SqliteStorage>,
     offline_mode: bool,
     event_tx: Option<broadcast::Sender<RealtimeEvent>>,
     #[cfg(feature = "server")]
     oauth_coordinator: Option<Arc<TokenRefreshCoordinator>>,
+    /// Server circuit breaker shared with the UI connection indicator.
+    #[cfg(feature = "server")]
+    server_breaker: Option<Arc<CircuitBreaker>>,
+    /// Request metrics shared with the dashboard and the shutdown summary.
+    #[cfg(feature = "server")]
+    network_metrics: Option<Arc<NetworkMetrics>>,
     app_handle: AppHandle,
     coaching_engine: Option<Arc<oneshim_analysis::CoachingEngine>>,
     coaching_storage: Option<Arc<dyn CoachingStoragePort>>,
@@ -69,6 +81,7 @@ pub(crate) struct AgentRuntimeBundle {
     suggestion_manager: Option<Arc<crate::suggestion_manager::SuggestionManager>>,
     suggestions_enabled: bool,
     focus_mode: Option<Arc<crate::focus_mode::FocusModeState>>,
+    metrics_rate: Option<Arc<crate::scheduler::metrics_rate::MetricsRate>>,
     shared_capture_services: Option<Arc<SharedCaptureServices>>,
     /// Shared suggestion queue — SAME Arc as SuggestionManager's queue,
     /// so SSE-received suggestions are visible in IPC queries.
@@ -78,6 +91,8 @@ pub(crate) struct AgentRuntimeBundle {
     /// SharedRegimeState passed through to the Scheduler so it shares the same
     /// instance as the SessionManager's context assembler.
     shared_regime: Option<Arc<SharedRegimeState>>,
+    /// Loop heartbeat registry shared with the web dashboard.
+    loop_health: Option<Arc<LoopHealthRegistry>>,
     /// Pre-created health flag for the primary analysis provider, shared with AppState
     /// so the `get_analysis_health` IPC command reflects actual provider health.
     analysis_health_flag: Option<Arc<std::sync::atomic::AtomicBool>>,
@@ -127,6 +142,17 @@ impl AgentRuntimeBundle {
         }
         // Clone before the move into Scheduler::with_config_manager below.
         builder = builder.with_config_manager(self.config_manager.clone());
+        if let Some(ref focus_mode) = self.focus_mode {
+            builder = builder.with_focus_mode(focus_mode.clone());
+        }
+        #[cfg(feature = "server")]
+        if let Some(ref breaker) = self.server_breaker {
+            builder = builder.with_server_breaker(breaker.clone());
+        }
+        #[cfg(feature = "server")]
+        if let Some(ref metrics) = self.network_metrics {
+            builder = builder.with_network_metrics(metrics.clone());
+        }
         let support = builder.build().await?;
         let accessibility_extractor = support.accessibility_extractor.clone();
 
@@ -176,9 +202,9 @@ impl AgentRuntimeBundle {
         // --- Layer 2b: Vector index + adaptive search coordinator ---
         let vector_index: Option<Arc<dyn oneshim_core::ports::vector_index::VectorIndex>> =
             if embedding.vector_store.is_some() {
-                let conn = self.sqlite_storage_concrete.connection_arc();
+                let pool = self.sqlite_storage_concrete.connection_pool();
                 Some(Arc::new(
-                    oneshim_storage::sqlite::vector_index_impl::SqliteVectorIndex::new(conn),
+                    oneshim_storage::sqlite::vector_index_impl::SqliteVectorIndex::new(pool),
                 ))
             } else {
                 None
@@ -280,6 +306,9 @@ impl AgentRuntimeBundle {
         if let Some(ref cm) = self.consent_manager {
             scheduler = scheduler.with_consent_manager(cm.clone());
         }
+        if let Some(registry) = self.consent_registry {
+            scheduler = scheduler.with_consent_registry(registry);
+        }
 
         // --- Coaching engine + storage + overlay wiring ---
         if let Some(engine) = self.coaching_engine {
@@ -309,10 +338,21 @@ impl AgentRuntimeBundle {
             scheduler = scheduler.with_focus_mode(focus_mode);
         }
 
+        // --- Metrics rate shared with the burst IPC commands ---
+        if let Some(metrics_rate) = self.metrics_rate {
+            scheduler = scheduler.with_metrics_rate(metrics_rate);
+        }
+
         // --- SharedRegimeState: thread through to scheduler for single-instance sharing ---
         if let Some(shared_regime) = self.shared_regime {
             scheduler = scheduler.with_shared_regime(shared_regime);
         }
+        if let Some(loop_health) = self.loop_health {
+            scheduler = scheduler.with_loop_health(loop_health);
+        }
+        if let Some(ref capture_services) = self.shared_capture_services {
+            scheduler = scheduler.with_capture_latency(capture_services.capture_latency.clone());
+        }
 
         // --- Analysis provider for coaching LLM personalization ---
         #[cfg(feature = "analysis")]
@@ -411,12 +451,19 @@ pub(crate) struct AgentRuntimeBuilder<'a> {
     config: &'a AppConfig,
     config_manager: ConfigManager,
     consent_manager: Option<Arc<ConsentManager>>,
+    consent_registry: Option<Arc<ConsentRegistry>>,
     /// Concrete SQLite storage for sync engine wiring.
     sqlite_storage_concrete: Arc<oneshim_storage::sqlite::SqliteStorage>,
     offline_mode: bool,
     event_tx: Option<broadcast::Sender<RealtimeEvent>>,
     #[cfg(feature = "server")]
     oauth_coordinator: Option<Arc<TokenRefreshCoordinator>>,
+    /// Server circuit breaker shared with the UI connection indicator.
+    #[cfg(feature = "server")]
+    server_breaker: Option<Arc<CircuitBreaker>>,
+    /// Request metrics shared with the dashboard and the shutdown summary.
+    #[cfg(feature = "server")]
+    network_metrics: Option<Arc<NetworkMetrics>>,
     app_handle: AppHandle,
     coaching_engine: Option<Arc<oneshim_analysis::CoachingEngine>>,
     coaching_storage: Option<Arc<dyn CoachingStoragePort>>,
@@ -437,6 +484,7 @@ pub(crate) struct AgentRuntimeBuilder<'a> {
     suggestion_manager: Option<Arc<crate::suggestion_manager::SuggestionManager>>,
     suggestions_enabled: bool,
     focus_mode: Option<Arc<crate::focus_mode::FocusModeState>>,
+    metrics_rate: Option<Arc<crate::scheduler::metrics_rate::MetricsRate>>,
     shared_capture_services: Option<Arc<SharedCaptureServices>>,
     /// Shared suggestion queue — passed through to AgentSupportContextBuilder
     /// so the SuggestionReceiver uses the same queue as SuggestionManager.
@@ -446,6 +494,8 @@ pub(crate) struct AgentRuntimeBuilder<'a> {
     /// SharedRegimeState — passed through to the Scheduler so it shares the same
     /// instance as the SessionManager's context assembler.
     shared_regime: Option<Arc<SharedRegimeState>>,
+    /// Loop heartbeat registry shared with the web dashboard.
+    loop_health: Option<Arc<LoopHealthRegistry>>,
     /// Pre-created health flag for the primary analysis provider, shared with AppState.
     analysis_health_flag: Option<Arc<std::sync::atomic::AtomicBool>>,
     /// Pre-constructed RegimeManager + RegimeClassifier handles — see
@@ -481,10 +531,15 @@ impl<'a> AgentRuntimeBuilder<'a> {
             config,
             config_manager,
             consent_manager: None,
+            consent_registry: None,
             offline_mode: false,
             event_tx: None,
             #[cfg(feature = "server")]
             oauth_coordinator: None,
+            #[cfg(feature = "server")]
+            server_breaker: None,
+            #[cfg(feature = "server")]
+            network_metrics: None,
             app_handle,
             coaching_engine: None,
             coaching_storage: None,
@@ -505,10 +560,12 @@ impl<'a> AgentRuntimeBuilder<'a> {
             suggestion_manager: None,
             suggestions_enabled: false,
             focus_mode: None,
+            metrics_rate: None,
             shared_capture_services: None,
             shared_suggestion_queue: None,
             shared_scorer: None,
             shared_regime: None,
+            loop_health: None,
             analysis_health_flag: None,
             regime_manager: None,
             regime_classifier: None,
@@ -536,6 +593,14 @@ impl<'a> AgentRuntimeBuilder<'a> {
         self
     }
 
+    pub(crate) fn with_metrics_rate(
+        mut self,
+        metrics_rate: Arc<crate::scheduler::metrics_rate::MetricsRate>,
+    ) -> Self {
+        self.metrics_rate = Some(metrics_rate);
+        self
+    }
+
     pub(crate) fn with_shared_capture_services(
         mut self,
         services: Arc<SharedCaptureServices>,
@@ -575,6 +640,11 @@ impl<'a> AgentRuntimeBuilder<'a> {
         self
     }
 
+    pub(crate) fn with_consent_registry(mut self, registry: Arc<ConsentRegistry>) -> Self {
+        self.consent_registry = Some(registry);
+        self
+    }
+
     pub(crate) fn with_offline_mode(mut self, offline_mode: bool) -> Self {
         self.offline_mode = offline_mode;
         self
@@ -597,6 +667,18 @@ impl<'a> AgentRuntimeBuilder<'a> {
         self
     }
 
+    #[cfg(feature = "server")]
+    pub(crate) fn with_server_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.server_breaker = Some(breaker);
+        self
+    }
+
+    #[cfg(feature = "server")]
+    pub(crate) fn with_network_metrics(mut self, metrics: Arc<NetworkMetrics>) -> Self {
+        self.network_metrics = Some(metrics);
+        self
+    }
+
     pub(crate) fn with_coaching_engine(
         mut self,
         engine: Arc<oneshim_analysis::CoachingEngine>,
@@ -715,6 +797,11 @@ impl<'a> AgentRuntimeBuilder<'a> {
         self
     }
 
+    pub(crate) fn with_loop_health(mut self, registry: Arc<LoopHealthRegistry>) -> Self {
+        self.loop_health = Some(registry);
+        self
+    }
+
     pub(crate) fn with_analysis_health_flag(
         mut self,
         flag: Arc<std::sync::atomic::AtomicBool>,
@@ -737,11 +824,16 @@ impl<'a> AgentRuntimeBuilder<'a> {
             config: self.config.clone(),
             config_manager: self.config_manager,
             consent_manager: self.consent_manager,
+            consent_registry: self.consent_registry,
             sqlite_storage_concrete: self.sqlite_storage_concrete,
             offline_mode: self.offline_mode,
             event_tx: self.event_tx,
             #[cfg(feature = "server")]
             oauth_coordinator: self.oauth_coordinator,
+            #[cfg(feature = "server")]
+            server_breaker: self.server_breaker,
+            #[cfg(feature = "server")]
+            network_metrics: self.network_metrics,
             app_handle: self.app_handle,
             coaching_engine: self.coaching_engine,
             coaching_storage: self.coaching_storage,
@@ -762,10 +854,12 @@ impl<'a> AgentRuntimeBuilder<'a> {
             suggestion_manager: self.suggestion_manager,
             suggestions_enabled: self.suggestions_enabled,
             focus_mode: self.focus_mode,
+            metrics_rate: self.metrics_rate,
             shared_capture_services: self.shared_capture_services,
             shared_suggestion_queue: self.shared_suggestion_queue,
             shared_scorer: self.shared_scorer,
             shared_regime: self.shared_regime,
+            loop_health: self.loop_health,
             analysis_health_flag: self.analysis_health_flag,
             regime_manager: self.regime_manager,
             regime_classifier: self.regime_classifier,
@@ -43,13 +43,13 @@ pub(super) async fn build_sync_engine(
         };
 
     let extractor = Arc::new(oneshim_storage::sync_extractor::SqliteSyncExtractor::new(
-        sqlite_storage_concrete.connection_arc(),
+        sqlite_storage_concrete.connection_pool(),
         device_id.clone(),
         device_name.clone(),
         config.sync.clone(),
     ));
     let merger = Arc::new(oneshim_storage::sync_merger::SqliteSyncMerger::new(
-        sqlite_storage_concrete.connection_arc(),
+        sqlite_storage_concrete.connection_pool(),
         device_id.clone(),
     ));
 
@@ -2,29 +2,41 @@ use anyhow::Result;
 use oneshim_core::config::AppConfig;
 use oneshim_core::config_manager::ConfigManager;
 use oneshim_core::ports::accessibility::AccessibilityExtractor;
+#[cfg(feature = "server")]
+use oneshim_core::ports::clock::{SkewCorrectedClock, SystemClock};
 use oneshim_core::ports::frame_storage::FrameStoragePort;
 use oneshim_core::ports::monitor::{ActivityMonitor, ProcessMonitor};
+use oneshim_core::title_pattern::PatternSet;
 #[cfg(feature = "server")]
 use oneshim_network::auth::TokenManager;
 #[cfg(feature = "server")]
 use oneshim_network::batch_uploader::BatchUploader;
+#[cfg(feature = "server")]
+use oneshim_network::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+#[cfg(feature = "server")]
+use oneshim_network::content_crypto::ContentEncryptor;
+#[cfg(feature = "server")]
+use oneshim_network::egress::EgressPolicy;
+#[cfg(feature = "server")]
+use oneshim_network::failover::ServerEndpoints;
 #[cfg(feature = "grpc")]
 use oneshim_network::grpc::{GrpcApiAdapter, GrpcConfig, GrpcSseAdapter, UnifiedClient};
 #[cfg(feature = "server")]
 use oneshim_network::http_client::HttpApiClient;
+#[cfg(feature = "server")]
+use oneshim_network::metrics::NetworkMetrics;
 #[cfg(all(feature = "server", not(feature = "grpc")))]
-use oneshim_network::sse_client::SseStreamClient;
+use oneshim_network::sse_client::{SseReconnectPolicy, SseStreamClient};
 use oneshim_storage::frame_storage::FrameFileStorage;
 use oneshim_vision::processor::EdgeFrameProcessor;
 use oneshim_vision::trigger::SmartCaptureTrigger;
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Duration;
 
 use std::sync::atomic::AtomicBool;
 
 use crate::capture_services::SharedCaptureServices;
-use crate::focus_analyzer::{FocusAnalyzer, FocusStorage};
+use crate::focus_analyzer::{FocusAnalyzer, FocusAnalyzerConfig, FocusStorage};
 use crate::notification_manager::NotificationManager;
 use crate::scheduler::SchedulerConfig;
 
@@ -79,6 +91,17 @@ pub(crate) struct AgentSupportContextBuilder<'a> {
     /// ConfigManager shared with the composition root. When set, the BatchUploader
     /// suppression predicate uses `snapshot()` to gate uploads during mute windows.
     config_manager: Option<ConfigManager>,
+    /// When set, notifications and suggestion popups are held while focus
+    /// mode is active.
+    focus_mode: Option<Arc<crate::focus_mode::FocusModeState>>,
+    /// Breaker shared with the UI connection indicator. A private one is
+    /// created when unset.
+    #[cfg(feature = "server")]
+    server_breaker: Option<Arc<CircuitBreaker>>,
+    /// Request metrics shared with the dashboard and the shutdown summary.
+    /// A private collector is created when unset.
+    #[cfg(feature = "server")]
+    network_metrics: Option<Arc<NetworkMetrics>>,
 }
 
 impl<'a> AgentSupportContextBuilder<'a> {
@@ -99,6 +122,11 @@ impl<'a> AgentSupportContextBuilder<'a> {
             few_shot_storage: None,
             analysis_health_flag: None,
             config_manager: None,
+            focus_mode: None,
+            #[cfg(feature = "server")]
+            server_breaker: None,
+            #[cfg(feature = "server")]
+            network_metrics: None,
         }
     }
 
@@ -160,6 +188,26 @@ impl<'a> AgentSupportContextBuilder<'a> {
         self
     }
 
+    pub(crate) fn with_focus_mode(
+        mut self,
+        focus_mode: Arc<crate::focus_mode::FocusModeState>,
+    ) -> Self {
+        self.focus_mode = Some(focus_mode);
+        self
+    }
+
+    #[cfg(feature = "server")]
+    pub(crate) fn with_server_breaker(mut self, breaker: Arc<CircuitBreaker>) -> Self {
+        self.server_breaker = Some(breaker);
+        self
+    }
+
+    #[cfg(feature = "server")]
+    pub(crate) fn with_network_metrics(mut self, metrics: Arc<NetworkMetrics>) -> Self {
+        self.network_metrics = Some(metrics);
+        self
+    }
+
     #[cfg(feature = "analysis")]
     fn build_context_analyzer(&self) -> Option<Arc<oneshim_analysis::ContextAnalyzer>> {
         if !self.config.analysis.enabled {
@@ -236,20 +284,39 @@ impl<'a> AgentSupportContextBuilder<'a> {
                     self.config.storage.max_storage_mb,
                     self.config.storage.retention_days,
                 )
-                .await?,
+                .await?
+                .with_shard_granularity(self.config.storage.frame_shard_granularity)
+                .with_disk_thresholds(
+                    self.config.storage.disk_critical_free_mb,
+                    self.config.storage.disk_resume_free_mb,
+                ),
+            );
+            let process_monitor: Arc<dyn ProcessMonitor> = Arc::new(
+                oneshim_monitor::process::ProcessTracker::new()
+                    .with_filter(self.config.monitor.process_filter()),
             );
-            let process_monitor: Arc<dyn ProcessMonitor> =
-                Arc::new(oneshim_monitor::process::ProcessTracker::new());
             let activity_monitor: Arc<dyn ActivityMonitor> = Arc::new(
                 oneshim_monitor::activity::ActivityTracker::new(process_monitor.clone()),
             );
             let ocr_tessdata = std::env::var("ONESHIM_TESSDATA").ok().map(PathBuf::from);
-            let frame_processor: Arc<dyn oneshim_core::ports::vision::FrameProcessor> =
-                Arc::new(EdgeFrameProcessor::new(
+            let frame_processor: Arc<dyn oneshim_core::ports::vision::FrameProcessor> = Arc::new(
+                EdgeFrameProcessor::new(
                     self.config.vision.thumbnail_width,
                     self.config.vision.thumbnail_height,
                     ocr_tessdata,
-                ));
+                )
+                .with_ocr_downscale_width(self.config.vision.ocr_downscale_width)
+                .with_notification_mask(
+                    oneshim_vision::notification_mask::NotificationMask::from_config(
+                        &self.config.privacy,
+                    ),
+                )
+                .with_privacy_preview(
+                    oneshim_vision::privacy_preview::PrivacyPreview::from_config(
+                        &self.config.privacy,
+                    ),
+                ),
+            );
             (
                 frame_storage,
                 process_monitor,
@@ -261,16 +328,47 @@ impl<'a> AgentSupportContextBuilder<'a> {
 
         let system_monitor = Arc::new(oneshim_monitor::system::SysInfoMonitor::new());
         let capture_trigger: Arc<dyn oneshim_core::ports::vision::CaptureTrigger> = Arc::new(
-            SmartCaptureTrigger::new(self.config.vision.capture_throttle_ms),
+            SmartCaptureTrigger::new(self.config.vision.capture_throttle_ms)
+                .with_signal_triggers(self.config.vision.signal_triggers.clone())
+                .with_dwell_secs(self.config.vision.dwell_capture_secs)
+                .with_title_exclusions(PatternSet::compile_lenient(
+                    &self.config.privacy.excluded_title_patterns,
+                )),
         );
 
         let session_id = generate_session_id();
         // Extract config_manager before any later borrows of `self` to avoid
         // partial-move conflicts (build_context_analyzer borrows self below).
         let config_manager = self.config_manager.take();
+        let category_overrides_rx = config_manager.as_ref().map(ConfigManager::subscribe);
+        // Server-skew-corrected time for upload stamps and suggestion expiry;
+        // local storage keeps raw timestamps.
         #[cfg(feature = "server")]
-        let (batch_sink_opt, api_client_opt, sse_client_opt) =
-            build_server_transports(self.config, &session_id, config_manager)?;
+        let server_clock = Arc::new(SkewCorrectedClock::new(
+            Arc::new(SystemClock),
+            std::time::Duration::from_secs(self.config.server.clock_skew_warn_secs),
+        ));
+        // One breaker for the server, so REST and SSE stop dialing together.
+        #[cfg(feature = "server")]
+        let server_breaker = self
+            .server_breaker
+            .take()
+            .unwrap_or_else(|| Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default())));
+        #[cfg(feature = "server")]
+        let network_metrics = self
+            .network_metrics
+            .take()
+            .unwrap_or_else(NetworkMetrics::new);
+        #[cfg(feature = "server")]
+        let (batch_sink_opt, api_client_opt, sse_client_opt) = build_server_transports(
+            self.config,
+            &session_id,
+            config_manager,
+            &server_clock,
+            server_breaker,
+            network_metrics,
+            self.storage.clone(),
+        )?;
         #[cfg(not(feature = "server"))]
         let (batch_sink_opt, api_client_opt) =
             build_server_transports(self.config, &session_id, config_manager)?;
@@ -281,14 +379,29 @@ impl<'a> AgentSupportContextBuilder<'a> {
             } else {
                 Arc::new(LogOnlyNotifier)
             };
-        let notification_manager = Arc::new(NotificationManager::new(
-            self.config.notification.clone(),
-            notifier.clone(),
-        ));
-        let focus_analyzer = Arc::new(FocusAnalyzer::with_defaults(
-            self.focus_storage.clone(),
-            notifier.clone(),
-        ));
+        let mut notification_manager =
+            NotificationManager::new(self.config.notification.clone(), notifier);
+        if let Some(focus_mode) = self.focus_mode.clone() {
+            notification_manager = notification_manager.with_focus_mode(focus_mode);
+        }
+        let notification_manager = Arc::new(notification_manager);
+        // Focus-gated when focus mode is wired, so every presenter defers alike.
+        let notifier = notification_manager.notifier();
+        let focus_analyzer = Arc::new(
+            FocusAnalyzer::new(
+                FocusAnalyzerConfig {
+                    classification: self.config.focus_classification.clone(),
+                    idle_session_end_secs: self.config.monitor.idle_session_end_secs,
+                    ..FocusAnalyzerConfig::default()
+                },
+                self.focus_storage.clone(),
+                notifier.clone(),
+            )
+            .with_category_overrides(self.config.monitor.category_overrides.clone()),
+        );
+        if let Some(config_rx) = category_overrides_rx {
+            focus_analyzer.follow_category_overrides(config_rx);
+        }
 
         let context_analyzer = self.build_context_analyzer();
 
@@ -309,7 +422,8 @@ impl<'a> AgentSupportContextBuilder<'a> {
                     Arc::new(tokio::sync::Mutex::new(
                         oneshim_suggestion::queue::SuggestionQueue::new(
                             self.config.analysis.max_suggestions,
-                        ),
+                        )
+                        .with_overflow_policy(self.config.suggestions.overflow_policy),
                     ))
                 });
                 let scorer = self.shared_scorer.unwrap_or_else(|| {
@@ -317,14 +431,31 @@ impl<'a> AgentSupportContextBuilder<'a> {
                         oneshim_suggestion::scorer::FeedbackScorer::new(),
                     ))
                 });
-                Some(Arc::new(
-                    oneshim_suggestion::receiver::SuggestionReceiver::new(
-                        sse_client,
-                        Some(notifier),
-                        queue,
-                        scorer,
+                let delivery = self.config.suggestions.delivery.clone();
+                let webhook = delivery.webhook_url.clone().map(|url| {
+                    Arc::new(
+                        oneshim_network::suggestion_webhook::HttpSuggestionWebhook::new(url)
+                            .with_egress_policy(EgressPolicy::from_config(&self.config.egress)),
+                    )
+                });
+                let mut receiver = oneshim_suggestion::receiver::SuggestionReceiver::new(
+                    sse_client,
+                    Some(notifier),
+                    queue,
+                    scorer,
+                )
+                .with_context_scoring(
+                    oneshim_suggestion::context_scorer::ContextScorer::new(
+                        self.config.suggestions.rescoring.clone(),
                     ),
-                ))
+                    focus_analyzer.clone(),
+                )
+                .with_clock(server_clock)
+                .with_delivery(delivery);
+                if let Some(webhook) = webhook {
+                    receiver = receiver.with_webhook(webhook);
+                }
+                Some(Arc::new(receiver))
             } else {
                 None
             }
@@ -334,23 +465,7 @@ impl<'a> AgentSupportContextBuilder<'a> {
         #[cfg(not(feature = "server"))]
         let suggestion_receiver = None;
 
-        let scheduler_config = SchedulerConfig {
-            poll_interval: Duration::from_millis(self.config.monitor.poll_interval_ms),
-            metrics_interval: Duration::from_secs(5),
-            process_interval: Duration::from_secs(10),
-            detailed_process_interval: Duration::from_secs(30),
-            input_activity_interval: Duration::from_secs(30),
-            sync_interval: Duration::from_millis(self.config.monitor.sync_interval_ms),
-            heartbeat_interval: Duration::from_millis(self.config.monitor.heartbeat_interval_ms),
-            aggregation_interval: Duration::from_secs(3600),
-            session_id,
-            external_data_policy: self.config.ai_provider.external_data_policy,
-            privacy_config: self.config.privacy.clone(),
-            idle_threshold_secs: 300,
-            upload_enabled: self.config.monitor.upload_enabled,
-            analysis_config: self.config.analysis.clone(),
-            cross_device_sync_interval: Duration::from_secs(300), // 5 min default
-        };
+        let scheduler_config = SchedulerConfig::from_app_config(self.config, session_id);
 
         Ok(AgentSupportContext {
             frame_storage,
@@ -376,27 +491,45 @@ fn build_server_transports(
     config: &AppConfig,
     session_id: &str,
     config_manager: Option<ConfigManager>,
+    server_clock: &Arc<SkewCorrectedClock>,
+    server_breaker: Arc<CircuitBreaker>,
+    network_metrics: Arc<NetworkMetrics>,
+    storage: Option<Arc<dyn oneshim_core::ports::storage::StorageService>>,
 ) -> Result<ServerTransportPorts> {
+    // Shared by every server client so they fail over together.
+    let endpoints = Arc::new(ServerEndpoints::from_config(&config.server));
     let token_manager = Arc::new(
         TokenManager::new_with_tls(
             &config.server.base_url,
             &config.tls,
             Some(config.request_timeout()),
         )
-        .map_err(|e| anyhow::anyhow!("failed to build TLS-aware TokenManager: {e}"))?,
+        .map_err(|e| anyhow::anyhow!("failed to build TLS-aware TokenManager: {e}"))?
+        .with_endpoints(endpoints.clone()),
     );
 
     #[cfg(feature = "grpc")]
     let (api_client, sse_client): (ApiClientPort, SseClientPort) = {
         let grpc_config =
             GrpcConfig::from_core_with_rest_tls(&config.grpc, &config.server.base_url, &config.tls);
-        let unified = Arc::new(UnifiedClient::new(grpc_config, token_manager.clone())?);
+        let unified = Arc::new(
+            UnifiedClient::new(grpc_config, token_manager.clone())?
+                .with_endpoints(endpoints.clone()),
+        );
         let http_fallback = HttpApiClient::new_with_tls(
             &config.server.base_url,
             token_manager.clone(),
             config.request_timeout(),
             &config.tls,
-        )?;
+        )?
+        .with_endpoints(endpoints.clone())
+        .with_egress_policy(EgressPolicy::from_config(&config.egress))
+        .with_server_clock(server_clock.clone())
+        .with_circuit_breaker(server_breaker.clone())
+        .with_metrics(network_metrics.clone())
+        .with_content_encryptor(ContentEncryptor::from_config(
+            &config.server.content_encryption,
+        )?);
         (
             Arc::new(GrpcApiAdapter::new(unified.clone(), http_fallback)),
             Arc::new(GrpcSseAdapter::new(unified)) as SseClientPort,
@@ -410,21 +543,41 @@ fn build_server_transports(
             token_manager.clone(),
             config.request_timeout(),
             &config.tls,
-        )?;
+        )?
+        .with_endpoints(endpoints.clone())
+        .with_egress_policy(EgressPolicy::from_config(&config.egress))
+        .with_server_clock(server_clock.clone())
+        .with_circuit_breaker(server_breaker.clone())
+        .with_metrics(network_metrics.clone())
+        .with_content_encryptor(ContentEncryptor::from_config(
+            &config.server.content_encryption,
+        )?);
         let sse_stream = SseStreamClient::new_with_tls(
             &config.server.base_url,
             token_manager,
             config.server.sse_max_retry_secs,
             &config.tls,
         )
-        .map_err(|e| anyhow::anyhow!("failed to build SSE client: {e}"))?;
+        .map_err(|e| anyhow::anyhow!("failed to build SSE client: {e}"))?
+        .with_endpoints(endpoints)
+        .with_reconnect_policy(SseReconnectPolicy::from_config(&config.server))
+        .with_egress_policy(EgressPolicy::from_config(&config.egress))
+        .with_circuit_breaker(server_breaker)
+        .with_metrics(network_metrics);
         (Arc::new(http_client), Arc::new(sse_stream) as SseClientPort)
     };
 
     // Build the suppression predicate: uploads are gated by the tracking schedule.
     // Uses snapshot() (O(1) Arc-clone) instead of get() (deep-clone of 37 sections)
     // per CONS-PI13 — the predicate is called on every flush, so hot-path cost matters.
-    let mut uploader = BatchUploader::new(api_client.clone(), session_id.to_string(), 100, 3);
+    let mut uploader = BatchUploader::new(api_client.clone(), session_id.to_string(), 100, 3)
+        .with_max_queue_size(config.monitor.upload_queue.backlog_cap)
+        .with_prioritization(config.monitor.upload_queue.prioritize)
+        .with_clock(server_clock.clone());
+    if let Some(storage) = storage.filter(|_| config.monitor.upload_queue.persist_idempotency_keys)
+    {
+        uploader = uploader.with_idempotency_key_store(storage);
+    }
     if let Some(mgr) = config_manager {
         let pred: Arc<dyn Fn() -> bool + Send + Sync> =
             Arc::new(move || crate::scheduler::tracking_schedule_active(&mgr.snapshot()));
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use oneshim_core::consent::ConsentManager;
+use oneshim_core::consent::{ConsentManager, ConsentRegistry};
 use oneshim_core::ports::coaching_storage::CoachingStoragePort;
 use oneshim_core::ports::session_context_store::SessionContextStorePort;
 use std::sync::Arc;
@@ -17,6 +17,7 @@ use crate::runtime_state::{
     CaptureContext, ConfigRuntimeState, ConnectionStatus, DetectionRuntimeState,
     ManagedStateBuilder, SuggestionRuntimeState,
 };
+use crate::scheduler::loop_health::LoopHealthRegistry;
 use crate::scheduler::shared_regime_state::SharedRegimeState;
 #[cfg(feature = "server")]
 use crate::server_runtime_context::ServerLaunchContext;
@@ -121,6 +122,9 @@ impl AppRuntimeLaunchBuilder {
             update_control.clone(),
         );
 
+        core_resources
+            .storage_runtime
+            .spawn_retention_follow(&handle, config_manager.subscribe());
         let sqlite_storage = core_resources.storage_runtime.sqlite_storage.clone();
         let encryption_key = core_resources.storage_runtime.encryption_key.clone();
         let event_tx = core_resources.background_runtime.event_tx();
@@ -158,14 +162,29 @@ impl AppRuntimeLaunchBuilder {
         // Focus mode state — transient, not persisted across restarts.
         let focus_mode = Arc::new(crate::focus_mode::FocusModeState::new());
 
+        // Metrics sampling rate — shared with the burst IPC commands.
+        let metrics_rate = Arc::new(crate::scheduler::metrics_rate::MetricsRate::new(
+            std::time::Duration::from_millis(config.monitor.metrics_interval_ms),
+        ));
+
         // Shared capture services are reused by scheduler and IPC commands so capture
         // semantics stay aligned across background monitoring and ad-hoc user actions.
+        // Per-capability consent, persisted next to the config file.
+        let consent_registry = Arc::new(ConsentRegistry::open(ConsentRegistry::path_next_to(
+            config_manager.config_path(),
+        )));
+
         let shared_capture_services = match handle.block_on(SharedCaptureServices::build(
             &data_dir_path,
             &config,
             encryption_key.clone(),
+            sqlite_storage.clone(),
+            consent_registry.clone(),
         )) {
-            Ok(services) => Some(Arc::new(services)),
+            Ok(services) => {
+                services.spawn_ocr_reset_on_reload(&handle, config_manager.subscribe());
+                Some(Arc::new(services))
+            }
             Err(error) => {
                 tracing::warn!("shared capture services init failed: {error}");
                 None
@@ -175,6 +194,12 @@ impl AppRuntimeLaunchBuilder {
             .as_ref()
             .map(|services| services.consent_manager.clone())
             .unwrap_or_else(|| Arc::new(ConsentManager::new(data_dir_path.join("consent.json"))));
+        // Installs predating the registry carry over their onboarding consent.
+        if let Some(record) = capture_consent_manager.current_consent() {
+            if let Err(e) = consent_registry.seed_from(&record.permissions) {
+                tracing::warn!("consent registry seed failed: {e}");
+            }
+        }
 
         // --- Phase 3 composition root ---
         //
@@ -202,7 +227,7 @@ impl AppRuntimeLaunchBuilder {
         let regime_storage: Arc<dyn oneshim_core::ports::regime_storage::RegimeStoragePort> =
             Arc::new(
                 oneshim_storage::regime_manager_state_store::SqliteRegimeManagerStateStore::new(
-                    sqlite_storage.connection_arc(),
+                    sqlite_storage.connection_pool(),
                 ),
             );
         {
@@ -241,7 +266,8 @@ impl AppRuntimeLaunchBuilder {
         // SSE-received suggestions appear in IPC queries via get_pending_suggestions.
         #[cfg(feature = "server")]
         let shared_suggestion_queue = Arc::new(tokio::sync::Mutex::new(
-            oneshim_suggestion::queue::SuggestionQueue::new(config.analysis.max_suggestions),
+            oneshim_suggestion::queue::SuggestionQueue::new(config.analysis.max_suggestions)
+                .with_overflow_policy(config.suggestions.overflow_policy),
         ));
 
         // Restore pending suggestions from SQLite into the queue.
@@ -273,8 +299,10 @@ impl AppRuntimeLaunchBuilder {
         #[cfg(feature = "server")]
         let suggestion_manager: Option<Arc<crate::suggestion_manager::SuggestionManager>> = {
             use oneshim_network::auth::TokenManager;
+            use oneshim_network::failover::ServerEndpoints;
             use oneshim_network::http_client::HttpApiClient;
 
+            let endpoints = Arc::new(ServerEndpoints::from_config(&config.server));
             #[allow(deprecated)] // Fallback to non-TLS TokenManager when TLS config unavailable
             let token_manager = Arc::new(
                 TokenManager::new_with_tls(
@@ -282,7 +310,8 @@ impl AppRuntimeLaunchBuilder {
                     &config.tls,
                     Some(config.request_timeout()),
                 )
-                .unwrap_or_else(|_| TokenManager::new(&config.server.base_url)),
+                .unwrap_or_else(|_| TokenManager::new(&config.server.base_url))
+                .with_endpoints(endpoints.clone()),
             );
 
             #[cfg(feature = "grpc")]
@@ -297,13 +326,26 @@ impl AppRuntimeLaunchBuilder {
                     &config.tls,
                 );
                 match (
-                    UnifiedClient::new(grpc_config, token_manager.clone()),
+                    UnifiedClient::new(grpc_config, token_manager.clone())
+                        .map(|unified| unified.with_endpoints(endpoints.clone())),
                     HttpApiClient::new_with_tls(
                         &config.server.base_url,
                         token_manager.clone(),
                         config.request_timeout(),
                         &config.tls,
-                    ),
+                    )
+                    .and_then(|client| {
+                        Ok(client
+                            .with_endpoints(endpoints.clone())
+                            .with_egress_policy(oneshim_network::egress::EgressPolicy::from_config(
+                                &config.egress,
+                            ))
+                            .with_content_encryptor(
+                                oneshim_network::content_crypto::ContentEncryptor::from_config(
+                                    &config.server.content_encryption,
+                                )?,
+                            ))
+                    }),
                 ) {
                     (Ok(unified), Ok(http_fallback)) => Ok(Arc::new(GrpcApiAdapter::new(
                         Arc::new(unified),
@@ -325,6 +367,17 @@ impl AppRuntimeLaunchBuilder {
                     config.request_timeout(),
                     &config.tls,
                 )
+                .and_then(|c| {
+                    Ok(c.with_endpoints(endpoints)
+                        .with_egress_policy(oneshim_network::egress::EgressPolicy::from_config(
+                            &config.egress,
+                        ))
+                        .with_content_encryptor(
+                            oneshim_network::content_crypto::ContentEncryptor::from_config(
+                                &config.server.content_encryption,
+                            )?,
+                        ))
+                })
                 .map(|c| Arc::new(c) as Arc<dyn oneshim_core::ports::api_client::ApiClient>)
                 .map_err(|e| anyhow::anyhow!("{e}"))
             };
@@ -476,6 +529,11 @@ impl AppRuntimeLaunchBuilder {
         // assembler) and Scheduler (monitor/coaching loops). Created before both consumers.
         let shared_regime_state = Arc::new(SharedRegimeState::new());
 
+        // Scheduler loop heartbeats — written by the scheduler, read by the dashboard.
+        let loop_health = Arc::new(LoopHealthRegistry::new(
+            config.monitor.watchdog.stall_multiplier,
+        ));
+
         // Obtain shutdown receiver for idle reaper before core_resources is consumed.
         let reaper_shutdown_rx = core_resources.background_runtime.shutdown_rx();
 
@@ -493,7 +551,7 @@ impl AppRuntimeLaunchBuilder {
             )
             .with_vector_store(Arc::new(
                 oneshim_storage::sqlite::vector_store_impl::SqliteVectorStore::new(
-                    sqlite_storage.connection_arc(),
+                    sqlite_storage.connection_pool(),
                 ),
             ));
             if let Some(ref capture_services) = shared_capture_services {
@@ -510,6 +568,7 @@ impl AppRuntimeLaunchBuilder {
                 .with_calibration_reader(sqlite_storage.clone())
                 .with_override_store(sqlite_storage.clone())
                 .with_consent_manager(capture_consent_manager.clone())
+                .with_consent_registry(consent_registry.clone())
                 .with_coaching_engine(coaching_engine.clone())
                 .with_coaching_storage(coaching_storage.clone())
                 .with_regime_handles(regime_manager_arc.clone(), regime_classifier_arc.clone())
@@ -520,7 +579,9 @@ impl AppRuntimeLaunchBuilder {
                 .with_capture_paused(capture_paused.clone())
                 .with_detection_active(detection_active.clone())
                 .with_focus_mode(focus_mode.clone())
+                .with_metrics_rate(metrics_rate.clone())
                 .with_shared_regime(shared_regime_state.clone())
+                .with_loop_health(loop_health.clone())
                 .with_health_flags(
                     server_health_flag.clone(),
                     llm_health_flag.clone(),
@@ -637,6 +698,23 @@ impl AppRuntimeLaunchBuilder {
             info!("idle reaper background task started");
         }
 
+        // App credentials live in the OS keychain or the encrypted file
+        // fallback; env/config values are imported on first use.
+        let app_secret_store = oneshim_core::config_manager::ConfigManager::config_dir()
+            .map_err(|e| e.to_string())
+            .and_then(|dir| {
+                crate::provider_secret_backend::create_app_secret_store(&dir)
+                    .map_err(|e| e.to_string())
+            })
+            .map_err(|e| tracing::warn!("app secret store unavailable: {e}"))
+            .ok();
+        let gui_ticket_hmac_secret = handle.block_on(crate::app_secrets::resolve_app_secret(
+            app_secret_store.as_ref(),
+            oneshim_core::ports::secret_store::GUI_TICKET_HMAC_SECRET_KEY,
+            std::env::var("ONESHIM_GUI_TICKET_HMAC_SECRET").ok(),
+            "ONESHIM_GUI_TICKET_HMAC_SECRET",
+        ));
+
         let automation_controller = if config.web.enabled {
             let launch_context = WebServerLaunchContext::new(
                 &handle,
@@ -650,7 +728,9 @@ impl AppRuntimeLaunchBuilder {
                 integration_runtime_status,
             )
             .with_app_handle(self.app_handle.clone())
-            .with_cli_health_flag(cli_health_flag.clone());
+            .with_cli_health_flag(cli_health_flag.clone())
+            .with_focus_mode(focus_mode.clone())
+            .with_gui_ticket_hmac_secret(gui_ticket_hmac_secret);
             let mut builder = WebServerRuntimeBuilder::new(
                 sqlite_storage.clone(),
                 &config,
@@ -660,6 +740,7 @@ impl AppRuntimeLaunchBuilder {
             )
             .with_override_store(sqlite_storage.clone())
             .with_recluster_requested(recluster_requested.clone())
+            .with_loop_health(loop_health.clone())
             .with_coaching_engine(
                 coaching_engine.clone() as Arc<dyn oneshim_core::ports::coaching::CoachingPort>
             );
@@ -668,7 +749,9 @@ impl AppRuntimeLaunchBuilder {
                     as Arc<dyn oneshim_core::ports::conversation_session::SessionManager>);
             }
             if let Some(ref capture_services) = shared_capture_services {
-                builder = builder.with_frame_storage(capture_services.frame_storage.clone());
+                builder = builder
+                    .with_frame_storage(capture_services.frame_storage.clone())
+                    .with_capture_latency(capture_services.capture_latency.clone());
             }
             // Task 7.1: pre-create LiveExternalConfig + ExternalMetrics Arcs so they can be
             // shared between the web server's DiagnosticsState (for live-config REST) and the
@@ -748,12 +831,19 @@ impl AppRuntimeLaunchBuilder {
                 let grpc_pii_sanitizer =
                     std::sync::Arc::new(oneshim_vision::privacy::VisionPiiSanitizer)
                         as std::sync::Arc<dyn oneshim_core::ports::pii_sanitizer::PiiSanitizer>;
+                let web_integration_auth_token =
+                    handle.block_on(crate::app_secrets::resolve_app_secret(
+                        app_secret_store.as_ref(),
+                        oneshim_core::ports::secret_store::WEB_INTEGRATION_AUTH_TOKEN_SECRET_KEY,
+                        config.web.integration_auth_token.clone(),
+                        "web.integration_auth_token",
+                    ));
                 let cfg = oneshim_web::grpc::GrpcSpawnConfig {
                     port: grpc_port,
                     storage: grpc_storage,
                     system_monitor: grpc_monitor,
                     event_tx: event_tx.clone(),
-                    integration_auth_token: config.web.integration_auth_token.clone(),
+                    integration_auth_token: web_integration_auth_token,
                     pii_sanitizer: Some(grpc_pii_sanitizer),
                     ai_runtime_status_snapshot: web_server_runtime.ai_runtime_status.clone(),
                     load_policy,
@@ -863,6 +953,56 @@ impl AppRuntimeLaunchBuilder {
             None
         };
 
+        // Server-pushed automation commands — executed through the controller
+        // so the same policy and audit checks apply.
+        #[cfg(feature = "server")]
+        if config.automation.command_channel_enabled {
+            if let Some(controller) = automation_controller.as_ref() {
+                use oneshim_core::error::CoreError;
+                use oneshim_network::auth::TokenManager;
+                use oneshim_network::ws_client::{
+                    automation_channel_url, executor_from_port, AutomationCommandChannel,
+                };
+
+                #[allow(deprecated)] // Fallback to non-TLS TokenManager when TLS config unavailable
+                let token_manager = Arc::new(
+                    TokenManager::new_with_tls(
+                        &config.server.base_url,
+                        &config.tls,
+                        Some(config.request_timeout()),
+                    )
+                    .unwrap_or_else(|_| TokenManager::new(&config.server.base_url)),
+                );
+                let channel = AutomationCommandChannel::new(
+                    &automation_channel_url(&config.server.base_url),
+                    executor_from_port(controller.clone()),
+                )
+                .with_token_manager(token_manager);
+                // The channel URL is derived from `server.base_url`, so that
+                // is the field to fix when it does not parse.
+                let checked = channel.validate_url().map_err(|error| match error {
+                    CoreError::Validation { code, message, .. } => CoreError::Validation {
+                        code,
+                        field: "server.base_url".to_string(),
+                        message,
+                    },
+                    other => other,
+                });
+                match checked {
+                    Ok(()) => {
+                        let channel_shutdown_rx = core_resources.background_runtime.shutdown_rx();
+                        handle.spawn(async move { channel.run(channel_shutdown_rx).await });
+                        info!("automation command channel started");
+                    }
+                    Err(error) => {
+                        tracing::warn!("automation command channel not started: {error}");
+                    }
+                }
+            } else {
+                tracing::warn!("automation command channel enabled but automation is unavailable");
+            }
+        }
+
         // Connection status is now driven by the health check loop —
         // no optimistic initialization. The loop reads adapter health flags
         // and updates connection flags as the single source of truth.
@@ -1099,8 +1239,11 @@ impl AppRuntimeLaunchBuilder {
                     server_connected,
                     llm_connected,
                     cli_connected,
+                    server_circuit: None,
                 },
                 focus_mode,
+                metrics_rate,
+                consent_registry,
                 capture: CaptureContext {
                     frame_processor: shared_capture_services
                         .as_ref()
@@ -1122,6 +1265,7 @@ impl AppRuntimeLaunchBuilder {
                 analysis_health,
                 regime_storage: Some(regime_storage.clone()),
                 regime_manager_snapshot: Some(regime_manager_arc.clone()),
+                network_metrics: None,
             },
             config_runtime_state,
         )
@@ -0,0 +1,114 @@
+//! App-level credentials (server tokens, signing keys) read through the
+//! [`SecretStore`] port.
+//!
+//! Values still found in env vars or plaintext config are imported into the
+//! store on first use so the plaintext source can be removed.
+
+use std::sync::Arc;
+
+use oneshim_core::ports::secret_store::{SecretStore, APP_CREDENTIALS_SECRET_NAMESPACE};
+use tracing::{info, warn};
+
+/// Stored value for `key`, else `legacy` (imported into the store).
+pub(crate) async fn resolve_app_secret(
+    store: Option<&Arc<dyn SecretStore>>,
+    key: &str,
+    legacy: Option<String>,
+    legacy_source: &str,
+) -> Option<String> {
+    let legacy = legacy.filter(|value| !value.trim().is_empty());
+    let Some(store) = store else {
+        return legacy;
+    };
+
+    match store.retrieve(APP_CREDENTIALS_SECRET_NAMESPACE, key).await {
+        Ok(Some(value)) => return Some(value),
+        Ok(None) => {}
+        Err(e) => {
+            warn!(key, "app secret lookup failed: {e}");
+            return legacy;
+        }
+    }
+
+    let value = legacy?;
+    match store
+        .store(APP_CREDENTIALS_SECRET_NAMESPACE, key, &value)
+        .await
+    {
+        Ok(()) => info!(
+            key,
+            source = legacy_source,
+            "imported plaintext secret into the secret store; remove it from {legacy_source}"
+        ),
+        Err(e) => warn!(
+            key,
+            source = legacy_source,
+            "plaintext secret import failed: {e}"
+        ),
+    }
+    Some(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oneshim_storage::encryption::EncryptionKey;
+    use oneshim_storage::file_secret_store::FileSecretStore;
+
+    fn store(dir: &std::path::Path) -> Arc<dyn SecretStore> {
+        Arc::new(
+            FileSecretStore::encrypted(
+                dir.join("secrets.json"),
+                EncryptionKey::from_bytes([0x33; 32]),
+            )
+            .unwrap(),
+        )
+    }
+
+    #[tokio::test]
+    async fn stored_value_wins_over_plaintext_source() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+        store
+            .store(APP_CREDENTIALS_SECRET_NAMESPACE, "token", "stored")
+            .await
+            .unwrap();
+
+        let value =
+            resolve_app_secret(Some(&store), "token", Some("from-env".to_string()), "env").await;
+        assert_eq!(value.as_deref(), Some("stored"));
+    }
+
+    #[tokio::test]
+    async fn plaintext_value_is_imported_once() {
+        let dir = tempfile::tempdir().unwrap();
+        let store = store(dir.path());
+
+        let value =
+            resolve_app_secret(Some(&store), "token", Some("from-env".to_string()), "env").await;
+        assert_eq!(value.as_deref(), Some("from-env"));
+        assert_eq!(
+            store
+                .retrieve(APP_CREDENTIALS_SECRET_NAMESPACE, "token")
+                .await
+                .unwrap()
+                .as_deref(),
+            Some("from-env")
+        );
+        assert_eq!(
+            resolve_app_secret(Some(&store), "token", None, "env")
+                .await
+                .as_deref(),
+            Some("from-env")
+        );
+    }
+
+    #[tokio::test]
+    async fn without_store_or_value_there_is_no_secret() {
+        assert!(
+            resolve_app_secret(None, "token", Some(" ".to_string()), "env")
+                .await
+                .is_none()
+        );
+    }
+}
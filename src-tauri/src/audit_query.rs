@@ -12,7 +12,7 @@
 use std::sync::Arc;
 
 use oneshim_automation::audit::AuditQuery;
-use oneshim_core::models::audit::AuditEntry;
+use oneshim_core::models::audit::{AuditEntry, AuditFilter, AuditPage, AuditQueryResult};
 use oneshim_storage::sqlite::SqliteStorage;
 
 /// SQLite-backed implementation of [`AuditQuery`].
@@ -33,4 +33,8 @@ impl AuditQuery for SqliteAuditQuery {
     fn entries_by_command_id(&self, command_id: &str, limit: usize) -> Vec<AuditEntry> {
         self.storage.entries_by_command_id(command_id, limit)
     }
+
+    fn query(&self, filter: &AuditFilter, page: AuditPage) -> Option<AuditQueryResult> {
+        Some(self.storage.query_audit_entries(filter, page))
+    }
 }
@@ -3,6 +3,7 @@ use std::sync::Arc;
 
 use oneshim_automation::audit::AuditLogger;
 use oneshim_automation::controller::AutomationController;
+use oneshim_automation::outcome_capture::OutcomeCapture;
 use oneshim_automation::policy::PolicyClient;
 use oneshim_automation::sandbox::create_platform_sandbox;
 use oneshim_core::config::{AiAccessMode, AiProviderConfig, AppConfig};
@@ -11,12 +12,14 @@ use oneshim_core::ports::skill_loader::SkillLoader;
 use oneshim_core::ports::{oauth::OAuthPort, secret_store::SecretStoreSet};
 use oneshim_monitor::process::ProcessTracker;
 use oneshim_storage::frame_storage::FrameFileStorage;
+use oneshim_storage::sqlite::SqliteStorage;
 use oneshim_web::AiRuntimeStatus;
 use tokio::runtime::Handle;
 use tokio::sync::RwLock;
-use tracing::{error, info, warn};
+use tracing::{debug, error, info, warn};
 
 use crate::automation_runtime::{build_automation_runtime, build_noop_intent_executor};
+use crate::focus_mode::{DeferredInterruption, FocusModeState};
 use crate::provider_adapters::ExternalOcrPrivacyGuard;
 
 #[derive(Clone)]
@@ -31,8 +34,11 @@ pub(crate) struct AutomationControllerBuilder<'a> {
     _runtime_handle: &'a Handle,
     audit_logger: Arc<RwLock<AuditLogger>>,
     frame_storage: Option<Arc<FrameFileStorage>>,
+    frame_index: Option<Arc<SqliteStorage>>,
     app_handle: Option<tauri::AppHandle>,
     cli_health_flag: Option<Arc<std::sync::atomic::AtomicBool>>,
+    focus_mode: Option<Arc<FocusModeState>>,
+    gui_ticket_hmac_secret: Option<String>,
     #[cfg(feature = "server")]
     provider_secret_stores: Option<SecretStoreSet>,
     #[cfg(feature = "server")]
@@ -53,8 +59,11 @@ impl<'a> AutomationControllerBuilder<'a> {
             _runtime_handle: runtime_handle,
             audit_logger,
             frame_storage,
+            frame_index: None,
             app_handle: None,
             cli_health_flag: None,
+            focus_mode: None,
+            gui_ticket_hmac_secret: None,
             #[cfg(feature = "server")]
             provider_secret_stores: None,
             #[cfg(feature = "server")]
@@ -67,6 +76,25 @@ impl<'a> AutomationControllerBuilder<'a> {
         self
     }
 
+    /// Confirmation prompts are not shown while focus mode is active; they
+    /// lapse as denied and are listed in the focus digest.
+    pub(crate) fn with_focus_mode(mut self, focus_mode: Arc<FocusModeState>) -> Self {
+        self.focus_mode = Some(focus_mode);
+        self
+    }
+
+    /// Signing key for GUI execution tickets, resolved from the secret store.
+    pub(crate) fn with_gui_ticket_hmac_secret(mut self, secret: Option<String>) -> Self {
+        self.gui_ticket_hmac_secret = secret;
+        self
+    }
+
+    /// Frame metadata index used to store before/after automation screenshots.
+    pub(crate) fn with_frame_index(mut self, index: Arc<SqliteStorage>) -> Self {
+        self.frame_index = Some(index);
+        self
+    }
+
     #[cfg(feature = "server")]
     pub(crate) fn with_provider_secret_stores(mut self, secret_stores: SecretStoreSet) -> Self {
         self.provider_secret_stores = Some(secret_stores);
@@ -102,6 +130,7 @@ impl<'a> AutomationControllerBuilder<'a> {
             Some(self.audit_logger.clone()),
         );
         let skill_loader = discover_skill_loader();
+        let outcome_capture = self.outcome_capture();
 
         #[cfg(feature = "server")]
         let runtime = preflight_provider_oauth_connection(
@@ -146,6 +175,9 @@ impl<'a> AutomationControllerBuilder<'a> {
                     runtime,
                     self.app_handle,
                     self.cli_health_flag.clone(),
+                    self.focus_mode.clone(),
+                    self.gui_ticket_hmac_secret.clone(),
+                    outcome_capture,
                 ))),
             },
             Err(err) => {
@@ -162,6 +194,7 @@ impl<'a> AutomationControllerBuilder<'a> {
                         controller: Some(Arc::new(build_noop_controller(
                             self.config,
                             self.audit_logger,
+                            outcome_capture,
                         ))),
                     };
                 }
@@ -185,6 +218,45 @@ impl<'a> AutomationControllerBuilder<'a> {
     }
 }
 
+impl AutomationControllerBuilder<'_> {
+    /// Before/after step screenshots, when `automation.capture_step_frames`
+    /// is on and both frame storage and the frame index are available.
+    fn outcome_capture(&self) -> Option<Arc<dyn OutcomeCapture>> {
+        if !self.config.automation.capture_step_frames {
+            return None;
+        }
+        let (Some(frame_storage), Some(index)) =
+            (self.frame_storage.clone(), self.frame_index.clone())
+        else {
+            warn!("automation step capture enabled but frame storage is unavailable");
+            return None;
+        };
+        let ocr_tessdata = std::env::var("ONESHIM_TESSDATA")
+            .ok()
+            .map(std::path::PathBuf::from);
+        let frame_processor = Arc::new(
+            oneshim_vision::processor::EdgeFrameProcessor::new(
+                self.config.vision.thumbnail_width,
+                self.config.vision.thumbnail_height,
+                ocr_tessdata,
+            )
+            .with_ocr_downscale_width(self.config.vision.ocr_downscale_width)
+            .with_notification_mask(
+                oneshim_vision::notification_mask::NotificationMask::from_config(
+                    &self.config.privacy,
+                ),
+            ),
+        );
+        Some(Arc::new(
+            crate::automation_outcome_capture::FrameOutcomeCapture::new(
+                frame_processor,
+                frame_storage,
+                index,
+            ),
+        ))
+    }
+}
+
 fn discover_skill_loader() -> Option<Arc<dyn SkillLoader>> {
     let mut roots = Vec::new();
     if let Some(home) = directories::BaseDirs::new() {
@@ -198,6 +270,7 @@ fn discover_skill_loader() -> Option<Arc<dyn SkillLoader>> {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn build_controller_from_runtime(
     config: &AppConfig,
     audit_logger: Arc<RwLock<AuditLogger>>,
@@ -205,6 +278,9 @@ fn build_controller_from_runtime(
     runtime: crate::automation_runtime::AutomationRuntime,
     app_handle: Option<tauri::AppHandle>,
     cli_health_flag: Option<Arc<std::sync::atomic::AtomicBool>>,
+    focus_mode: Option<Arc<FocusModeState>>,
+    hmac_secret: Option<String>,
+    outcome_capture: Option<Arc<dyn OutcomeCapture>>,
 ) -> AutomationController {
     // Clone handle early so we can wire the confirmation callback after
     // the overlay driver consumes the original.
@@ -215,7 +291,7 @@ fn build_controller_from_runtime(
         llm = runtime.llm_provider_name,
         "AI provider adapters resolved"
     );
-    let policy_client = Arc::new(PolicyClient::new());
+    let policy_client = Arc::new(build_policy_client(config));
     let sandbox = create_platform_sandbox(&config.automation.sandbox);
     let mut controller = if let Some(flag) = cli_health_flag {
         AutomationController::new(
@@ -234,9 +310,13 @@ fn build_controller_from_runtime(
         )
     };
     controller.set_enabled(true);
+    controller.set_allowed_hours(config.automation.allowed_hours.clone());
     controller.set_scene_finder(runtime.element_finder.clone());
     controller.set_intent_executor(runtime.intent_executor);
     controller.set_intent_planner(runtime.intent_planner);
+    if let Some(capture) = outcome_capture {
+        controller.set_outcome_capture(capture);
+    }
 
     let focus_probe: Arc<dyn oneshim_core::ports::focus_probe::FocusProbe> = Arc::new(
         crate::focus_probe_adapter::ProcessMonitorFocusProbe::new(process_monitor),
@@ -253,7 +333,6 @@ fn build_controller_from_runtime(
             crate::platform_overlay::create_platform_overlay_driver()
         };
 
-    let hmac_secret = std::env::var("ONESHIM_GUI_TICKET_HMAC_SECRET").ok();
     if let Err(error) =
         controller.configure_gui_interaction(focus_probe, overlay_driver, hmac_secret)
     {
@@ -265,6 +344,18 @@ fn build_controller_from_runtime(
         let handle_clone = handle.clone();
         controller = controller.with_confirmation_callback(Arc::new(move |confirmation| {
             use tauri::Emitter;
+            if let Some(ref focus_mode) = focus_mode {
+                if focus_mode.is_active() {
+                    debug!(
+                        command_id = %confirmation.command_id,
+                        "automation confirmation held back by focus mode"
+                    );
+                    focus_mode.defer(DeferredInterruption::AutomationPrompt {
+                        process_name: confirmation.process_name,
+                    });
+                    return;
+                }
+            }
             let _ = handle_clone.emit("automation:confirm-request", &confirmation);
         }));
     }
@@ -272,11 +363,19 @@ fn build_controller_from_runtime(
     controller
 }
 
+fn build_policy_client(config: &AppConfig) -> PolicyClient {
+    match config.automation.policy_cache_ttl_secs {
+        Some(ttl_seconds) => PolicyClient::new().with_cache_ttl(ttl_seconds),
+        None => PolicyClient::new(),
+    }
+}
+
 fn build_noop_controller(
     config: &AppConfig,
     audit_logger: Arc<RwLock<AuditLogger>>,
+    outcome_capture: Option<Arc<dyn OutcomeCapture>>,
 ) -> AutomationController {
-    let policy_client = Arc::new(PolicyClient::new());
+    let policy_client = Arc::new(build_policy_client(config));
     let sandbox = create_platform_sandbox(&config.automation.sandbox);
     let mut controller = AutomationController::new(
         policy_client,
@@ -285,7 +384,11 @@ fn build_noop_controller(
         config.automation.sandbox.clone(),
     );
     controller.set_enabled(true);
+    controller.set_allowed_hours(config.automation.allowed_hours.clone());
     controller.set_intent_executor(build_noop_intent_executor());
+    if let Some(capture) = outcome_capture {
+        controller.set_outcome_capture(capture);
+    }
     controller
 }
 
@@ -0,0 +1,79 @@
+//! `OutcomeCapture` adapter — before/after automation screenshots stored as
+//! regular frames so they show up in the timeline and can be audited.
+
+use std::sync::Arc;
+
+use async_trait::async_trait;
+use base64::{engine::general_purpose::STANDARD as BASE64, Engine as _};
+use oneshim_automation::outcome_capture::OutcomeCapture;
+use oneshim_core::error::CoreError;
+use oneshim_core::models::frame::ImagePayload;
+use oneshim_core::ports::vision::{CaptureRequest, FrameProcessor};
+use oneshim_storage::frame_storage::FrameFileStorage;
+use oneshim_storage::sqlite::SqliteStorage;
+
+const TRIGGER_TYPE: &str = "automation_outcome";
+
+pub(crate) struct FrameOutcomeCapture {
+    frame_processor: Arc<dyn FrameProcessor>,
+    frame_storage: Arc<FrameFileStorage>,
+    index: Arc<SqliteStorage>,
+}
+
+impl FrameOutcomeCapture {
+    pub(crate) fn new(
+        frame_processor: Arc<dyn FrameProcessor>,
+        frame_storage: Arc<FrameFileStorage>,
+        index: Arc<SqliteStorage>,
+    ) -> Self {
+        Self {
+            frame_processor,
+            frame_storage,
+            index,
+        }
+    }
+}
+
+#[async_trait]
+impl OutcomeCapture for FrameOutcomeCapture {
+    async fn capture_frame(&self, label: &str) -> Result<i64, CoreError> {
+        let frame = self
+            .frame_processor
+            .capture_and_process(&CaptureRequest {
+                trigger_type: TRIGGER_TYPE.to_string(),
+                importance: 1.0,
+                app_name: "automation".to_string(),
+                window_title: label.to_string(),
+                window_bounds: None,
+            })
+            .await?;
+
+        let (image_bytes, ocr_text) = match &frame.image_payload {
+            Some(ImagePayload::Full { data, ocr_text, .. }) => {
+                (BASE64.decode(data).ok(), ocr_text.clone())
+            }
+            _ => (None, None),
+        };
+        let file_path = match image_bytes {
+            Some(bytes) => Some(
+                self.frame_storage
+                    .save_frame(frame.metadata.timestamp, &bytes)
+                    .await?
+                    .to_string_lossy()
+                    .to_string(),
+            ),
+            None => None,
+        };
+
+        let index = self.index.clone();
+        tokio::task::spawn_blocking(move || {
+            index.save_frame_metadata(&frame.metadata, file_path.as_deref(), ocr_text.as_deref())
+        })
+        .await
+        .map_err(|e| CoreError::Internal {
+            code: oneshim_core::error_codes::InternalCode::Generic,
+            message: format!("frame metadata task failed: {e}"),
+        })?
+        .map_err(CoreError::from)
+    }
+}
@@ -82,15 +82,25 @@ impl BootstrapRuntimeBundle {
 
 pub(crate) struct BootstrapRuntimeBuilder {
     data_dir_override: Option<PathBuf>,
+    profile: Option<String>,
 }
 
 impl BootstrapRuntimeBuilder {
     pub(crate) fn new() -> Self {
         Self {
             data_dir_override: None,
+            profile: None,
         }
     }
 
+    /// Apply config profile `profile` (from `--profile <name>`) at startup.
+    /// It is persisted as `active_profile`, so later launches without the
+    /// flag keep it.
+    pub(crate) fn with_profile(mut self, profile: Option<String>) -> Self {
+        self.profile = profile;
+        self
+    }
+
     #[cfg(test)]
     pub(crate) fn with_data_dir_override(mut self, data_dir_override: PathBuf) -> Self {
         self.data_dir_override = Some(data_dir_override);
@@ -115,6 +125,17 @@ impl BootstrapRuntimeBuilder {
             }
         };
         info!("settings file: {:?}", config_manager.config_path());
+        if let Some(profile) = self.profile.as_deref() {
+            match config_manager.update_with(|config| config.apply_profile(profile)) {
+                Ok(_) => info!(profile, "config profile applied"),
+                Err(error) => warn!(profile, "config profile not applied: {error}"),
+            }
+        } else if let Some(profile) = config_manager.get().active_profile {
+            info!(profile, "config profile active from last run");
+        }
+        if let Err(error) = config_manager.watch() {
+            warn!("settings hot-reload unavailable: {error}");
+        }
 
         let background_runtime = spawn_background_runtime()?;
         let runtime_handle = background_runtime.handle();
@@ -155,7 +176,21 @@ impl BootstrapRuntimeBuilder {
     }
 }
 
-fn resolve_db_path(data_dir: Option<&Path>) -> PathBuf {
+/// Value of `--profile <name>` or `--profile=<name>` in the launch args.
+pub(crate) fn profile_arg(args: &[String]) -> Option<String> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        if arg == "--profile" {
+            return args.next().cloned();
+        }
+        if let Some(name) = arg.strip_prefix("--profile=") {
+            return Some(name.to_string());
+        }
+    }
+    None
+}
+
+pub(crate) fn resolve_db_path(data_dir: Option<&Path>) -> PathBuf {
     data_dir
         .map(|directory| directory.join("oneshim.db"))
         .or_else(|| {
@@ -204,6 +239,21 @@ mod tests {
         }
     }
 
+    #[test]
+    fn profile_arg_accepts_both_spellings() {
+        let args = |list: &[&str]| list.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        assert_eq!(
+            profile_arg(&args(&["oneshim", "--profile", "focus"])).as_deref(),
+            Some("focus")
+        );
+        assert_eq!(
+            profile_arg(&args(&["oneshim", "--profile=at-office"])).as_deref(),
+            Some("at-office")
+        );
+        assert_eq!(profile_arg(&args(&["oneshim", "--profile"])), None);
+        assert_eq!(profile_arg(&args(&["oneshim"])), None);
+    }
+
     #[test]
     fn resolve_db_path_default() {
         let path = resolve_db_path(None);
@@ -3,14 +3,18 @@ use std::sync::Arc;
 
 use anyhow::Result;
 use oneshim_core::config::AppConfig;
-use oneshim_core::consent::ConsentManager;
+use oneshim_core::consent::{ConsentManager, ConsentRegistry};
+use oneshim_core::models::capture_latency::CaptureLatencyRecorder;
 use oneshim_core::ports::accessibility::AccessibilityExtractor;
 use oneshim_core::ports::frame_storage::FrameStoragePort;
 use oneshim_core::ports::monitor::{ActivityMonitor, ProcessMonitor};
 use oneshim_core::ports::vision::FrameProcessor;
 use oneshim_storage::encryption::EncryptionKey;
 use oneshim_storage::frame_storage::FrameFileStorage;
+use oneshim_storage::sqlite::SqliteStorage;
+use oneshim_vision::ocr_gate::OcrGate;
 use oneshim_vision::processor::EdgeFrameProcessor;
+use tokio::sync::watch;
 
 pub(crate) struct SharedCaptureServices {
     pub(crate) frame_storage: Arc<dyn FrameStoragePort>,
@@ -19,6 +23,11 @@ pub(crate) struct SharedCaptureServices {
     pub(crate) frame_processor: Arc<dyn FrameProcessor>,
     pub(crate) accessibility_extractor: Option<Arc<dyn AccessibilityExtractor>>,
     pub(crate) consent_manager: Arc<ConsentManager>,
+    /// Disabled by the processor when the OCR engine fails to start.
+    pub(crate) ocr_gate: Arc<OcrGate>,
+    /// Capture/encode/OCR timings recorded by the processor; read by the
+    /// web diagnostics and snapshotted hourly by the scheduler.
+    pub(crate) capture_latency: Arc<CaptureLatencyRecorder>,
 }
 
 impl SharedCaptureServices {
@@ -26,6 +35,8 @@ impl SharedCaptureServices {
         data_dir: &Path,
         config: &AppConfig,
         encryption_key: Option<Arc<EncryptionKey>>,
+        frame_index: Arc<SqliteStorage>,
+        consent_registry: Arc<ConsentRegistry>,
     ) -> Result<Self> {
         let frame_storage = Arc::new(
             FrameFileStorage::with_encryption(
@@ -34,21 +45,41 @@ impl SharedCaptureServices {
                 config.storage.retention_days,
                 encryption_key,
             )
-            .await?,
+            .await?
+            .with_shard_granularity(config.storage.frame_shard_granularity)
+            .with_keep_tags(frame_index, config.storage.retention_keep_tags.clone())
+            .with_disk_thresholds(
+                config.storage.disk_critical_free_mb,
+                config.storage.disk_resume_free_mb,
+            ),
         );
 
-        let process_monitor: Arc<dyn ProcessMonitor> =
-            Arc::new(oneshim_monitor::process::ProcessTracker::new());
+        let process_monitor: Arc<dyn ProcessMonitor> = Arc::new(
+            oneshim_monitor::process::ProcessTracker::new()
+                .with_filter(config.monitor.process_filter()),
+        );
         let activity_monitor: Arc<dyn ActivityMonitor> = Arc::new(
             oneshim_monitor::activity::ActivityTracker::new(process_monitor.clone()),
         );
 
         let ocr_tessdata = std::env::var("ONESHIM_TESSDATA").ok().map(PathBuf::from);
-        let frame_processor: Arc<dyn FrameProcessor> = Arc::new(EdgeFrameProcessor::new(
+        let capture_latency = Arc::new(CaptureLatencyRecorder::default());
+        let edge_processor = EdgeFrameProcessor::new(
             config.vision.thumbnail_width,
             config.vision.thumbnail_height,
             ocr_tessdata,
-        ));
+        )
+        .with_ocr_downscale_width(config.vision.ocr_downscale_width)
+        .with_notification_mask(
+            oneshim_vision::notification_mask::NotificationMask::from_config(&config.privacy),
+        )
+        .with_privacy_preview(
+            oneshim_vision::privacy_preview::PrivacyPreview::from_config(&config.privacy),
+        )
+        .with_latency_recorder(Some(capture_latency.clone()))
+        .with_consent_registry(Some(consent_registry));
+        let ocr_gate = edge_processor.ocr_gate();
+        let frame_processor: Arc<dyn FrameProcessor> = Arc::new(edge_processor);
 
         Ok(Self {
             frame_storage,
@@ -57,6 +88,23 @@ impl SharedCaptureServices {
             frame_processor,
             accessibility_extractor: oneshim_vision::accessibility::create_extractor(),
             consent_manager: Arc::new(ConsentManager::new(data_dir.join("consent.json"))),
+            ocr_gate,
+            capture_latency,
         })
     }
+
+    /// Retry OCR engine init after every config reload, so fixing the OCR
+    /// setup does not require restarting the app.
+    pub(crate) fn spawn_ocr_reset_on_reload(
+        &self,
+        runtime: &tokio::runtime::Handle,
+        mut config_rx: watch::Receiver<Arc<AppConfig>>,
+    ) {
+        let ocr_gate = self.ocr_gate.clone();
+        runtime.spawn(async move {
+            while config_rx.changed().await.is_ok() {
+                ocr_gate.reset();
+            }
+        });
+    }
 }
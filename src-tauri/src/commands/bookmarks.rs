@@ -0,0 +1,105 @@
+use oneshim_core::models::bookmark::Bookmark;
+use oneshim_core::ports::bookmark_storage::BookmarkStorage;
+use tauri::{command, AppHandle, Emitter, Manager};
+
+use crate::commands::capture::{active_window_context, capture_and_persist};
+use crate::ipc_error::IpcError;
+use crate::runtime_state::{AppState, ConfigRuntimeState};
+
+/// Record a bookmark at the current moment, optionally labelled.
+///
+/// Called from the label prompt after the bookmark hotkey, or from the UI.
+#[command]
+pub async fn record_bookmark(app: AppHandle, label: Option<String>) -> Result<Bookmark, IpcError> {
+    record_bookmark_now(&app, label).await
+}
+
+/// Shared by the IPC command and the global hotkey: snapshot the active
+/// window, capture a frame when `vision.bookmarks.capture_frame` is on and
+/// capture is not paused, persist the bookmark and emit `bookmark:recorded`.
+pub(crate) async fn record_bookmark_now(
+    app: &AppHandle,
+    label: Option<String>,
+) -> Result<Bookmark, IpcError> {
+    let state = app
+        .try_state::<AppState>()
+        .ok_or_else(|| IpcError::new("service.unavailable", "App state not ready"))?;
+    let capture_frame = app
+        .try_state::<ConfigRuntimeState>()
+        .map(|config| config.config_manager().get().vision.bookmarks.capture_frame)
+        .unwrap_or(state.config.vision.bookmarks.capture_frame);
+
+    let (app_name, window_title) = active_window_context(&state).await;
+    let paused = state
+        .capture_paused
+        .load(std::sync::atomic::Ordering::Relaxed);
+    let frame_id = if capture_frame && !paused {
+        match capture_and_persist(&state, "bookmark", app_name.clone(), window_title.clone()).await
+        {
+            Ok(capture) => capture.frame_id.and_then(|id| id.parse::<i64>().ok()),
+            Err(e) => {
+                tracing::warn!("Bookmark frame capture failed: {e}");
+                None
+            }
+        }
+    } else {
+        None
+    };
+
+    let bookmark = build_bookmark(label, &app_name, &window_title, frame_id);
+    let storage = state.storage.clone();
+    let to_save = bookmark.clone();
+    tokio::task::block_in_place(|| storage.save_bookmark(&to_save)).map_err(IpcError::from)?;
+
+    if let Err(e) = app.emit_to("main", "bookmark:recorded", &bookmark) {
+        tracing::debug!("bookmark:recorded emit failed: {e}");
+    }
+    Ok(bookmark)
+}
+
+/// Placeholder window context (`"unknown"` app, empty title) is stored as
+/// absent rather than as literal values.
+fn build_bookmark(
+    label: Option<String>,
+    app_name: &str,
+    window_title: &str,
+    frame_id: Option<i64>,
+) -> Bookmark {
+    let mut bookmark = Bookmark::new(label);
+    bookmark.frame_id = frame_id;
+    bookmark.app_name = Some(app_name)
+        .filter(|name| !name.is_empty() && *name != "unknown")
+        .map(str::to_string);
+    bookmark.window_title = Some(window_title)
+        .filter(|title| !title.is_empty())
+        .map(str::to_string);
+    bookmark
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_bookmark_keeps_label_and_window_context() {
+        let bookmark = build_bookmark(
+            Some("bug reproduced here".to_string()),
+            "Safari",
+            "Checkout — Staging",
+            Some(7),
+        );
+        assert_eq!(bookmark.label.as_deref(), Some("bug reproduced here"));
+        assert_eq!(bookmark.app_name.as_deref(), Some("Safari"));
+        assert_eq!(bookmark.window_title.as_deref(), Some("Checkout — Staging"));
+        assert_eq!(bookmark.frame_id, Some(7));
+    }
+
+    #[test]
+    fn build_bookmark_drops_placeholder_context() {
+        let bookmark = build_bookmark(None, "unknown", "", None);
+        assert!(bookmark.label.is_none());
+        assert!(bookmark.app_name.is_none());
+        assert!(bookmark.window_title.is_none());
+        assert!(bookmark.frame_id.is_none());
+    }
+}
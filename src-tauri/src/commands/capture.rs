@@ -65,14 +65,14 @@ pub struct ManualCaptureResponse {
 pub async fn trigger_manual_capture(
     state: tauri::State<'_, AppState>,
 ) -> Result<ManualCaptureResponse, IpcError> {
-    let frame_processor = state
-        .capture
-        .frame_processor
-        .as_ref()
-        .ok_or_else(|| IpcError::new("service.unavailable", "Capture not available"))?;
+    let (app_name, window_title) = active_window_context(&state).await;
+    capture_and_persist(&state, "manual", app_name, window_title).await
+}
 
-    // Get current window context for CaptureRequest
-    let (app_name, window_title) = if let Some(ref monitor) = state.capture.activity_monitor {
+/// Current foreground app name and window title, or `("unknown", "")` when
+/// the activity monitor is unavailable.
+pub(crate) async fn active_window_context(state: &AppState) -> (String, String) {
+    if let Some(ref monitor) = state.capture.activity_monitor {
         match monitor.collect_context().await {
             Ok(ctx) => match ctx.active_window {
                 Some(ref w) => (w.app_name.clone(), w.title.clone()),
@@ -82,10 +82,25 @@ pub async fn trigger_manual_capture(
         }
     } else {
         ("unknown".to_string(), String::new())
-    };
+    }
+}
+
+/// Capture one frame, save its image and metadata, and flash the overlay.
+/// Shared by the manual-capture command and the bookmark hotkey.
+pub(crate) async fn capture_and_persist(
+    state: &AppState,
+    trigger_type: &str,
+    app_name: String,
+    window_title: String,
+) -> Result<ManualCaptureResponse, IpcError> {
+    let frame_processor = state
+        .capture
+        .frame_processor
+        .as_ref()
+        .ok_or_else(|| IpcError::new("service.unavailable", "Capture not available"))?;
 
     let request = CaptureRequest {
-        trigger_type: "manual".to_string(),
+        trigger_type: trigger_type.to_string(),
         importance: 1.0,
         app_name,
         window_title,
@@ -56,6 +56,8 @@ pub struct ConnectionStatusResponse {
     pub server: bool,
     pub llm: bool,
     pub cli: bool,
+    /// Seconds until the next server attempt while the circuit breaker is open.
+    pub server_retry_in_secs: Option<u64>,
 }
 
 #[command]
@@ -150,6 +152,7 @@ pub async fn get_connection_status(
         server: state.connection.server_connected.load(Ordering::Relaxed),
         llm: state.connection.llm_connected.load(Ordering::Relaxed),
         cli: state.connection.cli_connected.load(Ordering::Relaxed),
+        server_retry_in_secs: crate::tray::server_retry_in_secs(&state.connection),
     })
 }
 
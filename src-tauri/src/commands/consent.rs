@@ -0,0 +1,47 @@
+use oneshim_core::consent::{Capability, CapabilityConsent};
+use serde::Serialize;
+use tauri::{command, State};
+
+use crate::ipc_error::IpcError;
+use crate::runtime_state::AppState;
+
+#[derive(Serialize)]
+pub struct CapabilityConsentStatus {
+    pub required_version: u32,
+    pub records: Vec<CapabilityConsent>,
+    /// Capabilities to ask about: never decided, or decided under an older version.
+    pub pending: Vec<Capability>,
+}
+
+#[command]
+pub async fn get_capability_consents(
+    state: State<'_, AppState>,
+) -> Result<CapabilityConsentStatus, IpcError> {
+    let registry = &state.consent_registry;
+    Ok(CapabilityConsentStatus {
+        required_version: registry.required_version(),
+        records: registry.records(),
+        pending: registry.pending_prompts(),
+    })
+}
+
+/// Grant or revoke one capability. Takes effect on the next capture or
+/// monitoring tick; no restart needed.
+#[command]
+pub async fn set_capability_consent(
+    state: State<'_, AppState>,
+    capability: Capability,
+    granted: bool,
+) -> Result<CapabilityConsent, IpcError> {
+    let record = state
+        .consent_registry
+        .set(capability, granted)
+        .map_err(IpcError::from)?;
+    tracing::info!(
+        capability = capability.as_str(),
+        granted,
+        version = record.version,
+        "capability consent changed"
+    );
+    Ok(record)
+}
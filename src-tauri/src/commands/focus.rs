@@ -10,6 +10,8 @@ pub struct FocusModeResponse {
     pub auto: bool,
     pub remaining_minutes: Option<u32>,
     pub activated_at: Option<String>,
+    /// Interruptions held back so far; delivered as a digest when focus ends.
+    pub deferred_count: usize,
 }
 
 #[command]
@@ -43,5 +45,6 @@ pub async fn get_focus_mode_status(
         auto: state.focus_mode.is_auto_activated(),
         remaining_minutes: state.focus_mode.remaining_minutes(),
         activated_at: state.focus_mode.activated_at().map(|t| t.to_rfc3339()),
+        deferred_count: state.focus_mode.deferred_count(),
     })
 }
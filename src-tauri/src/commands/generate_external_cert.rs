@@ -154,7 +154,7 @@ mod tests {
 
 /// CLI entry point for the `generate-external-cert` subcommand.
 ///
-/// Invoked from `src-tauri/src/main.rs` before Tauri initialization if
+/// Invoked from `src-tauri/src/lib.rs` before Tauri initialization if
 /// `std::env::args().nth(1)` is `"generate-external-cert"`. Exits the
 /// process with code 0 on success or 1 on failure.
 #[cfg(feature = "external-grpc-tools")]
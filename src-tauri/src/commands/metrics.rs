@@ -0,0 +1,58 @@
+use std::time::{Duration, Instant};
+
+use chrono::Utc;
+use serde::Serialize;
+use tauri::command;
+
+use crate::ipc_error::IpcError;
+use crate::runtime_state::AppState;
+
+const DEFAULT_BURST_INTERVAL_MS: u64 = 1_000;
+const DEFAULT_BURST_WINDOW_SECS: u64 = 60;
+
+#[derive(Serialize)]
+pub struct MetricsRateResponse {
+    /// Interval the metrics loop is sampling at now.
+    pub interval_ms: u64,
+    /// Configured `monitor.metrics_interval_ms`.
+    pub base_interval_ms: u64,
+    /// When the running burst reverts to the base interval.
+    pub burst_ends_at: Option<String>,
+}
+
+/// Sample system metrics faster for a bounded window, then revert.
+///
+/// Defaults to 1s sampling for 60s. The interval is floored at 250ms and the
+/// window capped at 10 minutes.
+#[command]
+pub async fn start_metrics_burst(
+    state: tauri::State<'_, AppState>,
+    interval_ms: Option<u64>,
+    window_secs: Option<u64>,
+) -> Result<MetricsRateResponse, IpcError> {
+    let until = state.metrics_rate.start_burst(
+        Duration::from_millis(interval_ms.unwrap_or(DEFAULT_BURST_INTERVAL_MS)),
+        Duration::from_secs(window_secs.unwrap_or(DEFAULT_BURST_WINDOW_SECS)),
+    );
+    let remaining = until.saturating_duration_since(Instant::now());
+    Ok(MetricsRateResponse {
+        interval_ms: state.metrics_rate.current().as_millis() as u64,
+        base_interval_ms: state.metrics_rate.base().as_millis() as u64,
+        burst_ends_at: chrono::Duration::from_std(remaining)
+            .ok()
+            .map(|remaining| (Utc::now() + remaining).to_rfc3339()),
+    })
+}
+
+/// End a running metrics burst early.
+#[command]
+pub async fn stop_metrics_burst(
+    state: tauri::State<'_, AppState>,
+) -> Result<MetricsRateResponse, IpcError> {
+    state.metrics_rate.stop_burst();
+    Ok(MetricsRateResponse {
+        interval_ms: state.metrics_rate.current().as_millis() as u64,
+        base_interval_ms: state.metrics_rate.base().as_millis() as u64,
+        burst_ends_at: None,
+    })
+}
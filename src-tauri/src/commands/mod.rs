@@ -3,16 +3,19 @@ pub(crate) mod analysis;
 pub(crate) mod audio;
 pub(crate) mod automation;
 pub(crate) mod autostart;
+pub(crate) mod bookmarks;
 pub(crate) mod bug_report;
 pub(crate) mod capture;
 pub(crate) mod capture_status;
 pub(crate) mod coaching;
+pub(crate) mod consent;
 pub(crate) mod dashboard;
 pub(crate) mod detection;
 pub(crate) mod error_report;
 pub(crate) mod focus;
 pub(crate) mod generate_external_cert;
 pub(crate) mod integration;
+pub(crate) mod metrics;
 pub(crate) mod onboarding;
 pub(crate) mod permissions;
 pub(crate) mod settings;
@@ -21,7 +21,17 @@ const REDACTED_PATHS: &[(&str, &[&str])] = &[
     ("server", &["base_url", "api_key"]),
     ("ai_provider", &["ocr_api.api_key", "llm_api.api_key"]),
     ("web", &["integration_auth_token"]),
-    ("tls", &["enabled", "allow_self_signed"]),
+    (
+        "tls",
+        &[
+            "enabled",
+            "allow_self_signed",
+            "client_cert_pem",
+            "client_key_path",
+            "client_key_pem",
+            "ca_cert_pem",
+        ],
+    ),
     (
         "grpc",
         &[
@@ -34,7 +44,14 @@ const REDACTED_PATHS: &[(&str, &[&str])] = &[
     ),
 ];
 
-const FORBIDDEN_ALLOWED_SUBPATHS: &[(&str, &[&str])] = &[("web", &["integration_auth_token"])];
+const FORBIDDEN_ALLOWED_SUBPATHS: &[(&str, &[&str])] = &[(
+    "web",
+    &[
+        "integration_auth_token",
+        "share_link_secret",
+        "revoked_share_links",
+    ],
+)];
 
 /// WebView에서 수정 가능한 설정 키 화이트리스트.
 /// update_setting + get_allowed_setting_keys에서 공유.
@@ -182,6 +199,7 @@ fn validate_config_bounds(config: &oneshim_core::config::AppConfig) -> Result<()
     if config.notification.idle_notification_mins == 0 {
         return Err("notification.idle_notification_mins must be >= 1 (got 0)".to_string());
     }
+    config.privacy.validate_bounds()?;
     Ok(())
 }
 
@@ -425,4 +443,26 @@ mod tests {
         let err = reject_forbidden_allowed_subpaths(&patch).expect_err("forbidden subpath");
         assert!(err.contains("web.integration_auth_token"));
     }
+
+    #[test]
+    fn reject_forbidden_allowed_subpaths_rejects_share_link_secret() {
+        let patch = json!({
+            "web": {
+                "share_link_secret": "forged-secret"
+            }
+        });
+        let err = reject_forbidden_allowed_subpaths(&patch).expect_err("forbidden subpath");
+        assert!(err.contains("web.share_link_secret"));
+    }
+
+    #[test]
+    fn reject_forbidden_allowed_subpaths_rejects_revoked_share_links() {
+        let patch = json!({
+            "web": {
+                "revoked_share_links": []
+            }
+        });
+        let err = reject_forbidden_allowed_subpaths(&patch).expect_err("forbidden subpath");
+        assert!(err.contains("web.revoked_share_links"));
+    }
 }
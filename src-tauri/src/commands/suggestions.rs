@@ -98,7 +98,8 @@ pub async fn get_pending_suggestions(
     // BEFORE calling is_read() — is_read() acquires its own lock (read_ids),
     // and holding both would cause a nested lock.
     let snapshot: Vec<_> = {
-        let queue = mgr.queue().lock().await;
+        let mut queue = mgr.queue().lock().await;
+        queue.purge_expired(chrono::Utc::now());
         queue
             .iter()
             .map(|s| {
@@ -237,8 +237,8 @@ pub async fn record_frontend_log(
 pub async fn preview_update(
     state: tauri::State<'_, ConfigRuntimeState>,
 ) -> Result<UpdatePreview, IpcError> {
-    let update_config = state.config_manager().get().update.clone();
-    let updater = Updater::new(update_config);
+    let config = state.config_manager().get();
+    let updater = Updater::new(config.update.clone()).with_egress(config.egress.clone());
     updater
         .preview_update_availability()
         .await
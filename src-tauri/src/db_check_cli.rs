@@ -0,0 +1,122 @@
+//! `oneshim --check-db` — verify the local SQLite database without booting the app.
+
+use std::path::Path;
+
+use oneshim_storage::encryption::EncryptionKey;
+use oneshim_storage::sqlite::SqliteStorage;
+
+/// Runs the integrity check and prints a report. Exit code: 0 healthy, 1 otherwise.
+///
+/// The database is opened read-only: a check never migrates, backs up or
+/// otherwise modifies the file it is asked to inspect.
+pub fn run(db_path: &Path, data_dir: &Path) -> i32 {
+    if !db_path.exists() {
+        println!("no database at {} — nothing to check", db_path.display());
+        return 0;
+    }
+
+    // Only reuse an existing key: a check must never provision new key material.
+    let encryption_key = if data_dir.join(".db_key").exists() {
+        EncryptionKey::load_or_create(data_dir).ok()
+    } else {
+        None
+    };
+
+    let report = match SqliteStorage::integrity_check_read_only(db_path, encryption_key.as_ref()) {
+        Ok(report) => report,
+        Err(error) => {
+            eprintln!(
+                "integrity check could not run on {}: {error}",
+                db_path.display()
+            );
+            eprintln!("the database may be corrupt or locked by a running ONESHIM instance");
+            return 1;
+        }
+    };
+
+    if report.is_healthy() {
+        println!("{}: ok", db_path.display());
+        return 0;
+    }
+
+    println!("{}: problems found", db_path.display());
+    for message in &report.integrity_errors {
+        println!("  integrity: {message}");
+    }
+    for violation in &report.foreign_key_violations {
+        let rowid = violation
+            .rowid
+            .map_or_else(|| "?".to_string(), |id| id.to_string());
+        println!(
+            "  foreign key: {} row {rowid} references missing {}",
+            violation.table, violation.parent
+        );
+    }
+    println!("recommended recovery steps:");
+    for (index, step) in report.recovery_steps().iter().enumerate() {
+        println!("  {}. {step}", index + 1);
+    }
+    1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_database_is_not_an_error() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(run(&dir.path().join("oneshim.db"), dir.path()), 0);
+    }
+
+    #[test]
+    fn fresh_database_passes() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("oneshim.db");
+        drop(SqliteStorage::open(&db_path, 30, None).unwrap());
+        assert_eq!(run(&db_path, dir.path()), 0);
+    }
+
+    #[test]
+    fn check_does_not_migrate_an_older_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let db_path = dir.path().join("oneshim.db");
+        {
+            let conn = rusqlite::Connection::open(&db_path).unwrap();
+            conn.execute_batch(
+                "PRAGMA user_version = 3;
+                 CREATE TABLE schema_version (
+                     version INTEGER PRIMARY KEY,
+                     applied_at TEXT NOT NULL DEFAULT (datetime('now'))
+                 );
+                 INSERT INTO schema_version (version) VALUES (1);
+                 CREATE TABLE events (id TEXT PRIMARY KEY, data TEXT NOT NULL);",
+            )
+            .unwrap();
+        }
+        let snapshot = || {
+            let conn = rusqlite::Connection::open(&db_path).unwrap();
+            let user_version: i64 = conn
+                .query_row("PRAGMA user_version", [], |row| row.get(0))
+                .unwrap();
+            let schema_version: i64 = conn
+                .query_row("SELECT MAX(version) FROM schema_version", [], |row| {
+                    row.get(0)
+                })
+                .unwrap();
+            let mut stmt = conn
+                .prepare("SELECT type, name, sql FROM sqlite_master ORDER BY type, name")
+                .unwrap();
+            let schema: Vec<(String, String, Option<String>)> = stmt
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))
+                .unwrap()
+                .collect::<Result<_, _>>()
+                .unwrap();
+            (user_version, schema_version, schema)
+        };
+        let before = snapshot();
+
+        assert_eq!(run(&db_path, dir.path()), 0);
+        assert_eq!(snapshot(), before);
+    }
+}
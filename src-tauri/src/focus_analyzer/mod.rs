@@ -4,16 +4,19 @@ mod suggestions;
 // ── Public re-exports (external API) ────────────────────────────────
 pub use models::{FocusAnalyzerConfig, FocusStorage};
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use oneshim_core::config::AppConfig;
 use oneshim_core::models::work_session::AppCategory;
 use oneshim_core::ports::notifier::DesktopNotifier;
+use oneshim_suggestion::context_scorer::{LocalContext, LocalContextSource};
+use std::collections::HashMap;
 use std::sync::Arc;
-use tokio::sync::RwLock;
+use tokio::sync::{watch, RwLock};
 use tracing::{debug, warn};
 
 use crate::workflow_intelligence::WorkflowIntelligence;
 
-use models::{SessionTracker, SuggestionCooldowns};
+use models::{IdleSessionTransition, SessionTracker, SuggestionCooldowns};
 
 pub struct FocusAnalyzer {
     pub(super) config: FocusAnalyzerConfig,
@@ -22,6 +25,9 @@ pub struct FocusAnalyzer {
     pub(super) tracker: RwLock<SessionTracker>,
     pub(super) cooldowns: RwLock<SuggestionCooldowns>,
     pub(super) workflow_intelligence: RwLock<WorkflowIntelligence>,
+    /// `monitor.category_overrides`, kept current by
+    /// [`FocusAnalyzer::follow_category_overrides`].
+    pub(super) category_overrides: RwLock<HashMap<String, AppCategory>>,
 }
 
 impl FocusAnalyzer {
@@ -37,9 +43,35 @@ impl FocusAnalyzer {
             tracker: RwLock::new(SessionTracker::default()),
             cooldowns: RwLock::new(SuggestionCooldowns::default()),
             workflow_intelligence: RwLock::new(WorkflowIntelligence::default()),
+            category_overrides: RwLock::new(HashMap::new()),
         }
     }
 
+    pub fn with_category_overrides(mut self, overrides: HashMap<String, AppCategory>) -> Self {
+        self.category_overrides = RwLock::new(overrides);
+        self
+    }
+
+    /// Apply `monitor.category_overrides` from every config reload so edits
+    /// to config.json classify the next app switch.
+    pub fn follow_category_overrides(
+        self: &Arc<Self>,
+        mut config_rx: watch::Receiver<Arc<AppConfig>>,
+    ) {
+        let analyzer = Arc::clone(self);
+        tokio::spawn(async move {
+            while config_rx.changed().await.is_ok() {
+                let overrides = config_rx
+                    .borrow_and_update()
+                    .monitor
+                    .category_overrides
+                    .clone();
+                *analyzer.category_overrides.write().await = overrides;
+            }
+        });
+    }
+
+    #[allow(dead_code)] // convenience constructor used in tests
     pub fn with_defaults(
         storage: Arc<dyn FocusStorage>,
         notifier: Arc<dyn DesktopNotifier>,
@@ -58,7 +90,11 @@ impl FocusAnalyzer {
         window_title: &str,
         ocr_hint: Option<&str>,
     ) {
-        let new_category = AppCategory::from_app_name(new_app);
+        let new_category = AppCategory::from_app_name_with_overrides(
+            new_app,
+            &self.category_overrides.read().await,
+        );
+        let new_class = self.config.classification.classify(new_app, new_category);
         let now = Utc::now();
         let today = now.format("%Y-%m-%d").to_string();
 
@@ -86,10 +122,14 @@ impl FocusAnalyzer {
             {
                 let duration_secs = (now - start).num_seconds().max(0) as u64;
                 previous_usage = Some((prev_app_name.clone(), prev_cat, duration_secs));
+                let prev_class = self
+                    .config
+                    .classification
+                    .classify(&prev_app_name, prev_cat);
 
-                let (deep_work, comm) = if prev_cat.is_deep_work() {
+                let (deep_work, comm) = if prev_class.is_deep_work() {
                     (duration_secs, 0)
-                } else if prev_cat.is_communication() {
+                } else if prev_class.is_communication() {
                     (0, duration_secs)
                 } else {
                     (0, 0)
@@ -106,7 +146,7 @@ impl FocusAnalyzer {
                     warn!("in progress min failure: {e}");
                 }
 
-                if prev_cat.is_deep_work() {
+                if prev_class.is_deep_work() {
                     tracker.continuous_deep_work_secs += duration_secs;
 
                     if let Some(session_id) = tracker.active_session_id {
@@ -116,7 +156,7 @@ impl FocusAnalyzer {
                     }
                 }
 
-                if prev_cat.is_deep_work() && new_category.is_communication() {
+                if prev_class.is_deep_work() && new_class.is_communication() {
                     let interruption = oneshim_core::models::work_session::Interruption::new(
                         0, // id assigned on persist
                         prev_app_name,
@@ -147,7 +187,7 @@ impl FocusAnalyzer {
                     }
                 }
 
-                if prev_cat.is_communication() && new_category.is_deep_work() {
+                if prev_class.is_communication() && new_class.is_deep_work() {
                     if let Some(int_id) = tracker.pending_interruption_id.take() {
                         if let Err(e) = self.storage.record_interruption_resume(int_id, new_app) {
                             debug!("record_interruption_resume failed: {e}");
@@ -158,15 +198,35 @@ impl FocusAnalyzer {
                 }
             }
 
-            if new_category.is_communication() {
-                if let Some(session_id) = tracker.active_session_id.take() {
-                    if let Err(e) = self.storage.end_work_session(session_id) {
-                        debug!("end_work_session failed: {e}");
+            if new_class.is_communication() {
+                if tracker.active_session_id.is_some() && tracker.session_gap_started_at.is_none() {
+                    if self.config.session_gap_grace_secs == 0 {
+                        self.end_active_session(&mut tracker, now);
+                    } else {
+                        // Keep the session open; it ends only if the gap
+                        // outlives the grace period.
+                        tracker.session_gap_started_at = Some(now);
                     }
-                    tracker.continuous_deep_work_secs = 0;
-                    debug!("session ended ( switch): id={}", session_id);
                 }
-            } else if new_category.is_deep_work() && tracker.active_session_id.is_none() {
+            } else if new_class.is_deep_work() {
+                if let Some(gap_start) = tracker.session_gap_started_at {
+                    let gap_secs = (now - gap_start).num_seconds().max(0) as u64;
+                    match tracker.active_session_id {
+                        Some(session_id) if gap_secs <= self.config.session_gap_grace_secs => {
+                            tracker.session_gap_started_at = None;
+                            if let Err(e) =
+                                self.storage.record_work_session_gap(session_id, gap_secs)
+                            {
+                                debug!("record_work_session_gap failed: {e}");
+                            }
+                            debug!("session gap merged: id={}, gap={}s", session_id, gap_secs);
+                        }
+                        _ => self.end_active_session(&mut tracker, gap_start),
+                    }
+                }
+            }
+
+            if new_class.is_deep_work() && tracker.active_session_id.is_none() {
                 match self.storage.start_work_session(new_app, new_category) {
                     Ok(session) => {
                         debug!("session started: id={}, app={}", session.id, new_app);
@@ -220,6 +280,8 @@ impl FocusAnalyzer {
         let now = Utc::now();
         let today = now.format("%Y-%m-%d").to_string();
 
+        self.expire_session_gap(now).await;
+
         let metrics = match self.storage.get_or_create_focus_metrics(&today) {
             Ok(m) => m,
             Err(e) => {
@@ -262,6 +324,76 @@ impl FocusAnalyzer {
         );
     }
 
+    /// Per-tick idle reading. Past `idle_session_end_secs` the active session
+    /// ends, back-dated to when idle began, and the current app stops
+    /// accruing time — the idle stretch is already recorded as an idle
+    /// period. When activity returns, the current app resumes accruing and a
+    /// deep-work app starts a new session.
+    pub async fn on_idle_tick(&self, idle_secs: u64) {
+        let now = Utc::now();
+        let mut tracker = self.tracker.write().await;
+        match tracker.observe_idle(idle_secs, self.config.idle_session_end_secs, now) {
+            Some(IdleSessionTransition::End { idle_started_at }) => {
+                if let (Some(app), Some(category), Some(start)) = (
+                    tracker.current_app.clone(),
+                    tracker.current_category,
+                    tracker.current_app_start.take(),
+                ) {
+                    let active_secs = (idle_started_at - start).num_seconds().max(0) as u64;
+                    let class = self.config.classification.classify(&app, category);
+                    let (deep_work, comm) = if class.is_deep_work() {
+                        (active_secs, 0)
+                    } else if class.is_communication() {
+                        (0, active_secs)
+                    } else {
+                        (0, 0)
+                    };
+                    let today = idle_started_at.format("%Y-%m-%d").to_string();
+                    if let Err(e) = self.storage.increment_focus_metrics(
+                        &today,
+                        active_secs,
+                        deep_work,
+                        comm,
+                        0,
+                        0,
+                    ) {
+                        warn!("in progress min failure: {e}");
+                    }
+                    if let Some(session_id) = tracker.active_session_id.filter(|_| deep_work > 0) {
+                        if let Err(e) = self.storage.add_deep_work_secs(session_id, deep_work) {
+                            warn!("session deep_work_secs add failure: {e}");
+                        }
+                    }
+                }
+                self.end_active_session(&mut tracker, idle_started_at);
+                debug!("session ended by idle: idle={}s", idle_secs);
+            }
+            Some(IdleSessionTransition::Resume) => {
+                tracker.current_app_start = Some(now);
+                let (Some(app), Some(category)) =
+                    (tracker.current_app.clone(), tracker.current_category)
+                else {
+                    return;
+                };
+                let is_deep_work = self
+                    .config
+                    .classification
+                    .classify(&app, category)
+                    .is_deep_work();
+                if is_deep_work && tracker.active_session_id.is_none() {
+                    match self.storage.start_work_session(&app, category) {
+                        Ok(session) => {
+                            debug!("session started after idle: id={}, app={}", session.id, app);
+                            tracker.active_session_id = Some(session.id);
+                        }
+                        Err(e) => warn!("session started failure: {e}"),
+                    }
+                }
+            }
+            None => {}
+        }
+    }
+
     #[allow(dead_code)] // convenience wrapper used in tests
     pub async fn on_idle_resume(&self) {
         let now = Utc::now();
@@ -272,13 +404,9 @@ impl FocusAnalyzer {
 
         let mut tracker = self.tracker.write().await;
 
-        if let Some(session_id) = tracker.active_session_id.take() {
-            if let Err(e) = self.storage.end_work_session(session_id) {
-                debug!("end_work_session (idle resume) failed: {e}");
-            }
-        }
+        let ended_at = tracker.session_gap_started_at.unwrap_or(now);
+        self.end_active_session(&mut tracker, ended_at);
 
-        tracker.continuous_deep_work_secs = 0;
         tracker.pending_interruption_id = None;
         tracker.current_app = None;
         tracker.current_category = None;
@@ -292,14 +420,74 @@ impl FocusAnalyzer {
     }
 }
 
+impl FocusAnalyzer {
+    /// End the active session (if any) at `ended_at` and reset the
+    /// deep-work streak and any open gap.
+    fn end_active_session(&self, tracker: &mut SessionTracker, ended_at: DateTime<Utc>) {
+        tracker.session_gap_started_at = None;
+        tracker.continuous_deep_work_secs = 0;
+        if let Some(session_id) = tracker.active_session_id.take() {
+            if let Err(e) = self.storage.end_work_session_at(session_id, ended_at) {
+                debug!("end_work_session failed: {e}");
+            }
+            debug!("session ended: id={}", session_id);
+        }
+    }
+
+    /// Close a session whose gap has outlived the grace period, back-dated
+    /// to when the gap began.
+    async fn expire_session_gap(&self, now: DateTime<Utc>) {
+        let mut tracker = self.tracker.write().await;
+        if let Some(gap_start) = tracker.session_gap_started_at {
+            let gap_secs = (now - gap_start).num_seconds().max(0) as u64;
+            if gap_secs > self.config.session_gap_grace_secs {
+                self.end_active_session(&mut tracker, gap_start);
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl LocalContextSource for FocusAnalyzer {
+    async fn local_context(&self) -> LocalContext {
+        let tracker = self.tracker.read().await;
+        let current_app_secs = tracker
+            .current_app_start
+            .map(|start| (Utc::now() - start).num_seconds().max(0) as u64)
+            .unwrap_or(0);
+        // `continuous_deep_work_secs` is only credited on app switch, so add
+        // the time spent in the current deep-work app.
+        let in_deep_work = match (&tracker.current_app, tracker.current_category) {
+            (Some(app), Some(category)) => self
+                .config
+                .classification
+                .classify(app, category)
+                .is_deep_work(),
+            _ => false,
+        };
+        let continuous_deep_work_secs = if in_deep_work {
+            tracker.continuous_deep_work_secs + current_app_secs
+        } else {
+            tracker.continuous_deep_work_secs
+        };
+
+        LocalContext {
+            current_category: tracker.current_category,
+            current_app_secs,
+            continuous_deep_work_secs,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use async_trait::async_trait;
     use chrono::Duration;
+    use oneshim_core::config::FocusClassificationConfig;
     use oneshim_core::error::CoreError;
     use oneshim_core::models::suggestion::Suggestion;
-    use oneshim_core::models::work_session::FocusMetrics;
+    use oneshim_core::models::work_session::{FocusMetrics, WorkClass};
     use oneshim_storage::sqlite::SqliteStorage;
     use std::sync::atomic::{AtomicU32, Ordering};
     use tempfile::TempDir;
@@ -362,6 +550,57 @@ mod tests {
         assert_eq!(tracker.current_category, Some(AppCategory::Development));
     }
 
+    #[tokio::test]
+    async fn category_overrides_follow_config_reloads() {
+        let (analyzer, _temp, _notifier) = create_test_analyzer().await;
+        let analyzer = Arc::new(analyzer);
+        let (config_tx, config_rx) = watch::channel(Arc::new(AppConfig::default_config()));
+        analyzer.follow_category_overrides(config_rx);
+
+        analyzer.on_app_switch("Jira-Desktop").await;
+        assert_eq!(
+            analyzer.tracker.read().await.current_category,
+            Some(AppCategory::Other)
+        );
+
+        let mut config = AppConfig::default_config();
+        config
+            .monitor
+            .category_overrides
+            .insert("jira-desktop".to_string(), AppCategory::Communication);
+        config_tx.send(Arc::new(config)).unwrap();
+        for _ in 0..50 {
+            if !analyzer.category_overrides.read().await.is_empty() {
+                break;
+            }
+            tokio::time::sleep(tokio::time::Duration::from_millis(10)).await;
+        }
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        analyzer.on_app_switch("Jira-Desktop").await;
+        assert_eq!(
+            analyzer.tracker.read().await.current_category,
+            Some(AppCategory::Communication)
+        );
+    }
+
+    #[tokio::test]
+    async fn local_context_counts_time_in_current_deep_work_app() {
+        let (analyzer, _temp, _notifier) = create_test_analyzer().await;
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        {
+            let mut tracker = analyzer.tracker.write().await;
+            tracker.current_app_start = Some(Utc::now() - Duration::minutes(90));
+            tracker.continuous_deep_work_secs = 600;
+        }
+
+        let context = analyzer.local_context().await;
+        assert_eq!(context.current_category, Some(AppCategory::Development));
+        assert!(context.current_app_secs >= 90 * 60);
+        assert!(context.continuous_deep_work_secs >= 600 + 90 * 60);
+    }
+
     #[tokio::test]
     async fn deep_work_to_communication_creates_interruption() {
         let (analyzer, _temp, _notifier) = create_test_analyzer().await;
@@ -486,6 +725,182 @@ mod tests {
         }
     }
 
+    fn create_gap_test_analyzer(grace_secs: u64) -> (FocusAnalyzer, TempDir, Arc<SqliteStorage>) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(
+            SqliteStorage::open(&temp_dir.path().join("test.db"), 30, None)
+                .expect("storage creation failed"),
+        );
+        let config = FocusAnalyzerConfig {
+            session_gap_grace_secs: grace_secs,
+            ..FocusAnalyzerConfig::default()
+        };
+        let analyzer = FocusAnalyzer::new(config, storage.clone(), Arc::new(MockNotifier::new()));
+        (analyzer, temp_dir, storage)
+    }
+
+    fn session_window() -> (String, String) {
+        (
+            (Utc::now() - Duration::hours(1)).to_rfc3339(),
+            (Utc::now() + Duration::hours(1)).to_rfc3339(),
+        )
+    }
+
+    #[tokio::test]
+    async fn short_gap_within_grace_merges_into_same_session() {
+        let (analyzer, _temp, storage) = create_gap_test_analyzer(120);
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        let session_id = analyzer.tracker.read().await.active_session_id;
+        assert!(session_id.is_some());
+
+        analyzer.on_app_switch("Slack").await;
+        analyzer.on_app_switch("Visual Studio Code").await;
+
+        let tracker = analyzer.tracker.read().await;
+        assert_eq!(tracker.active_session_id, session_id);
+        assert!(tracker.session_gap_started_at.is_none());
+
+        let (from, to) = session_window();
+        let merged = storage.list_merged_work_sessions(&from, &to, 10).unwrap();
+        assert_eq!(merged.len(), 1);
+        assert_eq!(Some(merged[0].id), session_id);
+        assert_eq!(merged[0].merged_gap_count, 1);
+        assert_eq!(storage.list_work_sessions(&from, &to, 10).unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn gap_longer_than_grace_splits_session() {
+        let (analyzer, _temp, storage) = create_gap_test_analyzer(120);
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        let first_session = analyzer.tracker.read().await.active_session_id;
+
+        analyzer.on_app_switch("Slack").await;
+        {
+            let mut tracker = analyzer.tracker.write().await;
+            tracker.session_gap_started_at = Some(Utc::now() - Duration::minutes(10));
+        }
+        analyzer.on_app_switch("Visual Studio Code").await;
+
+        let second_session = analyzer.tracker.read().await.active_session_id;
+        assert!(second_session.is_some());
+        assert_ne!(second_session, first_session);
+
+        let (from, to) = session_window();
+        assert!(storage
+            .list_merged_work_sessions(&from, &to, 10)
+            .unwrap()
+            .is_empty());
+        let sessions = storage.list_work_sessions(&from, &to, 10).unwrap();
+        assert_eq!(sessions.len(), 2);
+        let first = sessions
+            .iter()
+            .find(|session| Some(session.id) == first_session)
+            .unwrap();
+        assert_eq!(first.state, "completed");
+    }
+
+    #[tokio::test]
+    async fn periodic_analysis_closes_expired_gap() {
+        let (analyzer, _temp, _storage) = create_gap_test_analyzer(120);
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        analyzer.on_app_switch("Slack").await;
+        assert!(analyzer.tracker.read().await.active_session_id.is_some());
+
+        {
+            let mut tracker = analyzer.tracker.write().await;
+            tracker.session_gap_started_at = Some(Utc::now() - Duration::minutes(10));
+        }
+        analyzer.analyze_periodic().await;
+
+        let tracker = analyzer.tracker.read().await;
+        assert!(tracker.active_session_id.is_none());
+        assert!(tracker.session_gap_started_at.is_none());
+    }
+
+    fn create_idle_test_analyzer(
+        idle_session_end_secs: u64,
+    ) -> (FocusAnalyzer, TempDir, Arc<SqliteStorage>) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(
+            SqliteStorage::open(&temp_dir.path().join("test.db"), 30, None)
+                .expect("storage creation failed"),
+        );
+        let config = FocusAnalyzerConfig {
+            idle_session_end_secs,
+            ..FocusAnalyzerConfig::default()
+        };
+        let analyzer = FocusAnalyzer::new(config, storage.clone(), Arc::new(MockNotifier::new()));
+        (analyzer, temp_dir, storage)
+    }
+
+    #[tokio::test]
+    async fn idle_beyond_threshold_ends_session_and_activity_starts_new_one() {
+        let (analyzer, _temp, storage) = create_idle_test_analyzer(900);
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        let first_session = analyzer.tracker.read().await.active_session_id;
+        assert!(first_session.is_some());
+
+        analyzer.on_idle_tick(300).await;
+        assert_eq!(
+            analyzer.tracker.read().await.active_session_id,
+            first_session
+        );
+
+        analyzer.on_idle_tick(900).await;
+        {
+            let tracker = analyzer.tracker.read().await;
+            assert!(tracker.active_session_id.is_none());
+            assert!(tracker.current_app_start.is_none());
+        }
+
+        // Activity returns in the same app — no app switch event fires.
+        analyzer.on_idle_tick(0).await;
+        let second_session = analyzer.tracker.read().await.active_session_id;
+        assert!(second_session.is_some());
+        assert_ne!(second_session, first_session);
+
+        let (from, to) = session_window();
+        let sessions = storage.list_work_sessions(&from, &to, 10).unwrap();
+        assert_eq!(sessions.len(), 2);
+        let first = sessions
+            .iter()
+            .find(|session| Some(session.id) == first_session)
+            .unwrap();
+        assert_eq!(first.state, "completed");
+    }
+
+    #[tokio::test]
+    async fn idle_session_end_disabled_keeps_session() {
+        let (analyzer, _temp, _storage) = create_idle_test_analyzer(0);
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        let session = analyzer.tracker.read().await.active_session_id;
+        analyzer.on_idle_tick(7200).await;
+        analyzer.on_idle_tick(0).await;
+
+        assert_eq!(analyzer.tracker.read().await.active_session_id, session);
+    }
+
+    #[tokio::test]
+    async fn zero_grace_splits_on_every_switch() {
+        let (analyzer, _temp, _storage) = create_gap_test_analyzer(0);
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        let first_session = analyzer.tracker.read().await.active_session_id;
+        analyzer.on_app_switch("Slack").await;
+        assert!(analyzer.tracker.read().await.active_session_id.is_none());
+
+        analyzer.on_app_switch("Visual Studio Code").await;
+        assert_ne!(
+            analyzer.tracker.read().await.active_session_id,
+            first_session
+        );
+    }
+
     #[tokio::test]
     async fn same_app_switch_no_change() {
         let (analyzer, _temp, _notifier) = create_test_analyzer().await;
@@ -495,4 +910,64 @@ mod tests {
         let tracker = analyzer.tracker.read().await;
         assert_eq!(tracker.current_app, Some("Visual Studio Code".to_string()));
     }
+
+    /// Ten minutes in Slack, then back to the editor; returns the day's
+    /// (deep_work_secs, communication_secs).
+    async fn slack_then_editor_metrics(classification: FocusClassificationConfig) -> (u64, u64) {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(
+            SqliteStorage::open(&temp_dir.path().join("test.db"), 30, None)
+                .expect("storage creation failed"),
+        );
+        let config = FocusAnalyzerConfig {
+            classification,
+            ..FocusAnalyzerConfig::default()
+        };
+        let analyzer = FocusAnalyzer::new(config, storage.clone(), Arc::new(MockNotifier::new()));
+
+        analyzer.on_app_switch("Slack").await;
+        analyzer.tracker.write().await.current_app_start = Some(Utc::now() - Duration::minutes(10));
+        analyzer.on_app_switch("Visual Studio Code").await;
+
+        let today = Utc::now().format("%Y-%m-%d").to_string();
+        let metrics = storage.get_or_create_focus_metrics(&today).unwrap();
+        (metrics.deep_work_secs, metrics.communication_secs)
+    }
+
+    #[tokio::test]
+    async fn reclassifying_communication_as_deep_work_changes_deep_work_secs() {
+        let (deep_default, comm_default) =
+            slack_then_editor_metrics(FocusClassificationConfig::default()).await;
+        assert_eq!(deep_default, 0);
+        assert!(comm_default >= 600);
+
+        let support_team = FocusClassificationConfig {
+            categories: [(AppCategory::Communication, WorkClass::DeepWork)].into(),
+            ..FocusClassificationConfig::default()
+        };
+        let (deep_support, comm_support) = slack_then_editor_metrics(support_team).await;
+        assert!(deep_support >= 600);
+        assert_eq!(comm_support, 0);
+    }
+
+    #[tokio::test]
+    async fn app_override_starts_session_for_reclassified_app() {
+        let temp_dir = TempDir::new().unwrap();
+        let storage = Arc::new(
+            SqliteStorage::open(&temp_dir.path().join("test.db"), 30, None)
+                .expect("storage creation failed"),
+        );
+        let config = FocusAnalyzerConfig {
+            classification: FocusClassificationConfig {
+                apps: [("Zendesk".to_string(), WorkClass::DeepWork)].into(),
+                ..FocusClassificationConfig::default()
+            },
+            ..FocusAnalyzerConfig::default()
+        };
+        let analyzer = FocusAnalyzer::new(config, storage, Arc::new(MockNotifier::new()));
+
+        analyzer.on_app_switch("Zendesk").await;
+
+        assert!(analyzer.tracker.read().await.active_session_id.is_some());
+    }
 }
@@ -1,7 +1,32 @@
+use async_trait::async_trait;
 use chrono::{DateTime, Utc};
-use parking_lot::RwLock;
+use oneshim_core::error::CoreError;
+use oneshim_core::models::suggestion::Suggestion;
+use oneshim_core::ports::notifier::DesktopNotifier;
+use parking_lot::{Mutex, RwLock};
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::Arc;
 use std::time::Instant;
+use tokio::sync::Notify;
+use tracing::{debug, info};
+
+/// Oldest deferred items are dropped beyond this many.
+const MAX_DEFERRED: usize = 100;
+
+/// An interruption held back while focus mode is active.
+#[derive(Debug, Clone)]
+pub enum DeferredInterruption {
+    Notification {
+        title: String,
+        body: String,
+    },
+    Suggestion(Box<Suggestion>),
+    /// Automation confirmation that was not shown; the command itself was
+    /// declined when its confirmation window lapsed.
+    AutomationPrompt {
+        process_name: String,
+    },
+}
 
 /// Transient focus mode state. Not persisted across app restarts.
 pub struct FocusModeState {
@@ -10,6 +35,10 @@ pub struct FocusModeState {
     duration_minutes: AtomicU32, // 0 = indefinite
     auto_activated: AtomicBool,
     last_deactivation: RwLock<Option<Instant>>,
+    deferred: Mutex<Vec<DeferredInterruption>>,
+    /// Signalled on deactivation so the digest goes out without waiting
+    /// for the next notification tick.
+    ended: Notify,
 }
 
 impl FocusModeState {
@@ -20,6 +49,8 @@ impl FocusModeState {
             duration_minutes: AtomicU32::new(0),
             auto_activated: AtomicBool::new(false),
             last_deactivation: RwLock::new(None),
+            deferred: Mutex::new(Vec::new()),
+            ended: Notify::new(),
         }
     }
 
@@ -40,11 +71,14 @@ impl FocusModeState {
     }
 
     pub fn deactivate(&self) {
-        self.active.store(false, Ordering::Release);
+        let was_active = self.active.swap(false, Ordering::AcqRel);
         *self.activated_at.write() = None;
         self.duration_minutes.store(0, Ordering::Relaxed);
         self.auto_activated.store(false, Ordering::Relaxed);
         *self.last_deactivation.write() = Some(Instant::now());
+        if was_active {
+            self.ended.notify_one();
+        }
     }
 
     pub fn remaining_minutes(&self) -> Option<u32> {
@@ -98,6 +132,124 @@ impl FocusModeState {
             None => false,
         }
     }
+
+    /// Hold `item` until focus mode ends.
+    pub fn defer(&self, item: DeferredInterruption) {
+        let mut deferred = self.deferred.lock();
+        if deferred.len() >= MAX_DEFERRED {
+            deferred.remove(0);
+        }
+        deferred.push(item);
+    }
+
+    pub fn deferred_count(&self) -> usize {
+        self.deferred.lock().len()
+    }
+
+    /// Drain held items, or nothing while focus mode is still active.
+    pub fn take_deferred(&self) -> Vec<DeferredInterruption> {
+        if self.is_active() {
+            return Vec::new();
+        }
+        std::mem::take(&mut *self.deferred.lock())
+    }
+
+    /// Resolves after the next deactivation of an active session.
+    pub async fn ended(&self) {
+        self.ended.notified().await;
+    }
+}
+
+/// Notifier that holds suggestions and notifications while focus mode is
+/// active. Errors always pass through.
+pub struct FocusAwareNotifier {
+    inner: Arc<dyn DesktopNotifier>,
+    focus_mode: Arc<FocusModeState>,
+}
+
+impl FocusAwareNotifier {
+    pub fn new(inner: Arc<dyn DesktopNotifier>, focus_mode: Arc<FocusModeState>) -> Self {
+        Self { inner, focus_mode }
+    }
+
+    /// Deliver everything held during the last focus session. A single item
+    /// is shown as-is; several are summarised in one digest notification.
+    /// Returns the number of items delivered.
+    pub async fn deliver_digest(&self) -> usize {
+        let items = self.focus_mode.take_deferred();
+        let result = match items.as_slice() {
+            [] => return 0,
+            [DeferredInterruption::Suggestion(suggestion)] => {
+                self.inner.show_suggestion(suggestion).await
+            }
+            [DeferredInterruption::Notification { title, body }] => {
+                self.inner.show_notification(title, body).await
+            }
+            _ => {
+                self.inner
+                    .show_notification("While you were focused", &digest_body(&items))
+                    .await
+            }
+        };
+        if let Err(e) = result {
+            debug!("focus digest delivery failure: {e}");
+        }
+        info!(count = items.len(), "focus mode digest delivered");
+        items.len()
+    }
+}
+
+#[async_trait]
+impl DesktopNotifier for FocusAwareNotifier {
+    async fn show_suggestion(&self, suggestion: &Suggestion) -> Result<(), CoreError> {
+        if self.focus_mode.is_active() {
+            self.focus_mode
+                .defer(DeferredInterruption::Suggestion(Box::new(
+                    suggestion.clone(),
+                )));
+            return Ok(());
+        }
+        self.inner.show_suggestion(suggestion).await
+    }
+
+    async fn show_notification(&self, title: &str, body: &str) -> Result<(), CoreError> {
+        if self.focus_mode.is_active() {
+            self.focus_mode.defer(DeferredInterruption::Notification {
+                title: title.to_string(),
+                body: body.to_string(),
+            });
+            return Ok(());
+        }
+        self.inner.show_notification(title, body).await
+    }
+
+    async fn show_error(&self, message: &str) -> Result<(), CoreError> {
+        self.inner.show_error(message).await
+    }
+}
+
+fn digest_body(items: &[DeferredInterruption]) -> String {
+    let count = |pred: fn(&DeferredInterruption) -> bool| items.iter().filter(|i| pred(i)).count();
+    let parts = [
+        (
+            count(|i| matches!(i, DeferredInterruption::Notification { .. })),
+            "notification",
+        ),
+        (
+            count(|i| matches!(i, DeferredInterruption::Suggestion(_))),
+            "suggestion",
+        ),
+        (
+            count(|i| matches!(i, DeferredInterruption::AutomationPrompt { .. })),
+            "skipped automation prompt",
+        ),
+    ];
+    let summary: Vec<String> = parts
+        .iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, label)| format!("{n} {label}{}", if *n == 1 { "" } else { "s" }))
+        .collect();
+    format!("{} held during focus mode.", summary.join(", "))
 }
 
 #[cfg(test)]
@@ -168,4 +320,115 @@ mod tests {
         let state = FocusModeState::new();
         assert!(!state.in_cooldown(300));
     }
+
+    #[derive(Default)]
+    struct RecordingNotifier {
+        suggestions: Mutex<Vec<String>>,
+        notifications: Mutex<Vec<(String, String)>>,
+    }
+
+    #[async_trait]
+    impl DesktopNotifier for RecordingNotifier {
+        async fn show_suggestion(&self, suggestion: &Suggestion) -> Result<(), CoreError> {
+            self.suggestions
+                .lock()
+                .push(suggestion.suggestion_id.clone());
+            Ok(())
+        }
+
+        async fn show_notification(&self, title: &str, body: &str) -> Result<(), CoreError> {
+            self.notifications
+                .lock()
+                .push((title.to_string(), body.to_string()));
+            Ok(())
+        }
+
+        async fn show_error(&self, _message: &str) -> Result<(), CoreError> {
+            Ok(())
+        }
+    }
+
+    fn suggestion(id: &str) -> Suggestion {
+        use oneshim_core::models::suggestion::{Priority, SuggestionSource, SuggestionType};
+        Suggestion {
+            suggestion_id: id.to_string(),
+            suggestion_type: SuggestionType::WorkGuidance,
+            content: "Take a look".to_string(),
+            priority: Priority::Medium,
+            confidence_score: 0.9,
+            relevance_score: 0.9,
+            is_actionable: true,
+            created_at: Utc::now(),
+            expires_at: None,
+            source: SuggestionSource::default(),
+            reasoning: None,
+        }
+    }
+
+    fn gated() -> (
+        Arc<FocusModeState>,
+        Arc<RecordingNotifier>,
+        FocusAwareNotifier,
+    ) {
+        let focus = Arc::new(FocusModeState::new());
+        let inner = Arc::new(RecordingNotifier::default());
+        let notifier = FocusAwareNotifier::new(inner.clone(), focus.clone());
+        (focus, inner, notifier)
+    }
+
+    #[tokio::test]
+    async fn deferred_suggestion_is_delivered_after_focus_ends() {
+        let (focus, inner, notifier) = gated();
+        focus.activate(25, false);
+
+        notifier.show_suggestion(&suggestion("s1")).await.unwrap();
+        assert!(inner.suggestions.lock().is_empty());
+        assert_eq!(notifier.deliver_digest().await, 0, "held while active");
+
+        focus.deactivate();
+        assert_eq!(notifier.deliver_digest().await, 1);
+        assert_eq!(*inner.suggestions.lock(), vec!["s1".to_string()]);
+        assert_eq!(focus.deferred_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn several_deferred_items_arrive_as_one_digest() {
+        let (focus, inner, notifier) = gated();
+        focus.activate(0, false);
+        notifier.show_notification("Idle", "Break?").await.unwrap();
+        notifier.show_suggestion(&suggestion("s1")).await.unwrap();
+        notifier.show_suggestion(&suggestion("s2")).await.unwrap();
+        focus.defer(DeferredInterruption::AutomationPrompt {
+            process_name: "git".to_string(),
+        });
+        focus.deactivate();
+
+        assert_eq!(notifier.deliver_digest().await, 4);
+        assert!(inner.suggestions.lock().is_empty());
+        let notifications = inner.notifications.lock();
+        assert_eq!(notifications.len(), 1);
+        assert_eq!(
+            notifications[0].1,
+            "1 notification, 2 suggestions, 1 skipped automation prompt held during focus mode."
+        );
+    }
+
+    #[tokio::test]
+    async fn inactive_focus_passes_notifications_through() {
+        let (_focus, inner, notifier) = gated();
+        notifier.show_notification("Idle", "Break?").await.unwrap();
+        assert_eq!(inner.notifications.lock().len(), 1);
+    }
+
+    #[test]
+    fn deferred_items_are_capped() {
+        let focus = FocusModeState::new();
+        focus.activate(0, false);
+        for _ in 0..MAX_DEFERRED + 5 {
+            focus.defer(DeferredInterruption::AutomationPrompt {
+                process_name: "git".to_string(),
+            });
+        }
+        assert_eq!(focus.deferred_count(), MAX_DEFERRED);
+    }
 }
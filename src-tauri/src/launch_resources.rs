@@ -39,13 +39,16 @@ impl<'a> LaunchCoreResourcesBuilder<'a> {
     }
 
     pub(crate) fn build(&self) -> Result<LaunchCoreResources<'a>> {
-        let update_runtime =
-            UpdateRuntimeBuilder::new(&self.config.update, self.runtime_handle).build_and_spawn();
+        let update_runtime = UpdateRuntimeBuilder::new(&self.config.update, self.runtime_handle)
+            .with_egress(&self.config.egress)
+            .build_and_spawn();
         let storage_runtime = StorageRuntimeBuilder::new(
             self.db_path,
             self.data_dir_path,
             self.config.storage.retention_days,
         )
+        .with_text_compression(self.config.storage.text_compression_min_bytes)
+        .with_key_source(self.config.storage.db_key_source)
         .build()?;
         let background_runtime =
             BackgroundRuntimeCoordinator::new(self.runtime_handle, self.app_handle.clone());
@@ -47,7 +47,7 @@ impl NativeBorderIndicator {
     }
 
     #[cfg(not(target_os = "macos"))]
-    #[allow(dead_code)] // Stub for non-macOS platforms; called from main.rs setup
+    #[allow(dead_code)] // Stub for non-macOS platforms; called from lib.rs setup
     pub fn new() -> Option<Self> {
         None
     }
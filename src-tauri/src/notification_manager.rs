@@ -5,6 +5,8 @@ use std::sync::Arc;
 use tokio::sync::RwLock;
 use tracing::{debug, info};
 
+use crate::focus_mode::{FocusAwareNotifier, FocusModeState};
+
 #[derive(Debug, Default)]
 struct NotificationState {
     last_idle_notification: Option<DateTime<Utc>>,
@@ -18,6 +20,7 @@ pub struct NotificationManager {
     config: RwLock<NotificationConfig>,
     notifier: Arc<dyn DesktopNotifier>,
     state: RwLock<NotificationState>,
+    focus_notifier: Option<Arc<FocusAwareNotifier>>,
 }
 
 #[allow(dead_code)] // API surface wired in scheduler notification loop
@@ -31,6 +34,30 @@ impl NotificationManager {
                 last_activity: Some(Utc::now()),
                 ..Default::default()
             }),
+            focus_notifier: None,
+        }
+    }
+
+    /// Hold notifications while `focus_mode` is active and deliver them as a
+    /// digest via [`Self::deliver_focus_digest`] once it ends.
+    pub fn with_focus_mode(mut self, focus_mode: Arc<FocusModeState>) -> Self {
+        let gated = Arc::new(FocusAwareNotifier::new(self.notifier.clone(), focus_mode));
+        self.notifier = gated.clone();
+        self.focus_notifier = Some(gated);
+        self
+    }
+
+    /// Notifier other presenters (suggestions, focus analyzer) should share so
+    /// they honor focus mode too.
+    pub fn notifier(&self) -> Arc<dyn DesktopNotifier> {
+        self.notifier.clone()
+    }
+
+    /// Deliver what was held during the last focus session.
+    pub async fn deliver_focus_digest(&self) -> usize {
+        match &self.focus_notifier {
+            Some(gated) => gated.deliver_digest().await,
+            None => 0,
         }
     }
 
@@ -248,6 +275,31 @@ mod tests {
         }
     }
 
+    #[tokio::test]
+    async fn focus_mode_holds_notifications_until_digest() {
+        let config = NotificationConfig {
+            enabled: true,
+            ..Default::default()
+        };
+        let notifier = Arc::new(MockNotifier::new());
+        let focus = Arc::new(FocusModeState::new());
+        let manager =
+            NotificationManager::new(config, notifier.clone()).with_focus_mode(focus.clone());
+
+        focus.activate(30, false);
+        manager.notify("Heads up", "Build finished").await;
+        manager.notify_coaching("Stretch").await;
+        assert_eq!(notifier.calls(), 0);
+        assert_eq!(manager.deliver_focus_digest().await, 0);
+
+        focus.deactivate();
+        assert_eq!(manager.deliver_focus_digest().await, 2);
+        assert_eq!(notifier.calls(), 1, "one digest notification");
+
+        manager.notify("Heads up", "Tests passed").await;
+        assert_eq!(notifier.calls(), 2);
+    }
+
     #[tokio::test]
     async fn idle_notification_triggers() {
         let config = NotificationConfig {
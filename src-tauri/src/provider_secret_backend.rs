@@ -6,6 +6,7 @@ use oneshim_core::error::CoreError;
 use oneshim_core::ports::secret_store::SecretStore;
 #[cfg(feature = "server")]
 use oneshim_core::ports::secret_store::SecretStoreSet;
+use oneshim_storage::encryption::EncryptionKey;
 use oneshim_storage::env_secret_store::EnvSecretStore;
 use oneshim_storage::file_secret_store::FileSecretStore;
 use oneshim_storage::keychain::{KeychainOps, KeychainSecretStore};
@@ -13,6 +14,8 @@ use oneshim_storage::keychain::{KeychainOps, KeychainSecretStore};
 pub const ONESHIM_PROVIDER_SECRET_BACKEND_ENV: &str = "ONESHIM_PROVIDER_SECRET_BACKEND";
 pub const ONESHIM_PROVIDER_SECRET_FILE_ENV: &str = "ONESHIM_PROVIDER_SECRET_FILE";
 pub const FILE_SECRET_STORE_NAME: &str = "oneshim-secrets.json";
+/// Key for the encrypted file fallback, kept next to the registry.
+const FILE_SECRET_KEY_NAME: &str = "oneshim-secrets.key";
 const KEYCHAIN_REGISTRY_FILE_NAME: &str = "oneshim-keychain-registry.json";
 
 #[derive(Clone)]
@@ -39,8 +42,24 @@ pub fn create_os_secret_store(config_dir: &Path) -> Option<Arc<dyn SecretStore>>
     }
 }
 
+/// Encrypted-file fallback for hosts without a usable OS keychain.
 pub fn create_file_secret_store(config_dir: &Path) -> Result<Arc<dyn SecretStore>, CoreError> {
-    Ok(Arc::new(FileSecretStore::new(file_secret_store_path(config_dir))?) as Arc<dyn SecretStore>)
+    Ok(Arc::new(open_file_secret_store(config_dir)?) as Arc<dyn SecretStore>)
+}
+
+/// OS keychain when available, otherwise the encrypted file store.
+pub fn create_app_secret_store(config_dir: &Path) -> Result<Arc<dyn SecretStore>, CoreError> {
+    match create_os_secret_store(config_dir) {
+        Some(store) => Ok(store),
+        None => create_file_secret_store(config_dir),
+    }
+}
+
+fn open_file_secret_store(config_dir: &Path) -> Result<FileSecretStore, CoreError> {
+    let registry_path = file_secret_store_path(config_dir);
+    let key_path = registry_path.with_file_name(FILE_SECRET_KEY_NAME);
+    let key = EncryptionKey::load_or_create_at(&key_path)?;
+    Ok(FileSecretStore::encrypted(registry_path, key)?)
 }
 
 pub fn create_env_secret_store() -> Arc<dyn SecretStore> {
@@ -61,6 +80,12 @@ pub fn resolve_provider_secret_backend(
                     backend_kind: CredentialBackendKind::OsSecretStore,
                     fallback_backend_kind: CredentialBackendKind::Unavailable,
                 }
+            } else if requested_backend == RequestedProviderSecretBackend::Auto {
+                ProviderSecretBackendResolution {
+                    secret_store: Some(create_file_secret_store(config_dir)?),
+                    backend_kind: CredentialBackendKind::FileSecretStore,
+                    fallback_backend_kind: CredentialBackendKind::Unavailable,
+                }
             } else {
                 ProviderSecretBackendResolution {
                     secret_store: None,
@@ -70,9 +95,7 @@ pub fn resolve_provider_secret_backend(
             }
         }
         RequestedProviderSecretBackend::FileSecretStore => ProviderSecretBackendResolution {
-            secret_store: Some(Arc::new(FileSecretStore::new(file_secret_store_path(
-                config_dir,
-            ))?)),
+            secret_store: Some(create_file_secret_store(config_dir)?),
             backend_kind: CredentialBackendKind::FileSecretStore,
             fallback_backend_kind: CredentialBackendKind::Unavailable,
         },
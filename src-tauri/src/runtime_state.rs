@@ -1,6 +1,6 @@
 use oneshim_core::config::{AppConfig, CredentialBackendKind};
 use oneshim_core::config_manager::ConfigManager;
-use oneshim_core::consent::ConsentManager;
+use oneshim_core::consent::{ConsentManager, ConsentRegistry};
 use oneshim_core::ports::accessibility::AccessibilityExtractor;
 use oneshim_core::ports::audio_capture::AudioCapturePort;
 use oneshim_core::ports::coaching::CoachingPort;
@@ -9,7 +9,9 @@ use oneshim_core::ports::frame_storage::FrameStoragePort;
 use oneshim_core::ports::integration::{IntegrationAuthPort, IntegrationSessionPort};
 use oneshim_core::ports::model_downloader::ModelDownloader;
 use oneshim_core::ports::monitor::ActivityMonitor;
+use oneshim_core::ports::network_metrics::NetworkMetricsProvider;
 use oneshim_core::ports::oauth::OAuthPort;
+use oneshim_core::ports::server_circuit::ServerCircuitStatus;
 use oneshim_core::ports::session_storage::SessionStoragePort;
 use oneshim_core::ports::stt_provider::SttProvider;
 use oneshim_core::ports::vision::FrameProcessor;
@@ -367,6 +369,9 @@ pub struct ConnectionStatus {
     pub llm_connected: Arc<AtomicBool>,
     /// CLI bridge / automation controller connectivity.
     pub cli_connected: Arc<AtomicBool>,
+    /// Server circuit breaker, for "retrying in Ns" while it is open.
+    /// `None` without the `server` feature.
+    pub server_circuit: Option<Arc<dyn ServerCircuitStatus>>,
 }
 
 #[allow(dead_code)] // runtime_handle/update_control stored for future scheduler access
@@ -394,19 +399,26 @@ pub struct AppState {
     pub connection: ConnectionStatus,
     /// Focus mode state — transient, not persisted. Suppresses coaching + notifications.
     pub focus_mode: Arc<crate::focus_mode::FocusModeState>,
+    /// Metrics sampling rate — base interval plus any temporary burst.
+    pub metrics_rate: Arc<crate::scheduler::metrics_rate::MetricsRate>,
+    /// Per-capability consent decisions (screen capture, OCR, clipboard, input).
+    pub consent_registry: Arc<ConsentRegistry>,
     /// Capture-related resources for IPC commands (A1, A2).
     pub capture: CaptureContext,
     /// Analysis provider health (None when no LLM configured).
     pub analysis_health: Option<AnalysisHealthFlags>,
     /// Regime state persistence port. Populated by the composition root
     /// (`app_runtime_launch.rs`). The save-guard in
-    /// `main.rs::RunEvent::Exit` short-circuits on `None` — which only
+    /// `lib.rs::RunEvent::Exit` short-circuits on `None` — which only
     /// happens in test builders that skip wiring.
     pub regime_storage: Option<Arc<dyn oneshim_core::ports::regime_storage::RegimeStoragePort>>,
     /// Shared handle to the `RegimeManager` so the shutdown path can read
     /// the current regime set and hand it to `regime_storage.save_all`.
     /// Populated by the composition root; `None` only in test builders.
     pub regime_manager_snapshot: Option<Arc<parking_lot::Mutex<oneshim_analysis::RegimeManager>>>,
+    /// Server request metrics, summarised in the log at shutdown.
+    /// `None` without the `server` feature.
+    pub network_metrics: Option<Arc<dyn NetworkMetricsProvider>>,
 }
 
 pub struct OAuthState(pub Option<Arc<dyn OAuthPort>>);
@@ -574,6 +586,18 @@ impl ManagedStateBuilder {
         self
     }
 
+    #[cfg(feature = "server")]
+    pub(crate) fn with_server_circuit(mut self, circuit: Arc<dyn ServerCircuitStatus>) -> Self {
+        self.app_state.connection.server_circuit = Some(circuit);
+        self
+    }
+
+    #[cfg(feature = "server")]
+    pub(crate) fn with_network_metrics(mut self, metrics: Arc<dyn NetworkMetricsProvider>) -> Self {
+        self.app_state.network_metrics = Some(metrics);
+        self
+    }
+
     pub(crate) fn build(self) -> ManagedStateRegistration {
         let oauth_available = self.oauth_state.0.is_some();
         let secret_backend_state = SecretBackendState(secret_backend_capabilities(
@@ -696,8 +720,15 @@ mod tests {
                     server_connected: Arc::new(AtomicBool::new(false)),
                     llm_connected: Arc::new(AtomicBool::new(false)),
                     cli_connected: Arc::new(AtomicBool::new(false)),
+                    server_circuit: None,
                 },
                 focus_mode: Arc::new(crate::focus_mode::FocusModeState::new()),
+                metrics_rate: Arc::new(crate::scheduler::metrics_rate::MetricsRate::new(
+                    std::time::Duration::from_secs(5),
+                )),
+                consent_registry: Arc::new(ConsentRegistry::open(
+                    temp_dir.path().join("consent_registry.json"),
+                )),
                 capture: CaptureContext {
                     frame_processor: None,
                     frame_storage: None,
@@ -709,6 +740,7 @@ mod tests {
                 analysis_health: None,
                 regime_storage: None,
                 regime_manager_snapshot: None,
+                network_metrics: None,
             },
             ConfigRuntimeState::new(config_manager, web_port),
         )
@@ -50,6 +50,12 @@ impl oneshim_core::ports::storage::StorageService for NoopStorage {
     ) -> Result<(), CoreError> {
         Ok(())
     }
+    async fn save_events(
+        &self,
+        _events: &[oneshim_core::models::event::Event],
+    ) -> Result<(), CoreError> {
+        Ok(())
+    }
     async fn get_events(
         &self,
         _from: DateTime<Utc>,
@@ -67,9 +73,21 @@ impl oneshim_core::ports::storage::StorageService for NoopStorage {
     async fn mark_as_sent(&self, _event_ids: &[String]) -> Result<(), CoreError> {
         Ok(())
     }
+    async fn get_idempotency_keys(
+        &self,
+        _event_ids: &[String],
+    ) -> Result<std::collections::HashMap<String, String>, CoreError> {
+        Ok(Default::default())
+    }
     async fn mark_unsent_as_sent_before(&self, _before: DateTime<Utc>) -> Result<usize, CoreError> {
         Ok(0)
     }
+    async fn quarantine_events(
+        &self,
+        _events: &[oneshim_core::models::event::QuarantinedEvent],
+    ) -> Result<(), CoreError> {
+        Ok(())
+    }
     async fn enforce_retention(&self) -> Result<usize, CoreError> {
         Ok(0)
     }
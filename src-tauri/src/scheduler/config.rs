@@ -1,6 +1,9 @@
 use base64::Engine;
 use chrono::{DateTime, Utc};
-use oneshim_core::config::{AnalysisConfig, ExternalDataPolicy, PrivacyConfig};
+use oneshim_core::config::{
+    AnalysisConfig, AppConfig, ExternalDataPolicy, LoopWatchdogConfig, PrivacyConfig,
+    SchedulerLoopsConfig,
+};
 use oneshim_core::error::CoreError;
 use oneshim_core::models::context::WindowBounds;
 use oneshim_core::models::event::Event;
@@ -9,6 +12,7 @@ use oneshim_core::models::tiered_memory::SegmentSummary;
 use oneshim_core::ports::storage::MetricsStorage;
 use oneshim_storage::sqlite::SqliteStorage;
 use oneshim_vision::privacy::{sanitize_title_with_level, should_exclude};
+use oneshim_vision::privacy_gateway::permit_event_upload;
 use std::time::Duration;
 
 pub trait SchedulerStorage: MetricsStorage + Send + Sync {
@@ -20,6 +24,14 @@ pub trait SchedulerStorage: MetricsStorage + Send + Sync {
         bounds: Option<&WindowBounds>,
     ) -> Result<i64, CoreError>;
 
+    /// Insert a buffered burst of frames in one transaction. Returns the new
+    /// row ids in input order.
+    #[allow(dead_code)] // Flushed from a capture buffer once burst batching is wired
+    fn save_frames_metadata(
+        &self,
+        frames: &[oneshim_core::models::storage_records::NewFrameMetadata<'_>],
+    ) -> Result<Vec<i64>, CoreError>;
+
     /// Check whether server-sourced suggestions exist within the given lookback
     /// window (in seconds). Used by the analysis loop to suppress local LLM
     /// analysis when the server is actively providing suggestions.
@@ -102,6 +114,24 @@ pub trait SchedulerStorage: MetricsStorage + Send + Sync {
     /// bulk operations (IVF index builds, large batch inserts).
     #[allow(dead_code)] // Called after bulk IVF index builds in maintenance loop
     fn run_analyze(&self) -> Result<(), CoreError>;
+
+    /// Snapshot the database via the online backup API, keeping the newest
+    /// `keep` snapshots.
+    fn create_database_backup(
+        &self,
+        keep: usize,
+    ) -> Result<oneshim_core::models::storage_records::DatabaseBackupRecord, CoreError>;
+
+    /// Apply rule-derived tags to a saved frame, marked as auto-applied.
+    /// Returns how many tags were newly applied.
+    fn auto_tag_frame(&self, frame_id: i64, tags: &[String]) -> Result<usize, CoreError>;
+
+    /// Persist one snapshot of the frame pipeline's per-stage latency.
+    fn save_capture_latency(
+        &self,
+        recorded_at: chrono::DateTime<chrono::Utc>,
+        summaries: &[oneshim_core::models::capture_latency::StageLatencySummary],
+    ) -> Result<usize, CoreError>;
 }
 
 impl SchedulerStorage for SqliteStorage {
@@ -116,6 +146,13 @@ impl SchedulerStorage for SqliteStorage {
             .map_err(Into::into)
     }
 
+    fn save_frames_metadata(
+        &self,
+        frames: &[oneshim_core::models::storage_records::NewFrameMetadata<'_>],
+    ) -> Result<Vec<i64>, CoreError> {
+        SqliteStorage::save_frames_metadata(self, frames).map_err(Into::into)
+    }
+
     fn has_recent_server_suggestions(&self, lookback_secs: u64) -> Result<bool, CoreError> {
         SqliteStorage::has_recent_server_suggestions(self, lookback_secs).map_err(Into::into)
     }
@@ -207,6 +244,25 @@ impl SchedulerStorage for SqliteStorage {
     fn run_analyze(&self) -> Result<(), CoreError> {
         SqliteStorage::run_analyze(self).map_err(Into::into)
     }
+
+    fn create_database_backup(
+        &self,
+        keep: usize,
+    ) -> Result<oneshim_core::models::storage_records::DatabaseBackupRecord, CoreError> {
+        SqliteStorage::create_database_backup(self, keep).map_err(Into::into)
+    }
+
+    fn auto_tag_frame(&self, frame_id: i64, tags: &[String]) -> Result<usize, CoreError> {
+        SqliteStorage::auto_tag_frame(self, frame_id, tags).map_err(Into::into)
+    }
+
+    fn save_capture_latency(
+        &self,
+        recorded_at: chrono::DateTime<chrono::Utc>,
+        summaries: &[oneshim_core::models::capture_latency::StageLatencySummary],
+    ) -> Result<usize, CoreError> {
+        SqliteStorage::save_capture_latency(self, recorded_at, summaries).map_err(Into::into)
+    }
 }
 
 pub(super) fn base64_decode(input: &str) -> Result<Vec<u8>, String> {
@@ -264,6 +320,9 @@ impl PlatformEgressPolicy {
         if !self.enabled {
             return None;
         }
+        if permit_event_upload(&self.privacy_config, &event).is_err() {
+            return None;
+        }
 
         match &mut event {
             Event::Context(ctx) => {
@@ -333,10 +392,20 @@ pub struct SchedulerConfig {
     pub external_data_policy: ExternalDataPolicy,
     pub privacy_config: PrivacyConfig,
     pub idle_threshold_secs: u64,
+    /// Gap under which a new idle period reopens the previous one.
+    pub idle_merge_threshold_secs: u64,
     pub upload_enabled: bool,
     pub analysis_config: AnalysisConfig,
     /// Interval for cross-device sync loop (P3 Phase 3a-2).
     pub cross_device_sync_interval: Duration,
+    /// Stalled-loop detection and optional restart.
+    pub watchdog: LoopWatchdogConfig,
+    /// Which loops `run` spawns.
+    pub loops: SchedulerLoopsConfig,
+    /// Interval between database snapshots; `None` disables the loop.
+    pub db_backup_interval: Option<Duration>,
+    /// Database snapshots kept by rotation.
+    pub db_backup_keep: usize,
 }
 
 impl Default for SchedulerConfig {
@@ -354,9 +423,39 @@ impl Default for SchedulerConfig {
             external_data_policy: ExternalDataPolicy::default(),
             privacy_config: PrivacyConfig::default(),
             idle_threshold_secs: 300, // 5 min
+            idle_merge_threshold_secs: 0,
             upload_enabled: false,
             analysis_config: AnalysisConfig::default(),
             cross_device_sync_interval: Duration::from_secs(300), // 5 min default
+            watchdog: LoopWatchdogConfig::default(),
+            loops: SchedulerLoopsConfig::default(),
+            db_backup_interval: None,
+            db_backup_keep: 7,
+        }
+    }
+}
+
+impl SchedulerConfig {
+    /// Derive loop intervals and policies from the user's `AppConfig`.
+    pub fn from_app_config(config: &AppConfig, session_id: String) -> Self {
+        Self {
+            poll_interval: Duration::from_millis(config.monitor.poll_interval_ms),
+            sync_interval: Duration::from_millis(config.monitor.sync_interval_ms),
+            heartbeat_interval: Duration::from_millis(config.monitor.heartbeat_interval_ms),
+            metrics_interval: Duration::from_millis(config.monitor.metrics_interval_ms),
+            session_id,
+            external_data_policy: config.ai_provider.external_data_policy,
+            privacy_config: config.privacy.clone(),
+            idle_merge_threshold_secs: config.monitor.idle_merge_threshold_secs,
+            upload_enabled: config.monitor.upload_enabled,
+            analysis_config: config.analysis.clone(),
+            watchdog: config.monitor.watchdog.clone(),
+            loops: config.monitor.loops.clone(),
+            db_backup_interval: (config.storage.db_backup_interval_hours > 0).then(|| {
+                Duration::from_secs(u64::from(config.storage.db_backup_interval_hours) * 3600)
+            }),
+            db_backup_keep: config.storage.db_backup_keep,
+            ..Self::default()
         }
     }
 }
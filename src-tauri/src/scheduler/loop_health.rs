@@ -0,0 +1,93 @@
+//! Per-loop heartbeat registry shared between the scheduler loops, the
+//! watchdog, and the dashboard (`GET /api/dashboard/loops`).
+
+use chrono::{DateTime, Utc};
+use oneshim_core::models::loop_health::{self, LoopHeartbeat};
+use oneshim_core::ports::loop_health::LoopHealthProvider;
+use parking_lot::RwLock;
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+pub(crate) struct LoopHealthRegistry {
+    heartbeats: RwLock<BTreeMap<&'static str, LoopHeartbeat>>,
+    stall_multiplier: u32,
+}
+
+impl LoopHealthRegistry {
+    pub(crate) fn new(stall_multiplier: u32) -> Self {
+        Self {
+            heartbeats: RwLock::new(BTreeMap::new()),
+            stall_multiplier: stall_multiplier.max(1),
+        }
+    }
+
+    /// Record one tick of `name`. The first tick registers the loop, so loops
+    /// that exit early because their feature is disabled never show up.
+    pub(crate) fn beat(&self, name: &'static str, interval: Duration) {
+        self.beat_at(name, interval, Utc::now());
+    }
+
+    fn beat_at(&self, name: &'static str, interval: Duration, now: DateTime<Utc>) {
+        self.heartbeats
+            .write()
+            .entry(name)
+            .and_modify(|hb| hb.record_tick(now))
+            .or_insert_with(|| LoopHeartbeat::new(name, interval, now));
+    }
+
+    /// Count a watchdog restart and give the new task a fresh deadline.
+    pub(crate) fn record_restart(&self, name: &str) {
+        if let Some(hb) = self.heartbeats.write().get_mut(name) {
+            hb.restart_count = hb.restart_count.saturating_add(1);
+            hb.last_tick_at = Utc::now();
+        }
+    }
+
+    pub(crate) fn stalled_at(&self, now: DateTime<Utc>) -> Vec<String> {
+        loop_health::stalled_loops(&self.heartbeats(), now, self.stall_multiplier)
+    }
+}
+
+impl LoopHealthProvider for LoopHealthRegistry {
+    fn heartbeats(&self) -> Vec<LoopHeartbeat> {
+        self.heartbeats.read().values().cloned().collect()
+    }
+
+    fn stall_multiplier(&self) -> u32 {
+        self.stall_multiplier
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn silent_loop_is_reported_as_stalled() {
+        let registry = LoopHealthRegistry::new(3);
+        let start = Utc::now();
+        registry.beat_at("metrics", Duration::from_secs(5), start);
+        registry.beat_at("process", Duration::from_secs(10), start);
+
+        // metrics keeps ticking, process goes silent.
+        let later = start + chrono::Duration::seconds(70);
+        registry.beat_at("metrics", Duration::from_secs(5), later);
+
+        assert_eq!(registry.stalled_at(later), vec!["process".to_string()]);
+    }
+
+    #[test]
+    fn restart_resets_deadline_and_counts() {
+        let registry = LoopHealthRegistry::new(3);
+        registry.beat_at(
+            "sync",
+            Duration::from_secs(10),
+            Utc::now() - chrono::Duration::seconds(120),
+        );
+        assert_eq!(registry.stalled_at(Utc::now()), vec!["sync".to_string()]);
+
+        registry.record_restart("sync");
+        assert!(registry.stalled_at(Utc::now()).is_empty());
+        assert_eq!(registry.heartbeats()[0].restart_count, 1);
+    }
+}
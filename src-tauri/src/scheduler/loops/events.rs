@@ -1,5 +1,7 @@
 use chrono::Utc;
-use oneshim_core::models::event::{Event, ProcessSnapshotEvent};
+use oneshim_core::consent::Capability;
+use oneshim_core::models::event::{Event, FileEventType, ProcessSnapshotEvent};
+use oneshim_core::ports::vision::CaptureSignal;
 use oneshim_monitor::input_activity::InputActivityCollector;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
@@ -28,6 +30,7 @@ impl Scheduler {
         // D13: 4-term privacy gate DI — clone singletons for the async block.
         let config9 = self.config_manager.clone();
         let consent9 = self.consent_manager.clone();
+        let consent_registry9 = self.consent_registry.clone();
         let capture_paused9 = self.capture_paused.clone();
 
         // Clipboard monitor — polls system clipboard for changes each input tick.
@@ -54,6 +57,8 @@ impl Scheduler {
         let file_watcher = Arc::new(oneshim_monitor::file_access::FileAccessWatcher::new(
             file_access_config,
         ));
+        let loop_health = self.loop_health.clone();
+        let pending_capture_signal = self.pending_capture_signal.clone();
 
         tokio::spawn(async move {
             let mut process_interval = tokio::time::interval(detailed_process_interval);
@@ -63,6 +68,7 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = process_interval.tick() => {
+                        loop_health.beat("event_snapshot", process_interval.period());
                         // Row 7: 4-term composite gate (CONS-PC02 / D13).
                         let consent = consent9.as_ref()
                             .and_then(|cm| cm.current_consent().map(|r| r.permissions.clone()))
@@ -95,8 +101,9 @@ impl Scheduler {
                                 }
 
                                 if let Some(ref sink) = uploader9 {
+                                    let local_id = event.local_id();
                                     if let Some(upload_event) = egress9.prepare_event_for_upload(event) {
-                                        sink.enqueue(upload_event);
+                                        sink.enqueue_local(local_id, upload_event);
                                     }
                                 }
 
@@ -108,6 +115,7 @@ impl Scheduler {
                         }
                     }
                     _ = input_interval.tick() => {
+                        loop_health.beat("event_snapshot", input_interval.period());
                         // Rows 8-10: 4-term composite gate — input, clipboard, file-access
                         // sub-branches all inside this block, so a single gate covers all three
                         // (CONS-PC02 / D13).
@@ -122,11 +130,17 @@ impl Scheduler {
                             debug!("event_snapshot(input/clipboard/file): capture gate closed (TS/consent/paused) — skipping tick");
                             continue;
                         }
+                        // Per-capability consent — read every tick so a revocation
+                        // stops collection without a restart.
+                        let granted = |capability: Capability| consent_registry9
+                            .as_ref()
+                            .map_or(true, |registry| registry.is_granted(capability));
                         let input_event = input_collector9.take_snapshot();
 
-                        if input_event.mouse.click_count > 0
+                        if granted(Capability::InputMonitoring)
+                            && (input_event.mouse.click_count > 0
                             || input_event.keyboard.total_keystrokes > 0
-                            || input_event.mouse.scroll_count > 0
+                            || input_event.mouse.scroll_count > 0)
                         {
                             let event = Event::Input(input_event);
                             if let Err(e) = storage9.save_event(&event).await {
@@ -134,8 +148,9 @@ impl Scheduler {
                             }
 
                             if let Some(ref sink) = uploader9 {
+                                let local_id = event.local_id();
                                 if let Some(upload_event) = egress9.prepare_event_for_upload(event) {
-                                    sink.enqueue(upload_event);
+                                    sink.enqueue_local(local_id, upload_event);
                                 }
                             }
                         }
@@ -143,14 +158,16 @@ impl Scheduler {
                         // Poll clipboard for changes (non-blocking on macOS/Linux/Windows).
                         // Runs on the same cadence as input activity collection.
                         let cb = clipboard_monitor.clone();
+                        let clipboard_granted = granted(Capability::Clipboard);
                         if let Some(clip_event) = tokio::task::spawn_blocking(move || {
-                            cb.poll_system_clipboard()
+                            clipboard_granted.then(|| cb.poll_system_clipboard()).flatten()
                         }).await.unwrap_or(None) {
                             debug!(
                                 content_type = ?clip_event.content_type,
                                 chars = clip_event.char_count,
                                 "clipboard change detected"
                             );
+                            *pending_capture_signal.lock() = Some(CaptureSignal::ClipboardChange);
                             let event = Event::Clipboard(clip_event);
                             if let Err(e) = storage9.save_event(&event).await {
                                 warn!(err.code = %e.code(), "clipboard event save failure: {e}");
@@ -162,16 +179,21 @@ impl Scheduler {
                         let file_events = tokio::task::spawn_blocking(move || {
                             fw.poll_changes()
                         }).await.unwrap_or_default();
+                        let mut file_access_events = Vec::with_capacity(file_events.len());
                         for file_event in file_events {
                             debug!(
                                 event_type = ?file_event.event_type,
                                 path = %file_event.relative_path.display(),
                                 "file change detected"
                             );
-                            let event = Event::FileAccess(file_event);
-                            if let Err(e) = storage9.save_event(&event).await {
-                                warn!(err.code = %e.code(), "file access event save failure: {e}");
+                            if matches!(file_event.event_type, FileEventType::Created | FileEventType::Modified) {
+                                *pending_capture_signal.lock() = Some(CaptureSignal::FileSave);
                             }
+                            file_access_events.push(Event::FileAccess(file_event));
+                        }
+                        // A checkout or build can touch hundreds of files in one poll.
+                        if let Err(e) = storage9.save_events(&file_access_events).await {
+                            warn!(err.code = %e.code(), "file access event save failure: {e}");
                         }
                     }
                     _ = shutdown_rx.changed() => {
@@ -190,6 +212,7 @@ impl Scheduler {
         mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
     ) -> tokio::task::JoinHandle<()> {
         let notif7 = self.notification_manager.clone();
+        let loop_health = self.loop_health.clone();
 
         tokio::spawn(async move {
             let notif = match notif7 {
@@ -204,11 +227,15 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("notification", interval.period());
                         // A4: Suppress notifications when focus mode active
                         if !focus_mode.is_active() {
                             notif.check_long_session().await;
                         }
                     }
+                    _ = focus_mode.ended() => {
+                        notif.deliver_focus_digest().await;
+                    }
                     _ = shutdown_rx.changed() => {
                         info!("notification ended");
                         break;
@@ -1,8 +1,18 @@
+use oneshim_core::models::connectivity::{
+    ConnectivityDebouncer, ConnectivityState, ConnectivityTransition,
+};
+use oneshim_core::models::event::{Event, SystemEvent, SystemEventType};
+use oneshim_core::ports::storage::StorageService;
+use oneshim_web::{ConnectivityUpdate, RealtimeEvent};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
 use tauri::{AppHandle, Emitter, Manager};
-use tracing::{debug, info};
+use tokio::sync::broadcast;
+use tracing::{debug, info, warn};
+
+/// A server state change must hold this long before it is recorded.
+const CONNECTIVITY_DEBOUNCE: Duration = Duration::from_secs(30);
 
 /// Adapter-side health flags — written by adapters on success/failure.
 pub(crate) struct AdapterHealthFlags {
@@ -18,19 +28,47 @@ pub(crate) struct ConnectionFlags {
     pub cli: Arc<AtomicBool>,
 }
 
+/// Where debounced server connectivity transitions are recorded.
+pub(crate) struct ConnectivitySinks {
+    pub storage: Arc<dyn StorageService>,
+    pub event_tx: Option<broadcast::Sender<RealtimeEvent>>,
+}
+
+/// Builds the stored system event and the dashboard update for a transition.
+pub(crate) fn connectivity_events(transition: &ConnectivityTransition) -> (Event, RealtimeEvent) {
+    let event = Event::System(SystemEvent {
+        event_id: uuid::Uuid::new_v4(),
+        event_type: SystemEventType::ConnectivityChanged,
+        timestamp: transition.changed_at,
+        data: serde_json::json!({
+            "from": transition.from.as_str(),
+            "to": transition.to.as_str(),
+        }),
+    });
+    let update = RealtimeEvent::Connectivity(ConnectivityUpdate {
+        timestamp: transition.changed_at.to_rfc3339(),
+        from: transition.from.as_str().to_string(),
+        to: transition.to.as_str().to_string(),
+    });
+    (event, update)
+}
+
 /// Spawn a periodic health check loop that reads adapter health flags,
 /// updates connection status flags, and emits Tauri events on change.
+/// Server online/offline transitions are debounced and recorded via `sinks`.
 ///
 /// Uses concrete `AppHandle` (Wry runtime), not generic `<R: Runtime>`.
 pub(crate) fn spawn_health_check_loop(
     interval: Duration,
     adapter_flags: AdapterHealthFlags,
     connection_flags: ConnectionFlags,
+    sinks: ConnectivitySinks,
     app_handle: AppHandle,
     mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
 ) -> tokio::task::JoinHandle<()> {
     tokio::spawn(async move {
         let mut ticker = tokio::time::interval(interval);
+        let mut debouncer = ConnectivityDebouncer::new(CONNECTIVITY_DEBOUNCE);
         loop {
             tokio::select! {
                 _ = ticker.tick() => {
@@ -42,6 +80,20 @@ pub(crate) fn spawn_health_check_loop(
                     let prev_llm = connection_flags.llm.swap(llm, Ordering::Relaxed);
                     let prev_cli = connection_flags.cli.swap(cli, Ordering::Relaxed);
 
+                    let state = if srv { ConnectivityState::Online } else { ConnectivityState::Offline };
+                    if let Some(transition) = debouncer.observe(state, chrono::Utc::now()) {
+                        let (event, update) = connectivity_events(&transition);
+                        if let Err(e) = sinks.storage.save_event(&event).await {
+                            warn!(err.code = %e.code(), "connectivity event save failure: {e}");
+                        }
+                        if let Some(tx) = sinks.event_tx.as_ref() {
+                            if let Err(e) = tx.send(update) {
+                                debug!("connectivity event channel send failed: {e}");
+                            }
+                        }
+                        info!(from = transition.from.as_str(), to = transition.to.as_str(), "server connectivity changed");
+                    }
+
                     if prev_srv != srv || prev_llm != llm || prev_cli != cli {
                         let payload = serde_json::json!({
                             "server": srv, "llm": llm, "cli": cli
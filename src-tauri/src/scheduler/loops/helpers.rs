@@ -1,13 +1,16 @@
 use std::sync::Arc;
 use tracing::{debug, info, warn};
 
-use chrono::Utc;
-use oneshim_api_contracts::stream::{FrameUpdate, IdleUpdate, RealtimeEvent};
+use chrono::{DateTime, DurationRound, Utc};
+use oneshim_api_contracts::stream::{DiskSpaceUpdate, FrameUpdate, IdleUpdate, RealtimeEvent};
+use oneshim_core::config::AutoTagRules;
+use oneshim_core::error::CoreError;
 use oneshim_core::models::activity::IdleState;
-use oneshim_core::models::event::InputActivityEvent;
-use oneshim_core::models::frame::{ImagePayload, OcrRegion};
+use oneshim_core::models::event::{ContextEvent, Event, InputActivityEvent};
+use oneshim_core::models::frame::{truncate_ocr_text, FrameMetadata, ImagePayload, OcrRegion};
 use oneshim_core::models::storage_records::SegmentSummaryRecord;
 use oneshim_core::models::tiered_memory::{ContentActivity, SegmentSummary, TriggerReason};
+use oneshim_core::ports::batch_sink::BatchSink;
 use oneshim_core::ports::frame_storage::FrameStoragePort;
 use oneshim_core::ports::storage::StorageService;
 use oneshim_core::ports::vision::{CaptureRequest, FrameProcessor};
@@ -15,7 +18,8 @@ use oneshim_monitor::idle::IdleTracker;
 use oneshim_monitor::input_activity::InputActivityCollector;
 use tokio::sync::broadcast;
 
-use super::super::config::{base64_decode, SchedulerStorage};
+use super::super::config::{base64_decode, PlatformEgressPolicy, SchedulerStorage};
+use super::super::presence_state::SharedPresenceState;
 use crate::magic_overlay::MagicOverlayHandle;
 use crate::notification_manager::NotificationManager;
 
@@ -112,6 +116,33 @@ pub(super) async fn handle_event_analysis(
     }
 }
 
+/// Save context events released by the sampler, bump the session event
+/// counter and queue them for upload.
+pub(super) async fn persist_context_events(
+    events: Vec<ContextEvent>,
+    storage: &Arc<dyn StorageService>,
+    sqlite: &Arc<dyn SchedulerStorage>,
+    session_id: &str,
+    uploader: &Option<Arc<dyn BatchSink>>,
+    egress: &PlatformEgressPolicy,
+) {
+    for event in events {
+        let ctx_event = Event::Context(event);
+        if let Err(e) = storage.save_event(&ctx_event).await {
+            warn!(err.code = %e.code(), "event save failure: {e}");
+        }
+        if let Err(e) = sqlite.increment_session_counters(session_id, 1, 0, 0).await {
+            debug!("increment_session_counters failed: {e}");
+        }
+        if let Some(sink) = uploader {
+            let local_id = ctx_event.local_id();
+            if let Some(upload_event) = egress.prepare_event_for_upload(ctx_event) {
+                sink.enqueue_local(local_id, upload_event);
+            }
+        }
+    }
+}
+
 /// Capture a frame, process it (full/delta/thumbnail), save image data and
 /// metadata.  Returns the OCR text extracted from the frame (if any) and
 /// any OCR regions with bounding boxes for GUI element correlation.
@@ -121,6 +152,7 @@ pub(super) async fn handle_event_analysis(
 type FrameCaptureResult = (Option<String>, Vec<OcrRegion>, Option<(Vec<u8>, u32, u32)>);
 
 #[tracing::instrument(skip_all)]
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn handle_frame_capture(
     capture_req: &CaptureRequest,
     processor: &Arc<dyn FrameProcessor>,
@@ -128,6 +160,8 @@ pub(super) async fn handle_frame_capture(
     sqlite: &Arc<dyn SchedulerStorage>,
     session_id: &str,
     pii_filter_level: oneshim_core::config::PiiFilterLevel,
+    ocr_max_chars: Option<usize>,
+    auto_tags: &AutoTagRules,
     event_tx: &Option<broadcast::Sender<RealtimeEvent>>,
 ) -> FrameCaptureResult {
     match processor.capture_and_process(capture_req).await {
@@ -151,13 +185,21 @@ pub(super) async fn handle_frame_capture(
                 let saved_path = if let Some(ref fs) = frame_storage {
                     match base64_decode(data_str) {
                         Ok(webp_bytes) => {
-                            match fs.save_frame(frame.metadata.timestamp, &webp_bytes).await {
-                                Ok(path) => Some(path.to_string_lossy().to_string()),
-                                Err(e) => {
-                                    warn!("frame file save failure: {e}");
-                                    None
-                                }
-                            }
+                            let saved =
+                                match fs.save_frame(frame.metadata.timestamp, &webp_bytes).await {
+                                    Ok(path) => Some(path.to_string_lossy().to_string()),
+                                    // Storage already warned once when writes stopped.
+                                    Err(e) if fs.image_writes_suspended() => {
+                                        debug!("frame image skipped: {e}");
+                                        None
+                                    }
+                                    Err(e) => {
+                                        warn!("frame file save failure: {e}");
+                                        None
+                                    }
+                                };
+                            publish_disk_pressure_change(fs.as_ref(), event_tx);
+                            saved
                         }
                         Err(e) => {
                             warn!("Base64 decoding failure: {e}");
@@ -179,7 +221,12 @@ pub(super) async fn handle_frame_capture(
             // screenshot. Sanitize at the write boundary before frames.ocr_text
             // persists.
             let sanitized_ocr = ocr_text.as_deref().map(|raw| {
-                oneshim_vision::privacy::sanitize_title_with_level(raw, pii_filter_level)
+                let sanitized =
+                    oneshim_vision::privacy::sanitize_title_with_level(raw, pii_filter_level);
+                match ocr_max_chars {
+                    Some(max_chars) => truncate_ocr_text(&sanitized, max_chars).into_owned(),
+                    None => sanitized,
+                }
             });
             match sqlite.save_frame_metadata_with_bounds(
                 &frame.metadata,
@@ -188,6 +235,7 @@ pub(super) async fn handle_frame_capture(
                 capture_req.window_bounds.as_ref(),
             ) {
                 Ok(frame_id) => {
+                    apply_auto_tags(sqlite.as_ref(), frame_id, &frame.metadata, auto_tags);
                     // Emit FrameUpdate after successful DB insert. Fields sourced from
                     // in-memory frame.metadata — no DB round-trip needed (spec §B).
                     if let Some(tx) = event_tx.as_ref() {
@@ -198,6 +246,7 @@ pub(super) async fn handle_frame_capture(
                             window_title: frame.metadata.window_title.clone(),
                             importance: frame.metadata.importance,
                             trigger_type: frame.metadata.trigger_type.clone(),
+                            preview: frame.preview.clone(),
                         };
                         if let Err(e) = tx.send(RealtimeEvent::Frame(update)) {
                             debug!("frame event channel send failed: {e}");
@@ -213,6 +262,11 @@ pub(super) async fn handle_frame_capture(
 
             (ocr_text, ocr_regions, raw_rgba)
         }
+        // Expected while the user has revoked screen capture; not a failure.
+        Err(e @ CoreError::ConsentRequired { .. }) => {
+            debug!("frame skipped: {e}");
+            (None, Vec::new(), None)
+        }
         Err(e) => {
             warn!("frame failure: {e}");
             (None, Vec::new(), None)
@@ -220,28 +274,100 @@ pub(super) async fn handle_frame_capture(
     }
 }
 
+/// Tag a freshly saved frame by the configured auto-tag rules.
+fn apply_auto_tags(
+    sqlite: &dyn SchedulerStorage,
+    frame_id: i64,
+    metadata: &FrameMetadata,
+    rules: &AutoTagRules,
+) {
+    let tags = rules.tags_for(&metadata.app_name, &metadata.window_title);
+    if tags.is_empty() {
+        return;
+    }
+    if let Err(e) = sqlite.auto_tag_frame(frame_id, &tags) {
+        warn!("frame auto-tag failure: {e}");
+    }
+}
+
+/// Forward a frame-storage suspend/resume transition to the dashboard.
+fn publish_disk_pressure_change(
+    frame_storage: &dyn FrameStoragePort,
+    event_tx: &Option<broadcast::Sender<RealtimeEvent>>,
+) {
+    let Some(change) = frame_storage.take_disk_pressure_change() else {
+        return;
+    };
+    if let Some(tx) = event_tx.as_ref() {
+        let update = RealtimeEvent::DiskSpace(DiskSpaceUpdate {
+            timestamp: Utc::now().to_rfc3339(),
+            images_suspended: change.suspended,
+            free_mb: change.free_mb,
+        });
+        if let Err(e) = tx.send(update) {
+            debug!("disk space event channel send failed: {e}");
+        }
+    }
+}
+
+// ── Hourly aggregation boundary ───────────────────────────────────────
+
+/// Tracks the last hour seen by the aggregation loop so each completed hour
+/// is rolled up once, when the clock first crosses into the next hour.
+#[derive(Debug, Default)]
+pub(super) struct HourBoundary {
+    current_hour: Option<DateTime<Utc>>,
+}
+
+impl HourBoundary {
+    /// Start of the hour that just completed, if `now` falls in a later hour
+    /// than the previous call. The first call always reports the prior hour.
+    pub(super) fn crossed(&mut self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let hour = now
+            .duration_trunc(chrono::Duration::hours(1))
+            .unwrap_or(now);
+        if self.current_hour.is_some_and(|seen| hour <= seen) {
+            return None;
+        }
+        self.current_hour = Some(hour);
+        Some(hour - chrono::Duration::hours(1))
+    }
+}
+
 // ── Idle state tracking ───────────────────────────────────────────────
 
 /// Process idle state transitions: start/end idle periods in storage,
 /// reset notifications on resume, and check idle notification thresholds.
 /// Returns the updated `prev_idle_secs` value for the caller to persist.
+/// Idle resuming within `idle_merge_threshold_secs` of the last period's end
+/// reopens that period.
+#[allow(clippy::too_many_arguments)]
 pub(super) async fn handle_idle_tick(
     idle_tracker: &mut IdleTracker,
     sqlite: &Arc<dyn SchedulerStorage>,
     notif: &Option<Arc<NotificationManager>>,
     input_collector: &InputActivityCollector,
     prev_idle_secs: u64,
+    idle_merge_threshold_secs: u64,
     focus_mode_active: bool,
     event_tx: &Option<broadcast::Sender<RealtimeEvent>>,
+    presence: &SharedPresenceState,
 ) -> u64 {
     // Capture previous state BEFORE check_idle() updates it, so edge detection
     // (`prev_state == Active && current == Idle`) works correctly.
     let prev_state = idle_tracker.previous_state();
     let idle_info = idle_tracker.check_idle().await;
+    presence.record(&idle_info);
 
     if prev_state == IdleState::Active && idle_info.state == IdleState::Idle {
         // Storage FIRST (spec §U2 I2 ordering). Log-and-continue on failure.
-        match sqlite.start_idle_period(Utc::now()).await {
+        // The tracker only notices idleness once the threshold has elapsed, so
+        // the period began `idle_secs` before this tick.
+        let idle_started = Utc::now() - chrono::Duration::seconds(idle_info.idle_secs as i64);
+        match sqlite
+            .start_idle_period(idle_started, idle_merge_threshold_secs)
+            .await
+        {
             Ok(id) => {
                 idle_tracker.set_idle_period_id(Some(id));
                 debug!("idle period started: id={}", id);
@@ -400,6 +526,17 @@ pub(crate) fn record_to_segment_summary(r: &SegmentSummaryRecord) -> Option<Segm
     })
 }
 
+/// Live `monitor.poll_interval_ms` when a config reload changed it away from
+/// `current`, so the monitor loop can re-arm its interval.
+pub(super) fn reloaded_poll_interval(
+    config_manager: Option<&oneshim_core::config_manager::ConfigManager>,
+    current: std::time::Duration,
+) -> Option<std::time::Duration> {
+    let poll_ms = config_manager?.snapshot().monitor.poll_interval_ms;
+    let poll = std::time::Duration::from_millis(poll_ms);
+    (poll_ms > 0 && poll != current).then_some(poll)
+}
+
 /// Interval between automatic frame retention enforcement runs (100 seconds).
 pub(super) const FRAME_RETENTION_INTERVAL: std::time::Duration =
     std::time::Duration::from_secs(100);
@@ -417,6 +554,53 @@ pub(super) async fn enforce_frame_retention(frame_storage: &dyn FrameStoragePort
 
 #[cfg(test)]
 mod tests {
+    #[test]
+    fn reloaded_poll_interval_reports_only_changes() {
+        use oneshim_core::config_manager::ConfigManager;
+        use std::time::Duration;
+
+        let tmp = tempfile::TempDir::new().unwrap();
+        let mgr = ConfigManager::with_path(tmp.path().join("config.json")).unwrap();
+        let current = Duration::from_millis(mgr.get().monitor.poll_interval_ms);
+
+        assert_eq!(reloaded_poll_interval(None, current), None);
+        assert_eq!(reloaded_poll_interval(Some(&mgr), current), None);
+
+        mgr.update_with(|c| {
+            c.monitor.poll_interval_ms = 2_500;
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(
+            reloaded_poll_interval(Some(&mgr), current),
+            Some(Duration::from_millis(2_500))
+        );
+    }
+
+    #[test]
+    fn advancing_clock_crosses_aggregation_boundary_once() {
+        use chrono::TimeZone;
+        use oneshim_core::ports::clock::{Clock, ManualClock};
+
+        let clock = ManualClock::new(Utc.with_ymd_and_hms(2026, 3, 1, 9, 58, 0).unwrap());
+        let mut boundary = HourBoundary::default();
+
+        assert_eq!(
+            boundary.crossed(clock.now()),
+            Some(Utc.with_ymd_and_hms(2026, 3, 1, 8, 0, 0).unwrap())
+        );
+
+        clock.advance(std::time::Duration::from_secs(60));
+        assert_eq!(boundary.crossed(clock.now()), None);
+
+        clock.advance(std::time::Duration::from_secs(60));
+        assert_eq!(
+            boundary.crossed(clock.now()),
+            Some(Utc.with_ymd_and_hms(2026, 3, 1, 9, 0, 0).unwrap())
+        );
+        assert_eq!(boundary.crossed(clock.now()), None);
+    }
+
     use super::*;
     use oneshim_core::models::activity::{IdlePeriod, ProcessSnapshot, SessionStats};
     use oneshim_core::models::system::SystemMetrics;
@@ -429,7 +613,10 @@ mod tests {
     // Only `start_idle_period` and `end_idle_period` are exercised by
     // `handle_idle_tick`. All other methods panic with `unimplemented!` to
     // surface accidental calls clearly in test output.
-    struct MockSchedulerStorage;
+    #[derive(Default)]
+    struct MockSchedulerStorage {
+        idle_starts: std::sync::Mutex<Vec<chrono::DateTime<chrono::Utc>>>,
+    }
 
     #[async_trait::async_trait]
     impl MetricsStorage for MockSchedulerStorage {
@@ -456,6 +643,13 @@ mod tests {
             unimplemented!("handle_idle_tick should not call aggregate_hourly_metrics")
         }
 
+        async fn aggregate_daily_metrics(
+            &self,
+            _: chrono::DateTime<chrono::Utc>,
+        ) -> Result<(), oneshim_core::error::CoreError> {
+            unimplemented!("handle_idle_tick should not call aggregate_daily_metrics")
+        }
+
         async fn cleanup_old_metrics(
             &self,
             _: chrono::DateTime<chrono::Utc>,
@@ -486,12 +680,15 @@ mod tests {
             unimplemented!("handle_idle_tick should not call cleanup_old_process_snapshots")
         }
 
-        /// Returns a fixed id (1) so `idle_tracker.set_idle_period_id` gets a
-        /// valid value without touching real storage.
+        /// Records `start_time` and returns a fixed id (1) so
+        /// `idle_tracker.set_idle_period_id` gets a valid value without
+        /// touching real storage.
         async fn start_idle_period(
             &self,
-            _start_time: chrono::DateTime<chrono::Utc>,
+            start_time: chrono::DateTime<chrono::Utc>,
+            _merge_threshold_secs: u64,
         ) -> Result<i64, oneshim_core::error::CoreError> {
+            self.idle_starts.lock().unwrap().push(start_time);
             Ok(1)
         }
 
@@ -555,6 +752,16 @@ mod tests {
         ) -> Result<(), oneshim_core::error::CoreError> {
             unimplemented!("handle_idle_tick should not call increment_session_counters")
         }
+
+        async fn event_histogram(
+            &self,
+            _: chrono::DateTime<chrono::Utc>,
+            _: chrono::DateTime<chrono::Utc>,
+            _: chrono::Duration,
+        ) -> Result<Vec<(chrono::DateTime<chrono::Utc>, u64)>, oneshim_core::error::CoreError>
+        {
+            unimplemented!("handle_idle_tick should not call event_histogram")
+        }
     }
 
     impl SchedulerStorage for MockSchedulerStorage {
@@ -568,6 +775,13 @@ mod tests {
             unimplemented!("handle_idle_tick should not call save_frame_metadata_with_bounds")
         }
 
+        fn save_frames_metadata(
+            &self,
+            _: &[oneshim_core::models::storage_records::NewFrameMetadata<'_>],
+        ) -> Result<Vec<i64>, oneshim_core::error::CoreError> {
+            unimplemented!("handle_idle_tick should not call save_frames_metadata")
+        }
+
         fn has_recent_server_suggestions(
             &self,
             _: u64,
@@ -674,6 +888,32 @@ mod tests {
         fn run_analyze(&self) -> Result<(), oneshim_core::error::CoreError> {
             unimplemented!("handle_idle_tick should not call run_analyze")
         }
+
+        fn create_database_backup(
+            &self,
+            _: usize,
+        ) -> Result<
+            oneshim_core::models::storage_records::DatabaseBackupRecord,
+            oneshim_core::error::CoreError,
+        > {
+            unimplemented!("handle_idle_tick should not call create_database_backup")
+        }
+
+        fn auto_tag_frame(
+            &self,
+            _: i64,
+            _: &[String],
+        ) -> Result<usize, oneshim_core::error::CoreError> {
+            unimplemented!("handle_idle_tick should not call auto_tag_frame")
+        }
+
+        fn save_capture_latency(
+            &self,
+            _: chrono::DateTime<chrono::Utc>,
+            _: &[oneshim_core::models::capture_latency::StageLatencySummary],
+        ) -> Result<usize, oneshim_core::error::CoreError> {
+            unimplemented!("handle_idle_tick should not call save_capture_latency")
+        }
     }
 
     // ── Tests ─────────────────────────────────────────────────────────────
@@ -688,12 +928,13 @@ mod tests {
     /// platform idle time at test runtime.
     #[tokio::test]
     async fn handle_idle_tick_emits_on_edge_only() {
-        let sqlite: Arc<dyn SchedulerStorage> = Arc::new(MockSchedulerStorage);
+        let sqlite: Arc<dyn SchedulerStorage> = Arc::new(MockSchedulerStorage::default());
         // threshold=0 → check_idle() always returns Idle (any idle_secs ≥ 0).
         let mut idle_tracker = oneshim_monitor::idle::IdleTracker::new(Some(0));
         let input_collector = InputActivityCollector::new();
         let (tx, mut rx) = broadcast::channel::<RealtimeEvent>(16);
         let event_tx: Option<broadcast::Sender<RealtimeEvent>> = Some(tx);
+        let presence = SharedPresenceState::new();
 
         // ── Call 1: Active→Idle edge ─────────────────────────────────────
         // idle_tracker.previous_state=Active (initial). check_idle yields Idle.
@@ -704,8 +945,10 @@ mod tests {
             &None,
             &input_collector,
             0,
+            0,
             false,
             &event_tx,
+            &presence,
         )
         .await;
 
@@ -721,6 +964,11 @@ mod tests {
             }
             other => panic!("expected RealtimeEvent::Idle, got {other:?}"),
         }
+        assert_eq!(
+            presence.snapshot().map(|p| p.idle_state),
+            Some(IdleState::Idle),
+            "heartbeat presence must follow the idle tracker"
+        );
 
         // ── Call 2: mid-Idle (Idle→Idle) — no second emission ───────────
         // idle_tracker.previous_state=Idle (set by call 1). check_idle yields
@@ -731,8 +979,10 @@ mod tests {
             &None,
             &input_collector,
             0,
+            0,
             false,
             &event_tx,
+            &presence,
         )
         .await;
 
@@ -765,12 +1015,13 @@ mod tests {
     /// suite (`grpc_dashboard_integration.rs`).
     #[tokio::test]
     async fn handle_idle_tick_suppresses_mid_active_tick() {
-        let sqlite: Arc<dyn SchedulerStorage> = Arc::new(MockSchedulerStorage);
+        let sqlite: Arc<dyn SchedulerStorage> = Arc::new(MockSchedulerStorage::default());
         // threshold=MAX → check_idle always returns Active.
         let mut idle_tracker = oneshim_monitor::idle::IdleTracker::new(Some(u64::MAX));
         let input_collector = InputActivityCollector::new();
         let (tx, mut rx) = broadcast::channel::<RealtimeEvent>(16);
         let event_tx: Option<broadcast::Sender<RealtimeEvent>> = Some(tx);
+        let presence = SharedPresenceState::new();
 
         // ── Call 1: Active→Active — no emit ──────────────────────────────
         handle_idle_tick(
@@ -779,8 +1030,10 @@ mod tests {
             &None,
             &input_collector,
             0,
+            0,
             false,
             &event_tx,
+            &presence,
         )
         .await;
 
@@ -796,8 +1049,10 @@ mod tests {
             &None,
             &input_collector,
             0,
+            0,
             false,
             &event_tx,
+            &presence,
         )
         .await;
 
@@ -806,4 +1061,84 @@ mod tests {
             "Active→Active (call 2) must not emit"
         );
     }
+
+    /// The tracker reports idleness only after `threshold` seconds without
+    /// input, so the stored period must start back when input stopped, not
+    /// at the tick that noticed it.
+    #[tokio::test]
+    async fn handle_idle_tick_backdates_idle_period_start() {
+        let storage = Arc::new(MockSchedulerStorage::default());
+        let sqlite: Arc<dyn SchedulerStorage> = storage.clone();
+        let probe: oneshim_monitor::idle::IdleProbe = Arc::new(|| Some(300));
+        let mut idle_tracker =
+            oneshim_monitor::idle::IdleTracker::new(Some(300)).with_idle_probe(probe);
+        let input_collector = InputActivityCollector::new();
+        let presence = SharedPresenceState::new();
+
+        let before = Utc::now();
+        handle_idle_tick(
+            &mut idle_tracker,
+            &sqlite,
+            &None,
+            &input_collector,
+            0,
+            0,
+            false,
+            &None,
+            &presence,
+        )
+        .await;
+        let after = Utc::now();
+
+        let starts = storage.idle_starts.lock().unwrap().clone();
+        assert_eq!(starts.len(), 1, "Active→Idle edge must open one period");
+        let idle = chrono::Duration::seconds(300);
+        assert!(
+            starts[0] >= before - idle && starts[0] <= after - idle,
+            "idle period must start 300s before the tick, got {} (tick between {before} and {after})",
+            starts[0]
+        );
+    }
+
+    #[test]
+    fn auto_tag_rule_tags_matching_frame_only() {
+        use oneshim_core::config::{AutoTagConfig, AutoTagMatchMode, AutoTagRule};
+        use oneshim_storage::sqlite::SqliteStorage;
+
+        let storage = SqliteStorage::open_in_memory(30).unwrap();
+        let rules = AutoTagConfig {
+            enabled: true,
+            match_mode: AutoTagMatchMode::FirstMatch,
+            rules: vec![AutoTagRule {
+                tag: "design".to_string(),
+                app_patterns: vec!["Figma".to_string()],
+                ..Default::default()
+            }],
+        }
+        .compile();
+
+        let mut ids = Vec::new();
+        for app_name in ["Figma", "Terminal"] {
+            let metadata = FrameMetadata {
+                timestamp: Utc::now(),
+                trigger_type: "test".to_string(),
+                app_name: app_name.to_string(),
+                window_title: "untitled".to_string(),
+                resolution: (1920, 1080),
+                importance: 0.5,
+            };
+            let id = SchedulerStorage::save_frame_metadata_with_bounds(
+                &storage, &metadata, None, None, None,
+            )
+            .unwrap();
+            apply_auto_tags(&storage, id, &metadata, &rules);
+            ids.push(id);
+        }
+
+        let tagged = storage.get_tags_for_frame(ids[0]).unwrap();
+        assert_eq!(tagged.len(), 1);
+        assert_eq!(tagged[0].name, "design");
+        assert!(tagged[0].auto_applied);
+        assert!(storage.get_tags_for_frame(ids[1]).unwrap().is_empty());
+    }
 }
@@ -24,6 +24,7 @@ impl Scheduler {
         // D13: 4-term privacy gate DI.
         let consent_mgr_a = self.consent_manager.clone();
         let capture_paused_a = self.capture_paused.clone();
+        let loop_health = self.loop_health.clone();
 
         tokio::spawn(async move {
             let analyzer = match analyzer {
@@ -46,6 +47,7 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("analysis", interval.period());
                         // D13: 4-term composite gate (CONS-PC02 / §3.3 A.9).
                         let consent = consent_mgr_a.as_ref()
                             .and_then(|cm| cm.current_consent().map(|r| r.permissions.clone()))
@@ -147,6 +149,7 @@ impl Scheduler {
         let config_mgr_f = self.config_manager.clone();
         let consent_mgr_f = self.consent_manager.clone();
         let capture_paused_f = self.capture_paused.clone();
+        let loop_health = self.loop_health.clone();
 
         tokio::spawn(async move {
             let focus = match focus8 {
@@ -161,6 +164,7 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("focus", interval.period());
                         // D13: 4-term composite gate (CONS-PC02 / §3.3 A.9).
                         let consent = consent_mgr_f.as_ref()
                             .and_then(|cm| cm.current_consent().map(|r| r.permissions.clone()))
@@ -201,6 +205,7 @@ impl Scheduler {
         let config_mgr_c = self.config_manager.clone();
         let consent_mgr_c = self.consent_manager.clone();
         let capture_paused_c = self.capture_paused.clone();
+        let loop_health = self.loop_health.clone();
 
         tokio::spawn(async move {
             let engine = match coaching {
@@ -218,6 +223,7 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("coaching", interval.period());
                         // D13: 4-term composite gate (CONS-PC02 / §3.3 A.9).
                         // Coaching during an opt-out window is invasive (R3.I4).
                         let consent = consent_mgr_c.as_ref()
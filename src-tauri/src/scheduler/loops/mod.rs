@@ -14,6 +14,7 @@ mod sync;
 mod system;
 pub(super) mod tracking_schedule_helper;
 mod vision_helper;
+mod watchdog;
 
 // ── Public re-exports ────────────────────────────────────────────────
 pub(crate) use helpers::record_to_segment_summary;
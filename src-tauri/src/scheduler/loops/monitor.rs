@@ -1,9 +1,13 @@
 use chrono::Utc;
+use oneshim_core::config::AppConfig;
 use oneshim_core::models::event::Event;
 use oneshim_core::models::focused_element::AccessibilityElement;
 use oneshim_core::models::frame::OcrRegion;
+use oneshim_monitor::context_sampler::ContextEventSampler;
 use oneshim_monitor::idle::IdleTracker;
 use oneshim_monitor::input_activity::InputActivityCollector;
+use oneshim_monitor::power::PowerTracker;
+use oneshim_monitor::screen_lock::ScreenLockTracker;
 use oneshim_monitor::window_layout::WindowLayoutTracker;
 use oneshim_vision::ring_buffer::{CaptureRingBuffer, RingFrame};
 use std::sync::Arc;
@@ -17,7 +21,8 @@ use super::super::Scheduler;
 use super::coaching_helper::{CoachingEvalContext, CoachingTickState};
 use super::helpers::{
     audit_consent_and_pii_changes, build_segment_stats_snapshot, emit_heatmap_and_goals,
-    handle_event_analysis, handle_frame_capture, handle_idle_tick,
+    handle_event_analysis, handle_frame_capture, handle_idle_tick, persist_context_events,
+    reloaded_poll_interval,
 };
 use crate::focus_mode::FocusModeState;
 
@@ -64,6 +69,18 @@ impl Scheduler {
         let detection_active = self.detection_active.clone();
         let scene_finder_ref = self.scene_finder.clone();
         let event_tx_mon = self.event_tx.clone();
+        let monitor_feed = self.monitor_feed.clone();
+        let loop_health = self.loop_health.clone();
+        let presence = self.presence.clone();
+        let pending_capture_signal = self.pending_capture_signal.clone();
+        let clock = self.clock.clone();
+        let idle_merge_threshold_secs = self.config.idle_merge_threshold_secs;
+        let maintenance_enabled = self.config.loops.maintenance;
+        let capture_enabled = self.config.loops.capture_enabled();
+        let context_sampling = config_manager1
+            .as_ref()
+            .map(|cm| cm.get().monitor.context_sampling.clone())
+            .unwrap_or_default();
 
         tokio::spawn(async move {
             let mut prev_app: Option<String> = None;
@@ -71,7 +88,7 @@ impl Scheduler {
             let mut prev_idle_secs: u64 = 0;
             let mut interval = tokio::time::interval(poll);
             let mut focus_block = super::autostart_helper::FocusBlockState::default();
-            let mut idle_tracker = IdleTracker::new(Some(idle_threshold));
+            let mut idle_tracker = IdleTracker::new(Some(idle_threshold)).with_clock(clock);
             let mut adaptive_trigger_state = adaptive_trigger_state;
             let window_tracker = WindowLayoutTracker::new();
             let input_collector = input_collector1;
@@ -93,10 +110,18 @@ impl Scheduler {
                 .map(|cm| cm.get().analysis.text_intelligence.pii_extraction_level)
                 .unwrap_or_default();
             let mut ts_notify_state = (false, None::<Instant>); // A.18: (prev_active, last_notified)
+            let mut screen_lock = ScreenLockTracker::new();
+            let mut power = PowerTracker::new();
+            let mut context_sampler = ContextEventSampler::new(&context_sampling);
 
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("monitor", interval.period());
+                        if let Some(poll) = reloaded_poll_interval(config_manager1.as_ref(), interval.period()) {
+                            info!(poll_ms = poll.as_millis() as u64, "monitor poll interval reloaded");
+                            interval = tokio::time::interval_at(tokio::time::Instant::now() + poll, poll);
+                        }
                         // A4: Focus mode auto-expiry check
                         if focus_mode.check_expiry() {
                             if let Some(ref overlay) = overlay_ref {
@@ -110,17 +135,41 @@ impl Scheduler {
                             &notif1,
                             &input_collector,
                             prev_idle_secs,
+                            idle_merge_threshold_secs,
                             focus_mode.is_active(),
                             &event_tx_mon,  // reuse clone added by B3-1
+                            &presence,
                         ).await;
+                        if let Some(ref focus) = focus1 {
+                            focus.on_idle_tick(new_idle_secs).await;
+                        }
 
                         // A.18: TS window enter/exit → desktop notify (60s debounce)
                         super::tracking_schedule_helper::tick_ts_notifications(&config_manager1, notif1.as_deref(), &mut ts_notify_state.0, &mut ts_notify_state.1).await;
 
+                        // Screen lock: record lock/unlock, skip capture + collection while locked
+                        let lock_cfg = config_manager1.as_ref().map(|cm| cm.get()).unwrap_or_else(AppConfig::default_config);
+                        let (lock_paused, lock_event) = super::tracking_schedule_helper::tick_screen_lock(&lock_cfg, &mut screen_lock).await;
+                        if let Some(lock_event) = lock_event {
+                            if let Err(e) = storage1.save_event(&lock_event).await {
+                                warn!(err.code = %e.code(), "screen lock event save failure: {e}");
+                            }
+                            if let Some(ref sink) = uploader1 {
+                                let local_id = lock_event.local_id();
+                                if let Some(upload_event) = egress1.prepare_event_for_upload(lock_event) {
+                                    sink.enqueue_local(local_id, upload_event);
+                                }
+                            }
+                        }
+                        if lock_paused {
+                            continue;
+                        }
+
                         // PR-B1 §5.5: productive-session detection (Idle↔Active transitions, idempotent counter)
                         focus_block.tick(&mut prev_idle_secs, new_idle_secs, idle_threshold, app_handle.as_ref(), config_manager1.as_ref());
                         match act_mon.collect_context().await {
                             Ok(ctx) => {
+                                if let Some(ref feed) = monitor_feed { feed.publish_context(&ctx); }
                                 let app_name = ctx.active_window.as_ref()
                                     .map(|w| w.app_name.clone())
                                     .unwrap_or_default();
@@ -194,8 +243,9 @@ impl Scheduler {
                                         warn!(err.code = %e.code(), "window event save failure: {e}");
                                     }
                                     if let Some(ref sink) = uploader1 {
+                                        let local_id = win_event.local_id();
                                         if let Some(upload_event) = egress1.prepare_event_for_upload(win_event) {
-                                            sink.enqueue(upload_event);
+                                            sink.enqueue_local(local_id, upload_event);
                                         }
                                     }
                                 }
@@ -206,16 +256,20 @@ impl Scheduler {
                                     prev_app_name: prev_app.clone(),
                                     timestamp: Utc::now(),
                                     input_activity_level: input_collector.peek_activity_level(),
+                                    repeat_count: 0,
                                 };
 
-                                // Gate: consent · active_hours · tracking-schedule · tray-pause (A.7)
+                                // Gate: consent · active_hours · tracking-schedule · tray-pause (A.7) · low battery
+                                let low_battery = super::tracking_schedule_helper::battery_pauses_capture(&lock_cfg, &mut power).await;
                                 let consent = consent_manager1.as_ref()
                                     .and_then(|cm| cm.current_consent().map(|r| r.permissions.clone()))
                                     .unwrap_or_default();
                                 let paused = capture_paused.load(std::sync::atomic::Ordering::Relaxed);
                                 let permitted = config_manager1.as_ref()
                                     .map(|cm| crate::scheduler::capture_permitted_now(&cm.snapshot(), &consent, paused))
-                                    .unwrap_or(!paused);
+                                    .unwrap_or(!paused)
+                                    && capture_enabled
+                                    && !low_battery;
                                 if permitted {
                                 // --- Ring buffer: capture thumbnail every cycle ---
                                 if let Ok(thumb_data) = processor.capture_thumbnail().await {
@@ -238,7 +292,12 @@ impl Scheduler {
                                 }
 
                                 {
-                                    let capture_req = trigger.should_capture(&event);
+                                    // Clipboard/file-save signals take precedence when the
+                                    // trigger accepts them; otherwise fall back to window rules.
+                                    let signal = pending_capture_signal.lock().take();
+                                    let capture_req = signal
+                                        .and_then(|s| trigger.should_capture_signal(s, &event))
+                                        .or_else(|| trigger.should_capture(&event));
 
                                     // Force capture during post-event window (dashcam "after" frames)
                                     let force_post = ring_buffer.should_force_post_capture();
@@ -281,7 +340,9 @@ impl Scheduler {
 
                                         // D5 iter-3: pii_level for OCR sanitization at storage boundary.
                                         let capture_pii = config_manager1.as_ref().map(|cm| cm.get().privacy.pii_filter_level).unwrap_or_default();
-                                        let (ocr_hint, regions, frame_rgba) = handle_frame_capture(&capture_req, &processor, &frame_storage1, &sqlite1, &session1, capture_pii, &event_tx_mon).await;
+                                        let ocr_max_chars = config_manager1.as_ref().and_then(|cm| cm.get().vision.ocr_max_chars);
+                                        let auto_tags = config_manager1.as_ref().map(|cm| cm.snapshot().auto_tag.compile()).unwrap_or_default();
+                                        let (ocr_hint, regions, frame_rgba) = handle_frame_capture(&capture_req, &processor, &frame_storage1, &sqlite1, &session1, capture_pii, ocr_max_chars, &auto_tags, &event_tx_mon).await;
                                         focus_ocr_hint = ocr_hint;
                                         if !regions.is_empty() {
                                             last_ocr_regions = regions;
@@ -301,18 +362,8 @@ impl Scheduler {
                                         }
                                     }
                                 }
-                                let ctx_event = Event::Context(event);
-                                if let Err(e) = storage1.save_event(&ctx_event).await {
-                                    warn!(err.code = %e.code(), "event save failure: {e}");
-                                }
-                                if let Err(e) = sqlite1.increment_session_counters(&session1, 1, 0, 0).await {
-                                    debug!("increment_session_counters failed: {e}");
-                                }
-                                if let Some(ref sink) = uploader1 {
-                                    if let Some(upload_event) = egress1.prepare_event_for_upload(ctx_event) {
-                                        sink.enqueue(upload_event);
-                                    }
-                                }
+                                if let Some(ref cm) = config_manager1 { context_sampler.set_config(&cm.get().monitor.context_sampling); }
+                                persist_context_events(context_sampler.push(event), &storage1, &sqlite1, &session1, &uploader1, &egress1).await;
                                 } // end capture gate
 
                                 let app_changed = prev_app.as_ref() != Some(&app_name);
@@ -465,7 +516,7 @@ impl Scheduler {
                                         regime_id: regime_id_for_coaching,
                                         prev_app: prev_app.as_deref(),
                                         drift_detected,
-                                        poll_secs: poll.as_secs(),
+                                        poll_secs: interval.period().as_secs(),
                                         pii_sanitizer: &coaching_pii_sanitizer,
                                         pii_level: super::coaching_helper::resolve_pii_level(&config_manager1),
                                     };
@@ -483,7 +534,7 @@ impl Scheduler {
                                 super::vision_helper::log_ring_buffer_evictions(&ring_buffer);
 
                                 // ── Periodic frame retention enforcement ──
-                                if last_retention_check.elapsed() >= super::helpers::FRAME_RETENTION_INTERVAL {
+                                if maintenance_enabled && last_retention_check.elapsed() >= super::helpers::FRAME_RETENTION_INTERVAL {
                                     last_retention_check = Instant::now();
                                     if let Some(ref fs) = frame_storage1 {
                                         super::helpers::enforce_frame_retention(fs.as_ref()).await;
@@ -499,6 +550,7 @@ impl Scheduler {
                         }
                     }
                     _ = shutdown_rx.changed() => {
+                        persist_context_events(context_sampler.flush().into_iter().collect(), &storage1, &sqlite1, &session1, &uploader1, &egress1).await;
                         info!("monitoring ended");
                         break;
                     }
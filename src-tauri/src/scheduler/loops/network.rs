@@ -1,4 +1,6 @@
-use chrono::Utc;
+use oneshim_core::models::event::Event;
+use oneshim_core::ports::batch_sink::{BatchSink, FlushReport};
+use oneshim_core::ports::storage::StorageService;
 use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, info, warn};
@@ -6,6 +8,42 @@ use tracing::{debug, info, warn};
 use super::super::config::PlatformEgressPolicy;
 use super::super::Scheduler;
 
+/// Mark the rows the server accepted as sent and quarantine the ones it
+/// gave up on.
+async fn record_flush_report(storage: &dyn StorageService, report: &FlushReport) {
+    if !report.accepted.is_empty() {
+        if let Err(e) = storage.mark_as_sent(&report.accepted).await {
+            warn!(err.code = %e.code(), "mark sent failure: {e}");
+        }
+    }
+    if !report.quarantined.is_empty() {
+        warn!(
+            count = report.quarantined.len(),
+            "events quarantined after server rejection"
+        );
+        if let Err(e) = storage.quarantine_events(&report.quarantined).await {
+            warn!(err.code = %e.code(), "quarantine failure: {e}");
+        }
+    }
+}
+
+/// Queue the next page of rows still unsent in storage, e.g. from a previous
+/// run or dropped at queue capacity. A no-op while the queue holds events.
+async fn restore_pending_uploads(
+    sink: &dyn BatchSink,
+    storage: &dyn StorageService,
+    egress: &PlatformEgressPolicy,
+) {
+    let prepare = |event: Event| egress.prepare_event_for_upload(event);
+    match sink.restore_pending(storage, &prepare).await {
+        Ok(0) => {}
+        Ok(restored) => info!(restored, "pending uploads restored from storage"),
+        Err(e) => {
+            warn!(err.code = %e.code(), "pending upload restore failure: {e}")
+        }
+    }
+}
+
 impl Scheduler {
     #[tracing::instrument(skip_all)]
     pub(in crate::scheduler) fn spawn_sync_loop(
@@ -18,22 +56,38 @@ impl Scheduler {
         let storage4 = self.storage.clone();
         let frame_storage4 = self.frame_storage.clone();
         let egress4 = egress_policy;
+        let loop_health = self.loop_health.clone();
+        let maintenance_enabled = self.config.loops.maintenance;
 
         tokio::spawn(async move {
+            // Re-queue uploads still unsent from a previous run.
+            if let Some(ref sink) = uploader4 {
+                if egress4.is_enabled() {
+                    restore_pending_uploads(sink.as_ref(), storage4.as_ref(), &egress4).await;
+                }
+            }
+
             let mut interval = tokio::time::interval(sync_interval);
 
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("sync", interval.period());
                         if let Some(ref sink) = uploader4 {
                             if egress4.is_enabled() {
                                 match sink.flush().await {
-                                    Ok(count) => {
-                                        if count > 0 {
-                                            debug!("batch: {count}items sent");
-                                            if let Err(e) = storage4.mark_unsent_as_sent_before(Utc::now()).await {
-                                                warn!(err.code = %e.code(), "mark sent failure: {e}");
-                                            }
+                                    Ok(report) => {
+                                        if !report.is_empty() {
+                                            debug!(
+                                                sent = report.sent(),
+                                                quarantined = report.quarantined.len(),
+                                                requeued = report.requeued,
+                                                "batch flushed"
+                                            );
+                                            record_flush_report(storage4.as_ref(), &report).await;
+                                            // Rows only leave the backlog once acknowledged;
+                                            // page in the next batch once the queue drains.
+                                            restore_pending_uploads(sink.as_ref(), storage4.as_ref(), &egress4).await;
                                         }
                                     }
                                     Err(e) => {
@@ -51,16 +105,18 @@ impl Scheduler {
                             }
                         }
 
-                        if let Err(e) = storage4.enforce_retention().await {
-                            warn!(err.code = %e.code(), "event policy failure: {e}");
-                        }
-
-                        if let Some(ref fs) = frame_storage4 {
-                            if let Err(e) = fs.enforce_retention().await {
-                                warn!(err.code = %e.code(), "frame policy failure: {e}");
+                        if maintenance_enabled {
+                            if let Err(e) = storage4.enforce_retention().await {
+                                warn!(err.code = %e.code(), "event policy failure: {e}");
                             }
-                            if let Err(e) = fs.enforce_storage_limit().await {
-                                warn!(err.code = %e.code(), "frame failure: {e}");
+
+                            if let Some(ref fs) = frame_storage4 {
+                                if let Err(e) = fs.enforce_retention().await {
+                                    warn!(err.code = %e.code(), "frame policy failure: {e}");
+                                }
+                                if let Err(e) = fs.enforce_storage_limit().await {
+                                    warn!(err.code = %e.code(), "frame failure: {e}");
+                                }
                             }
                         }
                     }
@@ -70,9 +126,10 @@ impl Scheduler {
                             if egress4.is_enabled() {
                                 loop {
                                     match sink.flush().await {
-                                        Ok(0) => break,
-                                        Ok(count) => {
-                                            info!("shutdown flush: {count} events sent");
+                                        Ok(report) if report.is_empty() => break,
+                                        Ok(report) => {
+                                            info!("shutdown flush: {} events sent", report.sent());
+                                            record_flush_report(storage4.as_ref(), &report).await;
                                         }
                                         Err(e) => {
                                             warn!(err.code = %e.code(), "shutdown flush failed: {e}");
@@ -100,6 +157,8 @@ impl Scheduler {
     ) -> tokio::task::JoinHandle<()> {
         let api = self.api_client.clone();
         let sid = session_id;
+        let loop_health = self.loop_health.clone();
+        let presence = self.presence.clone();
 
         tokio::spawn(async move {
             let api = match api {
@@ -120,7 +179,12 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
-                        if let Err(e) = api.send_heartbeat(&sid).await {
+                        loop_health.beat("heartbeat", interval.period());
+                        let result = match presence.snapshot() {
+                            Some(p) => api.send_heartbeat_with_presence(&sid, &p).await,
+                            None => api.send_heartbeat(&sid).await,
+                        };
+                        if let Err(e) = result {
                             warn!(err.code = %e.code(), "heartbeat failure: {e}");
                         }
                     }
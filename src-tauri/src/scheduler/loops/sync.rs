@@ -95,6 +95,7 @@ impl Scheduler {
         let config_mgr_s = self.config_manager.clone();
         let consent_mgr_s = self.consent_manager.clone();
         let capture_paused_s = self.capture_paused.clone();
+        let loop_health = self.loop_health.clone();
 
         tokio::spawn(async move {
             let engine = match sync_engine {
@@ -113,6 +114,7 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("cross_device_sync", interval.period());
                         // D13: 4-term composite gate (CONS-PC02 / §3.3 A.9).
                         let consent = consent_mgr_s.as_ref()
                             .and_then(|cm| cm.current_consent().map(|r| r.permissions.clone()))
@@ -162,7 +164,6 @@ impl Scheduler {
         app_handle: Option<tauri::AppHandle>,
     ) {
         let poll = self.config.poll_interval;
-        let metrics_interval = self.config.metrics_interval;
         let process_interval = self.config.process_interval;
         let detailed_process_interval = self.config.detailed_process_interval;
         let input_activity_interval = self.config.input_activity_interval;
@@ -301,50 +302,77 @@ impl Scheduler {
             .clone()
             .unwrap_or_else(|| Arc::new(SharedRegimeState::new()));
 
-        let monitor_task = self.spawn_monitor_loop(
-            poll,
-            idle_threshold,
-            session_id.clone(),
-            egress_policy.clone(),
-            shared_input_collector.clone(),
-            adaptive_trigger_state,
-            shared_regime.clone(),
-            self.focus_mode.clone(),
-            shutdown_rx.clone(),
-            app_handle.clone(),
-        );
+        let loops = self.config.loops.clone();
+
+        let monitor_task = loops.is_enabled("monitor").then(|| {
+            self.spawn_monitor_loop(
+                poll,
+                idle_threshold,
+                session_id.clone(),
+                egress_policy.clone(),
+                shared_input_collector.clone(),
+                adaptive_trigger_state,
+                shared_regime.clone(),
+                self.focus_mode.clone(),
+                shutdown_rx.clone(),
+                app_handle.clone(),
+            )
+        });
 
-        let metrics_task = self.spawn_metrics_loop(metrics_interval, shutdown_rx.clone());
+        let metrics_task = loops
+            .is_enabled("metrics")
+            .then(|| self.spawn_metrics_loop(shutdown_rx.clone()));
 
-        let process_task = self.spawn_process_loop(process_interval, shutdown_rx.clone());
+        let process_task = loops
+            .is_enabled("process")
+            .then(|| self.spawn_process_loop(process_interval, shutdown_rx.clone()));
 
         let sync_task = self.spawn_sync_loop(sync, egress_policy.clone(), shutdown_rx.clone());
 
-        let heartbeat_task = self.spawn_heartbeat_loop(
-            heartbeat,
-            session_id.clone(),
-            egress_policy.clone(),
-            shutdown_rx.clone(),
-        );
-
-        let aggregation_task = self.spawn_aggregation_loop(
-            aggregation,
-            llm_summarizer_for_digest,
-            shutdown_rx.clone(),
-        );
-
-        let notification_task =
-            self.spawn_notification_loop(self.focus_mode.clone(), shutdown_rx.clone());
-
-        let focus_task = self.spawn_focus_loop(shutdown_rx.clone());
+        let heartbeat_task = loops.is_enabled("heartbeat").then(|| {
+            self.spawn_heartbeat_loop(
+                heartbeat,
+                session_id.clone(),
+                egress_policy.clone(),
+                shutdown_rx.clone(),
+            )
+        });
 
-        let event_snapshot_task = self.spawn_event_snapshot_loop(
-            detailed_process_interval,
-            input_activity_interval,
-            egress_policy.clone(),
-            shared_input_collector.clone(),
-            shutdown_rx.clone(),
-        );
+        let aggregation_task = loops.is_enabled("aggregation").then(|| {
+            self.spawn_aggregation_loop(
+                aggregation,
+                llm_summarizer_for_digest.clone(),
+                shutdown_rx.clone(),
+            )
+        });
+
+        // Retention normally rides on the aggregation loop.
+        let maintenance_task = loops
+            .is_enabled("maintenance")
+            .then(|| self.spawn_maintenance_loop(aggregation, shutdown_rx.clone()));
+
+        let db_backup_task = self
+            .config
+            .db_backup_interval
+            .map(|interval| self.spawn_db_backup_loop(interval, shutdown_rx.clone()));
+
+        let notification_task = loops
+            .is_enabled("notification")
+            .then(|| self.spawn_notification_loop(self.focus_mode.clone(), shutdown_rx.clone()));
+
+        let focus_task = loops
+            .is_enabled("focus")
+            .then(|| self.spawn_focus_loop(shutdown_rx.clone()));
+
+        let event_snapshot_task = loops.is_enabled("event_snapshot").then(|| {
+            self.spawn_event_snapshot_loop(
+                detailed_process_interval,
+                input_activity_interval,
+                egress_policy.clone(),
+                shared_input_collector.clone(),
+                shutdown_rx.clone(),
+            )
+        });
 
         // 10. OAuth token refresh (conditional — returns None if no coordinator)
         #[cfg(feature = "server")]
@@ -393,6 +421,10 @@ impl Scheduler {
                     llm: l_conn,
                     cli: c_conn,
                 },
+                super::health::ConnectivitySinks {
+                    storage: self.storage.clone(),
+                    event_tx: self.event_tx.clone(),
+                },
                 handle,
                 shutdown_rx.clone(),
             ))
@@ -431,30 +463,43 @@ impl Scheduler {
             None
         };
 
-        let _ = shutdown_rx.changed().await;
-        info!("ended received");
-
-        let sqlite_end = self.sqlite_storage.clone();
-        if let Err(e) = sqlite_end.end_session(&session_id, Utc::now()).await {
-            warn!("session ended record failure: {e}");
-        }
-
-        // Abort all loops and check for panics
-        let tasks: Vec<(&str, tokio::task::JoinHandle<()>)> = vec![
+        let mut tasks = super::watchdog::keep_enabled_loops(vec![
             ("monitor", monitor_task),
             ("metrics", metrics_task),
             ("process", process_task),
-            ("sync", sync_task),
+            ("sync", Some(sync_task)),
             ("heartbeat", heartbeat_task),
             ("aggregation", aggregation_task),
+            ("maintenance", maintenance_task),
+            ("db_backup", db_backup_task),
             ("notification", notification_task),
             ("focus", focus_task),
             ("event_snapshot", event_snapshot_task),
-            ("analysis", analysis_task),
-            ("cross_device_sync", cross_device_sync_task),
-            ("coaching", coaching_task),
-        ];
+            ("analysis", Some(analysis_task)),
+            ("cross_device_sync", Some(cross_device_sync_task)),
+            ("coaching", Some(coaching_task)),
+        ]);
+
+        // 16. Watchdog — blocks until shutdown, flagging (and optionally
+        // restarting) loops whose heartbeat went silent.
+        let restart_ctx = super::watchdog::LoopRestartContext {
+            session_id: session_id.clone(),
+            egress_policy: egress_policy.clone(),
+            input_collector: shared_input_collector.clone(),
+            llm_summarizer: llm_summarizer_for_digest,
+            shared_regime: shared_regime.clone(),
+            shutdown_rx: shutdown_rx.clone(),
+        };
+        self.supervise_loops(&mut tasks, &restart_ctx, &mut shutdown_rx)
+            .await;
+        info!("ended received");
+
+        let sqlite_end = self.sqlite_storage.clone();
+        if let Err(e) = sqlite_end.end_session(&session_id, Utc::now()).await {
+            warn!("session ended record failure: {e}");
+        }
 
+        // Abort all loops and check for panics
         for (name, task) in tasks {
             task.abort();
             match task.await {
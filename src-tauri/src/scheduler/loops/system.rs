@@ -5,29 +5,36 @@ use std::sync::Arc;
 use std::time::Duration;
 use tracing::{debug, info, warn};
 
+use super::super::config::SchedulerStorage;
 use super::super::Scheduler;
-use super::helpers::record_to_segment_summary;
+use super::helpers::{record_to_segment_summary, HourBoundary};
 
 impl Scheduler {
     #[tracing::instrument(skip_all)]
     pub(in crate::scheduler) fn spawn_metrics_loop(
         &self,
-        metrics_interval: Duration,
         mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
     ) -> tokio::task::JoinHandle<()> {
         let sys_mon = self.system_monitor.clone();
         let sqlite2 = self.sqlite_storage.clone();
         let event_tx2 = self.event_tx.clone();
+        let monitor_feed = self.monitor_feed.clone();
         let notif2 = self.notification_manager.clone();
+        let loop_health = self.loop_health.clone();
+        let rate = self.metrics_rate.clone();
 
         tokio::spawn(async move {
-            let mut interval = tokio::time::interval(metrics_interval);
+            let mut interval = tokio::time::interval(rate.current());
 
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("metrics", rate.base());
                         match sys_mon.collect_metrics().await {
                             Ok(metrics) => {
+                                if let Some(ref feed) = monitor_feed {
+                                    feed.publish_metrics(&metrics);
+                                }
                                 if let Err(e) = sqlite2.save_metrics(&metrics).await {
                                     warn!("system save failure: {e}");
                                 }
@@ -59,6 +66,17 @@ impl Scheduler {
                                 warn!("system collect failure: {e}");
                             }
                         }
+
+                        // A burst window ran out: fall back to the configured rate.
+                        let period = rate.current();
+                        if period != interval.period() {
+                            debug!(period_ms = period.as_millis() as u64, "metrics rate changed");
+                            interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+                        }
+                    }
+                    _ = rate.changed() => {
+                        // Burst started or stopped: sample now at the new rate.
+                        interval = tokio::time::interval(rate.current());
                     }
                     _ = shutdown_rx.changed() => {
                         info!("ended");
@@ -77,6 +95,7 @@ impl Scheduler {
     ) -> tokio::task::JoinHandle<()> {
         let proc_mon = self.process_monitor.clone();
         let sqlite3 = self.sqlite_storage.clone();
+        let loop_health = self.loop_health.clone();
 
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(process_interval);
@@ -84,6 +103,7 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
+                        loop_health.beat("process", interval.period());
                         match proc_mon.get_top_processes(10).await {
                             Ok(processes) => {
                                 let snapshot = ProcessSnapshot {
@@ -121,6 +141,7 @@ impl Scheduler {
         mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
     ) -> tokio::task::JoinHandle<()> {
         let sqlite6 = self.sqlite_storage.clone();
+        let capture_latency = self.capture_latency.clone();
         let vector_store = self.vector_store.clone();
         let embedding_provider = self.embedding_provider.clone();
         let config_manager = self.config_manager.clone();
@@ -137,9 +158,13 @@ impl Scheduler {
         // Config file mtime tracker — shared into the spawned task.
         let config_mtime: Arc<parking_lot::Mutex<Option<std::time::SystemTime>>> =
             Arc::new(parking_lot::Mutex::new(None));
+        let loop_health = self.loop_health.clone();
+        let maintenance_enabled = self.config.loops.maintenance;
+        let clock = self.clock.clone();
 
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(aggregation_interval);
+            let mut hour_boundary = HourBoundary::default();
             let mut last_reindex_check: Option<chrono::DateTime<Utc>> = None;
             let mut last_index_maintenance: Option<chrono::DateTime<Utc>> = None;
             let mut last_log_cleanup: Option<chrono::DateTime<Utc>> = None;
@@ -149,26 +174,28 @@ impl Scheduler {
             loop {
                 tokio::select! {
                     _ = interval.tick() => {
-                        let now = Utc::now();
-
-                        let prev_hour = now - ChronoDuration::hours(1);
-                        if let Err(e) = sqlite6.aggregate_hourly_metrics(prev_hour).await {
-                            warn!("hour failure: {e}");
-                        }
+                        loop_health.beat("aggregation", interval.period());
+                        let now = clock.now();
 
-                        let metrics_cutoff = now - ChronoDuration::hours(super::super::config::RAW_METRICS_RETENTION_HOURS);
-                        if let Err(e) = sqlite6.cleanup_old_metrics(metrics_cutoff).await {
-                            warn!("delete failure: {e}");
-                        }
-
-                        let process_cutoff = now - ChronoDuration::days(super::super::config::PROCESS_SNAPSHOT_RETENTION_DAYS);
-                        if let Err(e) = sqlite6.cleanup_old_process_snapshots(process_cutoff).await {
-                            warn!("delete failure: {e}");
+                        if let Some(prev_hour) = hour_boundary.crossed(now) {
+                            if let Err(e) = sqlite6.aggregate_hourly_metrics(prev_hour).await {
+                                warn!("hour failure: {e}");
+                            }
+                            // The last hour of a UTC day closes that day's rollup.
+                            if prev_hour.hour() == 23 {
+                                if let Err(e) = sqlite6.aggregate_daily_metrics(prev_hour).await {
+                                    warn!("daily metrics rollup failure: {e}");
+                                }
+                            }
+                            if let Some(ref recorder) = capture_latency {
+                                if let Err(e) = sqlite6.save_capture_latency(now, &recorder.summaries()) {
+                                    warn!("capture latency snapshot failure: {e}");
+                                }
+                            }
                         }
 
-                        let idle_cutoff = now - ChronoDuration::days(super::super::config::IDLE_PERIOD_RETENTION_DAYS);
-                        if let Err(e) = sqlite6.cleanup_old_idle_periods(idle_cutoff).await {
-                            warn!("idle period delete failure: {e}");
+                        if maintenance_enabled {
+                            enforce_core_retention(sqlite6.as_ref(), config_manager.as_ref(), now).await;
                         }
 
                         // --- Embedding re-indexing on model version change (daily) ---
@@ -267,27 +294,6 @@ impl Scheduler {
                             }
                         }
 
-                        // --- Activity segment retention (default: 90 days, same as embedding) ---
-                        {
-                            let segment_retention_days = config_manager
-                                .as_ref()
-                                .map(|cm| cm.get().analysis.embedding.retention_days)
-                                .unwrap_or(90);
-                            if let Err(e) = sqlite6.enforce_segment_retention(segment_retention_days) {
-                                warn!("segment retention failure: {e}");
-                            }
-
-                            // Weekly digests retention (keep 52 weeks = 1 year)
-                            if let Err(e) = sqlite6.enforce_digest_retention(52) {
-                                warn!("digest retention failure: {e}");
-                            }
-
-                            // Auxiliary table retention (work_sessions, interruptions, etc.)
-                            if let Err(e) = sqlite6.enforce_all_retention() {
-                                warn!("auxiliary table retention failure: {e}");
-                            }
-                        }
-
                         // --- Weekly digest auto-generation ---
                         {
                             let digest_day = config_manager
@@ -484,30 +490,14 @@ impl Scheduler {
                         }
 
                         // --- SQLite periodic maintenance (WAL checkpoint, FTS merge, conditional VACUUM) ---
-                        {
+                        if maintenance_enabled {
                             let should_maintain = last_sqlite_maintenance
                                 .map(|last| (now - last).num_minutes() >= super::super::config::SQLITE_MAINTENANCE_INTERVAL_MINS)
                                 .unwrap_or(true);
 
                             if should_maintain {
                                 last_sqlite_maintenance = Some(now);
-
-                                // WAL checkpoint (PASSIVE — non-blocking)
-                                if let Err(e) = sqlite6.wal_checkpoint_passive() {
-                                    warn!("WAL checkpoint failure: {e}");
-                                }
-
-                                // FTS5 incremental merge
-                                if let Err(e) = sqlite6.fts_merge(super::super::config::FTS_MERGE_PAGES) {
-                                    warn!("FTS5 merge failure: {e}");
-                                }
-
-                                // Conditional VACUUM (only when freelist > 20%)
-                                match sqlite6.maybe_vacuum(super::super::config::VACUUM_FREELIST_THRESHOLD_PERCENT) {
-                                    Ok(true) => info!("VACUUM completed during maintenance"),
-                                    Ok(false) => {}
-                                    Err(e) => warn!("VACUUM check failure: {e}"),
-                                }
+                                run_sqlite_maintenance(sqlite6.as_ref());
                             }
                         }
 
@@ -557,6 +547,139 @@ impl Scheduler {
             }
         })
     }
+
+    /// Retention and SQLite upkeep on the aggregation cadence, for setups
+    /// that disable the aggregation loop itself.
+    #[tracing::instrument(skip_all)]
+    pub(in crate::scheduler) fn spawn_maintenance_loop(
+        &self,
+        maintenance_interval: Duration,
+        mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
+    ) -> tokio::task::JoinHandle<()> {
+        let sqlite = self.sqlite_storage.clone();
+        let config_manager = self.config_manager.clone();
+        let loop_health = self.loop_health.clone();
+        let clock = self.clock.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(maintenance_interval);
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        loop_health.beat("maintenance", interval.period());
+                        enforce_core_retention(sqlite.as_ref(), config_manager.as_ref(), clock.now()).await;
+                        run_sqlite_maintenance(sqlite.as_ref());
+                        debug!("completed");
+                    }
+                    _ = shutdown_rx.changed() => {
+                        info!("ended");
+                        break;
+                    }
+                }
+            }
+        })
+    }
+
+    /// Periodic database snapshots with rotation. The first snapshot is taken
+    /// one interval after startup, not immediately.
+    #[tracing::instrument(skip_all)]
+    pub(in crate::scheduler) fn spawn_db_backup_loop(
+        &self,
+        backup_interval: Duration,
+        mut shutdown_rx: tokio::sync::watch::Receiver<bool>,
+    ) -> tokio::task::JoinHandle<()> {
+        let sqlite = self.sqlite_storage.clone();
+        let keep = self.config.db_backup_keep;
+        let loop_health = self.loop_health.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval_at(
+                tokio::time::Instant::now() + backup_interval,
+                backup_interval,
+            );
+
+            loop {
+                tokio::select! {
+                    _ = interval.tick() => {
+                        loop_health.beat("db_backup", interval.period());
+                        let sqlite = sqlite.clone();
+                        match tokio::task::spawn_blocking(move || sqlite.create_database_backup(keep)).await {
+                            Ok(Ok(record)) => info!(path = %record.path, size_bytes = record.size_bytes, "database backup completed"),
+                            Ok(Err(e)) => warn!("database backup failure: {e}"),
+                            Err(e) => warn!("database backup task failure: {e}"),
+                        }
+                    }
+                    _ = shutdown_rx.changed() => {
+                        info!("ended");
+                        break;
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Delete expired raw metrics, snapshots, segments, digests and auxiliary rows.
+async fn enforce_core_retention(
+    sqlite: &dyn SchedulerStorage,
+    config_manager: Option<&oneshim_core::config_manager::ConfigManager>,
+    now: chrono::DateTime<Utc>,
+) {
+    let metrics_cutoff =
+        now - ChronoDuration::hours(super::super::config::RAW_METRICS_RETENTION_HOURS);
+    if let Err(e) = sqlite.cleanup_old_metrics(metrics_cutoff).await {
+        warn!("delete failure: {e}");
+    }
+
+    let process_cutoff =
+        now - ChronoDuration::days(super::super::config::PROCESS_SNAPSHOT_RETENTION_DAYS);
+    if let Err(e) = sqlite.cleanup_old_process_snapshots(process_cutoff).await {
+        warn!("delete failure: {e}");
+    }
+
+    let idle_cutoff = now - ChronoDuration::days(super::super::config::IDLE_PERIOD_RETENTION_DAYS);
+    if let Err(e) = sqlite.cleanup_old_idle_periods(idle_cutoff).await {
+        warn!("idle period delete failure: {e}");
+    }
+
+    // Activity segment retention (default: 90 days, same as embedding)
+    let segment_retention_days = config_manager
+        .map(|cm| cm.get().analysis.embedding.retention_days)
+        .unwrap_or(90);
+    if let Err(e) = sqlite.enforce_segment_retention(segment_retention_days) {
+        warn!("segment retention failure: {e}");
+    }
+
+    // Weekly digests retention (keep 52 weeks = 1 year)
+    if let Err(e) = sqlite.enforce_digest_retention(52) {
+        warn!("digest retention failure: {e}");
+    }
+
+    // Auxiliary table retention (work_sessions, interruptions, etc.)
+    if let Err(e) = sqlite.enforce_all_retention() {
+        warn!("auxiliary table retention failure: {e}");
+    }
+}
+
+/// WAL checkpoint, FTS merge and conditional VACUUM.
+fn run_sqlite_maintenance(sqlite: &dyn SchedulerStorage) {
+    // WAL checkpoint (PASSIVE — non-blocking)
+    if let Err(e) = sqlite.wal_checkpoint_passive() {
+        warn!("WAL checkpoint failure: {e}");
+    }
+
+    // FTS5 incremental merge
+    if let Err(e) = sqlite.fts_merge(super::super::config::FTS_MERGE_PAGES) {
+        warn!("FTS5 merge failure: {e}");
+    }
+
+    // Conditional VACUUM (only when freelist > 20%)
+    match sqlite.maybe_vacuum(super::super::config::VACUUM_FREELIST_THRESHOLD_PERCENT) {
+        Ok(true) => info!("VACUUM completed during maintenance"),
+        Ok(false) => {}
+        Err(e) => warn!("VACUUM check failure: {e}"),
+    }
 }
 
 /// Check if the config file has been modified on disk.
@@ -12,12 +12,23 @@
 //!   tracking-schedule window is entered or exited, with a 60-second debounce guard
 //!   to suppress flip-flop storms from DST edges or backward clock jumps (A.18).
 //!
+//! * [`screen_lock_pauses_monitoring`] — the screen-lock gate: capture and
+//!   context collection are skipped while the screen is locked when
+//!   `schedule.pause_on_screen_lock` is set.
+//!
+//! * [`battery_pauses_capture`] — the low-battery gate: capture, OCR and
+//!   encoding are skipped on battery below
+//!   `schedule.min_battery_percent_for_capture`.
+//!
 //! A.5 implements the real logic for both functions.
 
 use async_trait::async_trait;
 use chrono::{DateTime, Local};
 use oneshim_core::config::AppConfig;
 use oneshim_core::consent::ConsentPermissions;
+use oneshim_core::models::event::{Event, SystemEvent, SystemEventType};
+use oneshim_monitor::power::{heavy_tasks_allowed, PowerTracker};
+use oneshim_monitor::screen_lock::{ScreenLockChange, ScreenLockTracker};
 use std::time::Instant;
 
 // ── TsNotifier — narrow port for tracking-schedule notifications ─────────────
@@ -175,6 +186,56 @@ pub(super) async fn tick_ts_notifications(
     *prev_ts_active = now_active;
 }
 
+// ── Screen lock gate ─────────────────────────────────────────────────────────
+
+/// Returns `true` when a locked screen should pause capture and context
+/// collection for this tick.
+pub(crate) fn screen_lock_pauses_monitoring(cfg: &AppConfig, screen_locked: bool) -> bool {
+    screen_locked && cfg.schedule.pause_on_screen_lock
+}
+
+/// Builds the system event recorded for a lock/unlock transition, or `None`
+/// when `schedule.record_screen_lock_events` is off.
+pub(crate) fn screen_lock_event(cfg: &AppConfig, change: ScreenLockChange) -> Option<Event> {
+    if !cfg.schedule.record_screen_lock_events {
+        return None;
+    }
+    let event_type = match change {
+        ScreenLockChange::Locked => SystemEventType::ScreenLocked,
+        ScreenLockChange::Unlocked => SystemEventType::ScreenUnlocked,
+    };
+    Some(Event::System(SystemEvent {
+        event_id: uuid::Uuid::new_v4(),
+        event_type,
+        timestamp: chrono::Utc::now(),
+        data: serde_json::Value::Null,
+    }))
+}
+
+/// Per-tick screen lock check for the monitor loop. Returns whether the rest
+/// of the tick should be skipped, plus the transition event to record.
+pub(super) async fn tick_screen_lock(
+    cfg: &AppConfig,
+    tracker: &mut ScreenLockTracker,
+) -> (bool, Option<Event>) {
+    let status = tracker.check().await;
+    let event = status
+        .change
+        .and_then(|change| screen_lock_event(cfg, change));
+    (screen_lock_pauses_monitoring(cfg, status.locked), event)
+}
+
+// ── Low battery gate ─────────────────────────────────────────────────────────
+
+/// Returns `true` when a low battery should skip capture, OCR and encoding
+/// this tick. The power state is only read when a threshold is configured.
+pub(super) async fn battery_pauses_capture(cfg: &AppConfig, power: &mut PowerTracker) -> bool {
+    let Some(min_percent) = cfg.schedule.min_battery_percent_for_capture else {
+        return false;
+    };
+    !heavy_tasks_allowed(power.status().await, Some(min_percent))
+}
+
 // ── TsNotifier impl for NotificationManager ──────────────────────────────────
 
 // `NotificationManager` 가 `TsNotifier` 를 구현하므로 monitor 루프에서 직접 전달된다.
@@ -195,6 +256,8 @@ mod tests {
     use chrono::{NaiveDate, TimeZone as _, Timelike};
     use oneshim_core::config::Weekday;
     use oneshim_core::config::{TrackingScheduleConfig, TrackingWindow};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
 
     // ── Fixture helpers ─────────────────────────────────────────────────────
 
@@ -804,4 +867,77 @@ mod tests {
             "last_notified_at must remain None when config disabled"
         );
     }
+
+    // ── Screen lock gate tests ──────────────────────────────────────────────
+
+    fn probe_tracker(locked: Arc<AtomicBool>) -> ScreenLockTracker {
+        ScreenLockTracker::new().with_probe(Arc::new(move || Some(locked.load(Ordering::SeqCst))))
+    }
+
+    #[test]
+    fn screen_lock_pauses_only_when_locked_and_enabled() {
+        let mut cfg = AppConfig::default_config();
+        cfg.schedule.pause_on_screen_lock = true;
+        assert!(screen_lock_pauses_monitoring(&cfg, true));
+        assert!(!screen_lock_pauses_monitoring(&cfg, false));
+
+        cfg.schedule.pause_on_screen_lock = false;
+        assert!(!screen_lock_pauses_monitoring(&cfg, true));
+    }
+
+    #[tokio::test]
+    async fn capture_suppressed_while_locked() {
+        let locked = Arc::new(AtomicBool::new(false));
+        let mut tracker = probe_tracker(locked.clone());
+        let mut cfg = AppConfig::default_config();
+        cfg.schedule.pause_on_screen_lock = true;
+
+        let (paused, _) = tick_screen_lock(&cfg, &mut tracker).await;
+        assert!(!paused, "unlocked screen must not pause capture");
+
+        locked.store(true, Ordering::SeqCst);
+        let (paused, _) = tick_screen_lock(&cfg, &mut tracker).await;
+        assert!(paused, "locked screen must pause capture");
+        let (paused, _) = tick_screen_lock(&cfg, &mut tracker).await;
+        assert!(
+            paused,
+            "capture must stay paused while the screen stays locked"
+        );
+
+        locked.store(false, Ordering::SeqCst);
+        let (paused, _) = tick_screen_lock(&cfg, &mut tracker).await;
+        assert!(!paused, "unlocking must resume capture");
+    }
+
+    #[tokio::test]
+    async fn lock_transitions_recorded_only_when_enabled() {
+        let locked = Arc::new(AtomicBool::new(true));
+        let mut cfg = AppConfig::default_config();
+        cfg.schedule.record_screen_lock_events = true;
+        let mut tracker = probe_tracker(locked.clone());
+
+        let (_, event) = tick_screen_lock(&cfg, &mut tracker).await;
+        assert!(matches!(
+            event,
+            Some(Event::System(SystemEvent {
+                event_type: SystemEventType::ScreenLocked,
+                ..
+            }))
+        ));
+        let (_, event) = tick_screen_lock(&cfg, &mut tracker).await;
+        assert!(event.is_none(), "no event without a transition");
+
+        locked.store(false, Ordering::SeqCst);
+        let (_, event) = tick_screen_lock(&cfg, &mut tracker).await;
+        assert!(matches!(
+            event,
+            Some(Event::System(SystemEvent {
+                event_type: SystemEventType::ScreenUnlocked,
+                ..
+            }))
+        ));
+
+        let quiet = AppConfig::default_config();
+        assert!(screen_lock_event(&quiet, ScreenLockChange::Locked).is_none());
+    }
 }
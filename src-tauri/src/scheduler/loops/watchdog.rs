@@ -0,0 +1,198 @@
+use chrono::Utc;
+use oneshim_monitor::input_activity::InputActivityCollector;
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::task::JoinHandle;
+use tracing::{error, info, warn};
+
+use super::super::config::PlatformEgressPolicy;
+use super::super::shared_regime_state::SharedRegimeState;
+use super::super::Scheduler;
+
+/// Everything needed to respawn a loop after its task exited. The monitor
+/// loop owns the adaptive trigger state and cannot be rebuilt from here.
+pub(in crate::scheduler) struct LoopRestartContext {
+    pub session_id: String,
+    pub egress_policy: Arc<PlatformEgressPolicy>,
+    pub input_collector: Arc<InputActivityCollector>,
+    pub llm_summarizer: Option<Arc<oneshim_analysis::LlmSegmentSummarizer>>,
+    pub shared_regime: Arc<SharedRegimeState>,
+    pub shutdown_rx: tokio::sync::watch::Receiver<bool>,
+}
+
+/// Drop the loops that were not spawned because config disabled them,
+/// logging which ones were skipped.
+pub(in crate::scheduler) fn keep_enabled_loops(
+    candidates: Vec<(&'static str, Option<JoinHandle<()>>)>,
+) -> Vec<(&'static str, JoinHandle<()>)> {
+    let mut disabled = Vec::new();
+    let tasks = candidates
+        .into_iter()
+        .filter_map(|(name, task)| {
+            if task.is_none() {
+                disabled.push(name);
+            }
+            task.map(|t| (name, t))
+        })
+        .collect();
+    if !disabled.is_empty() {
+        info!(?disabled, "scheduler loops disabled by config");
+    }
+    tasks
+}
+
+impl Scheduler {
+    /// Wait for shutdown while periodically checking loop heartbeats.
+    pub(in crate::scheduler) async fn supervise_loops(
+        &self,
+        tasks: &mut [(&'static str, JoinHandle<()>)],
+        restart_ctx: &LoopRestartContext,
+        shutdown_rx: &mut tokio::sync::watch::Receiver<bool>,
+    ) {
+        let watchdog = &self.config.watchdog;
+        if !watchdog.enabled {
+            let _ = shutdown_rx.changed().await;
+            return;
+        }
+
+        let mut ticker =
+            tokio::time::interval(Duration::from_secs(watchdog.check_interval_secs.max(1)));
+        ticker.tick().await; // skip immediate first tick
+        let mut alerted: HashSet<String> = HashSet::new();
+
+        loop {
+            tokio::select! {
+                _ = ticker.tick() => {
+                    self.check_loop_health(tasks, restart_ctx, &mut alerted).await;
+                }
+                _ = shutdown_rx.changed() => break,
+            }
+        }
+    }
+
+    /// One watchdog pass: restart exited loops when allowed, otherwise alert
+    /// once per stall episode.
+    async fn check_loop_health(
+        &self,
+        tasks: &mut [(&'static str, JoinHandle<()>)],
+        restart_ctx: &LoopRestartContext,
+        alerted: &mut HashSet<String>,
+    ) {
+        let stalled = self.loop_health.stalled_at(Utc::now());
+        alerted.retain(|name| stalled.contains(name));
+
+        for name in stalled {
+            let Some(slot) = tasks.iter_mut().find(|(n, _)| *n == name) else {
+                continue;
+            };
+            let exited = slot.1.is_finished();
+
+            if exited && self.config.watchdog.auto_restart {
+                if let Some(handle) = self.respawn_loop(slot.0, restart_ctx) {
+                    let old = std::mem::replace(&mut slot.1, handle);
+                    if let Err(e) = old.await {
+                        error!(loop_name = slot.0, "scheduler loop panicked: {e}");
+                    }
+                    self.loop_health.record_restart(slot.0);
+                    alerted.remove(&name);
+                    warn!(loop_name = slot.0, "scheduler loop restarted by watchdog");
+                    continue;
+                }
+            }
+
+            if alerted.insert(name.clone()) {
+                error!(
+                    loop_name = %name,
+                    task_exited = exited,
+                    "scheduler loop stalled: no heartbeat within the watchdog window"
+                );
+                if let Some(ref notif) = self.notification_manager {
+                    let body = format!(
+                        "The '{name}' loop stopped updating. \
+                         Restart the app if data looks stale."
+                    );
+                    notif.notify("Background task stopped", &body).await;
+                }
+            }
+        }
+    }
+
+    fn respawn_loop(&self, name: &str, ctx: &LoopRestartContext) -> Option<JoinHandle<()>> {
+        let rx = ctx.shutdown_rx.clone();
+        let handle = match name {
+            "metrics" => self.spawn_metrics_loop(rx),
+            "process" => self.spawn_process_loop(self.config.process_interval, rx),
+            "sync" => {
+                self.spawn_sync_loop(self.config.sync_interval, ctx.egress_policy.clone(), rx)
+            }
+            "heartbeat" => self.spawn_heartbeat_loop(
+                self.config.heartbeat_interval,
+                ctx.session_id.clone(),
+                ctx.egress_policy.clone(),
+                rx,
+            ),
+            "aggregation" => self.spawn_aggregation_loop(
+                self.config.aggregation_interval,
+                ctx.llm_summarizer.clone(),
+                rx,
+            ),
+            "maintenance" => self.spawn_maintenance_loop(self.config.aggregation_interval, rx),
+            "db_backup" => self.spawn_db_backup_loop(self.config.db_backup_interval?, rx),
+            "notification" => self.spawn_notification_loop(self.focus_mode.clone(), rx),
+            "focus" => self.spawn_focus_loop(rx),
+            "event_snapshot" => self.spawn_event_snapshot_loop(
+                self.config.detailed_process_interval,
+                self.config.input_activity_interval,
+                ctx.egress_policy.clone(),
+                ctx.input_collector.clone(),
+                rx,
+            ),
+            "analysis" => self.spawn_analysis_loop(self.config.analysis_config.clone(), rx),
+            "cross_device_sync" => {
+                self.spawn_cross_device_sync_loop(self.config.cross_device_sync_interval, rx)
+            }
+            "coaching" => self.spawn_coaching_loop(ctx.shared_regime.clone(), rx),
+            _ => {
+                info!(
+                    loop_name = name,
+                    "scheduler loop cannot be restarted in-process"
+                );
+                return None;
+            }
+        };
+        Some(handle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn idle_task(mut rx: tokio::sync::watch::Receiver<bool>) -> JoinHandle<()> {
+        tokio::spawn(async move {
+            let _ = rx.changed().await;
+        })
+    }
+
+    #[tokio::test]
+    async fn disabled_loop_is_not_spawned_and_others_keep_running() {
+        let (tx, rx) = tokio::sync::watch::channel(false);
+        let tasks = keep_enabled_loops(vec![
+            ("metrics", None),
+            ("process", Some(idle_task(rx.clone()))),
+            ("sync", Some(idle_task(rx.clone()))),
+        ]);
+
+        let names: Vec<_> = tasks.iter().map(|(n, _)| *n).collect();
+        assert_eq!(names, vec!["process", "sync"]);
+
+        tokio::task::yield_now().await;
+        assert!(tasks.iter().all(|(_, t)| !t.is_finished()));
+
+        tx.send(true).unwrap();
+        for (_, task) in tasks {
+            task.await.unwrap();
+        }
+    }
+}
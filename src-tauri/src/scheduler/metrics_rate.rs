@@ -0,0 +1,136 @@
+//! Metrics sampling rate shared between the metrics loop and IPC commands.
+//!
+//! The loop normally samples at `monitor.metrics_interval_ms`. A burst raises
+//! the rate for a bounded window (e.g. while the user reports a slowdown)
+//! and reverts on its own when the window ends.
+
+use parking_lot::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Fastest sampling a burst may request.
+pub(crate) const MIN_BURST_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Longest burst window; longer requests are clamped.
+pub(crate) const MAX_BURST_WINDOW: Duration = Duration::from_secs(10 * 60);
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Burst {
+    interval: Duration,
+    until: Instant,
+}
+
+pub struct MetricsRate {
+    base: Duration,
+    burst: Mutex<Option<Burst>>,
+    /// Wakes the metrics loop so a burst takes effect without waiting out
+    /// a long base tick.
+    changed: Notify,
+}
+
+impl MetricsRate {
+    pub(crate) fn new(base: Duration) -> Self {
+        Self {
+            base,
+            burst: Mutex::new(None),
+            changed: Notify::new(),
+        }
+    }
+
+    pub(crate) fn base(&self) -> Duration {
+        self.base
+    }
+
+    /// Sample every `interval` for the next `window`, replacing any running
+    /// burst. Both are clamped to [`MIN_BURST_INTERVAL`] and
+    /// [`MAX_BURST_WINDOW`]. Returns when the burst ends.
+    pub(crate) fn start_burst(&self, interval: Duration, window: Duration) -> Instant {
+        self.start_burst_at(interval, window, Instant::now())
+    }
+
+    fn start_burst_at(&self, interval: Duration, window: Duration, now: Instant) -> Instant {
+        let until = now + window.min(MAX_BURST_WINDOW);
+        *self.burst.lock() = Some(Burst {
+            interval: interval.max(MIN_BURST_INTERVAL),
+            until,
+        });
+        self.changed.notify_one();
+        until
+    }
+
+    /// End a running burst early.
+    pub(crate) fn stop_burst(&self) {
+        if self.burst.lock().take().is_some() {
+            self.changed.notify_one();
+        }
+    }
+
+    /// Interval the metrics loop should use right now.
+    pub(crate) fn current(&self) -> Duration {
+        self.interval_at(Instant::now())
+    }
+
+    fn interval_at(&self, now: Instant) -> Duration {
+        let mut burst = self.burst.lock();
+        match *burst {
+            Some(active) if now < active.until => active.interval.min(self.base),
+            Some(_) => {
+                *burst = None;
+                self.base
+            }
+            None => self.base,
+        }
+    }
+
+    /// Resolves after the next `start_burst` / `stop_burst`.
+    pub(crate) async fn changed(&self) {
+        self.changed.notified().await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn uses_configured_interval_without_burst() {
+        let rate = MetricsRate::new(Duration::from_secs(30));
+        assert_eq!(rate.current(), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn burst_raises_rate_then_restores_after_window() {
+        let rate = MetricsRate::new(Duration::from_secs(5));
+        let start = Instant::now();
+        let until = rate.start_burst_at(Duration::from_secs(1), Duration::from_secs(60), start);
+
+        assert_eq!(until, start + Duration::from_secs(60));
+        assert_eq!(rate.interval_at(start), Duration::from_secs(1));
+        assert_eq!(
+            rate.interval_at(start + Duration::from_secs(59)),
+            Duration::from_secs(1)
+        );
+        assert_eq!(rate.interval_at(until), Duration::from_secs(5));
+        assert!(rate.burst.lock().is_none(), "expired burst is cleared");
+    }
+
+    #[test]
+    fn burst_is_clamped_and_never_slows_sampling() {
+        let rate = MetricsRate::new(Duration::from_secs(1));
+        let start = Instant::now();
+        let until = rate.start_burst_at(Duration::ZERO, Duration::from_secs(86_400), start);
+        assert_eq!(until, start + MAX_BURST_WINDOW);
+        assert_eq!(rate.interval_at(start), MIN_BURST_INTERVAL);
+
+        rate.start_burst_at(Duration::from_secs(10), Duration::from_secs(60), start);
+        assert_eq!(rate.interval_at(start), Duration::from_secs(1));
+    }
+
+    #[test]
+    fn stop_burst_restores_base_immediately() {
+        let rate = MetricsRate::new(Duration::from_secs(5));
+        rate.start_burst(Duration::from_secs(1), Duration::from_secs(60));
+        rate.stop_burst();
+        assert_eq!(rate.current(), Duration::from_secs(5));
+    }
+}
@@ -16,7 +16,10 @@ mod config;
 /// Called after `run_analysis_tick()` each cycle when `gui_intelligence.enabled`.
 pub(crate) mod gui_pipeline;
 pub(crate) mod heatmap;
+pub(crate) mod loop_health;
 mod loops;
+pub(crate) mod metrics_rate;
+pub(crate) mod presence_state;
 pub(crate) mod shared_regime_state;
 
 // ── Public re-exports (external API) ────────────────────────────────
@@ -27,22 +30,25 @@ use chrono::{Datelike, Timelike};
 use oneshim_core::app_registry::AppRegistry;
 use oneshim_core::config::{AppConfig, Weekday};
 use oneshim_core::config_manager::ConfigManager;
-use oneshim_core::consent::ConsentManager;
+use oneshim_core::consent::{ConsentManager, ConsentRegistry};
 use oneshim_core::models::activity::SessionStats;
+use oneshim_core::models::capture_latency::CaptureLatencyRecorder;
 use oneshim_core::models::tiered_memory::ResolvedParams;
+use oneshim_core::monitor_feed::MonitorFeed;
 use oneshim_core::ports::accessibility::AccessibilityExtractor;
 #[cfg(feature = "hnsw")]
 use oneshim_core::ports::ann_index::AnnIndex;
 use oneshim_core::ports::api_client::ApiClient;
 use oneshim_core::ports::batch_sink::BatchSink;
 use oneshim_core::ports::calibration_store::{CalibrationReader, CalibrationWriter};
+use oneshim_core::ports::clock::{Clock, SystemClock};
 use oneshim_core::ports::coaching_storage::CoachingStoragePort;
 use oneshim_core::ports::frame_storage::FrameStoragePort;
 use oneshim_core::ports::monitor::{ActivityMonitor, ProcessMonitor, SystemMonitor};
 use oneshim_core::ports::overlay_driver::OverlayDriver;
 use oneshim_core::ports::storage::StorageService;
 use oneshim_core::ports::vector_index::VectorIndex;
-use oneshim_core::ports::vision::{CaptureTrigger, FrameProcessor};
+use oneshim_core::ports::vision::{CaptureSignal, CaptureTrigger, FrameProcessor};
 #[cfg(feature = "server")]
 use oneshim_network::oauth::refresh_coordinator::TokenRefreshCoordinator;
 use oneshim_web::RealtimeEvent;
@@ -75,12 +81,12 @@ pub(crate) struct AdaptiveTriggerState {
     // Wrapped in `Arc<parking_lot::Mutex<_>>` so the composition root
     // can share handles with `AppState` for (a) startup hydration from
     // `RegimeStoragePort::load_all`, (b) shutdown save via the guard in
-    // `main.rs::RunEvent::Exit`, and (c) `CompositeFeedbackSink` fan-out
+    // `lib.rs::RunEvent::Exit`, and (c) `CompositeFeedbackSink` fan-out
     // (feedback_sink.rs). At runtime the scheduler has de-facto
     // exclusive access — the shutdown save guard fires only after
     // `shutdown_tx → shutdown_blocking()` drains the scheduler loops —
     // so scheduler-vs-save contention is absent. This says nothing
-    // about the separate connection-mutex story in main.rs (see the
+    // about the separate connection-mutex story in lib.rs (see the
     // WAL-checkpoint-before-save note in `RunEvent::Exit`).
     pub regime_classifier: Arc<parking_lot::Mutex<oneshim_analysis::RegimeClassifier>>,
     pub regime_manager: Arc<parking_lot::Mutex<oneshim_analysis::RegimeManager>>,
@@ -146,6 +152,7 @@ pub struct Scheduler {
     pub(super) batch_sink: Option<Arc<dyn BatchSink>>,
     pub(super) api_client: Option<Arc<dyn ApiClient>>,
     pub(super) event_tx: Option<broadcast::Sender<RealtimeEvent>>,
+    pub(super) monitor_feed: Option<MonitorFeed>,
     pub(super) notification_manager: Option<Arc<NotificationManager>>,
     pub(super) focus_analyzer: Option<Arc<FocusAnalyzer>>,
     #[cfg(feature = "server")]
@@ -174,6 +181,9 @@ pub struct Scheduler {
     /// ConsentManager for runtime consent checks (e.g., full_text_extraction).
     /// Wrapped in Arc for shared access across async blocks.
     pub(super) consent_manager: Option<Arc<ConsentManager>>,
+    /// Per-capability consent (clipboard, input monitoring), checked each tick
+    /// so a revocation applies without a restart.
+    pub(super) consent_registry: Option<Arc<ConsentRegistry>>,
     /// Coaching engine for proactive coaching messages (Phase 1).
     /// `None` when coaching is not configured. The engine checks `enabled`
     /// internally and returns `None` from `evaluate()` when disabled.
@@ -227,6 +237,21 @@ pub struct Scheduler {
     /// with SessionManager's context assembler. Falls back to a local instance
     /// in run_scheduler_loops if not provided.
     pub(super) shared_regime: Option<Arc<shared_regime_state::SharedRegimeState>>,
+    /// Per-loop heartbeats written each tick and read by the watchdog and
+    /// the dashboard.
+    pub(super) loop_health: Arc<loop_health::LoopHealthRegistry>,
+    /// Idle state written by the monitor loop and attached to heartbeats.
+    pub(super) presence: Arc<presence_state::SharedPresenceState>,
+    /// Latest clipboard/file-save signal from the event snapshot loop,
+    /// consumed by the monitor loop on its next capture decision.
+    pub(super) pending_capture_signal: Arc<parking_lot::Mutex<Option<CaptureSignal>>>,
+    /// Time source for aggregation boundaries, retention cutoffs and idle
+    /// timestamps. `SystemClock` unless a test injects its own.
+    pub(super) clock: Arc<dyn Clock>,
+    /// Frame pipeline stage timings, snapshotted to storage every hour.
+    pub(super) capture_latency: Option<Arc<CaptureLatencyRecorder>>,
+    /// Metrics loop sampling rate, raised temporarily by burst requests.
+    pub(super) metrics_rate: Arc<metrics_rate::MetricsRate>,
 }
 
 // --- Builder methods ---
@@ -246,6 +271,10 @@ impl Scheduler {
         batch_sink: Option<Arc<dyn BatchSink>>,
         api_client: Option<Arc<dyn ApiClient>>,
     ) -> Self {
+        let loop_health = Arc::new(loop_health::LoopHealthRegistry::new(
+            config.watchdog.stall_multiplier,
+        ));
+        let metrics_rate = Arc::new(metrics_rate::MetricsRate::new(config.metrics_interval));
         Self {
             config,
             system_monitor,
@@ -259,6 +288,7 @@ impl Scheduler {
             batch_sink,
             api_client,
             event_tx: None,
+            monitor_feed: None,
             notification_manager: None,
             focus_analyzer: None,
             #[cfg(feature = "server")]
@@ -275,6 +305,7 @@ impl Scheduler {
             sync_engine: None,
             accessibility_extractor: None,
             consent_manager: None,
+            consent_registry: None,
             coaching_engine: None,
             magic_overlay: None,
             overlay_driver: None,
@@ -297,6 +328,12 @@ impl Scheduler {
             suggestions_enabled: false,
             focus_mode: Arc::new(crate::focus_mode::FocusModeState::new()),
             shared_regime: None,
+            loop_health,
+            presence: Arc::new(presence_state::SharedPresenceState::new()),
+            pending_capture_signal: Arc::new(parking_lot::Mutex::new(None)),
+            clock: Arc::new(SystemClock),
+            capture_latency: None,
+            metrics_rate,
         }
     }
 
@@ -310,6 +347,12 @@ impl Scheduler {
         self
     }
 
+    /// Publish every collected context and metrics sample to `feed`.
+    pub fn with_monitor_feed(mut self, feed: MonitorFeed) -> Self {
+        self.monitor_feed = Some(feed);
+        self
+    }
+
     pub fn with_notification_manager(mut self, manager: Arc<NotificationManager>) -> Self {
         self.notification_manager = Some(manager);
         self
@@ -397,6 +440,11 @@ impl Scheduler {
         self
     }
 
+    pub fn with_consent_registry(mut self, consent_registry: Arc<ConsentRegistry>) -> Self {
+        self.consent_registry = Some(consent_registry);
+        self
+    }
+
     pub fn with_coaching_engine(mut self, engine: Arc<oneshim_analysis::CoachingEngine>) -> Self {
         self.coaching_engine = Some(engine);
         self
@@ -497,6 +545,15 @@ impl Scheduler {
         self
     }
 
+    /// Share the metrics sampling rate with IPC burst commands.
+    pub(crate) fn with_metrics_rate(
+        mut self,
+        metrics_rate: Arc<metrics_rate::MetricsRate>,
+    ) -> Self {
+        self.metrics_rate = metrics_rate;
+        self
+    }
+
     pub fn with_focus_mode(mut self, focus_mode: Arc<crate::focus_mode::FocusModeState>) -> Self {
         self.focus_mode = focus_mode;
         self
@@ -510,6 +567,24 @@ impl Scheduler {
         self
     }
 
+    pub(crate) fn with_loop_health(
+        mut self,
+        registry: Arc<loop_health::LoopHealthRegistry>,
+    ) -> Self {
+        self.loop_health = registry;
+        self
+    }
+
+    pub(crate) fn with_capture_latency(mut self, recorder: Arc<CaptureLatencyRecorder>) -> Self {
+        self.capture_latency = Some(recorder);
+        self
+    }
+
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
     // --- Session management ---
 
     pub(super) async fn initialize_session(&self, session_id: &str) {
@@ -529,7 +604,7 @@ impl Scheduler {
     ) {
         info!(
             monitor_poll_ms = self.config.poll_interval.as_millis() as u64,
-            metrics_ms = self.config.metrics_interval.as_millis() as u64,
+            metrics_ms = self.metrics_rate.base().as_millis() as u64,
             process_ms = self.config.process_interval.as_millis() as u64,
             detailed_process_ms = self.config.detailed_process_interval.as_millis() as u64,
             input_activity_ms = self.config.input_activity_interval.as_millis() as u64,
@@ -539,6 +614,8 @@ impl Scheduler {
             health_check_secs = 60,
             coaching_secs = config::COACHING_INTERVAL_SECS,
             sqlite_maintenance_mins = config::SQLITE_MAINTENANCE_INTERVAL_MINS,
+            watchdog_enabled = self.config.watchdog.enabled,
+            watchdog_auto_restart = self.config.watchdog.auto_restart,
             "scheduler loops starting"
         );
         self.run_scheduler_loops(shutdown_rx, app_handle).await;
@@ -696,6 +773,14 @@ mod tests {
         assert_eq!(config.idle_threshold_secs, 300);
     }
 
+    #[test]
+    fn scheduler_config_honors_metrics_interval() {
+        let mut app_config = AppConfig::default_config();
+        app_config.monitor.metrics_interval_ms = 30_000;
+        let config = SchedulerConfig::from_app_config(&app_config, "sess".to_string());
+        assert_eq!(config.metrics_interval, Duration::from_secs(30));
+    }
+
     #[test]
     fn platform_sync_is_disabled_in_current_ai_runtime() {
         let config = SchedulerConfig {
@@ -778,6 +863,33 @@ mod tests {
         assert!(policy.prepare_event_for_upload(event).is_none());
     }
 
+    #[test]
+    fn local_only_keeps_content_but_uploads_metrics() {
+        let config = SchedulerConfig {
+            upload_enabled: true,
+            privacy_config: PrivacyConfig {
+                local_only: true,
+                ..PrivacyConfig::default()
+            },
+            ..SchedulerConfig::default()
+        };
+        let policy = PlatformEgressPolicy::new(&config);
+        let context = Event::Context(ContextEvent {
+            app_name: "Code".to_string(),
+            window_title: "main.rs".to_string(),
+            ..Default::default()
+        });
+        assert!(policy.prepare_event_for_upload(context).is_none());
+
+        let metrics = Event::System(oneshim_core::models::event::SystemEvent {
+            event_id: uuid::Uuid::new_v4(),
+            event_type: oneshim_core::models::event::SystemEventType::MetricsUpdate,
+            timestamp: chrono::Utc::now(),
+            data: serde_json::Value::Null,
+        });
+        assert!(policy.prepare_event_for_upload(metrics).is_some());
+    }
+
     // ── Overnight active_hours wrap tests (CONS-C05) ─────────────────────────
 
     /// Build a `DateTime<Local>` for a known weekday at HH:MM.
@@ -0,0 +1,64 @@
+use oneshim_core::models::activity::{HeartbeatPresence, IdleInfo};
+use parking_lot::RwLock;
+
+/// Latest idle state, shared between monitor and heartbeat loops.
+/// Monitor loop writes each tick, heartbeat loop reads once per heartbeat.
+/// Holds state only, never input content.
+pub struct SharedPresenceState {
+    inner: RwLock<Option<HeartbeatPresence>>,
+}
+
+impl SharedPresenceState {
+    pub fn new() -> Self {
+        Self {
+            inner: RwLock::new(None),
+        }
+    }
+
+    /// Called by monitor loop after each idle check.
+    pub fn record(&self, info: &IdleInfo) {
+        *self.inner.write() = Some(HeartbeatPresence::from(info));
+    }
+
+    /// Called by heartbeat loop. `None` until the first idle check.
+    pub fn snapshot(&self) -> Option<HeartbeatPresence> {
+        *self.inner.read()
+    }
+}
+
+impl Default for SharedPresenceState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+    use oneshim_core::models::activity::IdleState;
+
+    #[test]
+    fn snapshot_is_empty_before_first_check() {
+        assert!(SharedPresenceState::new().snapshot().is_none());
+    }
+
+    #[test]
+    fn record_populates_from_idle_tracker_output() {
+        let state = SharedPresenceState::new();
+        state.record(&IdleInfo {
+            state: IdleState::Active,
+            idle_secs: 2,
+            timestamp: Utc::now(),
+        });
+        state.record(&IdleInfo {
+            state: IdleState::Idle,
+            idle_secs: 310,
+            timestamp: Utc::now(),
+        });
+
+        let presence = state.snapshot().unwrap();
+        assert_eq!(presence.idle_state, IdleState::Idle);
+        assert_eq!(presence.secs_since_last_input, 310);
+    }
+}
@@ -11,6 +11,10 @@ use oneshim_core::ports::oauth::OAuthPort;
 #[cfg(feature = "server")]
 use oneshim_core::ports::secret_store::{SecretStore, SecretStoreSet};
 #[cfg(feature = "server")]
+use oneshim_network::circuit_breaker::{CircuitBreaker, CircuitBreakerConfig};
+#[cfg(feature = "server")]
+use oneshim_network::metrics::NetworkMetrics;
+#[cfg(feature = "server")]
 use oneshim_network::oauth::refresh_coordinator::TokenRefreshCoordinator;
 #[cfg(feature = "server")]
 use oneshim_network::oauth::OAuthClient;
@@ -101,6 +105,10 @@ pub(crate) struct ServerLaunchContext {
     oauth_port: Option<Arc<dyn OAuthPort>>,
     oauth_coordinator: OAuthCoordinator,
     oauth_provider_ids: Vec<String>,
+    /// Shared by the server clients and the connection indicator.
+    server_breaker: Arc<CircuitBreaker>,
+    /// Shared by the server clients, the dashboard and the shutdown summary.
+    network_metrics: Arc<NetworkMetrics>,
 }
 
 #[cfg(feature = "server")]
@@ -114,6 +122,8 @@ impl ServerLaunchContext {
             oauth_port: server.oauth_port,
             oauth_coordinator: server.oauth_coordinator,
             oauth_provider_ids: server.oauth_provider_ids,
+            server_breaker: Arc::new(CircuitBreaker::new(CircuitBreakerConfig::default())),
+            network_metrics: NetworkMetrics::new(),
         }
     }
 
@@ -129,26 +139,31 @@ impl ServerLaunchContext {
         &self,
         builder: AgentRuntimeBuilder<'a>,
     ) -> AgentRuntimeBuilder<'a> {
-        builder.with_oauth_coordinator(self.oauth_coordinator.clone())
+        builder
+            .with_oauth_coordinator(self.oauth_coordinator.clone())
+            .with_server_breaker(self.server_breaker.clone())
+            .with_network_metrics(self.network_metrics.clone())
     }
 
     pub(crate) fn configure_web_server_builder<'a>(
         &self,
         builder: WebServerRuntimeBuilder<'a>,
     ) -> WebServerRuntimeBuilder<'a> {
-        builder.with_server_support(WebServerServerSupport::new(
-            self.integration_bindings.auth.clone(),
-            self.integration_bindings.session.clone(),
-            self.integration_bindings.outbox.clone(),
-            self.integration_bindings.inbox.clone(),
-            self.integration_bindings.inbox_store.clone(),
-            self.integration_bindings.audit.clone(),
-            self.integration_bindings.telemetry.clone(),
-            self.provider_secret_backend.secret_store.clone(),
-            Some(self.provider_secret_stores.clone()),
-            Some(self.provider_secret_backend.backend_kind),
-            self.oauth_port.clone(),
-        ))
+        builder
+            .with_network_metrics(self.network_metrics.clone())
+            .with_server_support(WebServerServerSupport::new(
+                self.integration_bindings.auth.clone(),
+                self.integration_bindings.session.clone(),
+                self.integration_bindings.outbox.clone(),
+                self.integration_bindings.inbox.clone(),
+                self.integration_bindings.inbox_store.clone(),
+                self.integration_bindings.audit.clone(),
+                self.integration_bindings.telemetry.clone(),
+                self.provider_secret_backend.secret_store.clone(),
+                Some(self.provider_secret_stores.clone()),
+                Some(self.provider_secret_backend.backend_kind),
+                self.oauth_port.clone(),
+            ))
     }
 
     pub(crate) fn configure_state_builder(
@@ -166,6 +181,8 @@ impl ServerLaunchContext {
                 self.integration_bindings.auth.clone(),
                 self.integration_bindings.session.clone(),
             )
+            .with_server_circuit(self.server_breaker.clone())
+            .with_network_metrics(self.network_metrics.clone())
     }
 }
 
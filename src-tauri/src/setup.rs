@@ -6,7 +6,7 @@ use tracing::info;
 use tracing::warn;
 
 use crate::app_runtime_launch::{AppRuntimeLaunchBuilder, AppRuntimeLaunchResult};
-use crate::bootstrap_runtime::{BootstrapRuntimeBuilder, BootstrapRuntimeBundle};
+use crate::bootstrap_runtime::{profile_arg, BootstrapRuntimeBuilder, BootstrapRuntimeBundle};
 use crate::desktop_startup::DesktopStartupCoordinator;
 use crate::telemetry;
 
@@ -28,10 +28,13 @@ pub fn init(app: &mut App) -> Result<(), Box<dyn std::error::Error>> {
     }
 
     info!("Tauri setup: initializing Maekon agent");
-    let bundle = BootstrapRuntimeBuilder::new().build()?;
+    let args: Vec<String> = std::env::args().collect();
+    let bundle = BootstrapRuntimeBuilder::new()
+        .with_profile(profile_arg(&args))
+        .build()?;
 
     // Bus-driven telemetry reconcile task. ConfigManager now exists (built
-    // inside bundle); the tracing subscriber was installed in main.rs with a
+    // inside bundle); the tracing subscriber was installed in lib.rs with a
     // seeded-disabled TelemetryConfig. Spawn a task that forwards every
     // ConfigChangeBus update to `TelemetryHandle::apply`. The first iteration
     // RECONCILES: it compares `TelemetryConfig::default()` against the real
@@ -72,10 +75,10 @@ pub fn init(app: &mut App) -> Result<(), Box<dyn std::error::Error>> {
 /// Spawn the bus-driven telemetry reconcile task. Must be called AFTER
 /// `bundle.config_manager` is fully constructed — the task captures a
 /// subscriber via `config_manager.subscribe()` and keeps a clone of the
-/// `Arc<TelemetryHandle>` that `main.rs` stashed in Tauri managed state.
+/// `Arc<TelemetryHandle>` that `lib.rs` stashed in Tauri managed state.
 ///
 /// The first iteration is a synchronous reconcile: seed `prev` with
-/// `TelemetryConfig::default()` (matching what `main.rs` used for the
+/// `TelemetryConfig::default()` (matching what `lib.rs` used for the
 /// subscriber), then compare against the real startup config and apply if it
 /// differs. This is how a startup config with `enabled=true` actually
 /// activates the exporter — without it, the user's opt-in would stay dormant
@@ -157,15 +160,15 @@ mod tests {
         );
     }
 
-    /// main.rs에 RunEvent::Reopen 핸들러가 있는지 검증.
+    /// lib.rs에 RunEvent::Reopen 핸들러가 있는지 검증.
     /// macOS dock 아이콘 클릭 시 윈도우를 다시 표시하기 위해 필수.
     #[test]
-    fn main_contains_reopen_handler() {
-        let main_src = include_str!("main.rs");
+    fn lib_contains_reopen_handler() {
+        let lib_src = include_str!("lib.rs");
 
         assert!(
-            main_src.contains("RunEvent::Reopen"),
-            "main.rs must handle RunEvent::Reopen for macOS dock icon clicks"
+            lib_src.contains("RunEvent::Reopen"),
+            "lib.rs must handle RunEvent::Reopen for macOS dock icon clicks"
         );
     }
 }
@@ -19,6 +19,9 @@ pub(crate) fn register_all(app: &App) {
 
     // Register automation quick-access shortcut (Cmd+Shift+A / Ctrl+Shift+A)
     register_automation_shortcut(app);
+
+    // Register bookmark shortcut (Cmd+Shift+B / Ctrl+Shift+B)
+    register_bookmark_shortcut(app);
 }
 
 /// Register Cmd+Shift+\ (macOS) / Ctrl+Shift+\ (Windows/Linux) to toggle
@@ -217,3 +220,42 @@ fn register_automation_shortcut(app: &App) {
         tracing::warn!("failed to register automation shortcut: {e}");
     }
 }
+
+/// Register Cmd+Shift+B (macOS) / Ctrl+Shift+B (Windows/Linux) to mark the
+/// current moment. With `vision.bookmarks.prompt_label` on, the main window is
+/// asked for a label (`bookmark:label-prompt`) and records via the
+/// `record_bookmark` command; otherwise the bookmark is recorded immediately.
+fn register_bookmark_shortcut(app: &App) {
+    use tauri::{Emitter, Manager};
+    use tauri_plugin_global_shortcut::{GlobalShortcutExt, ShortcutState};
+
+    let result =
+        app.global_shortcut()
+            .on_shortcut("CmdOrCtrl+Shift+B", |app_handle, _shortcut, event| {
+                if event.state == ShortcutState::Pressed {
+                    let handle = app_handle.clone();
+                    tauri::async_runtime::spawn(async move {
+                        let prompt_label = handle
+                            .try_state::<crate::runtime_state::ConfigRuntimeState>()
+                            .map(|config| {
+                                config.config_manager().get().vision.bookmarks.prompt_label
+                            })
+                            .unwrap_or(false);
+                        if prompt_label {
+                            let _ = handle.emit_to("main", "bookmark:label-prompt", ());
+                            return;
+                        }
+                        if let Err(e) =
+                            crate::commands::bookmarks::record_bookmark_now(&handle, None).await
+                        {
+                            tracing::warn!("Failed to record bookmark: {e}");
+                        }
+                    });
+                }
+            });
+
+    match result {
+        Ok(()) => info!("Global shortcut registered: CmdOrCtrl+Shift+B (bookmark)"),
+        Err(e) => tracing::warn!("Failed to register bookmark shortcut: {e}"),
+    }
+}
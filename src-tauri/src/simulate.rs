@@ -0,0 +1,209 @@
+//! Simulation (dry-run) mode — run the monitoring pipeline without keeping
+//! or sending anything.
+//!
+//! [`AgentBuilder::simulate`](crate::AgentBuilder::simulate) swaps these
+//! adapters in at wiring time: uploads are counted and logged instead of
+//! sent, and capture records frame metadata without grabbing pixels. Storage
+//! is an in-memory database that disappears with the agent.
+
+use async_trait::async_trait;
+use chrono::Utc;
+use oneshim_core::error::CoreError;
+use oneshim_core::models::event::Event;
+use oneshim_core::models::frame::{FrameMetadata, ProcessedFrame};
+use oneshim_core::ports::batch_sink::{BatchSink, FlushReport};
+use oneshim_core::ports::vision::{CaptureRequest, FrameProcessor};
+use parking_lot::Mutex;
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use tracing::{debug, info};
+
+/// Running totals of what a simulated agent would have done.
+#[derive(Debug, Default)]
+pub struct SimulationStats {
+    events_queued: AtomicU64,
+    events_uploaded: AtomicU64,
+    upload_flushes: AtomicU64,
+    frames_captured: AtomicU64,
+}
+
+impl SimulationStats {
+    pub fn summary(&self) -> SimulationSummary {
+        SimulationSummary {
+            events_queued: self.events_queued.load(Ordering::Relaxed),
+            events_would_upload: self.events_uploaded.load(Ordering::Relaxed),
+            upload_flushes: self.upload_flushes.load(Ordering::Relaxed),
+            frames_would_capture: self.frames_captured.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Point-in-time copy of [`SimulationStats`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SimulationSummary {
+    /// Events handed to the upload queue.
+    pub events_queued: u64,
+    /// Events that a real uploader would have sent.
+    pub events_would_upload: u64,
+    /// Upload cycles that had something to send.
+    pub upload_flushes: u64,
+    /// Screen captures that were requested (metadata only).
+    pub frames_would_capture: u64,
+}
+
+impl fmt::Display for SimulationSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} events collected, {} would be uploaded in {} batches, {} frames would be captured",
+            self.events_queued,
+            self.events_would_upload,
+            self.upload_flushes,
+            self.frames_would_capture
+        )
+    }
+}
+
+/// [`BatchSink`] that drains its queue into the log instead of the network.
+pub(crate) struct SimulatedBatchSink {
+    /// Queued events with their local row ids.
+    queue: Mutex<Vec<(String, Event)>>,
+    stats: Arc<SimulationStats>,
+}
+
+impl SimulatedBatchSink {
+    pub(crate) fn new(stats: Arc<SimulationStats>) -> Self {
+        Self {
+            queue: Mutex::new(Vec::new()),
+            stats,
+        }
+    }
+}
+
+#[async_trait]
+impl BatchSink for SimulatedBatchSink {
+    fn enqueue(&self, event: Event) {
+        self.enqueue_local(event.local_id(), event);
+    }
+
+    fn enqueue_many(&self, events: Vec<Event>) {
+        self.stats
+            .events_queued
+            .fetch_add(events.len() as u64, Ordering::Relaxed);
+        self.queue
+            .lock()
+            .extend(events.into_iter().map(|event| (event.local_id(), event)));
+    }
+
+    fn enqueue_local(&self, local_id: String, event: Event) {
+        self.stats.events_queued.fetch_add(1, Ordering::Relaxed);
+        self.queue.lock().push((local_id, event));
+    }
+
+    async fn flush(&self) -> Result<FlushReport, CoreError> {
+        let batch = std::mem::take(&mut *self.queue.lock());
+        if batch.is_empty() {
+            return Ok(FlushReport::default());
+        }
+        self.stats
+            .events_uploaded
+            .fetch_add(batch.len() as u64, Ordering::Relaxed);
+        self.stats.upload_flushes.fetch_add(1, Ordering::Relaxed);
+        info!(count = batch.len(), "simulate: would upload event batch");
+        Ok(FlushReport {
+            accepted: batch.into_iter().map(|(local_id, _)| local_id).collect(),
+            ..FlushReport::default()
+        })
+    }
+}
+
+/// [`FrameProcessor`] that records what would be captured without touching
+/// the screen. Frames carry metadata only.
+pub(crate) struct MetadataOnlyFrameProcessor {
+    stats: Arc<SimulationStats>,
+}
+
+impl MetadataOnlyFrameProcessor {
+    pub(crate) fn new(stats: Arc<SimulationStats>) -> Self {
+        Self { stats }
+    }
+}
+
+#[async_trait]
+impl FrameProcessor for MetadataOnlyFrameProcessor {
+    async fn capture_and_process(
+        &self,
+        capture_request: &CaptureRequest,
+    ) -> Result<ProcessedFrame, CoreError> {
+        self.stats.frames_captured.fetch_add(1, Ordering::Relaxed);
+        debug!(
+            app = %capture_request.app_name,
+            trigger = %capture_request.trigger_type,
+            "simulate: would capture frame"
+        );
+        let resolution = capture_request
+            .window_bounds
+            .map_or((0, 0), |bounds| (bounds.width, bounds.height));
+        Ok(ProcessedFrame {
+            metadata: FrameMetadata {
+                timestamp: Utc::now(),
+                trigger_type: capture_request.trigger_type.clone(),
+                app_name: capture_request.app_name.clone(),
+                window_title: capture_request.window_title.clone(),
+                resolution,
+                importance: capture_request.importance,
+            },
+            image_payload: None,
+            ocr_regions: Vec::new(),
+            raw_rgba: None,
+            preview: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use oneshim_core::models::event::ContextEvent;
+
+    #[tokio::test]
+    async fn batch_sink_counts_instead_of_sending() {
+        let stats = Arc::new(SimulationStats::default());
+        let sink = SimulatedBatchSink::new(stats.clone());
+        sink.enqueue(Event::Context(ContextEvent::default()));
+        sink.enqueue_many(vec![Event::Context(ContextEvent::default()); 2]);
+
+        assert_eq!(sink.flush().await.unwrap().sent(), 3);
+        assert_eq!(sink.flush().await.unwrap().sent(), 0);
+        assert_eq!(
+            stats.summary(),
+            SimulationSummary {
+                events_queued: 3,
+                events_would_upload: 3,
+                upload_flushes: 1,
+                frames_would_capture: 0,
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn frame_processor_returns_metadata_only() {
+        let stats = Arc::new(SimulationStats::default());
+        let processor = MetadataOnlyFrameProcessor::new(stats.clone());
+        let frame = processor
+            .capture_and_process(&CaptureRequest {
+                trigger_type: "AppSwitch".to_string(),
+                importance: 0.8,
+                app_name: "Code".to_string(),
+                window_title: "main.rs".to_string(),
+                window_bounds: None,
+            })
+            .await
+            .unwrap();
+
+        assert!(frame.image_payload.is_none());
+        assert_eq!(frame.metadata.app_name, "Code");
+        assert_eq!(stats.summary().frames_would_capture, 1);
+    }
+}
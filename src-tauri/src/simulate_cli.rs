@@ -0,0 +1,106 @@
+//! `oneshim --simulate [--summary-secs N]` — run the agent as a dry run.
+//!
+//! Nothing is written to disk or sent to the server; a summary of what would
+//! have been collected and uploaded is printed every N seconds (default 60)
+//! and once more on Ctrl-C.
+
+use std::time::Duration;
+
+use oneshim_core::config::AppConfig;
+use oneshim_core::config_manager::ConfigManager;
+
+use crate::agent::AgentBuilder;
+
+const DEFAULT_SUMMARY_SECS: u64 = 60;
+
+pub fn run(args: &[String]) -> i32 {
+    let summary_every = match parse_summary_secs(args) {
+        Ok(secs) => Duration::from_secs(secs),
+        Err(message) => {
+            eprintln!("{message}");
+            eprintln!("Usage: oneshim --simulate [--summary-secs N]");
+            return 1;
+        }
+    };
+    // Use the user's settings so the dry run matches a real run.
+    let config =
+        ConfigManager::new().map_or_else(|_| AppConfig::default_config(), |manager| manager.get());
+
+    let runtime = match tokio::runtime::Runtime::new() {
+        Ok(runtime) => runtime,
+        Err(error) => {
+            eprintln!("failed to start runtime: {error}");
+            return 1;
+        }
+    };
+    runtime.block_on(simulate(config, summary_every))
+}
+
+async fn simulate(config: AppConfig, summary_every: Duration) -> i32 {
+    let mut agent = match AgentBuilder::new(config).simulate().build() {
+        Ok(agent) => agent,
+        Err(error) => {
+            eprintln!("failed to build simulated agent: {error:#}");
+            return 1;
+        }
+    };
+    if let Err(error) = agent.start() {
+        eprintln!("failed to start simulated agent: {error:#}");
+        return 1;
+    }
+    println!("simulation running — nothing is stored or uploaded. Press Ctrl-C to stop.");
+
+    let mut ticker = tokio::time::interval(summary_every);
+    ticker.tick().await;
+    loop {
+        tokio::select! {
+            _ = ticker.tick() => {
+                if let Some(summary) = agent.simulation_summary() {
+                    println!("simulate: {summary}");
+                }
+            }
+            _ = tokio::signal::ctrl_c() => break,
+        }
+    }
+
+    let exit_code = match agent.stop().await {
+        Ok(()) => 0,
+        Err(error) => {
+            eprintln!("simulated agent did not stop cleanly: {error:#}");
+            1
+        }
+    };
+    if let Some(summary) = agent.simulation_summary() {
+        println!("simulation finished: {summary}");
+    }
+    exit_code
+}
+
+fn parse_summary_secs(args: &[String]) -> Result<u64, String> {
+    match args {
+        [] => Ok(DEFAULT_SUMMARY_SECS),
+        [flag, value] if flag == "--summary-secs" => value
+            .parse::<u64>()
+            .ok()
+            .filter(|secs| *secs > 0)
+            .ok_or_else(|| format!("invalid --summary-secs value: {value}")),
+        _ => Err(format!("unexpected arguments: {}", args.join(" "))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn summary_interval_defaults_and_parses() {
+        assert_eq!(parse_summary_secs(&[]), Ok(DEFAULT_SUMMARY_SECS));
+        assert_eq!(parse_summary_secs(&args(&["--summary-secs", "5"])), Ok(5));
+        assert!(parse_summary_secs(&args(&["--summary-secs", "0"])).is_err());
+        assert!(parse_summary_secs(&args(&["--bogus"])).is_err());
+    }
+}
@@ -1,8 +1,10 @@
 use anyhow::Result;
+use oneshim_core::config::{AppConfig, DbKeySource};
 use oneshim_storage::encryption::EncryptionKey;
 use oneshim_storage::sqlite::SqliteStorage;
 use std::path::Path;
 use std::sync::Arc;
+use tokio::sync::watch;
 use tracing::{info, warn};
 
 pub(crate) struct StorageRuntimeBundle {
@@ -11,10 +13,40 @@ pub(crate) struct StorageRuntimeBundle {
     pub(crate) encryption_key: Option<Arc<EncryptionKey>>,
 }
 
+impl StorageRuntimeBundle {
+    /// Apply `storage.retention_days` and `storage.retention_overrides`
+    /// from config reloads to the open database so the next retention run
+    /// uses the new windows.
+    pub(crate) fn spawn_retention_follow(
+        &self,
+        runtime: &tokio::runtime::Handle,
+        mut config_rx: watch::Receiver<Arc<AppConfig>>,
+    ) {
+        let sqlite_storage = self.sqlite_storage.clone();
+        apply_retention(&sqlite_storage, &config_rx.borrow());
+        runtime.spawn(async move {
+            while config_rx.changed().await.is_ok() {
+                let config = config_rx.borrow_and_update().clone();
+                apply_retention(&sqlite_storage, &config);
+            }
+        });
+    }
+}
+
+fn apply_retention(sqlite_storage: &SqliteStorage, config: &AppConfig) {
+    sqlite_storage.set_retention_days(config.storage.retention_days);
+    sqlite_storage.set_retention_overrides(
+        config.storage.retention_overrides.clone(),
+        config.monitor.category_overrides.clone(),
+    );
+}
+
 pub(crate) struct StorageRuntimeBuilder<'a> {
     db_path: &'a Path,
     data_dir: &'a Path,
     retention_days: u32,
+    text_compression_min_bytes: Option<usize>,
+    key_source: DbKeySource,
 }
 
 impl<'a> StorageRuntimeBuilder<'a> {
@@ -23,30 +55,47 @@ impl<'a> StorageRuntimeBuilder<'a> {
             db_path,
             data_dir,
             retention_days,
+            text_compression_min_bytes: None,
+            key_source: DbKeySource::default(),
         }
     }
 
+    pub(crate) fn with_text_compression(mut self, min_bytes: Option<usize>) -> Self {
+        self.text_compression_min_bytes = min_bytes;
+        self
+    }
+
+    pub(crate) fn with_key_source(mut self, key_source: DbKeySource) -> Self {
+        self.key_source = key_source;
+        self
+    }
+
     pub(crate) fn build(&self) -> Result<StorageRuntimeBundle> {
-        let encryption_key =
-            match oneshim_storage::encryption::EncryptionKey::load_or_create(self.data_dir) {
-                Ok(key) => {
-                    info!(
-                        "DB encryption key ready ({})",
-                        self.data_dir.join(".db_key").display()
-                    );
-                    Some(key)
-                }
-                Err(error) => {
-                    warn!("DB encryption key provisioning failed (non-fatal): {error}");
-                    None
-                }
-            };
+        let (provisioned, key_location) = match self.key_source {
+            DbKeySource::File => (
+                EncryptionKey::load_or_create(self.data_dir),
+                self.data_dir.join(".db_key").display().to_string(),
+            ),
+            DbKeySource::Keyring => (
+                EncryptionKey::load_or_create_in_keyring(),
+                "OS keychain".to_string(),
+            ),
+        };
+        let encryption_key = match provisioned {
+            Ok(key) => {
+                info!("DB encryption key ready ({key_location})");
+                Some(key)
+            }
+            Err(error) => {
+                warn!("DB encryption key provisioning failed (non-fatal): {error}");
+                None
+            }
+        };
 
-        let sqlite_storage = Arc::new(SqliteStorage::open(
-            self.db_path,
-            self.retention_days,
-            encryption_key.as_ref(),
-        )?);
+        let sqlite_storage = Arc::new(
+            SqliteStorage::open(self.db_path, self.retention_days, encryption_key.as_ref())?
+                .with_text_compression(self.text_compression_min_bytes),
+        );
         if encryption_key.is_some() {
             info!(
                 "SQLite initialized: {} (SQLCipher encrypted)",
@@ -54,7 +54,7 @@ impl Handle {
     ///   reads/writes `telemetry_instance_id` under `data_dir`, wraps the
     ///   `OtelLayer` in the reload wrapper.
     ///
-    /// Signature is identical across feature states so `main.rs` and tests can
+    /// Signature is identical across feature states so `lib.rs` and tests can
     /// pass `data_dir` unconditionally.
     pub fn new_with_layer(
         _cfg: &TelemetryConfig,
@@ -204,7 +204,7 @@ mod tests_feature_on {
         let cfg_path = tmp.path().join("config.json");
         let mgr = StdArc::new(ConfigManager::with_path(cfg_path).unwrap());
 
-        // Build the handle seeded with defaults — matches main.rs wiring.
+        // Build the handle seeded with defaults — matches lib.rs wiring.
         let (_layer, handle) = Handle::new_with_layer(&TelemetryConfig::default(), tmp.path())
             .expect("feature-on disabled construction is infallible");
         let handle = StdArc::new(handle);
@@ -33,6 +33,15 @@ fn read_connection_status<R: Runtime>(app: &impl Manager<R>) -> (bool, bool, boo
         .unwrap_or((false, false, false))
 }
 
+/// Whole seconds until the next server attempt while the circuit breaker is
+/// open, rounded up so the label never reads "retrying in 0s".
+pub(crate) fn server_retry_in_secs(
+    connection: &crate::runtime_state::ConnectionStatus,
+) -> Option<u64> {
+    let retry_in = connection.server_circuit.as_ref()?.retry_in()?;
+    Some(retry_in.as_secs() + u64::from(retry_in.subsec_nanos() > 0))
+}
+
 /// Determine the tray icon state from capture and connection flags.
 fn resolve_icon_state(paused: bool, any_disconnected: bool) -> TrayIconState {
     if paused {
@@ -52,10 +61,13 @@ fn build_connection_items<R: Runtime>(
     llm: bool,
     cli: bool,
 ) -> Result<(MenuItem<R>, MenuItem<R>, MenuItem<R>), Box<dyn std::error::Error>> {
+    let srv_retry = app
+        .try_state::<crate::runtime_state::AppState>()
+        .and_then(|s| server_retry_in_secs(&s.connection));
     let srv_item = MenuItem::with_id(
         app,
         "conn-server",
-        connection_item_label("Server API", srv),
+        server_item_label(srv, srv_retry),
         false,
         None::<&str>,
     )?;
@@ -87,6 +99,15 @@ fn connection_item_label(name: &str, connected: bool) -> String {
     )
 }
 
+/// Server row: the retry countdown replaces the plain status while the
+/// circuit breaker holds requests back.
+fn server_item_label(connected: bool, retry_in_secs: Option<u64>) -> String {
+    match retry_in_secs {
+        Some(secs) => format!("  Server API: unreachable (retrying in {secs}s)"),
+        None => connection_item_label("Server API", connected),
+    }
+}
+
 /// Determine status text from capture/connection state (no emoji — template icon handles visual).
 fn status_text(paused: bool, any_disconnected: bool) -> &'static str {
     if paused {
@@ -176,6 +197,8 @@ fn build_tray_menu<R: Runtime>(
     let toggle_capture = MenuItem::with_id(app, "toggle-capture", toggle_text, true, None::<&str>)?;
     let toggle_indicator =
         MenuItem::with_id(app, "toggle-indicator", indicator_text, true, None::<&str>)?;
+    let toggle_focus =
+        MenuItem::with_id(app, "toggle-focus", "Toggle Focus Mode", true, None::<&str>)?;
 
     let show = MenuItem::with_id(app, "show", dashboard_toggle_label(), true, None::<&str>)?;
     let settings = MenuItem::with_id(app, "settings", "Settings", true, None::<&str>)?;
@@ -214,6 +237,7 @@ fn build_tray_menu<R: Runtime>(
             &PredefinedMenuItem::separator(app)?,
             &toggle_capture,
             &toggle_indicator,
+            &toggle_focus,
             &PredefinedMenuItem::separator(app)?,
             &show,
             &PredefinedMenuItem::separator(app)?,
@@ -334,6 +358,21 @@ pub fn setup_tray<R: Runtime>(app: &tauri::App<R>) -> Result<(), Box<dyn std::er
                     }
                 }
             }
+            "toggle-focus" => {
+                if let Some(state) = app.try_state::<crate::runtime_state::AppState>() {
+                    // Indefinite from the tray; timed sessions go through IPC.
+                    let active = !state.focus_mode.is_active();
+                    if active {
+                        state.focus_mode.activate(0, false);
+                    } else {
+                        state.focus_mode.deactivate();
+                    }
+                    if let Some(ref overlay) = state.magic_overlay {
+                        overlay.emit_focus_mode(active, false);
+                    }
+                    info!(active, "tray: focus mode toggled");
+                }
+            }
             "show" => {
                 if let Some(w) = app.get_webview_window("main") {
                     if w.is_visible().unwrap_or(false) {
@@ -461,6 +500,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn server_item_label_shows_retry_countdown_while_circuit_is_open() {
+        assert_eq!(
+            server_item_label(false, Some(30)),
+            "  Server API: unreachable (retrying in 30s)"
+        );
+        assert_eq!(server_item_label(true, None), "  Server API: connected");
+    }
+
     #[test]
     fn update_actions_are_disabled_without_actionable_update() {
         let actions = tray_update_actions(None);
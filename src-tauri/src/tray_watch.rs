@@ -17,14 +17,14 @@ use tracing::{debug, warn};
 
 /// Owned wrapper around the tray-watch task `JoinHandle`.
 ///
-/// Stored as Tauri managed state so that `main.rs` shutdown code can call
+/// Stored as Tauri managed state so that `lib.rs` shutdown code can call
 /// `abort()` cleanly on app exit.
 pub(crate) struct TrayWatchHandle(pub(crate) tauri::async_runtime::JoinHandle<()>);
 
 impl Drop for TrayWatchHandle {
     fn drop(&mut self) {
         // Best-effort abort on drop; task is also aborted explicitly in the
-        // RunEvent::Exit handler in main.rs.
+        // RunEvent::Exit handler in lib.rs.
         self.0.abort();
     }
 }
@@ -2,7 +2,7 @@ use crate::updater::{UpdateCheckResult, UpdateError, Updater};
 use async_trait::async_trait;
 use chrono::Utc;
 use oneshim_api_contracts::update::DownloadProgress;
-use oneshim_core::config::UpdateConfig;
+use oneshim_core::config::{EgressConfig, UpdateConfig};
 use oneshim_web::update_control::{PendingUpdateInfo, UpdateAction, UpdatePhase, UpdateStatus};
 use std::path::{Path, PathBuf};
 use std::sync::Arc;
@@ -84,6 +84,7 @@ pub fn initial_status(config: &UpdateConfig, auto_install: bool) -> UpdateStatus
 
 pub async fn run_update_coordinator(
     config: UpdateConfig,
+    egress: EgressConfig,
     state: Arc<RwLock<UpdateStatus>>,
     action_rx: mpsc::UnboundedReceiver<UpdateAction>,
     status_tx: Option<broadcast::Sender<UpdateStatus>>,
@@ -129,7 +130,7 @@ pub async fn run_update_coordinator(
         );
     }
 
-    let updater = Updater::new(config);
+    let updater = Updater::new(config).with_egress(egress);
 
     run_update_coordinator_with_executor(
         updater,
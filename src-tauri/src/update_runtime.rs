@@ -1,4 +1,4 @@
-use oneshim_core::config::UpdateConfig;
+use oneshim_core::config::{EgressConfig, UpdateConfig};
 use oneshim_web::update_control::{PendingUpdateInfo, UpdateAction, UpdateControl, UpdatePhase};
 use tokio::runtime::Handle;
 use tracing::{debug, info};
@@ -13,6 +13,7 @@ pub(crate) struct UpdateRuntimeBundle {
 
 pub(crate) struct UpdateRuntimeBuilder<'a> {
     config: &'a UpdateConfig,
+    egress: EgressConfig,
     runtime_handle: &'a Handle,
 }
 
@@ -20,10 +21,16 @@ impl<'a> UpdateRuntimeBuilder<'a> {
     pub(crate) fn new(config: &'a UpdateConfig, runtime_handle: &'a Handle) -> Self {
         Self {
             config,
+            egress: EgressConfig::default(),
             runtime_handle,
         }
     }
 
+    pub(crate) fn with_egress(mut self, egress: &EgressConfig) -> Self {
+        self.egress = egress.clone();
+        self
+    }
+
     pub(crate) fn build_and_spawn(&self) -> UpdateRuntimeBundle {
         let runtime_auto_update = self.config.auto_install;
         let (update_action_tx, update_action_rx) =
@@ -38,10 +45,11 @@ impl<'a> UpdateRuntimeBuilder<'a> {
             // Publishes to broadcast channel so the Tauri event bridge can
             // forward the result to the frontend immediately.
             let startup_config = self.config.clone();
+            let startup_egress = self.egress.clone();
             let startup_event_tx = update_control.event_tx.clone();
             let startup_state = update_control.state.clone();
             self.runtime_handle.spawn(async move {
-                let updater = Updater::new(startup_config);
+                let updater = Updater::new(startup_config).with_egress(startup_egress);
                 match tokio::time::timeout(
                     std::time::Duration::from_secs(3),
                     updater.check_for_updates(),
@@ -92,11 +100,13 @@ impl<'a> UpdateRuntimeBuilder<'a> {
             });
 
             let update_config = self.config.clone();
+            let update_egress = self.egress.clone();
             let update_state = update_control.state.clone();
             let update_status_tx = Some(update_control.event_tx.clone());
             self.runtime_handle.spawn(async move {
                 update_coordinator::run_update_coordinator(
                     update_config,
+                    update_egress,
                     update_state,
                     update_action_rx,
                     update_status_tx,
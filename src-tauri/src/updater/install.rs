@@ -414,15 +414,31 @@ impl Updater {
                 host
             )));
         }
+        self.check_egress(parsed.as_str())?;
 
         Ok(parsed)
     }
 
+    /// Enforce the user-configured egress allowlist (`egress` config section).
+    pub(super) fn check_egress(&self, url: &str) -> Result<(), UpdateError> {
+        if !self.egress.enabled {
+            return Ok(());
+        }
+        let host = reqwest::Url::parse(url)
+            .ok()
+            .and_then(|parsed| parsed.host_str().map(str::to_string))
+            .unwrap_or_default();
+        if self.egress.is_allowed_host(&host) {
+            return Ok(());
+        }
+        tracing::warn!(host = %host, "egress blocked: host is not in the egress allowlist");
+        Err(UpdateError::EgressBlocked(format!(
+            "host '{host}' is not in the egress allowlist"
+        )))
+    }
+
     pub(super) fn is_allowed_download_host(host: &str) -> bool {
-        let allowlisted = Self::ALLOWED_DOWNLOAD_HOSTS.iter().any(|allowed_host| {
-            host == *allowed_host || host.ends_with(&format!(".{}", allowed_host))
-        });
-        if allowlisted {
+        if oneshim_core::config::host_matches_allowlist(host, &Self::ALLOWED_DOWNLOAD_HOSTS) {
             return true;
         }
 
@@ -17,7 +17,7 @@ mod trusted_keys;
 pub(crate) use health_probe::{HealthProbe, ProbeError, RollbackReason, StartupAction};
 
 #[allow(unused_imports)] // UpdateChannel used in #[cfg(test)] only
-use oneshim_core::config::{UpdateChannel, UpdateConfig};
+use oneshim_core::config::{EgressConfig, UpdateChannel, UpdateConfig};
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
@@ -78,6 +78,9 @@ pub enum UpdateError {
 
     #[error("Integrity verification failed: {0}")]
     Integrity(String),
+
+    #[error("Egress blocked: {0}")]
+    EgressBlocked(String),
 }
 
 #[derive(Debug, Clone, Deserialize)]
@@ -119,6 +122,7 @@ pub enum UpdateCheckResult {
 pub struct Updater {
     pub(super) config: UpdateConfig,
     pub(super) http_client: reqwest::Client,
+    pub(super) egress: EgressConfig,
 }
 
 impl Updater {
@@ -150,14 +154,23 @@ impl Updater {
         Self {
             config,
             http_client,
+            egress: EgressConfig::default(),
         }
     }
 
+    /// Apply the shared outbound host allowlist on top of the built-in
+    /// GitHub download hosts.
+    pub fn with_egress(mut self, egress: EgressConfig) -> Self {
+        self.egress = egress;
+        self
+    }
+
     #[cfg(test)]
     pub fn with_client(config: UpdateConfig, http_client: reqwest::Client) -> Self {
         Self {
             config,
             http_client,
+            egress: EgressConfig::default(),
         }
     }
 
@@ -293,6 +306,7 @@ impl Updater {
             )
         };
 
+        self.check_egress(&url)?;
         let response = self.http_client.get(&url).send().await?;
 
         if !response.status().is_success() {
@@ -827,6 +841,27 @@ mod tests {
         assert!(unknown_host.is_err());
     }
 
+    #[test]
+    fn validate_download_url_applies_egress_allowlist() {
+        let allow_github = Updater::new(test_config()).with_egress(EgressConfig {
+            enabled: true,
+            allowed_hosts: vec!["github.com".to_string()],
+        });
+        assert!(allow_github
+            .validate_download_url("https://github.com/owner/repo/file.tar.gz")
+            .is_ok());
+
+        let allow_nothing = Updater::new(test_config()).with_egress(EgressConfig {
+            enabled: true,
+            allowed_hosts: vec![],
+        });
+        let blocked = allow_nothing
+            .validate_download_url("https://github.com/owner/repo/file.tar.gz")
+            .unwrap_err();
+        assert!(matches!(blocked, UpdateError::EgressBlocked(_)));
+        assert!(blocked.to_string().contains("github.com"));
+    }
+
     #[test]
     fn extract_zip_rejects_path_traversal_entries() {
         use std::io::Write;
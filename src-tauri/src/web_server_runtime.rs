@@ -5,12 +5,15 @@ use oneshim_core::config::AppConfig;
 #[cfg(feature = "server")]
 use oneshim_core::config::CredentialBackendKind;
 use oneshim_core::config_manager::ConfigManager;
+use oneshim_core::models::capture_latency::CaptureLatencyRecorder;
 use oneshim_core::ports::frame_storage::FrameStoragePort;
 #[cfg(feature = "server")]
 use oneshim_core::ports::integration::{
     IntegrationAuditPort, IntegrationAuthPort, IntegrationInboxPort, IntegrationInboxStorePort,
     IntegrationOutboxPort, IntegrationRuntimeTelemetryPort, IntegrationSessionPort,
 };
+use oneshim_core::ports::loop_health::LoopHealthProvider;
+use oneshim_core::ports::network_metrics::NetworkMetricsProvider;
 #[cfg(feature = "server")]
 use oneshim_core::ports::oauth::OAuthPort;
 use oneshim_core::ports::runtime_log_provider::RuntimeLogProvider;
@@ -124,6 +127,8 @@ pub(crate) struct WebServerSupportContext {
     integration_runtime_status: IntegrationOutboundRuntimeStatus,
     app_handle: Option<tauri::AppHandle>,
     cli_health_flag: Option<Arc<std::sync::atomic::AtomicBool>>,
+    focus_mode: Option<Arc<crate::focus_mode::FocusModeState>>,
+    gui_ticket_hmac_secret: Option<String>,
     #[cfg(feature = "server")]
     server: Option<WebServerServerSupport>,
 }
@@ -140,6 +145,8 @@ impl WebServerSupportContext {
             integration_runtime_status,
             app_handle: None,
             cli_health_flag: None,
+            focus_mode: None,
+            gui_ticket_hmac_secret: None,
             #[cfg(feature = "server")]
             server: None,
         }
@@ -155,6 +162,19 @@ impl WebServerSupportContext {
         self
     }
 
+    pub(crate) fn with_focus_mode(
+        mut self,
+        focus_mode: Arc<crate::focus_mode::FocusModeState>,
+    ) -> Self {
+        self.focus_mode = Some(focus_mode);
+        self
+    }
+
+    pub(crate) fn with_gui_ticket_hmac_secret(mut self, secret: Option<String>) -> Self {
+        self.gui_ticket_hmac_secret = secret;
+        self
+    }
+
     #[cfg(feature = "server")]
     pub(crate) fn with_server_support(mut self, server: WebServerServerSupport) -> Self {
         self.server = Some(server);
@@ -175,6 +195,12 @@ impl WebServerSupportContext {
         } else {
             builder
         };
+        let builder = if let Some(ref focus_mode) = self.focus_mode {
+            builder.with_focus_mode(focus_mode.clone())
+        } else {
+            builder
+        };
+        let builder = builder.with_gui_ticket_hmac_secret(self.gui_ticket_hmac_secret.clone());
 
         #[cfg(feature = "server")]
         {
@@ -256,6 +282,9 @@ pub(crate) struct WebServerRuntimeBuilder<'a> {
     coaching_engine: Option<Arc<dyn oneshim_core::ports::coaching::CoachingPort>>,
     session_manager: Option<Arc<dyn oneshim_core::ports::conversation_session::SessionManager>>,
     frame_storage: Option<Arc<dyn FrameStoragePort>>,
+    loop_health: Option<Arc<dyn LoopHealthProvider>>,
+    capture_latency: Option<Arc<CaptureLatencyRecorder>>,
+    network_metrics: Option<Arc<dyn NetworkMetricsProvider>>,
     /// Task 7.1: pre-built LiveExternalConfig Arc shared with the external gRPC server.
     /// Populated before `build_and_spawn` when `grpc-dashboard-external` is active so the
     /// web server's `DiagnosticsState` can serve `GET /api/external-grpc/live-config`.
@@ -284,6 +313,9 @@ impl<'a> WebServerRuntimeBuilder<'a> {
             coaching_engine: None,
             session_manager: None,
             frame_storage: None,
+            loop_health: None,
+            capture_latency: None,
+            network_metrics: None,
             #[cfg(feature = "grpc-dashboard-external")]
             external_grpc_live: None,
             #[cfg(feature = "grpc-dashboard-external")]
@@ -319,6 +351,24 @@ impl<'a> WebServerRuntimeBuilder<'a> {
         self
     }
 
+    pub(crate) fn with_loop_health(mut self, provider: Arc<dyn LoopHealthProvider>) -> Self {
+        self.loop_health = Some(provider);
+        self
+    }
+
+    pub(crate) fn with_capture_latency(mut self, recorder: Arc<CaptureLatencyRecorder>) -> Self {
+        self.capture_latency = Some(recorder);
+        self
+    }
+
+    pub(crate) fn with_network_metrics(
+        mut self,
+        provider: Arc<dyn NetworkMetricsProvider>,
+    ) -> Self {
+        self.network_metrics = Some(provider);
+        self
+    }
+
     pub(crate) fn with_recluster_requested(
         mut self,
         flag: Arc<std::sync::atomic::AtomicBool>,
@@ -377,7 +427,14 @@ impl<'a> WebServerRuntimeBuilder<'a> {
             )
             .await
         }) {
-            Ok(storage) => Some(Arc::new(storage)),
+            Ok(storage) => Some(Arc::new(
+                storage
+                    .with_shard_granularity(self.config.storage.frame_shard_granularity)
+                    .with_disk_thresholds(
+                        self.config.storage.disk_critical_free_mb,
+                        self.config.storage.disk_resume_free_mb,
+                    ),
+            )),
             Err(err) => {
                 warn!(error = %err, "frame storage init failure, falling back to NoOp");
                 None
@@ -391,7 +448,8 @@ impl<'a> WebServerRuntimeBuilder<'a> {
                 self.launch_context.runtime_handle,
                 web_audit_logger.clone(),
                 automation_frame_storage,
-            );
+            )
+            .with_frame_index(self.storage.clone());
             let builder = self.support_context.configure_automation_builder(builder);
             builder.build()
         };
@@ -431,6 +489,9 @@ impl<'a> WebServerRuntimeBuilder<'a> {
         let ext_live_for_web = self.external_grpc_live.take();
         #[cfg(feature = "grpc-dashboard-external")]
         let ext_metrics_for_web = self.external_grpc_metrics.take();
+        let loop_health_for_web = self.loop_health.take();
+        let capture_latency_for_web = self.capture_latency.take();
+        let network_metrics_for_web = self.network_metrics.take();
         self.launch_context.runtime_handle.spawn(async move {
             if let Some(controller) = automation_controller {
                 runtime_bindings.automation.automation_controller = Some(controller);
@@ -447,6 +508,18 @@ impl<'a> WebServerRuntimeBuilder<'a> {
                 .with_system_info_provider(
                     Arc::new(SysInfoProvider::new()) as Arc<dyn SystemInfoProvider>
                 );
+            let web_server = match loop_health_for_web {
+                Some(provider) => web_server.with_loop_health_provider(provider),
+                None => web_server,
+            };
+            let web_server = match capture_latency_for_web {
+                Some(recorder) => web_server.with_capture_latency_recorder(recorder),
+                None => web_server,
+            };
+            let web_server = match network_metrics_for_web {
+                Some(provider) => web_server.with_network_metrics_provider(provider),
+                None => web_server,
+            };
             // Task 7.1: wire LiveExternalConfig + ExternalMetrics into AppState so the
             // GET /api/external-grpc/live-config endpoint can serve live snapshots.
             #[cfg(feature = "grpc-dashboard-external")]
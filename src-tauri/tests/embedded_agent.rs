@@ -0,0 +1,239 @@
+//! Embedding tests for the library `AgentBuilder`/`Agent` API.
+//!
+//! Runs the scheduler headless with in-memory storage, offline mode and
+//! stubbed vision ports so no screen capture or network access happens.
+
+use async_trait::async_trait;
+use futures::StreamExt;
+use oneshim_app::AgentBuilder;
+use oneshim_core::config::AppConfig;
+use oneshim_core::error::CoreError;
+use oneshim_core::models::event::{ContextEvent, Event, EventBatch};
+use oneshim_core::models::frame::{ContextUpload, ProcessedFrame};
+use oneshim_core::models::suggestion::SuggestionFeedback;
+use oneshim_core::models::system::SystemMetrics;
+use oneshim_core::monitor_feed::MonitorItem;
+use oneshim_core::ports::api_client::{ApiClient, SessionCreateResponse};
+use oneshim_core::ports::batch_sink::{BatchSink, FlushReport};
+use oneshim_core::ports::monitor::SystemMonitor;
+use oneshim_core::ports::vision::{CaptureRequest, CaptureTrigger, FrameProcessor};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+// ── Stub vision ports ──
+
+struct NeverCapture;
+
+impl CaptureTrigger for NeverCapture {
+    fn should_capture(&self, _event: &ContextEvent) -> Option<CaptureRequest> {
+        None
+    }
+}
+
+struct UnavailableFrameProcessor;
+
+#[async_trait]
+impl FrameProcessor for UnavailableFrameProcessor {
+    async fn capture_and_process(
+        &self,
+        _capture_request: &CaptureRequest,
+    ) -> Result<ProcessedFrame, CoreError> {
+        Err(CoreError::Internal {
+            code: oneshim_core::error_codes::InternalCode::Generic,
+            message: "capture disabled in tests".to_string(),
+        })
+    }
+}
+
+struct FixedMetrics;
+
+#[async_trait]
+impl SystemMonitor for FixedMetrics {
+    async fn collect_metrics(&self) -> Result<SystemMetrics, CoreError> {
+        Ok(SystemMetrics {
+            timestamp: chrono::Utc::now(),
+            cpu_usage: 42.0,
+            memory_used: 1,
+            memory_total: 2,
+            disk_used: 0,
+            disk_total: 0,
+            network: None,
+            typing_wpm: 0.0,
+        })
+    }
+}
+
+/// Counts every network-facing call so simulate mode can prove it made none.
+#[derive(Default)]
+struct CountingNetwork {
+    calls: AtomicUsize,
+}
+
+impl CountingNetwork {
+    fn record(&self) {
+        self.calls.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+#[async_trait]
+impl ApiClient for CountingNetwork {
+    async fn create_session(&self, client_id: &str) -> Result<SessionCreateResponse, CoreError> {
+        self.record();
+        Ok(SessionCreateResponse {
+            session_id: "s".to_string(),
+            user_id: "u".to_string(),
+            client_id: client_id.to_string(),
+            capabilities: Vec::new(),
+        })
+    }
+
+    async fn end_session(&self, _session_id: &str) -> Result<(), CoreError> {
+        self.record();
+        Ok(())
+    }
+
+    async fn upload_batch(&self, _batch: &EventBatch) -> Result<(), CoreError> {
+        self.record();
+        Ok(())
+    }
+
+    async fn upload_context(&self, _upload: &ContextUpload) -> Result<(), CoreError> {
+        self.record();
+        Ok(())
+    }
+
+    async fn send_feedback(&self, _feedback: &SuggestionFeedback) -> Result<(), CoreError> {
+        self.record();
+        Ok(())
+    }
+
+    async fn send_heartbeat(&self, _session_id: &str) -> Result<(), CoreError> {
+        self.record();
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl BatchSink for CountingNetwork {
+    fn enqueue(&self, _event: Event) {
+        self.record();
+    }
+
+    fn enqueue_many(&self, _events: Vec<Event>) {
+        self.record();
+    }
+
+    async fn flush(&self) -> Result<FlushReport, CoreError> {
+        self.record();
+        Ok(FlushReport::default())
+    }
+}
+
+fn headless_builder() -> AgentBuilder {
+    AgentBuilder::new(AppConfig::default_config())
+        .with_in_memory_storage()
+        .offline()
+        .with_capture_trigger(Arc::new(NeverCapture))
+        .with_frame_processor(Arc::new(UnavailableFrameProcessor))
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn in_memory_offline_agent_starts_and_stops_cleanly() {
+    let mut agent = headless_builder().build().expect("agent should build");
+    assert!(agent.is_offline());
+    assert!(!agent.is_running());
+
+    agent.start().expect("agent should start");
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    assert!(agent.is_running());
+
+    tokio::time::timeout(Duration::from_secs(10), agent.stop())
+        .await
+        .expect("agent should stop within 10s")
+        .expect("agent loops should exit without panicking");
+    assert!(!agent.is_running());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn agent_rejects_double_start_and_tolerates_double_stop() {
+    let mut agent = headless_builder().build().expect("agent should build");
+
+    agent.start().expect("first start succeeds");
+    assert!(agent.start().is_err());
+
+    agent.stop().await.expect("first stop succeeds");
+    agent
+        .stop()
+        .await
+        .expect("stopping an idle agent is a no-op");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn agent_can_restart_after_stop() {
+    let mut agent = headless_builder().build().expect("agent should build");
+
+    agent.start().expect("start");
+    agent.stop().await.expect("stop");
+    agent.start().expect("restart");
+    agent.stop().await.expect("stop again");
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn simulate_mode_skips_network_and_disk() {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let db_path = dir.path().join("simulated.db");
+    let network = Arc::new(CountingNetwork::default());
+    let mut config = AppConfig::default_config();
+    config.monitor.sync_interval_ms = 50;
+
+    let mut agent = AgentBuilder::new(config)
+        .with_db_path(&db_path)
+        .with_api_client(network.clone())
+        .with_batch_sink(network.clone())
+        .with_capture_trigger(Arc::new(NeverCapture))
+        .simulate()
+        .build()
+        .expect("agent should build");
+    assert!(agent.is_simulated());
+
+    agent.start().expect("start");
+    tokio::time::sleep(Duration::from_millis(300)).await;
+    agent.stop().await.expect("stop");
+
+    assert_eq!(network.calls.load(Ordering::SeqCst), 0);
+    assert!(!db_path.exists(), "simulated storage must stay in memory");
+    assert!(agent.simulation_summary().is_some());
+}
+
+#[tokio::test]
+async fn regular_agent_has_no_simulation_summary() {
+    let agent = headless_builder().build().expect("agent should build");
+    assert!(!agent.is_simulated());
+    assert!(agent.simulation_summary().is_none());
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn subscribe_streams_scheduler_collected_metrics() {
+    let mut agent = headless_builder()
+        .with_system_monitor(Arc::new(FixedMetrics))
+        .build()
+        .expect("agent should build");
+    let mut stream = agent.subscribe();
+
+    agent.start().expect("start");
+    let metrics = tokio::time::timeout(Duration::from_secs(10), async {
+        while let Some(item) = stream.next().await {
+            if let MonitorItem::Metrics(metrics) = item {
+                return metrics;
+            }
+        }
+        panic!("monitor stream ended before any metrics");
+    })
+    .await
+    .expect("metrics should be streamed within 10s");
+    assert_eq!(metrics.cpu_usage, 42.0);
+
+    drop(stream);
+    agent.stop().await.expect("stop");
+}
@@ -7,8 +7,8 @@ use oneshim_storage::sqlite::SqliteStorage;
 
 /// Helper: insert sample data into core tables so we can verify deletion.
 fn seed_sample_data(storage: &SqliteStorage) {
-    let conn = storage.connection_arc();
-    let guard = conn.lock().expect("lock");
+    let pool = storage.connection_pool();
+    let guard = pool.get().expect("connection");
 
     // V1 tables
     guard
@@ -106,8 +106,8 @@ fn seed_sample_data(storage: &SqliteStorage) {
 
 /// Helper: count rows in a table.
 fn count_rows(storage: &SqliteStorage, table: &str) -> u64 {
-    let conn = storage.connection_arc();
-    let guard = conn.lock().expect("lock");
+    let pool = storage.connection_pool();
+    let guard = pool.get().expect("connection");
     guard
         .query_row(&format!("SELECT COUNT(*) FROM {table}"), [], |row| {
             row.get::<_, i64>(0)
@@ -221,8 +221,8 @@ fn transaction_rollback_preserves_data_on_failure() {
     // We do this by directly using the connection to show that a rolled-back
     // transaction preserves all data.
     {
-        let conn = storage.connection_arc();
-        let mut guard = conn.lock().expect("lock");
+        let pool = storage.connection_pool();
+        let mut guard = pool.get().expect("connection");
         let tx = guard.transaction().expect("begin tx");
 
         // Delete events (succeeds)
@@ -261,8 +261,8 @@ fn fts5_table_cleared_within_transaction() {
 
     // Insert multiple FTS5 rows
     {
-        let conn = storage.connection_arc();
-        let guard = conn.lock().expect("lock");
+        let pool = storage.connection_pool();
+        let guard = pool.get().expect("connection");
         for i in 0..5 {
             guard
                 .execute(
@@ -284,8 +284,8 @@ fn fts5_table_cleared_within_transaction() {
 
     // Verify FTS5 search works before deletion
     {
-        let conn = storage.connection_arc();
-        let guard = conn.lock().expect("lock");
+        let pool = storage.connection_pool();
+        let guard = pool.get().expect("connection");
         let fts_count: i64 = guard
             .query_row(
                 "SELECT COUNT(*) FROM search_fts WHERE search_fts MATCH 'searchable'",
@@ -308,8 +308,8 @@ fn fts5_table_cleared_within_transaction() {
 
     // FTS5 MATCH query should return 0 results
     {
-        let conn = storage.connection_arc();
-        let guard = conn.lock().expect("lock");
+        let pool = storage.connection_pool();
+        let guard = pool.get().expect("connection");
         let fts_count: i64 = guard
             .query_row(
                 "SELECT COUNT(*) FROM search_fts WHERE search_fts MATCH 'searchable'",
@@ -134,11 +134,7 @@ async fn test_api_client_upload_batch() {
         })
         .collect();
 
-    let batch = EventBatch {
-        session_id: "test_session_batch".to_string(),
-        events,
-        created_at: chrono::Utc::now(),
-    };
+    let batch = EventBatch::new("test_session_batch".to_string(), events, chrono::Utc::now());
 
     let result = api_client.upload_batch(&batch).await;
     assert!(result.is_ok(), "batch upload failure: {:?}", result.err());
@@ -262,11 +258,7 @@ async fn test_full_client_workflow() {
         })
         .collect();
 
-    let batch = EventBatch {
-        session_id: session.session_id.clone(),
-        events,
-        created_at: chrono::Utc::now(),
-    };
+    let batch = EventBatch::new(session.session_id.clone(), events, chrono::Utc::now());
 
     api_client
         .upload_batch(&batch)